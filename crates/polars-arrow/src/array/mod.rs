@@ -660,6 +660,32 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+/// Whether two dynamic [`Array`]s are views of the same memory, e.g. because one is a clone of
+/// the other.
+/// # Implementation
+/// This operation is `O(1)` over `len`, it compares the addresses of the buffers of both arrays
+/// and of their children and dictionaries.
+pub fn same_buffers(lhs: &dyn Array, rhs: &dyn Array) -> bool {
+    if lhs.data_type() != rhs.data_type() || lhs.len() != rhs.len() {
+        return false;
+    }
+    let (lhs_offset, lhs_buffers, lhs_children, lhs_dictionary) =
+        offset_buffers_children_dictionary(lhs);
+    let (rhs_offset, rhs_buffers, rhs_children, rhs_dictionary) =
+        offset_buffers_children_dictionary(rhs);
+    lhs_offset == rhs_offset
+        && lhs_buffers == rhs_buffers
+        && lhs_children.len() == rhs_children.len()
+        && lhs_children
+            .iter()
+            .zip(rhs_children.iter())
+            .all(|(l, r)| same_buffers(l.as_ref(), r.as_ref()))
+        && match (lhs_dictionary, rhs_dictionary) {
+            (Some(l), Some(r)) => same_buffers(l.as_ref(), r.as_ref()),
+            (l, r) => l.is_none() && r.is_none(),
+        }
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
@@ -136,7 +136,9 @@ fn cast_list_to_fixed_size_list<O: Offset>(
     let null_cnt = list.null_count();
     let new_values = if null_cnt == 0 {
         let offsets = list.offsets().buffer().iter();
-        let expected = (0..list.len()).map(|ix| O::from_as_usize(ix * size));
+        // the list may be sliced, so the offsets don't necessarily start at zero
+        let first = *list.offsets().first();
+        let expected = (0..=list.len()).map(|ix| first + O::from_as_usize(ix * size));
 
         match offsets
             .zip(expected)
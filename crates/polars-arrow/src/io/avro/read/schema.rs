@@ -60,10 +60,10 @@ fn schema_to_field(
                     ArrowDataType::Time64(TimeUnit::Microsecond)
                 },
                 avro_schema::schema::LongLogical::TimestampMillis => {
-                    ArrowDataType::Timestamp(TimeUnit::Millisecond, Some("00:00".to_string()))
+                    ArrowDataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string()))
                 },
                 avro_schema::schema::LongLogical::TimestampMicros => {
-                    ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("00:00".to_string()))
+                    ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string()))
                 },
                 avro_schema::schema::LongLogical::LocalTimestampMillis => {
                     ArrowDataType::Timestamp(TimeUnit::Millisecond, None)
@@ -65,6 +65,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
 pub fn skip_fixed_size_list(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<()> {
     let _ = field_nodes.pop_front().ok_or_else(|| {
@@ -79,5 +80,10 @@ pub fn skip_fixed_size_list(
 
     let (field, _) = FixedSizeListArray::get_child_and_size(data_type);
 
-    skip(field_nodes, field.data_type(), buffers)
+    skip(
+        field_nodes,
+        field.data_type(),
+        &ipc_field.fields[0],
+        buffers,
+    )
 }
@@ -85,6 +85,7 @@ where
 pub fn skip_list<O: Offset>(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<()> {
     let _ = field_nodes.pop_front().ok_or_else(|| {
@@ -102,5 +103,5 @@ pub fn skip_list<O: Offset>(
 
     let data_type = ListArray::<O>::get_child_type(data_type);
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, data_type, &ipc_field.fields[0], buffers)
 }
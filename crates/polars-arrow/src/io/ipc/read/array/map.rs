@@ -80,6 +80,7 @@ pub fn read_map<R: Read + Seek>(
 pub fn skip_map(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<()> {
     let _ = field_nodes.pop_front().ok_or_else(|| {
@@ -97,5 +98,5 @@ pub fn skip_map(
 
     let data_type = MapArray::get_field(data_type).data_type();
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, data_type, &ipc_field.fields[0], buffers)
 }
@@ -0,0 +1,172 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+use super::super::super::IpcField;
+use super::super::deserialize::{read, skip};
+use super::super::{Compression, Dictionaries, IpcBuffer, Node, Version};
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::take::take;
+use crate::datatypes::{ArrowDataType, Field, IntegerType};
+use crate::io::ipc::read::array::{try_get_array_length, try_get_field_node};
+
+fn run_ends_to_vec(run_ends: &dyn Array) -> PolarsResult<Vec<usize>> {
+    if run_ends.null_count() > 0 {
+        polars_bail!(oos = "IPC: the run ends of RunEndEncoded must not be null");
+    }
+    fn to_usize<T: Copy + TryInto<usize>>(values: &[T]) -> PolarsResult<Vec<usize>> {
+        values
+            .iter()
+            .map(|v| {
+                (*v).try_into().map_err(|_| {
+                    polars_err!(oos = "IPC: the run ends of RunEndEncoded must be positive")
+                })
+            })
+            .collect()
+    }
+    let any = run_ends.as_any();
+    match run_ends.data_type() {
+        ArrowDataType::Int16 => {
+            to_usize(any.downcast_ref::<PrimitiveArray<i16>>().unwrap().values())
+        },
+        ArrowDataType::Int32 => {
+            to_usize(any.downcast_ref::<PrimitiveArray<i32>>().unwrap().values())
+        },
+        ArrowDataType::Int64 => {
+            to_usize(any.downcast_ref::<PrimitiveArray<i64>>().unwrap().values())
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Decode a run-end encoded array of `length` values to a plain array. The value at `i`
+/// is repeated until the run end at `i`.
+pub(crate) fn decode_run_end_encoded(
+    run_ends: &[usize],
+    values: &dyn Array,
+    length: usize,
+) -> PolarsResult<Box<dyn Array>> {
+    if run_ends.len() != values.len() {
+        polars_bail!(oos = "IPC: RunEndEncoded must have as many run ends as values");
+    }
+    let mut indices = Vec::<u64>::with_capacity(length);
+    let mut run_start = 0;
+    for (i, &run_end) in run_ends.iter().enumerate() {
+        if run_end <= run_start {
+            polars_bail!(oos = "IPC: the run ends of RunEndEncoded must be strictly increasing");
+        }
+        let n = std::cmp::min(run_end, length).saturating_sub(run_start);
+        indices.extend(std::iter::repeat(i as u64).take(n));
+        run_start = run_end;
+        if run_start >= length {
+            break;
+        }
+    }
+    if indices.len() != length {
+        polars_bail!(
+            oos = "IPC: the run ends of RunEndEncoded don't cover the length of the array"
+        );
+    }
+    take(values, &PrimitiveArray::from_vec(indices))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_run_end_encoded<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    field: &Field,
+    ipc_field: &IpcField,
+    run_ends_type: IntegerType,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    limit: Option<usize>,
+    version: Version,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<Box<dyn Array>> {
+    // the run-end encoded parent has no buffers, only the children do
+    let field_node = try_get_field_node(field_nodes, field.data_type())?;
+    let length = try_get_array_length(field_node, limit)?;
+
+    let run_ends_field = Field::new("run_ends", run_ends_type.into(), false);
+    let values_field = Field::new("values", field.data_type().clone(), true);
+
+    // the children have their own length, so they are read completely
+    let run_ends = read(
+        field_nodes,
+        variadic_buffer_counts,
+        &run_ends_field,
+        &ipc_field.fields[0],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        None,
+        version,
+        scratch,
+    )?;
+    let values = read(
+        field_nodes,
+        variadic_buffer_counts,
+        &values_field,
+        &ipc_field.fields[1],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        None,
+        version,
+        scratch,
+    )?;
+
+    let run_ends = run_ends_to_vec(run_ends.as_ref())?;
+    decode_run_end_encoded(&run_ends, values.as_ref(), length)
+}
+
+pub fn skip_run_end_encoded(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: &ArrowDataType,
+    ipc_field: &IpcField,
+    run_ends_type: IntegerType,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> PolarsResult<()> {
+    let _ = field_nodes.pop_front().ok_or_else(|| {
+        polars_err!(
+            oos = "IPC: unable to fetch the field for run-end encoded. The file or stream is corrupted."
+        )
+    })?;
+
+    skip(
+        field_nodes,
+        &run_ends_type.into(),
+        &ipc_field.fields[0],
+        buffers,
+    )?;
+    skip(field_nodes, data_type, &ipc_field.fields[1], buffers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Utf8Array;
+
+    #[test]
+    fn test_decode_run_end_encoded() -> PolarsResult<()> {
+        let values = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+        let out = decode_run_end_encoded(&[2, 3, 6], &values, 5)?;
+        let expected = Utf8Array::<i32>::from([Some("a"), Some("a"), None, Some("b"), Some("b")]);
+        assert_eq!(out.as_ref(), &expected as &dyn Array);
+
+        assert!(decode_run_end_encoded(&[2, 2, 6], &values, 5).is_err());
+        assert!(decode_run_end_encoded(&[1, 2, 3], &values, 5).is_err());
+        Ok(())
+    }
+}
@@ -70,6 +70,7 @@ pub fn read_struct<R: Read + Seek>(
 pub fn skip_struct(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<()> {
     let _ = field_nodes.pop_front().ok_or_else(|| {
@@ -86,5 +87,6 @@ pub fn skip_struct(
 
     fields
         .iter()
-        .try_for_each(|field| skip(field_nodes, field.data_type(), buffers))
+        .zip(ipc_field.fields.iter())
+        .try_for_each(|(field, ipc_field)| skip(field_nodes, field.data_type(), ipc_field, buffers))
 }
@@ -149,8 +149,8 @@ pub fn read_record_batch<R: Read + Seek>(
                     version,
                     scratch,
                 )?)),
-                ProjectionResult::NotSelected((field, _)) => {
-                    skip(&mut field_nodes, &field.data_type, &mut buffers)?;
+                ProjectionResult::NotSelected((field, ipc_field)) => {
+                    skip(&mut field_nodes, &field.data_type, ipc_field, &mut buffers)?;
                     Ok(None)
                 },
             })
@@ -27,6 +27,25 @@ pub fn read<R: Read + Seek>(
     version: MetadataVersion,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<Box<dyn Array>> {
+    if let Some(run_ends_type) = ipc_field.run_ends {
+        return read_run_end_encoded(
+            field_nodes,
+            variadic_buffer_counts,
+            field,
+            ipc_field,
+            run_ends_type,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            limit,
+            version,
+            scratch,
+        );
+    }
+
     use PhysicalType::*;
     let data_type = field.data_type.clone();
 
@@ -262,8 +281,13 @@ pub fn read<R: Read + Seek>(
 pub fn skip(
     field_nodes: &mut VecDeque<Node>,
     data_type: &ArrowDataType,
+    ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<()> {
+    if let Some(run_ends_type) = ipc_field.run_ends {
+        return skip_run_end_encoded(field_nodes, data_type, ipc_field, run_ends_type, buffers);
+    }
+
     use PhysicalType::*;
     match data_type.to_physical_type() {
         Null => skip_null(field_nodes),
@@ -272,13 +296,13 @@ pub fn skip(
         LargeBinary | Binary => skip_binary(field_nodes, buffers),
         LargeUtf8 | Utf8 => skip_utf8(field_nodes, buffers),
         FixedSizeBinary => skip_fixed_size_binary(field_nodes, buffers),
-        List => skip_list::<i32>(field_nodes, data_type, buffers),
-        LargeList => skip_list::<i64>(field_nodes, data_type, buffers),
-        FixedSizeList => skip_fixed_size_list(field_nodes, data_type, buffers),
-        Struct => skip_struct(field_nodes, data_type, buffers),
+        List => skip_list::<i32>(field_nodes, data_type, ipc_field, buffers),
+        LargeList => skip_list::<i64>(field_nodes, data_type, ipc_field, buffers),
+        FixedSizeList => skip_fixed_size_list(field_nodes, data_type, ipc_field, buffers),
+        Struct => skip_struct(field_nodes, data_type, ipc_field, buffers),
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
-        Union => skip_union(field_nodes, data_type, buffers),
-        Map => skip_map(field_nodes, data_type, buffers),
+        Union => skip_union(field_nodes, data_type, ipc_field, buffers),
+        Map => skip_map(field_nodes, data_type, ipc_field, buffers),
         BinaryView | Utf8View => todo!(),
     }
 }
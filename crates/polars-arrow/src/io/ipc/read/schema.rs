@@ -126,6 +126,7 @@ fn deserialize_union(union_: UnionRef, field: FieldRef) -> PolarsResult<(ArrowDa
     let ipc_field = IpcField {
         fields: ipc_fields,
         dictionary_id: None,
+        run_ends: None,
     };
     Ok((ArrowDataType::Union(fields, ids, mode), ipc_field))
 }
@@ -147,6 +148,7 @@ fn deserialize_map(map: MapRef, field: FieldRef) -> PolarsResult<(ArrowDataType,
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -165,6 +167,7 @@ fn deserialize_struct(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)
     let ipc_field = IpcField {
         fields: ipc_fields,
         dictionary_id: None,
+        run_ends: None,
     };
     Ok((ArrowDataType::Struct(fields), ipc_field))
 }
@@ -183,6 +186,7 @@ fn deserialize_list(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)>
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -201,6 +205,7 @@ fn deserialize_large_list(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcFi
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
         },
     ))
 }
@@ -227,6 +232,43 @@ fn deserialize_fixed_size_list(
         IpcField {
             fields: vec![ipc_field],
             dictionary_id: None,
+            run_ends: None,
+        },
+    ))
+}
+
+fn has_dictionary(ipc_field: &IpcField) -> bool {
+    ipc_field.dictionary_id.is_some() || ipc_field.fields.iter().any(has_dictionary)
+}
+
+/// Run-end encoded arrays are decoded when they are read, so the field gets the data type of
+/// the values. The [`IpcField`] keeps the run-end encoding and the children.
+fn deserialize_run_end_encoded(field: FieldRef) -> PolarsResult<(ArrowDataType, IpcField)> {
+    let children = field
+        .children()?
+        .ok_or_else(|| polars_err!(oos = "IPC: RunEndEncoded must contain children"))?;
+    if children.len() != 2 {
+        polars_bail!(oos = "IPC: RunEndEncoded must contain two children");
+    }
+    let (run_ends, run_ends_ipc_field) = deserialize_field(children.get(0).unwrap()?)?;
+    let (values, values_ipc_field) = deserialize_field(children.get(1).unwrap()?)?;
+
+    let run_ends = match run_ends.data_type() {
+        ArrowDataType::Int16 => IntegerType::Int16,
+        ArrowDataType::Int32 => IntegerType::Int32,
+        ArrowDataType::Int64 => IntegerType::Int64,
+        _ => polars_bail!(oos = "IPC: the run ends of RunEndEncoded must be Int16, Int32 or Int64"),
+    };
+    if has_dictionary(&values_ipc_field) {
+        polars_bail!(ComputeError: "IPC: run-end encoded dictionary arrays are not supported");
+    }
+
+    Ok((
+        values.data_type,
+        IpcField {
+            fields: vec![run_ends_ipc_field, values_ipc_field],
+            dictionary_id: None,
+            run_ends: Some(run_ends),
         },
     ))
 }
@@ -351,7 +393,7 @@ fn get_data_type(
         Struct(_) => deserialize_struct(field)?,
         Union(union_) => deserialize_union(union_, field)?,
         Map(map) => deserialize_map(map, field)?,
-        RunEndEncoded(_) => todo!(),
+        RunEndEncoded(_) => deserialize_run_end_encoded(field)?,
         LargeListView(_) | ListView(_) => todo!(),
     })
 }
@@ -34,6 +34,7 @@ fn default_ipc_field(data_type: &ArrowDataType, current_id: &mut i64) -> IpcFiel
         Map(inner, ..) | FixedSizeList(inner, _) | LargeList(inner) | List(inner) => IpcField {
             fields: vec![default_ipc_field(inner.data_type(), current_id)],
             dictionary_id: None,
+            run_ends: None,
         },
         // multiple children => recurse
         Union(fields, ..) | Struct(fields) => IpcField {
@@ -42,6 +43,7 @@ fn default_ipc_field(data_type: &ArrowDataType, current_id: &mut i64) -> IpcFiel
                 .map(|f| default_ipc_field(f.data_type(), current_id))
                 .collect(),
             dictionary_id: None,
+            run_ends: None,
         },
         // dictionary => current_id
         Dictionary(_, data_type, _) => {
@@ -50,12 +52,14 @@ fn default_ipc_field(data_type: &ArrowDataType, current_id: &mut i64) -> IpcFiel
             IpcField {
                 fields: vec![default_ipc_field(data_type, current_id)],
                 dictionary_id,
+                run_ends: None,
             }
         },
         // no children => do nothing
         _ => IpcField {
             fields: vec![],
             dictionary_id: None,
+            run_ends: None,
         },
     }
 }
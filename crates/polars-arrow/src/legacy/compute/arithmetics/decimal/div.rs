@@ -20,6 +20,22 @@ fn decimal_div(a: i128, b: i128, scale: i128) -> i128 {
     (a * scale / b).as_i128()
 }
 
+/// Computes `a * factor / b` in 256-bit space, so that the dividend can be scaled up
+/// without overflowing. Returns `None` if `b` is zero or if the quotient doesn't fit
+/// in an `i128`.
+#[inline]
+pub fn checked_decimal_div(a: i128, b: i128, factor: i128) -> Option<i128> {
+    if b == 0 {
+        return None;
+    }
+    let out = I256::new(a) * I256::new(factor) / I256::new(b);
+    if out > I256::new(i128::MAX) || out < I256::new(i128::MIN) {
+        None
+    } else {
+        Some(out.as_i128())
+    }
+}
+
 pub fn div(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
@@ -19,11 +19,76 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign,
 {
-    let new_wt = if adjust { T::one() } else { alpha };
     let old_wt_factor = T::one() - alpha;
+    ewm_mean_internal(
+        xs,
+        |_, _| (old_wt_factor, alpha),
+        adjust,
+        min_periods,
+        ignore_nulls,
+    )
+}
+
+/// Exponentially weighted mean of irregularly spaced observations.
+///
+/// The weight of an observation halves every `half_life` that has passed since it was
+/// observed, `times` and `half_life` must be in the same unit.
+pub fn ewm_mean_by<I, T>(
+    xs: I,
+    times: &[i64],
+    half_life: i64,
+    adjust: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> PrimitiveArray<T>
+where
+    I: IntoIterator<Item = Option<T>>,
+    I::IntoIter: TrustedLen,
+    T: Float + NativeType + AddAssign + MulAssign,
+{
+    ewm_mean_internal(
+        xs,
+        time_decay(times, half_life),
+        adjust,
+        min_periods,
+        ignore_nulls,
+    )
+}
+
+/// Returns the decay between the rows at `prev` and `i`, see [`ewm_mean_internal`].
+pub(super) fn time_decay<T: Float>(
+    times: &[i64],
+    half_life: i64,
+) -> impl FnMut(usize, usize) -> (T, T) + '_ {
+    assert!(half_life > 0);
+    let half = T::from(0.5).unwrap();
+    let half_life = T::from(half_life).unwrap();
+    move |prev, i| {
+        let elapsed = T::from(times[i] - times[prev]).unwrap();
+        let old_wt_factor = half.powf(elapsed / half_life);
+        (old_wt_factor, T::one() - old_wt_factor)
+    }
+}
+
+/// `decay(prev, i)` returns the factor by which the weight of the rows up to `prev`
+/// decays when moving to row `i`, and the weight of row `i` if `adjust` is `false`.
+fn ewm_mean_internal<I, T, F>(
+    xs: I,
+    mut decay: F,
+    adjust: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> PrimitiveArray<T>
+where
+    I: IntoIterator<Item = Option<T>>,
+    I::IntoIter: TrustedLen,
+    T: Float + NativeType + AddAssign + MulAssign,
+    F: FnMut(usize, usize) -> (T, T),
+{
     let mut old_wt = T::one();
     let mut weighted_avg = None;
     let mut non_null_cnt = 0usize;
+    let mut prev = 0usize;
 
     xs.into_iter()
         .enumerate()
@@ -32,9 +97,15 @@ where
                 non_null_cnt += 1;
             }
             match (i, weighted_avg) {
-                (0, _) | (_, None) => weighted_avg = opt_x,
+                (0, _) | (_, None) => {
+                    weighted_avg = opt_x;
+                    prev = i;
+                },
                 (_, Some(w_avg)) => {
                     if opt_x.is_some() || !ignore_nulls {
+                        let (old_wt_factor, alpha) = decay(prev, i);
+                        let new_wt = if adjust { T::one() } else { alpha };
+                        prev = i;
                         old_wt *= old_wt_factor;
                         if let Some(x) = opt_x {
                             if w_avg != x {
@@ -161,4 +232,34 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_mean_by() {
+        let xs = vec![Some(1.0f64), Some(2.0), None, Some(3.0)];
+        // equally spaced observations with a half-life of one step match `alpha = 0.5`
+        for adjust in [false, true] {
+            for ignore_nulls in [false, true] {
+                assert_allclose!(
+                    ewm_mean_by(xs.clone(), &[0, 1, 2, 3], 1, adjust, 0, ignore_nulls),
+                    ewm_mean(xs.clone(), ALPHA, adjust, 0, ignore_nulls),
+                    EPS
+                );
+            }
+        }
+
+        // the weight of the first observation decays to a quarter after two half-lives
+        let xs = vec![Some(1.0f64), None, Some(3.0)];
+        for ignore_nulls in [false, true] {
+            assert_allclose!(
+                ewm_mean_by(xs.clone(), &[0, 10, 20], 10, true, 0, ignore_nulls),
+                PrimitiveArray::from([Some(1.0), Some(1.0), Some(2.6)]),
+                EPS
+            );
+        }
+        assert_allclose!(
+            ewm_mean_by(xs, &[0, 10, 20], 10, false, 0, true),
+            PrimitiveArray::from([Some(1.0), Some(1.0), Some(2.5)]),
+            EPS
+        );
+    }
 }
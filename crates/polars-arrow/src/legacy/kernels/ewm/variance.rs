@@ -2,16 +2,18 @@ use std::ops::{AddAssign, DivAssign, MulAssign};
 
 use num_traits::Float;
 
+use super::average::time_decay;
 use crate::array::PrimitiveArray;
 use crate::legacy::trusted_len::TrustedLen;
 use crate::legacy::utils::CustomIterTools;
 use crate::types::NativeType;
 
+/// `decay` has the same meaning as in `ewm_mean_internal`.
 #[allow(clippy::too_many_arguments)]
-fn ewm_cov_internal<I, T>(
+fn ewm_cov_internal<I, T, F>(
     xs: I,
     ys: I,
-    alpha: T,
+    mut decay: F,
     adjust: bool,
     bias: bool,
     min_periods: usize,
@@ -22,9 +24,9 @@ where
     I: IntoIterator<Item = Option<T>>,
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign + DivAssign,
+    F: FnMut(usize, usize) -> (T, T),
 {
-    let old_wt_factor = T::one() - alpha;
-    let new_wt = if adjust { T::one() } else { alpha };
+    let mut prev = 0usize;
     let mut sum_wt = T::one();
     let mut sum_wt2 = T::one();
     let mut old_wt = T::one();
@@ -49,10 +51,14 @@ where
                     if is_observation {
                         opt_mean_x = opt_x;
                         opt_mean_y = opt_y;
+                        prev = i;
                     }
                 },
                 (_, Some(mean_x), Some(mean_y)) => {
                     if is_observation || !ignore_nulls {
+                        let (old_wt_factor, alpha) = decay(prev, i);
+                        let new_wt = if adjust { T::one() } else { alpha };
+                        prev = i;
                         sum_wt *= old_wt_factor;
                         sum_wt2 *= old_wt_factor * old_wt_factor;
                         old_wt *= old_wt_factor;
@@ -97,6 +103,7 @@ where
                     if is_observation {
                         opt_mean_x = opt_x;
                         opt_mean_y = opt_y;
+                        prev = i;
                     }
                 },
             }
@@ -140,10 +147,11 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign + DivAssign,
 {
+    let old_wt_factor = T::one() - alpha;
     ewm_cov_internal(
         xs,
         ys,
-        alpha,
+        |_, _| (old_wt_factor, alpha),
         adjust,
         bias,
         min_periods,
@@ -165,10 +173,11 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign + DivAssign,
 {
+    let old_wt_factor = T::one() - alpha;
     ewm_cov_internal(
         xs.clone(),
         xs,
-        alpha,
+        |_, _| (old_wt_factor, alpha),
         adjust,
         bias,
         min_periods,
@@ -190,10 +199,67 @@ where
     I::IntoIter: TrustedLen,
     T: Float + NativeType + AddAssign + MulAssign + DivAssign,
 {
+    let old_wt_factor = T::one() - alpha;
     ewm_cov_internal(
         xs.clone(),
         xs,
-        alpha,
+        |_, _| (old_wt_factor, alpha),
+        adjust,
+        bias,
+        min_periods,
+        ignore_nulls,
+        true,
+    )
+}
+
+/// Exponentially weighted variance of irregularly spaced observations, see
+/// [`ewm_mean_by`](super::ewm_mean_by).
+pub fn ewm_var_by<I, T>(
+    xs: I,
+    times: &[i64],
+    half_life: i64,
+    adjust: bool,
+    bias: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> PrimitiveArray<T>
+where
+    I: IntoIterator<Item = Option<T>> + Clone,
+    I::IntoIter: TrustedLen,
+    T: Float + NativeType + AddAssign + MulAssign + DivAssign,
+{
+    ewm_cov_internal(
+        xs.clone(),
+        xs,
+        time_decay(times, half_life),
+        adjust,
+        bias,
+        min_periods,
+        ignore_nulls,
+        false,
+    )
+}
+
+/// Exponentially weighted standard deviation of irregularly spaced observations, see
+/// [`ewm_mean_by`](super::ewm_mean_by).
+pub fn ewm_std_by<I, T>(
+    xs: I,
+    times: &[i64],
+    half_life: i64,
+    adjust: bool,
+    bias: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> PrimitiveArray<T>
+where
+    I: IntoIterator<Item = Option<T>> + Clone,
+    I::IntoIter: TrustedLen,
+    T: Float + NativeType + AddAssign + MulAssign + DivAssign,
+{
+    ewm_cov_internal(
+        xs.clone(),
+        xs,
+        time_decay(times, half_life),
         adjust,
         bias,
         min_periods,
@@ -808,4 +874,34 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_var_by() {
+        // equally spaced observations with a half-life of one step match `alpha = 0.5`
+        let times = [0, 1, 2, 3, 4, 5, 6];
+        for adjust in [false, true] {
+            for bias in [false, true] {
+                for ignore_nulls in [false, true] {
+                    assert_allclose!(
+                        ewm_var_by(YS.to_vec(), &times, 1, adjust, bias, 0, ignore_nulls),
+                        ewm_var(YS.to_vec(), ALPHA, adjust, bias, 0, ignore_nulls),
+                        EPS
+                    );
+                    assert_allclose!(
+                        ewm_std_by(XS.to_vec(), &times, 1, adjust, bias, 0, ignore_nulls),
+                        ewm_std(XS.to_vec(), ALPHA, adjust, bias, 0, ignore_nulls),
+                        EPS
+                    );
+                }
+            }
+        }
+
+        // after two half-lives the old observation weighs a quarter of the new one
+        let xs = vec![Some(1.0f64), Some(3.0)];
+        assert_allclose!(
+            ewm_var_by(xs, &[0, 20], 10, true, true, 0, true),
+            PrimitiveArray::from([Some(0.0), Some(0.64)]),
+            EPS
+        );
+    }
 }
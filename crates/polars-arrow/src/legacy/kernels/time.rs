@@ -8,10 +8,12 @@ use chrono_tz::Tz;
 use polars_error::PolarsResult;
 use polars_error::{polars_bail, PolarsError};
 
+#[derive(Clone, Copy)]
 pub enum Ambiguous {
     Earliest,
     Latest,
     Raise,
+    Null,
 }
 impl FromStr for Ambiguous {
     type Err = PolarsError;
@@ -21,26 +23,30 @@ impl FromStr for Ambiguous {
             "earliest" => Ok(Ambiguous::Earliest),
             "latest" => Ok(Ambiguous::Latest),
             "raise" => Ok(Ambiguous::Raise),
+            "null" => Ok(Ambiguous::Null),
             s => polars_bail!(InvalidOperation:
-                "Invalid argument {}, expected one of: \"earliest\", \"latest\", \"raise\"", s
+                "Invalid argument {}, expected one of: \"earliest\", \"latest\", \"raise\", \"null\"", s
             ),
         }
     }
 }
 
+/// Localize `ndt` from `from_tz` into `to_tz`. Only returns `None` for ambiguous datetimes
+/// if [`Ambiguous::Null`] is passed.
 #[cfg(feature = "timezones")]
 pub fn convert_to_naive_local(
     from_tz: &Tz,
     to_tz: &Tz,
     ndt: NaiveDateTime,
     ambiguous: Ambiguous,
-) -> PolarsResult<NaiveDateTime> {
+) -> PolarsResult<Option<NaiveDateTime>> {
     let ndt = from_tz.from_utc_datetime(&ndt).naive_local();
     match to_tz.from_local_datetime(&ndt) {
-        LocalResult::Single(dt) => Ok(dt.naive_utc()),
+        LocalResult::Single(dt) => Ok(Some(dt.naive_utc())),
         LocalResult::Ambiguous(dt_earliest, dt_latest) => match ambiguous {
-            Ambiguous::Earliest => Ok(dt_earliest.naive_utc()),
-            Ambiguous::Latest => Ok(dt_latest.naive_utc()),
+            Ambiguous::Earliest => Ok(Some(dt_earliest.naive_utc())),
+            Ambiguous::Latest => Ok(Some(dt_latest.naive_utc())),
+            Ambiguous::Null => Ok(None),
             Ambiguous::Raise => {
                 polars_bail!(ComputeError: "datetime '{}' is ambiguous in time zone '{}'. Please use `ambiguous` to tell how it should be localized.", ndt, to_tz)
             },
@@ -65,7 +71,7 @@ pub fn convert_to_naive_local_opt(
         LocalResult::Ambiguous(dt_earliest, dt_latest) => match ambiguous {
             Ambiguous::Earliest => Some(dt_earliest.naive_utc()),
             Ambiguous::Latest => Some(dt_latest.naive_utc()),
-            Ambiguous::Raise => None,
+            Ambiguous::Raise | Ambiguous::Null => None,
         },
         LocalResult::None => None,
     }
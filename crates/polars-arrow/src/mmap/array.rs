@@ -408,6 +408,10 @@ fn get_array<T: AsRef<[u8]>>(
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> PolarsResult<ArrowArray> {
     use crate::datatypes::PhysicalType::*;
+    if ipc_field.run_ends.is_some() {
+        // run-end encoded arrays are decoded when read, they cannot be memory mapped
+        polars_bail!(ComputeError: "memory mapping run-end encoded arrays is not supported")
+    }
     let node = field_nodes.pop_front().ok_or_else(
         || polars_err!(ComputeError: "out-of-spec: {:?}", OutOfSpecKind::ExpectedBuffer),
     )?;
@@ -1,8 +1,10 @@
-use arrow::legacy::compute::arithmetics::decimal;
+use arrow::legacy::compute::arithmetics::decimal::checked_decimal_div;
 
 use super::*;
 use crate::prelude::DecimalChunked;
-use crate::utils::align_chunks_binary;
+
+/// The maximum number of significant digits an `i128` backed decimal can hold.
+const MAX_PRECISION: usize = 38;
 
 // TODO: remove
 impl ArrayArithmetics for i128 {
@@ -35,65 +37,118 @@ impl ArrayArithmetics for i128 {
     }
 }
 
+#[inline]
+fn pow10(exp: usize) -> PolarsResult<i128> {
+    10i128
+        .checked_pow(exp as u32)
+        .ok_or_else(|| {
+            polars_err!(ComputeError: "decimal scale {} exceeds the maximum of {}", exp, MAX_PRECISION)
+        })
+}
+
+fn decimal_params(dtype: &DataType) -> PolarsResult<(Option<usize>, usize)> {
+    match dtype {
+        DataType::Decimal(precision, Some(scale)) => Ok((*precision, *scale)),
+        dt => polars_bail!(ComputeError: "expected a Decimal with known scale, got {}", dt),
+    }
+}
+
+fn combine_precision(
+    lhs: Option<usize>,
+    rhs: Option<usize>,
+    f: impl Fn(usize, usize) -> usize,
+) -> Option<usize> {
+    lhs.zip(rhs).map(|(l, r)| f(l, r).min(MAX_PRECISION))
+}
+
+/// Output type of adding or subtracting two decimals.
+///
+/// The operands are brought to the largest of both scales and one digit is
+/// reserved for the carry.
+pub fn _get_decimal_add_sub_dtype(lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType> {
+    let (p1, s1) = decimal_params(lhs)?;
+    let (p2, s2) = decimal_params(rhs)?;
+    let scale = s1.max(s2);
+    let precision = combine_precision(p1, p2, |p1, p2| {
+        p1.saturating_sub(s1).max(p2.saturating_sub(s2)) + scale + 1
+    });
+    Ok(DataType::Decimal(precision, Some(scale)))
+}
+
+/// Output type of multiplying two decimals. The scales and precisions add up.
+pub fn _get_decimal_mul_dtype(lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType> {
+    let (p1, s1) = decimal_params(lhs)?;
+    let (p2, s2) = decimal_params(rhs)?;
+    let scale = s1 + s2;
+    polars_ensure!(
+        scale <= MAX_PRECISION,
+        ComputeError: "decimal multiplication would result in scale {} which exceeds the maximum of {}; cast to a lower scale first",
+        scale, MAX_PRECISION
+    );
+    let precision = combine_precision(p1, p2, |p1, p2| p1 + p2);
+    Ok(DataType::Decimal(precision, Some(scale)))
+}
+
+/// Output type of dividing two decimals. The result keeps the largest of both
+/// scales and reserves room for the integer digits of the quotient.
+pub fn _get_decimal_div_dtype(lhs: &DataType, rhs: &DataType) -> PolarsResult<DataType> {
+    let (p1, s1) = decimal_params(lhs)?;
+    let (p2, s2) = decimal_params(rhs)?;
+    let scale = s1.max(s2);
+    let precision = combine_precision(p1, p2, |p1, _| p1.saturating_sub(s1) + s2 + scale);
+    Ok(DataType::Decimal(precision, Some(scale)))
+}
+
 impl DecimalChunked {
-    fn arithmetic_helper<Kernel, ScalarKernelLhs, ScalarKernelRhs>(
+    /// Applies `op` on the non-null physical values of both arrays, broadcasting unit
+    /// length arrays. `op` returns `None` if the computation overflows and `Some(None)`
+    /// if the result is undefined, which results in a null.
+    fn arithmetic_helper<F>(
         &self,
         rhs: &DecimalChunked,
-        kernel: Kernel,
-        operation_lhs: ScalarKernelLhs,
-        operation_rhs: ScalarKernelRhs,
+        dtype: DataType,
+        op: F,
     ) -> PolarsResult<Self>
     where
-        Kernel:
-            Fn(&PrimitiveArray<i128>, &PrimitiveArray<i128>) -> PolarsResult<PrimitiveArray<i128>>,
-        ScalarKernelLhs: Fn(&PrimitiveArray<i128>, i128) -> PolarsResult<PrimitiveArray<i128>>,
-        ScalarKernelRhs: Fn(i128, &PrimitiveArray<i128>) -> PolarsResult<PrimitiveArray<i128>>,
+        F: Fn(i128, i128) -> Option<Option<i128>>,
     {
-        let lhs = self;
-
-        let mut ca = match (lhs.len(), rhs.len()) {
-            (a, b) if a == b => {
-                let (lhs, rhs) = align_chunks_binary(lhs, rhs);
-                let chunks = lhs
-                    .downcast_iter()
-                    .zip(rhs.downcast_iter())
-                    .map(|(lhs, rhs)| kernel(lhs, rhs).map(|a| Box::new(a) as ArrayRef))
-                    .collect::<PolarsResult<_>>()?;
-                unsafe { lhs.copy_with_chunks(chunks, false, false) }
-            },
-            // broadcast right path
-            (_, 1) => {
-                let opt_rhs = rhs.get(0);
-                match opt_rhs {
-                    None => ChunkedArray::full_null(lhs.name(), lhs.len()),
-                    Some(rhs_val) => {
-                        let chunks = lhs
-                            .downcast_iter()
-                            .map(|lhs| operation_lhs(lhs, rhs_val).map(|a| Box::new(a) as ArrayRef))
-                            .collect::<PolarsResult<_>>()?;
-                        unsafe { lhs.copy_with_chunks(chunks, false, false) }
-                    },
-                }
-            },
-            (1, _) => {
-                let opt_lhs = lhs.get(0);
-                match opt_lhs {
-                    None => ChunkedArray::full_null(lhs.name(), rhs.len()),
-                    Some(lhs_val) => {
-                        let chunks = rhs
-                            .downcast_iter()
-                            .map(|rhs| operation_rhs(lhs_val, rhs).map(|a| Box::new(a) as ArrayRef))
-                            .collect::<PolarsResult<_>>()?;
-                        unsafe { lhs.copy_with_chunks(chunks, false, false) }
-                    },
+        polars_ensure!(
+            self.len() == rhs.len() || self.len() == 1 || rhs.len() == 1,
+            ComputeError: "cannot apply operation on arrays of different lengths"
+        );
+        let (precision, scale) = decimal_params(&dtype)?;
+        let precision_max = precision.unwrap_or(MAX_PRECISION);
+        // 10^38 still fits in an i128.
+        let max = pow10(precision_max)?;
+
+        let mut ca: Int128Chunked =
+            arity::broadcast_try_binary_elementwise(&self.0, &rhs.0, |a, b| {
+                let (Some(a), Some(b)) = (a, b) else {
+                    return Ok(None);
+                };
+                match op(a, b) {
+                    Some(Some(v)) if v.checked_abs().map_or(false, |v| v < max) => Ok(Some(v)),
+                    Some(None) => Ok(None),
+                    _ => Err(polars_err!(
+                        ComputeError: "Decimal overflowed the allowed precision: {}", precision_max
+                    )),
                 }
-            },
-            _ => {
-                polars_bail!(ComputeError: "Cannot apply operation on arrays of different lengths")
-            },
-        };
-        ca.rename(lhs.name());
-        Ok(ca.into_decimal_unchecked(self.precision(), self.scale()))
+            })?;
+        ca.rename(self.name());
+        Ok(ca.into_decimal_unchecked(precision, scale))
+    }
+
+    fn add_sub_helper<F>(&self, rhs: &DecimalChunked, op: F) -> PolarsResult<Self>
+    where
+        F: Fn(i128, i128) -> Option<i128>,
+    {
+        let dtype = _get_decimal_add_sub_dtype(self.dtype(), rhs.dtype())?;
+        let (_, scale) = decimal_params(&dtype)?;
+        let lhs_factor = pow10(scale - self.scale())?;
+        let rhs_factor = pow10(scale - rhs.scale())?;
+        self.arithmetic_helper(rhs, dtype, |a, b| {
+            op(a.checked_mul(lhs_factor)?, b.checked_mul(rhs_factor)?).map(Some)
+        })
     }
 }
 
@@ -101,12 +156,7 @@ impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.arithmetic_helper(
-            rhs,
-            decimal::add,
-            |lhs, rhs_val| decimal::add_scalar(lhs, rhs_val, &rhs.dtype().to_arrow(true)),
-            |lhs_val, rhs| decimal::add_scalar(rhs, lhs_val, &self.dtype().to_arrow(true)),
-        )
+        self.add_sub_helper(rhs, i128::checked_add)
     }
 }
 
@@ -114,12 +164,7 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.arithmetic_helper(
-            rhs,
-            decimal::sub,
-            decimal::sub_scalar,
-            decimal::sub_scalar_swapped,
-        )
+        self.add_sub_helper(rhs, i128::checked_sub)
     }
 }
 
@@ -127,24 +172,26 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self.arithmetic_helper(
-            rhs,
-            decimal::mul,
-            |lhs, rhs_val| decimal::mul_scalar(lhs, rhs_val, &rhs.dtype().to_arrow(true)),
-            |lhs_val, rhs| decimal::mul_scalar(rhs, lhs_val, &self.dtype().to_arrow(true)),
-        )
+        let dtype = _get_decimal_mul_dtype(self.dtype(), rhs.dtype())?;
+        self.arithmetic_helper(rhs, dtype, |a, b| a.checked_mul(b).map(Some))
     }
 }
 
 impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
+    /// Division by zero results in a null.
     fn div(self, rhs: Self) -> Self::Output {
-        self.arithmetic_helper(
-            rhs,
-            decimal::div,
-            |lhs, rhs_val| decimal::div_scalar(lhs, rhs_val, &rhs.dtype().to_arrow(true)),
-            |lhs_val, rhs| decimal::div_scalar_swapped(lhs_val, &self.dtype().to_arrow(true), rhs),
-        )
+        let dtype = _get_decimal_div_dtype(self.dtype(), rhs.dtype())?;
+        let (_, scale) = decimal_params(&dtype)?;
+        // (a / 10^s1) / (b / 10^s2) * 10^scale == a * 10^(scale + s2 - s1) / b
+        let factor = pow10(scale + rhs.scale() - self.scale())?;
+        self.arithmetic_helper(rhs, dtype, |a, b| {
+            if b == 0 {
+                Some(None)
+            } else {
+                checked_decimal_div(a, b, factor).map(Some)
+            }
+        })
     }
 }
@@ -10,6 +10,8 @@ use arrow::compute::arithmetics::basic;
 use arrow::compute::arity_assign;
 use arrow::compute::utils::combine_validities_and;
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-decimal")]
+pub use decimal::{_get_decimal_add_sub_dtype, _get_decimal_div_dtype, _get_decimal_mul_dtype};
 use num_traits::{Num, NumCast, ToPrimitive, Zero};
 pub(super) use numeric::arithmetic_helper;
 
@@ -393,19 +393,12 @@ impl ChunkCast for ListChunked {
             },
             #[cfg(feature = "dtype-array")]
             Array(child_type, width) => {
-                let physical_type = data_type.to_physical();
-                // cast to the physical type to avoid logical chunks.
-                let chunks = cast_chunks(self.chunks(), &physical_type, true)?;
-                // Safety: we just casted so the dtype matches.
-                // we must take this path to correct for physical types.
-                unsafe {
-                    Ok(Series::from_chunks_and_dtype_unchecked(
-                        self.name(),
-                        chunks,
-                        &Array(child_type.clone(), *width),
-                    ))
-                }
+                // first change the layout, then cast the inner values so that
+                // logical and nested inner types are cast by us and not by arrow.
+                list_to_fixed_size_list(self, *width)?.cast(&Array(child_type.clone(), *width))
             },
+            #[cfg(feature = "dtype-struct")]
+            Struct(fields) => cast_list_to_struct(self, fields),
             _ => {
                 polars_bail!(
                     ComputeError: "cannot cast List type (inner: '{:?}', to: '{:?}')",
@@ -453,21 +446,12 @@ impl ChunkCast for ArrayChunked {
                     },
                 }
             },
-            List(child_type) => {
-                let physical_type = data_type.to_physical();
-                // cast to the physical type to avoid logical chunks.
-                let chunks = cast_chunks(self.chunks(), &physical_type, true)?;
-                // Safety: we just casted so the dtype matches.
-                // we must take this path to correct for physical types.
-                unsafe {
-                    Ok(Series::from_chunks_and_dtype_unchecked(
-                        self.name(),
-                        chunks,
-                        &List(child_type.clone()),
-                    ))
-                }
-            },
-            _ => polars_bail!(ComputeError: "cannot cast list type"),
+            List(child_type) => fixed_size_list_to_list(self)?.cast(&List(child_type.clone())),
+            _ => polars_bail!(
+                ComputeError: "cannot cast Array type (inner: '{:?}', to: '{:?}')",
+                self.inner_dtype(),
+                data_type,
+            ),
         }
     }
 
@@ -556,6 +540,96 @@ fn cast_fixed_size_list(
     Ok((Box::new(new_arr), inner_dtype))
 }
 
+// Change the layout of a list array to a fixed-size list array, the inner values are not cast.
+// Null lists don't need to have the width, they result in a null array element.
+#[cfg(feature = "dtype-array")]
+fn list_to_fixed_size_list(ca: &ListChunked, width: usize) -> PolarsResult<ArrayChunked> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let data_type = FixedSizeListArray::default_datatype(arr.values().data_type().clone(), width);
+    let new_arr = arrow::compute::cast::cast(arr, &data_type, CastOptions::default())?;
+    // Safety: the inner values are not changed.
+    unsafe {
+        Ok(ArrayChunked::from_chunks_and_dtype_unchecked(
+            ca.name(),
+            vec![new_arr],
+            DataType::Array(Box::new(ca.inner_dtype()), width),
+        ))
+    }
+}
+
+// Change the layout of a fixed-size list array to a list array, the inner values are not cast.
+#[cfg(feature = "dtype-array")]
+fn fixed_size_list_to_list(ca: &ArrayChunked) -> PolarsResult<ListChunked> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let data_type = ListArray::<i64>::default_datatype(arr.values().data_type().clone());
+            arrow::compute::cast::cast(arr, &data_type, CastOptions::default())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // Safety: the inner values are not changed.
+    unsafe {
+        Ok(ListChunked::from_chunks_and_dtype_unchecked(
+            ca.name(),
+            chunks,
+            DataType::List(Box::new(ca.inner_dtype())),
+        ))
+    }
+}
+
+// Cast a `List<Struct>` to a `Struct` of lists. Every list field gets the offsets and
+// validity of the original list, so a null list results in a null in every field.
+#[cfg(feature = "dtype-struct")]
+fn cast_list_to_struct(ca: &ListChunked, fields: &[Field]) -> PolarsResult<Series> {
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        matches!(inner_dtype, DataType::Struct(_)),
+        ComputeError: "cannot cast List type (inner: '{:?}', to: '{:?}')",
+        inner_dtype, DataType::Struct(fields.to_vec()),
+    );
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    // safety: inner dtype is passed correctly
+    let values = unsafe {
+        Series::from_chunks_and_dtype_unchecked("", vec![arr.values().clone()], &inner_dtype)
+    };
+    let values = values.struct_()?;
+
+    let new_fields = fields
+        .iter()
+        .map(|field| {
+            let DataType::List(child_type) = field.data_type() else {
+                polars_bail!(
+                    ComputeError: "cannot cast List of Struct to Struct with non-list field '{}'",
+                    field.name()
+                )
+            };
+            let field_values = match values.fields().iter().find(|s| s.name() == field.name()) {
+                Some(s) => s.cast(child_type)?.rechunk(),
+                None => Series::full_null("", values.len(), child_type),
+            };
+            let new_values = field_values.array_ref(0).clone();
+            let data_type = ListArray::<i64>::default_datatype(new_values.data_type().clone());
+            let new_arr = ListArray::<i64>::new(
+                data_type,
+                arr.offsets().clone(),
+                new_values,
+                arr.validity().cloned(),
+            );
+            // Safety: we just casted so the dtype matches.
+            unsafe {
+                Ok(Series::from_chunks_and_dtype_unchecked(
+                    field.name(),
+                    vec![Box::new(new_arr)],
+                    &DataType::List(Box::new(field_values.dtype().clone())),
+                ))
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::new(ca.name(), &new_fields).map(|ca| ca.into_series())
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -574,6 +648,101 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_cast_list_inner_to_string() -> PolarsResult<()> {
+        let s: Series = [Some(vec![1i64, 2]), None, Some(vec![])]
+            .into_iter()
+            .collect();
+        let out = s.cast(&DataType::List(Box::new(DataType::String)))?;
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::String)));
+        assert!(out.get(1)?.is_null());
+        let first = out.list()?.get_as_series(0).unwrap();
+        assert_eq!(first.str()?.get(1), Some("2"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_cast_array_list_roundtrip() -> PolarsResult<()> {
+        let s: Series = [Some(vec![1i32, 2]), None, Some(vec![3, 4])]
+            .into_iter()
+            .collect();
+        // offsets of the slice don't start at zero
+        let s = s.slice(1, 2);
+
+        let arr_dtype = DataType::Array(Box::new(DataType::Float64), 2);
+        let out = s.cast(&arr_dtype)?;
+        assert_eq!(out.dtype(), &arr_dtype);
+        assert_eq!(out.null_count(), 1);
+
+        let list_dtype = DataType::List(Box::new(DataType::String));
+        let out = out.cast(&list_dtype)?;
+        assert_eq!(out.dtype(), &list_dtype);
+        assert_eq!(out.null_count(), 1);
+        let last = out.list()?.get_as_series(1).unwrap();
+        assert_eq!(last.str()?.get(0), Some("3.0"));
+
+        // lists that don't have the width cannot be cast
+        assert!([vec![1i32], vec![2, 3]]
+            .into_iter()
+            .collect::<Series>()
+            .cast(&DataType::Array(Box::new(DataType::Int32), 2))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_cast_struct_fields() -> PolarsResult<()> {
+        let a = Series::new("a", [1i32, 2]);
+        let b = Series::new("b", ["x", "y"]);
+        let s = StructChunked::new("s", &[a, b])?.into_series();
+
+        // subset and reorder by name
+        let dtype = DataType::Struct(vec![
+            Field::new("b", DataType::String),
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Boolean),
+        ]);
+        let out = s.cast(&dtype)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(out.struct_()?.fields()[2].null_count(), 2);
+
+        // cast by position if no names match
+        let dtype = DataType::Struct(vec![
+            Field::new("x", DataType::Float64),
+            Field::new("y", DataType::String),
+        ]);
+        let out = s.cast(&dtype)?;
+        assert_eq!(out.dtype(), &dtype);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_cast_list_of_struct_to_struct_of_lists() -> PolarsResult<()> {
+        let a = Series::new("a", [1i32, 2, 3]);
+        let b = Series::new("b", ["x", "y", "z"]);
+        let values = StructChunked::new("", &[a, b])?.into_series();
+        let mut builder = get_list_builder(values.dtype(), 3, 3, "l")?;
+        builder.append_series(&values.slice(0, 2))?;
+        builder.append_null();
+        builder.append_series(&values.slice(2, 1))?;
+        let s = builder.finish().into_series();
+
+        let dtype = DataType::Struct(vec![
+            Field::new("b", DataType::List(Box::new(DataType::String))),
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+        ]);
+        let out = s.cast(&dtype)?;
+        assert_eq!(out.dtype(), &dtype);
+        let fields = out.struct_()?.fields();
+        assert_eq!(fields[1].list()?.get_as_series(0).unwrap().len(), 2);
+        // the null list is null in every field
+        assert!(fields.iter().all(|s| s.get(1).unwrap().is_null()));
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_cast_noop() {
@@ -44,7 +44,7 @@ where
     let rev_map_r = rhs.get_rev_map();
     polars_ensure!(rev_map_l.same_src(rev_map_r), ComputeError: "can only compare categoricals of the same type with the same categories");
 
-    if rev_map_l.is_enum() || !lhs.uses_lexical_ordering() {
+    if !lhs.uses_lexical_ordering() {
         Ok(compare_function(lhs.physical(), rhs.physical()))
     } else {
         match (lhs.len(), rhs.len()) {
@@ -169,7 +169,8 @@ where
 {
     let rev_map = lhs.get_rev_map();
     if rev_map.is_enum() {
-        let rhs_cat = rhs.cast(lhs.dtype())?;
+        // Strings that are not in the enum can't be compared, so this must raise.
+        let rhs_cat = rhs.clone().into_series().strict_cast(lhs.dtype())?;
         cat_compare_function(lhs, rhs_cat.categorical().unwrap())
     } else if rhs.len() == 1 {
         match rhs.get(0) {
@@ -200,7 +201,8 @@ where
 {
     let rev_map = lhs.get_rev_map();
     if rev_map.is_enum() {
-        let rhs_cat = rhs.cast(lhs.dtype())?;
+        // Strings that are not in the enum can't be compared, so this must raise.
+        let rhs_cat = rhs.clone().into_series().strict_cast(lhs.dtype())?;
         cat_compare_function(lhs, rhs_cat.categorical().unwrap())
     } else if rhs.len() == 1 {
         match rhs.get(0) {
@@ -353,28 +355,29 @@ where
 {
     let rev_map = lhs.get_rev_map();
     if rev_map.is_enum() {
-        match rev_map.find(rhs) {
-            None => {
-                polars_bail!(
-                    not_in_enum,
-                    value = rhs,
-                    categories = rev_map.get_categories()
-                )
-            },
-            Some(idx) => Ok(phys_compare_function(lhs.physical(), idx)),
+        let Some(idx) = rev_map.find(rhs) else {
+            polars_bail!(
+                not_in_enum,
+                value = rhs,
+                categories = rev_map.get_categories()
+            )
+        };
+        // The physical order of an enum is the order of its categories.
+        if !lhs.uses_lexical_ordering() {
+            return Ok(phys_compare_function(lhs.physical(), idx));
         }
-    } else {
-        // Apply comparison on categories map and then do a lookup
-        let bitmap = str_single_compare_function(lhs.get_rev_map().get_categories(), rhs);
-
-        Ok(
-            BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(|opt_idx| {
-                // Safety: indexing into bitmap with same length as original array
-                opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
-            }))
-            .with_name(lhs.name()),
-        )
     }
+
+    // Apply comparison on categories map and then do a lookup
+    let bitmap = str_single_compare_function(rev_map.get_categories(), rhs);
+
+    Ok(
+        BooleanChunked::from_iter_trusted_length(lhs.physical().into_iter().map(|opt_idx| {
+            // Safety: indexing into bitmap with same length as original array
+            opt_idx.map(|idx| unsafe { bitmap.get_bit_unchecked(idx as usize) })
+        }))
+        .with_name(lhs.name()),
+    )
 }
 
 impl ChunkCompare<&str> for CategoricalChunked {
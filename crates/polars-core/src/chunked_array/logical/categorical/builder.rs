@@ -336,7 +336,7 @@ impl CategoricalChunked {
     }
 
     /// Create a [`CategoricalChunked`] from a fixed list of categories and a List of strings.
-    /// This will error if a string is not in the fixed list of categories
+    /// Strings that are not in the fixed list of categories are set to null.
     pub fn from_string_to_enum(
         values: &StringChunked,
         categories: &Utf8Array<i64>,
@@ -353,16 +353,8 @@ impl CategoricalChunked {
         // Find idx of every value in the map
         let mut keys: UInt32Chunked = values
             .into_iter()
-            .map(|opt_s: Option<&str>| {
-                opt_s
-                    .map(|s| {
-                        map.get(s).copied().ok_or_else(|| {
-                            polars_err!(not_in_enum, value = s, categories = categories)
-                        })
-                    })
-                    .transpose()
-            })
-            .collect::<Result<UInt32Chunked, PolarsError>>()?;
+            .map(|opt_s: Option<&str>| opt_s.and_then(|s| map.get(s).copied()))
+            .collect();
         keys.rename(values.name());
         let rev_map = RevMapping::build_enum(categories.clone());
         unsafe {
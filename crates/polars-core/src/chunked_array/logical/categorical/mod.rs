@@ -84,12 +84,14 @@ impl CategoricalChunked {
         };
 
         let local_rev_map = RevMapping::build_local(categories.clone());
-        // TODO: A fast path can possibly be implemented here:
-        // if all physical map keys are equal to their values,
-        // we can skip the apply and only update the rev_map
-        let local_ca = self
-            .physical()
-            .apply(|opt_v| opt_v.map(|v| *physical_map.get(&v).unwrap()));
+        // If all global ids are equal to their local ids we can skip the remapping
+        // and only have to update the rev_map.
+        let local_ca = if physical_map.iter().all(|(global, local)| global == local) {
+            self.physical().clone()
+        } else {
+            self.physical()
+                .apply(|opt_v| opt_v.map(|v| *physical_map.get(&v).unwrap()))
+        };
 
         let mut out = unsafe {
             Self::from_cats_and_rev_map_unchecked(
@@ -124,7 +126,7 @@ impl CategoricalChunked {
         }
     }
 
-    // Convert to fixed enum. In case a value is not in the categories return Error
+    // Convert to fixed enum. Values that are not in the categories are set to null.
     pub fn to_enum(&self, categories: &Utf8Array<i64>, hash: u128) -> PolarsResult<Self> {
         // Fast paths
         match self.get_rev_map().as_ref() {
@@ -153,22 +155,8 @@ impl CategoricalChunked {
         let new_phys: UInt32Chunked = self
             .physical()
             .into_iter()
-            .map(|opt_v: Option<u32>| {
-                let Some(v) = opt_v else {
-                    return Ok(None);
-                };
-
-                let Some(idx) = idx_map.get(&v) else {
-                    polars_bail!(
-                        not_in_enum,
-                        value = old_rev_map.get(v),
-                        categories = &categories
-                    );
-                };
-
-                Ok(Some(*idx))
-            })
-            .collect::<PolarsResult<_>>()?;
+            .map(|opt_v: Option<u32>| opt_v.and_then(|v| idx_map.get(&v).copied()))
+            .collect();
 
         Ok(
             // Safety: we created the physical from the enum categories
@@ -274,6 +262,25 @@ impl CategoricalChunked {
         }
     }
 
+    /// Get statistics of the mapping of categorical types to string values, e.g. to find
+    /// out which columns keep categories of the global string cache alive.
+    pub fn rev_map_stats(&self) -> RevMapStats {
+        let rev_map = self.get_rev_map();
+        let in_string_cache = match &**rev_map {
+            RevMapping::Global(_, _, id) => {
+                using_string_cache() && crate::STRING_CACHE.read_map().uuid == *id
+            },
+            _ => false,
+        };
+        RevMapStats {
+            is_global: rev_map.is_global(),
+            in_string_cache,
+            n_categories: rev_map.len(),
+            category_bytes: rev_map.get_categories().offsets().range() as usize,
+            n_shared: Arc::strong_count(rev_map),
+        }
+    }
+
     /// Create an `[Iterator]` that iterates over the `&str` values of the `[CategoricalChunked]`.
     pub fn iter_str(&self) -> CatIter<'_> {
         let iter = self.physical().into_iter();
@@ -489,4 +496,47 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_string_cache_stats() -> PolarsResult<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::{
+            set_string_cache_hook, set_string_cache_warning_threshold, string_cache_stats,
+        };
+
+        static N_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        enable_string_cache();
+        set_string_cache_warning_threshold(Some(2));
+        set_string_cache_hook(Some(Arc::new(|stats| {
+            assert!(stats.n_categories > 2);
+            N_CALLS.fetch_add(1, Ordering::Relaxed);
+        })));
+
+        let s = Series::new("a", ["a", "b", "c", "d", "e", "a"])
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let stats = string_cache_stats();
+        assert!(stats.enabled);
+        assert!(stats.n_categories >= 5);
+        assert!(stats.string_bytes >= 5);
+        assert!(stats.allocated_bytes > stats.string_bytes);
+        assert!(N_CALLS.load(Ordering::Relaxed) >= 1);
+
+        // the slice shares the mapping
+        let _slice = s.slice(0, 2);
+        let rev_map_stats = s.categorical()?.rev_map_stats();
+        assert!(rev_map_stats.is_global);
+        assert!(rev_map_stats.in_string_cache);
+        assert_eq!(rev_map_stats.n_categories, 5);
+        assert_eq!(rev_map_stats.category_bytes, 5);
+        assert!(rev_map_stats.n_shared >= 2);
+
+        set_string_cache_hook(None);
+        set_string_cache_warning_threshold(None);
+        disable_string_cache();
+        Ok(())
+    }
 }
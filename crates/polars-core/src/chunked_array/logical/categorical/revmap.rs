@@ -46,6 +46,21 @@ impl Debug for RevMapping {
     }
 }
 
+/// Statistics of the [`RevMapping`] of a single categorical column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevMapStats {
+    /// Whether the categories are taken from a global string cache.
+    pub is_global: bool,
+    /// Whether the categories are taken from the string cache that is currently active.
+    pub in_string_cache: bool,
+    /// Number of categories in the mapping.
+    pub n_categories: usize,
+    /// Number of bytes of the category strings.
+    pub category_bytes: usize,
+    /// Number of arrays that share this mapping.
+    pub n_shared: usize,
+}
+
 impl Default for RevMapping {
     fn default() -> Self {
         let slice: &[Option<&str>] = &[];
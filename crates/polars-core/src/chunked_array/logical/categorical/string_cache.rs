@@ -1,10 +1,11 @@
 use std::hash::{Hash, Hasher};
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use ahash::RandomState;
 use hashbrown::hash_map::RawEntryMut;
 use once_cell::sync::Lazy;
+use polars_error::polars_warn;
 use smartstring::{LazyCompact, SmartString};
 
 use crate::datatypes::{InitHashMaps2, PlIdHashMap};
@@ -15,6 +16,18 @@ use crate::hashing::_HASHMAP_INIT_SIZE;
 static STRING_CACHE_REFCOUNT: Mutex<u32> = Mutex::new(0);
 static STRING_CACHE_ENABLED_GLOBALLY: AtomicBool = AtomicBool::new(false);
 static STRING_CACHE_UUID_CTR: AtomicU32 = AtomicU32::new(0);
+/// The number of categories the string cache allocates for when it is created.
+static STRING_CACHE_CAPACITY: AtomicUsize = AtomicUsize::new(_HASHMAP_INIT_SIZE);
+/// Number of categories after which we warn that the string cache grows large, 0 if disabled.
+static STRING_CACHE_WARN_THRESHOLD: AtomicUsize = AtomicUsize::new(0);
+static STRING_CACHE_HOOK: RwLock<Option<StringCacheHook>> = RwLock::new(None);
+
+/// Strings up to this length are stored inline and don't allocate.
+const MAX_INLINE_STR_LEN: usize = std::mem::size_of::<String>() - 1;
+
+/// Function that is called when the global string cache grows beyond the warning threshold.
+/// See [`set_string_cache_hook`].
+pub type StringCacheHook = Arc<dyn Fn(&StringCacheStats) + Send + Sync>;
 
 /// Enable the global string cache as long as the object is alive ([RAII]).
 ///
@@ -109,6 +122,62 @@ pub fn using_string_cache() -> bool {
     *refcount > 0
 }
 
+/// Set the number of categories the global string cache allocates for.
+///
+/// If the cache is currently smaller, it reserves up to `capacity` categories immediately,
+/// otherwise this applies when the cache is (re)created. Setting a capacity up front avoids
+/// rehashing the cache in processes that are known to create many categories.
+pub fn set_string_cache_capacity(capacity: usize) {
+    STRING_CACHE_CAPACITY.store(capacity, Ordering::Release);
+    let mut cache = STRING_CACHE.lock_map();
+    cache.reserve(capacity.saturating_sub(cache.len()));
+}
+
+/// Get the number of categories the global string cache allocates for.
+pub fn get_string_cache_capacity() -> usize {
+    STRING_CACHE_CAPACITY.load(Ordering::Acquire)
+}
+
+/// Warn when the global string cache holds more than `n_categories` categories.
+///
+/// Once the threshold is crossed, the next warning is raised when the cache has doubled in
+/// size, so a long-running process gets a warning for every doubling instead of for every
+/// insertion. Pass `None` to disable the warnings.
+pub fn set_string_cache_warning_threshold(n_categories: Option<usize>) {
+    let threshold = n_categories.unwrap_or(0);
+    STRING_CACHE_WARN_THRESHOLD.store(threshold, Ordering::Release);
+    STRING_CACHE.lock_map().next_warning = threshold;
+}
+
+/// Register a function that is called, next to the warning, when the global string cache grows
+/// beyond the threshold set with [`set_string_cache_warning_threshold`]. Pass `None` to remove
+/// the hook.
+///
+/// The hook is called without holding the cache, so it may use the string cache itself.
+pub fn set_string_cache_hook(hook: Option<StringCacheHook>) {
+    *STRING_CACHE_HOOK.write().unwrap() = hook;
+}
+
+/// Statistics of the global string cache.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringCacheStats {
+    /// Whether the global string cache is enabled.
+    pub enabled: bool,
+    /// Number of categories in the cache.
+    pub n_categories: usize,
+    /// Number of bytes of the category strings.
+    pub string_bytes: usize,
+    /// Estimated number of bytes the cache has allocated, including the hash table.
+    pub allocated_bytes: usize,
+}
+
+/// Get the statistics of the global string cache.
+pub fn string_cache_stats() -> StringCacheStats {
+    let enabled = using_string_cache();
+    let cache = STRING_CACHE.read_map();
+    cache.stats(enabled)
+}
+
 // This is the hash and the Index offset in the linear buffer
 #[derive(Copy, Clone)]
 struct Key {
@@ -134,6 +203,11 @@ pub(crate) struct SCacheInner {
     map: PlIdHashMap<Key, ()>,
     pub(crate) uuid: u32,
     payloads: Vec<StrHashGlobal>,
+    string_bytes: usize,
+    // bytes of the strings that are not stored inline
+    heap_bytes: usize,
+    // number of categories at which we warn, 0 if disabled
+    next_warning: usize,
 }
 
 impl SCacheInner {
@@ -146,6 +220,34 @@ impl SCacheInner {
         self.map.len()
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+        self.payloads.reserve(additional);
+    }
+
+    fn stats(&self, enabled: bool) -> StringCacheStats {
+        // every bucket of the hash table has a control byte
+        let map_bytes = self.map.capacity() * (std::mem::size_of::<Key>() + 1);
+        let payload_bytes = self.payloads.capacity() * std::mem::size_of::<StrHashGlobal>();
+        StringCacheStats {
+            enabled,
+            n_categories: self.len(),
+            string_bytes: self.string_bytes,
+            allocated_bytes: map_bytes + payload_bytes + self.heap_bytes,
+        }
+    }
+
+    /// Returns the statistics if the cache grew beyond the warning threshold.
+    fn check_warning_threshold(&mut self) -> Option<StringCacheStats> {
+        if self.next_warning == 0 || self.len() <= self.next_warning {
+            return None;
+        }
+        while self.next_warning < self.len() {
+            self.next_warning = self.next_warning.saturating_mul(2);
+        }
+        Some(self.stats(true))
+    }
+
     #[inline]
     pub(crate) fn insert_from_hash(&mut self, h: u64, s: &str) -> u32 {
         let mut global_idx = self.payloads.len() as u32;
@@ -170,6 +272,10 @@ impl SCacheInner {
 
                 // only just now we allocate the string
                 self.payloads.push(s.into());
+                self.string_bytes += s.len();
+                if s.len() > MAX_INLINE_STR_LEN {
+                    self.heap_bytes += s.len();
+                }
             },
         }
         global_idx
@@ -200,10 +306,14 @@ impl SCacheInner {
 
 impl Default for SCacheInner {
     fn default() -> Self {
+        let capacity = get_string_cache_capacity();
         Self {
-            map: PlIdHashMap::with_capacity(_HASHMAP_INIT_SIZE),
+            map: PlIdHashMap::with_capacity(capacity),
             uuid: STRING_CACHE_UUID_CTR.fetch_add(1, Ordering::AcqRel),
-            payloads: Vec::with_capacity(_HASHMAP_INIT_SIZE),
+            payloads: Vec::with_capacity(capacity),
+            string_bytes: 0,
+            heap_bytes: 0,
+            next_warning: STRING_CACHE_WARN_THRESHOLD.load(Ordering::Acquire),
         }
     }
 }
@@ -241,15 +351,34 @@ impl StringCache {
     where
         F: FnOnce(&mut RwLockWriteGuard<SCacheInner>) -> T,
     {
-        let cache = &mut crate::STRING_CACHE.lock_map();
+        let mut cache = crate::STRING_CACHE.lock_map();
 
-        let result = fun(cache);
+        let result = fun(&mut cache);
 
         if cache.len() > u32::MAX as usize {
             panic!("not more than {} categories supported", u32::MAX)
         };
+        let uuid = cache.uuid;
+        let crossed_threshold = cache.check_warning_threshold();
+        drop(cache);
+
+        if let Some(stats) = crossed_threshold {
+            notify_threshold_crossed(&stats);
+        }
+        (uuid, result)
+    }
+}
 
-        (cache.uuid, result)
+fn notify_threshold_crossed(stats: &StringCacheStats) {
+    polars_warn!(
+        "the global string cache holds {} categories ({} bytes); consider disabling the \
+        string cache when it is not needed, so that it can be cleared",
+        (stats.n_categories),
+        (stats.allocated_bytes)
+    );
+    let hook = STRING_CACHE_HOOK.read().unwrap().clone();
+    if let Some(hook) = hook {
+        hook(stats)
     }
 }
 
@@ -313,25 +313,45 @@ impl StructChunked {
     unsafe fn cast_impl(&self, dtype: &DataType, unchecked: bool) -> PolarsResult<Series> {
         match dtype {
             DataType::Struct(dtype_fields) => {
+                let cast_field = |s: &Series, new_field: &Field| {
+                    if unchecked {
+                        s.cast_unchecked(&new_field.dtype)
+                    } else {
+                        s.cast(&new_field.dtype)
+                    }
+                };
                 let map = BTreeMap::from_iter(self.fields().iter().map(|s| (s.name(), s)));
                 let struct_len = self.len();
-                let new_fields = dtype_fields
+                let no_names_match = dtype_fields
                     .iter()
-                    .map(|new_field| match map.get(new_field.name().as_str()) {
-                        Some(s) => {
-                            if unchecked {
-                                s.cast_unchecked(&new_field.dtype)
-                            } else {
-                                s.cast(&new_field.dtype)
-                            }
-                        },
-                        None => Ok(Series::full_null(
-                            new_field.name(),
-                            struct_len,
-                            &new_field.dtype,
-                        )),
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
+                    .all(|fld| !map.contains_key(fld.name().as_str()));
+
+                let new_fields = if no_names_match && dtype_fields.len() == self.fields.len() {
+                    // none of the names match, cast the fields by position and rename them.
+                    self.fields
+                        .iter()
+                        .zip(dtype_fields)
+                        .map(|(s, new_field)| {
+                            let mut out = cast_field(s, new_field)?;
+                            out.rename(new_field.name());
+                            Ok(out)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?
+                } else {
+                    // select, reorder and cast the fields by name,
+                    // fields that don't exist are filled with nulls.
+                    dtype_fields
+                        .iter()
+                        .map(|new_field| match map.get(new_field.name().as_str()) {
+                            Some(s) => cast_field(s, new_field),
+                            None => Ok(Series::full_null(
+                                new_field.name(),
+                                struct_len,
+                                &new_field.dtype,
+                            )),
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?
+                };
                 StructChunked::new(self.name(), &new_fields).map(|ca| ca.into_series())
             },
             DataType::String => {
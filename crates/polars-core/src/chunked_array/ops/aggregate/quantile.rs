@@ -56,7 +56,7 @@ fn midpoint_interpol<T: Float>(lower: T, upper: T) -> T {
 }
 
 // Uses quickselect instead of sorting all data
-fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
+pub(crate) fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     vals: &mut [T],
     quantile: f64,
     interpol: QuantileInterpolOptions,
@@ -189,11 +189,6 @@ where
             self.quantile(quantile, interpol)
         }
     }
-
-    pub(crate) fn median_faster(self) -> Option<f64> {
-        self.quantile_faster(0.5, QuantileInterpolOptions::Linear)
-            .unwrap()
-    }
 }
 
 impl ChunkQuantile<f32> for Float32Chunked {
@@ -251,11 +246,6 @@ impl Float64Chunked {
             self.quantile(quantile, interpol)
         }
     }
-
-    pub(crate) fn median_faster(self) -> Option<f64> {
-        self.quantile_faster(0.5, QuantileInterpolOptions::Linear)
-            .unwrap()
-    }
 }
 
 impl Float32Chunked {
@@ -272,11 +262,6 @@ impl Float32Chunked {
             self.quantile(quantile, interpol)
         }
     }
-
-    pub(crate) fn median_faster(self) -> Option<f32> {
-        self.quantile_faster(0.5, QuantileInterpolOptions::Linear)
-            .unwrap()
-    }
 }
 
 impl ChunkQuantile<String> for StringChunked {}
@@ -428,6 +428,35 @@ pub enum FillNullStrategy {
     /// replace with the minimal value of that data type
     MinBound,
 }
+
+/// A [`FillNullStrategy`] per group of data types, used by
+/// [`DataFrame::fill_null_by_dtype`](crate::frame::DataFrame::fill_null_by_dtype).
+///
+/// Columns whose data type belongs to a group without a strategy are left as is.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FillNullStrategyByDtype {
+    /// integer and float columns
+    pub numeric: Option<FillNullStrategy>,
+    /// string columns
+    pub string: Option<FillNullStrategy>,
+    /// date, datetime, duration and time columns
+    pub temporal: Option<FillNullStrategy>,
+}
+
+impl FillNullStrategyByDtype {
+    /// Get the strategy that applies to columns of `dtype`, if any.
+    pub fn get(&self, dtype: &DataType) -> Option<FillNullStrategy> {
+        if dtype.is_numeric() {
+            self.numeric
+        } else if dtype.is_temporal() {
+            self.temporal
+        } else if matches!(dtype, DataType::String) {
+            self.string
+        } else {
+            None
+        }
+    }
+}
 /// Replace None values with a value
 pub trait ChunkFillNullValue<T> {
     /// Replace None values with a give value `T`.
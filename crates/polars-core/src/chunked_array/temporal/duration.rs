@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use crate::export::chrono::Duration as ChronoDuration;
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
@@ -60,6 +62,42 @@ impl DurationChunked {
         self.2 = Some(Duration(tu))
     }
 
+    /// Convert from [`Duration`] to String with the given format.
+    ///
+    /// The format is either `"polars"`, the representation used when printing a
+    /// duration (e.g. `1d 2h 3m`), or `"iso"` for ISO 8601 (e.g. `P1DT2H3M`).
+    pub fn to_string(&self, format: &str) -> PolarsResult<StringChunked> {
+        let tu = self.time_unit();
+        let fmt: fn(&mut String, i64, TimeUnit) = match format {
+            "polars" => |buf, v, tu| write!(buf, "{}", AnyValue::Duration(v, tu)).unwrap(),
+            "iso" => fmt_duration_iso,
+            _ => polars_bail!(
+                InvalidOperation: "invalid format '{}' for Duration, expected one of 'iso' or 'polars'", format
+            ),
+        };
+
+        let mut ca: StringChunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * 8);
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        fmt(&mut buf, *v, tu);
+                        mutarr.push(Some(&buf))
+                    },
+                }
+            }
+
+            let arr: Utf8Array<i64> = mutarr.into();
+            Box::new(arr)
+        });
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Construct a new [`DurationChunked`] from an iterator over [`ChronoDuration`].
     pub fn from_duration<I: IntoIterator<Item = ChronoDuration>>(
         name: &str,
@@ -90,3 +128,88 @@ impl DurationChunked {
         Int64Chunked::from_iter_options(name, vals).into_duration(tu)
     }
 }
+
+/// Write `v` as an ISO 8601 duration, only using days and smaller designators as
+/// the length of months and years isn't fixed.
+fn fmt_duration_iso(buf: &mut String, v: i64, tu: TimeUnit) {
+    let (per_second, digits) = match tu {
+        TimeUnit::Nanoseconds => (1_000_000_000, 9),
+        TimeUnit::Microseconds => (1_000_000, 6),
+        TimeUnit::Milliseconds => (1_000, 3),
+    };
+    if v == 0 {
+        buf.push_str("PT0S");
+        return;
+    }
+    if v < 0 {
+        buf.push('-');
+    }
+    let v = v.unsigned_abs();
+    let secs = v / per_second;
+    let subsec = v % per_second;
+
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3_600;
+    let minutes = (secs % 3_600) / 60;
+    let seconds = secs % 60;
+
+    buf.push('P');
+    if days > 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && subsec == 0 {
+        return;
+    }
+    buf.push('T');
+    if hours > 0 {
+        write!(buf, "{hours}H").unwrap();
+    }
+    if minutes > 0 {
+        write!(buf, "{minutes}M").unwrap();
+    }
+    if seconds > 0 || subsec > 0 {
+        write!(buf, "{seconds}").unwrap();
+        if subsec > 0 {
+            let frac = format!("{subsec:0digits$}");
+            write!(buf, ".{}", frac.trim_end_matches('0')).unwrap();
+        }
+        buf.push('S');
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_duration_to_string() {
+        let ca = Int64Chunked::new(
+            "a",
+            &[
+                Some(93_784_000_005),
+                Some(-1_500_000),
+                Some(0),
+                None,
+                Some(86_400_000_000_000),
+            ],
+        )
+        .into_duration(TimeUnit::Nanoseconds);
+
+        let iso = ca.to_string("iso").unwrap();
+        assert_eq!(iso.name(), "a");
+        assert_eq!(
+            Vec::from(&iso),
+            &[
+                Some("P1DT2H3M4.000000005S"),
+                Some("-PT0.0015S"),
+                Some("PT0S"),
+                None,
+                Some("P1D"),
+            ]
+        );
+        let polars = ca.to_string("polars").unwrap();
+        assert_eq!(polars.get(0), Some("1d 2h 3m 4s 5ns"));
+        assert_eq!(polars.get(3), None);
+        assert!(ca.to_string("%H").is_err());
+    }
+}
@@ -48,6 +48,13 @@ pub fn get_file_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(POOL.current_num_threads() * 2, 16))
 }
 
+/// The number of rows a task reads when small files are compacted.
+pub fn get_file_compaction_rows() -> usize {
+    std::env::var("POLARS_FILE_COMPACTION_ROWS")
+        .map(|s| s.parse::<usize>().expect("integer"))
+        .unwrap_or(1 << 17)
+}
+
 pub fn get_rg_prefetch_size() -> usize {
     std::env::var("POLARS_ROW_GROUP_PREFETCH_SIZE")
         .map(|s| s.parse::<usize>().expect("integer"))
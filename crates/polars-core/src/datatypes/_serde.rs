@@ -185,7 +185,7 @@ impl From<SerializableDataType> for DataType {
             Struct(flds) => Self::Struct(flds),
             #[cfg(feature = "dtype-categorical")]
             Categorical(categories, ordering) => categories
-                .map(|categories| create_enum_data_type(categories.0))
+                .map(|categories| create_enum_data_type(categories.0, ordering))
                 .unwrap_or_else(|| Self::Categorical(None, ordering)),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown", None),
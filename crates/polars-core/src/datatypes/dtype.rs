@@ -215,6 +215,15 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is an Enum, i.e. a Categorical with a fixed set of categories.
+    pub fn is_enum(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rev_map), _) => rev_map.is_enum(),
+            _ => false,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     pub fn is_float(&self) -> bool {
         matches!(self, DataType::Float32 | DataType::Float64)
@@ -462,9 +471,12 @@ pub(crate) fn can_extend_dtype(left: &DataType, right: &DataType) -> PolarsResul
 }
 
 #[cfg(feature = "dtype-categorical")]
-pub fn create_enum_data_type(categories: Utf8Array<i64>) -> DataType {
-    let rev_map = RevMapping::build_enum(categories.clone());
-    DataType::Categorical(Some(Arc::new(rev_map)), Default::default())
+pub fn create_enum_data_type(
+    categories: Utf8Array<i64>,
+    ordering: CategoricalOrdering,
+) -> DataType {
+    let rev_map = RevMapping::build_enum(categories);
+    DataType::Categorical(Some(Arc::new(rev_map)), ordering)
 }
 
 #[cfg(feature = "dtype-categorical")]
@@ -1,4 +1,6 @@
 use arrow::chunk::Chunk;
+use arrow::datatypes::Metadata;
+use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 
@@ -19,3 +21,194 @@ impl std::convert::TryFrom<(ArrowChunk, &[ArrowField])> for DataFrame {
         DataFrame::new(columns?)
     }
 }
+
+/// Operations that would rechunk columns of a [`DataFrame`], as reported by
+/// [`DataFrame::rechunk_diagnostics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RechunkDiagnostic {
+    /// The operations that trigger the rechunk.
+    pub operations: &'static [&'static str],
+    /// The columns that would be rechunked.
+    pub columns: Vec<SmartString>,
+}
+
+impl DataFrame {
+    /// Convert the [`DataFrame`] to Arrow record batches, one per chunk, and their schema.
+    /// `metadata` is set as the metadata of the schema.
+    ///
+    /// The chunks are aligned first, see [`DataFrame::align_chunks`]. The record batches share
+    /// the buffers of the chunks, unless a column has to be converted to its Arrow
+    /// representation, which depends on `pl_flavor`.
+    pub fn to_record_batches(
+        &self,
+        metadata: Metadata,
+        pl_flavor: bool,
+    ) -> (ArrowSchema, Vec<ArrowChunk>) {
+        let mut df = self.clone();
+        df.align_chunks();
+        let schema = df.schema().to_arrow(pl_flavor).with_metadata(metadata);
+        let batches = df.iter_chunks(pl_flavor).collect();
+        (schema, batches)
+    }
+
+    /// Create a [`DataFrame`] from Arrow record batches with the given `schema`. Every record
+    /// batch becomes a chunk.
+    ///
+    /// The columns share the buffers of the record batches, unless an array has to be converted
+    /// to the polars representation of its type. The metadata of the schema is returned with the
+    /// [`DataFrame`], so that it can be passed back to [`DataFrame::to_record_batches`].
+    pub fn from_record_batches<I>(
+        schema: &ArrowSchema,
+        batches: I,
+    ) -> PolarsResult<(DataFrame, Metadata)>
+    where
+        I: IntoIterator<Item = ArrowChunk>,
+    {
+        let mut columns = vec![vec![]; schema.fields.len()];
+        for batch in batches {
+            polars_ensure!(
+                batch.arrays().len() == schema.fields.len(),
+                ShapeMismatch: "record batch has {} columns, but the schema has {} fields",
+                batch.arrays().len(), schema.fields.len()
+            );
+            for (chunks, array) in columns.iter_mut().zip(batch.into_arrays()) {
+                chunks.push(array);
+            }
+        }
+
+        let columns = schema
+            .fields
+            .iter()
+            .zip(columns)
+            .map(|(field, chunks)| {
+                if chunks.is_empty() {
+                    return Ok(Series::new_empty(&field.name, &field.data_type().into()));
+                }
+                for array in &chunks {
+                    polars_ensure!(
+                        array.data_type() == field.data_type(),
+                        SchemaMismatch: "expected arrays of type {:?} in column {:?}, got {:?}",
+                        field.data_type(), field.name, array.data_type()
+                    );
+                }
+                // SAFETY: we checked the types of the arrays.
+                unsafe { Series::_try_from_arrow_unchecked(&field.name, chunks, field.data_type()) }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok((DataFrame::new(columns)?, schema.metadata.clone()))
+    }
+
+    /// The lengths of the chunks of every column.
+    pub fn chunk_lengths(&self) -> Vec<Vec<usize>> {
+        self.columns
+            .iter()
+            .map(|s| s.chunk_lengths().collect())
+            .collect()
+    }
+
+    /// The chunk lengths that all columns can be split into by slicing their chunks, or `None`
+    /// if aligning the chunks needs a rechunk.
+    ///
+    /// We only split if this doesn't lead to more chunks than the column with the most chunks
+    /// has, so that aligning never fragments the data.
+    pub(super) fn aligned_chunk_lengths(&self) -> Option<Vec<usize>> {
+        let height = self.height();
+        if height == 0 {
+            return None;
+        }
+        #[cfg(feature = "object")]
+        if self
+            .columns
+            .iter()
+            .any(|s| matches!(s.dtype(), DataType::Object(_, _)))
+        {
+            return None;
+        }
+
+        let mut max_n_chunks = 0;
+        let mut offsets = vec![];
+        for s in &self.columns {
+            max_n_chunks = std::cmp::max(max_n_chunks, s.n_chunks());
+            let mut offset = 0;
+            offsets.extend(s.chunk_lengths().filter(|&len| len > 0).map(|len| {
+                offset += len;
+                offset
+            }));
+        }
+        offsets.sort_unstable();
+        offsets.dedup();
+        if offsets.len() > max_n_chunks || offsets.len() > height {
+            return None;
+        }
+
+        let mut previous = 0;
+        Some(
+            offsets
+                .into_iter()
+                .map(|offset| {
+                    let len = offset - previous;
+                    previous = offset;
+                    len
+                })
+                .collect(),
+        )
+    }
+
+    /// Split the chunks of all columns to the given chunk lengths. This doesn't copy any data.
+    pub(super) fn split_chunks_to(&mut self, chunk_lengths: &[usize]) {
+        for s in &mut self.columns {
+            if s.chunk_lengths().eq(chunk_lengths.iter().copied()) {
+                continue;
+            }
+            // every slice falls within a single chunk, so this is zero-copy
+            let mut out = s.slice(0, chunk_lengths[0]);
+            let mut offset = chunk_lengths[0];
+            for &len in &chunk_lengths[1..] {
+                out.append(&s.slice(offset as i64, len)).unwrap();
+                offset += len;
+            }
+            *s = out;
+        }
+    }
+
+    /// Report which operations would implicitly rechunk columns of this [`DataFrame`],
+    /// and which columns they would rechunk.
+    ///
+    /// Returns an empty `Vec` if no operation would rechunk.
+    pub fn rechunk_diagnostics(&self) -> Vec<RechunkDiagnostic> {
+        let multi_chunk_columns = self
+            .columns
+            .iter()
+            .filter(|s| s.n_chunks() > 1)
+            .map(|s| SmartString::from(s.name()))
+            .collect::<Vec<_>>();
+        if multi_chunk_columns.is_empty() {
+            return vec![];
+        }
+
+        let mut out = vec![RechunkDiagnostic {
+            operations: &["sort", "unique"],
+            columns: multi_chunk_columns.clone(),
+        }];
+        if self.should_rechunk() {
+            out.push(RechunkDiagnostic {
+                operations: &["join"],
+                columns: multi_chunk_columns.clone(),
+            });
+            if self.aligned_chunk_lengths().is_none() {
+                out.push(RechunkDiagnostic {
+                    operations: &[
+                        "align_chunks",
+                        "to_arrow",
+                        "write_ipc",
+                        "write_parquet",
+                        "write_json",
+                    ],
+                    columns: multi_chunk_columns,
+                });
+            }
+        }
+        out
+    }
+}
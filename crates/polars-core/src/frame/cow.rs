@@ -0,0 +1,136 @@
+//! Copy-on-write updates of [`DataFrame`]s that keep sharing memory with the original.
+use arrow::array::same_buffers;
+
+use crate::prelude::*;
+
+impl DataFrame {
+    /// Return a new [`DataFrame`] in which the chunks of the column `name` are updated by `f`.
+    ///
+    /// `f` is called with every chunk of the column as a [`Series`] and returns `None` to keep
+    /// the chunk, or the values that replace it. The new frame shares the buffers of all other
+    /// columns and of the kept chunks with `self`, so only the replaced chunks take up memory.
+    /// Use [`DataFrame::shared_chunk_counts`] to see what is shared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2], "b" => ["x", "y"])?;
+    /// df.vstack_mut(&df!("a" => [-3], "b" => ["z"])?)?;
+    ///
+    /// // Only the chunk with negative values is copied.
+    /// let fixed = df.with_column_cow("a", |chunk| {
+    ///     let a = chunk.i32()?;
+    ///     if a.lt(0).any() {
+    ///         Ok(Some(a.apply_values(|v| v.abs()).into_series()))
+    ///     } else {
+    ///         Ok(None)
+    ///     }
+    /// })?;
+    /// assert_eq!(fixed.shared_chunk_counts(&df), &[1, 2]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn with_column_cow<F>(&self, name: &str, mut f: F) -> PolarsResult<DataFrame>
+    where
+        F: FnMut(&Series) -> PolarsResult<Option<Series>>,
+    {
+        let column = self.column(name)?;
+        let dtype = column.dtype();
+
+        let mut out: Option<Series> = None;
+        for array in column.chunks() {
+            // SAFETY: the chunk stems from a column of this dtype.
+            let chunk = unsafe {
+                Series::from_chunks_and_dtype_unchecked(name, vec![array.clone()], dtype)
+            };
+            let chunk = match f(&chunk)? {
+                Some(new) => {
+                    polars_ensure!(
+                        new.len() == chunk.len(),
+                        ShapeMismatch: "chunk of length {} of column '{}' was replaced by {} values",
+                        chunk.len(), name, new.len()
+                    );
+                    polars_ensure!(
+                        new.dtype() == dtype,
+                        SchemaMismatch: "chunk of column '{}' with dtype {} was replaced by values \
+                        of dtype {}", name, dtype, new.dtype()
+                    );
+                    new
+                },
+                None => chunk,
+            };
+            match &mut out {
+                // Appending adds the chunks of the other series, no values are copied.
+                Some(out) => {
+                    out.append(&chunk)?;
+                },
+                None => out = Some(chunk),
+            }
+        }
+
+        let mut new_column = out.unwrap_or_else(|| column.clone());
+        new_column.rename(name);
+        let mut df = self.clone();
+        df.replace(name, new_column)?;
+        Ok(df)
+    }
+
+    /// For every column, the number of its chunks whose buffers are shared with a chunk of the
+    /// column of the same name in `other`, e.g. because one frame was derived from the other with
+    /// [`DataFrame::with_column_cow`]. Columns that `other` doesn't have share no chunks.
+    pub fn shared_chunk_counts(&self, other: &DataFrame) -> Vec<usize> {
+        self.columns
+            .iter()
+            .map(|s| {
+                let Ok(other) = other.column(s.name()) else {
+                    return 0;
+                };
+                s.chunks()
+                    .iter()
+                    .filter(|array| {
+                        other
+                            .chunks()
+                            .iter()
+                            .any(|other| same_buffers(array.as_ref(), other.as_ref()))
+                    })
+                    .count()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_with_column_cow() -> PolarsResult<()> {
+        let mut df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+        df.vstack_mut(&df!("a" => [4, 5], "b" => ["u", "v"])?)?;
+        df.vstack_mut(&df!("a" => [6], "b" => ["w"])?)?;
+        assert_eq!(df.shared_chunk_counts(&df), &[3, 3]);
+
+        let updated =
+            df.with_column_cow("a", |chunk| Ok((chunk.len() == 2).then(|| chunk * 10)))?;
+        assert_eq!(
+            Vec::from(updated.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(40), Some(50), Some(6)]
+        );
+        assert_eq!(updated.column("a")?.n_chunks(), 3);
+        assert_eq!(updated.shared_chunk_counts(&df), &[2, 3]);
+        // The original is untouched.
+        assert_eq!(df.column("a")?.i32()?.get(3), Some(4));
+
+        let other = df!("b" => ["x", "y", "z", "u", "v", "w"])?;
+        assert_eq!(df.shared_chunk_counts(&other), &[0, 0]);
+
+        assert!(df
+            .with_column_cow("a", |chunk| Ok(Some(chunk.head(Some(1)))))
+            .is_err());
+        assert!(df
+            .with_column_cow("a", |chunk| Ok(Some(chunk.cast(&DataType::Int64)?)))
+            .is_err());
+        assert!(df.with_column_cow("c", |_| Ok(None)).is_err());
+        Ok(())
+    }
+}
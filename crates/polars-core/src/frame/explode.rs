@@ -21,6 +21,80 @@ fn get_exploded(series: &Series) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
     }
 }
 
+/// What to do when the lists that are exploded together have different lengths in a row. Rows
+/// in which all lists have the same length are always exploded positionally.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum ExplodeMismatch {
+    /// Raise an error.
+    #[default]
+    Error,
+    /// Pad the shorter lists with nulls to the length of the longest list.
+    NullPad,
+    /// Explode the row to every combination of the elements of its lists.
+    Cartesian,
+}
+
+/// Explode columns whose lists have different lengths in some rows. Returns `df` with its rows
+/// repeated as often as they explode and the exploded columns gathered to match those rows.
+fn explode_mismatched(
+    df: &DataFrame,
+    exploded_columns: Vec<(Series, OffsetsBuffer<i64>)>,
+    mismatch: ExplodeMismatch,
+) -> PolarsResult<(DataFrame, Vec<Series>)> {
+    // Empty and null lists explode to a single null.
+    let lengths = exploded_columns
+        .iter()
+        .map(|(_, offsets)| offsets.lengths().map(|len| len.max(1)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut starts = vec![0usize; exploded_columns.len()];
+
+    let mut row_idx = Vec::with_capacity(exploded_columns[0].0.len());
+    let mut take_idx = vec![Vec::<Option<IdxSize>>::new(); exploded_columns.len()];
+    for row in 0..df.height() {
+        let cartesian = mismatch == ExplodeMismatch::Cartesian
+            && lengths[1..].iter().any(|l| l[row] != lengths[0][row]);
+        let row_lengths = lengths.iter().map(|lengths| lengths[row]);
+        let n = if cartesian {
+            row_lengths.product()
+        } else {
+            row_lengths.max().unwrap()
+        };
+        row_idx.extend(std::iter::repeat(row as IdxSize).take(n));
+
+        // In the cartesian product the elements of the last column vary fastest.
+        let mut stride = 1;
+        for (k, idx) in take_idx.iter_mut().enumerate().rev() {
+            let (start, len) = (starts[k], lengths[k][row]);
+            if cartesian {
+                idx.extend((0..n).map(|j| Some((start + (j / stride) % len) as IdxSize)));
+                stride *= len;
+            } else {
+                idx.extend((0..n).map(|j| (j < len).then_some((start + j) as IdxSize)));
+            }
+            starts[k] += len;
+        }
+    }
+
+    let mut row_idx = IdxCa::from_vec("", row_idx);
+    row_idx.set_sorted_flag(IsSorted::Ascending);
+    // Safety
+    // We just created indices that are in bounds.
+    let df = unsafe { df.take_unchecked(&row_idx) };
+
+    let exploded = exploded_columns
+        .into_iter()
+        .zip(take_idx)
+        .map(|((s, _), idx)| {
+            let idx = IdxCa::from_iter_options("", idx.into_iter());
+            // Safety
+            // The indices are within the exploded lists.
+            unsafe { s.take_unchecked(&idx) }
+        })
+        .collect();
+    Ok((df, exploded))
+}
+
 /// Arguments for `[DataFrame::melt]` function
 #[derive(Clone, Default, Debug, PartialEq)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
@@ -36,7 +110,11 @@ pub struct MeltArgs {
 }
 
 impl DataFrame {
-    pub fn explode_impl(&self, mut columns: Vec<Series>) -> PolarsResult<DataFrame> {
+    pub fn explode_impl(
+        &self,
+        mut columns: Vec<Series>,
+        mismatch: ExplodeMismatch,
+    ) -> PolarsResult<DataFrame> {
         polars_ensure!(!columns.is_empty(), InvalidOperation: "no columns provided in explode");
         let mut df = self.clone();
         if self.height() == 0 {
@@ -81,31 +159,30 @@ impl DataFrame {
             Ok(())
         }
 
-        let check_offsets = || {
-            let first_offsets = exploded_columns[0].1.as_slice();
-            for (_, offsets) in &exploded_columns[1..] {
-                polars_ensure!(first_offsets == offsets.as_slice(),
-                    ShapeMismatch: "exploded columns must have matching element counts"
-                )
+        let lengths_match = exploded_columns[1..]
+            .iter()
+            .all(|(_, offsets)| offsets.lengths().eq(exploded_columns[0].1.lengths()));
+        if !lengths_match && mismatch != ExplodeMismatch::Error {
+            let (mut df, exploded) = explode_mismatched(&df, exploded_columns, mismatch)?;
+            for exploded in exploded {
+                process_column(self, &mut df, exploded)?
             }
-            Ok(())
-        };
-        let process_first = || {
-            let (exploded, offsets) = &exploded_columns[0];
+            return Ok(df);
+        }
+
+        polars_ensure!(lengths_match,
+            ShapeMismatch: "exploded columns must have matching element counts"
+        );
 
-            let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
-            let mut row_idx = IdxCa::from_vec("", row_idx);
-            row_idx.set_sorted_flag(IsSorted::Ascending);
+        let (exploded, offsets) = &exploded_columns[0];
+        let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
+        let mut row_idx = IdxCa::from_vec("", row_idx);
+        row_idx.set_sorted_flag(IsSorted::Ascending);
 
-            // Safety
-            // We just created indices that are in bounds.
-            let mut df = unsafe { df.take_unchecked(&row_idx) };
-            process_column(self, &mut df, exploded.clone())?;
-            PolarsResult::Ok(df)
-        };
-        let (df, result) = POOL.join(process_first, check_offsets);
-        let mut df = df?;
-        result?;
+        // Safety
+        // We just created indices that are in bounds.
+        let mut df = unsafe { df.take_unchecked(&row_idx) };
+        process_column(self, &mut df, exploded.clone())?;
 
         for (exploded, _) in exploded_columns.into_iter().skip(1) {
             process_column(self, &mut df, exploded)?
@@ -180,7 +257,24 @@ impl DataFrame {
         // We need to sort the column by order of original occurrence. Otherwise the insert by index
         // below will panic
         let columns = self.select_series(columns)?;
-        self.explode_impl(columns)
+        self.explode_impl(columns, ExplodeMismatch::Error)
+    }
+
+    /// Explode `DataFrame` to long format by exploding multiple columns in lockstep.
+    ///
+    /// The elements at the same position in the lists of a row end up in the same output row.
+    /// `mismatch` determines what happens if the lists of a row have different lengths.
+    pub fn explode_with<I, S>(
+        &self,
+        columns: I,
+        mismatch: ExplodeMismatch,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = self.select_series(columns)?;
+        self.explode_impl(columns, mismatch)
     }
 
     ///
@@ -347,7 +441,7 @@ impl DataFrame {
 
 #[cfg(test)]
 mod test {
-    use crate::frame::explode::MeltArgs;
+    use crate::frame::explode::{ExplodeMismatch, MeltArgs};
     use crate::prelude::*;
 
     #[test]
@@ -433,6 +527,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_mismatch() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            &[
+                Series::new("", &[1, 2]),
+                Series::new("", &[3]),
+                Series::new("", &[4]).clear(),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            &[
+                Series::new("", &["x", "y"]),
+                Series::new("", &["z", "w"]),
+                Series::new("", &["v"]),
+            ],
+        );
+        let id = Series::new("id", [1, 2, 3]);
+        let df = DataFrame::new(vec![id, a, b])?;
+
+        assert!(df.explode(["a", "b"]).is_err());
+
+        let out = df.explode_with(["a", "b"], ExplodeMismatch::NullPad)?;
+        let expected = df![
+            "id" => [1, 1, 2, 2, 3],
+            "a" => [Some(1), Some(2), Some(3), None, None],
+            "b" => ["x", "y", "z", "w", "v"],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let out = df.explode_with(["a", "b"], ExplodeMismatch::Cartesian)?;
+        let expected = df![
+            "id" => [1, 1, 2, 2, 3],
+            "a" => [Some(1), Some(2), Some(3), Some(3), None],
+            "b" => ["x", "y", "z", "w", "v"],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let df = df.slice(1, 1);
+        let out = df.explode_with(["b", "a"], ExplodeMismatch::Cartesian)?;
+        assert_eq!(out.shape(), (2, 3));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_melt() -> PolarsResult<()> {
@@ -110,6 +110,8 @@ impl Series {
 
         match self.dtype() {
             Boolean => self.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => self.cast(&Float64).unwrap().agg_median(groups),
             Float32 => SeriesWrap(self.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(self.f64().unwrap().clone()).agg_median(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(self, agg_median, groups),
@@ -145,6 +147,11 @@ impl Series {
         match self.dtype() {
             Float32 => self.f32().unwrap().agg_quantile(groups, quantile, interpol),
             Float64 => self.f64().unwrap().agg_quantile(groups, quantile, interpol),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => self
+                .cast(&Float64)
+                .unwrap()
+                .agg_quantile(groups, quantile, interpol),
             dt if dt.is_numeric() || dt.is_temporal() => {
                 let ca = self.to_physical_repr();
                 let physical_type = ca.dtype();
@@ -168,6 +175,8 @@ impl Series {
 
         match self.dtype() {
             Boolean => self.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
             Float32 => SeriesWrap(self.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(self.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(self, agg_mean, groups),
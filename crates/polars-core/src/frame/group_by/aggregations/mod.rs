@@ -26,6 +26,7 @@ use rayon::prelude::*;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::extension::create_extension;
+use crate::chunked_array::ops::aggregate::quantile_slice;
 use crate::frame::group_by::GroupsIdx;
 #[cfg(feature = "object")]
 use crate::frame::group_by::GroupsIndicator;
@@ -282,8 +283,6 @@ impl_take_extremum!(float: f64);
 trait QuantileDispatcher<K> {
     fn _quantile(self, quantile: f64, interpol: QuantileInterpolOptions)
         -> PolarsResult<Option<K>>;
-
-    fn _median(self) -> Option<K>;
 }
 
 impl<T> QuantileDispatcher<f64> for ChunkedArray<T>
@@ -299,9 +298,6 @@ where
     ) -> PolarsResult<Option<f64>> {
         self.quantile_faster(quantile, interpol)
     }
-    fn _median(self) -> Option<f64> {
-        self.median_faster()
-    }
 }
 
 impl QuantileDispatcher<f32> for Float32Chunked {
@@ -312,9 +308,6 @@ impl QuantileDispatcher<f32> for Float32Chunked {
     ) -> PolarsResult<Option<f32>> {
         self.quantile_faster(quantile, interpol)
     }
-    fn _median(self) -> Option<f32> {
-        self.median_faster()
-    }
 }
 impl QuantileDispatcher<f64> for Float64Chunked {
     fn _quantile(
@@ -324,9 +317,6 @@ impl QuantileDispatcher<f64> for Float64Chunked {
     ) -> PolarsResult<Option<f64>> {
         self.quantile_faster(quantile, interpol)
     }
-    fn _median(self) -> Option<f64> {
-        self.median_faster()
-    }
 }
 
 unsafe fn agg_quantile_generic<T, K>(
@@ -349,15 +339,37 @@ where
     match groups {
         GroupsProxy::Idx(groups) => {
             let ca = ca.rechunk();
-            agg_helper_idx_on_all::<K, _>(groups, |idx| {
-                debug_assert!(idx.len() <= ca.len());
-                if idx.is_empty() {
-                    return None;
-                }
-                let take = { ca.take_unchecked(idx) };
-                // checked with invalid quantile check
-                take._quantile(quantile, interpol).unwrap_unchecked()
-            })
+            let arr = ca.downcast_iter().next().unwrap();
+            let values = arr.values().as_slice();
+            let validity = arr.validity();
+            // Only a single order statistic is needed, so instead of sorting the groups we
+            // gather their non-null values and use a selection algorithm. The buffer is reused
+            // for all groups of a thread.
+            let out: ChunkedArray<K> = POOL.install(|| {
+                groups
+                    .all()
+                    .par_iter()
+                    .map_init(Vec::new, |buf, idx| {
+                        debug_assert!(idx.len() <= ca.len());
+                        buf.clear();
+                        match validity {
+                            None => {
+                                buf.extend(idx.iter().map(|i| *values.get_unchecked(*i as usize)))
+                            },
+                            Some(validity) => buf.extend(
+                                idx.iter()
+                                    .filter(|i| validity.get_bit_unchecked(**i as usize))
+                                    .map(|i| *values.get_unchecked(*i as usize)),
+                            ),
+                        }
+                        // checked with invalid quantile check
+                        quantile_slice(buf, quantile, interpol)
+                            .unwrap_unchecked()
+                            .map(|v| NumCast::from(v).unwrap_unchecked())
+                    })
+                    .collect()
+            });
+            out.into_series()
         },
         GroupsProxy::Slice { groups, .. } => {
             if _use_rolling_kernels(groups, ca.chunks()) {
@@ -420,22 +432,7 @@ where
     K: PolarsNumericType,
     <K as datatypes::PolarsNumericType>::Native: num_traits::Float,
 {
-    match groups {
-        GroupsProxy::Idx(groups) => {
-            let ca = ca.rechunk();
-            agg_helper_idx_on_all::<K, _>(groups, |idx| {
-                debug_assert!(idx.len() <= ca.len());
-                if idx.is_empty() {
-                    return None;
-                }
-                let take = { ca.take_unchecked(idx) };
-                take._median()
-            })
-        },
-        GroupsProxy::Slice { .. } => {
-            agg_quantile_generic::<T, K>(ca, groups, 0.5, QuantileInterpolOptions::Linear)
-        },
-    }
+    agg_quantile_generic::<T, K>(ca, groups, 0.5, QuantileInterpolOptions::Linear)
 }
 
 impl<T> ChunkedArray<T>
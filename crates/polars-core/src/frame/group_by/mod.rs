@@ -709,6 +709,39 @@ impl<'df> GroupBy<'df> {
         DataFrame::new(cols)
     }
 
+    /// Get the number of rows per group, in a column `"len"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.group_by(["date"])?.len()
+    /// }
+    /// ```
+    /// Returns:
+    ///
+    /// ```text
+    /// +------------+-----+
+    /// | date       | len |
+    /// | ---        | --- |
+    /// | Date       | u32 |
+    /// +============+=====+
+    /// | 2020-08-23 | 1   |
+    /// +------------+-----+
+    /// | 2020-08-22 | 2   |
+    /// +------------+-----+
+    /// | 2020-08-21 | 2   |
+    /// +------------+-----+
+    /// ```
+    pub fn len(&self) -> PolarsResult<DataFrame> {
+        let mut cols = self.keys();
+        let mut ca = self.groups.group_count();
+        ca.rename("len");
+        cols.push(ca.into_series());
+        DataFrame::new(cols)
+    }
+
     /// Get the group_by group indexes.
     ///
     /// # Example
@@ -949,6 +982,13 @@ mod test {
             &Series::new("temp_count", [2 as IdxSize, 2, 1])
         );
 
+        let out = df.group_by_stable(["date"])?.len()?;
+        assert_eq!(out.get_column_names(), &["date", "len"]);
+        assert_eq!(
+            out.column("len")?,
+            &Series::new("len", [2 as IdxSize, 2, 1])
+        );
+
         // Use of deprecated mean() for testing purposes
         #[allow(deprecated)]
         // Select multiple
@@ -1169,6 +1209,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_quantile() -> PolarsResult<()> {
+        use arrow::legacy::prelude::QuantileInterpolOptions;
+
+        let df = df![
+            "g" => ["foo", "bar", "foo", "foo", "bar", "foo", "baz"],
+            "flt" => [Some(4.0), Some(1.0), None, Some(1.0), None, Some(3.0), None],
+            "int" => [Some(4), Some(1), None, Some(1), Some(2), Some(3), None]
+        ]?;
+
+        // Use of deprecated `quantile()` for testing purposes
+        #[allow(deprecated)]
+        let out = df
+            .group_by_stable(["g"])?
+            .select(["flt", "int"])
+            .quantile(0.5, QuantileInterpolOptions::Linear)?;
+        // the nulls are ignored
+        assert_eq!(
+            Vec::from(out.column("flt_quantile_0.50")?.f64()?),
+            &[Some(3.0), Some(1.0), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("int_quantile_0.50")?.f64()?),
+            &[Some(3.0), Some(1.5), None]
+        );
+
+        #[allow(deprecated)]
+        let out = df
+            .group_by_stable(["g"])?
+            .select(["int"])
+            .quantile(0.75, QuantileInterpolOptions::Lower)?;
+        assert_eq!(
+            Vec::from(out.column("int_quantile_0.75")?.f64()?),
+            &[Some(3.0), Some(1.0), None]
+        );
+
+        #[allow(deprecated)]
+        let out = df.group_by_stable(["g"])?.select(["flt"]).median()?;
+        assert_eq!(
+            Vec::from(out.column("flt_median")?.f64()?),
+            &[Some(3.0), Some(1.0), None]
+        );
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
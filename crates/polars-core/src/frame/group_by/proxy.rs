@@ -499,6 +499,33 @@ impl GroupsProxy {
         }
     }
 
+    /// Keep the groups for which `mask` is true.
+    pub fn filter(&self, mask: &BooleanChunked) -> GroupsProxy {
+        debug_assert_eq!(mask.len(), self.len());
+        match self {
+            GroupsProxy::Idx(groups) => {
+                let (first, all) = groups
+                    .first()
+                    .iter()
+                    .zip(groups.all())
+                    .zip(mask)
+                    .filter_map(|((first, all), keep)| {
+                        keep.unwrap_or(false).then(|| (*first, all.clone()))
+                    })
+                    .unzip();
+                GroupsProxy::Idx(GroupsIdx::new(first, all, groups.is_sorted_flag()))
+            },
+            GroupsProxy::Slice { groups, rolling } => GroupsProxy::Slice {
+                groups: groups
+                    .iter()
+                    .zip(mask)
+                    .filter_map(|(group, keep)| keep.unwrap_or(false).then_some(*group))
+                    .collect(),
+                rolling: *rolling,
+            },
+        }
+    }
+
     pub fn slice(&self, offset: i64, len: usize) -> SlicedGroups {
         // Safety:
         // we create new `Vec`s from the sliced groups. But we wrap them in ManuallyDrop
@@ -0,0 +1,485 @@
+//! Secondary indexes on the columns of a [`DataFrame`].
+use std::cmp::Ordering;
+use std::iter;
+
+use ahash::RandomState;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::sort::prepare_arg_sort;
+use crate::prelude::*;
+
+/// The kind of a [`DataFrameIndex`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum IndexKind {
+    /// The permutation that sorts the rows by the indexed columns. Supports point lookups and
+    /// [`search_sorted`](DataFrameIndex::search_sorted).
+    #[default]
+    Sorted,
+    /// A hash table from the values of the indexed columns to their rows. Supports point
+    /// lookups in constant time.
+    Hash,
+}
+
+enum IndexData {
+    /// The rows in ascending order of the indexed columns, nulls first.
+    Sorted(IdxCa),
+    /// The rows by the hash of their values. Rows with a null in any of the indexed columns are
+    /// left out as they are not equal to anything.
+    Hash {
+        random_state: RandomState,
+        rows: PlHashMap<u64, Vec<IdxSize>>,
+    },
+}
+
+struct IndexInner {
+    /// The indexed columns as they were when the index was built. The index is valid as long as
+    /// the DataFrame holds these very columns; any mutation of them creates new ones.
+    columns: Vec<Series>,
+    /// The indexed columns in the form in which they are compared, see [`normalize`].
+    keys: Vec<Series>,
+    data: IndexData,
+}
+
+/// An index on one or more columns of a [`DataFrame`], created by
+/// [`DataFrame::create_index`], that answers point queries without scanning the columns.
+///
+/// The index is shared by the clones of the DataFrame and is invalidated as soon as one of the
+/// indexed columns is mutated or replaced.
+#[derive(Clone)]
+pub struct DataFrameIndex(Arc<IndexInner>);
+
+fn same_series(a: &Series, b: &Series) -> bool {
+    std::ptr::eq(
+        Arc::as_ptr(&a.0) as *const u8,
+        Arc::as_ptr(&b.0) as *const u8,
+    )
+}
+
+/// The values of `s` in the form in which they are indexed: categoricals as strings, so that keys
+/// with another categorical mapping compare equal, booleans as integers, floats with `-0.0` as
+/// `0.0`, and other logical types as their physical type.
+fn normalize(s: &Series) -> PolarsResult<Series> {
+    let s = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) => s.cast(&DataType::String)?,
+        DataType::Boolean => s.cast(&DataType::UInt8)?,
+        DataType::Float32 => s.f32()?.apply_values(|v| v + 0.0).into_series(),
+        DataType::Float64 => s.f64()?.apply_values(|v| v + 0.0).into_series(),
+        _ => s.to_physical_repr().into_owned(),
+    };
+    polars_ensure!(
+        s.dtype().is_numeric() || matches!(s.dtype(), DataType::String | DataType::Binary),
+        InvalidOperation: "cannot index column '{}' of dtype {}", s.name(), s.dtype()
+    );
+    Ok(s)
+}
+
+fn hash_rows(keys: &[Series], random_state: &RandomState) -> PolarsResult<Vec<u64>> {
+    let mut hashes = Vec::with_capacity(keys[0].len());
+    keys[0].vec_hash(random_state.clone(), &mut hashes)?;
+    for s in &keys[1..] {
+        s.vec_hash_combine(random_state.clone(), &mut hashes)?;
+    }
+    Ok(hashes)
+}
+
+/// Whether none of the values of a row are null.
+fn not_null(keys: &[Series]) -> BooleanChunked {
+    keys.iter()
+        .map(|s| s.is_not_null())
+        .reduce(|acc, mask| &acc & &mask)
+        .unwrap()
+}
+
+/// Compare normalized values with nulls first and NaN after all other floats.
+fn compare(a: &AnyValue, b: &AnyValue) -> Ordering {
+    match (a, b) {
+        (AnyValue::Null, AnyValue::Null) => Ordering::Equal,
+        (AnyValue::Null, _) => Ordering::Less,
+        (_, AnyValue::Null) => Ordering::Greater,
+        (a, b) => a.partial_cmp(b).unwrap_or_else(|| {
+            let is_nan = |v: &AnyValue| v.extract::<f64>().is_some_and(f64::is_nan);
+            is_nan(a).cmp(&is_nan(b))
+        }),
+    }
+}
+
+impl DataFrameIndex {
+    fn new(columns: Vec<Series>, kind: IndexKind) -> PolarsResult<Self> {
+        let keys = columns
+            .iter()
+            .map(normalize)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let data = match kind {
+            IndexKind::Sorted => {
+                let rows = if keys.len() == 1 {
+                    keys[0].arg_sort(SortOptions {
+                        descending: false,
+                        nulls_last: false,
+                        multithreaded: true,
+                        maintain_order: true,
+                    })
+                } else {
+                    let (first, other, descending) =
+                        prepare_arg_sort(keys.clone(), vec![false; keys.len()])?;
+                    first.arg_sort_multiple(&SortMultipleOptions {
+                        other,
+                        descending,
+                        multithreaded: true,
+                    })?
+                };
+                IndexData::Sorted(rows.rechunk())
+            },
+            IndexKind::Hash => {
+                let random_state = RandomState::new();
+                let hashes = hash_rows(&keys, &random_state)?;
+                let mut rows = PlHashMap::<u64, Vec<IdxSize>>::with_capacity(hashes.len());
+                for (row, (hash, valid)) in hashes.into_iter().zip(&not_null(&keys)).enumerate() {
+                    if valid == Some(true) {
+                        rows.entry(hash).or_default().push(row as IdxSize);
+                    }
+                }
+                IndexData::Hash { random_state, rows }
+            },
+        };
+        Ok(Self(Arc::new(IndexInner {
+            columns,
+            keys,
+            data,
+        })))
+    }
+
+    pub fn kind(&self) -> IndexKind {
+        match self.0.data {
+            IndexData::Sorted(_) => IndexKind::Sorted,
+            IndexData::Hash { .. } => IndexKind::Hash,
+        }
+    }
+
+    /// The names of the indexed columns.
+    pub fn column_names(&self) -> Vec<&str> {
+        self.0.columns.iter().map(|s| s.name()).collect()
+    }
+
+    /// Whether `df` still holds the columns this index was built on.
+    pub fn is_valid_for(&self, df: &DataFrame) -> bool {
+        self.0.columns.iter().all(|s| {
+            df.column(s.name())
+                .map_or(false, |column| same_series(column, s))
+        })
+    }
+
+    /// Whether this index was built on exactly `columns`, in this order.
+    pub fn is_on(&self, columns: &[Series]) -> bool {
+        columns.len() == self.0.columns.len()
+            && columns
+                .iter()
+                .zip(&self.0.columns)
+                .all(|(a, b)| same_series(a, b))
+    }
+
+    /// The rows in ascending order of the indexed columns, nulls first, if this is a sorted
+    /// index.
+    pub fn sorted_rows(&self) -> Option<&IdxCa> {
+        match &self.0.data {
+            IndexData::Sorted(rows) => Some(rows),
+            IndexData::Hash { .. } => None,
+        }
+    }
+
+    /// Cast `keys` to the dtypes of the indexed columns and normalize them.
+    fn conform_keys(&self, keys: &[Series]) -> PolarsResult<Vec<Series>> {
+        polars_ensure!(
+            keys.len() == self.0.columns.len(),
+            ShapeMismatch: "expected {} key columns for an index on {:?}, got {}",
+            self.0.columns.len(), self.column_names(), keys.len()
+        );
+        keys.iter()
+            .zip(&self.0.columns)
+            .map(|(key, column)| normalize(&key.cast(column.dtype())?))
+            .collect()
+    }
+
+    fn compare_row(&self, row: IdxSize, values: &[AnyValue]) -> Ordering {
+        for (s, value) in self.0.keys.iter().zip(values) {
+            // SAFETY: the rows of the index are in bounds of the indexed columns.
+            let av = unsafe { s.get_unchecked(row as usize) };
+            match compare(&av, value) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Keep the pairs of rows whose values are equal, where NaN is equal to NaN.
+    fn retain_equal(
+        &self,
+        keys: &[Series],
+        left: Vec<IdxSize>,
+        right: Vec<IdxSize>,
+    ) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+        let left_idx = IdxCa::from_vec("", left);
+        let right_idx = IdxCa::from_vec("", right);
+        let mut mask = BooleanChunked::full("", true, left_idx.len());
+        for (key, column) in keys.iter().zip(&self.0.keys) {
+            let key = key.take(&left_idx)?;
+            let column = column.take(&right_idx)?;
+            let mut equal = key.equal(&column)?;
+            if key.dtype().is_float() {
+                equal = &equal | &(&key.is_nan()? & &column.is_nan()?);
+            }
+            mask = &mask & &equal;
+        }
+        let left = left_idx.filter(&mask)?;
+        let right = right_idx.filter(&mask)?;
+        Ok((
+            left.into_no_null_iter().collect(),
+            right.into_no_null_iter().collect(),
+        ))
+    }
+
+    /// The pairs of rows of `keys` and of the indexed columns with equal values, ordered by the
+    /// row of `keys` and then by the row of the indexed columns. The keys are cast to the dtypes
+    /// of the indexed columns. Rows with a null in any of the columns have no matches.
+    pub fn probe(&self, keys: &[Series]) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+        let keys = self.conform_keys(keys)?;
+        let valid = not_null(&keys);
+        let mut left = vec![];
+        let mut right = vec![];
+        match &self.0.data {
+            IndexData::Hash { random_state, rows } => {
+                let hashes = hash_rows(&keys, random_state)?;
+                for (i, (hash, valid)) in hashes.iter().zip(&valid).enumerate() {
+                    if valid != Some(true) {
+                        continue;
+                    }
+                    if let Some(matches) = rows.get(hash) {
+                        left.extend(iter::repeat(i as IdxSize).take(matches.len()));
+                        right.extend_from_slice(matches);
+                    }
+                }
+                // Different values may have equal hashes.
+                self.retain_equal(&keys, left, right)
+            },
+            IndexData::Sorted(rows) => {
+                let rows = rows.cont_slice()?;
+                let mut values = Vec::with_capacity(keys.len());
+                for (i, valid) in valid.into_iter().enumerate() {
+                    if valid != Some(true) {
+                        continue;
+                    }
+                    values.clear();
+                    for s in &keys {
+                        values.push(s.get(i)?);
+                    }
+                    let lower = rows.partition_point(|&row| self.compare_row(row, &values).is_lt());
+                    let upper = lower
+                        + rows[lower..]
+                            .partition_point(|&row| self.compare_row(row, &values).is_eq());
+                    let start = right.len();
+                    right.extend_from_slice(&rows[lower..upper]);
+                    right[start..].sort_unstable();
+                    left.extend(iter::repeat(i as IdxSize).take(upper - lower));
+                }
+                Ok((left, right))
+            },
+        }
+    }
+
+    /// The rows whose values equal any row of `keys`, in ascending order.
+    pub fn lookup(&self, keys: &[Series]) -> PolarsResult<IdxCa> {
+        let (_, mut rows) = self.probe(keys)?;
+        rows.sort_unstable();
+        rows.dedup();
+        Ok(IdxCa::from_vec("", rows))
+    }
+
+    /// For every value, the position in the sorted indexed column at which it would be
+    /// inserted: the number of smaller values, or of smaller or equal values if `right`. Nulls
+    /// sort first. Requires a sorted index on a single column.
+    pub fn search_sorted(&self, values: &Series, right: bool) -> PolarsResult<IdxCa> {
+        let Some(rows) = self.sorted_rows() else {
+            polars_bail!(InvalidOperation: "search_sorted requires a sorted index");
+        };
+        polars_ensure!(
+            self.0.columns.len() == 1,
+            InvalidOperation: "search_sorted requires an index on a single column, got an index \
+            on {:?}", self.column_names()
+        );
+        let rows = rows.cont_slice()?;
+        let values = self.conform_keys(std::slice::from_ref(values))?;
+        let positions = (0..values[0].len())
+            .map(|i| {
+                let value = [values[0].get(i)?];
+                let position = rows.partition_point(|&row| match self.compare_row(row, &value) {
+                    Ordering::Less => true,
+                    Ordering::Equal => right,
+                    Ordering::Greater => false,
+                });
+                Ok(position as IdxSize)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(IdxCa::from_vec(values[0].name(), positions))
+    }
+}
+
+impl DataFrame {
+    /// Create an index on `columns` that subsequent point queries on these columns reuse: equality
+    /// filters on a [`DataFrame`] scan in the lazy engine, joins with this DataFrame on the right
+    /// and [`DataFrameIndex::search_sorted`]. An existing index of the same kind on the same
+    /// columns is replaced.
+    ///
+    /// The index is kept by clones of the DataFrame and invalidated by any mutation of the
+    /// indexed columns. Other operations return DataFrames without indexes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("id" => [3, 1, 2], "value" => ["c", "a", "b"])?;
+    /// df.create_index(["id"], IndexKind::Hash)?;
+    ///
+    /// let index = df.get_index(&["id"]).unwrap();
+    /// let rows = index.lookup(&[Series::new("id", [2, 3])])?;
+    /// assert_eq!(Vec::from(&rows), &[Some(0), Some(2)]);
+    ///
+    /// df.apply("id", |s| s + 1)?;
+    /// assert!(df.get_index(&["id"]).is_none());
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn create_index<I, S>(&mut self, columns: I, kind: IndexKind) -> PolarsResult<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = columns
+            .into_iter()
+            .map(|name| self.column(name.as_ref()).cloned())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            !columns.is_empty(),
+            ComputeError: "an index needs at least one column"
+        );
+        let index = DataFrameIndex::new(columns, kind)?;
+        self.indexes
+            .retain(|other| other.kind() != kind || other.column_names() != index.column_names());
+        self.indexes.push(index);
+        Ok(())
+    }
+
+    /// Drop all indexes of this DataFrame.
+    pub fn drop_indexes(&mut self) {
+        self.indexes.clear()
+    }
+
+    /// The valid indexes of this DataFrame.
+    pub fn indexes(&self) -> impl Iterator<Item = &DataFrameIndex> {
+        self.indexes.iter().filter(|index| index.is_valid_for(self))
+    }
+
+    /// A valid index on exactly the given columns, in the given order. A hash index is preferred
+    /// over a sorted index.
+    pub fn get_index<S: AsRef<str>>(&self, columns: &[S]) -> Option<&DataFrameIndex> {
+        let is_match = |index: &&DataFrameIndex| {
+            let names = index.column_names();
+            names.len() == columns.len() && names.iter().zip(columns).all(|(a, b)| *a == b.as_ref())
+        };
+        let mut matches = self.indexes().filter(is_match);
+        let first = matches.next()?;
+        Some(
+            iter::once(first)
+                .chain(matches)
+                .find(|index| index.kind() == IndexKind::Hash)
+                .unwrap_or(first),
+        )
+    }
+
+    /// Drop the indexes whose columns were mutated, which frees the columns they hold on to.
+    pub(crate) fn prune_indexes(&mut self) {
+        if !self.indexes.is_empty() {
+            let columns = &self.columns;
+            self.indexes.retain(|index| {
+                index.0.columns.iter().all(|s| {
+                    columns
+                        .iter()
+                        .any(|column| column.name() == s.name() && same_series(column, s))
+                })
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_lookup() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [Some(2), Some(1), None, Some(2), Some(3)],
+            "b" => ["x", "y", "z", "y", "x"],
+            "c" => [0.0, f64::NAN, 1.0, -0.0, 2.0],
+        )?;
+        for kind in [IndexKind::Sorted, IndexKind::Hash] {
+            df.create_index(["a", "b"], kind)?;
+            df.create_index(["c"], kind)?;
+            let index = df.get_index(&["a", "b"]).unwrap();
+            assert_eq!(index.kind(), kind);
+
+            let keys = [
+                Series::new("a", [Some(2i64), None, Some(1), Some(2)]),
+                Series::new("b", ["y", "z", "y", "y"]),
+            ];
+            let (left, right) = index.probe(&keys)?;
+            assert_eq!(left, &[0, 2, 3]);
+            assert_eq!(right, &[3, 1, 3]);
+            assert_eq!(Vec::from(&index.lookup(&keys)?), &[Some(1), Some(3)]);
+
+            let index = df.get_index(&["c"]).unwrap();
+            let keys = [Series::new("c", [0.0, f64::NAN])];
+            assert_eq!(
+                Vec::from(&index.lookup(&keys)?),
+                &[Some(0), Some(1), Some(3)]
+            );
+            df.drop_indexes();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_search_sorted() -> PolarsResult<()> {
+        let mut df = df!("a" => [Some(3), None, Some(1), Some(3)])?;
+        df.create_index(["a"], IndexKind::Sorted)?;
+        let index = df.get_index(&["a"]).unwrap();
+        assert_eq!(
+            Vec::from(index.sorted_rows().unwrap()),
+            &[Some(1), Some(2), Some(0), Some(3)]
+        );
+        let values = Series::new("v", [Some(0), Some(3), None, Some(4)]);
+        let left = index.search_sorted(&values, false)?;
+        assert_eq!(Vec::from(&left), &[Some(1), Some(2), Some(0), Some(4)]);
+        let right = index.search_sorted(&values, true)?;
+        assert_eq!(Vec::from(&right), &[Some(1), Some(4), Some(1), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_invalidation() -> PolarsResult<()> {
+        let mut df = df!("a" => [1, 2], "b" => [3, 4])?;
+        df.create_index(["a"], IndexKind::Hash)?;
+        let clone = df.clone();
+
+        // Mutating another column keeps the index.
+        df.apply("b", |s| s + 1)?;
+        assert!(df.get_index(&["a"]).is_some());
+
+        df.extend(&clone)?;
+        assert!(df.get_index(&["a"]).is_none());
+        assert!(df.indexes.is_empty());
+        assert!(clone.get_index(&["a"]).is_some());
+        Ok(())
+    }
+}
@@ -14,10 +14,12 @@ use crate::utils::{slice_offsets, split_ca, split_df, try_get_supertype, NoNull}
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 mod chunks;
+mod cow;
 pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
+pub mod index;
 #[cfg(feature = "rows")]
 pub mod row;
 mod top_k;
@@ -136,6 +138,7 @@ pub enum UniqueKeepStrategy {
 #[derive(Clone)]
 pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
+    pub(crate) indexes: Vec<index::DataFrameIndex>,
 }
 
 impl DataFrame {
@@ -298,9 +301,7 @@ impl DataFrame {
             series_cols
         };
 
-        Ok(DataFrame {
-            columns: series_cols,
-        })
+        Ok(DataFrame::new_no_checks(series_cols))
     }
 
     /// Creates an empty `DataFrame` usable in a compile time context (such as static initializers).
@@ -332,7 +333,9 @@ impl DataFrame {
     /// # Ok::<(), PolarsError>(())
     /// ```
     pub fn pop(&mut self) -> Option<Series> {
-        self.columns.pop()
+        let s = self.columns.pop();
+        self.prune_indexes();
+        s
     }
 
     /// Add a new column at index 0 that counts the rows.
@@ -405,7 +408,10 @@ impl DataFrame {
     /// It is the callers responsibility to uphold the contract of all `Series`
     /// having an equal length, if not this may panic down the line.
     pub const fn new_no_checks(columns: Vec<Series>) -> DataFrame {
-        DataFrame { columns }
+        DataFrame {
+            columns,
+            indexes: Vec::new(),
+        }
     }
 
     /// Aggregate all chunks to contiguous memory.
@@ -423,6 +429,7 @@ impl DataFrame {
         for s in &mut self.columns {
             s.shrink_to_fit();
         }
+        self.prune_indexes();
     }
 
     /// Aggregate all the chunks in the DataFrame to a single chunk.
@@ -431,6 +438,7 @@ impl DataFrame {
         for s in &mut self.columns {
             *s = s.rechunk();
         }
+        self.prune_indexes();
         self
     }
 
@@ -439,6 +447,7 @@ impl DataFrame {
     pub fn as_single_chunk_par(&mut self) -> &mut Self {
         if self.columns.iter().any(|s| s.n_chunks() > 1) {
             self.columns = self.apply_columns_par(&|s| s.rechunk());
+            self.prune_indexes();
         }
         self
     }
@@ -473,11 +482,19 @@ impl DataFrame {
     }
 
     /// Ensure all the chunks in the [`DataFrame`] are aligned.
+    ///
+    /// If possible the chunks are split to the same lengths without copying, otherwise all
+    /// columns are rechunked.
     pub fn align_chunks(&mut self) -> &mut Self {
-        if self.should_rechunk() {
-            self.as_single_chunk_par()
-        } else {
-            self
+        if !self.should_rechunk() {
+            return self;
+        }
+        match self.aligned_chunk_lengths() {
+            Some(chunk_lengths) => {
+                self.split_chunks_to(&chunk_lengths);
+                self
+            },
+            None => self.as_single_chunk_par(),
         }
     }
 
@@ -525,6 +542,8 @@ impl DataFrame {
     /// # Safety
     /// The caller must ensure the length of all [`Series`] remains equal.
     pub unsafe fn get_columns_mut(&mut self) -> &mut Vec<Series> {
+        // The columns may be mutated after this returns.
+        self.indexes.clear();
         &mut self.columns
     }
 
@@ -602,6 +621,7 @@ impl DataFrame {
                 s
             })
             .collect();
+        self.prune_indexes();
         Ok(())
     }
 
@@ -921,6 +941,7 @@ impl DataFrame {
                 left.append(right)?;
                 Ok(())
             })?;
+        self.prune_indexes();
         Ok(self)
     }
 
@@ -932,6 +953,7 @@ impl DataFrame {
             .for_each(|(left, right)| {
                 left.append(right).expect("should not fail");
             });
+        self.prune_indexes();
     }
 
     /// Extend the memory backed by this [`DataFrame`] with the values from `other`.
@@ -962,7 +984,9 @@ impl DataFrame {
                 ensure_can_extend(left, right)?;
                 left.extend(right).unwrap();
                 Ok(())
-            })
+            })?;
+        self.prune_indexes();
+        Ok(())
     }
 
     /// Remove a column by name and return the column removed.
@@ -983,7 +1007,9 @@ impl DataFrame {
     /// ```
     pub fn drop_in_place(&mut self, name: &str) -> PolarsResult<Series> {
         let idx = self.check_name_to_idx(name)?;
-        Ok(self.columns.remove(idx))
+        let s = self.columns.remove(idx);
+        self.prune_indexes();
+        Ok(s)
     }
 
     /// Return a new [`DataFrame`] where all null values are dropped.
@@ -1762,6 +1788,7 @@ impl DataFrame {
             unique_names.len() == self.width(),
             Duplicate: "duplicate column names found"
         );
+        self.prune_indexes();
         Ok(self)
     }
 
@@ -1992,6 +2019,7 @@ impl DataFrame {
         );
         let old_col = &mut self.columns[index];
         mem::swap(old_col, &mut new_column);
+        self.prune_indexes();
         Ok(self)
     }
 
@@ -2109,6 +2137,7 @@ impl DataFrame {
             let col = self.columns.get_unchecked_mut(idx);
             col.rename(&name);
         }
+        self.prune_indexes();
         Ok(self)
     }
 
@@ -2173,6 +2202,7 @@ impl DataFrame {
             let col = self.columns.get_unchecked_mut(idx);
             col.rename(&name);
         }
+        self.prune_indexes();
         Ok(self)
     }
 
@@ -2442,6 +2472,20 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
+    /// Replace None values with a strategy chosen by the data type of each column, e.g. fill
+    /// numeric columns with their mean while forward filling string columns. All columns are
+    /// processed in a single parallel pass.
+    ///
+    /// Columns without a matching strategy are returned unchanged.
+    pub fn fill_null_by_dtype(&self, strategies: FillNullStrategyByDtype) -> PolarsResult<Self> {
+        let col = self.try_apply_columns_par(&|s| match strategies.get(s.dtype()) {
+            Some(strategy) if s.null_count() > 0 => s.fill_null(strategy),
+            _ => Ok(s.clone()),
+        })?;
+
+        Ok(DataFrame::new_no_checks(col))
+    }
+
     /// Aggregate the column horizontally to their min values.
     #[cfg(feature = "zip_with")]
     pub fn min_horizontal(&self) -> PolarsResult<Option<Series>> {
@@ -3092,6 +3136,9 @@ fn ensure_can_extend(left: &Series, right: &Series) -> PolarsResult<()> {
 
 #[cfg(test)]
 mod test {
+    use arrow::array::PrimitiveArray;
+    use arrow::datatypes::Metadata;
+
     use super::*;
     use crate::frame::NullStrategy;
 
@@ -3113,6 +3160,28 @@ mod test {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn test_fill_null_by_dtype() -> PolarsResult<()> {
+        let df = df!(
+            "int" => [Some(1), None, Some(3)],
+            "str" => [Some("a"), None, Some("c")],
+            "bool" => [None, Some(true), None]
+        )?;
+        let strategies = FillNullStrategyByDtype {
+            numeric: Some(FillNullStrategy::Mean),
+            string: Some(FillNullStrategy::Forward(None)),
+            ..Default::default()
+        };
+        let out = df.fill_null_by_dtype(strategies)?;
+        let expected = df!(
+            "int" => [1, 2, 3],
+            "str" => ["a", "a", "c"],
+            "bool" => [None, Some(true), None]
+        )?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_select() {
@@ -3180,6 +3249,69 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_record_batches_roundtrip() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        )?;
+        let other = df.clone();
+        df.vstack_mut(&other)?;
+        let metadata = Metadata::from([("key".to_string(), "value".to_string())]);
+
+        let (schema, batches) = df.to_record_batches(metadata.clone(), false);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(schema.metadata, metadata);
+
+        let (out, out_metadata) = DataFrame::from_record_batches(&schema, batches.clone())?;
+        assert!(out.equals(&df));
+        assert_eq!(out_metadata, metadata);
+        assert_eq!(out.n_chunks(), 2);
+
+        // the buffers are shared
+        let values = |arr: &ArrayRef| {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap()
+                .values()
+                .as_ptr()
+        };
+        assert_eq!(
+            values(&out.column("a")?.chunks()[0]),
+            values(&batches[0].arrays()[0])
+        );
+
+        let (_, batches) = DataFrame::default().to_record_batches(Metadata::new(), false);
+        assert!(batches.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_align_chunks_without_rechunk() -> PolarsResult<()> {
+        let mut a = Series::new("a", &[1, 2]);
+        a.append(&Series::new("a", &[3, 4, 5]))?;
+        let b = Series::new("b", &[1, 2, 3, 4, 5]);
+        let mut df = DataFrame::new(vec![a, b])?;
+        assert_eq!(df.chunk_lengths(), &[vec![2, 3], vec![5]]);
+        assert_eq!(df.rechunk_diagnostics().len(), 2);
+
+        df.align_chunks();
+        assert_eq!(df.chunk_lengths(), &[vec![2, 3], vec![2, 3]]);
+        assert!(!df.should_rechunk());
+
+        // aligning these would fragment the chunks, so they are rechunked
+        let mut c = Series::new("c", &[1, 2, 3, 4]);
+        c.append(&Series::new("c", &[5]))?;
+        df.with_column(c)?;
+        let diagnostics = df.rechunk_diagnostics();
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[2].operations[0], "align_chunks");
+        df.align_chunks();
+        assert_eq!(df.chunk_lengths(), &[vec![5], vec![5], vec![5]]);
+        assert!(df.rechunk_diagnostics().is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_duplicate_column() {
         let mut df = df! {
@@ -0,0 +1,264 @@
+use super::*;
+
+const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+/// Incrementally build a [`DataFrame`] from rows or column slices.
+///
+/// Rows are written into typed buffers and appended column slices are staged until
+/// `chunk_size` rows are collected. Only then they are merged into a single chunk, so the
+/// resulting [`DataFrame`] consists of chunks of roughly `chunk_size` rows and doesn't need a
+/// full rechunk.
+///
+/// This should be used instead of [`DataFrame::vstack`]-ing many small frames in a loop.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::frame::row::DataFrameBuilder;
+/// let schema = Schema::from_iter([
+///     Field::new("a", DataType::Int32),
+///     Field::new("b", DataType::String),
+/// ]);
+/// let mut builder = DataFrameBuilder::new(Arc::new(schema));
+/// builder.push_row([AnyValue::Int32(1), AnyValue::String("foo")])?;
+/// builder.push_row([AnyValue::Null, AnyValue::String("bar")])?;
+/// builder.append_columns(&[
+///     Series::new("a", &[3i32, 4]),
+///     Series::new("b", &["ham", "spam"]),
+/// ])?;
+/// let df = builder.finish()?;
+/// assert_eq!(df.shape(), (4, 2));
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct DataFrameBuilder<'a> {
+    schema: SchemaRef,
+    // the sealed chunks of every column
+    columns: Vec<Series>,
+    // rows that are pushed but not yet staged
+    buffers: Vec<AnyValueBuffer<'a>>,
+    buffered_rows: usize,
+    // the buffers may hold values of an incomplete row
+    dirty: bool,
+    // data that is staged, but not yet merged into a chunk
+    staged: Vec<Series>,
+    staged_rows: usize,
+    chunk_size: usize,
+}
+
+impl<'a> DataFrameBuilder<'a> {
+    /// Create a new builder that produces a [`DataFrame`] with the given schema.
+    pub fn new(schema: SchemaRef) -> Self {
+        Self::with_chunk_size(schema, DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Create a new builder that produces chunks of `chunk_size` rows.
+    pub fn with_chunk_size(schema: SchemaRef, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+        let empty = || {
+            schema
+                .iter()
+                .map(|(name, dtype)| Series::new_empty(name.as_str(), dtype))
+                .collect::<Vec<_>>()
+        };
+        let buffers = schema
+            .iter_dtypes()
+            .map(|dtype| AnyValueBuffer::new(dtype, chunk_size))
+            .collect();
+
+        Self {
+            columns: empty(),
+            staged: empty(),
+            schema,
+            buffers,
+            buffered_rows: 0,
+            dirty: false,
+            staged_rows: 0,
+            chunk_size,
+        }
+    }
+
+    /// Number of rows in the builder.
+    pub fn len(&self) -> usize {
+        self.columns.first().map(|s| s.len()).unwrap_or(0) + self.staged_rows + self.buffered_rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Push a single row. The values must be in the order of the schema.
+    ///
+    /// If a value cannot be added, the row is discarded and an error is returned.
+    pub fn push_row<I>(&mut self, row: I) -> PolarsResult<()>
+    where
+        I: IntoIterator<Item = AnyValue<'a>>,
+    {
+        let mut row = row.into_iter();
+        let mut width = 0;
+        let mut result = Ok(());
+        self.dirty = true;
+        for (value, buf) in (&mut row).zip(self.buffers.iter_mut()) {
+            width += 1;
+            if let Err(e) = buf.add_fallible(&value) {
+                result = Err(e);
+                break;
+            }
+        }
+        if result.is_ok() && (width != self.schema.len() || row.next().is_some()) {
+            result = Err(polars_err!(
+                ShapeMismatch: "row does not have the same number of values as the schema ({})",
+                self.schema.len()
+            ));
+        }
+        if result.is_err() {
+            // the buffers may hold a part of this row, stage the complete rows only.
+            self.stage_buffers();
+            return result;
+        }
+
+        self.buffered_rows += 1;
+        if self.buffered_rows == self.chunk_size {
+            self.stage_buffers();
+            self.seal()?;
+        }
+        Ok(())
+    }
+
+    /// Push a single [`Row`].
+    pub fn push(&mut self, row: Row<'a>) -> PolarsResult<()> {
+        self.push_row(row.0)
+    }
+
+    /// Append column slices. The columns must be in the order of the schema and have equal length.
+    ///
+    /// Large slices are appended as chunks without copying, small slices are staged and merged
+    /// once `chunk_size` rows are collected.
+    pub fn append_columns(&mut self, columns: &[Series]) -> PolarsResult<()> {
+        polars_ensure!(
+            columns.len() == self.schema.len(),
+            ShapeMismatch: "got {} columns, but the schema has {} columns",
+            columns.len(), self.schema.len()
+        );
+        let height = columns.first().map(|s| s.len()).unwrap_or(0);
+        polars_ensure!(
+            columns.iter().all(|s| s.len() == height),
+            ShapeMismatch: "all columns must have equal length"
+        );
+        if height == 0 {
+            return Ok(());
+        }
+        let columns = columns
+            .iter()
+            .zip(self.schema.iter_dtypes())
+            .map(|(s, dtype)| s.cast(dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // keep the order of the rows that were pushed before
+        self.stage_buffers();
+
+        if height >= self.chunk_size {
+            self.seal()?;
+            for (col, s) in self.columns.iter_mut().zip(&columns) {
+                col.append(s)?;
+            }
+        } else {
+            for (staged, s) in self.staged.iter_mut().zip(&columns) {
+                staged.append(s)?;
+            }
+            self.staged_rows += height;
+            if self.staged_rows >= self.chunk_size {
+                self.seal()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Append the columns of a [`DataFrame`] that has the same schema as this builder.
+    pub fn append_frame(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.append_columns(df.get_columns())
+    }
+
+    // move the complete rows in the buffers to the staging area
+    fn stage_buffers(&mut self) {
+        let n_rows = self.buffered_rows;
+        if !self.dirty {
+            return;
+        }
+        for (buf, staged) in self.buffers.iter_mut().zip(self.staged.iter_mut()) {
+            let s = buf.reset(self.chunk_size);
+            if n_rows > 0 {
+                let s = if s.len() > n_rows {
+                    s.slice(0, n_rows)
+                } else {
+                    s
+                };
+                // the buffers are created from the schema dtypes
+                staged.append(&s).unwrap();
+            }
+        }
+        self.staged_rows += n_rows;
+        self.buffered_rows = 0;
+        self.dirty = false;
+    }
+
+    // merge the staged data into a single chunk and append that to the columns
+    fn seal(&mut self) -> PolarsResult<()> {
+        if self.staged_rows == 0 {
+            return Ok(());
+        }
+        for (col, staged) in self.columns.iter_mut().zip(self.staged.iter_mut()) {
+            let empty = Series::new_empty(staged.name(), staged.dtype());
+            let s = std::mem::replace(staged, empty);
+            col.append(&s.rechunk())?;
+        }
+        self.staged_rows = 0;
+        Ok(())
+    }
+
+    /// Finish the builder and return the [`DataFrame`].
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        self.stage_buffers();
+        self.seal()?;
+        Ok(DataFrame::new_no_checks(self.columns))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_df_builder_chunks() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::String),
+        ]);
+        let mut builder = DataFrameBuilder::with_chunk_size(Arc::new(schema), 4);
+        for i in 0..6i64 {
+            builder.push_row([AnyValue::Int64(i), AnyValue::String("x")])?;
+        }
+        // a failing row is discarded
+        assert!(builder
+            .push_row([AnyValue::Int64(6), AnyValue::List(Series::new("", [1]))])
+            .is_err());
+        builder.append_columns(&[Series::new("a", [6i64, 7]), Series::new("b", ["y", "z"])])?;
+        assert_eq!(builder.len(), 8);
+
+        let df = builder.finish()?;
+        assert_eq!(df.shape(), (8, 2));
+        assert_eq!(df.n_chunks(), 2);
+        assert_eq!(
+            df.column("a")?
+                .i64()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            (0..8).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod av_buffer;
+mod builder;
 mod dataframe;
 mod transpose;
 
@@ -8,6 +9,7 @@ use std::hint::unreachable_unchecked;
 
 use arrow::bitmap::Bitmap;
 pub use av_buffer::*;
+pub use builder::*;
 use rayon::prelude::*;
 
 use crate::prelude::*;
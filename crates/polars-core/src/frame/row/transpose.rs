@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use either::Either;
 
 use super::*;
+use crate::utils::_split_offsets;
 
 impl DataFrame {
     pub(crate) fn transpose_from_dtype(
@@ -46,37 +47,55 @@ impl DataFrame {
             },
             _ => {
                 let phys_dtype = dtype.to_physical();
-                let mut buffers = (0..new_width)
-                    .map(|_| {
-                        let buf: AnyValueBufferTrusted = (&phys_dtype, new_height).into();
-                        buf
-                    })
-                    .collect::<Vec<_>>();
-
                 let columns = self
                     .columns
                     .iter()
                     // first cast to supertype before casting to physical to ensure units are correct
                     .map(|s| s.cast(dtype).unwrap().cast(&phys_dtype).unwrap())
                     .collect::<Vec<_>>();
-
-                // this is very expensive. A lot of cache misses here.
-                // This is the part that is performance critical.
-                for s in columns {
+                for s in &columns {
                     polars_ensure!(s.dtype() == &phys_dtype, ComputeError: "cannot transpose with supertype: {}", dtype);
-                    s.iter().zip(buffers.iter_mut()).for_each(|(av, buf)| {
-                        // safety: we checked the type and we borrow
-                        unsafe {
-                            buf.add_unchecked_borrowed_physical(&av);
-                        }
-                    });
                 }
-                cols_t.extend(buffers.into_iter().zip(names_out).map(|(buf, name)| {
-                    // Safety: we are casting back to the supertype
-                    let mut s = unsafe { buf.into_series().cast_unchecked(dtype).unwrap() };
-                    s.rename(name);
-                    s
-                }));
+
+                // this is very expensive. A lot of cache misses here.
+                // This is the part that is performance critical, so every thread fills the
+                // output columns of its own slice of rows.
+                let offsets = _split_offsets(new_width, POOL.current_num_threads());
+                let out = POOL.install(|| {
+                    offsets
+                        .into_par_iter()
+                        .map(|(offset, len)| {
+                            let mut buffers = (0..len)
+                                .map(|_| {
+                                    let buf: AnyValueBufferTrusted =
+                                        (&phys_dtype, new_height).into();
+                                    buf
+                                })
+                                .collect::<Vec<_>>();
+                            for s in &columns {
+                                let s = s.slice(offset as i64, len);
+                                s.iter().zip(buffers.iter_mut()).for_each(|(av, buf)| {
+                                    // safety: we checked the type and we borrow
+                                    unsafe {
+                                        buf.add_unchecked_borrowed_physical(&av);
+                                    }
+                                });
+                            }
+                            buffers
+                                .into_iter()
+                                .zip(&names_out[offset..offset + len])
+                                .map(|(buf, name)| {
+                                    // Safety: we are casting back to the supertype
+                                    let mut s =
+                                        unsafe { buf.into_series().cast_unchecked(dtype).unwrap() };
+                                    s.rename(name);
+                                    s
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                });
+                cols_t.extend(out.into_iter().flatten());
             },
         };
         Ok(DataFrame::new_no_checks(cols_t))
@@ -93,8 +112,10 @@ impl DataFrame {
             None => (0..self.height()).map(|i| format!("column_{i}")).collect(),
             Some(cn) => match cn {
                 Either::Left(name) => {
-                    let new_names = self.column(&name).and_then(|x| x.str())?;
-                    polars_ensure!(!new_names.has_validity(), ComputeError: "Column with new names can't have null values");
+                    // Non-string columns (e.g. integer ids) are formatted as their string representation.
+                    let new_names = self.column(&name)?.cast(&DataType::String)?;
+                    let new_names = new_names.str()?;
+                    polars_ensure!(new_names.null_count() == 0, ComputeError: "Column with new names can't have null values");
                     df = Cow::Owned(self.drop(&name)?);
                     new_names
                         .into_no_null_iter()
@@ -180,7 +201,7 @@ where
     let validity_buf_ptr = &mut validity_buf as *mut Vec<Vec<bool>> as usize;
 
     POOL.install(|| {
-        cols.iter().enumerate().for_each(|(row_idx, s)| {
+        cols.par_iter().enumerate().for_each(|(row_idx, s)| {
             let s = s.cast(&T::get_dtype()).unwrap();
             let ca = s.unpack::<T>().unwrap();
 
@@ -299,4 +320,23 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_column_names() -> PolarsResult<()> {
+        let df = df![
+            "id" => [1, 2, 3],
+            "a" => ["x", "y", "z"],
+            "b" => [Some(10), None, Some(30)],
+        ]?;
+
+        let out = df.transpose(Some("column"), Some(Either::Left("id".into())))?;
+        let expected = df![
+            "column" => ["a", "b"],
+            "1" => ["x", "10"],
+            "2" => [Some("y"), None],
+            "3" => ["z", "30"],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
 }
@@ -160,6 +160,8 @@ vec_hash_int!(UInt64Chunked);
 vec_hash_int!(UInt32Chunked);
 vec_hash_int!(UInt16Chunked);
 vec_hash_int!(UInt8Chunked);
+#[cfg(feature = "dtype-decimal")]
+vec_hash_int!(Int128Chunked);
 
 impl VecHash for StringChunked {
     fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
@@ -441,8 +441,12 @@ impl Series {
                     StringChunked::full("", "", 0)
                 };
 
-                ca.cast(&DataType::Categorical(rev_map.clone(), *ordering))
-                    .unwrap()
+                let dtype = DataType::Categorical(rev_map.clone(), *ordering);
+                if strict {
+                    ca.into_series().strict_cast(&dtype)?
+                } else {
+                    ca.cast(&dtype)?
+                }
             },
             dt => panic!("{dt:?} not supported"),
         };
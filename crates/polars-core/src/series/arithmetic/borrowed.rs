@@ -380,6 +380,26 @@ fn coerce_time_units<'a>(
     }
 }
 
+/// Cast the operands of an operation between two temporal dtypes according to
+/// [`temporal_coercion`]. Returns `None` if not both operands are temporal.
+pub(crate) fn coerce_temporal_lhs_rhs<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+    op: TemporalOp,
+) -> PolarsResult<Option<(Cow<'a, Series>, Cow<'a, Series>)>> {
+    let Some(coercion) = temporal_coercion(lhs.dtype(), rhs.dtype(), op)? else {
+        return Ok(None);
+    };
+    let cast = |s: &'a Series, dtype: &DataType| -> PolarsResult<Cow<'a, Series>> {
+        if s.dtype() == dtype {
+            Ok(Cow::Borrowed(s))
+        } else {
+            s.cast(dtype).map(Cow::Owned)
+        }
+    };
+    Ok(Some((cast(lhs, &coercion.lhs)?, cast(rhs, &coercion.rhs)?)))
+}
+
 #[cfg(feature = "dtype-struct")]
 pub fn _struct_arithmetic<F: FnMut(&Series, &Series) -> Series>(
     s: &Series,
@@ -412,6 +432,79 @@ pub fn _struct_arithmetic<F: FnMut(&Series, &Series) -> Series>(
     }
 }
 
+/// Applies `op` elementwise on the values of fixed-size arrays. Both operands are broadcast
+/// to the same length; a non-array operand is applied to every element of its row.
+#[cfg(feature = "dtype-array")]
+pub fn _array_arithmetic<F>(lhs: &Series, rhs: &Series, op: F) -> PolarsResult<Series>
+where
+    F: Fn(&Series, &Series) -> PolarsResult<Series>,
+{
+    let len = match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => l,
+        (l, 1) => l,
+        (1, r) => r,
+        (l, r) => {
+            polars_bail!(ShapeMismatch: "cannot apply arithmetic on arrays of different lengths: {} and {}", l, r)
+        },
+    };
+    let width = match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Array(_, wl), DataType::Array(_, wr)) => {
+            polars_ensure!(
+                wl == wr,
+                ShapeMismatch: "cannot apply arithmetic on arrays of different widths: {} and {}", wl, wr
+            );
+            *wl
+        },
+        (DataType::Array(_, w), _) | (_, DataType::Array(_, w)) => *w,
+        _ => unreachable!(),
+    };
+
+    // Flatten an operand to the values it takes at every position of the output values.
+    let flatten = |s: &Series| -> PolarsResult<(Series, Option<Bitmap>)> {
+        match s.dtype() {
+            DataType::Array(_, _) => {
+                let s = if s.len() == len {
+                    s.rechunk()
+                } else {
+                    s.new_from_index(0, len)
+                };
+                let ca = s.array()?;
+                let validity = ca.downcast_iter().next().unwrap().validity().cloned();
+                Ok((ca.get_inner(), validity))
+            },
+            // Unit length operands are broadcast by the operation on the values.
+            _ if s.len() == 1 => Ok((s.clone(), None)),
+            _ => {
+                let idx = (0..len as IdxSize)
+                    .flat_map(|i| std::iter::repeat(i).take(width))
+                    .collect();
+                Ok((s.take(&IdxCa::from_vec("", idx))?, None))
+            },
+        }
+    };
+    let (values_l, validity_l) = flatten(lhs)?;
+    let (values_r, validity_r) = flatten(rhs)?;
+
+    let values = op(&values_l, &values_r)?.rechunk().to_arrow(0, true);
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    let arr = FixedSizeListArray::new(
+        data_type,
+        values,
+        combine_validities_and(validity_l.as_ref(), validity_r.as_ref()),
+    );
+    let name = if lhs.len() == len {
+        lhs.name()
+    } else {
+        rhs.name()
+    };
+    Ok(ArrayChunked::with_chunk(name, arr).into_series())
+}
+
+#[cfg(feature = "dtype-array")]
+fn is_array_arithmetic(lhs: &DataType, rhs: &DataType) -> bool {
+    matches!(lhs, DataType::Array(_, _)) || matches!(rhs, DataType::Array(_, _))
+}
+
 impl Sub for &Series {
     type Output = Series;
 
@@ -421,10 +514,7 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.subtract(rhs.as_ref()).expect("data types don't match")
-            },
+            _ => self.try_sub(rhs).unwrap(),
         }
     }
 }
@@ -436,12 +526,39 @@ impl Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 Ok(_struct_arithmetic(self, rhs, |a, b| a.add(b)))
             },
+            #[cfg(feature = "dtype-array")]
+            (l, r) if is_array_arithmetic(l, r) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_add(b))
+            },
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = match coerce_temporal_lhs_rhs(self, rhs, TemporalOp::Add)? {
+                    Some(coerced) => coerced,
+                    None => coerce_lhs_rhs(self, rhs)?,
+                };
                 lhs.add_to(rhs.as_ref())
             },
         }
     }
+
+    pub fn try_sub(&self, rhs: &Series) -> PolarsResult<Series> {
+        match (self.dtype(), rhs.dtype()) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.sub(b)))
+            },
+            #[cfg(feature = "dtype-array")]
+            (l, r) if is_array_arithmetic(l, r) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_sub(b))
+            },
+            _ => {
+                let (lhs, rhs) = match coerce_temporal_lhs_rhs(self, rhs, TemporalOp::Sub)? {
+                    Some(coerced) => coerced,
+                    None => coerce_lhs_rhs(self, rhs)?,
+                };
+                lhs.subtract(rhs.as_ref())
+            },
+        }
+    }
 }
 impl Add for &Series {
     type Output = Series;
@@ -465,6 +582,11 @@ impl Mul for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.mul(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (l, r) if is_array_arithmetic(l, r) => {
+                _array_arithmetic(self, rhs, |a, b| Ok(a.mul(b)))
+                    .expect("cannot apply arithmetic on arrays")
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.multiply(rhs.as_ref()).expect("data types don't match")
@@ -487,6 +609,11 @@ impl Div for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.div(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (l, r) if is_array_arithmetic(l, r) => {
+                _array_arithmetic(self, rhs, |a, b| Ok(a.div(b)))
+                    .expect("cannot apply arithmetic on arrays")
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.divide(rhs.as_ref()).expect("data types don't match")
@@ -509,6 +636,11 @@ impl Rem for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (l, r) if is_array_arithmetic(l, r) => {
+                _array_arithmetic(self, rhs, |a, b| Ok(a.rem(b)))
+                    .expect("cannot apply arithmetic on arrays")
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.remainder(rhs.as_ref()).expect("data types don't match")
@@ -867,4 +999,40 @@ mod test {
         let out = s_f64.checked_div_num(0.0f64).unwrap();
         assert_eq!(Vec::from(out.f64().unwrap()), &[None, None, None]);
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration"
+    ))]
+    fn test_mixed_temporal_arithmetic() -> PolarsResult<()> {
+        let ns = TimeUnit::Nanoseconds;
+        let day = 86_400_000_000_000i64;
+        let date = Int32Chunked::new("a", [1, 2]).into_date().into_series();
+        let dt = Int64Chunked::new("b", [day, 0])
+            .into_datetime(ns, None)
+            .into_series();
+
+        let out = date.try_sub(&dt)?;
+        assert_eq!(out.dtype(), &DataType::Duration(ns));
+        assert_eq!(out.cast(&DataType::Int64)?.i64()?.get(1), Some(2 * day));
+
+        let dur = Int64Chunked::new("c", [1, 2])
+            .into_duration(TimeUnit::Milliseconds)
+            .into_series();
+        let out = dur.try_add(&dt)?;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+
+        let aware = Int64Chunked::new("d", [day, 0])
+            .into_datetime(ns, Some("UTC".into()))
+            .into_series();
+        assert!(aware.try_sub(&dt).is_err());
+        assert!(aware.equal(&dt).is_err());
+        assert!(date.try_add(&dt).is_err());
+        Ok(())
+    }
 }
@@ -4,8 +4,12 @@ mod owned;
 use std::borrow::Cow;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
+#[cfg(feature = "dtype-array")]
+use arrow::bitmap::Bitmap;
+#[cfg(feature = "dtype-array")]
+use arrow::compute::utils::combine_validities_and;
 pub use borrowed::*;
 use num_traits::{Num, NumCast};
 
 use crate::prelude::*;
-use crate::utils::{get_time_units, try_get_supertype};
+use crate::utils::{get_time_units, temporal_coercion, try_get_supertype, TemporalOp};
@@ -6,8 +6,9 @@ use std::ops::Deref;
 use super::Series;
 use crate::apply_method_physical_numeric;
 use crate::prelude::*;
-use crate::series::arithmetic::coerce_lhs_rhs;
+use crate::series::arithmetic::{coerce_lhs_rhs, coerce_temporal_lhs_rhs};
 use crate::series::nulls::replace_non_null;
+use crate::utils::TemporalOp;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
@@ -41,7 +42,10 @@ macro_rules! impl_compare {
             _ => (),
         };
 
-        let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = match coerce_temporal_lhs_rhs(lhs, rhs, TemporalOp::Compare)? {
+            Some(coerced) => coerced,
+            None => coerce_lhs_rhs(lhs, rhs).expect("cannot coerce datatypes"),
+        };
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -58,6 +62,8 @@ macro_rules! impl_compare {
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => lhs.decimal().unwrap().$method(&rhs.decimal().unwrap().0),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => lhs.array().unwrap().$method(rhs.array().unwrap()),
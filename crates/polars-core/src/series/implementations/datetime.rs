@@ -95,14 +95,14 @@ impl private::PrivateSeries for SeriesWrap<DatetimeChunked> {
     fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
         match (self.dtype(), rhs.dtype()) {
             (DataType::Datetime(tu, tz), DataType::Datetime(tur, tzr)) => {
-                assert_eq!(tu, tur);
-                assert_eq!(tz, tzr);
+                polars_ensure!(tu == tur, InvalidOperation: "units are different");
+                polars_ensure!(tz == tzr, InvalidOperation: "time zones are different");
                 let lhs = self.cast(&DataType::Int64).unwrap();
                 let rhs = rhs.cast(&DataType::Int64).unwrap();
                 Ok(lhs.subtract(&rhs)?.into_duration(*tu).into_series())
             },
             (DataType::Datetime(tu, tz), DataType::Duration(tur)) => {
-                assert_eq!(tu, tur);
+                polars_ensure!(tu == tur, InvalidOperation: "units are different");
                 let lhs = self.cast(&DataType::Int64).unwrap();
                 let rhs = rhs.cast(&DataType::Int64).unwrap();
                 Ok(lhs
@@ -116,7 +116,7 @@ impl private::PrivateSeries for SeriesWrap<DatetimeChunked> {
     fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
         match (self.dtype(), rhs.dtype()) {
             (DataType::Datetime(tu, tz), DataType::Duration(tur)) => {
-                assert_eq!(tu, tur);
+                polars_ensure!(tu == tur, InvalidOperation: "units are different");
                 let lhs = self.cast(&DataType::Int64).unwrap();
                 let rhs = rhs.cast(&DataType::Int64).unwrap();
                 Ok(lhs
@@ -1,4 +1,8 @@
+use ahash::RandomState;
+
 use super::{private, IntoSeries, SeriesTrait, SeriesWrap, *};
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
 use crate::prelude::*;
 
 unsafe impl IntoSeries for DecimalChunked {
@@ -24,6 +28,11 @@ impl SeriesWrap<DecimalChunked> {
         let scale = self.0.scale();
         ca.into_decimal_unchecked(precision, scale).into_series()
     }
+
+    /// Statistics that can't be represented with the same scale are computed on floats.
+    fn to_float(&self) -> Series {
+        self.0.cast(&DataType::Float64).unwrap()
+    }
 }
 
 unsafe impl IntoSeries for Int128Chunked {
@@ -58,11 +67,33 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.0.set_flags(flags)
     }
 
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.decimal().unwrap();
+        self.0.get(idx_self) == other.get(idx_other)
+    }
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.deref().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.deref().into_total_ord_inner()
+    }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.0.vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        self.0.vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
     #[cfg(feature = "zip_with")]
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.decimal()?;
         Ok(self
             .0
-            .zip_with(mask, other.as_ref().as_ref())?
+            .zip_with(mask, other)?
             .into_decimal_unchecked(self.0.precision(), self.0.scale())
             .into_series())
     }
@@ -82,6 +113,16 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_max(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_float().agg_var(groups, ddof)
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
         self.0.agg_list(groups)
@@ -103,6 +144,15 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         let rhs = rhs.decimal()?;
         ((&self.0) / rhs).map(|ca| ca.into_series())
     }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.0.group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(&self, options: &SortMultipleOptions) -> PolarsResult<IdxCa> {
+        self.0.deref().arg_sort_multiple(options)
+    }
 }
 
 impl SeriesTrait for SeriesWrap<DecimalChunked> {
@@ -138,7 +188,8 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
 
     fn extend(&mut self, other: &Series) -> PolarsResult<()> {
         polars_ensure!(self.0.dtype() == other.dtype(), extend);
-        self.0.extend(other.as_ref().as_ref());
+        let other = other.decimal()?;
+        self.0.extend(&other.0);
         Ok(())
     }
 
@@ -222,10 +273,41 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.0.get_any_value_unchecked(index)
     }
 
+    fn mean(&self) -> Option<f64> {
+        self.to_float().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_float().median()
+    }
+
+    fn sort_with(&self, options: SortOptions) -> Series {
+        self.apply_physical(|ca| ca.sort_with(options))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.arg_sort(options)
+    }
+
     fn null_count(&self) -> usize {
         self.0.null_count()
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.apply_physical(|ca| ca.unique().unwrap()))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.arg_unique()
+    }
+
     fn has_validity(&self) -> bool {
         self.0.has_validity()
     }
@@ -268,4 +350,20 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             Int128Chunked::from_slice_options(self.name(), &[max])
         }))
     }
+    fn median_as_series(&self) -> PolarsResult<Series> {
+        self.to_float().median_as_series()
+    }
+    fn var_as_series(&self, ddof: u8) -> PolarsResult<Series> {
+        self.to_float().var_as_series(ddof)
+    }
+    fn std_as_series(&self, ddof: u8) -> PolarsResult<Series> {
+        self.to_float().std_as_series(ddof)
+    }
+    fn quantile_as_series(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        self.to_float().quantile_as_series(quantile, interpol)
+    }
 }
@@ -1,3 +1,8 @@
+#[cfg(feature = "dtype-struct")]
+use std::borrow::Borrow;
+#[cfg(feature = "dtype-struct")]
+use std::hash::{BuildHasher, Hash};
+
 use crate::prelude::any_value::arr_to_any_value;
 use crate::prelude::*;
 use crate::utils::NoNull;
@@ -57,6 +62,144 @@ impl FromIterator<String> for Series {
     }
 }
 
+impl<'a> FromIterator<Option<&'a str>> for Series {
+    fn from_iter<I: IntoIterator<Item = Option<&'a str>>>(iter: I) -> Self {
+        let ca: StringChunked = iter.into_iter().collect();
+        ca.into_series()
+    }
+}
+
+impl FromIterator<Option<String>> for Series {
+    fn from_iter<I: IntoIterator<Item = Option<String>>>(iter: I) -> Self {
+        let ca: StringChunked = iter.into_iter().collect();
+        ca.into_series()
+    }
+}
+
+/// The [`DataType`] of a [`Series`] collected from values of type `T`.
+fn collected_dtype<T>() -> DataType
+where
+    Series: FromIterator<T>,
+{
+    std::iter::empty::<T>().collect::<Series>().dtype().clone()
+}
+
+fn collect_list<T, I>(iter: I) -> Series
+where
+    I: Iterator<Item = Option<Vec<T>>>,
+    Series: FromIterator<T>,
+{
+    let ca: ListChunked = iter
+        .map(|opt_v| opt_v.map(|v| v.into_iter().collect::<Series>()))
+        .collect();
+    let s = ca.into_series();
+    // empty or all null input doesn't know the inner dtype
+    if has_null_inner(s.dtype()) {
+        s.cast(&DataType::List(Box::new(collected_dtype::<T>())))
+            .unwrap()
+    } else {
+        s
+    }
+}
+
+fn has_null_inner(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(inner) => matches!(**inner, DataType::Null) || has_null_inner(inner),
+        _ => false,
+    }
+}
+
+/// Collect nested values into a `List` [`Series`]. The inner dtype is determined by `T`,
+/// so `Vec<Vec<i32>>` becomes `List(Int32)` and `Vec<Vec<Vec<i32>>>` becomes `List(List(Int32))`.
+impl<T> FromIterator<Vec<T>> for Series
+where
+    Series: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        collect_list(iter.into_iter().map(Some))
+    }
+}
+
+impl<T> FromIterator<Option<Vec<T>>> for Series
+where
+    Series: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Vec<T>>>>(iter: I) -> Self {
+        collect_list(iter.into_iter())
+    }
+}
+
+macro_rules! from_iterator_tuple {
+    ($($T:ident: $idx:tt),+) => {
+        /// Collect tuples into a `Struct` [`Series`] with fields `field_0`, `field_1`, etc.
+        #[cfg(feature = "dtype-struct")]
+        impl<$($T),+> FromIterator<($($T,)+)> for Series
+        where
+            $(Series: FromIterator<$T>,)+
+        {
+            #[allow(non_snake_case)]
+            fn from_iter<I: IntoIterator<Item = ($($T,)+)>>(iter: I) -> Self {
+                let iter = iter.into_iter();
+                let capacity = crate::utils::get_iter_capacity(&iter);
+                $(let mut $T = Vec::with_capacity(capacity);)+
+                for tuple in iter {
+                    $($T.push(tuple.$idx);)+
+                }
+                let fields = [$({
+                    let mut s = $T.into_iter().collect::<Series>();
+                    s.rename(&format!("field_{}", $idx));
+                    s
+                }),+];
+                StructChunked::new("", &fields).unwrap().into_series()
+            }
+        }
+    };
+}
+
+from_iterator_tuple!(A: 0);
+from_iterator_tuple!(A: 0, B: 1);
+from_iterator_tuple!(A: 0, B: 1, C: 2);
+from_iterator_tuple!(A: 0, B: 1, C: 2, D: 3);
+from_iterator_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+
+/// Collect maps into a `Struct` [`Series`]. Every key that occurs in any of the maps becomes a
+/// field (sorted by name); maps that don't contain a key are null in that field.
+#[cfg(feature = "dtype-struct")]
+impl<K, V, S> FromIterator<std::collections::HashMap<K, V, S>> for Series
+where
+    K: Borrow<str> + Hash + Eq,
+    V: Clone,
+    S: BuildHasher,
+    Series: FromIterator<Option<V>>,
+{
+    fn from_iter<I: IntoIterator<Item = std::collections::HashMap<K, V, S>>>(iter: I) -> Self {
+        let maps = iter.into_iter().collect::<Vec<_>>();
+        let mut keys = maps
+            .iter()
+            .flat_map(|map| map.keys().map(|k| <K as Borrow<str>>::borrow(k)))
+            .collect::<PlHashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return StructChunked::full_null("", maps.len()).into_series();
+        }
+        keys.sort_unstable();
+
+        let fields = keys
+            .iter()
+            .map(|key| {
+                let mut s = maps
+                    .iter()
+                    .map(|map| map.get(*key).cloned())
+                    .collect::<Series>();
+                s.rename(key);
+                s
+            })
+            .collect::<Vec<_>>();
+        StructChunked::new("", &fields).unwrap().into_series()
+    }
+}
+
 pub type SeriesPhysIter<'a> = Box<dyn ExactSizeIterator<Item = AnyValue<'a>> + 'a>;
 
 impl Series {
@@ -193,4 +336,42 @@ mod test {
             .into_iter()
             .map(|opt_v| opt_v.map(|v| v * 2));
     }
+
+    #[test]
+    fn test_collect_nested() {
+        let s: Series = vec![Some(vec![1i32, 2]), None, Some(vec![])]
+            .into_iter()
+            .collect();
+        assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Int32)));
+        assert_eq!(s.null_count(), 1);
+
+        let s: Series = vec![None::<Vec<Vec<i64>>>; 2].into_iter().collect();
+        assert_eq!(
+            s.dtype(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Int64))))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_collect_struct() {
+        let s: Series = vec![(1i32, "a"), (2, "b")].into_iter().collect();
+        assert_eq!(
+            s.dtype(),
+            &DataType::Struct(vec![
+                Field::new("field_0", DataType::Int32),
+                Field::new("field_1", DataType::String),
+            ])
+        );
+
+        let maps = vec![
+            std::collections::HashMap::from([("b", 1.0f64)]),
+            std::collections::HashMap::from([("a", 2.0), ("b", 3.0)]),
+        ];
+        let s: Series = maps.into_iter().collect();
+        let ca = s.struct_().unwrap();
+        assert_eq!(ca.fields()[0].name(), "a");
+        assert_eq!(ca.fields()[0].null_count(), 1);
+        assert_eq!(ca.fields()[1].f64().unwrap().get(1), Some(3.0));
+    }
 }
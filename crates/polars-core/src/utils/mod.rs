@@ -1,6 +1,7 @@
 pub mod flatten;
 pub(crate) mod series;
 mod supertype;
+mod temporal;
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 
@@ -13,6 +14,7 @@ use rayon::prelude::*;
 pub use series::*;
 use smartstring::alias::String as SmartString;
 pub use supertype::*;
+pub use temporal::*;
 pub use {arrow, rayon};
 
 use crate::prelude::*;
@@ -44,6 +44,18 @@ pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<
     let failure_mask = !input.is_null() & output.is_null();
     let failures = input.filter_threaded(&failure_mask, false)?;
 
+    #[cfg(feature = "dtype-categorical")]
+    if let DataType::Categorical(Some(rev_map), _) = output.dtype() {
+        if rev_map.is_enum() && !failures.is_empty() {
+            let value = failures.cast(&DataType::String)?;
+            polars_bail!(
+                not_in_enum,
+                value = value.str()?.get(0).unwrap_or_default(),
+                categories = rev_map.get_categories()
+            );
+        }
+    }
+
     let additional_info = match (input.dtype(), output.dtype()) {
         (DataType::String, DataType::Date | DataType::Datetime(_, _)) => {
             "\n\nYou might want to try:\n\
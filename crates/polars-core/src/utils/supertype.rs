@@ -263,11 +263,26 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 }
                 Some(Struct(new_fields))
             }
+            // Decimal supertypes keep the largest scale and make sure the integer part of
+            // both sides still fits within the precision.
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            (Decimal(p, s), dt) if dt.is_integer() => {
+                let precision = p.map(|p| {
+                    let scale = s.unwrap_or(0);
+                    (p.saturating_sub(scale).max(integer_digits(dt)) + scale).min(38)
+                });
+                Some(Decimal(precision, *s))
+            }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                let scale = (*s1).max(*s2);
+                let precision = (*p1).zip(*p2).map(|(p1, p2)| {
+                    let int_digits = p1
+                        .saturating_sub(s1.unwrap_or(0))
+                        .max(p2.saturating_sub(s2.unwrap_or(0)));
+                    (int_digits + scale.unwrap_or(0)).min(38)
+                });
+                Some(Decimal(precision, scale))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
@@ -278,6 +293,20 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     inner(l, r).or_else(|| inner(r, l))
 }
 
+/// The number of decimal digits needed to represent any value of an integer type.
+#[cfg(feature = "dtype-decimal")]
+fn integer_digits(dtype: &DataType) -> usize {
+    use DataType::*;
+    match dtype {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        _ => 38,
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 fn union_struct_fields(fields_a: &[Field], fields_b: &[Field]) -> Option<DataType> {
     let (longest, shortest) = {
@@ -0,0 +1,160 @@
+use super::*;
+
+/// Binary operations that are defined between temporal dtypes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TemporalOp {
+    Add,
+    Sub,
+    Compare,
+}
+
+impl std::fmt::Display for TemporalOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TemporalOp::Add => "add",
+            TemporalOp::Sub => "sub",
+            TemporalOp::Compare => "compare",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The dtypes both operands of a temporal operation are cast to and the dtype of the result.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemporalCoercion {
+    pub lhs: DataType,
+    pub rhs: DataType,
+    pub output: DataType,
+}
+
+impl TemporalCoercion {
+    fn new(lhs: DataType, rhs: DataType, output: DataType) -> Self {
+        Self { lhs, rhs, output }
+    }
+}
+
+fn common_time_zone(
+    op: TemporalOp,
+    tz_l: &Option<TimeZone>,
+    tz_r: &Option<TimeZone>,
+) -> PolarsResult<Option<TimeZone>> {
+    match (tz_l, tz_r) {
+        (None, None) => Ok(None),
+        (Some(l), Some(r)) if l == r => Ok(tz_l.clone()),
+        (Some(_), Some(_)) => polars_bail!(
+            InvalidOperation:
+            "cannot {} datetimes with different time zones: {:?} and {:?}; \
+            convert them to the same time zone first", op, tz_l, tz_r
+        ),
+        _ => polars_bail!(
+            InvalidOperation:
+            "cannot {} a time zone aware datetime with a naive datetime: {:?} and {:?}; \
+            set or remove the time zone first", op, tz_l, tz_r
+        ),
+    }
+}
+
+/// Determine how a binary operation between two temporal dtypes is resolved.
+///
+/// Both engines use these rules, so mixed temporal arithmetic resolves to the same dtype
+/// eagerly and in the lazy schema:
+/// - different time units are cast to the coarsest unit (see [`get_time_units`]),
+/// - a `Date` combined with a `Datetime` is cast to that `Datetime`,
+/// - datetimes must have the same time zone, mixing time zones is ambiguous and errors,
+/// - `Date - Date` and `Datetime - Datetime` result in a `Duration`,
+/// - `Date/Datetime ± Duration` keeps the `Date/Datetime` dtype.
+///
+/// Returns `None` if not both dtypes are temporal (the supertype rules apply in that case) and
+/// an error if the operation is not defined for the dtypes.
+pub fn temporal_coercion(
+    lhs: &DataType,
+    rhs: &DataType,
+    op: TemporalOp,
+) -> PolarsResult<Option<TemporalCoercion>> {
+    use DataType::*;
+    if !(lhs.is_temporal() && rhs.is_temporal()) {
+        return Ok(None);
+    }
+    let invalid = || polars_err!(InvalidOperation: "cannot {} `{}` and `{}`", op, lhs, rhs);
+
+    let coercion = match (lhs, rhs, op) {
+        (Time, Time, TemporalOp::Compare) => TemporalCoercion::new(Time, Time, Boolean),
+        (Date, Date, TemporalOp::Compare) => TemporalCoercion::new(Date, Date, Boolean),
+        (Date, Date, TemporalOp::Sub) => {
+            let dt = Datetime(TimeUnit::Milliseconds, None);
+            TemporalCoercion::new(dt.clone(), dt, Duration(TimeUnit::Milliseconds))
+        },
+        (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r), TemporalOp::Sub | TemporalOp::Compare) => {
+            let tu = get_time_units(tu_l, tu_r);
+            let dt = Datetime(tu, common_time_zone(op, tz_l, tz_r)?);
+            let output = if op == TemporalOp::Sub {
+                Duration(tu)
+            } else {
+                Boolean
+            };
+            TemporalCoercion::new(dt.clone(), dt, output)
+        },
+        (Date, Datetime(tu, tz), TemporalOp::Sub | TemporalOp::Compare)
+        | (Datetime(tu, tz), Date, TemporalOp::Sub | TemporalOp::Compare) => {
+            let dt = Datetime(*tu, tz.clone());
+            let output = if op == TemporalOp::Sub {
+                Duration(*tu)
+            } else {
+                Boolean
+            };
+            TemporalCoercion::new(dt.clone(), dt, output)
+        },
+        (Datetime(tu_l, tz), Duration(tu_r), TemporalOp::Add | TemporalOp::Sub) => {
+            let tu = get_time_units(tu_l, tu_r);
+            let dt = Datetime(tu, tz.clone());
+            TemporalCoercion::new(dt.clone(), Duration(tu), dt)
+        },
+        (Duration(tu_l), Datetime(tu_r, tz), TemporalOp::Add) => {
+            let tu = get_time_units(tu_l, tu_r);
+            let dt = Datetime(tu, tz.clone());
+            TemporalCoercion::new(Duration(tu), dt.clone(), dt)
+        },
+        (Date, Duration(tu), TemporalOp::Add | TemporalOp::Sub) => {
+            TemporalCoercion::new(Date, Duration(*tu), Date)
+        },
+        (Duration(tu), Date, TemporalOp::Add) => TemporalCoercion::new(Duration(*tu), Date, Date),
+        (Duration(tu_l), Duration(tu_r), _) => {
+            let tu = get_time_units(tu_l, tu_r);
+            let output = if op == TemporalOp::Compare {
+                Boolean
+            } else {
+                Duration(tu)
+            };
+            TemporalCoercion::new(Duration(tu), Duration(tu), output)
+        },
+        _ => return Err(invalid()),
+    };
+    Ok(Some(coercion))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_temporal_coercion() -> PolarsResult<()> {
+        use DataType::*;
+        let ms = TimeUnit::Milliseconds;
+        let ns = TimeUnit::Nanoseconds;
+        let tz = Some("Europe/Amsterdam".to_string());
+
+        let c = temporal_coercion(&Date, &Datetime(ns, None), TemporalOp::Sub)?.unwrap();
+        assert_eq!(c.lhs, Datetime(ns, None));
+        assert_eq!(c.output, Duration(ns));
+
+        let c = temporal_coercion(&Datetime(ns, tz.clone()), &Duration(ms), TemporalOp::Add)?;
+        assert_eq!(c.unwrap().output, Datetime(ms, tz.clone()));
+
+        assert!(
+            temporal_coercion(&Datetime(ns, tz), &Datetime(ns, None), TemporalOp::Sub).is_err()
+        );
+        assert!(temporal_coercion(&Date, &Date, TemporalOp::Add).is_err());
+        assert!(temporal_coercion(&Date, &Int32, TemporalOp::Add)?.is_none());
+        Ok(())
+    }
+}
@@ -45,6 +45,57 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-datetime", feature = "dtype-struct"))]
+    fn test_write_logical_and_nested() -> PolarsResult<()> {
+        let dt = Series::new("dt", &[1_500i64, 2_000_000])
+            .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))?;
+        let list = Series::new(
+            "list",
+            &[Series::new("", &[1i64, 2]), Series::new("", &[3i64])],
+        );
+        let fields = [Series::new("a", &[1u8, 2]), Series::new("b", &["x", "y"])];
+        let s = StructChunked::new("struct", &fields)?.into_series();
+        let df = DataFrame::new(vec![Series::new("i8", &[1i8, -1]), dt, list, s])?;
+        // multiple chunks are written as multiple blocks
+        let mut write_df = df.vstack(&df)?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&mut write_df)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        assert_eq!(read_df.height(), 4);
+        assert_eq!(read_df.column("i8")?.dtype(), &DataType::Int32);
+        assert_eq!(
+            read_df.column("dt")?.dtype(),
+            &DataType::Datetime(TimeUnit::Microseconds, None)
+        );
+        assert_eq!(read_df.column("dt")?.datetime()?.get(1), Some(2_000));
+        assert!(read_df
+            .column("list")?
+            .equals_missing(write_df.column("list")?));
+        let fields = read_df.column("struct")?.struct_()?.fields().to_vec();
+        assert_eq!(fields[0].dtype(), &DataType::Int32);
+        assert_eq!(fields[1].dtype(), &DataType::String);
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf)
+            .with_datetime_unit(Some(TimeUnit::Milliseconds))
+            .with_local_timestamps(false)
+            .finish(&mut write_df)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        assert_eq!(
+            read_df.column("dt")?.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".into()))
+        );
+        assert_eq!(read_df.column("dt")?.datetime()?.get(1), Some(2));
+
+        Ok(())
+    }
+
     #[test]
     fn test_with_projection() -> PolarsResult<()> {
         let mut df = df!(
@@ -10,6 +10,10 @@ use super::*;
 ///
 /// [Apache Avro]: https://avro.apache.org
 ///
+/// Dates, datetimes and decimals are written with their Avro logical types, lists and structs
+/// as arrays and records. Types that Avro lacks are widened: small and unsigned integers to
+/// `int` or `long` and categoricals to strings.
+///
 /// # Example
 ///
 /// ```
@@ -30,6 +34,8 @@ pub struct AvroWriter<W> {
     writer: W,
     compression: Option<AvroCompression>,
     name: String,
+    datetime_unit: Option<TimeUnit>,
+    local_timestamps: bool,
 }
 
 impl<W> AvroWriter<W>
@@ -46,6 +52,70 @@ where
         self.name = name;
         self
     }
+
+    /// Set the unit that datetimes are written with, either milliseconds or microseconds.
+    /// Defaults to the unit of the column, where nanoseconds are written as microseconds as
+    /// Avro has no nanosecond timestamps.
+    pub fn with_datetime_unit(mut self, datetime_unit: Option<TimeUnit>) -> Self {
+        self.datetime_unit = datetime_unit;
+        self
+    }
+
+    /// Write datetimes without a time zone with the `local-timestamp` logical types, or with
+    /// the `timestamp` logical types as if they were in UTC. The latter are understood by more
+    /// readers. Defaults to `true`.
+    pub fn with_local_timestamps(mut self, local_timestamps: bool) -> Self {
+        self.local_timestamps = local_timestamps;
+        self
+    }
+}
+
+/// The dtype a column of `dtype` is written as. Avro has no small or unsigned integers, no
+/// categoricals and no nanosecond timestamps.
+fn avro_dtype(dtype: &DataType, datetime_unit: Option<TimeUnit>) -> DataType {
+    match dtype {
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => {
+            let tu = match (datetime_unit, tu) {
+                (Some(tu), _) => tu,
+                (None, TimeUnit::Nanoseconds) => TimeUnit::Microseconds,
+                (None, tu) => *tu,
+            };
+            DataType::Datetime(tu, tz.clone())
+        },
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(avro_dtype(inner, datetime_unit))),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name(), avro_dtype(f.data_type(), datetime_unit)))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// Mark the timestamps without a time zone as UTC timestamps.
+fn to_utc(data_type: &ArrowDataType) -> ArrowDataType {
+    match data_type {
+        ArrowDataType::Timestamp(tu, None) => ArrowDataType::Timestamp(*tu, Some("UTC".into())),
+        ArrowDataType::LargeList(field) => ArrowDataType::LargeList(Box::new(ArrowField::new(
+            field.name.clone(),
+            to_utc(field.data_type()),
+            field.is_nullable,
+        ))),
+        ArrowDataType::Struct(fields) => ArrowDataType::Struct(
+            fields
+                .iter()
+                .map(|f| ArrowField::new(f.name.clone(), to_utc(f.data_type()), f.is_nullable))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -57,12 +127,39 @@ where
             writer,
             compression: None,
             name: "".to_string(),
+            datetime_unit: None,
+            local_timestamps: true,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = df.schema().to_arrow(false);
+        polars_ensure!(
+            self.datetime_unit != Some(TimeUnit::Nanoseconds),
+            InvalidOperation: "avro does not support nanosecond timestamps"
+        );
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                let dtype = avro_dtype(s.dtype(), self.datetime_unit);
+                if &dtype == s.dtype() {
+                    Ok(s.clone())
+                } else {
+                    s.strict_cast(&dtype)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let df = DataFrame::new_no_checks(columns);
+
+        let mut schema = df.schema().to_arrow(false);
+        if !self.local_timestamps {
+            for field in schema.fields.iter_mut() {
+                field.data_type = to_utc(field.data_type());
+            }
+        }
         let record = write::to_record(&schema, self.name.clone())?;
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            .map_err(to_compute_err)?;
 
         let mut data = vec![];
         let mut compressed_block = avro_schema::file::CompressedBlock::default();
@@ -80,9 +177,6 @@ where
                 avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
                     .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
             avro_schema::write::write_block(&mut self.writer, &compressed_block)
                 .map_err(to_compute_err)?;
             // reuse block for next iteration.
@@ -3,6 +3,7 @@ use std::io::Read;
 #[cfg(feature = "aws")]
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 
 #[cfg(feature = "aws")]
 use object_store::aws::AmazonS3Builder;
@@ -63,13 +64,23 @@ pub struct CloudOptions {
     azure: Option<Configs<AzureConfigKey>>,
     #[cfg(feature = "gcp")]
     gcp: Option<Configs<GoogleConfigKey>>,
+    /// The maximum number of times a failed request is retried.
     pub max_retries: usize,
+    /// The maximum time since the first attempt of a request after which it is not retried.
+    pub retry_timeout: Duration,
+    /// The time to wait before the first retry. The wait time doubles with every retry.
+    pub init_backoff: Duration,
+    /// The maximum time to wait before a retry.
+    pub max_backoff: Duration,
 }
 
 impl Default for CloudOptions {
     fn default() -> Self {
         Self {
             max_retries: 2,
+            retry_timeout: Duration::from_secs(10),
+            init_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(15),
             #[cfg(feature = "aws")]
             aws: Default::default(),
             #[cfg(feature = "azure")]
@@ -155,14 +166,6 @@ impl FromStr for CloudType {
         polars_bail!(ComputeError: "at least one of the cloud features must be enabled");
     }
 }
-#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
-fn get_retry_config(max_retries: usize) -> RetryConfig {
-    RetryConfig {
-        backoff: BackoffConfig::default(),
-        max_retries,
-        retry_timeout: std::time::Duration::from_secs(10),
-    }
-}
 
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
 pub(super) fn get_client_options() -> ClientOptions {
@@ -210,6 +213,36 @@ fn read_config(
 }
 
 impl CloudOptions {
+    /// Set the retry policy of failed requests: retry at most `max_retries` times within
+    /// `retry_timeout` of the first attempt, waiting between `init_backoff` and `max_backoff`
+    /// with exponential backoff.
+    pub fn with_retry_policy(
+        mut self,
+        max_retries: usize,
+        retry_timeout: Duration,
+        init_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        self.max_retries = max_retries;
+        self.retry_timeout = retry_timeout;
+        self.init_backoff = init_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            backoff: BackoffConfig {
+                init_backoff: self.init_backoff,
+                max_backoff: self.max_backoff,
+                base: 2.0,
+            },
+            max_retries: self.max_retries,
+            retry_timeout: self.retry_timeout,
+        }
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -305,7 +338,7 @@ impl CloudOptions {
 
         builder
             .with_client_options(get_client_options())
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
@@ -339,7 +372,7 @@ impl CloudOptions {
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
@@ -373,7 +406,7 @@ impl CloudOptions {
         builder
             .with_client_options(get_client_options())
             .with_url(url)
-            .with_retry(get_retry_config(self.max_retries))
+            .with_retry(self.retry_config())
             .build()
             .map_err(to_compute_err)
     }
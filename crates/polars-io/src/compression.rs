@@ -0,0 +1,199 @@
+//! Transparent decompression of the inputs of the readers. The compression is detected by the
+//! magic bytes at the start of the input.
+use std::io::{Read, Seek, SeekFrom};
+
+use polars_core::prelude::*;
+
+use crate::mmap::ReaderBytes;
+
+// magic numbers
+const GZIP: [u8; 2] = [31, 139];
+const ZLIB0: [u8; 2] = [0x78, 0x01];
+const ZLIB1: [u8; 2] = [0x78, 0x9C];
+const ZLIB2: [u8; 2] = [0x78, 0xDA];
+const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+const BZIP2: [u8; 3] = [b'B', b'Z', b'h'];
+// the start of the first block, which follows the block size digit of the bzip2 header
+const BZIP2_BLOCK: [u8; 6] = [0x31, 0x41, 0x59, 0x26, 0x53, 0x59];
+/// The number of bytes needed to detect any of the supported compressions.
+const MAGIC_LEN: usize = 10;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SupportedCompression {
+    Gzip,
+    Zlib,
+    Zstd,
+    Bzip2,
+}
+
+impl SupportedCompression {
+    /// Detect the compression from the magic bytes at the start of `bytes`.
+    pub fn check(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&GZIP) {
+            Some(Self::Gzip)
+        } else if bytes.starts_with(&ZLIB0)
+            || bytes.starts_with(&ZLIB1)
+            || bytes.starts_with(&ZLIB2)
+        {
+            Some(Self::Zlib)
+        } else if bytes.starts_with(&ZSTD) {
+            Some(Self::Zstd)
+        } else if bytes.len() >= MAGIC_LEN
+            && bytes.starts_with(&BZIP2)
+            && bytes[3].is_ascii_digit()
+            && bytes[3] != b'0'
+            && bytes[4..MAGIC_LEN] == BZIP2_BLOCK
+        {
+            Some(Self::Bzip2)
+        } else {
+            None
+        }
+    }
+}
+
+/// Check if the bytes start with the magic bytes of a supported compression.
+pub fn is_compressed(bytes: &[u8]) -> bool {
+    SupportedCompression::check(bytes).is_some()
+}
+
+/// Wrap `reader` in a decoder that decompresses it on the fly.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub fn decoder<'a, R: Read + 'a>(
+    reader: R,
+    compression: SupportedCompression,
+) -> PolarsResult<Box<dyn Read + 'a>> {
+    Ok(match compression {
+        SupportedCompression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+        SupportedCompression::Zlib => Box::new(flate2::read::ZlibDecoder::new(reader)),
+        SupportedCompression::Zstd => Box::new(zstd::Decoder::new(reader)?),
+        SupportedCompression::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(reader)),
+    })
+}
+
+#[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+pub fn decoder<'a, R: Read + 'a>(
+    _reader: R,
+    compression: SupportedCompression,
+) -> PolarsResult<Box<dyn Read + 'a>> {
+    polars_bail!(
+        ComputeError: "cannot read {:?} compressed data; \
+        compile with feature 'decompress' or 'decompress-fast'", compression
+    )
+}
+
+/// Decompress `bytes` completely if they are compressed, otherwise return `None`.
+pub fn maybe_decompress_bytes(bytes: &[u8]) -> PolarsResult<Option<Vec<u8>>> {
+    match SupportedCompression::check(bytes) {
+        Some(compression) => {
+            // decompression in a preallocated buffer does not work with zlib-ng
+            // and will put the original compressed data in the buffer.
+            let mut out = Vec::new();
+            decoder(bytes, compression)?.read_to_end(&mut out)?;
+            Ok(Some(out))
+        },
+        None => Ok(None),
+    }
+}
+
+/// Replace compressed [`ReaderBytes`] by their decompressed bytes.
+pub fn maybe_decompress_reader_bytes(reader_bytes: ReaderBytes) -> PolarsResult<ReaderBytes> {
+    Ok(match maybe_decompress_bytes(&reader_bytes)? {
+        Some(out) => ReaderBytes::Owned(out),
+        None => reader_bytes,
+    })
+}
+
+/// Peek at the magic bytes at the current position of `reader` and restore the position.
+fn peek_compression<R: Read + Seek>(reader: &mut R) -> PolarsResult<Option<SupportedCompression>> {
+    let pos = reader.stream_position()?;
+    let mut magic_nr = [0u8; MAGIC_LEN];
+    let mut len = 0;
+    while len < magic_nr.len() {
+        match reader.read(&mut magic_nr[len..])? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    reader.seek(SeekFrom::Start(pos))?;
+    Ok(SupportedCompression::check(&magic_nr[..len]))
+}
+
+/// Decompress the rest of `reader` if it is compressed, otherwise return `None` and leave
+/// the position of `reader` untouched.
+pub fn maybe_decompress_reader<R: Read + Seek>(reader: &mut R) -> PolarsResult<Option<Vec<u8>>> {
+    match peek_compression(reader)? {
+        Some(compression) => {
+            let mut out = Vec::new();
+            decoder(reader, compression)?.read_to_end(&mut out)?;
+            Ok(Some(out))
+        },
+        None => Ok(None),
+    }
+}
+
+/// Return a reader that decompresses `reader` on the fly if it is compressed.
+pub fn decompressed_reader<'a, R: Read + Seek + 'a>(
+    mut reader: R,
+) -> PolarsResult<Box<dyn Read + 'a>> {
+    match peek_compression(&mut reader)? {
+        Some(compression) => decoder(reader, compression),
+        None => Ok(Box::new(reader)),
+    }
+}
+
+#[cfg(all(test, any(feature = "decompress", feature = "decompress-fast")))]
+mod test {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+
+    fn compress(data: &[u8], compression: SupportedCompression) -> Vec<u8> {
+        match compression {
+            SupportedCompression::Gzip => {
+                let mut enc = flate2::write::GzEncoder::new(vec![], Default::default());
+                enc.write_all(data).unwrap();
+                enc.finish().unwrap()
+            },
+            SupportedCompression::Zlib => {
+                let mut enc = flate2::write::ZlibEncoder::new(vec![], Default::default());
+                enc.write_all(data).unwrap();
+                enc.finish().unwrap()
+            },
+            SupportedCompression::Zstd => zstd::encode_all(data, 0).unwrap(),
+            SupportedCompression::Bzip2 => {
+                let mut enc = bzip2::write::BzEncoder::new(vec![], Default::default());
+                enc.write_all(data).unwrap();
+                enc.finish().unwrap()
+            },
+        }
+    }
+
+    #[test]
+    fn test_decompress_roundtrip() -> PolarsResult<()> {
+        let data = b"a,b\n1,2\n3,4\n";
+        assert_eq!(maybe_decompress_bytes(data)?, None);
+
+        for compression in [
+            SupportedCompression::Gzip,
+            SupportedCompression::Zlib,
+            SupportedCompression::Zstd,
+            SupportedCompression::Bzip2,
+        ] {
+            let compressed = compress(data, compression);
+            assert_eq!(SupportedCompression::check(&compressed), Some(compression));
+            assert_eq!(maybe_decompress_bytes(&compressed)?.unwrap(), data);
+
+            let mut reader = Cursor::new(compressed);
+            assert_eq!(maybe_decompress_reader(&mut reader)?.unwrap(), data);
+
+            let mut out = vec![];
+            decompressed_reader(Cursor::new(compress(data, compression)))?.read_to_end(&mut out)?;
+            assert_eq!(out, data);
+        }
+
+        let mut reader = Cursor::new(data.to_vec());
+        assert!(maybe_decompress_reader(&mut reader)?.is_none());
+        assert_eq!(reader.position(), 0);
+        Ok(())
+    }
+}
@@ -57,6 +57,22 @@ impl PrimitiveParser for Int64Type {
     }
 }
 
+/// A float column whose values use a comma as the decimal separator.
+pub(crate) struct DecimalFloatField<T: PolarsNumericType> {
+    builder: PrimitiveChunkedBuilder<T>,
+    // The current value with the comma replaced by a dot.
+    scratch: Vec<u8>,
+}
+
+impl<T: PolarsNumericType> DecimalFloatField<T> {
+    fn new(name: &str, capacity: usize) -> Self {
+        Self {
+            builder: PrimitiveChunkedBuilder::new(name, capacity),
+            scratch: vec![],
+        }
+    }
+}
+
 trait ParsedBuffer {
     fn parse_bytes(
         &mut self,
@@ -120,6 +136,38 @@ where
     }
 }
 
+impl<T> ParsedBuffer for DecimalFloatField<T>
+where
+    T: PolarsNumericType + PrimitiveParser,
+{
+    #[inline]
+    fn parse_bytes(
+        &mut self,
+        bytes: &[u8],
+        ignore_errors: bool,
+        needs_escaping: bool,
+        missing_is_null: bool,
+        _time_unit: Option<TimeUnit>,
+    ) -> PolarsResult<()> {
+        let bytes = if needs_escaping && bytes.len() >= 2 {
+            &bytes[1..bytes.len() - 1]
+        } else {
+            bytes
+        };
+        let bytes = match memchr::memchr(b',', bytes) {
+            Some(pos) => {
+                self.scratch.clear();
+                self.scratch.extend_from_slice(bytes);
+                self.scratch[pos] = b'.';
+                self.scratch.as_slice()
+            },
+            None => bytes,
+        };
+        self.builder
+            .parse_bytes(bytes, ignore_errors, false, missing_is_null, None)
+    }
+}
+
 pub(crate) struct Utf8Field {
     name: String,
     // buffer that holds the string data
@@ -487,6 +535,7 @@ pub(crate) fn init_buffers(
     quote_char: Option<u8>,
     encoding: CsvEncoding,
     ignore_errors: bool,
+    decimal_comma: bool,
 ) -> PolarsResult<Vec<Buffer>> {
     // we keep track of the string columns we have seen so that we can increment the index
     let mut str_index = 0;
@@ -508,6 +557,8 @@ pub(crate) fn init_buffers(
                 &DataType::Int64 => Buffer::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::UInt32 => Buffer::UInt32(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::UInt64 => Buffer::UInt64(PrimitiveChunkedBuilder::new(name, capacity)),
+                &DataType::Float32 if decimal_comma => Buffer::DecimalFloat32(DecimalFloatField::new(name, capacity)),
+                &DataType::Float64 if decimal_comma => Buffer::DecimalFloat64(DecimalFloatField::new(name, capacity)),
                 &DataType::Float32 => Buffer::Float32(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::Float64 => Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::String => Buffer::Utf8(Utf8Field::new(
@@ -552,6 +603,8 @@ pub(crate) enum Buffer {
     UInt64(PrimitiveChunkedBuilder<UInt64Type>),
     Float32(PrimitiveChunkedBuilder<Float32Type>),
     Float64(PrimitiveChunkedBuilder<Float64Type>),
+    DecimalFloat32(DecimalFloatField<Float32Type>),
+    DecimalFloat64(DecimalFloatField<Float64Type>),
     /// Stores the Utf8 fields and the total string length seen for that column
     Utf8(Utf8Field),
     #[cfg(feature = "dtype-datetime")]
@@ -576,6 +629,8 @@ impl Buffer {
             Buffer::UInt64(v) => v.finish().into_series(),
             Buffer::Float32(v) => v.finish().into_series(),
             Buffer::Float64(v) => v.finish().into_series(),
+            Buffer::DecimalFloat32(v) => v.builder.finish().into_series(),
+            Buffer::DecimalFloat64(v) => v.builder.finish().into_series(),
             #[cfg(feature = "dtype-datetime")]
             Buffer::Datetime {
                 buf,
@@ -657,6 +712,8 @@ impl Buffer {
             Buffer::UInt64(v) => v.append_null(),
             Buffer::Float32(v) => v.append_null(),
             Buffer::Float64(v) => v.append_null(),
+            Buffer::DecimalFloat32(v) => v.builder.append_null(),
+            Buffer::DecimalFloat64(v) => v.builder.append_null(),
             Buffer::Utf8(v) => {
                 v.offsets.push(v.data.len() as i64);
                 v.validity.push(valid);
@@ -686,8 +743,8 @@ impl Buffer {
             Buffer::Int64(_) => DataType::Int64,
             Buffer::UInt32(_) => DataType::UInt32,
             Buffer::UInt64(_) => DataType::UInt64,
-            Buffer::Float32(_) => DataType::Float32,
-            Buffer::Float64(_) => DataType::Float64,
+            Buffer::Float32(_) | Buffer::DecimalFloat32(_) => DataType::Float32,
+            Buffer::Float64(_) | Buffer::DecimalFloat64(_) => DataType::Float64,
             Buffer::Utf8(_) => DataType::String,
             #[cfg(feature = "dtype-datetime")]
             Buffer::Datetime { time_unit, .. } => DataType::Datetime(*time_unit, None),
@@ -773,6 +830,22 @@ impl Buffer {
                 missing_is_null,
                 None,
             ),
+            DecimalFloat32(buf) => <DecimalFloatField<Float32Type> as ParsedBuffer>::parse_bytes(
+                buf,
+                bytes,
+                ignore_errors,
+                needs_escaping,
+                missing_is_null,
+                None,
+            ),
+            DecimalFloat64(buf) => <DecimalFloatField<Float64Type> as ParsedBuffer>::parse_bytes(
+                buf,
+                bytes,
+                ignore_errors,
+                needs_escaping,
+                missing_is_null,
+                None,
+            ),
             Utf8(buf) => <Utf8Field as ParsedBuffer>::parse_bytes(
                 buf,
                 bytes,
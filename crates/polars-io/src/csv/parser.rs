@@ -1,4 +1,4 @@
-use memchr::memchr2_iter;
+use memchr::{memchr2_iter, memchr_iter};
 use num_traits::Pow;
 use polars_core::prelude::*;
 
@@ -38,6 +38,38 @@ pub(crate) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
     Some(pos)
 }
 
+/// Find the start of the first line that begins after `search_pos`, where `input` must start at
+/// the start of a line.
+///
+/// This doesn't guess from the number of fields like [`next_line_position`]. It determines whether
+/// `search_pos` lies in a quoted field by counting the quote chars before it, and skips the line
+/// endings in quoted fields. So lines are split exactly like [`SplitLines`] splits them, also if
+/// quoted fields contain line endings.
+pub(crate) fn next_line_position_quoted(
+    input: &[u8],
+    search_pos: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> Option<usize> {
+    let rest = input.get(search_pos..)?;
+    let pos = match quote_char {
+        Some(quote_char) => {
+            let mut in_field = memchr_iter(quote_char, &input[..search_pos]).count() % 2 == 1;
+            memchr2_iter(quote_char, eol_char, rest).find(|&i| {
+                if rest[i] == quote_char {
+                    in_field = !in_field;
+                    false
+                } else {
+                    !in_field
+                }
+            })?
+        },
+        None => memchr::memchr(eol_char, rest)?,
+    };
+    let pos = search_pos + pos + 1;
+    (pos < input.len()).then_some(pos)
+}
+
 /// Find the nearest next line position that is not embedded in a String field.
 pub(crate) fn next_line_position(
     mut input: &[u8],
@@ -574,4 +606,30 @@ mod test {
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_next_line_position_quoted() {
+        let input = "1,\"a\n2,b\n3,c\"\n4,\"\"\"d\"\n5,e\n".as_bytes();
+        // the line endings in the quoted field are skipped
+        assert_eq!(
+            next_line_position_quoted(input, 0, Some(b'"'), b'\n'),
+            Some(14)
+        );
+        assert_eq!(
+            next_line_position_quoted(input, 4, Some(b'"'), b'\n'),
+            Some(14)
+        );
+        // escaped quotes don't end the field
+        assert_eq!(
+            next_line_position_quoted(input, 17, Some(b'"'), b'\n'),
+            Some(22)
+        );
+        // there is no line after the last line ending
+        assert_eq!(
+            next_line_position_quoted(input, 25, Some(b'"'), b'\n'),
+            None
+        );
+        // without quoting every line ending counts
+        assert_eq!(next_line_position_quoted(input, 0, None, b'\n'), Some(5));
+    }
 }
@@ -3,7 +3,14 @@ use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
 };
-use crate::csv::utils::infer_file_schema;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::csv::utils::decompress;
+use crate::csv::utils::{
+    compile_dtype_overwrite_regex, infer_file_schema, infer_file_schema_with_report,
+    SchemaInference,
+};
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::mmap::ReaderBytes;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -21,7 +28,8 @@ pub enum NullValues {
     AllColumnsSingle(String),
     /// Multiple values that are used for all columns
     AllColumns(Vec<String>),
-    /// Tuples that map column names to null value of that column
+    /// Tuples that map column names to null value of that column. A column may appear in
+    /// several tuples to have several null values.
     Named(Vec<(String, String)>),
 }
 
@@ -57,8 +65,8 @@ pub(super) enum NullValuesCompiled {
     AllColumnsSingle(String),
     // Multiple null values that are null for all columns
     AllColumns(Vec<String>),
-    /// A different null value per column, computed from `NullValues::Named`
-    Columns(Vec<String>),
+    /// Different null values per column, computed from `NullValues::Named`
+    Columns(Vec<Vec<String>>),
 }
 
 impl NullValuesCompiled {
@@ -82,7 +90,7 @@ impl NullValuesCompiled {
             AllColumns(v) => v.iter().any(|v| v.as_bytes() == field),
             Columns(v) => {
                 debug_assert!(index < v.len());
-                v.get_unchecked(index).as_bytes() == field
+                v.get_unchecked(index).iter().any(|v| v.as_bytes() == field)
             },
         }
     }
@@ -94,10 +102,14 @@ impl NullValues {
             NullValues::AllColumnsSingle(v) => NullValuesCompiled::AllColumnsSingle(v),
             NullValues::AllColumns(v) => NullValuesCompiled::AllColumns(v),
             NullValues::Named(v) => {
-                let mut null_values = vec!["".to_string(); schema.len()];
+                let mut null_values: Vec<Vec<String>> = vec![vec![]; schema.len()];
                 for (name, null_value) in v {
                     let i = schema.try_index_of(&name)?;
-                    null_values[i] = null_value;
+                    null_values[i].push(null_value);
+                }
+                // Columns without null values of their own only treat empty fields as null.
+                for nv in null_values.iter_mut().filter(|nv| nv.is_empty()) {
+                    nv.push("".to_string());
                 }
                 NullValuesCompiled::Columns(null_values)
             },
@@ -143,6 +155,7 @@ where
     path: Option<PathBuf>,
     schema_overwrite: Option<SchemaRef>,
     dtype_overwrite: Option<&'a [DataType]>,
+    dtype_overwrite_regex: Option<Vec<(String, DataType)>>,
     sample_size: usize,
     chunk_size: usize,
     comment_prefix: Option<CommentPrefix>,
@@ -151,6 +164,7 @@ where
     quote_char: Option<u8>,
     skip_rows_after_header: usize,
     try_parse_dates: bool,
+    decimal_comma: bool,
     row_index: Option<RowIndex>,
     /// Aggregates chunk afterwards to a single chunk.
     rechunk: bool,
@@ -287,6 +301,14 @@ where
         self
     }
 
+    /// Overwrite the dtype of every column whose name matches a regex. This is useful for wide
+    /// files where many columns share a prefix. The first matching regex wins, and the dtypes
+    /// given by [`CsvReader::with_dtypes`] take precedence.
+    pub fn with_dtypes_regex(mut self, dtypes: Option<Vec<(String, DataType)>>) -> Self {
+        self.dtype_overwrite_regex = dtypes;
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     ///
     /// # Arguments
@@ -360,6 +382,13 @@ where
         self
     }
 
+    /// Parse floats with a comma as the decimal separator, e.g. `3,14`, as is common in European
+    /// locales. This can't be combined with a comma as the field separator.
+    pub fn with_decimal_comma(mut self, toggle: bool) -> Self {
+        self.decimal_comma = toggle;
+        self
+    }
+
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
         self
@@ -406,6 +435,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.n_threads,
             schema,
             self.dtype_overwrite,
+            self.dtype_overwrite_regex.as_deref(),
             self.sample_size,
             self.chunk_size,
             self.low_memory,
@@ -419,11 +449,77 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_after_header,
             std::mem::take(&mut self.row_index),
             self.try_parse_dates,
+            self.decimal_comma,
             self.raise_if_empty,
             self.truncate_ragged_lines,
         )
     }
 
+    /// Infer the schema of the file like [`CsvReader::finish`] does, and report per column which
+    /// dtypes its values were inferred as and which rows conflicted with the most common dtype.
+    pub fn infer_schema_report(mut self) -> PolarsResult<SchemaInference> {
+        let separator = self.separator.unwrap_or(b',');
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let reader_bytes = match decompress(
+            &reader_bytes,
+            self.n_rows,
+            separator,
+            self.quote_char,
+            self.eol_char,
+        ) {
+            Some(b) => ReaderBytes::Owned(b),
+            None => reader_bytes,
+        };
+        let dtype_overwrite_regex = self
+            .dtype_overwrite_regex
+            .as_deref()
+            .map(compile_dtype_overwrite_regex)
+            .transpose()?;
+
+        infer_file_schema_with_report(
+            &reader_bytes,
+            separator,
+            self.max_records,
+            self.has_header,
+            self.schema_overwrite.as_deref(),
+            dtype_overwrite_regex.as_deref(),
+            &mut self.skip_rows_before_header,
+            self.skip_rows_after_header,
+            self.comment_prefix.as_ref(),
+            self.quote_char,
+            self.eol_char,
+            self.null_values.as_ref(),
+            self.try_parse_dates,
+            self.decimal_comma,
+            self.raise_if_empty,
+        )
+    }
+
+    /// Add the columns of `schema` that match a dtype regex to the schema overwrite, so that they
+    /// are cast like the columns of which the dtype is set by name.
+    fn resolve_dtype_overwrite_regex(&mut self, schema: &Schema) -> PolarsResult<()> {
+        let Some(dtypes) = self.dtype_overwrite_regex.take() else {
+            return Ok(());
+        };
+        let dtypes = compile_dtype_overwrite_regex(&dtypes)?;
+        let mut overwrite = self
+            .schema_overwrite
+            .as_deref()
+            .cloned()
+            .unwrap_or_default();
+        for name in schema.iter_names() {
+            if overwrite.get(name).is_some() {
+                continue;
+            }
+            if let Some((_, dtype)) = dtypes.iter().find(|(re, _)| re.is_match(name)) {
+                overwrite.with_column(name.clone(), dtype.clone());
+            }
+        }
+        self.schema_overwrite = Some(Arc::new(overwrite));
+        Ok(())
+    }
+
     fn prepare_schema_overwrite(
         &self,
         overwriting_schema: &Schema,
@@ -517,6 +613,20 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
             Some(schema) => Ok(to_batched_owned_mmap(self, schema)),
             None => {
                 let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                // only decompress the rows needed to infer the schema
+                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+                let reader_bytes = match decompress(
+                    &reader_bytes,
+                    self.max_records.map(|n| {
+                        n + self.skip_rows_before_header + self.skip_rows_after_header + 1
+                    }),
+                    self.separator.unwrap_or(b','),
+                    self.quote_char,
+                    self.eol_char,
+                ) {
+                    Some(b) => ReaderBytes::Owned(b),
+                    None => reader_bytes,
+                };
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
@@ -524,6 +634,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.max_records,
                     self.has_header,
                     None,
+                    None,
                     &mut self.skip_rows_before_header,
                     self.skip_rows_after_header,
                     self.comment_prefix.as_ref(),
@@ -531,8 +642,10 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    self.decimal_comma,
                     self.raise_if_empty,
                 )?;
+                self.resolve_dtype_overwrite_regex(&inferred_schema)?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_mmap(self, schema))
             },
@@ -546,6 +659,20 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
             Some(schema) => Ok(to_batched_owned_read(self, schema)),
             None => {
                 let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                // only decompress the rows needed to infer the schema
+                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+                let reader_bytes = match decompress(
+                    &reader_bytes,
+                    self.max_records.map(|n| {
+                        n + self.skip_rows_before_header + self.skip_rows_after_header + 1
+                    }),
+                    self.separator.unwrap_or(b','),
+                    self.quote_char,
+                    self.eol_char,
+                ) {
+                    Some(b) => ReaderBytes::Owned(b),
+                    None => reader_bytes,
+                };
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
@@ -553,6 +680,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.max_records,
                     self.has_header,
                     None,
+                    None,
                     &mut self.skip_rows_before_header,
                     self.skip_rows_after_header,
                     self.comment_prefix.as_ref(),
@@ -560,8 +688,10 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
+                    self.decimal_comma,
                     self.raise_if_empty,
                 )?;
+                self.resolve_dtype_overwrite_regex(&inferred_schema)?;
                 let schema = Arc::new(inferred_schema);
                 Ok(to_batched_owned_read(self, schema))
             },
@@ -592,6 +722,7 @@ where
             path: None,
             schema_overwrite: None,
             dtype_overwrite: None,
+            dtype_overwrite_regex: None,
             sample_size: 1024,
             chunk_size: 1 << 18,
             low_memory: false,
@@ -603,6 +734,7 @@ where
             quote_char: Some(b'"'),
             skip_rows_after_header: 0,
             try_parse_dates: false,
+            decimal_comma: false,
             row_index: None,
             raise_if_empty: true,
             truncate_ragged_lines: false,
@@ -617,12 +749,18 @@ where
 
         #[cfg(feature = "dtype-categorical")]
         let mut _cat_lock = None;
+        #[cfg(feature = "dtype-categorical")]
+        let regex_has_cat = self.dtype_overwrite_regex.as_ref().map_or(false, |dtypes| {
+            dtypes
+                .iter()
+                .any(|(_, dtype)| matches!(dtype, DataType::Categorical(_, _)))
+        });
 
         let mut df = if let Some(schema) = schema_overwrite.as_deref() {
             let (schema, to_cast, _has_cat) = self.prepare_schema_overwrite(schema)?;
 
             #[cfg(feature = "dtype-categorical")]
-            if _has_cat {
+            if _has_cat || regex_has_cat {
                 _cat_lock = Some(polars_core::StringCacheHolder::hold())
             }
 
@@ -640,7 +778,7 @@ where
                             .any(|dtype| matches!(dtype, DataType::Categorical(_, _)))
                     })
                     .unwrap_or(false);
-                if has_cat {
+                if has_cat || regex_has_cat {
                     _cat_lock = Some(polars_core::StringCacheHolder::hold())
                 }
             }
@@ -5,15 +5,12 @@ use crate::csv::CsvReader;
 use crate::mmap::MmapBytesReader;
 use crate::prelude::update_row_counts2;
 
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn get_file_chunks_iterator(
     offsets: &mut VecDeque<(usize, usize)>,
     last_pos: &mut usize,
     n_chunks: usize,
     chunk_size: usize,
     bytes: &[u8],
-    expected_fields: usize,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -24,14 +21,13 @@ pub(crate) fn get_file_chunks_iterator(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
+        let end_pos = match next_line_position_quoted(
+            &bytes[*last_pos..],
+            chunk_size,
             quote_char,
             eol_char,
         ) {
-            Some(pos) => search_pos + pos,
+            Some(pos) => *last_pos + pos,
             None => {
                 break;
             },
@@ -83,8 +79,6 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.n_chunks,
                     self.rows_per_batch * bytes_first_row,
                     self.bytes,
-                    self.expected_fields,
-                    self.separator,
                     self.quote_char,
                     self.eol_char,
                 );
@@ -105,6 +99,8 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
 impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses mmap to load data.
     pub fn batched_mmap(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        self.decompress_reader_bytes();
         let reader_bytes = self.reader_bytes.take().unwrap();
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
@@ -162,6 +158,7 @@ impl<'a> CoreReader<'a> {
             to_cast: self.to_cast,
             ignore_errors: self.ignore_errors,
             truncate_ragged_lines: self.truncate_ragged_lines,
+            decimal_comma: self.decimal_comma,
             n_rows: self.n_rows,
             encoding: self.encoding,
             separator: self.separator,
@@ -188,6 +185,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     truncate_ragged_lines: bool,
+    decimal_comma: bool,
     to_cast: Vec<Field>,
     ignore_errors: bool,
     n_rows: Option<usize>,
@@ -247,6 +245,7 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         self.null_values.as_ref(),
                         self.missing_is_null,
                         self.truncate_ragged_lines,
+                        self.decimal_comma,
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
@@ -1,20 +1,17 @@
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use super::*;
+use crate::compression::decompressed_reader;
 use crate::csv::CsvReader;
 use crate::mmap::MmapBytesReader;
 use crate::prelude::update_row_counts2;
 
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn get_offsets(
     offsets: &mut VecDeque<(usize, usize)>,
     n_chunks: usize,
     chunk_size: usize,
     bytes: &[u8],
-    expected_fields: usize,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -26,14 +23,14 @@ pub(crate) fn get_offsets(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
+        // `search_pos` may lie before `start`, if a quoted field spans several chunk sizes
+        let end_pos = match next_line_position_quoted(
+            &bytes[start..],
+            search_pos.saturating_sub(start),
             quote_char,
             eol_char,
         ) {
-            Some(pos) => search_pos + pos,
+            Some(pos) => start + pos,
             None => {
                 break;
             },
@@ -44,7 +41,8 @@ pub(crate) fn get_offsets(
 }
 
 struct ChunkReader<'a> {
-    file: &'a File,
+    /// The file, or a decoder that decompresses the file on the fly.
+    reader: Box<dyn Read + 'a>,
     buf: Vec<u8>,
     finished: bool,
     page_size: u64,
@@ -63,8 +61,10 @@ struct ChunkReader<'a> {
 }
 
 impl<'a> ChunkReader<'a> {
+    #[allow(clippy::too_many_arguments)]
     fn new(
-        file: &'a File,
+        reader: Box<dyn Read + 'a>,
+        buf: Vec<u8>,
         rows_per_batch: usize,
         expected_fields: usize,
         separator: u8,
@@ -73,8 +73,8 @@ impl<'a> ChunkReader<'a> {
         page_size: u64,
     ) -> Self {
         Self {
-            file,
-            buf: vec![],
+            reader,
+            buf,
             buf_end: 0,
             offsets: VecDeque::new(),
             finished: false,
@@ -115,7 +115,8 @@ impl<'a> ChunkReader<'a> {
 
         if self.buf.len() <= self.page_size as usize {
             let read = self
-                .file
+                .reader
+                .by_ref()
                 .take(self.page_size)
                 .read_to_end(&mut self.buf)
                 .unwrap();
@@ -141,7 +142,8 @@ impl<'a> ChunkReader<'a> {
                     break;
                 } else {
                     let read = self
-                        .file
+                        .reader
+                        .by_ref()
                         .take(self.page_size)
                         .read_to_end(&mut self.buf)
                         .unwrap();
@@ -159,7 +161,8 @@ impl<'a> ChunkReader<'a> {
         if self.buf.len() < expected_bytes {
             let to_read = expected_bytes - self.buf.len();
             let read = self
-                .file
+                .reader
+                .by_ref()
                 .take(to_read as u64)
                 .read_to_end(&mut self.buf)
                 .unwrap();
@@ -178,8 +181,6 @@ impl<'a> ChunkReader<'a> {
             self.n_chunks,
             self.rows_per_batch * bytes_first_row,
             &self.buf,
-            self.expected_fields,
-            self.separator,
             self.quote_char,
             self.eol_char,
         );
@@ -188,22 +189,58 @@ impl<'a> ChunkReader<'a> {
 }
 
 impl<'a> CoreReader<'a> {
-    /// Create a batched csv reader that uses read calls to load data.
+    /// Read the bytes up to the starting point from `reader` and return the bytes read after
+    /// the starting point.
+    fn skip_to_starting_point(
+        &self,
+        reader: &mut dyn Read,
+    ) -> PolarsResult<(Vec<u8>, Option<usize>)> {
+        // the lines before the starting point and the first data line
+        let n_lines = self.skip_rows_before_header
+            + self.has_header as usize
+            + self.skip_rows_after_header
+            + 1;
+        let mut buf = vec![];
+        loop {
+            let read = reader.by_ref().take(4096).read_to_end(&mut buf)?;
+            if read == 0 || memchr::memchr_iter(self.eol_char, &buf).count() >= n_lines {
+                break;
+            }
+        }
+        let (_, starting_point_offset) =
+            self.find_starting_point(&buf, self.quote_char, self.eol_char)?;
+        buf.drain(..starting_point_offset.unwrap_or(buf.len()));
+        Ok((buf, starting_point_offset))
+    }
+
+    /// Create a batched csv reader that uses read calls to load data. Compressed files are
+    /// decompressed on the fly.
     pub fn batched_read(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderRead<'a>> {
         let reader_bytes = self.reader_bytes.take().unwrap();
 
-        let ReaderBytes::Mapped(bytes, mut file) = &reader_bytes else {
-            unreachable!()
+        let (mut reader, skipped): (Box<dyn Read + 'a>, _) = match reader_bytes {
+            ReaderBytes::Mapped(bytes, mut file) if !is_compressed(&bytes) => {
+                let (_, starting_point_offset) =
+                    self.find_starting_point(&bytes, self.quote_char, self.eol_char)?;
+                if let Some(starting_point_offset) = starting_point_offset {
+                    file.seek(SeekFrom::Current(starting_point_offset as i64))
+                        .unwrap();
+                }
+                (Box::new(file), Some(starting_point_offset))
+            },
+            ReaderBytes::Mapped(_, file) => (decompressed_reader(file)?, None),
+            ReaderBytes::Owned(bytes) => (Box::new(Cursor::new(bytes)), None),
+            ReaderBytes::Borrowed(bytes) => (Box::new(bytes), None),
+        };
+        // The readers that cannot seek still have to skip the bytes before the starting point.
+        let (buf, starting_point_offset) = match skipped {
+            Some(starting_point_offset) => (vec![], starting_point_offset),
+            None => self.skip_to_starting_point(&mut reader)?,
         };
-        let (_, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
-        if let Some(starting_point_offset) = starting_point_offset {
-            file.seek(SeekFrom::Current(starting_point_offset as i64))
-                .unwrap();
-        }
 
         let chunk_iter = ChunkReader::new(
-            file,
+            reader,
+            buf,
             self.chunk_size,
             self.schema.len(),
             self.separator,
@@ -245,6 +282,7 @@ impl<'a> CoreReader<'a> {
             to_cast: self.to_cast,
             ignore_errors: self.ignore_errors,
             truncate_ragged_lines: self.truncate_ragged_lines,
+            decimal_comma: self.decimal_comma,
             n_rows: self.n_rows,
             encoding: self.encoding,
             separator: self.separator,
@@ -273,6 +311,7 @@ pub struct BatchedCsvReaderRead<'a> {
     to_cast: Vec<Field>,
     ignore_errors: bool,
     truncate_ragged_lines: bool,
+    decimal_comma: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
     separator: u8,
@@ -344,6 +383,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         self.null_values.as_ref(),
                         self.missing_is_null,
                         self.truncate_ragged_lines,
+                        self.decimal_comma,
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
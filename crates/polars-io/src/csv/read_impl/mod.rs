@@ -88,6 +88,40 @@ pub(crate) fn cast_columns(
     Ok(())
 }
 
+/// The dtypes that are set by a regex may not be supported by the parser. Those columns are parsed
+/// as a supported dtype and added to `to_cast`, like the dtypes of a schema overwrite.
+fn cast_regex_overwrites(
+    schema: &mut Schema,
+    dtypes: &[(regex::Regex, DataType)],
+    schema_overwrite: Option<&Schema>,
+    to_cast: &mut Vec<Field>,
+) -> PolarsResult<()> {
+    let overwritten = schema
+        .iter_fields()
+        .filter(|fld| {
+            schema_overwrite.map_or(true, |schema| schema.get(fld.name()).is_none())
+                && dtypes.iter().any(|(re, _)| re.is_match(fld.name()))
+        })
+        .collect::<Vec<_>>();
+    for fld in overwritten {
+        use DataType::*;
+        let parse_dtype = match fld.data_type() {
+            Int8 | Int16 | UInt8 | UInt16 => Int32,
+            Time => String,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, Some(_)) => String,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, None) => {
+                polars_bail!(ComputeError: "'scale' must be set when reading csv column as Decimal")
+            },
+            _ => continue,
+        };
+        schema.set_dtype(fld.name(), parse_dtype);
+        to_cast.push(fld);
+    }
+    Ok(())
+}
+
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
@@ -118,6 +152,7 @@ pub(crate) struct CoreReader<'a> {
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
     truncate_ragged_lines: bool,
+    decimal_comma: bool,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -195,6 +230,7 @@ impl<'a> CoreReader<'a> {
         n_threads: Option<usize>,
         schema_overwrite: Option<SchemaRef>,
         dtype_overwrite: Option<&'a [DataType]>,
+        dtype_overwrite_regex: Option<&[(String, DataType)]>,
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
@@ -204,10 +240,11 @@ impl<'a> CoreReader<'a> {
         null_values: Option<NullValues>,
         missing_is_null: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
-        to_cast: Vec<Field>,
+        mut to_cast: Vec<Field>,
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         try_parse_dates: bool,
+        decimal_comma: bool,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
     ) -> PolarsResult<CoreReader<'a>> {
@@ -224,6 +261,10 @@ impl<'a> CoreReader<'a> {
 
         // check if schema should be inferred
         let separator = separator.unwrap_or(b',');
+        polars_ensure!(
+            !decimal_comma || separator != b',',
+            InvalidOperation: "'decimal_comma' cannot be combined with ',' as separator"
+        );
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -239,12 +280,16 @@ impl<'a> CoreReader<'a> {
                         reader_bytes = ReaderBytes::Owned(b);
                     }
 
-                    let (inferred_schema, _, _) = infer_file_schema(
+                    let dtype_overwrite_regex = dtype_overwrite_regex
+                        .map(compile_dtype_overwrite_regex)
+                        .transpose()?;
+                    let (mut inferred_schema, _, _) = infer_file_schema(
                         &reader_bytes,
                         separator,
                         max_records,
                         has_header,
                         schema_overwrite.as_deref(),
+                        dtype_overwrite_regex.as_deref(),
                         &mut skip_rows,
                         skip_rows_after_header,
                         comment_prefix.as_ref(),
@@ -252,8 +297,17 @@ impl<'a> CoreReader<'a> {
                         eol_char,
                         null_values.as_ref(),
                         try_parse_dates,
+                        decimal_comma,
                         raise_if_empty,
                     )?;
+                    if let Some(dtypes) = dtype_overwrite_regex.as_deref() {
+                        cast_regex_overwrites(
+                            &mut inferred_schema,
+                            dtypes,
+                            schema_overwrite.as_deref(),
+                            &mut to_cast,
+                        )?;
+                    }
                     Arc::new(inferred_schema)
                 }
             },
@@ -308,9 +362,26 @@ impl<'a> CoreReader<'a> {
             to_cast,
             row_index,
             truncate_ragged_lines,
+            decimal_comma,
         })
     }
 
+    /// Decompress the bytes if they are compressed. If the schema was not given they were already
+    /// decompressed to infer it.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn decompress_reader_bytes(&mut self) {
+        let reader_bytes = self.reader_bytes.as_ref().unwrap();
+        if let Some(b) = decompress(
+            reader_bytes,
+            self.n_rows,
+            self.separator,
+            self.quote_char,
+            self.eol_char,
+        ) {
+            self.reader_bytes = Some(ReaderBytes::Owned(b));
+        }
+    }
+
     fn find_starting_point<'b>(
         &self,
         mut bytes: &'b [u8],
@@ -345,10 +416,7 @@ impl<'a> CoreReader<'a> {
                 let pos = if is_comment_line(bytes, self.comment_prefix.as_ref()) {
                     next_line_position_naive(bytes, eol_char)
                 } else {
-                    // we don't pass expected fields
-                    // as we want to skip all rows
-                    // no matter the no. of fields
-                    next_line_position(bytes, None, self.separator, self.quote_char, eol_char)
+                    next_line_position_quoted(bytes, 0, self.quote_char, eol_char)
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -406,16 +474,11 @@ impl<'a> CoreReader<'a> {
                 let n_bytes = (line_length_upper_bound * (n_rows as f32)) as usize;
 
                 if n_bytes < bytes.len() {
-                    if let Some(pos) = next_line_position(
-                        &bytes[n_bytes..],
-                        Some(self.schema.len()),
-                        self.separator,
-                        self.quote_char,
-                        self.eol_char,
-                    ) {
+                    if let Some(pos) =
+                        next_line_position_quoted(bytes, n_bytes, self.quote_char, self.eol_char)
+                    {
                         if set_upper_bound {
-                            (bytes, remaining_bytes) =
-                                (&bytes[..n_bytes + pos], Some(&bytes[n_bytes + pos..]))
+                            (bytes, remaining_bytes) = (&bytes[..pos], Some(&bytes[pos..]))
                         }
                     }
                 }
@@ -461,14 +524,7 @@ impl<'a> CoreReader<'a> {
         // split the file by the nearest new line characters such that every thread processes
         // approximately the same number of rows.
 
-        let chunks = get_file_chunks(
-            bytes,
-            n_file_chunks,
-            self.schema.len(),
-            self.separator,
-            self.quote_char,
-            self.eol_char,
-        );
+        let chunks = get_file_chunks(bytes, n_file_chunks, self.quote_char, self.eol_char);
 
         if logging {
             eprintln!(
@@ -587,6 +643,7 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.ignore_errors,
+                                self.decimal_comma,
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
@@ -676,6 +733,7 @@ impl<'a> CoreReader<'a> {
                             self.null_values.as_ref(),
                             self.missing_is_null,
                             self.truncate_ragged_lines,
+                            self.decimal_comma,
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
@@ -709,6 +767,7 @@ impl<'a> CoreReader<'a> {
                                 self.quote_char,
                                 self.encoding,
                                 self.ignore_errors,
+                                self.decimal_comma,
                             )?;
 
                             parse_lines(
@@ -755,6 +814,8 @@ impl<'a> CoreReader<'a> {
 
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        self.decompress_reader_bytes();
         let predicate = self.predicate.take();
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
@@ -806,6 +867,7 @@ fn read_chunk(
     null_values: Option<&NullValuesCompiled>,
     missing_is_null: bool,
     truncate_ragged_lines: bool,
+    decimal_comma: bool,
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
@@ -819,6 +881,7 @@ fn read_chunk(
         quote_char,
         encoding,
         ignore_errors,
+        decimal_comma,
     )?;
 
     let mut last_read = usize::MAX;
@@ -9,22 +9,27 @@ use polars_core::prelude::*;
 use polars_time::chunkedarray::string::infer as date_infer;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::string::Pattern;
+use regex::Regex;
 
+pub use crate::compression::is_compressed;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::compression::{self, SupportedCompression};
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use crate::csv::parser::next_line_position_naive;
-use crate::csv::parser::{next_line_position, skip_bom, skip_line_ending, SplitLines};
+use crate::csv::parser::{
+    next_line_position, next_line_position_quoted, skip_bom, skip_line_ending, SplitLines,
+};
 use crate::csv::splitfields::SplitFields;
 use crate::csv::CsvEncoding;
 use crate::mmap::ReaderBytes;
 use crate::prelude::parser::is_comment_line;
 use crate::prelude::{CommentPrefix, NullValues};
-use crate::utils::{BOOLEAN_RE, FLOAT_RE, INTEGER_RE};
+use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
+/// Split `bytes`, which must start at the start of a line, in `n_chunks` chunks of whole lines.
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
-    expected_fields: usize,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
@@ -39,18 +44,14 @@ pub(crate) fn get_file_chunks(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
-        };
+        // we know that `last_pos` is the start of a line, so we can track the quoting from there
+        let end_pos =
+            match next_line_position_quoted(&bytes[last_pos..], chunk_size, quote_char, eol_char) {
+                Some(pos) => last_pos + pos,
+                None => {
+                    break;
+                },
+            };
         offsets.push((last_pos, end_pos));
         last_pos = end_pos;
     }
@@ -59,7 +60,7 @@ pub(crate) fn get_file_chunks(
 }
 
 /// Infer the data type of a record
-fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
+fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bool) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // String for them
     if string.starts_with('"') {
@@ -90,7 +91,9 @@ fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
     // match regex in a particular order
     else if BOOLEAN_RE.is_match(string) {
         DataType::Boolean
-    } else if FLOAT_RE.is_match(string) {
+    } else if !decimal_comma && FLOAT_RE.is_match(string) {
+        DataType::Float64
+    } else if decimal_comma && FLOAT_RE_DECIMAL.is_match(string) {
         DataType::Float64
     } else if INTEGER_RE.is_match(string) {
         DataType::Int64
@@ -132,6 +135,49 @@ pub(crate) fn parse_bytes_with_encoding(
     })
 }
 
+/// The maximum number of conflicting rows that is reported per column.
+const MAX_CONFLICTING_ROWS: usize = 10;
+
+/// How the dtype of a single column was inferred.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInference {
+    pub name: String,
+    /// The dtype the column will be read as.
+    pub dtype: DataType,
+    /// Every dtype a value of the column was inferred as, with the number of those values.
+    /// Sorted by descending count, the first one is the most common candidate.
+    pub candidates: Vec<(DataType, usize)>,
+    /// Whether the dtype was set by a schema overwrite, instead of inferred.
+    pub overwritten: bool,
+    /// The first rows whose value was inferred as another dtype than the most common candidate.
+    /// Rows are counted from the first row that is used for inference.
+    pub conflicting_rows: Vec<usize>,
+}
+
+/// The full result of inferring the schema of a CSV file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaInference {
+    pub schema: Schema,
+    pub columns: Vec<ColumnInference>,
+    /// The number of rows used for inference.
+    pub rows_read: usize,
+}
+
+/// Compile dtype overwrites that are keyed by a regex on the column name.
+pub fn compile_dtype_overwrite_regex(
+    dtypes: &[(String, DataType)],
+) -> PolarsResult<Vec<(Regex, DataType)>> {
+    dtypes
+        .iter()
+        .map(|(pattern, dtype)| {
+            let re = Regex::new(pattern).map_err(|e| {
+                polars_err!(ComputeError: "invalid regex {:?} in dtype overwrite: {}", pattern, e)
+            })?;
+            Ok((re, dtype.clone()))
+        })
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema_inner(
     reader_bytes: &ReaderBytes,
@@ -139,6 +185,7 @@ pub fn infer_file_schema_inner(
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
+    dtype_overwrite_regex: Option<&[(Regex, DataType)]>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
     skip_rows: &mut usize,
@@ -148,8 +195,10 @@ pub fn infer_file_schema_inner(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    decimal_comma: bool,
     recursion_count: u8,
     raise_if_empty: bool,
+    report: Option<&mut Vec<ColumnInference>>,
 ) -> PolarsResult<(Schema, usize, usize)> {
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
@@ -240,6 +289,7 @@ pub fn infer_file_schema_inner(
             max_read_rows,
             has_header,
             schema_overwrite,
+            dtype_overwrite_regex,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
@@ -247,8 +297,10 @@ pub fn infer_file_schema_inner(
             eol_char,
             null_values,
             try_parse_dates,
+            decimal_comma,
             recursion_count + 1,
             raise_if_empty,
+            report,
         );
     } else if !raise_if_empty {
         return Ok((Schema::new(), 0, 0));
@@ -266,6 +318,12 @@ pub fn infer_file_schema_inner(
         vec![PlHashSet::with_capacity(4); header_length];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; header_length];
+    // only if a report is requested: per column the count and first rows of every candidate
+    let mut candidate_rows: Vec<PlHashMap<DataType, (usize, Vec<usize>)>> = if report.is_some() {
+        vec![PlHashMap::new(); header_length]
+    } else {
+        vec![]
+    };
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(header_length);
@@ -325,32 +383,30 @@ pub fn infer_file_schema_inner(
                         slice
                     };
                     let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    match &null_values {
-                        None => {
-                            column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                        },
+                    let is_null = match &null_values {
+                        None => false,
                         Some(NullValues::AllColumns(names)) => {
-                            if !names.iter().any(|nv| nv == s.as_ref()) {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                            }
-                        },
-                        Some(NullValues::AllColumnsSingle(name)) => {
-                            if s.as_ref() != name {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                            }
+                            names.iter().any(|nv| nv == s.as_ref())
                         },
+                        Some(NullValues::AllColumnsSingle(name)) => s.as_ref() == name,
                         Some(NullValues::Named(names)) => {
                             let current_name = &headers[i];
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
-
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                                }
-                            } else {
-                                column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                            }
+                            // A column may have several null values.
+                            names
+                                .iter()
+                                .any(|(name, nv)| name == current_name && nv == s.as_ref())
                         },
+                    };
+                    if !is_null {
+                        let dtype = infer_field_schema(&s, try_parse_dates, decimal_comma);
+                        if let Some(candidates) = candidate_rows.get_mut(i) {
+                            let (count, rows) = candidates.entry(dtype.clone()).or_default();
+                            *count += 1;
+                            if rows.len() < MAX_CONFLICTING_ROWS {
+                                rows.push(rows_count - 1);
+                            }
+                        }
+                        column_types[i].insert(dtype);
                     }
                 }
             }
@@ -358,6 +414,7 @@ pub fn infer_file_schema_inner(
     }
 
     // build schema from inference results
+    let mut overwritten = vec![false; header_length];
     for i in 0..header_length {
         let possibilities = &column_types[i];
         let field_name = &headers[i];
@@ -365,6 +422,7 @@ pub fn infer_file_schema_inner(
         if let Some(schema_overwrite) = schema_overwrite {
             if let Some((_, name, dtype)) = schema_overwrite.get_full(field_name) {
                 fields.push(Field::new(name, dtype.clone()));
+                overwritten[i] = true;
                 continue;
             }
 
@@ -373,11 +431,21 @@ pub fn infer_file_schema_inner(
             if schema_overwrite.len() == header_length {
                 if let Some((name, dtype)) = schema_overwrite.get_at_index(i) {
                     fields.push(Field::new(name, dtype.clone()));
+                    overwritten[i] = true;
                     continue;
                 }
             }
         }
 
+        // the first matching regex wins
+        if let Some((_, dtype)) = dtype_overwrite_regex
+            .and_then(|dtypes| dtypes.iter().find(|(re, _)| re.is_match(field_name)))
+        {
+            fields.push(Field::new(field_name, dtype.clone()));
+            overwritten[i] = true;
+            continue;
+        }
+
         // determine data type based on possible types
         // if there are incompatible types, use DataType::String
         match possibilities.len() {
@@ -432,6 +500,7 @@ pub fn infer_file_schema_inner(
             max_read_rows,
             has_header,
             schema_overwrite,
+            dtype_overwrite_regex,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
@@ -439,11 +508,43 @@ pub fn infer_file_schema_inner(
             eol_char,
             null_values,
             try_parse_dates,
+            decimal_comma,
             recursion_count + 1,
             raise_if_empty,
+            report,
         );
     }
 
+    if let Some(report) = report {
+        *report = fields
+            .iter()
+            .zip(candidate_rows)
+            .zip(overwritten)
+            .map(|((fld, candidates), overwritten)| {
+                let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+                // on a tie, the candidate that was seen first is the most common one
+                candidates.sort_by_key(|(_, (count, rows))| (std::cmp::Reverse(*count), rows[0]));
+                let mut conflicting_rows = candidates
+                    .iter()
+                    .skip(1)
+                    .flat_map(|(_, (_, rows))| rows.iter().copied())
+                    .collect::<Vec<_>>();
+                conflicting_rows.sort_unstable();
+                conflicting_rows.truncate(MAX_CONFLICTING_ROWS);
+                ColumnInference {
+                    name: fld.name().to_string(),
+                    dtype: fld.data_type().clone(),
+                    candidates: candidates
+                        .into_iter()
+                        .map(|(dtype, (count, _))| (dtype, count))
+                        .collect(),
+                    overwritten,
+                    conflicting_rows,
+                }
+            })
+            .collect();
+    }
+
     Ok((Schema::from_iter(fields), rows_count, end_ptr - start_ptr))
 }
 
@@ -463,6 +564,7 @@ pub fn infer_file_schema(
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
+    dtype_overwrite_regex: Option<&[(Regex, DataType)]>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
     skip_rows: &mut usize,
@@ -472,6 +574,7 @@ pub fn infer_file_schema(
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
+    decimal_comma: bool,
     raise_if_empty: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
     infer_file_schema_inner(
@@ -480,6 +583,7 @@ pub fn infer_file_schema(
         max_read_rows,
         has_header,
         schema_overwrite,
+        dtype_overwrite_regex,
         skip_rows,
         skip_rows_after_header,
         comment_prefix,
@@ -487,25 +591,58 @@ pub fn infer_file_schema(
         eol_char,
         null_values,
         try_parse_dates,
+        decimal_comma,
         0,
         raise_if_empty,
+        None,
     )
 }
 
-// magic numbers
-const GZIP: [u8; 2] = [31, 139];
-const ZLIB0: [u8; 2] = [0x78, 0x01];
-const ZLIB1: [u8; 2] = [0x78, 0x9C];
-const ZLIB2: [u8; 2] = [0x78, 0xDA];
-const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
-
-/// check if csv file is compressed
-pub fn is_compressed(bytes: &[u8]) -> bool {
-    bytes.starts_with(&ZLIB0)
-        || bytes.starts_with(&ZLIB1)
-        || bytes.starts_with(&ZLIB2)
-        || bytes.starts_with(&GZIP)
-        || bytes.starts_with(&ZSTD)
+/// Infer the schema of a CSV file like [`infer_file_schema`], but also report per column which
+/// dtypes its values were inferred as and which rows conflicted with the most common dtype.
+#[allow(clippy::too_many_arguments)]
+pub fn infer_file_schema_with_report(
+    reader_bytes: &ReaderBytes,
+    separator: u8,
+    max_read_rows: Option<usize>,
+    has_header: bool,
+    schema_overwrite: Option<&Schema>,
+    dtype_overwrite_regex: Option<&[(Regex, DataType)]>,
+    skip_rows: &mut usize,
+    skip_rows_after_header: usize,
+    comment_prefix: Option<&CommentPrefix>,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    null_values: Option<&NullValues>,
+    try_parse_dates: bool,
+    decimal_comma: bool,
+    raise_if_empty: bool,
+) -> PolarsResult<SchemaInference> {
+    let mut columns = vec![];
+    let (schema, rows_read, _) = infer_file_schema_inner(
+        reader_bytes,
+        separator,
+        max_read_rows,
+        has_header,
+        schema_overwrite,
+        dtype_overwrite_regex,
+        skip_rows,
+        skip_rows_after_header,
+        comment_prefix,
+        quote_char,
+        eol_char,
+        null_values,
+        try_parse_dates,
+        decimal_comma,
+        0,
+        raise_if_empty,
+        Some(&mut columns),
+    )?;
+    Ok(SchemaInference {
+        schema,
+        columns,
+        rows_read,
+    })
 }
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
@@ -589,18 +726,9 @@ pub(crate) fn decompress(
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
-    if bytes.starts_with(&GZIP) {
-        let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
-        let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else if bytes.starts_with(&ZSTD) {
-        let mut decoder = zstd::Decoder::new(bytes).ok()?;
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-    } else {
-        None
-    }
+    let compression = SupportedCompression::check(bytes)?;
+    let mut decoder = compression::decoder(bytes, compression).ok()?;
+    decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
 }
 
 /// replace double quotes by single ones
@@ -661,13 +789,82 @@ mod test {
         assert!(FLOAT_RE.is_match("+7e+05"));
     }
 
+    #[test]
+    fn test_float_parse_decimal_comma() {
+        assert!(FLOAT_RE_DECIMAL.is_match("0,1"));
+        assert!(FLOAT_RE_DECIMAL.is_match("-3,00001"));
+        assert!(FLOAT_RE_DECIMAL.is_match(",5"));
+        assert!(FLOAT_RE_DECIMAL.is_match("2,5E-10"));
+        assert!(FLOAT_RE_DECIMAL.is_match("NaN"));
+        assert!(!FLOAT_RE_DECIMAL.is_match("0.1"));
+        assert!(!FLOAT_RE_DECIMAL.is_match("1,2,3"));
+        assert_eq!(infer_field_schema("1,5", false, true), DataType::Float64);
+        assert_eq!(infer_field_schema("1,5", false, false), DataType::String);
+    }
+
+    #[test]
+    fn test_infer_file_schema_with_report() -> PolarsResult<()> {
+        let csv = "id,sensor_a,sensor_b,flag\n1,1.5,2,true\n2,x,3,false\n3,2.5,4.5,1\n4,,5,true\n";
+        let reader_bytes = ReaderBytes::Borrowed(csv.as_bytes());
+        let dtypes = compile_dtype_overwrite_regex(&[("^sensor_.*$".into(), DataType::Float32)])?;
+        let schema_overwrite = Schema::from_iter([Field::new("sensor_b", DataType::Float64)]);
+
+        let inference = infer_file_schema_with_report(
+            &reader_bytes,
+            b',',
+            None,
+            true,
+            Some(&schema_overwrite),
+            Some(&dtypes),
+            &mut 0,
+            0,
+            None,
+            Some(b'"'),
+            b'\n',
+            None,
+            false,
+            false,
+            true,
+        )?;
+        assert_eq!(inference.rows_read, 4);
+        assert_eq!(
+            inference.schema,
+            Schema::from_iter([
+                Field::new("id", DataType::Int64),
+                Field::new("sensor_a", DataType::Float32),
+                Field::new("sensor_b", DataType::Float64),
+                Field::new("flag", DataType::String),
+            ])
+        );
+
+        let sensor_a = &inference.columns[1];
+        assert!(sensor_a.overwritten);
+        assert_eq!(
+            sensor_a.candidates,
+            &[(DataType::Float64, 2), (DataType::String, 1)]
+        );
+        assert_eq!(sensor_a.conflicting_rows, &[1]);
+
+        let flag = &inference.columns[3];
+        assert!(!flag.overwritten);
+        assert_eq!(
+            flag.candidates,
+            &[(DataType::Boolean, 3), (DataType::Int64, 1)]
+        );
+        assert_eq!(flag.conflicting_rows, &[2]);
+        assert!(inference.columns[0].conflicting_rows.is_empty());
+
+        assert!(compile_dtype_overwrite_regex(&[("(".into(), DataType::Int64)]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_get_file_chunks() {
         let path = "../../examples/datasets/foods1.csv";
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
-        assert!((get_file_chunks(bytes, 10, 4, b',', None, b'\n').len() as i32 - 10).abs() <= 1);
-        assert!((get_file_chunks(bytes, 8, 4, b',', None, b'\n').len() as i32 - 8).abs() <= 1);
+        assert!((get_file_chunks(bytes, 10, None, b'\n').len() as i32 - 10).abs() <= 1);
+        assert!((get_file_chunks(bytes, 8, None, b'\n').len() as i32 - 8).abs() <= 1);
     }
 }
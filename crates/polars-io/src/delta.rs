@@ -0,0 +1,402 @@
+//! Read the state of a [Delta Lake](https://delta.io) table.
+//!
+//! A Delta table is a directory of parquet files with a transaction log in its `_delta_log`
+//! directory. Every commit to the table is a newline delimited JSON file named after its
+//! version, e.g. `00000000000000000003.json`, that holds one action per line. An `add` action
+//! adds a data file to the table and a `remove` action removes it again, so the active files of
+//! a version are found by replaying the actions of all commits up to that version.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde_json::Value;
+
+/// The highest version of the Delta protocol that can be read.
+const MAX_READER_VERSION: i64 = 1;
+
+/// The version of a Delta table to read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum DeltaVersion {
+    /// The latest version.
+    #[default]
+    Latest,
+    /// The version with this number.
+    Version(i64),
+    /// The latest version that was committed at or before this timestamp, in milliseconds
+    /// since the UNIX epoch.
+    Timestamp(i64),
+}
+
+/// A data file of a Delta table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeltaFile {
+    pub path: PathBuf,
+    /// The value of every partition column in this file, `None` for a null value.
+    pub partition_values: Vec<(String, Option<String>)>,
+    /// The size of the file in bytes.
+    pub size: Option<u64>,
+}
+
+impl DeltaFile {
+    /// The partition values of this file as [`Series`] of length 1, with the data types of
+    /// `schema`.
+    pub fn partition_columns(&self, schema: &Schema) -> PolarsResult<Vec<Series>> {
+        self.partition_values
+            .iter()
+            .map(|(name, value)| {
+                let dtype = schema.try_get(name)?;
+                Series::new(name, &[value.as_deref()]).strict_cast(dtype)
+            })
+            .collect()
+    }
+}
+
+/// The state of a Delta table at a version.
+#[derive(Clone, Debug)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    /// The commit timestamp of the version, in milliseconds since the UNIX epoch.
+    pub timestamp: i64,
+    pub schema: Schema,
+    pub partition_columns: Vec<String>,
+    /// The active data files, in the order in which they were added.
+    pub files: Vec<DeltaFile>,
+}
+
+impl DeltaSnapshot {
+    /// Read the state of the Delta table at `path` at `version` from its transaction log.
+    pub fn read(path: &Path, version: DeltaVersion) -> PolarsResult<Self> {
+        let log_dir = path.join("_delta_log");
+        let entries = fs::read_dir(&log_dir).map_err(|e| {
+            polars_err!(
+                ComputeError: "cannot read the Delta transaction log at {}: {}",
+                log_dir.display(), e
+            )
+        })?;
+        let mut commits = vec![];
+        for entry in entries {
+            let file_name = entry?.file_name();
+            if let Some(v) = file_name.to_str().and_then(parse_commit_version) {
+                commits.push(v);
+            }
+        }
+        commits.sort_unstable();
+
+        let (Some(&first), Some(&latest)) = (commits.first(), commits.last()) else {
+            polars_bail!(
+                ComputeError: "no commits found in the Delta transaction log at {}",
+                log_dir.display()
+            );
+        };
+        // Without the commits from version 0 the state has to be restored from a checkpoint.
+        polars_ensure!(
+            first == 0,
+            ComputeError: "the Delta transaction log at {} starts at version {}; reading \
+            Delta checkpoints is not supported", log_dir.display(), first
+        );
+        if let DeltaVersion::Version(v) = version {
+            polars_ensure!(
+                commits.binary_search(&v).is_ok(),
+                ComputeError: "version {} of the Delta table at {} does not exist, the latest \
+                version is {}", v, path.display(), latest
+            );
+        }
+
+        let mut snapshot = DeltaSnapshot {
+            version: -1,
+            timestamp: 0,
+            schema: Schema::new(),
+            partition_columns: vec![],
+            files: vec![],
+        };
+        let mut files = PlIndexMap::new();
+        for v in commits {
+            if matches!(version, DeltaVersion::Version(target) if v > target) {
+                break;
+            }
+            let commit_path = log_dir.join(format!("{v:020}.json"));
+            let actions = read_actions(&commit_path)?;
+            let timestamp = match commit_timestamp(&actions) {
+                Some(timestamp) => timestamp,
+                None => file_timestamp(&commit_path)?,
+            };
+            if let DeltaVersion::Timestamp(target) = version {
+                if timestamp > target {
+                    polars_ensure!(
+                        v > 0,
+                        ComputeError: "the Delta table at {} did not exist at timestamp {}, \
+                        its first commit is at timestamp {}", path.display(), target, timestamp
+                    );
+                    break;
+                }
+            }
+            snapshot.apply(path, &actions, &mut files)?;
+            snapshot.version = v;
+            snapshot.timestamp = timestamp;
+        }
+        snapshot.files = files.into_values().collect();
+        Ok(snapshot)
+    }
+
+    fn apply(
+        &mut self,
+        table_path: &Path,
+        actions: &[Value],
+        files: &mut PlIndexMap<String, DeltaFile>,
+    ) -> PolarsResult<()> {
+        for action in actions {
+            if let Some(protocol) = action.get("protocol") {
+                let reader_version = protocol["minReaderVersion"].as_i64().unwrap_or(1);
+                polars_ensure!(
+                    reader_version <= MAX_READER_VERSION,
+                    ComputeError: "reading Delta tables with reader version {} is not supported",
+                    reader_version
+                );
+            } else if let Some(metadata) = action.get("metaData") {
+                let schema_string = metadata["schemaString"].as_str().ok_or_else(
+                    || polars_err!(ComputeError: "Delta metadata has no `schemaString`"),
+                )?;
+                let schema: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+                self.schema = struct_fields(&schema)?.into_iter().collect();
+                self.partition_columns = metadata["partitionColumns"]
+                    .as_array()
+                    .map(|columns| {
+                        columns
+                            .iter()
+                            .filter_map(|c| c.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+            } else if let Some(add) = action.get("add") {
+                let key = file_key(add)?;
+                let partition_values = add["partitionValues"]
+                    .as_object()
+                    .map(|values| {
+                        values
+                            .iter()
+                            .map(|(k, v)| (k.clone(), v.as_str().map(String::from)))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let file = DeltaFile {
+                    path: resolve_path(table_path, key)?,
+                    partition_values,
+                    size: add["size"].as_u64(),
+                };
+                files.insert(key.to_string(), file);
+            } else if let Some(remove) = action.get("remove") {
+                files.shift_remove(file_key(remove)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse the version of a commit file name, e.g. `00000000000000000003.json`.
+fn parse_commit_version(file_name: &str) -> Option<i64> {
+    let version = file_name.strip_suffix(".json")?;
+    if version.len() == 20 && version.bytes().all(|b| b.is_ascii_digit()) {
+        version.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn read_actions(path: &Path) -> PolarsResult<Vec<Value>> {
+    let content = fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| {
+                polars_err!(
+                    ComputeError: "invalid action in Delta commit {}: {}", path.display(), e
+                )
+            })
+        })
+        .collect()
+}
+
+fn commit_timestamp(actions: &[Value]) -> Option<i64> {
+    actions
+        .iter()
+        .find_map(|action| action.get("commitInfo")?.get("timestamp")?.as_i64())
+}
+
+/// The modification time of a commit file is its timestamp if the commit has no `commitInfo`.
+fn file_timestamp(path: &Path) -> PolarsResult<i64> {
+    let modified = fs::metadata(path)?.modified()?;
+    let since_epoch = modified
+        .duration_since(UNIX_EPOCH)
+        .map_err(to_compute_err)?;
+    Ok(since_epoch.as_millis() as i64)
+}
+
+fn file_key(action: &Value) -> PolarsResult<&str> {
+    action["path"]
+        .as_str()
+        .ok_or_else(|| polars_err!(ComputeError: "Delta file action has no `path`"))
+}
+
+/// The paths of the data files are percent encoded URIs, relative to the table directory.
+fn resolve_path(table_path: &Path, key: &str) -> PolarsResult<PathBuf> {
+    let decoded = percent_encoding::percent_decode_str(key)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+    if let Some(path) = decoded.strip_prefix("file://") {
+        Ok(PathBuf::from(path))
+    } else {
+        polars_ensure!(
+            !decoded.contains("://"),
+            ComputeError: "the Delta data file {} is not on the local file system", decoded
+        );
+        Ok(table_path.join(decoded.as_ref()))
+    }
+}
+
+fn struct_fields(dtype: &Value) -> PolarsResult<Vec<Field>> {
+    let fields = dtype["fields"]
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid Delta struct type: {}", dtype))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field["name"]
+                .as_str()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid Delta field: {}", field))?;
+            Ok(Field::new(name, delta_dtype(&field["type"])?))
+        })
+        .collect()
+}
+
+/// Convert a type of the Delta schema to a [`DataType`].
+fn delta_dtype(dtype: &Value) -> PolarsResult<DataType> {
+    let dtype = match dtype {
+        Value::String(name) => match name.as_str() {
+            "string" => DataType::String,
+            "long" => DataType::Int64,
+            "integer" => DataType::Int32,
+            "short" => DataType::Int16,
+            "byte" => DataType::Int8,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "boolean" => DataType::Boolean,
+            "binary" => DataType::Binary,
+            "date" => DataType::Date,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let parse = || {
+                    let (precision, scale) = name
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some(DataType::Decimal(
+                        Some(precision.trim().parse().ok()?),
+                        Some(scale.trim().parse().ok()?),
+                    ))
+                };
+                parse().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Delta decimal type: {}", name),
+                )?
+            },
+            name => polars_bail!(ComputeError: "unsupported Delta data type: {}", name),
+        },
+        Value::Object(nested) => match nested.get("type").and_then(|t| t.as_str()) {
+            Some("array") => DataType::List(Box::new(delta_dtype(&nested["elementType"])?)),
+            #[cfg(feature = "dtype-struct")]
+            Some("struct") => DataType::Struct(struct_fields(dtype)?),
+            #[cfg(feature = "dtype-struct")]
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key", delta_dtype(&nested["keyType"])?),
+                Field::new("value", delta_dtype(&nested["valueType"])?),
+            ]))),
+            _ => polars_bail!(ComputeError: "unsupported Delta data type: {}", dtype),
+        },
+        _ => polars_bail!(ComputeError: "invalid Delta data type: {}", dtype),
+    };
+    Ok(dtype)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    const SCHEMA: &str = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"integer\",\"nullable\":true,\"metadata\":{}}]}"#;
+
+    fn write_commit(dir: &Path, version: i64, actions: &[String]) {
+        let mut f = fs::File::create(dir.join(format!("{version:020}.json"))).unwrap();
+        for action in actions {
+            writeln!(f, "{action}").unwrap();
+        }
+    }
+
+    fn add(path: &str, p: i32) -> String {
+        format!(r#"{{"add":{{"path":"{path}","partitionValues":{{"p":"{p}"}},"size":1}}}}"#)
+    }
+
+    fn commit_info(timestamp: i64) -> String {
+        format!(r#"{{"commitInfo":{{"timestamp":{timestamp}}}}}"#)
+    }
+
+    #[test]
+    fn test_delta_snapshot() -> PolarsResult<()> {
+        let table = tempfile::tempdir()?;
+        let log_dir = table.path().join("_delta_log");
+        fs::create_dir(&log_dir)?;
+        write_commit(
+            &log_dir,
+            0,
+            &[
+                commit_info(1000),
+                r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.into(),
+                format!(r#"{{"metaData":{{"schemaString":"{SCHEMA}","partitionColumns":["p"]}}}}"#),
+                add("p=1/a%20b.parquet", 1),
+                add("p=2/c.parquet", 2),
+            ],
+        );
+        write_commit(
+            &log_dir,
+            1,
+            &[
+                commit_info(2000),
+                r#"{"remove":{"path":"p=1/a%20b.parquet"}}"#.into(),
+                add("p=1/d.parquet", 1),
+            ],
+        );
+
+        let latest = DeltaSnapshot::read(table.path(), DeltaVersion::Latest)?;
+        assert_eq!(latest.version, 1);
+        assert_eq!(latest.schema.get("a"), Some(&DataType::Int64));
+        assert_eq!(latest.partition_columns, &["p"]);
+        let paths = latest
+            .files
+            .iter()
+            .map(|f| f.path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            &[
+                table.path().join("p=2/c.parquet"),
+                table.path().join("p=1/d.parquet")
+            ]
+        );
+        let p = &latest.files[0].partition_columns(&latest.schema)?[0];
+        assert_eq!(Vec::from(p.i32()?), &[Some(2)]);
+
+        let first = DeltaSnapshot::read(table.path(), DeltaVersion::Version(0))?;
+        assert_eq!(first.files[0].path, table.path().join("p=1/a b.parquet"));
+        assert_eq!(first.files.len(), 2);
+
+        let at = DeltaSnapshot::read(table.path(), DeltaVersion::Timestamp(1500))?;
+        assert_eq!(at.version, 0);
+        assert!(DeltaSnapshot::read(table.path(), DeltaVersion::Timestamp(500)).is_err());
+        assert!(DeltaSnapshot::read(table.path(), DeltaVersion::Version(2)).is_err());
+        Ok(())
+    }
+}
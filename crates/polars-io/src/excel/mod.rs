@@ -0,0 +1,252 @@
+//! # Read and write Excel workbooks
+//!
+//! Workbooks are read from and written to the Office Open XML format (`.xlsx`).
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//!
+//! fn example(sales: &DataFrame, costs: &DataFrame) -> PolarsResult<DataFrame> {
+//!     let file = File::create("report.xlsx")?;
+//!     ExcelWriter::new(file).finish_sheets(&[("sales", sales), ("costs", costs)])?;
+//!
+//!     let file = File::open("report.xlsx")?;
+//!     ExcelReader::new(file)
+//!         .with_sheet_name("costs")
+//!         .infer_schema(Some(1000))
+//!         .finish()
+//! }
+//! ```
+mod read;
+mod write;
+mod zip;
+
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+pub use read::*;
+pub use write::*;
+
+/// Days from the epoch of Excel's default 1900 date system to the unix epoch. Excel treats 1900
+/// as a leap year, which this offset accounts for from March 1900 on.
+const EPOCH_OFFSET_1900: f64 = 25569.0;
+/// Days from the epoch of the 1904 date system to the unix epoch.
+const EPOCH_OFFSET_1904: f64 = 24107.0;
+const MS_PER_DAY: f64 = 86_400_000.0;
+/// The maximum number of rows and columns of a worksheet.
+const MAX_ROWS: usize = 1_048_576;
+const MAX_COLUMNS: usize = 16_384;
+
+fn xml_err(e: quick_xml::Error) -> PolarsError {
+    polars_err!(ComputeError: "invalid xlsx file: {}", e)
+}
+
+/// The letters of the column with the given 0-based index, e.g. `AB` for 27.
+fn column_letters(mut idx: usize) -> String {
+    let mut letters = vec![];
+    loop {
+        letters.push(b'A' + (idx % 26) as u8);
+        if idx < 26 {
+            break;
+        }
+        idx = idx / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// The 0-based row and column of a cell reference such as `B3`.
+fn parse_cell_reference(reference: &str) -> Option<(usize, usize)> {
+    let split = reference.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = reference.split_at(split);
+    if letters.is_empty() || !letters.bytes().all(|b| b.is_ascii_uppercase()) {
+        return None;
+    }
+    let col = letters.bytes().try_fold(0usize, |acc, b| {
+        acc.checked_mul(26)?.checked_add((b - b'A' + 1) as usize)
+    })?;
+    let row = digits.parse::<usize>().ok()?;
+    Some((row.checked_sub(1)?, col - 1))
+}
+
+/// Decode an `_xHHHH_` escape at the start of `s`.
+fn decode_escape(s: &str) -> Option<char> {
+    let hex = s.strip_prefix("_x")?.get(..5)?.strip_suffix('_')?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+/// Decode the `_xHHHH_` escapes with which Excel stores characters that XML cannot represent.
+fn unescape(s: &str) -> Cow<'_, str> {
+    if !s.contains("_x") {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find("_x") {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        match decode_escape(rest) {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[7..];
+            },
+            None => {
+                out.push_str("_x");
+                rest = &rest[2..];
+            },
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// Escape `s` for XML text or attribute values, using `_xHHHH_` escapes for the characters XML
+/// cannot represent and for underscores that would otherwise be read as such an escape.
+fn escape(s: &str, out: &mut String) {
+    for (i, c) in s.char_indices() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '_' if decode_escape(&s[i..]).is_some() => out.push_str("_x005F_"),
+            // Carriage returns are normalized away by XML parsers.
+            '\r' | '\u{FFFE}' | '\u{FFFF}' => out.push_str(&format!("_x{:04X}_", c as u32)),
+            c if (c as u32) < 0x20 && c != '\t' && c != '\n' => {
+                out.push_str(&format!("_x{:04X}_", c as u32))
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use polars_core::df;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cell_references() {
+        for (idx, letters) in [(0, "A"), (25, "Z"), (26, "AA"), (27, "AB"), (16383, "XFD")] {
+            assert_eq!(column_letters(idx), letters);
+            assert_eq!(parse_cell_reference(&format!("{letters}7")), Some((6, idx)));
+        }
+        assert_eq!(parse_cell_reference("A0"), None);
+        assert_eq!(parse_cell_reference("7"), None);
+    }
+
+    #[test]
+    fn test_escape() {
+        let s = "a&b <c>\r\n\u{1}_x0041_ _x";
+        let mut escaped = String::new();
+        escape(s, &mut escaped);
+        assert_eq!(escaped, "a&amp;b &lt;c&gt;_x000D_\n_x0001__x005F_x0041_ _x");
+        // Unescaping the XML entities is up to the XML reader.
+        let escaped = escaped
+            .replace("&amp;", "&")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">");
+        assert_eq!(unescape(&escaped), s);
+    }
+
+    #[test]
+    fn test_excel_round_trip() -> PolarsResult<()> {
+        let first = df!(
+            "int" => [Some(1i64), None, Some(-3)],
+            "float" => [1.5, 2.0, f64::NAN],
+            "bool" => [true, false, true],
+            "str" => [Some("a & b"), None, Some(" padded ")],
+        )?;
+        let second = DataFrame::new(vec![
+            Series::new("date", [Some(19_000i32), Some(-1), None]).cast(&DataType::Date)?,
+            Series::new("datetime", [Some(1_700_000_000_123i64), Some(0), None])
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            Series::new("time", [Some(3_723_000_000_000i64), Some(0), None])
+                .cast(&DataType::Time)?,
+        ])?;
+
+        let mut buf = Cursor::new(vec![]);
+        ExcelWriter::new(&mut buf).finish_sheets(&[("first", &first), ("second", &second)])?;
+        let data = buf.into_inner();
+
+        let out = ExcelReader::new(Cursor::new(&data)).finish()?;
+        let expected = df!(
+            "int" => [Some(1i64), None, Some(-3)],
+            // Non-finite floats are written as errors, which are read as nulls.
+            "float" => [Some(1.5), Some(2.0), None],
+            "bool" => [true, false, true],
+            "str" => [Some("a & b"), None, Some(" padded ")],
+        )?;
+        assert!(out.equals_missing(&expected));
+
+        let out = ExcelReader::new(Cursor::new(&data))
+            .with_sheet_name("second")
+            .finish()?;
+        assert!(out.equals_missing(&second));
+        let out = ExcelReader::new(Cursor::new(&data))
+            .with_sheet_index(1)
+            .finish()?;
+        assert!(out.equals_missing(&second));
+
+        assert!(ExcelReader::new(Cursor::new(&data))
+            .with_sheet_name("third")
+            .finish()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_excel_header_row_and_inference() -> PolarsResult<()> {
+        let mut df = df!("a" => ["name", "1", "2"])?;
+        let mut buf = Cursor::new(vec![]);
+        ExcelWriter::new(&mut buf)
+            .include_header(false)
+            .finish(&mut df)?;
+        let data = buf.into_inner();
+
+        // Numbers stored as text stay strings.
+        let out = ExcelReader::new(Cursor::new(&data)).finish()?;
+        assert_eq!(out.get_column_names(), &["name"]);
+        assert_eq!(out.column("name")?.dtype(), &DataType::String);
+
+        let out = ExcelReader::new(Cursor::new(&data))
+            .with_header_row(None)
+            .with_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(out.get_column_names(), &["column_1"]);
+        assert_eq!(out.height(), 2);
+
+        let out = ExcelReader::new(Cursor::new(&data))
+            .with_header_row(Some(1))
+            .finish()?;
+        assert_eq!(out.get_column_names(), &["1"]);
+        assert_eq!(out.height(), 1);
+
+        // A value after the inference window that does not fit the inferred dtype.
+        let mut df = df!("a" => [1.0, 2.0, 2.5])?;
+        let mut buf = Cursor::new(vec![]);
+        ExcelWriter::new(&mut buf).finish(&mut df)?;
+        let data = buf.into_inner();
+
+        let err = ExcelReader::new(Cursor::new(&data))
+            .infer_schema(Some(2))
+            .finish()
+            .unwrap_err();
+        assert!(err.to_string().contains("A4"));
+        let out = ExcelReader::new(Cursor::new(&data))
+            .infer_schema(Some(3))
+            .finish()?;
+        assert!(out.equals(&df));
+        Ok(())
+    }
+}
@@ -0,0 +1,682 @@
+use std::io::Read;
+
+use arrow::temporal_conversions::{
+    date32_to_date_opt, time64ns_to_time_opt, timestamp_ms_to_datetime_opt,
+};
+use polars_core::prelude::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::zip::ZipArchive;
+use super::*;
+use crate::SerReader;
+
+/// How a number is displayed by its cell format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TemporalKind {
+    Date,
+    Datetime,
+    Time,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cell {
+    Empty,
+    Bool(bool),
+    Number(f64),
+    /// A date serial number, i.e. days since the epoch of the workbook.
+    Temporal(f64, TemporalKind),
+    String(String),
+}
+
+/// Whether the number is integral and exactly representable as such in a float.
+fn is_integer(v: f64) -> bool {
+    v.fract() == 0.0 && v.abs() <= 9_007_199_254_740_992.0
+}
+
+impl Cell {
+    fn to_string(&self, epoch_offset: f64) -> String {
+        match self {
+            Cell::Empty => String::new(),
+            Cell::Bool(b) => b.to_string(),
+            Cell::Number(v) if is_integer(*v) => (*v as i64).to_string(),
+            Cell::Number(v) => v.to_string(),
+            Cell::Temporal(v, kind) => {
+                let formatted = match kind {
+                    TemporalKind::Date => {
+                        date32_to_date_opt((v.floor() - epoch_offset) as i32).map(|d| d.to_string())
+                    },
+                    TemporalKind::Datetime => {
+                        timestamp_ms_to_datetime_opt(serial_to_ms(*v, epoch_offset))
+                            .map(|dt| dt.to_string())
+                    },
+                    TemporalKind::Time => {
+                        time64ns_to_time_opt(serial_to_time_ns(*v)).map(|t| t.to_string())
+                    },
+                };
+                formatted.unwrap_or_else(|| v.to_string())
+            },
+            Cell::String(s) => s.clone(),
+        }
+    }
+}
+
+fn serial_to_ms(v: f64, epoch_offset: f64) -> i64 {
+    ((v - epoch_offset) * MS_PER_DAY).round() as i64
+}
+
+/// The time of day of a serial number in nanoseconds, rounded to milliseconds.
+fn serial_to_time_ns(v: f64) -> i64 {
+    ((v.fract() * MS_PER_DAY).round() as i64 % MS_PER_DAY as i64) * 1_000_000
+}
+
+/// The value of the attribute with the given local name.
+fn attribute(e: &BytesStart, name: &[u8]) -> PolarsResult<Option<String>> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| xml_err(e.into()))?;
+        if attr.key.local_name().as_ref() == name {
+            return Ok(Some(attr.unescape_value().map_err(xml_err)?.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn read_part(archive: &ZipArchive, name: &str) -> PolarsResult<Vec<u8>> {
+    archive
+        .read(name)?
+        .ok_or_else(|| polars_err!(ComputeError: "invalid xlsx file: missing {}", name))
+}
+
+struct Workbook {
+    /// The names of the sheets and the paths of their parts in the archive, in workbook order.
+    sheets: Vec<(String, String)>,
+    epoch_offset: f64,
+}
+
+fn read_workbook(archive: &ZipArchive) -> PolarsResult<Workbook> {
+    let rels = read_part(archive, "xl/_rels/workbook.xml.rels")?;
+    let mut targets = PlHashMap::new();
+    let mut reader = Reader::from_reader(rels.as_slice());
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xml_err)? {
+            Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"Relationship" => {
+                if let (Some(id), Some(target)) = (attribute(&e, b"Id")?, attribute(&e, b"Target")?)
+                {
+                    let path = match target.strip_prefix('/') {
+                        Some(absolute) => absolute.to_string(),
+                        None => format!("xl/{target}"),
+                    };
+                    targets.insert(id, path);
+                }
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+
+    let workbook = read_part(archive, "xl/workbook.xml")?;
+    let mut sheets = vec![];
+    let mut epoch_offset = EPOCH_OFFSET_1900;
+    let mut reader = Reader::from_reader(workbook.as_slice());
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xml_err)? {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"workbookPr" => {
+                    if let Some("1" | "true") = attribute(&e, b"date1904")?.as_deref() {
+                        epoch_offset = EPOCH_OFFSET_1904;
+                    }
+                },
+                b"sheet" => {
+                    let name = attribute(&e, b"name")?.unwrap_or_default();
+                    let path = attribute(&e, b"id")?
+                        .and_then(|id| targets.get(&id).cloned())
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "invalid xlsx file: sheet '{}' has no part", name
+                            )
+                        })?;
+                    sheets.push((name, path));
+                },
+                _ => {},
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+    Ok(Workbook {
+        sheets,
+        epoch_offset,
+    })
+}
+
+fn read_shared_strings(archive: &ZipArchive) -> PolarsResult<Vec<String>> {
+    let Some(data) = archive.read("xl/sharedStrings.xml")? else {
+        return Ok(vec![]);
+    };
+    let mut strings = vec![];
+    let mut current = String::new();
+    let mut in_text = false;
+    // Phonetic runs annotate the text and are not part of it.
+    let mut in_phonetic = false;
+    let mut reader = Reader::from_reader(data.as_slice());
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xml_err)? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"si" => current.clear(),
+                b"t" => in_text = !in_phonetic,
+                b"rPh" => in_phonetic = true,
+                _ => {},
+            },
+            Event::Empty(e) if e.local_name().as_ref() == b"si" => strings.push(String::new()),
+            Event::Text(e) if in_text => current.push_str(&e.unescape().map_err(xml_err)?),
+            Event::CData(e) if in_text => current.push_str(&String::from_utf8_lossy(&e)),
+            Event::End(e) => match e.local_name().as_ref() {
+                b"si" => strings.push(unescape(&current).into_owned()),
+                b"t" => in_text = false,
+                b"rPh" => in_phonetic = false,
+                _ => {},
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+    Ok(strings)
+}
+
+/// Whether a custom number format displays a date, a time or both.
+fn format_code_kind(code: &str) -> Option<TemporalKind> {
+    // Only the first section applies to positive numbers. Quoted literals, escaped characters
+    // and bracketed colors or locales are not part of the format, elapsed time such as `[h]` is.
+    let section = code.split(';').next().unwrap_or_default();
+    let (mut date, mut time, mut month_or_minute) = (false, false, false);
+    let mut chars = section.chars();
+    while let Some(c) = chars.next() {
+        match c.to_ascii_lowercase() {
+            '"' => {
+                chars.by_ref().find(|&c| c == '"');
+            },
+            '\\' | '_' | '*' => {
+                chars.next();
+            },
+            '[' => {
+                let inner: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                if !inner.is_empty() && inner.chars().all(|c| "hHmMsS".contains(c)) {
+                    time = true;
+                }
+            },
+            'y' | 'd' => date = true,
+            'h' | 's' => time = true,
+            'm' => month_or_minute = true,
+            _ => {},
+        }
+    }
+    // `m` is a minute next to hours or seconds and a month otherwise.
+    match (date || (month_or_minute && !time), time) {
+        (true, true) => Some(TemporalKind::Datetime),
+        (true, false) => Some(TemporalKind::Date),
+        (false, true) => Some(TemporalKind::Time),
+        (false, false) => None,
+    }
+}
+
+/// The temporal kind of every cell format, indexed by the `s` attribute of the cells.
+fn read_styles(archive: &ZipArchive) -> PolarsResult<Vec<Option<TemporalKind>>> {
+    let Some(data) = archive.read("xl/styles.xml")? else {
+        return Ok(vec![]);
+    };
+    let mut custom_formats = PlHashMap::new();
+    let mut format_ids = vec![];
+    let mut in_cell_formats = false;
+    let mut reader = Reader::from_reader(data.as_slice());
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xml_err)? {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"numFmt" => {
+                    if let (Some(id), Some(code)) =
+                        (attribute(&e, b"numFmtId")?, attribute(&e, b"formatCode")?)
+                    {
+                        if let Ok(id) = id.parse::<u32>() {
+                            custom_formats.insert(id, code);
+                        }
+                    }
+                },
+                b"cellXfs" => in_cell_formats = true,
+                b"xf" if in_cell_formats => {
+                    let id = attribute(&e, b"numFmtId")?.and_then(|id| id.parse::<u32>().ok());
+                    format_ids.push(id.unwrap_or(0));
+                },
+                _ => {},
+            },
+            Event::End(e) if e.local_name().as_ref() == b"cellXfs" => in_cell_formats = false,
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+
+    let kinds = format_ids
+        .into_iter()
+        .map(|id| match id {
+            14..=17 => Some(TemporalKind::Date),
+            18..=21 | 45..=47 => Some(TemporalKind::Time),
+            22 => Some(TemporalKind::Datetime),
+            id => custom_formats
+                .get(&id)
+                .and_then(|code| format_code_kind(code)),
+        })
+        .collect();
+    Ok(kinds)
+}
+
+fn parse_cell(
+    value: &str,
+    cell_type: &str,
+    kind: Option<TemporalKind>,
+    strings: &[String],
+) -> PolarsResult<Cell> {
+    let cell = match cell_type {
+        "s" => {
+            let string = value
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| strings.get(idx));
+            let string = string.ok_or_else(
+                || polars_err!(ComputeError: "invalid xlsx file: unknown shared string {}", value),
+            )?;
+            Cell::String(string.clone())
+        },
+        "str" | "inlineStr" => Cell::String(unescape(value).into_owned()),
+        "b" => Cell::Bool(value.trim() == "1"),
+        // Error values such as `#DIV/0!`.
+        "e" => Cell::Empty,
+        // ISO 8601 dates, which are rare as Excel itself stores dates as numbers.
+        "d" => Cell::String(value.to_string()),
+        _ if value.trim().is_empty() => Cell::Empty,
+        _ => {
+            let v = value.trim().parse::<f64>().map_err(
+                |_| polars_err!(ComputeError: "invalid xlsx file: invalid number {}", value),
+            )?;
+            match kind {
+                Some(kind) => Cell::Temporal(v, kind),
+                None => Cell::Number(v),
+            }
+        },
+    };
+    Ok(cell)
+}
+
+/// The cells of a worksheet by row and column. Rows are as wide as their last non-empty cell.
+fn read_sheet(
+    data: &[u8],
+    strings: &[String],
+    styles: &[Option<TemporalKind>],
+) -> PolarsResult<Vec<Vec<Cell>>> {
+    let mut rows: Vec<Vec<Cell>> = vec![];
+    let mut row_idx = 0;
+    let mut col_idx = 0;
+    // The column, type and format of the cell that is being read.
+    let mut cell: Option<(usize, String, Option<TemporalKind>)> = None;
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut reader = Reader::from_reader(data);
+    let mut buf = vec![];
+    loop {
+        let event = reader.read_event_into(&mut buf).map_err(xml_err)?;
+        match &event {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"row" => {
+                    row_idx = match attribute(e, b"r")? {
+                        Some(r) => r
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|r| r.checked_sub(1))
+                            .filter(|&r| r < MAX_ROWS)
+                            .ok_or_else(|| {
+                                polars_err!(ComputeError: "invalid xlsx file: invalid row {}", r)
+                            })?,
+                        None => rows.len(),
+                    };
+                    if rows.len() <= row_idx {
+                        rows.resize_with(row_idx + 1, Vec::new);
+                    }
+                    col_idx = 0;
+                },
+                b"c" => {
+                    let col = attribute(e, b"r")?
+                        .and_then(|r| parse_cell_reference(&r))
+                        .map_or(col_idx, |(_, col)| col);
+                    polars_ensure!(
+                        col < MAX_COLUMNS,
+                        ComputeError: "invalid xlsx file: invalid column {}", col
+                    );
+                    col_idx = col + 1;
+                    // Cells without a value only carry formatting.
+                    if matches!(event, Event::Start(_)) {
+                        let cell_type = attribute(e, b"t")?.unwrap_or_default();
+                        let kind = attribute(e, b"s")?
+                            .and_then(|s| s.parse::<usize>().ok())
+                            .and_then(|s| styles.get(s).copied().flatten());
+                        value.clear();
+                        cell = Some((col, cell_type, kind));
+                    }
+                },
+                b"v" | b"t" => in_value = cell.is_some() && matches!(event, Event::Start(_)),
+                _ => {},
+            },
+            Event::Text(e) if in_value => value.push_str(&e.unescape().map_err(xml_err)?),
+            Event::CData(e) if in_value => value.push_str(&String::from_utf8_lossy(e)),
+            Event::End(e) => match e.local_name().as_ref() {
+                b"v" | b"t" => in_value = false,
+                b"c" => {
+                    if let Some((col, cell_type, kind)) = cell.take() {
+                        let row = rows.get_mut(row_idx).ok_or_else(|| {
+                            polars_err!(ComputeError: "invalid xlsx file: cell outside of a row")
+                        })?;
+                        let parsed = parse_cell(&value, &cell_type, kind, strings)?;
+                        if parsed != Cell::Empty {
+                            if row.len() <= col {
+                                row.resize(col + 1, Cell::Empty);
+                            }
+                            row[col] = parsed;
+                        }
+                    }
+                },
+                _ => {},
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+    Ok(rows)
+}
+
+const BOOL: u8 = 1;
+const INT: u8 = 1 << 1;
+const FLOAT: u8 = 1 << 2;
+const DATE: u8 = 1 << 3;
+const DATETIME: u8 = 1 << 4;
+const TIME: u8 = 1 << 5;
+
+/// The narrowest dtype that holds all cells, `String` if the cells are of mixed types.
+fn infer_dtype<'a>(cells: impl Iterator<Item = &'a Cell>) -> DataType {
+    let mut kinds = 0;
+    for cell in cells {
+        kinds |= match cell {
+            Cell::Empty => 0,
+            Cell::Bool(_) => BOOL,
+            Cell::Number(v) if is_integer(*v) => INT,
+            Cell::Number(_) => FLOAT,
+            Cell::Temporal(_, TemporalKind::Date) => DATE,
+            Cell::Temporal(_, TemporalKind::Datetime) => DATETIME,
+            Cell::Temporal(_, TemporalKind::Time) => TIME,
+            Cell::String(_) => return DataType::String,
+        };
+    }
+    match kinds {
+        BOOL => DataType::Boolean,
+        INT => DataType::Int64,
+        k if k != 0 && k & !(INT | FLOAT) == 0 => DataType::Float64,
+        DATE => DataType::Date,
+        k if k != 0 && k & !(DATE | DATETIME) == 0 => {
+            DataType::Datetime(TimeUnit::Milliseconds, None)
+        },
+        TIME => DataType::Time,
+        _ => DataType::String,
+    }
+}
+
+/// Convert the non-empty cells, returning the index of the first cell that cannot be converted.
+fn convert_cells<T>(
+    cells: &[&Cell],
+    convert: impl Fn(&Cell) -> Option<T>,
+) -> Result<Vec<Option<T>>, usize> {
+    cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| match cell {
+            Cell::Empty => Ok(None),
+            cell => convert(cell).map(Some).ok_or(i),
+        })
+        .collect()
+}
+
+fn build_column(
+    name: &str,
+    cells: &[&Cell],
+    dtype: &DataType,
+    epoch_offset: f64,
+) -> Result<Series, usize> {
+    let s = match dtype {
+        DataType::Boolean => Series::new(
+            name,
+            convert_cells(cells, |cell| match cell {
+                Cell::Bool(b) => Some(*b),
+                _ => None,
+            })?,
+        ),
+        DataType::Int64 => Series::new(
+            name,
+            convert_cells(cells, |cell| match cell {
+                Cell::Number(v) if is_integer(*v) => Some(*v as i64),
+                _ => None,
+            })?,
+        ),
+        DataType::Float64 => Series::new(
+            name,
+            convert_cells(cells, |cell| match cell {
+                Cell::Number(v) => Some(*v),
+                _ => None,
+            })?,
+        ),
+        DataType::Date => {
+            let days = convert_cells(cells, |cell| match cell {
+                Cell::Temporal(v, TemporalKind::Date) => Some((v.floor() - epoch_offset) as i32),
+                _ => None,
+            })?;
+            Int32Chunked::new(name, &days).into_date().into_series()
+        },
+        DataType::Datetime(_, _) => {
+            let ms = convert_cells(cells, |cell| match cell {
+                Cell::Temporal(v, TemporalKind::Date | TemporalKind::Datetime) => {
+                    Some(serial_to_ms(*v, epoch_offset))
+                },
+                _ => None,
+            })?;
+            Int64Chunked::new(name, &ms)
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series()
+        },
+        DataType::Time => {
+            let ns = convert_cells(cells, |cell| match cell {
+                Cell::Temporal(v, TemporalKind::Time) => Some(serial_to_time_ns(*v)),
+                _ => None,
+            })?;
+            Int64Chunked::new(name, &ns).into_time().into_series()
+        },
+        _ => Series::new(
+            name,
+            convert_cells(cells, |cell| Some(cell.to_string(epoch_offset)))?,
+        ),
+    };
+    Ok(s)
+}
+
+enum SheetSelection {
+    Index(usize),
+    Name(String),
+}
+
+/// Read a sheet of an Excel workbook (`.xlsx`) into a DataFrame.
+///
+/// The dtype of every column is inferred from the cells in the first
+/// [`infer_schema`](Self::infer_schema) rows of data: booleans, integers, floats, and numbers
+/// formatted as dates, datetimes or times are read as such, columns of mixed cell types as
+/// strings. Empty and error cells are read as nulls.
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("report.xlsx")?;
+///     ExcelReader::new(file)
+///         .with_sheet_name("sales")
+///         .with_header_row(Some(2))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct ExcelReader<R: Read> {
+    reader: R,
+    sheet: SheetSelection,
+    header_row: Option<usize>,
+    infer_schema_length: Option<usize>,
+    n_rows: Option<usize>,
+}
+
+impl<R: Read> ExcelReader<R> {
+    /// Read the sheet at the given 0-based position in the workbook. Defaults to the first sheet.
+    pub fn with_sheet_index(mut self, idx: usize) -> Self {
+        self.sheet = SheetSelection::Index(idx);
+        self
+    }
+
+    /// Read the sheet with the given name.
+    pub fn with_sheet_name(mut self, name: &str) -> Self {
+        self.sheet = SheetSelection::Name(name.to_string());
+        self
+    }
+
+    /// The 0-based row of the sheet that holds the column names. The data starts at the row
+    /// below it and the rows above it are skipped. If `None`, all rows are data and the columns
+    /// are named `column_1`, `column_2`, etc. Defaults to `Some(0)`.
+    pub fn with_header_row(mut self, header_row: Option<usize>) -> Self {
+        self.header_row = header_row;
+        self
+    }
+
+    /// Set the number of rows of data used to infer the dtypes of the columns. If `None`, all
+    /// rows are used. Defaults to `Some(100)`.
+    pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
+        self.infer_schema_length = max_records;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows of data.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+}
+
+impl<R: Read> SerReader<R> for ExcelReader<R> {
+    fn new(reader: R) -> Self {
+        ExcelReader {
+            reader,
+            sheet: SheetSelection::Index(0),
+            header_row: Some(0),
+            infer_schema_length: Some(100),
+            n_rows: None,
+        }
+    }
+
+    fn finish(self) -> PolarsResult<DataFrame> {
+        let archive = ZipArchive::new(self.reader)?;
+        let workbook = read_workbook(&archive)?;
+        let path = match &self.sheet {
+            SheetSelection::Index(idx) => workbook.sheets.get(*idx).map(|(_, path)| path),
+            SheetSelection::Name(name) => workbook
+                .sheets
+                .iter()
+                .find(|(sheet, _)| sheet == name)
+                .map(|(_, path)| path),
+        };
+        let path = path.ok_or_else(|| {
+            let names = workbook
+                .sheets
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            match &self.sheet {
+                SheetSelection::Index(idx) => polars_err!(
+                    ComputeError: "sheet index {} is out of bounds for a workbook with sheets {:?}",
+                    idx, names
+                ),
+                SheetSelection::Name(name) => polars_err!(
+                    ComputeError: "workbook has no sheet named '{}', its sheets are {:?}",
+                    name, names
+                ),
+            }
+        })?;
+        let strings = read_shared_strings(&archive)?;
+        let styles = read_styles(&archive)?;
+        let mut rows = read_sheet(&read_part(&archive, path)?, &strings, &styles)?;
+
+        let (header, first_row) = match self.header_row {
+            Some(header_row) => (
+                rows.get_mut(header_row)
+                    .map(std::mem::take)
+                    .unwrap_or_default(),
+                header_row + 1,
+            ),
+            None => (vec![], 0),
+        };
+        let mut data = rows.get(first_row..).unwrap_or_default();
+        if let Some(n_rows) = self.n_rows {
+            data = &data[..n_rows.min(data.len())];
+        }
+        // Rows that only carry formatting may follow the data.
+        while data.last().is_some_and(|row| row.is_empty()) {
+            data = &data[..data.len() - 1];
+        }
+        let width = data
+            .iter()
+            .map(|row| row.len())
+            .chain(std::iter::once(header.len()))
+            .max()
+            .unwrap_or(0);
+
+        let empty = Cell::Empty;
+        let mut name_counts = PlHashMap::with_capacity(width);
+        let mut columns = Vec::with_capacity(width);
+        for col in 0..width {
+            let mut name = header
+                .get(col)
+                .map(|cell| cell.to_string(workbook.epoch_offset))
+                .unwrap_or_default();
+            if name.is_empty() {
+                name = format!("column_{}", col + 1);
+            }
+            let count = name_counts.entry(name.clone()).or_insert(0usize);
+            if *count != 0 {
+                name = format!("{}_duplicated_{}", name, *count - 1);
+            }
+            *count += 1;
+
+            let cells = data
+                .iter()
+                .map(|row| row.get(col).unwrap_or(&empty))
+                .collect::<Vec<_>>();
+            let window = self.infer_schema_length.unwrap_or(usize::MAX);
+            let dtype = infer_dtype(cells.iter().take(window).copied());
+            let s = build_column(&name, &cells, &dtype, workbook.epoch_offset).map_err(|row| {
+                polars_err!(
+                    ComputeError: "could not read cell {}{} of column '{}' as {}; consider \
+                    increasing infer_schema_length",
+                    column_letters(col), first_row + row + 1, name, dtype
+                )
+            })?;
+            columns.push(s);
+        }
+        DataFrame::new(columns)
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt::Write as _;
+use std::io::Write;
+
+use polars_core::prelude::*;
+
+use super::zip::ZipWriter;
+use super::*;
+use crate::SerWriter;
+
+const XML_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
+const MAIN_NAMESPACE: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const PACKAGE_RELATIONSHIPS_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships";
+
+// The indices of the cell formats in `styles.xml`.
+const DATE_STYLE: usize = 1;
+const DATETIME_STYLE: usize = 2;
+const TIME_STYLE: usize = 3;
+
+const STYLES: &str = concat!(
+    r#"<numFmts count="3">"#,
+    r#"<numFmt numFmtId="164" formatCode="yyyy\-mm\-dd"/>"#,
+    r#"<numFmt numFmtId="165" formatCode="yyyy\-mm\-dd\ hh:mm:ss"/>"#,
+    r#"<numFmt numFmtId="166" formatCode="hh:mm:ss"/>"#,
+    r#"</numFmts>"#,
+    r#"<fonts count="1"><font><sz val="11"/><name val="Calibri"/><family val="2"/></font></fonts>"#,
+    r#"<fills count="2"><fill><patternFill patternType="none"/></fill>"#,
+    r#"<fill><patternFill patternType="gray125"/></fill></fills>"#,
+    r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+    r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+    r#"<cellXfs count="4">"#,
+    r#"<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>"#,
+    r#"<xf numFmtId="164" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+    r#"<xf numFmtId="165" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+    r#"<xf numFmtId="166" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+    r#"</cellXfs>"#,
+    r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles>"#,
+);
+
+/// The values of a column in the form in which they are written to cells.
+enum CellValues {
+    Null,
+    Bool(BooleanChunked),
+    Int(Int64Chunked),
+    Float(Float64Chunked),
+    /// Date serial numbers with the index of their cell format.
+    Temporal(Float64Chunked, usize),
+    String(StringChunked),
+}
+
+/// Convert the physical values of a temporal column to serial numbers, i.e. days since `epoch`.
+fn to_serials(s: &Series, units_per_day: f64, epoch: f64) -> PolarsResult<Float64Chunked> {
+    let ca = s.to_physical_repr().cast(&DataType::Float64)?;
+    Ok(ca
+        .f64()?
+        .apply_values(|v| v / units_per_day + epoch)
+        .rechunk())
+}
+
+fn cell_values(s: &Series) -> PolarsResult<CellValues> {
+    use DataType::*;
+    let values = match s.dtype() {
+        Null => CellValues::Null,
+        Boolean => CellValues::Bool(s.bool()?.rechunk()),
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 => {
+            CellValues::Int(s.cast(&Int64)?.i64()?.rechunk())
+        },
+        UInt64 | Float32 | Float64 => CellValues::Float(s.cast(&Float64)?.f64()?.rechunk()),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => CellValues::Float(s.cast(&Float64)?.f64()?.rechunk()),
+        Date => CellValues::Temporal(to_serials(s, 1.0, EPOCH_OFFSET_1900)?, DATE_STYLE),
+        Datetime(tu, _) => {
+            let units_per_day = match tu {
+                TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+                TimeUnit::Microseconds => 86_400_000_000.0,
+                TimeUnit::Milliseconds => MS_PER_DAY,
+            };
+            let serials = to_serials(s, units_per_day, EPOCH_OFFSET_1900)?;
+            CellValues::Temporal(serials, DATETIME_STYLE)
+        },
+        // Times of day are the fractional part of a serial number.
+        Time => CellValues::Temporal(to_serials(s, 86_400_000_000_000.0, 0.0)?, TIME_STYLE),
+        String => CellValues::String(s.str()?.rechunk()),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => CellValues::String(s.cast(&String)?.str()?.rechunk()),
+        dt => polars_bail!(
+            ComputeError: "column '{}' of dtype {} cannot be written to Excel", s.name(), dt
+        ),
+    };
+    Ok(values)
+}
+
+fn write_string_cell(out: &mut String, reference: &str, value: &str) {
+    let space = if value.starts_with(char::is_whitespace) || value.ends_with(char::is_whitespace) {
+        r#" xml:space="preserve""#
+    } else {
+        ""
+    };
+    write!(out, r#"<c r="{reference}" t="inlineStr"><is><t{space}>"#).unwrap();
+    escape(value, out);
+    out.push_str("</t></is></c>");
+}
+
+fn write_float_cell(out: &mut String, reference: &str, value: f64, style: Option<usize>) {
+    let result = match style {
+        // Excel has no representation of non-finite numbers other than an error.
+        _ if !value.is_finite() => write!(out, r##"<c r="{reference}" t="e"><v>#NUM!</v></c>"##),
+        Some(style) => write!(
+            out,
+            r#"<c r="{reference}" s="{style}"><v>{value:?}</v></c>"#
+        ),
+        None => write!(out, r#"<c r="{reference}"><v>{value:?}</v></c>"#),
+    };
+    result.unwrap()
+}
+
+fn write_worksheet(df: &DataFrame, include_header: bool) -> PolarsResult<String> {
+    let n_rows = df.height() + include_header as usize;
+    polars_ensure!(
+        n_rows <= MAX_ROWS && df.width() <= MAX_COLUMNS,
+        ComputeError: "a DataFrame of shape {:?} does not fit in an Excel sheet, which holds at \
+        most {} rows and {} columns", df.shape(), MAX_ROWS, MAX_COLUMNS
+    );
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(cell_values)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let letters = (0..df.width()).map(column_letters).collect::<Vec<_>>();
+
+    let mut out = format!(r#"{XML_HEADER}<worksheet xmlns="{MAIN_NAMESPACE}"><sheetData>"#);
+    let mut reference = String::new();
+    if include_header {
+        out.push_str(r#"<row r="1">"#);
+        for (s, letters) in df.get_columns().iter().zip(&letters) {
+            write_string_cell(&mut out, &format!("{letters}1"), s.name());
+        }
+        out.push_str("</row>");
+    }
+    for row in 0..df.height() {
+        let row_number = row + 1 + include_header as usize;
+        write!(out, r#"<row r="{row_number}">"#).unwrap();
+        for (values, letters) in columns.iter().zip(&letters) {
+            reference.clear();
+            write!(reference, "{letters}{row_number}").unwrap();
+            match values {
+                CellValues::Null => {},
+                CellValues::Bool(ca) => {
+                    if let Some(v) = ca.get(row) {
+                        write!(out, r#"<c r="{reference}" t="b"><v>{}</v></c>"#, v as u8).unwrap()
+                    }
+                },
+                CellValues::Int(ca) => {
+                    if let Some(v) = ca.get(row) {
+                        write!(out, r#"<c r="{reference}"><v>{v}</v></c>"#).unwrap()
+                    }
+                },
+                CellValues::Float(ca) => {
+                    if let Some(v) = ca.get(row) {
+                        write_float_cell(&mut out, &reference, v, None)
+                    }
+                },
+                CellValues::Temporal(ca, style) => {
+                    if let Some(v) = ca.get(row) {
+                        write_float_cell(&mut out, &reference, v, Some(*style))
+                    }
+                },
+                CellValues::String(ca) => {
+                    if let Some(v) = ca.get(row) {
+                        write_string_cell(&mut out, &reference, v)
+                    }
+                },
+            }
+        }
+        out.push_str("</row>");
+    }
+    out.push_str("</sheetData></worksheet>");
+    Ok(out)
+}
+
+fn validate_sheet_names(sheets: &[(&str, &DataFrame)]) -> PolarsResult<()> {
+    polars_ensure!(
+        !sheets.is_empty(),
+        ComputeError: "an Excel workbook must have at least one sheet"
+    );
+    let mut seen = PlHashSet::with_capacity(sheets.len());
+    for (name, _) in sheets {
+        polars_ensure!(
+            !name.is_empty()
+                && name.chars().count() <= 31
+                && !name.contains(['[', ']', ':', '*', '?', '/', '\\'])
+                && !name.starts_with('\'')
+                && !name.ends_with('\''),
+            ComputeError: "invalid sheet name '{}': sheet names must have 1 to 31 characters, \
+            none of []:*?/\\ and must not start or end with an apostrophe", name
+        );
+        // Excel compares sheet names case-insensitively.
+        polars_ensure!(
+            seen.insert(name.to_lowercase()),
+            Duplicate: "sheet name '{}' is used more than once", name
+        );
+    }
+    Ok(())
+}
+
+/// Write DataFrames to the sheets of an Excel workbook (`.xlsx`).
+///
+/// Numbers, booleans and strings are written as such; dates, datetimes and times as numbers with
+/// a date or time format, where datetimes with a time zone are written in UTC. Nulls are written
+/// as empty cells and non-finite floats as `#NUM!` errors. Nested dtypes are not supported.
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let file = File::create("example.xlsx")?;
+///     ExcelWriter::new(file).finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct ExcelWriter<W: Write> {
+    writer: W,
+    include_header: bool,
+}
+
+impl<W: Write> ExcelWriter<W> {
+    /// Write the column names to the first row of every sheet. Defaults to `true`.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    /// Write a workbook with a sheet for every DataFrame, in the given order and with the given
+    /// names.
+    pub fn finish_sheets(&mut self, sheets: &[(&str, &DataFrame)]) -> PolarsResult<()> {
+        validate_sheet_names(sheets)?;
+        let mut zip = ZipWriter::new(&mut self.writer);
+
+        let mut content_types = format!(
+            "{XML_HEADER}<Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+            <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+            <Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+            <Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>\
+            <Override PartName=\"/xl/styles.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml\"/>"
+        );
+        let mut workbook = format!(
+            r#"{XML_HEADER}<workbook xmlns="{MAIN_NAMESPACE}" xmlns:r="{RELATIONSHIPS_NAMESPACE}"><sheets>"#
+        );
+        let mut workbook_rels =
+            format!(r#"{XML_HEADER}<Relationships xmlns="{PACKAGE_RELATIONSHIPS_NAMESPACE}">"#);
+        for (i, (name, _)) in sheets.iter().enumerate() {
+            let n = i + 1;
+            write!(
+                content_types,
+                "<Override PartName=\"/xl/worksheets/sheet{n}.xml\" \
+                ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>"
+            )
+            .unwrap();
+            workbook.push_str(r#"<sheet name=""#);
+            escape(name, &mut workbook);
+            write!(workbook, r#"" sheetId="{n}" r:id="rId{n}"/>"#).unwrap();
+            write!(
+                workbook_rels,
+                r#"<Relationship Id="rId{n}" Type="{RELATIONSHIPS_NAMESPACE}/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+            )
+            .unwrap();
+        }
+        content_types.push_str("</Types>");
+        workbook.push_str("</sheets></workbook>");
+        write!(
+            workbook_rels,
+            r#"<Relationship Id="rId{}" Type="{RELATIONSHIPS_NAMESPACE}/styles" Target="styles.xml"/></Relationships>"#,
+            sheets.len() + 1
+        )
+        .unwrap();
+
+        zip.add("[Content_Types].xml", content_types.as_bytes())?;
+        zip.add(
+            "_rels/.rels",
+            format!(
+                r#"{XML_HEADER}<Relationships xmlns="{PACKAGE_RELATIONSHIPS_NAMESPACE}"><Relationship Id="rId1" Type="{RELATIONSHIPS_NAMESPACE}/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+            )
+            .as_bytes(),
+        )?;
+        zip.add("xl/workbook.xml", workbook.as_bytes())?;
+        zip.add("xl/_rels/workbook.xml.rels", workbook_rels.as_bytes())?;
+        zip.add(
+            "xl/styles.xml",
+            format!(r#"{XML_HEADER}<styleSheet xmlns="{MAIN_NAMESPACE}">{STYLES}</styleSheet>"#)
+                .as_bytes(),
+        )?;
+        for (i, (_, df)) in sheets.iter().enumerate() {
+            let sheet = write_worksheet(df, self.include_header)?;
+            zip.add(
+                &format!("xl/worksheets/sheet{}.xml", i + 1),
+                sheet.as_bytes(),
+            )?;
+        }
+        zip.finish()
+    }
+}
+
+impl<W: Write> SerWriter<W> for ExcelWriter<W> {
+    fn new(writer: W) -> Self {
+        ExcelWriter {
+            writer,
+            include_header: true,
+        }
+    }
+
+    /// Write a workbook with a single sheet named `Sheet1`.
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        self.finish_sheets(&[("Sheet1", &*df)])
+    }
+}
@@ -0,0 +1,271 @@
+//! A minimal reader and writer of zip archives, the container of xlsx files.
+//!
+//! Only what xlsx needs is supported: stored and deflated entries without encryption, and no
+//! zip64 extensions.
+use std::io::{Read, Write};
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+use polars_core::prelude::*;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const END_OF_CENTRAL_DIRECTORY_LEN: usize = 22;
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+// Zip 2.0, which introduced deflate.
+const VERSION: u16 = 20;
+// 1980-01-01 00:00:00 in MS-DOS format, the earliest representable timestamp.
+const DOS_TIME: u16 = 0;
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+fn read_u16(buf: &[u8], offset: usize) -> PolarsResult<u16> {
+    buf.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or_else(|| polars_err!(ComputeError: "corrupt zip archive"))
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> PolarsResult<u32> {
+    buf.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| polars_err!(ComputeError: "corrupt zip archive"))
+}
+
+struct ZipEntry {
+    name: String,
+    method: u16,
+    compressed_size: usize,
+    header_offset: usize,
+}
+
+/// The entries of a zip archive that is held in memory.
+pub(super) struct ZipArchive {
+    data: Vec<u8>,
+    entries: Vec<ZipEntry>,
+}
+
+impl ZipArchive {
+    pub(super) fn new(mut reader: impl Read) -> PolarsResult<Self> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        // The end of central directory record is followed by a comment of at most 64 KiB.
+        let end = (END_OF_CENTRAL_DIRECTORY_LEN
+            ..=data.len().min(END_OF_CENTRAL_DIRECTORY_LEN + 0xFFFF))
+            .map(|len| data.len() - len)
+            .find(|&offset| {
+                read_u32(&data, offset).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE)
+            })
+            .ok_or_else(|| polars_err!(ComputeError: "not a zip archive"))?;
+        let n_entries = read_u16(&data, end + 10)? as usize;
+        let mut offset = read_u32(&data, end + 16)? as usize;
+        polars_ensure!(
+            n_entries != 0xFFFF && offset != 0xFFFFFFFF,
+            ComputeError: "zip64 archives are not supported"
+        );
+
+        let mut entries = Vec::with_capacity(n_entries);
+        for _ in 0..n_entries {
+            polars_ensure!(
+                read_u32(&data, offset)? == CENTRAL_HEADER_SIGNATURE,
+                ComputeError: "corrupt zip archive"
+            );
+            let flags = read_u16(&data, offset + 8)?;
+            let method = read_u16(&data, offset + 10)?;
+            let compressed_size = read_u32(&data, offset + 20)? as usize;
+            let name_len = read_u16(&data, offset + 28)? as usize;
+            let extra_len = read_u16(&data, offset + 30)? as usize;
+            let comment_len = read_u16(&data, offset + 32)? as usize;
+            let header_offset = read_u32(&data, offset + 42)? as usize;
+            let name = data
+                .get(offset + 46..offset + 46 + name_len)
+                .ok_or_else(|| polars_err!(ComputeError: "corrupt zip archive"))?;
+            let name = String::from_utf8_lossy(name).into_owned();
+            polars_ensure!(
+                flags & 1 == 0,
+                ComputeError: "encrypted zip entry {} is not supported", name
+            );
+            entries.push(ZipEntry {
+                name,
+                method,
+                compressed_size,
+                header_offset,
+            });
+            offset += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(Self { data, entries })
+    }
+
+    /// The decompressed contents of the entry with the given name, `None` if there is none.
+    pub(super) fn read(&self, name: &str) -> PolarsResult<Option<Vec<u8>>> {
+        let Some(entry) = self.entries.iter().find(|e| e.name == name) else {
+            return Ok(None);
+        };
+        let offset = entry.header_offset;
+        polars_ensure!(
+            read_u32(&self.data, offset)? == LOCAL_HEADER_SIGNATURE,
+            ComputeError: "corrupt zip archive"
+        );
+        // The extra field of the local header may differ from the one in the central directory.
+        let start = offset
+            + 30
+            + read_u16(&self.data, offset + 26)? as usize
+            + read_u16(&self.data, offset + 28)? as usize;
+        let compressed = self
+            .data
+            .get(start..start + entry.compressed_size)
+            .ok_or_else(|| polars_err!(ComputeError: "corrupt zip archive"))?;
+
+        let out = match entry.method {
+            STORED => compressed.to_vec(),
+            DEFLATED => {
+                let mut out = vec![];
+                DeflateDecoder::new(compressed).read_to_end(&mut out)?;
+                out
+            },
+            method => polars_bail!(
+                ComputeError: "compression method {} of zip entry {} is not supported",
+                method, entry.name
+            ),
+        };
+        Ok(Some(out))
+    }
+}
+
+struct WrittenEntry {
+    name: String,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    header_offset: u32,
+}
+
+/// Writes a zip archive of deflated entries to a stream.
+pub(super) struct ZipWriter<W> {
+    writer: W,
+    offset: usize,
+    entries: Vec<WrittenEntry>,
+}
+
+impl<W: Write> ZipWriter<W> {
+    pub(super) fn new(writer: W) -> Self {
+        Self {
+            writer,
+            offset: 0,
+            entries: vec![],
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> PolarsResult<()> {
+        self.writer.write_all(buf)?;
+        self.offset += buf.len();
+        Ok(())
+    }
+
+    pub(super) fn add(&mut self, name: &str, data: &[u8]) -> PolarsResult<()> {
+        let mut crc = Crc::new();
+        crc.update(data);
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder.write_all(data)?;
+        let compressed = encoder.finish()?;
+
+        let to_u32 = |len: usize| {
+            u32::try_from(len)
+                .map_err(|_| polars_err!(ComputeError: "zip entry {} is too large", name))
+        };
+        let entry = WrittenEntry {
+            name: name.to_string(),
+            crc: crc.sum(),
+            compressed_size: to_u32(compressed.len())?,
+            size: to_u32(data.len())?,
+            header_offset: to_u32(self.offset)?,
+        };
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&LOCAL_HEADER_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        // flags
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&DEFLATED.to_le_bytes());
+        header.extend_from_slice(&DOS_TIME.to_le_bytes());
+        header.extend_from_slice(&DOS_DATE.to_le_bytes());
+        header.extend_from_slice(&entry.crc.to_le_bytes());
+        header.extend_from_slice(&entry.compressed_size.to_le_bytes());
+        header.extend_from_slice(&entry.size.to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        // extra field length
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(name.as_bytes());
+        self.write_all(&header)?;
+        self.write_all(&compressed)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Write the central directory, which completes the archive.
+    pub(super) fn finish(mut self) -> PolarsResult<()> {
+        let start = self.offset;
+        let mut directory = vec![];
+        for entry in &self.entries {
+            directory.extend_from_slice(&CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+            // version made by and version needed
+            directory.extend_from_slice(&VERSION.to_le_bytes());
+            directory.extend_from_slice(&VERSION.to_le_bytes());
+            // flags
+            directory.extend_from_slice(&0u16.to_le_bytes());
+            directory.extend_from_slice(&DEFLATED.to_le_bytes());
+            directory.extend_from_slice(&DOS_TIME.to_le_bytes());
+            directory.extend_from_slice(&DOS_DATE.to_le_bytes());
+            directory.extend_from_slice(&entry.crc.to_le_bytes());
+            directory.extend_from_slice(&entry.compressed_size.to_le_bytes());
+            directory.extend_from_slice(&entry.size.to_le_bytes());
+            directory.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            // extra field length, comment length, disk number, internal and external attributes
+            directory.extend_from_slice(&[0; 12]);
+            directory.extend_from_slice(&entry.header_offset.to_le_bytes());
+            directory.extend_from_slice(entry.name.as_bytes());
+        }
+        let n_entries = self.entries.len() as u16;
+        self.write_all(&directory)?;
+
+        let mut end = Vec::with_capacity(END_OF_CENTRAL_DIRECTORY_LEN);
+        end.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        // number of this disk and of the disk with the central directory
+        end.extend_from_slice(&[0; 4]);
+        end.extend_from_slice(&n_entries.to_le_bytes());
+        end.extend_from_slice(&n_entries.to_le_bytes());
+        end.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        end.extend_from_slice(&(start as u32).to_le_bytes());
+        // comment length
+        end.extend_from_slice(&0u16.to_le_bytes());
+        self.write_all(&end)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zip_round_trip() -> PolarsResult<()> {
+        let mut buf = vec![];
+        let mut writer = ZipWriter::new(&mut buf);
+        writer.add("a.txt", b"hello")?;
+        writer.add("dir/b.xml", "<a>b</a>".repeat(100).as_bytes())?;
+        writer.finish()?;
+
+        let archive = ZipArchive::new(buf.as_slice())?;
+        assert_eq!(archive.read("a.txt")?.as_deref(), Some(b"hello".as_slice()));
+        assert_eq!(
+            archive.read("dir/b.xml")?,
+            Some("<a>b</a>".repeat(100).into_bytes())
+        );
+        assert!(archive.read("c")?.is_none());
+        assert!(ZipArchive::new(b"not a zip".as_slice()).is_err());
+        Ok(())
+    }
+}
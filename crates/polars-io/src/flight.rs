@@ -0,0 +1,207 @@
+//! # Read from Arrow Flight servers
+//!
+//! A [`FlightReader`] sends a ticket to the `DoGet` method of an
+//! [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) server and decodes the stream
+//! of record batches that the server returns for it, such as the result of a query.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::flight::FlightReader;
+//!
+//! fn example() -> PolarsResult<DataFrame> {
+//!     FlightReader::new("grpc://localhost:8815", "SELECT * FROM trips").finish()
+//! }
+//! ```
+use arrow::io::flight::{deserialize_message, deserialize_schemas};
+use arrow::io::ipc::read::Dictionaries;
+use arrow::io::ipc::IpcSchema;
+use arrow_format::flight::data::{FlightData, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::pl_async::get_runtime;
+
+/// The URI of the gRPC channel to a Flight location. Flight names plaintext locations
+/// `grpc://` or `grpc+tcp://` and encrypted ones `grpc+tls://`.
+fn channel_uri(endpoint: &str) -> PolarsResult<String> {
+    let (scheme, rest) = endpoint.split_once("://").ok_or_else(
+        || polars_err!(ComputeError: "expected a flight location like grpc://host:port, got '{}'", endpoint),
+    )?;
+    let scheme = match scheme {
+        "grpc" | "grpc+tcp" | "http" => "http",
+        "grpc+tls" | "https" => "https",
+        _ => polars_bail!(
+            ComputeError: "unsupported scheme of flight location '{}', expected one of \
+            grpc, grpc+tcp or grpc+tls", endpoint
+        ),
+    };
+    Ok(format!("{scheme}://{rest}"))
+}
+
+struct FlightStream {
+    messages: BoxStream<'static, PolarsResult<FlightData>>,
+    schema: ArrowSchema,
+    ipc_schema: IpcSchema,
+    dictionaries: Dictionaries,
+}
+
+impl FlightStream {
+    async fn open(endpoint: &str, ticket: Vec<u8>) -> PolarsResult<Self> {
+        let mut client = FlightServiceClient::connect(channel_uri(endpoint)?)
+            .await
+            .map_err(|e| {
+                polars_err!(
+                    ComputeError: "could not connect to the flight server at {}: {}", endpoint, e
+                )
+            })?;
+        let response = client.do_get(Ticket { ticket }).await.map_err(
+            |status| polars_err!(ComputeError: "flight request to {} failed: {}", endpoint, status),
+        )?;
+        let mut messages = response
+            .into_inner()
+            .map(move |data| {
+                // The client owns the channel the stream is read from.
+                let _ = &client;
+                data.map_err(|status| polars_err!(ComputeError: "flight stream failed: {}", status))
+            })
+            .boxed();
+
+        // The first message with a header holds the schema.
+        let (schema, ipc_schema) = loop {
+            match messages.next().await {
+                Some(data) => {
+                    let data = data?;
+                    if !data.data_header.is_empty() {
+                        break deserialize_schemas(&data.data_header)?;
+                    }
+                },
+                None => polars_bail!(
+                    ComputeError: "flight stream of {} ended without a schema", endpoint
+                ),
+            }
+        };
+        Ok(Self {
+            messages,
+            schema,
+            ipc_schema,
+            dictionaries: Default::default(),
+        })
+    }
+}
+
+/// Reads the record batches that an Arrow Flight server returns for a ticket.
+///
+/// The request is sent on the first call of [`schema`](Self::schema),
+/// [`next_batch`](Self::next_batch) or [`finish`](Self::finish).
+pub struct FlightReader {
+    endpoint: String,
+    ticket: Vec<u8>,
+    columns: Option<Vec<String>>,
+    stream: Option<FlightStream>,
+}
+
+impl FlightReader {
+    /// Read the stream that the server at `endpoint`, e.g. `grpc://localhost:8815`, returns for
+    /// `ticket`.
+    pub fn new(endpoint: impl Into<String>, ticket: impl Into<Vec<u8>>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ticket: ticket.into(),
+            columns: None,
+            stream: None,
+        }
+    }
+
+    /// Only read these columns.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    fn stream(&mut self) -> PolarsResult<&mut FlightStream> {
+        if self.stream.is_none() {
+            let endpoint = self.endpoint.clone();
+            let ticket = self.ticket.clone();
+            let stream = get_runtime().block_on_potential_spawn(async move {
+                FlightStream::open(&endpoint, ticket).await
+            })?;
+            self.stream = Some(stream);
+        }
+        Ok(self.stream.as_mut().unwrap())
+    }
+
+    /// The schema of the stream, which is sent ahead of its record batches.
+    pub fn schema(&mut self) -> PolarsResult<ArrowSchema> {
+        Ok(self.stream()?.schema.clone())
+    }
+
+    /// The next record batch of the stream, `None` at its end.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let stream = self.stream()?;
+        let df = loop {
+            let Some(data) = get_runtime().block_on_potential_spawn(stream.messages.next()) else {
+                return Ok(None);
+            };
+            let data = data?;
+            // Messages without a header only carry application metadata.
+            if data.data_header.is_empty() {
+                continue;
+            }
+            if let Some(chunk) = deserialize_message(
+                &data,
+                &stream.schema.fields,
+                &stream.ipc_schema,
+                &mut stream.dictionaries,
+            )? {
+                break DataFrame::try_from((chunk, stream.schema.fields.as_slice()))?;
+            }
+        };
+        match &self.columns {
+            Some(columns) => df.select(columns).map(Some),
+            None => Ok(Some(df)),
+        }
+    }
+
+    /// Read the remaining record batches into a single DataFrame.
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        let mut dfs = vec![];
+        while let Some(df) = self.next_batch()? {
+            dfs.push(df);
+        }
+        if dfs.is_empty() {
+            let schema = Schema::from(&self.schema()?);
+            let df = DataFrame::from(&schema);
+            return match &self.columns {
+                Some(columns) => df.select(columns),
+                None => Ok(df),
+            };
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_channel_uri() -> PolarsResult<()> {
+        assert_eq!(
+            channel_uri("grpc://localhost:8815")?,
+            "http://localhost:8815"
+        );
+        assert_eq!(channel_uri("grpc+tcp://10.0.0.1:80")?, "http://10.0.0.1:80");
+        assert_eq!(
+            channel_uri("grpc+tls://example.com:443")?,
+            "https://example.com:443"
+        );
+        assert!(channel_uri("localhost:8815").is_err());
+        assert!(channel_uri("grpc+unix:///tmp/flight.sock").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,716 @@
+//! Read the state of an [Apache Iceberg](https://iceberg.apache.org) table.
+//!
+//! The state of an Iceberg table is described by a JSON metadata file in its `metadata`
+//! directory, which lists the schemas, the partition specs and the snapshots of the table. A
+//! snapshot points to a manifest list, an Avro file that lists the manifests of the snapshot.
+//! Every manifest is an Avro file that lists data files, with the partition values of a file and
+//! the lower and upper bounds and null counts of its columns.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use arrow::array::{Array, ListArray, StructArray};
+use arrow::compute::concatenate::concatenate;
+use arrow::io::avro::{avro_schema, read};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde_json::Value;
+
+use crate::predicates::{BatchStats, ColumnStats};
+
+/// The status of a manifest entry of a data file that was deleted in its snapshot.
+const STATUS_DELETED: i32 = 2;
+/// The content of a manifest that tracks delete files.
+const CONTENT_DELETES: i32 = 1;
+
+/// A data file of an Iceberg table.
+#[derive(Debug)]
+pub struct IcebergFile {
+    pub path: PathBuf,
+    pub record_count: Option<usize>,
+    /// The statistics of the columns of the table in this file, built from its partition values
+    /// and the column bounds of its manifest entry. They can be used to skip the file.
+    pub stats: BatchStats,
+}
+
+/// The state of an Iceberg table at a snapshot.
+#[derive(Debug)]
+pub struct IcebergSnapshot {
+    /// The id of the snapshot, `None` if the table has no snapshots.
+    pub snapshot_id: Option<i64>,
+    pub schema: SchemaRef,
+    /// The live data files of the snapshot.
+    pub files: Vec<IcebergFile>,
+}
+
+/// A partition field with the identity transform, whose values are the values of its source
+/// column.
+struct IdentityPartition {
+    name: String,
+    source: String,
+}
+
+impl IcebergSnapshot {
+    /// Read the state of the Iceberg table at `path` at the snapshot with `snapshot_id`, or at
+    /// its current snapshot. `path` is either the directory of the table or a metadata file.
+    pub fn read(path: &Path, snapshot_id: Option<i64>) -> PolarsResult<Self> {
+        let metadata_path = metadata_path(path)?;
+        let content = fs::read_to_string(&metadata_path)?;
+        let metadata: Value = serde_json::from_str(&content).map_err(|e| {
+            polars_err!(
+                ComputeError: "invalid Iceberg metadata file {}: {}", metadata_path.display(), e
+            )
+        })?;
+
+        let current_snapshot_id = metadata["current-snapshot-id"]
+            .as_i64()
+            .filter(|&id| id != -1);
+        let snapshot = match snapshot_id.or(current_snapshot_id) {
+            Some(id) => {
+                let snapshot = metadata["snapshots"]
+                    .as_array()
+                    .and_then(|snapshots| {
+                        snapshots
+                            .iter()
+                            .find(|s| s["snapshot-id"].as_i64() == Some(id))
+                    })
+                    .ok_or_else(|| {
+                        polars_err!(
+                            ComputeError: "snapshot {} of the Iceberg table at {} does not exist",
+                            id, path.display()
+                        )
+                    })?;
+                Some(snapshot)
+            },
+            None => None,
+        };
+
+        // Time travel reads a snapshot with the schema it was written with.
+        let schema_id = match (snapshot_id, snapshot) {
+            (Some(_), Some(snapshot)) => snapshot["schema-id"].as_i64(),
+            _ => None,
+        };
+        let (schema, ids) = table_schema(&metadata, schema_id)?;
+        let schema = Arc::new(schema);
+
+        let mut files = vec![];
+        if let Some(snapshot) = snapshot {
+            let partitions = identity_partitions(&metadata, &ids);
+            let default_spec_id = metadata["default-spec-id"].as_i64().unwrap_or(0);
+            for manifest in manifests(snapshot)? {
+                let spec_id = manifest.spec_id.unwrap_or(default_spec_id);
+                let partitions = partitions
+                    .iter()
+                    .find(|(id, _)| *id == spec_id)
+                    .map(|(_, partitions)| partitions.as_slice())
+                    .unwrap_or_default();
+                read_manifest(&manifest, partitions, &schema, &ids, &mut files)?;
+            }
+        }
+
+        Ok(IcebergSnapshot {
+            snapshot_id: snapshot.and_then(|s| s["snapshot-id"].as_i64()),
+            schema,
+            files,
+        })
+    }
+}
+
+/// The metadata file of the table at `path`. If `path` is the table directory, this is the
+/// version named by `metadata/version-hint.text`, or else the latest version.
+fn metadata_path(path: &Path) -> PolarsResult<PathBuf> {
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    let dir = path.join("metadata");
+    if let Ok(hint) = fs::read_to_string(dir.join("version-hint.text")) {
+        let path = dir.join(format!("v{}.metadata.json", hint.trim()));
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+    let entries = fs::read_dir(&dir).map_err(|e| {
+        polars_err!(
+            ComputeError: "cannot read the Iceberg metadata at {}: {}", dir.display(), e
+        )
+    })?;
+    let mut latest: Option<(u64, PathBuf)> = None;
+    for entry in entries {
+        let entry = entry?;
+        let version = entry.file_name().to_str().and_then(parse_metadata_version);
+        if let Some(version) = version {
+            if latest
+                .as_ref()
+                .map_or(true, |(latest, _)| version > *latest)
+            {
+                latest = Some((version, entry.path()));
+            }
+        }
+    }
+    latest.map(|(_, path)| path).ok_or_else(
+        || polars_err!(ComputeError: "no Iceberg metadata files found in {}", dir.display()),
+    )
+}
+
+/// Parse the version of a metadata file name, e.g. `v3.metadata.json` or
+/// `00003-<uuid>.metadata.json`.
+fn parse_metadata_version(file_name: &str) -> Option<u64> {
+    let stem = file_name.strip_suffix(".metadata.json")?;
+    let stem = stem.strip_prefix('v').unwrap_or(stem);
+    stem.split('-').next()?.parse().ok()
+}
+
+/// The schema with `schema_id`, or the current schema, and the names of its top-level fields by
+/// field id.
+fn table_schema(
+    metadata: &Value,
+    schema_id: Option<i64>,
+) -> PolarsResult<(Schema, Vec<(i64, String)>)> {
+    let schema_id = schema_id.or(metadata["current-schema-id"].as_i64());
+    // Format version 1 stores a single schema.
+    let schema = match (metadata["schemas"].as_array(), schema_id) {
+        (Some(schemas), Some(id)) => schemas
+            .iter()
+            .find(|schema| schema["schema-id"].as_i64() == Some(id))
+            .ok_or_else(|| polars_err!(ComputeError: "Iceberg schema {} does not exist", id))?,
+        _ => &metadata["schema"],
+    };
+    let fields = schema["fields"]
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg schema: {}", schema))?;
+    let ids = fields
+        .iter()
+        .filter_map(|field| Some((field["id"].as_i64()?, field["name"].as_str()?.to_string())))
+        .collect();
+    Ok((struct_fields(schema)?.into_iter().collect(), ids))
+}
+
+/// The identity partition fields of every partition spec, by spec id.
+fn identity_partitions(
+    metadata: &Value,
+    ids: &[(i64, String)],
+) -> Vec<(i64, Vec<IdentityPartition>)> {
+    let identity = |fields: &Value| {
+        fields
+            .as_array()
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter(|field| field["transform"].as_str() == Some("identity"))
+                    .filter_map(|field| {
+                        let source_id = field["source-id"].as_i64()?;
+                        let (_, source) = ids.iter().find(|(id, _)| *id == source_id)?;
+                        Some(IdentityPartition {
+                            name: field["name"].as_str()?.to_string(),
+                            source: source.clone(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    match metadata["partition-specs"].as_array() {
+        Some(specs) => specs
+            .iter()
+            .map(|spec| {
+                (
+                    spec["spec-id"].as_i64().unwrap_or(0),
+                    identity(&spec["fields"]),
+                )
+            })
+            .collect(),
+        // Format version 1 stores a single partition spec.
+        None => vec![(0, identity(&metadata["partition-spec"]))],
+    }
+}
+
+struct Manifest {
+    path: PathBuf,
+    spec_id: Option<i64>,
+    content: i32,
+}
+
+/// The manifests of a snapshot, from its manifest list.
+fn manifests(snapshot: &Value) -> PolarsResult<Vec<Manifest>> {
+    // Format version 1 may list the manifests in the snapshot itself.
+    if let Some(manifests) = snapshot["manifests"].as_array() {
+        return manifests
+            .iter()
+            .map(|path| {
+                let path = path.as_str().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Iceberg manifest path: {}", path),
+                )?;
+                Ok(Manifest {
+                    path: local_path(path)?,
+                    spec_id: None,
+                    content: 0,
+                })
+            })
+            .collect();
+    }
+    let manifest_list = snapshot["manifest-list"]
+        .as_str()
+        .ok_or_else(|| polars_err!(ComputeError: "Iceberg snapshot has no `manifest-list`"))?;
+    let records = read_avro(&local_path(manifest_list)?)?;
+    let paths = to_series("manifest_path", field(&records, "manifest_path")?)?;
+    let spec_ids = optional_series(&records, "partition_spec_id")?;
+    let contents = optional_series(&records, "content")?;
+
+    let paths = paths.str()?;
+    (0..records.len())
+        .map(|i| {
+            let path = paths.get(i).ok_or_else(
+                || polars_err!(ComputeError: "Iceberg manifest list has a null `manifest_path`"),
+            )?;
+            Ok(Manifest {
+                path: local_path(path)?,
+                spec_id: get_i64(spec_ids.as_ref(), i)?,
+                content: get_i64(contents.as_ref(), i)?.unwrap_or(0) as i32,
+            })
+        })
+        .collect()
+}
+
+/// Add the live data files of a manifest to `files`.
+fn read_manifest(
+    manifest: &Manifest,
+    partitions: &[IdentityPartition],
+    schema: &SchemaRef,
+    ids: &[(i64, String)],
+    files: &mut Vec<IcebergFile>,
+) -> PolarsResult<()> {
+    let records = read_avro(&manifest.path)?;
+    let status = to_series("status", field(&records, "status")?)?;
+    let status = status.cast(&DataType::Int32)?;
+    let status = status.i32()?;
+    if manifest.content == CONTENT_DELETES {
+        polars_ensure!(
+            status.into_iter().all(|s| s == Some(STATUS_DELETED)),
+            ComputeError: "reading Iceberg tables with row-level deletes is not supported"
+        );
+        return Ok(());
+    }
+
+    let data_file = as_struct(field(&records, "data_file")?)?;
+    let paths = to_series("file_path", field(data_file, "file_path")?)?;
+    let formats = to_series("file_format", field(data_file, "file_format")?)?;
+    let record_counts = optional_series(data_file, "record_count")?;
+    let partition_values = optional_field(data_file, "partition")
+        .map(as_struct)
+        .transpose()?;
+    let lower_bounds = optional_field(data_file, "lower_bounds");
+    let upper_bounds = optional_field(data_file, "upper_bounds");
+    let null_counts = optional_field(data_file, "null_value_counts");
+
+    let (paths, formats) = (paths.str()?, formats.str()?);
+    for i in 0..records.len() {
+        if status.get(i) == Some(STATUS_DELETED) {
+            continue;
+        }
+        let path = paths
+            .get(i)
+            .ok_or_else(|| polars_err!(ComputeError: "Iceberg manifest has a null `file_path`"))?;
+        let format = formats.get(i).unwrap_or_default();
+        polars_ensure!(
+            format.eq_ignore_ascii_case("parquet"),
+            ComputeError: "reading Iceberg data files of format {} is not supported: {}",
+            format, path
+        );
+
+        let mut min_values = PlHashMap::new();
+        let mut max_values = PlHashMap::new();
+        let mut null_values = PlHashMap::new();
+        let column = |id: i32| {
+            ids.iter()
+                .find(|(field_id, _)| *field_id == id as i64)
+                .map(|(_, name)| name.as_str())
+        };
+        for (bounds, values) in [
+            (lower_bounds, &mut min_values),
+            (upper_bounds, &mut max_values),
+        ] {
+            for (id, bytes) in map_entries(bounds, i)? {
+                let Some(name) = column(id) else { continue };
+                let bytes = bytes.binary()?;
+                let dtype = schema.try_get(name)?;
+                if let Some(value) = bytes.get(0).and_then(|b| decode_bound(name, b, dtype)) {
+                    values.insert(name, value);
+                }
+            }
+        }
+        for (id, counts) in map_entries(null_counts, i)? {
+            if let Some(name) = column(id) {
+                null_values.insert(name, counts.cast(&IDX_DTYPE)?.with_name(name));
+            }
+        }
+        // The value of an identity partition bounds its source column from both sides.
+        if let Some(partition_values) = partition_values {
+            for partition in partitions {
+                let Some(value) = optional_field(partition_values, &partition.name) else {
+                    continue;
+                };
+                let value = to_series(&partition.source, value.sliced(i, 1).as_ref())?;
+                if let Ok(value) = value.cast(schema.try_get(&partition.source)?) {
+                    min_values.insert(partition.source.as_str(), value.clone());
+                    max_values.insert(partition.source.as_str(), value);
+                }
+            }
+        }
+
+        let stats = schema
+            .iter_fields()
+            .map(|field| {
+                let name = field.name().as_str();
+                ColumnStats::new(
+                    field.clone(),
+                    null_values.remove(name),
+                    min_values.remove(name),
+                    max_values.remove(name),
+                )
+            })
+            .collect();
+        let record_count = get_i64(record_counts.as_ref(), i)?.map(|n| n as usize);
+        files.push(IcebergFile {
+            path: local_path(path)?,
+            record_count,
+            stats: BatchStats::new(schema.clone(), stats, record_count),
+        });
+    }
+    Ok(())
+}
+
+/// The records of an Avro file, as a struct array of its root record.
+fn read_avro(path: &Path) -> PolarsResult<StructArray> {
+    let mut file = fs::File::open(path).map_err(
+        |e| polars_err!(ComputeError: "cannot open Iceberg file {}: {}", path.display(), e),
+    )?;
+    let metadata = avro_schema::read::read_metadata(&mut file).map_err(to_compute_err)?;
+    let schema = read::infer_schema(&metadata.record)?;
+    let dtype = ArrowDataType::Struct(schema.fields.clone());
+    let chunks = read::Reader::new(file, metadata, schema.fields, None)
+        .map(|chunk| Ok(StructArray::new(dtype.clone(), chunk?.into_arrays(), None)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    match chunks.len() {
+        0 => Ok(StructArray::new_empty(dtype)),
+        1 => Ok(chunks.into_iter().next().unwrap()),
+        _ => {
+            let chunks = chunks.iter().map(|c| c as &dyn Array).collect::<Vec<_>>();
+            let out = concatenate(&chunks)?;
+            Ok(as_struct(out.as_ref())?.clone())
+        },
+    }
+}
+
+fn optional_field<'a>(records: &'a StructArray, name: &str) -> Option<&'a dyn Array> {
+    let i = records.fields().iter().position(|f| f.name == name)?;
+    Some(records.values()[i].as_ref())
+}
+
+fn field<'a>(records: &'a StructArray, name: &str) -> PolarsResult<&'a dyn Array> {
+    optional_field(records, name)
+        .ok_or_else(|| polars_err!(ComputeError: "Iceberg record has no field `{}`", name))
+}
+
+fn as_struct(array: &dyn Array) -> PolarsResult<&StructArray> {
+    array.as_any().downcast_ref::<StructArray>().ok_or_else(
+        || polars_err!(ComputeError: "expected an Iceberg record, got {:?}", array.data_type()),
+    )
+}
+
+fn to_series(name: &str, array: &dyn Array) -> PolarsResult<Series> {
+    Series::try_from((name, array.to_boxed()))
+}
+
+fn optional_series(records: &StructArray, name: &str) -> PolarsResult<Option<Series>> {
+    optional_field(records, name)
+        .map(|array| to_series(name, array)?.cast(&DataType::Int64))
+        .transpose()
+}
+
+fn get_i64(s: Option<&Series>, i: usize) -> PolarsResult<Option<i64>> {
+    Ok(match s {
+        Some(s) => s.i64()?.get(i),
+        None => None,
+    })
+}
+
+/// The entries of row `i` of a map keyed by field id, which Iceberg stores as a list of
+/// key-value records. Every value is returned as a [`Series`] of length 1.
+fn map_entries(map: Option<&dyn Array>, i: usize) -> PolarsResult<Vec<(i32, Series)>> {
+    let Some(map) = map else {
+        return Ok(vec![]);
+    };
+    let list = map.as_any().downcast_ref::<ListArray<i32>>().ok_or_else(
+        || polars_err!(ComputeError: "expected an Iceberg map, got {:?}", map.data_type()),
+    )?;
+    if list.is_null(i) {
+        return Ok(vec![]);
+    }
+    let entries = list.value(i);
+    let entries = as_struct(entries.as_ref())?;
+    let keys = to_series("key", field(entries, "key")?)?.cast(&DataType::Int32)?;
+    let values = to_series("value", field(entries, "value")?)?;
+    Ok(keys
+        .i32()?
+        .into_iter()
+        .enumerate()
+        .filter_map(|(j, key)| Some((key?, values.slice(j as i64, 1))))
+        .collect())
+}
+
+/// Decode a lower or upper bound of a column from its single-value serialization, see
+/// <https://iceberg.apache.org/spec/#binary-single-value-serialization>. Returns `None` for
+/// types whose bounds are not used.
+fn decode_bound(name: &str, bytes: &[u8], dtype: &DataType) -> Option<Series> {
+    let s = match dtype {
+        DataType::Boolean => Series::new(name, &[*bytes.first()? != 0]),
+        DataType::Int32 => Series::new(name, &[i32::from_le_bytes(bytes.try_into().ok()?)]),
+        DataType::Int64 => Series::new(name, &[i64::from_le_bytes(bytes.try_into().ok()?)]),
+        DataType::Float32 => Series::new(name, &[f32::from_le_bytes(bytes.try_into().ok()?)]),
+        DataType::Float64 => Series::new(name, &[f64::from_le_bytes(bytes.try_into().ok()?)]),
+        DataType::Date => Series::new(name, &[i32::from_le_bytes(bytes.try_into().ok()?)])
+            .cast(dtype)
+            .ok()?,
+        // Times are stored in microseconds, timestamps in the unit of their type.
+        DataType::Time => {
+            let us = i64::from_le_bytes(bytes.try_into().ok()?);
+            Series::new(name, &[us.checked_mul(1000)?])
+                .cast(dtype)
+                .ok()?
+        },
+        DataType::Datetime(_, _) => {
+            Series::new(name, &[i64::from_le_bytes(bytes.try_into().ok()?)])
+                .cast(dtype)
+                .ok()?
+        },
+        DataType::String => Series::new(name, &[std::str::from_utf8(bytes).ok()?]),
+        DataType::Binary => Series::new(name, &[bytes]),
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// The paths in Iceberg metadata are absolute URIs.
+fn local_path(uri: &str) -> PolarsResult<PathBuf> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        Ok(PathBuf::from(path))
+    } else if let Some(path) = uri.strip_prefix("file:") {
+        Ok(PathBuf::from(path))
+    } else {
+        polars_ensure!(
+            !uri.contains("://"),
+            ComputeError: "the Iceberg file {} is not on the local file system", uri
+        );
+        Ok(PathBuf::from(uri))
+    }
+}
+
+fn struct_fields(dtype: &Value) -> PolarsResult<Vec<Field>> {
+    let fields = dtype["fields"]
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg struct type: {}", dtype))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field["name"]
+                .as_str()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg field: {}", field))?;
+            Ok(Field::new(name, iceberg_dtype(&field["type"])?))
+        })
+        .collect()
+}
+
+/// Convert a type of the Iceberg schema to a [`DataType`].
+fn iceberg_dtype(dtype: &Value) -> PolarsResult<DataType> {
+    let dtype = match dtype {
+        Value::String(name) => match name.as_str() {
+            "boolean" => DataType::Boolean,
+            "int" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "date" => DataType::Date,
+            "time" => DataType::Time,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+            "timestamptz" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ns" => DataType::Datetime(TimeUnit::Nanoseconds, None),
+            "timestamptz_ns" => DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into())),
+            "string" => DataType::String,
+            "uuid" | "binary" => DataType::Binary,
+            name if name.starts_with("fixed[") => DataType::Binary,
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let parse = || {
+                    let (precision, scale) = name
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some(DataType::Decimal(
+                        Some(precision.trim().parse().ok()?),
+                        Some(scale.trim().parse().ok()?),
+                    ))
+                };
+                parse().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Iceberg decimal type: {}", name),
+                )?
+            },
+            name => polars_bail!(ComputeError: "unsupported Iceberg data type: {}", name),
+        },
+        Value::Object(nested) => match nested.get("type").and_then(|t| t.as_str()) {
+            Some("list") => DataType::List(Box::new(iceberg_dtype(&nested["element"])?)),
+            #[cfg(feature = "dtype-struct")]
+            Some("struct") => DataType::Struct(struct_fields(dtype)?),
+            #[cfg(feature = "dtype-struct")]
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key", iceberg_dtype(&nested["key"])?),
+                Field::new("value", iceberg_dtype(&nested["value"])?),
+            ]))),
+            _ => polars_bail!(ComputeError: "unsupported Iceberg data type: {}", dtype),
+        },
+        _ => polars_bail!(ComputeError: "invalid Iceberg data type: {}", dtype),
+    };
+    Ok(dtype)
+}
+
+#[cfg(all(test, feature = "dtype-struct"))]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+    use crate::avro::AvroWriter;
+    use crate::SerWriter;
+
+    fn write_avro(path: &Path, df: &mut DataFrame) {
+        let mut file = fs::File::create(path).unwrap();
+        AvroWriter::new(&mut file).finish(df).unwrap();
+    }
+
+    fn bound(id: i32, value: i64) -> Series {
+        let key = Series::new("key", &[id]);
+        let value = Series::new("value", &[&value.to_le_bytes()[..]]);
+        StructChunked::new("", &[key, value]).unwrap().into_series()
+    }
+
+    /// Write a manifest of `(status, file, p, lower bound of a, upper bound of a)` entries.
+    fn write_manifest(path: &Path, table: &Path, entries: &[(i32, &str, i32, i64, i64)]) {
+        let paths = entries
+            .iter()
+            .map(|e| format!("file://{}", table.join(e.1).display()))
+            .collect::<Vec<_>>();
+        let partition = StructChunked::new(
+            "partition",
+            &[Series::new(
+                "p",
+                entries.iter().map(|e| e.2).collect::<Vec<_>>(),
+            )],
+        )
+        .unwrap();
+        let data_file = StructChunked::new(
+            "data_file",
+            &[
+                Series::new("file_path", paths),
+                Series::new("file_format", vec!["PARQUET"; entries.len()]),
+                partition.into_series(),
+                Series::new("record_count", vec![10i64; entries.len()]),
+                Series::new(
+                    "lower_bounds",
+                    entries.iter().map(|e| bound(1, e.3)).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "upper_bounds",
+                    entries.iter().map(|e| bound(1, e.4)).collect::<Vec<_>>(),
+                ),
+            ],
+        )
+        .unwrap();
+        let status = Series::new("status", entries.iter().map(|e| e.0).collect::<Vec<_>>());
+        let mut df = DataFrame::new(vec![status, data_file.into_series()]).unwrap();
+        write_avro(path, &mut df);
+    }
+
+    fn write_manifest_list(path: &Path, manifest: &Path) {
+        let mut df = df!(
+            "manifest_path" => [format!("file://{}", manifest.display())],
+            "partition_spec_id" => [0i32],
+            "content" => [0i32],
+        )
+        .unwrap();
+        write_avro(path, &mut df);
+    }
+
+    #[test]
+    fn test_iceberg_snapshot() -> PolarsResult<()> {
+        let table = tempfile::tempdir()?;
+        let table = table.path();
+        let metadata_dir = table.join("metadata");
+        fs::create_dir(&metadata_dir)?;
+
+        write_manifest(
+            &metadata_dir.join("m1.avro"),
+            table,
+            &[(1, "a.parquet", 1, 0, 99), (1, "b.parquet", 2, 100, 199)],
+        );
+        write_manifest(
+            &metadata_dir.join("m2.avro"),
+            table,
+            &[
+                (0, "a.parquet", 1, 0, 99),
+                (2, "b.parquet", 2, 100, 199),
+                (1, "c.parquet", 2, 200, 299),
+            ],
+        );
+        write_manifest_list(
+            &metadata_dir.join("snap-1.avro"),
+            &metadata_dir.join("m1.avro"),
+        );
+        write_manifest_list(
+            &metadata_dir.join("snap-2.avro"),
+            &metadata_dir.join("m2.avro"),
+        );
+
+        let metadata = format!(
+            r#"{{
+                "format-version": 2,
+                "current-schema-id": 0,
+                "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                    {{"id": 1, "name": "a", "required": false, "type": "long"}},
+                    {{"id": 2, "name": "p", "required": false, "type": "int"}}
+                ]}}],
+                "default-spec-id": 0,
+                "partition-specs": [{{"spec-id": 0, "fields": [
+                    {{"name": "p", "transform": "identity", "source-id": 2, "field-id": 1000}}
+                ]}}],
+                "current-snapshot-id": 2,
+                "snapshots": [
+                    {{"snapshot-id": 1, "manifest-list": "{}"}},
+                    {{"snapshot-id": 2, "manifest-list": "{}"}}
+                ]
+            }}"#,
+            metadata_dir.join("snap-1.avro").display(),
+            metadata_dir.join("snap-2.avro").display(),
+        );
+        fs::write(metadata_dir.join("00001-abc.metadata.json"), metadata)?;
+
+        let latest = IcebergSnapshot::read(table, None)?;
+        assert_eq!(latest.snapshot_id, Some(2));
+        assert_eq!(latest.schema.get("p"), Some(&DataType::Int32));
+        let paths = latest
+            .files
+            .iter()
+            .map(|f| f.path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, &[table.join("a.parquet"), table.join("c.parquet")]);
+
+        let stats = &latest.files[1].stats;
+        let a = stats.get_stats("a")?.to_min_max().unwrap();
+        assert_eq!(Vec::from(a.i64()?), &[Some(200), Some(299)]);
+        let p = stats.get_stats("p")?.to_min_max().unwrap();
+        assert_eq!(Vec::from(p.i32()?), &[Some(2), Some(2)]);
+        assert_eq!(stats.num_rows(), Some(10));
+
+        let first = IcebergSnapshot::read(table, Some(1))?;
+        assert_eq!(first.files.len(), 2);
+        assert_eq!(first.files[1].path, table.join("b.parquet"));
+        assert!(IcebergSnapshot::read(table, Some(3)).is_err());
+        Ok(())
+    }
+}
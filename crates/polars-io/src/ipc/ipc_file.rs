@@ -32,15 +32,18 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.equals(&df_read));
 //! ```
-use std::io::{Read, Seek};
+use std::io::{Cursor, Read, Seek};
 use std::sync::Arc;
 
-use arrow::datatypes::ArrowSchemaRef;
+use arrow::array::{Array, StructArray};
+use arrow::chunk::Chunk;
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef};
 use arrow::io::ipc::read;
 use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
 
 use super::{finish_reader, ArrowReader};
+use crate::compression::maybe_decompress_reader;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
@@ -72,6 +75,8 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) projection: Option<Vec<usize>>,
     pub(crate) columns: Option<Vec<String>>,
     pub(super) row_index: Option<RowIndex>,
+    /// The fields to keep of some struct columns, by the name of the struct column.
+    pub(super) struct_fields: Option<Vec<(String, Vec<String>)>>,
     memmap: bool,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
@@ -103,13 +108,35 @@ impl<R: MmapBytesReader> IpcReader<R> {
     }
     fn get_metadata(&mut self) -> PolarsResult<&read::FileMetadata> {
         if self.metadata.is_none() {
-            let metadata = read::read_file_metadata(&mut self.reader)?;
+            let metadata = match maybe_decompress_reader(&mut self.reader)? {
+                Some(bytes) => read::read_file_metadata(&mut Cursor::new(bytes))?,
+                None => read::read_file_metadata(&mut self.reader)?,
+            };
             self.schema = Some(metadata.schema.clone());
             self.metadata = Some(metadata);
         }
         Ok(self.metadata.as_ref().unwrap())
     }
 
+    /// If the whole file is compressed (e.g. a gzipped IPC file), return a reader over the
+    /// decompressed bytes with the same options.
+    fn decompressed(&mut self) -> PolarsResult<Option<IpcReader<Cursor<Vec<u8>>>>> {
+        Ok(
+            maybe_decompress_reader(&mut self.reader)?.map(|bytes| IpcReader {
+                reader: Cursor::new(bytes),
+                rechunk: self.rechunk,
+                n_rows: self.n_rows,
+                projection: self.projection.take(),
+                columns: self.columns.take(),
+                row_index: self.row_index.take(),
+                struct_fields: self.struct_fields.take(),
+                memmap: false,
+                metadata: None,
+                schema: None,
+            }),
+        )
+    }
+
     /// Get arrow schema of the Ipc File.
     pub fn schema(&mut self) -> PolarsResult<ArrowSchemaRef> {
         self.get_metadata()?;
@@ -140,6 +167,14 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Only read these fields of struct columns, given as the name of the struct column and the
+    /// names of its fields to keep. The other fields are not converted, and if the file is memory
+    /// mapped they are never read from disk.
+    pub fn with_struct_fields(mut self, struct_fields: Option<Vec<(String, Vec<String>)>>) -> Self {
+        self.struct_fields = struct_fields;
+        self
+    }
+
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     pub fn memory_mapped(mut self, toggle: bool) -> Self {
         self.memmap = toggle;
@@ -153,6 +188,9 @@ impl<R: MmapBytesReader> IpcReader<R> {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         verbose: bool,
     ) -> PolarsResult<DataFrame> {
+        if let Some(reader) = self.decompressed()? {
+            return reader.finish_with_scan_ops(predicate, verbose);
+        }
         if self.memmap && self.reader.to_file().is_some() {
             if verbose {
                 eprintln!("memory map ipc file")
@@ -172,6 +210,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
         };
 
         let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        let (reader, schema) =
+            StructFieldsReader::new(reader, &schema, self.struct_fields.as_deref())?;
 
         finish_reader(reader, rechunk, None, predicate, &schema, self.row_index)
     }
@@ -195,6 +235,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             columns: None,
             projection: None,
             row_index: None,
+            struct_fields: None,
             memmap: true,
             metadata: None,
             schema: None,
@@ -207,6 +248,9 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
+        if let Some(reader) = self.decompressed()? {
+            return reader.finish();
+        }
         if self.memmap && self.reader.to_file().is_some() {
             match self.finish_memmapped(None) {
                 Ok(df) => return Ok(df),
@@ -230,6 +274,83 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
 
         let ipc_reader =
             read::FileReader::new(self.reader, metadata.clone(), self.projection, self.n_rows);
+        let (ipc_reader, schema) =
+            StructFieldsReader::new(ipc_reader, &schema, self.struct_fields.as_deref())?;
         finish_reader(ipc_reader, rechunk, None, None, &schema, self.row_index)
     }
 }
+
+/// Only keeps the projected fields of struct columns in the chunks of `reader`, before they are
+/// converted to a [`DataFrame`].
+pub(super) struct StructFieldsReader<R> {
+    reader: R,
+    /// The index of a struct column, its projected data type and the indices of its fields.
+    projections: Vec<(usize, ArrowDataType, Vec<usize>)>,
+}
+
+impl<R: ArrowReader> StructFieldsReader<R> {
+    /// Returns the reader and the schema of its chunks.
+    pub(super) fn new(
+        reader: R,
+        schema: &ArrowSchema,
+        struct_fields: Option<&[(String, Vec<String>)]>,
+    ) -> PolarsResult<(Self, ArrowSchema)> {
+        let mut schema = schema.clone();
+        let mut projections = vec![];
+        for (name, field_names) in struct_fields.unwrap_or_default() {
+            let Some(column_idx) = schema.fields.iter().position(|f| &f.name == name) else {
+                // the struct column is not projected
+                continue;
+            };
+            let column = &mut schema.fields[column_idx];
+            let ArrowDataType::Struct(fields) = column.data_type.to_logical_type() else {
+                polars_bail!(SchemaMismatch: "cannot project the fields of column '{}', which is not a struct", name);
+            };
+            let field_indices = field_names
+                .iter()
+                .map(|field_name| {
+                    fields
+                        .iter()
+                        .position(|f| &f.name == field_name)
+                        .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", field_name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let data_type =
+                ArrowDataType::Struct(field_indices.iter().map(|i| fields[*i].clone()).collect());
+            column.data_type = data_type.clone();
+            projections.push((column_idx, data_type, field_indices));
+        }
+        Ok((
+            Self {
+                reader,
+                projections,
+            },
+            schema,
+        ))
+    }
+}
+
+impl<R: ArrowReader> ArrowReader for StructFieldsReader<R> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<ArrowChunk>> {
+        let Some(chunk) = self.reader.next_record_batch()? else {
+            return Ok(None);
+        };
+        if self.projections.is_empty() {
+            return Ok(Some(chunk));
+        }
+        let mut arrays = chunk.into_arrays();
+        for (column_idx, data_type, field_indices) in &self.projections {
+            let array = arrays[*column_idx]
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
+            let values = field_indices
+                .iter()
+                .map(|i| array.values()[*i].clone())
+                .collect();
+            arrays[*column_idx] =
+                StructArray::new(data_type.clone(), values, array.validity().cloned()).boxed();
+        }
+        Ok(Some(Chunk::new(arrays)))
+    }
+}
@@ -4,6 +4,8 @@ use arrow::io::ipc::read::{Dictionaries, FileMetadata};
 use arrow::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
 use memmap::Mmap;
 
+#[cfg(feature = "ipc")]
+use super::ipc_file::StructFieldsReader;
 use super::*;
 use crate::mmap::MmapBytesReader;
 use crate::utils::{apply_projection, columns_to_projection};
@@ -91,6 +93,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
                 };
 
                 let reader = MMapChunkIter::new(mmap, metadata, &self.projection)?;
+                let (reader, schema) =
+                    StructFieldsReader::new(reader, &schema, self.struct_fields.as_deref())?;
 
                 finish_reader(
                     reader,
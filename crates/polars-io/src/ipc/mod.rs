@@ -6,6 +6,8 @@ mod ipc_file;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
 mod mmap;
+#[cfg(feature = "ipc")]
+mod on_disk;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 mod write;
 #[cfg(all(feature = "async", feature = "ipc"))]
@@ -15,4 +17,6 @@ mod write_async;
 pub use ipc_file::IpcReader;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+#[cfg(feature = "ipc")]
+pub use on_disk::OnDiskDataFrame;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOption};
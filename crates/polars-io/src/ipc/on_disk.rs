@@ -0,0 +1,284 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use arrow::chunk::Chunk;
+use arrow::io::ipc::read;
+use arrow::io::ipc::read::{Dictionaries, FileMetadata};
+use arrow::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
+use memmap::Mmap;
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+
+use crate::utils::{apply_projection, columns_to_projection};
+
+/// A DataFrame that stays in an uncompressed Arrow IPC file on disk.
+///
+/// The file is memory-mapped, so slicing and projecting only narrow down the rows and columns
+/// the handle refers to and never read any data. [`collect`](Self::collect) produces a
+/// DataFrame whose buffers still point into the mapping, which the operating system pages in
+/// when they are accessed. This keeps a working set far larger than RAM addressable.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::OnDiskDataFrame;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let df = OnDiskDataFrame::open("file.ipc")?;
+///     df.select(["a", "b"])?.slice(1_000_000, 10).collect()
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OnDiskDataFrame {
+    path: PathBuf,
+    mmap: Arc<Mmap>,
+    metadata: Arc<FileMetadata>,
+    dictionaries: Arc<Dictionaries>,
+    /// The row at which every record batch starts, followed by the number of rows in the file.
+    batch_offsets: Arc<[usize]>,
+    /// The columns of the file that are selected, all of them if `None`.
+    projection: Option<Vec<usize>>,
+    offset: usize,
+    len: usize,
+}
+
+impl OnDiskDataFrame {
+    /// Memory-map the IPC file at `path`.
+    ///
+    /// Compressed IPC files cannot be memory-mapped and return an error.
+    pub fn open(path: impl AsRef<Path>) -> PolarsResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = polars_utils::open_file(&path)?;
+        Self::from_file(path, &file)
+    }
+
+    fn from_file(path: PathBuf, file: &File) -> PolarsResult<Self> {
+        // SAFETY: the file must not be modified while it is mapped, like with the memory-mapped
+        // `IpcReader`.
+        let mmap = Arc::new(unsafe { Mmap::map(file)? });
+        let metadata = read::read_file_metadata(&mut std::io::Cursor::new(mmap.as_ref()))?;
+        let dictionaries = unsafe { mmap_dictionaries_unchecked(&metadata, mmap.clone())? };
+
+        // The row counts of the record batches are in their headers, mapping a batch doesn't
+        // touch its buffers.
+        let mut batch_offsets = Vec::with_capacity(metadata.blocks.len() + 1);
+        let mut height = 0;
+        for i in 0..metadata.blocks.len() {
+            batch_offsets.push(height);
+            let chunk = unsafe { mmap_unchecked(&metadata, &dictionaries, mmap.clone(), i)? };
+            height += chunk.len();
+        }
+        batch_offsets.push(height);
+
+        Ok(Self {
+            path,
+            mmap,
+            metadata: Arc::new(metadata),
+            dictionaries: Arc::new(dictionaries),
+            batch_offsets: batch_offsets.into(),
+            projection: None,
+            offset: 0,
+            len: height,
+        })
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn arrow_schema(&self) -> ArrowSchema {
+        match &self.projection {
+            Some(projection) => apply_projection(&self.metadata.schema, projection),
+            None => self.metadata.schema.as_ref().clone(),
+        }
+    }
+
+    /// The schema of the selected columns.
+    pub fn schema(&self) -> Schema {
+        Schema::from(&self.arrow_schema())
+    }
+
+    /// The names of the selected columns.
+    pub fn get_column_names(&self) -> Vec<&str> {
+        let fields = &self.metadata.schema.fields;
+        match &self.projection {
+            Some(projection) => projection
+                .iter()
+                .map(|&i| fields[i].name.as_str())
+                .collect(),
+            None => fields.iter().map(|f| f.name.as_str()).collect(),
+        }
+    }
+
+    /// The selected columns if only a part of the file's columns is selected.
+    pub fn columns(&self) -> Option<Vec<String>> {
+        self.projection.as_ref().map(|_| {
+            self.get_column_names()
+                .into_iter()
+                .map(String::from)
+                .collect()
+        })
+    }
+
+    /// The number of rows in the file before the first selected row.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of selected rows.
+    pub fn height(&self) -> usize {
+        self.len
+    }
+
+    /// The number of selected columns.
+    pub fn width(&self) -> usize {
+        match &self.projection {
+            Some(projection) => projection.len(),
+            None => self.metadata.schema.fields.len(),
+        }
+    }
+
+    /// The number of selected rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.height(), self.width())
+    }
+
+    /// Narrow the handle down to a slice of its rows, with the semantics of
+    /// [`DataFrame::slice`]. No data is read.
+    #[must_use]
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (offset, len) = slice_offsets(offset, length, self.len);
+        Self {
+            offset: self.offset + offset,
+            len,
+            ..self.clone()
+        }
+    }
+
+    /// Narrow the handle down to its first `length` rows, 10 if `None`.
+    #[must_use]
+    pub fn head(&self, length: Option<usize>) -> Self {
+        self.slice(0, length.unwrap_or(10))
+    }
+
+    /// Narrow the handle down to its last `length` rows, 10 if `None`.
+    #[must_use]
+    pub fn tail(&self, length: Option<usize>) -> Self {
+        let length = length.unwrap_or(10).min(self.len);
+        self.slice(-(length as i64), length)
+    }
+
+    /// Narrow the handle down to some of its columns, in the given order. No data is read.
+    pub fn select<I, S>(&self, columns: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let columns = columns
+            .into_iter()
+            .map(|name| name.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let selected = columns_to_projection(&columns, &self.arrow_schema())?;
+        let projection = match &self.projection {
+            Some(projection) => selected.into_iter().map(|i| projection[i]).collect(),
+            None => selected,
+        };
+        Ok(Self {
+            projection: Some(projection),
+            ..self.clone()
+        })
+    }
+
+    /// Materialize the selected rows and columns.
+    ///
+    /// The buffers of the result point into the memory-mapped file, only the record batches
+    /// that overlap with the selected rows are mapped.
+    pub fn collect(&self) -> PolarsResult<DataFrame> {
+        let schema = self.arrow_schema();
+        let (start, end) = (self.offset, self.offset + self.len);
+
+        let mut dfs = vec![];
+        for (i, batch) in self.batch_offsets.windows(2).enumerate() {
+            let (batch_start, batch_end) = (batch[0], batch[1]);
+            if batch_end <= start || batch_start == batch_end {
+                continue;
+            }
+            if batch_start >= end {
+                break;
+            }
+            let chunk = unsafe {
+                mmap_unchecked(&self.metadata, &self.dictionaries, self.mmap.clone(), i)?
+            };
+            let chunk = match &self.projection {
+                Some(projection) => {
+                    let arrays = chunk.into_arrays();
+                    Chunk::new(projection.iter().map(|&i| arrays[i].clone()).collect())
+                },
+                None => chunk,
+            };
+            let df = DataFrame::try_from((chunk, schema.fields.as_slice()))?;
+
+            let offset = start.saturating_sub(batch_start);
+            let len = end.min(batch_end) - batch_start.max(start);
+            dfs.push(df.slice(offset as i64, len));
+        }
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::from(&Schema::from(&schema)));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_on_disk_dataframe() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("on_disk.ipc");
+        let mut df = df!(
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|i| i.to_string()).collect::<Vec<_>>(),
+            "c" => (0..10).map(|i| i as f64).collect::<Vec<_>>()
+        )?;
+        // Several record batches, so that slices span batch boundaries.
+        let mut writer = IpcWriter::new(File::create(&path)?).batched(&df.schema())?;
+        for (offset, len) in [(0, 4), (4, 3), (7, 3)] {
+            writer.write_batch(&df.slice(offset, len))?;
+        }
+        writer.finish()?;
+
+        let on_disk = OnDiskDataFrame::open(&path)?;
+        assert_eq!(on_disk.shape(), (10, 3));
+        assert!(on_disk.collect()?.equals(&df));
+
+        let sliced = on_disk.slice(2, 7).select(["c", "a"])?;
+        assert_eq!(sliced.shape(), (7, 2));
+        assert_eq!(sliced.get_column_names(), &["c", "a"]);
+        assert!(sliced
+            .collect()?
+            .equals(&df.select(["c", "a"])?.slice(2, 7)));
+
+        let nested = sliced.slice(-3, 2).select(["a"])?;
+        assert_eq!(nested.offset(), 6);
+        assert!(nested.collect()?.equals(&df.select(["a"])?.slice(6, 2)));
+        assert!(on_disk.select(["d"]).is_err());
+
+        let empty = on_disk.slice(20, 5).collect()?;
+        assert_eq!(empty.shape(), (0, 3));
+        assert_eq!(empty.schema(), df.schema());
+
+        IpcWriter::new(File::create(&path)?)
+            .with_compression(Some(IpcCompression::LZ4))
+            .finish(&mut df)?;
+        assert!(OnDiskDataFrame::open(&path).is_err());
+        Ok(())
+    }
+}
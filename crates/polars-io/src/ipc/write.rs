@@ -278,6 +278,52 @@ mod test {
         assert!(df_read.equals(&expected));
     }
 
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_read_ipc_with_struct_fields() -> PolarsResult<()> {
+        let s = StructChunked::new(
+            "s",
+            &[
+                Series::new("x", [1, 2, 3]),
+                Series::new("y", ["a", "b", "c"]),
+                Series::new("z", [true, false, true]),
+            ],
+        )?
+        .into_series();
+        let mut df = DataFrame::new(vec![Series::new("a", [1, 2, 3]), s])?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+
+        let expected = StructChunked::new(
+            "s",
+            &[
+                Series::new("x", [1, 2, 3]),
+                Series::new("z", [true, false, true]),
+            ],
+        )?
+        .into_series();
+        for memmap in [true, false] {
+            buf.set_position(0);
+            let df_read = IpcReader::new(&mut buf)
+                .with_struct_fields(Some(vec![(
+                    "s".to_string(),
+                    vec!["x".to_string(), "z".to_string()],
+                )]))
+                .memory_mapped(memmap)
+                .finish()?;
+            assert!(df_read.column("s")?.equals(&expected));
+            assert!(df_read.column("a")?.equals(df.column("a")?));
+        }
+
+        buf.set_position(0);
+        let err = IpcReader::new(&mut buf)
+            .with_struct_fields(Some(vec![("a".to_string(), vec!["x".to_string()])]))
+            .finish();
+        assert!(err.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_write_with_compression() {
         let mut df = create_df();
@@ -312,6 +312,8 @@ where
                     false,
                     self.infer_schema_len,
                     self.ignore_errors,
+                    self.projection.as_deref(),
+                    None,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -321,7 +323,7 @@ where
             },
         }?;
 
-        // TODO! Ensure we don't materialize the columns we don't need
+        // TODO! Ensure we don't materialize the columns we don't need for `JsonFormat::Json`
         if let Some(proj) = &self.projection {
             out.select(proj)
         } else {
@@ -5,10 +5,19 @@
 #[cfg(feature = "avro")]
 pub mod avro;
 pub mod cloud;
+pub mod compression;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "excel")]
+pub mod excel;
 #[cfg(feature = "parquet")]
 pub mod export;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -24,6 +33,7 @@ mod options;
 pub mod parquet;
 pub mod predicates;
 pub mod prelude;
+pub mod scan_stats;
 #[cfg(all(test, feature = "csv"))]
 mod tests;
 pub mod utils;
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::fmt;
 use std::fs::File;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
@@ -9,9 +11,13 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
+use serde::de::{Deserialize, Deserializer, Error as _, IgnoredAny, MapAccess, Visitor};
+use serde_json::value::RawValue;
 
+use crate::compression::maybe_decompress_reader_bytes;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
+use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 const NEWLINE: u8 = b'\n';
 const RETURN: u8 = b'\r';
@@ -33,6 +39,8 @@ where
     path: Option<PathBuf>,
     low_memory: bool,
     ignore_errors: bool,
+    columns: Option<Vec<String>>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -91,6 +99,19 @@ where
         self.ignore_errors = ignore_errors;
         self
     }
+
+    /// Only read these columns. The values of all other keys are skipped by the parser
+    /// instead of being deserialized.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Filter the rows with this predicate while reading.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
 }
 
 impl<'a> JsonLineReader<'a, File> {
@@ -119,6 +140,8 @@ where
             chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
             low_memory: false,
             ignore_errors: false,
+            columns: None,
+            predicate: None,
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -135,6 +158,8 @@ where
             self.low_memory,
             self.infer_schema_len,
             self.ignore_errors,
+            self.columns.as_deref(),
+            self.predicate,
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -154,6 +179,9 @@ pub(crate) struct CoreJsonReader<'a> {
     chunk_size: NonZeroUsize,
     low_memory: bool,
     ignore_errors: bool,
+    /// Whether the schema is a projection, in which case only the keys in the schema are parsed.
+    projected: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -168,8 +196,10 @@ impl<'a> CoreJsonReader<'a> {
         low_memory: bool,
         infer_schema_len: Option<usize>,
         ignore_errors: bool,
+        columns: Option<&[String]>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
     ) -> PolarsResult<CoreJsonReader<'a>> {
-        let reader_bytes = reader_bytes;
+        let reader_bytes = maybe_decompress_reader_bytes(reader_bytes)?;
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -183,6 +213,16 @@ impl<'a> CoreJsonReader<'a> {
             let schema = Arc::make_mut(&mut schema);
             overwrite_schema(schema, overwriting_schema)?;
         }
+        let mut projected = false;
+        if let Some(columns) = columns {
+            projected = columns.len() < schema.len();
+            schema = Arc::new(
+                columns
+                    .iter()
+                    .map(|name| schema.try_get_field(name))
+                    .collect::<PolarsResult<_>>()?,
+            );
+        }
 
         Ok(CoreJsonReader {
             reader_bytes: Some(reader_bytes),
@@ -193,6 +233,8 @@ impl<'a> CoreJsonReader<'a> {
             chunk_size,
             low_memory,
             ignore_errors,
+            projected,
+            predicate,
         })
     }
     fn parse_json(&mut self, mut n_threads: usize, bytes: &[u8]) -> PolarsResult<DataFrame> {
@@ -228,19 +270,40 @@ impl<'a> CoreJsonReader<'a> {
         } else {
             std::cmp::min(rows_per_thread, max_proxy)
         };
+        // If `n_rows` is set the predicate is applied after slicing, otherwise we would
+        // stop at the `n_rows` matching rows instead of the first `n_rows` rows.
+        let predicate = match self.n_rows {
+            None => self.predicate.as_ref(),
+            Some(_) => None,
+        };
+        let key_idx: Option<PlHashMap<&str, usize>> = self.projected.then(|| {
+            self.schema
+                .iter_names()
+                .enumerate()
+                .map(|(i, name)| (name.as_str(), i))
+                .collect()
+        });
         let file_chunks = get_file_chunks_json(bytes, n_threads);
         let dfs = POOL.install(|| {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
                     let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
-                    DataFrame::new(
+                    parse_lines(
+                        &bytes[start_pos..stop_at_nbytes],
+                        &mut buffers,
+                        key_idx.as_ref(),
+                    )?;
+                    let df = DataFrame::new(
                         buffers
                             .into_values()
                             .map(|buf| buf.into_series())
                             .collect::<_>(),
-                    )
+                    )?;
+                    match predicate {
+                        Some(predicate) => apply_predicate(df, predicate),
+                        None => Ok(df),
+                    }
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
@@ -259,11 +322,22 @@ impl<'a> CoreJsonReader<'a> {
             if n_rows < df.height() {
                 df = df.slice(0, n_rows)
             }
+            if let Some(predicate) = &self.predicate {
+                df = apply_predicate(df, predicate)?;
+            }
         }
         Ok(df)
     }
 }
 
+fn apply_predicate(df: DataFrame, predicate: &Arc<dyn PhysicalIoExpr>) -> PolarsResult<DataFrame> {
+    let s = predicate.evaluate_io(&df)?;
+    let mask = s
+        .bool()
+        .map_err(|_| polars_err!(ComputeError: "filter predicate was not of type boolean"))?;
+    df.filter(mask)
+}
+
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
@@ -301,8 +375,110 @@ fn parse_impl(
     Ok(n)
 }
 
-fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
+/// A key of a JSON object, borrowed from the input unless it contains escape sequences.
+struct JsonKey<'de>(Cow<'de, str>);
+
+impl<'de> Deserialize<'de> for JsonKey<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyVisitor;
+
+        impl<'de> Visitor<'de> for KeyVisitor {
+            type Value = JsonKey<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(JsonKey(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(JsonKey(Cow::Owned(v.to_string())))
+            }
+        }
+
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
+
+/// Visits a JSON object and only deserializes the values of the keys that have a buffer.
+/// The values of all other keys are skipped without being materialized.
+struct ProjectedObject<'a, 'b> {
+    buffers: &'b mut PlIndexMap<BufferKey<'a>, Buffer<'a>>,
+    key_idx: &'b PlHashMap<&'b str, usize>,
+    seen: &'b mut [bool],
+    scratch: &'b mut Vec<u8>,
+}
+
+impl<'de> Visitor<'de> for ProjectedObject<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<JsonKey<'de>>()? {
+            match self.key_idx.get(key.0.as_ref()) {
+                Some(&idx) if !self.seen[idx] => {
+                    let raw = map.next_value::<&'de RawValue>()?;
+                    self.scratch.clear();
+                    self.scratch.extend_from_slice(raw.get().as_bytes());
+                    let value =
+                        simd_json::to_borrowed_value(self.scratch).map_err(A::Error::custom)?;
+                    let (_, buf) = self.buffers.get_index_mut(idx).unwrap();
+                    buf.add(&value).map_err(A::Error::custom)?;
+                    self.seen[idx] = true;
+                },
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[inline(always)]
+fn parse_projected_impl(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    key_idx: &PlHashMap<&str, usize>,
+    seen: &mut Vec<bool>,
+    scratch: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    seen.clear();
+    seen.resize(buffers.len(), false);
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    (&mut de)
+        .deserialize_map(ProjectedObject {
+            buffers,
+            key_idx,
+            seen,
+            scratch,
+        })
+        .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+    // Keys that are missing in this line.
+    for (_, buf) in seen
+        .iter()
+        .zip(buffers.values_mut())
+        .filter(|(seen, _)| !**seen)
+    {
+        buf.add_null()
+    }
+    Ok(())
+}
+
+/// Parse the lines into the buffers. If `key_idx` is given, it maps the keys to the index of
+/// their buffer and only those keys are parsed.
+fn parse_lines(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    key_idx: Option<&PlHashMap<&str, usize>>,
+) -> PolarsResult<()> {
     let mut buf = vec![];
+    let mut seen = vec![];
 
     // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
     // It is used to properly iterate over the lines without re-implementing the splitlines logic when this does the same thing.
@@ -312,7 +488,14 @@ fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> Pol
         match value_result {
             Ok(value) => {
                 let bytes = value.get().as_bytes();
-                parse_impl(bytes, buffers, &mut buf)?;
+                match key_idx {
+                    Some(key_idx) if bytes.first() == Some(&b'{') => {
+                        parse_projected_impl(bytes, buffers, key_idx, &mut seen, &mut buf)?
+                    },
+                    _ => {
+                        parse_impl(bytes, buffers, &mut buf)?;
+                    },
+                }
             },
             Err(e) => {
                 polars_bail!(ComputeError: "error parsing ndjson {}", e)
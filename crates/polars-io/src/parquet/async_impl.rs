@@ -23,6 +23,7 @@ use crate::parquet::read_impl::compute_row_group_range;
 use crate::pl_async::{get_runtime, with_concurrency_budget, MAX_BUDGET_PER_REQUEST};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::predicates::read_this_row_group;
+use crate::scan_stats::ScanStatistics;
 
 type DownloadedRowGroup = Vec<(u64, Bytes)>;
 type QueuePayload = (usize, DownloadedRowGroup);
@@ -33,6 +34,7 @@ pub struct ParquetObjectStore {
     path: ObjectPath,
     length: Option<u64>,
     metadata: Option<Arc<FileMetaData>>,
+    scan_stats: Option<Arc<ScanStatistics>>,
 }
 
 impl ParquetObjectStore {
@@ -48,22 +50,48 @@ impl ParquetObjectStore {
             path: ObjectPath::from_url_path(prefix).map_err(to_compute_err)?,
             length: None,
             metadata,
+            scan_stats: None,
         })
     }
 
+    /// Count the bytes of the downloaded column data in these statistics.
+    pub fn set_scan_statistics(&mut self, scan_stats: Option<Arc<ScanStatistics>>) {
+        self.scan_stats = scan_stats;
+    }
+
+    fn add_bytes_downloaded(&self, n: usize) {
+        if let Some(scan_stats) = &self.scan_stats {
+            scan_stats.add_bytes_downloaded(n);
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(path = %self.path))
+    )]
     async fn get_range(&self, start: usize, length: usize) -> PolarsResult<Bytes> {
-        with_concurrency_budget(1, || async {
+        let bytes = with_concurrency_budget(1, || async {
             self.store
                 .get_range(&self.path, start..start + length)
                 .await
                 .map_err(to_compute_err)
         })
-        .await
+        .await?;
+        self.add_bytes_downloaded(bytes.len());
+        Ok(bytes)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(path = %self.path, n_ranges = ranges.len())
+        )
+    )]
     async fn get_ranges(&self, ranges: &[Range<usize>]) -> PolarsResult<Vec<Bytes>> {
         // Object-store has a maximum of 10 concurrent.
-        with_concurrency_budget(
+        let bytes = with_concurrency_budget(
             (ranges.len() as u32).clamp(0, MAX_BUDGET_PER_REQUEST as u32),
             || async {
                 self.store
@@ -72,7 +100,9 @@ impl ParquetObjectStore {
                     .map_err(to_compute_err)
             },
         )
-        .await
+        .await?;
+        self.add_bytes_downloaded(bytes.iter().map(|b| b.len()).sum());
+        Ok(bytes)
     }
 
     /// Initialize the length property of the object, unless it has already been fetched.
@@ -105,6 +135,10 @@ impl ParquetObjectStore {
     }
 
     /// Fetch the metadata of the parquet file, do not memoize it.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(path = %self.path))
+    )]
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetaData> {
         self.initialize_length().await?;
         let object_store = self.store.clone();
@@ -246,8 +280,10 @@ impl FetchRowGroupsFromObjectStore {
                 .iter()
                 .enumerate()
                 .filter(|(i, rg)| {
-                    let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
+                    let should_be_read = matches!(
+                        read_this_row_group(Some(pred), rg, &schema, None, None),
+                        Ok(true)
+                    );
 
                     // Already add the row groups that will be skipped to the prefetched data.
                     if !should_be_read {
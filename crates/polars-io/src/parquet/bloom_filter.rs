@@ -0,0 +1,120 @@
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{
+    hash_byte, hash_native, insert, is_in_set, optimal_num_bytes, read,
+};
+use polars_parquet::read::ColumnChunkMetaData;
+
+/// The false positive probability of the bloom filters written by the
+/// [`ParquetWriter`](super::ParquetWriter).
+const BLOOM_FILTER_FPP: f64 = 0.01;
+
+/// Hash the non-null values of `s` the way the parquet specification hashes them in a bloom
+/// filter, that is, hash their physical parquet representation.
+fn hash_values(s: &Series) -> PolarsResult<Vec<u64>> {
+    use DataType::*;
+    let hashes = match s.dtype() {
+        Int8 | Int16 | Int32 | UInt8 | UInt16 => {
+            let s = s.cast(&Int32)?;
+            s.i32()?.into_iter().flatten().map(hash_native).collect()
+        },
+        // written as the int32 with the same bits
+        UInt32 => s
+            .u32()?
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i32))
+            .collect(),
+        Int64 => s.i64()?.into_iter().flatten().map(hash_native).collect(),
+        // written as the int64 with the same bits
+        UInt64 => s
+            .u64()?
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i64))
+            .collect(),
+        Float32 => s.f32()?.into_iter().flatten().map(hash_native).collect(),
+        Float64 => s.f64()?.into_iter().flatten().map(hash_native).collect(),
+        Date => {
+            let s = s.to_physical_repr();
+            s.i32()?.into_iter().flatten().map(hash_native).collect()
+        },
+        Datetime(_, _) | Duration(_) | Time => {
+            let s = s.to_physical_repr();
+            s.i64()?.into_iter().flatten().map(hash_native).collect()
+        },
+        String => s.str()?.into_iter().flatten().map(hash_byte).collect(),
+        Binary => s.binary()?.into_iter().flatten().map(hash_byte).collect(),
+        dt => polars_bail!(
+            InvalidOperation: "bloom filters are not supported for columns of type {}", dt
+        ),
+    };
+    Ok(hashes)
+}
+
+/// Returns an error if no bloom filter can be written for a column of type `dtype`.
+pub(super) fn ensure_bloom_filter_supported(dtype: &DataType) -> PolarsResult<()> {
+    hash_values(&Series::new_empty("", dtype)).map(|_| ())
+}
+
+/// A split block bloom filter of the values of a column chunk.
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    dtype: DataType,
+    bitset: Vec<u8>,
+}
+
+impl BloomFilter {
+    /// Create the bloom filter of the values of `s`, sized such that it has a false positive
+    /// probability of 1% if all values are distinct.
+    pub(super) fn from_values(s: &Series) -> PolarsResult<Self> {
+        let hashes = hash_values(s)?;
+        let mut bitset = vec![0; optimal_num_bytes(hashes.len(), BLOOM_FILTER_FPP)];
+        for hash in hashes {
+            insert(&mut bitset, hash);
+        }
+        Ok(Self {
+            dtype: s.dtype().clone(),
+            bitset,
+        })
+    }
+
+    /// Read the bloom filter of a column chunk of type `dtype` from the bytes of the file.
+    /// Returns `None` if the column chunk has no bloom filter that we can use.
+    pub(super) fn read(
+        md: &ColumnChunkMetaData,
+        dtype: &DataType,
+        file: &[u8],
+    ) -> PolarsResult<Option<Self>> {
+        if ensure_bloom_filter_supported(dtype).is_err() {
+            return Ok(None);
+        }
+        let mut bitset = vec![];
+        read(md, &mut Cursor::new(file), &mut bitset)?;
+        // a split block bloom filter consists of blocks of 32 bytes
+        if bitset.is_empty() || bitset.len() % 32 != 0 {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            dtype: dtype.clone(),
+            bitset,
+        }))
+    }
+
+    pub(super) fn bitset(&self) -> &[u8] {
+        &self.bitset
+    }
+
+    /// Returns `false` if the column chunk certainly doesn't contain the value in `value`.
+    pub fn may_contain(&self, value: &Series) -> bool {
+        // values of other types may have another physical representation
+        if value.len() != 1 || value.null_count() > 0 || value.dtype() != &self.dtype {
+            return true;
+        }
+        match hash_values(value) {
+            Ok(hashes) => hashes.iter().any(|hash| is_in_set(&self.bitset, *hash)),
+            Err(_) => true,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Parquet field ids, which identify the fields of a file independent of their names, as
+//! required by table formats such as Apache Iceberg.
+//!
+//! A field is addressed by its path: the names of the field and its parents, separated by
+//! `"."`. The items of a list are addressed by the name of the list's element field.
+use arrow::datatypes::ArrowDataType;
+use polars_core::prelude::*;
+use polars_parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use polars_parquet::parquet::schema::types::{GroupConvertedType, GroupLogicalType};
+use polars_parquet::write::{FileMetaData, ParquetType};
+
+fn is_list(parquet_type: &ParquetType) -> bool {
+    matches!(
+        parquet_type,
+        ParquetType::GroupType {
+            logical_type: Some(GroupLogicalType::List),
+            ..
+        } | ParquetType::GroupType {
+            converted_type: Some(GroupConvertedType::List),
+            ..
+        }
+    )
+}
+
+fn collect_field_ids(parquet_type: &ParquetType, path: String, out: &mut Vec<(String, i32)>) {
+    if let Some(id) = parquet_type.get_field_info().id {
+        out.push((path.clone(), id));
+    }
+    let ParquetType::GroupType { fields, .. } = parquet_type else {
+        return;
+    };
+    let list = is_list(parquet_type);
+    for field in fields {
+        match field {
+            // the repeated group of a three-level list wraps the element
+            ParquetType::GroupType {
+                fields: elements, ..
+            } if list && elements.len() == 1 => {
+                let element = &elements[0];
+                let element_path = format!("{path}.{}", element.get_field_info().name);
+                collect_field_ids(element, element_path, out)
+            },
+            _ => {
+                let field_path = format!("{path}.{}", field.get_field_info().name);
+                collect_field_ids(field, field_path, out)
+            },
+        }
+    }
+}
+
+/// The field ids in the schema of a parquet file, by the path of the field.
+pub fn read_field_ids(metadata: &FileMetaData) -> Vec<(String, i32)> {
+    let mut out = vec![];
+    for field in metadata.schema().fields() {
+        collect_field_ids(field, field.get_field_info().name.clone(), &mut out);
+    }
+    out
+}
+
+fn set_field_id(fields: &mut [ArrowField], path: &str, id: i32) -> bool {
+    for field in fields {
+        if path == field.name {
+            field
+                .metadata
+                .insert(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string());
+            return true;
+        }
+        let Some(child_path) = path
+            .strip_prefix(field.name.as_str())
+            .and_then(|p| p.strip_prefix('.'))
+        else {
+            continue;
+        };
+        let found = match &mut field.data_type {
+            ArrowDataType::Struct(children) => set_field_id(children, child_path, id),
+            ArrowDataType::List(child)
+            | ArrowDataType::LargeList(child)
+            | ArrowDataType::FixedSizeList(child, _) => {
+                set_field_id(std::slice::from_mut(child.as_mut()), child_path, id)
+            },
+            _ => false,
+        };
+        if found {
+            return true;
+        }
+    }
+    false
+}
+
+/// Store the field ids in the metadata of the arrow fields, from which the parquet schema is
+/// created.
+pub(super) fn set_field_ids(
+    fields: &mut [ArrowField],
+    field_ids: &[(String, i32)],
+) -> PolarsResult<()> {
+    for (path, id) in field_ids {
+        polars_ensure!(
+            set_field_id(fields, path, *id),
+            ColumnNotFound: "cannot set the parquet field id of '{}': field not found", path
+        );
+    }
+    Ok(())
+}
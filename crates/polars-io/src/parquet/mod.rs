@@ -16,6 +16,8 @@
 //!
 #[cfg(feature = "cloud")]
 pub(super) mod async_impl;
+mod bloom_filter;
+mod field_id;
 pub(super) mod mmap;
 pub mod predicates;
 mod read;
@@ -24,7 +26,10 @@ mod write;
 
 use std::borrow::Cow;
 
-pub use polars_parquet::write::FileMetaData;
+pub use bloom_filter::BloomFilter;
+pub use field_id::read_field_ids;
+pub use polars_parquet::read::KeyRetriever;
+pub use polars_parquet::write::{EncryptionKey, FileEncryptionProperties, FileMetaData};
 pub use read::*;
 pub use write::{BrotliLevel, GzipLevel, ZstdLevel, *};
 
@@ -139,4 +144,205 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.equals(&expected);
     }
+
+    #[test]
+    fn test_parquet_bloom_filter() -> PolarsResult<()> {
+        use super::BloomFilter;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1i64, 5, 3, 7], "b" => ["x", "y", "z", "w"])?;
+
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .with_bloom_filter_columns(vec!["b".to_string()])
+            .finish(&mut df)?;
+        let file = buf.into_inner();
+        let metadata = ParquetReader::new(Cursor::new(file.clone()))
+            .get_metadata()?
+            .clone();
+        assert_eq!(metadata.row_groups.len(), 2);
+
+        for (rg, (present, absent)) in metadata.row_groups.iter().zip([("x", "z"), ("z", "x")]) {
+            let [a, b] = rg.columns() else {
+                panic!("expected two column chunks")
+            };
+            assert!(a.metadata().bloom_filter_offset.is_none());
+            let bloom_filter = BloomFilter::read(b, &DataType::String, &file)?.unwrap();
+            assert!(bloom_filter.may_contain(&Series::new("", [present])));
+            assert!(!bloom_filter.may_contain(&Series::new("", [absent])));
+            // a value of another type is never ruled out
+            assert!(bloom_filter.may_contain(&Series::new("", [1i64])));
+        }
+
+        let err = ParquetWriter::new(Cursor::new(vec![]))
+            .with_bloom_filter_columns(vec!["c".to_string()])
+            .finish(&mut df);
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_column_options_and_row_group_size_bytes() -> PolarsResult<()> {
+        use polars_parquet::parquet::compression::Compression;
+        use polars_parquet::write::Encoding;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..100i64).collect::<Vec<_>>(), "b" => [1.5f64; 100])?;
+
+        let a_options = ParquetColumnOptions {
+            compression: Some(ParquetCompression::Snappy),
+            encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+            statistics: Some(false),
+        };
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            // 25 rows of two 8 byte columns
+            .with_row_group_size_bytes(Some(400))
+            .with_column_options(vec![("a".to_string(), a_options)])
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = ParquetReader::new(buf);
+        let metadata = reader.get_metadata()?.clone();
+        assert_eq!(metadata.row_groups.len(), 4);
+        for rg in &metadata.row_groups {
+            let [a, b] = rg.columns() else {
+                panic!("expected two column chunks")
+            };
+            assert!(a.statistics().is_none());
+            assert!(b.statistics().is_some());
+            assert_eq!(a.compression(), Compression::Snappy);
+            assert_eq!(b.compression(), Compression::Zstd);
+            assert!(a
+                .column_encoding()
+                .iter()
+                .any(|e| matches!(Encoding::try_from(*e), Ok(Encoding::DeltaBinaryPacked))));
+        }
+        assert!(reader.finish()?.equals(&df));
+
+        // delta length byte array encoding is only for strings and binary
+        let err = ParquetWriter::new(Cursor::new(vec![]))
+            .with_column_options(vec![(
+                "a".to_string(),
+                ParquetColumnOptions {
+                    encoding: Some(ParquetEncoding::DeltaLengthByteArray),
+                    ..Default::default()
+                },
+            )])
+            .finish(&mut df);
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_parquet_field_ids_round_trip() -> PolarsResult<()> {
+        let s = StructChunked::new(
+            "s",
+            &[Series::new("x", [1, 2]), Series::new("y", ["a", "b"])],
+        )?
+        .into_series();
+        let l = Series::new("l", [Series::new("", [1, 2]), Series::new("", [3])]);
+        let mut df = DataFrame::new(vec![Series::new("a", [1, 2]), s, l])?;
+
+        let field_ids = vec![
+            ("a".to_string(), 1),
+            ("s".to_string(), 2),
+            ("s.x".to_string(), 3),
+            ("s.y".to_string(), 4),
+            ("l".to_string(), 5),
+            ("l.item".to_string(), 6),
+        ];
+        let mut f = Cursor::new(vec![]);
+        ParquetWriter::new(&mut f)
+            .with_field_ids(field_ids.clone())
+            .finish(&mut df)?;
+
+        f.set_position(0);
+        let mut reader = ParquetReader::new(&mut f);
+        assert_eq!(reader.field_ids()?, field_ids);
+        let mut read = reader.finish()?;
+        assert!(read.equals(&df));
+
+        // the ids are preserved when the file is rewritten
+        let mut rewritten = Cursor::new(vec![]);
+        ParquetWriter::new(&mut rewritten)
+            .with_field_ids(field_ids.clone())
+            .finish(&mut read)?;
+        rewritten.set_position(0);
+        assert_eq!(ParquetReader::new(rewritten).field_ids()?, field_ids);
+
+        let err = ParquetWriter::new(Cursor::new(vec![]))
+            .with_field_ids(vec![("s.z".to_string(), 7)])
+            .finish(&mut df);
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet_encryption")]
+    #[test]
+    fn test_parquet_encryption_round_trip() -> PolarsResult<()> {
+        struct Keys(Vec<(&'static [u8], Vec<u8>)>);
+
+        impl KeyRetriever for Keys {
+            fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+                self.0
+                    .iter()
+                    .find(|(metadata, _)| *metadata == key_metadata)
+                    .map(|(_, key)| key.clone())
+                    .ok_or_else(|| polars_err!(ComputeError: "unknown key"))
+            }
+        }
+
+        let footer_key = vec![1u8; 16];
+        let column_key = vec![2u8; 32];
+        // strings are dictionary encoded
+        let mut df = df![
+            "a" => [1, 2, 3, 4, 5],
+            "secret" => ["hidden-1", "hidden-2", "hidden-3", "hidden-1", "hidden-2"],
+        ]?;
+
+        let properties = FileEncryptionProperties::new(
+            EncryptionKey::new(footer_key.clone()).with_key_metadata(b"footer".to_vec()),
+        )
+        .with_column_key(
+            "secret".to_string(),
+            EncryptionKey::new(column_key.clone()).with_key_metadata(b"column".to_vec()),
+        );
+        let mut f = Cursor::new(vec![]);
+        ParquetWriter::new(&mut f)
+            .with_compression(ParquetCompression::Uncompressed)
+            .with_row_group_size(Some(2))
+            .with_encryption(Some(properties))
+            .finish(&mut df)?;
+
+        let bytes = f.get_ref();
+        assert_eq!(&bytes[..4], b"PARE");
+        assert!(!bytes.windows(7).any(|w| w == b"hidden-"));
+
+        let keys = Keys(vec![
+            (&b"footer"[..], footer_key.clone()),
+            (&b"column"[..], column_key),
+        ]);
+        f.set_position(0);
+        let read = ParquetReader::new(&mut f)
+            .with_decryption(Some(Arc::new(keys)))
+            .finish()?;
+        assert!(read.equals(&df));
+
+        // the column metadata can't be decrypted with a wrong key
+        f.set_position(0);
+        let keys = Keys(vec![
+            (&b"footer"[..], footer_key.clone()),
+            (&b"column"[..], vec![3; 32]),
+        ]);
+        let read = ParquetReader::new(&mut f)
+            .with_decryption(Some(Arc::new(keys)))
+            .finish();
+        assert!(read.is_err());
+
+        f.set_position(0);
+        assert!(ParquetReader::new(&mut f).finish().is_err());
+        Ok(())
+    }
 }
@@ -1,9 +1,11 @@
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{get_field_columns, RowGroupMetaData};
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use super::BloomFilter;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, StatsEvaluator};
+use crate::scan_stats::ScanStatistics;
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -16,42 +18,94 @@ impl ColumnStats {
     }
 }
 
+/// Read the bloom filter of the column chunk of a non-nested field, if it has one.
+fn read_bloom_filter(
+    md: &RowGroupMetaData,
+    field: &ArrowField,
+    file: &[u8],
+) -> PolarsResult<Option<BloomFilter>> {
+    match get_field_columns(md.columns(), &field.name).as_slice() {
+        [column] if column.metadata().bloom_filter_offset.is_some() => {
+            BloomFilter::read(column, &DataType::from(field.data_type()), file)
+        },
+        _ => Ok(None),
+    }
+}
+
 /// Collect the statistics in a column chunk.
+///
+/// If the bytes of the file are given, the bloom filters of the column chunks are collected as
+/// well.
 pub(crate) fn collect_statistics(
     md: &RowGroupMetaData,
     schema: &ArrowSchema,
+    file: Option<&[u8]>,
 ) -> PolarsResult<Option<BatchStats>> {
     let mut stats = vec![];
 
     for field in schema.fields.iter() {
         let st = deserialize(field, md)?;
-        stats.push(ColumnStats::from_arrow_stats(st, field));
+        let mut column_stats = ColumnStats::from_arrow_stats(st, field);
+        if let Some(file) = file {
+            column_stats = column_stats.with_bloom_filter(read_bloom_filter(md, field, file)?);
+        }
+        stats.push(column_stats);
     }
 
     Ok(if stats.is_empty() {
         None
     } else {
-        Some(BatchStats::new(Arc::new(schema.into()), stats))
+        Some(BatchStats::new(
+            Arc::new(schema.into()),
+            stats,
+            Some(md.num_rows()),
+        ))
     })
 }
 
+fn should_read(
+    pred: &dyn StatsEvaluator,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    file: Option<&[u8]>,
+) -> PolarsResult<bool> {
+    if let Some(stats) = collect_statistics(md, schema, file)? {
+        let should_read = pred.should_read(&stats);
+        // a parquet file may not have statistics of all columns
+        if matches!(should_read, Ok(false)) {
+            return Ok(false);
+        } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
+            let _ = should_read?;
+        }
+    }
+    Ok(true)
+}
+
+/// Determine from the statistics of a row group whether it should be read. If the bytes of the
+/// file are available, the bloom filters of the row group are used as well.
+///
+/// Pruned row groups are counted in `scan_stats`, which requires evaluating the predicate
+/// without the bloom filters first to tell what pruned them.
 pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
     schema: &ArrowSchemaRef,
+    file: Option<&[u8]>,
+    scan_stats: Option<&ScanStatistics>,
 ) -> PolarsResult<bool> {
-    if let Some(pred) = predicate {
-        if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(md, schema)? {
-                let should_read = pred.should_read(&stats);
-                // a parquet file may not have statistics of all columns
-                if matches!(should_read, Ok(false)) {
-                    return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
-                    let _ = should_read?;
-                }
-            }
-        }
+    let Some(pred) = predicate.and_then(|pred| pred.as_stats_evaluator()) else {
+        return Ok(true);
+    };
+    let Some(scan_stats) = scan_stats else {
+        return should_read(pred, md, schema, file);
+    };
+    if !should_read(pred, md, schema, None)? {
+        scan_stats.add_row_groups_pruned_by_statistics(1);
+        Ok(false)
+    } else if file.is_some() && !should_read(pred, md, schema, file)? {
+        scan_stats.add_row_groups_pruned_by_bloom_filter(1);
+        Ok(false)
+    } else {
+        Ok(true)
     }
-    Ok(true)
 }
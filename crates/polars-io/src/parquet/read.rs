@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "cloud")]
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_parquet::read;
+use polars_parquet::read::KeyRetriever;
 use polars_parquet::write::FileMetaData;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,7 @@ use crate::parquet::async_impl::ParquetObjectStore;
 pub use crate::parquet::read_impl::BatchedParquetReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::scan_stats::ScanStatistics;
 use crate::RowIndex;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
@@ -54,6 +56,8 @@ pub struct ParquetReader<R: Read + Seek> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     hive_partition_columns: Option<Vec<Series>>,
     use_statistics: bool,
+    scan_stats: Option<Arc<ScanStatistics>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -127,22 +131,54 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
+    /// The parquet field ids of the fields of the file, by the path of the field. See
+    /// [`read_field_ids`].
+    pub fn field_ids(&mut self) -> PolarsResult<Vec<(String, i32)>> {
+        let metadata = self.get_metadata()?;
+        Ok(read_field_ids(metadata))
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
     }
 
+    /// Decrypt an encrypted file with the keys of `key_retriever`, which receives the key
+    /// metadata stored in the file. Requires the `parquet_encryption` feature.
+    pub fn with_decryption(mut self, key_retriever: Option<Arc<dyn KeyRetriever>>) -> Self {
+        self.key_retriever = key_retriever;
+        self
+    }
+
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetaDataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let metadata = match &self.key_retriever {
+                Some(key_retriever) => {
+                    read::read_metadata_with_decryption(&mut self.reader, key_retriever.as_ref())?
+                },
+                None => read::read_metadata(&mut self.reader)?,
+            };
+            self.metadata = Some(Arc::new(metadata));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
 
+    /// Set the metadata if it is already read, this saves reading the footer of the file again.
+    pub fn with_metadata(mut self, metadata: FileMetaDataRef) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
         self.predicate = predicate;
         self
     }
+
+    /// Count the pruned and read row groups and the decode time in these statistics.
+    pub fn with_scan_statistics(mut self, scan_stats: Option<Arc<ScanStatistics>>) -> Self {
+        self.scan_stats = scan_stats;
+        self
+    }
 }
 
 impl<R: MmapBytesReader + 'static> ParquetReader<R> {
@@ -162,6 +198,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             chunk_size,
             self.use_statistics,
             self.hive_partition_columns,
+            self.scan_stats,
         )
     }
 }
@@ -183,6 +220,8 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             use_statistics: true,
             hive_partition_columns: None,
+            scan_stats: None,
+            key_retriever: None,
         }
     }
 
@@ -210,6 +249,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.row_index,
             self.use_statistics,
             self.hive_partition_columns.as_deref(),
+            self.scan_stats.as_deref(),
         )
         .map(|mut df| {
             if self.rechunk {
@@ -233,6 +273,7 @@ pub struct ParquetAsyncReader {
     use_statistics: bool,
     hive_partition_columns: Option<Vec<Series>>,
     schema: Option<ArrowSchemaRef>,
+    scan_stats: Option<Arc<ScanStatistics>>,
 }
 
 #[cfg(feature = "cloud")]
@@ -253,6 +294,7 @@ impl ParquetAsyncReader {
             use_statistics: true,
             hive_partition_columns: None,
             schema,
+            scan_stats: None,
         })
     }
 
@@ -303,6 +345,14 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Count the pruned and read row groups, the downloaded bytes and the decode time in these
+    /// statistics.
+    pub fn with_scan_statistics(mut self, scan_stats: Option<Arc<ScanStatistics>>) -> Self {
+        self.reader.set_scan_statistics(scan_stats.clone());
+        self.scan_stats = scan_stats;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -330,6 +380,7 @@ impl ParquetAsyncReader {
             chunk_size,
             self.use_statistics,
             self.hive_partition_columns,
+            self.scan_stats,
         )
     }
 
@@ -22,6 +22,7 @@ use crate::parquet::mmap::mmap_columns;
 use crate::parquet::predicates::read_this_row_group;
 use crate::parquet::{mmap, FileMetaDataRef, ParallelStrategy};
 use crate::predicates::{apply_predicate, PhysicalIoExpr};
+use crate::scan_stats::ScanStatistics;
 use crate::utils::get_reader_bytes;
 use crate::RowIndex;
 
@@ -124,6 +125,21 @@ pub(crate) fn materialize_hive_partitions(
     }
 }
 
+/// Decode the columns of a row group, adding the time it takes to the scan statistics.
+fn time_decode<F>(scan_stats: Option<&ScanStatistics>, func: F) -> PolarsResult<Vec<Series>>
+where
+    F: FnOnce() -> PolarsResult<Vec<Series>>,
+{
+    match scan_stats {
+        Some(scan_stats) => {
+            let columns = scan_stats.time_decode(func)?;
+            scan_stats.add_row_groups_read(1);
+            Ok(columns)
+        },
+        None => func(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -139,6 +155,7 @@ fn rg_to_dfs(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    scan_stats: Option<&ScanStatistics>,
 ) -> PolarsResult<Vec<DataFrame>> {
     if let ParallelStrategy::Columns | ParallelStrategy::None = parallel {
         rg_to_dfs_optionally_par_over_columns(
@@ -155,6 +172,7 @@ fn rg_to_dfs(
             projection,
             use_statistics,
             hive_partition_columns,
+            scan_stats,
         )
     } else {
         rg_to_dfs_par_over_rg(
@@ -170,6 +188,7 @@ fn rg_to_dfs(
             projection,
             use_statistics,
             hive_partition_columns,
+            scan_stats,
         )
     }
 }
@@ -190,6 +209,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    scan_stats: Option<&ScanStatistics>,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -198,7 +218,13 @@ fn rg_to_dfs_optionally_par_over_columns(
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && !read_this_row_group(
+                predicate,
+                &file_metadata.row_groups[rg_idx],
+                schema,
+                store.file_bytes(),
+                scan_stats,
+            )?
         {
             *previous_row_count += current_row_count;
             continue;
@@ -211,10 +237,26 @@ fn rg_to_dfs_optionally_par_over_columns(
 
         let projection_height = (*remaining_rows).min(md.num_rows());
         let chunk_size = md.num_rows();
-        let columns = if let ParallelStrategy::Columns = parallel {
-            POOL.install(|| {
+        let columns = time_decode(scan_stats, || {
+            if let ParallelStrategy::Columns = parallel {
+                POOL.install(|| {
+                    projection
+                        .par_iter()
+                        .map(|column_i| {
+                            column_idx_to_series(
+                                *column_i,
+                                md,
+                                projection_height,
+                                schema,
+                                store,
+                                chunk_size,
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                })
+            } else {
                 projection
-                    .par_iter()
+                    .iter()
                     .map(|column_i| {
                         column_idx_to_series(
                             *column_i,
@@ -226,22 +268,8 @@ fn rg_to_dfs_optionally_par_over_columns(
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()
-            })?
-        } else {
-            projection
-                .iter()
-                .map(|column_i| {
-                    column_idx_to_series(
-                        *column_i,
-                        md,
-                        projection_height,
-                        schema,
-                        store,
-                        chunk_size,
-                    )
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-        };
+            }
+        })?;
 
         *remaining_rows -= projection_height;
 
@@ -278,6 +306,7 @@ fn rg_to_dfs_par_over_rg(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    scan_stats: Option<&ScanStatistics>,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let row_groups = file_metadata
@@ -302,7 +331,13 @@ fn rg_to_dfs_par_over_rg(
         .map(|(rg_idx, md, projection_height, row_count_start)| {
             if projection_height == 0
                 || use_statistics
-                    && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+                    && !read_this_row_group(
+                        predicate,
+                        &file_metadata.row_groups[rg_idx],
+                        schema,
+                        store.file_bytes(),
+                        scan_stats,
+                    )?
             {
                 return Ok(None);
             }
@@ -313,19 +348,21 @@ fn rg_to_dfs_par_over_rg(
             }
 
             let chunk_size = md.num_rows();
-            let columns = projection
-                .iter()
-                .map(|column_i| {
-                    column_idx_to_series(
-                        *column_i,
-                        md,
-                        projection_height,
-                        schema,
-                        store,
-                        chunk_size,
-                    )
-                })
-                .collect::<PolarsResult<Vec<_>>>()?;
+            let columns = time_decode(scan_stats, || {
+                projection
+                    .iter()
+                    .map(|column_i| {
+                        column_idx_to_series(
+                            *column_i,
+                            md,
+                            projection_height,
+                            schema,
+                            store,
+                            chunk_size,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
 
             let mut df = DataFrame::new_no_checks(columns);
 
@@ -354,6 +391,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     row_index: Option<RowIndex>,
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    scan_stats: Option<&ScanStatistics>,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if limit == 0 {
@@ -421,6 +459,7 @@ pub fn read_parquet<R: MmapBytesReader>(
         &materialized_projection,
         use_statistics,
         hive_partition_columns,
+        scan_stats,
     )?;
 
     if dfs.is_empty() {
@@ -524,6 +563,7 @@ pub struct BatchedParquetReader {
     chunk_size: usize,
     use_statistics: bool,
     hive_partition_columns: Option<Vec<Series>>,
+    scan_stats: Option<Arc<ScanStatistics>>,
     /// Has returned at least one materialized frame.
     has_returned: bool,
 }
@@ -541,6 +581,7 @@ impl BatchedParquetReader {
         chunk_size: usize,
         use_statistics: bool,
         hive_partition_columns: Option<Vec<Series>>,
+        scan_stats: Option<Arc<ScanStatistics>>,
     ) -> PolarsResult<Self> {
         let n_row_groups = metadata.row_groups.len();
         let projection = projection.unwrap_or_else(|| (0usize..schema.len()).collect::<Vec<_>>());
@@ -568,6 +609,7 @@ impl BatchedParquetReader {
             chunk_size,
             use_statistics,
             hive_partition_columns,
+            scan_stats,
             has_returned: false,
         })
     }
@@ -624,6 +666,7 @@ impl BatchedParquetReader {
                 &self.projection,
                 self.use_statistics,
                 self.hive_partition_columns.as_deref(),
+                self.scan_stats.as_deref(),
             )?;
 
             self.row_group_offset += n;
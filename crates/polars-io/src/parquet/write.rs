@@ -1,9 +1,12 @@
+use std::borrow::Cow;
 use std::io::Write;
+use std::path::PathBuf;
 
 use arrow::array::{Array, ArrayRef};
 use arrow::chunk::Chunk;
 use arrow::datatypes::{ArrowDataType, PhysicalType};
 use polars_core::prelude::*;
+use polars_core::utils::flatten::flatten_df_iter;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
@@ -16,6 +19,10 @@ use write::{
     ZstdLevel as ZstdLevelParquet,
 };
 
+use super::bloom_filter::{ensure_bloom_filter_supported, BloomFilter};
+use super::field_id::set_field_ids;
+use crate::{SerWriter, WriterFactory};
+
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GzipLevel(u8);
@@ -89,6 +96,69 @@ impl From<ParquetCompression> for CompressionOptions {
     }
 }
 
+/// The encodings that can be requested for a column with [`ParquetColumnOptions`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Dictionary encoding; falls back to `Plain` if the values cannot be dictionary encoded.
+    RleDictionary,
+    /// Only for integer and temporal columns.
+    DeltaBinaryPacked,
+    /// Only for string and binary columns.
+    DeltaLengthByteArray,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::RleDictionary => Encoding::RleDictionary,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+        }
+    }
+}
+
+fn ensure_encoding_supported(
+    name: &str,
+    dtype: &DataType,
+    encoding: ParquetEncoding,
+) -> PolarsResult<()> {
+    use DataType::*;
+    let supported = match (dtype, encoding) {
+        (dtype, _) if dtype.inner_dtype().is_some() => false,
+        #[cfg(feature = "dtype-struct")]
+        (Struct(_), _) => false,
+        #[cfg(feature = "dtype-categorical")]
+        (Categorical(_, _), encoding) => encoding == ParquetEncoding::RleDictionary,
+        (_, ParquetEncoding::Plain | ParquetEncoding::RleDictionary) => true,
+        (dtype, ParquetEncoding::DeltaBinaryPacked) => {
+            dtype.is_integer() || matches!(dtype, Date | Datetime(_, _) | Duration(_) | Time)
+        },
+        (String | Binary, ParquetEncoding::DeltaLengthByteArray) => true,
+        _ => false,
+    };
+    polars_ensure!(
+        supported,
+        InvalidOperation: "cannot write column '{}' of dtype {} with encoding {:?}",
+        name, dtype, encoding
+    );
+    Ok(())
+}
+
+/// Options of a single column that override the options of the [`ParquetWriter`].
+#[derive(Debug, Default, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// Data page compression of this column.
+    pub compression: Option<ParquetCompression>,
+    /// Encoding of this column. Not supported for nested columns.
+    pub encoding: Option<ParquetEncoding>,
+    /// Compute and write the statistics of this column.
+    pub statistics: Option<bool>,
+}
+
 /// Write a DataFrame to parquet format
 ///
 #[must_use]
@@ -100,10 +170,20 @@ pub struct ParquetWriter<W> {
     statistics: bool,
     /// if `None` will be 512^2 rows
     row_group_size: Option<usize>,
+    /// Target estimated size of a row group in bytes
+    row_group_size_bytes: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Write bloom filters of these columns
+    bloom_filter_columns: Vec<String>,
+    /// Options that override the above for some columns
+    column_options: Vec<(String, ParquetColumnOptions)>,
+    /// Parquet field ids by the path of the field
+    field_ids: Vec<(String, i32)>,
+    /// Modular encryption of the file
+    encryption: Option<FileEncryptionProperties>,
 }
 
 impl<W> ParquetWriter<W>
@@ -120,8 +200,13 @@ where
             compression: ParquetCompression::default().into(),
             statistics: false,
             row_group_size: None,
+            row_group_size_bytes: None,
             data_page_size: None,
             parallel: true,
+            bloom_filter_columns: vec![],
+            column_options: vec![],
+            field_ids: vec![],
+            encryption: None,
         }
     }
 
@@ -147,6 +232,14 @@ where
         self
     }
 
+    /// Set the maximum size of a row group in bytes, estimated from the in-memory size of the
+    /// [`DataFrame`]. Row groups that are larger are split, so this also applies to the batched
+    /// writer. If the row group size in rows is set as well, the smallest of both wins.
+    pub fn with_row_group_size_bytes(mut self, size: Option<usize>) -> Self {
+        self.row_group_size_bytes = size;
+        self
+    }
+
     /// Sets the maximum bytes size of a data page. If `None` will be 1024^2 bytes.
     pub fn with_data_page_size(mut self, limit: Option<usize>) -> Self {
         self.data_page_size = limit;
@@ -159,6 +252,50 @@ where
         self
     }
 
+    /// Write a split block bloom filter of the values of these columns in every row group.
+    ///
+    /// Readers use the bloom filters to skip row groups that don't contain the value of an
+    /// equality predicate, which the min/max statistics can rarely do for high-cardinality keys
+    /// whose values are not sorted. Only non-nested numeric, temporal, string and binary
+    /// columns are supported.
+    pub fn with_bloom_filter_columns(mut self, columns: Vec<String>) -> Self {
+        self.bloom_filter_columns = columns;
+        self
+    }
+
+    /// Override the compression, encoding or statistics of the columns with these names.
+    pub fn with_column_options(
+        mut self,
+        column_options: Vec<(String, ParquetColumnOptions)>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
+    /// Set the parquet field ids of fields, given as the path of the field and its id. The path
+    /// of a nested field joins the names of the field and its parents with `"."`, where the
+    /// items of a list are addressed by the name of the list's element field.
+    ///
+    /// Field ids identify the fields independent of their names, as required by Apache Iceberg.
+    /// Use [`read_field_ids`](super::read_field_ids) to preserve the field ids of a file that
+    /// is rewritten.
+    pub fn with_field_ids(mut self, field_ids: Vec<(String, i32)>) -> Self {
+        self.field_ids = field_ids;
+        self
+    }
+
+    /// Encrypt the file with parquet modular encryption. The footer and the columns that have no
+    /// key of their own are encrypted with the footer key of `properties`; if any column has a
+    /// key of its own, the columns without one are written in plaintext.
+    ///
+    /// Encrypted files are read with
+    /// [`ParquetReader::with_decryption`](super::ParquetReader::with_decryption). Requires the
+    /// `parquet_encryption` feature.
+    pub fn with_encryption(mut self, properties: Option<FileEncryptionProperties>) -> Self {
+        self.encryption = properties;
+        self
+    }
+
     fn materialize_options(&self) -> WriteOptions {
         WriteOptions {
             write_statistics: self.statistics,
@@ -168,21 +305,70 @@ where
         }
     }
 
-    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let fields = schema.to_arrow(true).fields;
-        let schema = ArrowSchema::from(fields);
+    /// The options of every field, and the encodings with the overrides applied.
+    fn materialize_column_options(
+        &self,
+        schema: &Schema,
+        encodings: &mut [Vec<Encoding>],
+    ) -> PolarsResult<Vec<WriteOptions>> {
+        let mut options = vec![self.materialize_options(); schema.len()];
+        for (name, column_options) in &self.column_options {
+            let (field_idx, _, dtype) = schema.try_get_full(name)?;
+            let field_options = &mut options[field_idx];
+            if let Some(compression) = column_options.compression {
+                field_options.compression = compression.into();
+            }
+            if let Some(statistics) = column_options.statistics {
+                field_options.write_statistics = statistics;
+            }
+            if let Some(encoding) = column_options.encoding {
+                ensure_encoding_supported(name, dtype, encoding)?;
+                encodings[field_idx] = vec![encoding.into()];
+            }
+        }
+        Ok(options)
+    }
 
-        let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
-        let options = self.materialize_options();
-        let writer = FileWriter::try_new(self.writer, schema, options)?;
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let mut fields = schema.to_arrow(true).fields;
+        set_field_ids(&mut fields, &self.field_ids)?;
+        let arrow_schema = ArrowSchema::from(fields);
+
+        let parquet_schema = to_parquet_schema(&arrow_schema)?;
+        let mut encodings = get_encodings(&arrow_schema);
+        let column_options = self.materialize_column_options(schema, &mut encodings)?;
+        // the file writes the column indexes if any of the columns has statistics
+        let options = WriteOptions {
+            write_statistics: column_options.iter().any(|o| o.write_statistics),
+            ..self.materialize_options()
+        };
+        let bloom_filter_columns = self
+            .bloom_filter_columns
+            .iter()
+            .map(|name| {
+                let (field_idx, _, dtype) = schema.try_get_full(name)?;
+                ensure_bloom_filter_supported(dtype)?;
+                let column_idx = parquet_schema
+                    .columns()
+                    .iter()
+                    .position(|column| column.path_in_schema == [name.as_str()])
+                    .unwrap();
+                Ok((field_idx, column_idx))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut writer = FileWriter::try_new(self.writer, arrow_schema, options)?;
+        if let Some(properties) = self.encryption {
+            writer = writer.with_encryption(properties)?;
+        }
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
-            options,
+            column_options,
             parallel: self.parallel,
+            row_group_size_bytes: self.row_group_size_bytes,
+            bloom_filter_columns,
         })
     }
 
@@ -202,11 +388,99 @@ where
 }
 
 // Note that the df should be rechunked
+/// Writes a [`DataFrame`] with a [`ParquetWriter`] through the [`SerWriter`] interface.
+struct ParquetSerWriter<W: Write>(Option<ParquetWriter<W>>);
+
+impl<W: Write> SerWriter<W> for ParquetSerWriter<W> {
+    fn new(writer: W) -> Self {
+        Self(Some(ParquetWriter::new(writer)))
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let writer = self
+            .0
+            .take()
+            .ok_or_else(|| polars_err!(ComputeError: "the parquet file is already written"))?;
+        writer.finish(df).map(|_| ())
+    }
+}
+
+pub struct ParquetWriterOption {
+    compression: ParquetCompression,
+    statistics: bool,
+    row_group_size: Option<usize>,
+    data_page_size: Option<usize>,
+    extension: PathBuf,
+}
+
+impl ParquetWriterOption {
+    pub fn new() -> Self {
+        Self {
+            compression: ParquetCompression::default(),
+            statistics: false,
+            row_group_size: None,
+            data_page_size: None,
+            extension: PathBuf::from(".parquet"),
+        }
+    }
+
+    /// Set the compression used. Defaults to `Zstd`.
+    pub fn with_compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Compute and write statistic
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Set the row group size (in number of rows) during writing.
+    pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
+        self.row_group_size = size;
+        self
+    }
+
+    /// Sets the maximum bytes size of a data page. If `None` will be 1024^2 bytes.
+    pub fn with_data_page_size(mut self, limit: Option<usize>) -> Self {
+        self.data_page_size = limit;
+        self
+    }
+
+    /// Set the extension. Defaults to ".parquet".
+    pub fn with_extension(mut self, extension: PathBuf) -> Self {
+        self.extension = extension;
+        self
+    }
+}
+
+impl Default for ParquetWriterOption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WriterFactory for ParquetWriterOption {
+    fn create_writer<W: Write + 'static>(&self, writer: W) -> Box<dyn SerWriter<W>> {
+        let writer = ParquetWriter::new(writer)
+            .with_compression(self.compression)
+            .with_statistics(self.statistics)
+            .with_row_group_size(self.row_group_size)
+            .with_data_page_size(self.data_page_size);
+        Box::new(ParquetSerWriter(Some(writer)))
+    }
+
+    fn extension(&self) -> PathBuf {
+        self.extension.to_owned()
+    }
+}
+
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    options: &'a [WriteOptions],
     parallel: bool,
 ) -> impl Iterator<Item = PolarsResult<RowGroupIter<'a, PolarsError>>> + 'a {
     let rb_iter = df.iter_chunks(true);
@@ -221,6 +495,26 @@ fn prepare_rg_iter<'a>(
     })
 }
 
+/// Split the row groups (the chunks) of `df` whose estimated size is larger than `size` bytes.
+fn split_row_groups_by_bytes(df: &DataFrame, size: usize) -> Cow<DataFrame> {
+    let bytes_per_row = df.estimated_size() / df.height().max(1);
+    let max_rows = (size / bytes_per_row.max(1)).max(1);
+    let fits = df
+        .get_columns()
+        .first()
+        .map_or(true, |s| s.chunk_lengths().all(|len| len <= max_rows));
+    if fits {
+        return Cow::Borrowed(df);
+    }
+    let row_groups = flatten_df_iter(df).flat_map(|chunk| {
+        (0..chunk.height())
+            .step_by(max_rows)
+            .map(|offset| chunk.slice(offset as i64, max_rows))
+            .collect::<Vec<_>>()
+    });
+    Cow::Owned(accumulate_dataframes_vertical_unchecked(row_groups))
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
@@ -251,8 +545,12 @@ pub struct BatchedWriter<W: Write> {
     writer: FileWriter<W>,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
-    options: WriteOptions,
+    /// The options of every field.
+    column_options: Vec<WriteOptions>,
     parallel: bool,
+    row_group_size_bytes: Option<usize>,
+    /// The index of the field and of the parquet column of the columns that get a bloom filter.
+    bloom_filter_columns: Vec<(usize, usize)>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -261,15 +559,39 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let df = match self.row_group_size_bytes {
+            Some(size) => split_row_groups_by_bytes(df, size),
+            None => Cow::Borrowed(df),
+        };
+        let df = &*df;
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
             &self.encodings,
-            self.options,
+            &self.column_options,
             self.parallel,
         );
+        // the row groups are the non-empty chunks
+        let mut row_group_lengths = df
+            .get_columns()
+            .first()
+            .into_iter()
+            .flat_map(|s| s.chunk_lengths())
+            .filter(|len| *len > 0);
+        let mut offset = 0;
         for group in row_group_iter {
             self.writer.write(group?)?;
+
+            if !self.bloom_filter_columns.is_empty() {
+                let len = row_group_lengths.next().unwrap();
+                for &(field_idx, column_idx) in &self.bloom_filter_columns {
+                    let s = df.get_columns()[field_idx].slice(offset as i64, len);
+                    let bloom_filter = BloomFilter::from_values(&s)?;
+                    self.writer
+                        .write_bloom_filter(column_idx, bloom_filter.bitset())?;
+                }
+                offset += len;
+            }
         }
         Ok(())
     }
@@ -285,10 +607,14 @@ fn create_serializer<'a>(
     batch: Chunk<Box<dyn Array>>,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    options: &[WriteOptions],
     parallel: bool,
 ) -> PolarsResult<RowGroupIter<'a, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
+    let func = move |(((array, type_), encoding), options): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &WriteOptions,
+    )| {
+        let options = *options;
         let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
 
         encoded_columns
@@ -322,6 +648,7 @@ fn create_serializer<'a>(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(options)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -331,6 +658,7 @@ fn create_serializer<'a>(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(options)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
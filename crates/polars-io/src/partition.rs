@@ -2,6 +2,7 @@ use std::fs::File;
 use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 
+use percent_encoding::{percent_encode, AsciiSet, CONTROLS};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::POOL;
@@ -10,8 +11,42 @@ use rayon::prelude::*;
 use crate::utils::resolve_homedir;
 use crate::WriterFactory;
 
+/// The directory name of a null partition value, as used by Hive.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+/// The characters that Hive escapes in partition values.
+const HIVE_ESCAPE: &AsciiSet = &CONTROLS
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'=')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'{')
+    .add(b'[')
+    .add(b']')
+    .add(b'^');
+
+fn partition_value(value: AnyValue) -> String {
+    match value {
+        AnyValue::Null => HIVE_DEFAULT_PARTITION.to_string(),
+        value => {
+            let value = match value.get_str() {
+                Some(s) => s.to_string(),
+                None => value.to_string(),
+            };
+            percent_encode(value.as_bytes(), HIVE_ESCAPE).to_string()
+        },
+    }
+}
+
+/// The Hive directory (`{rootdir}/{key}={value}/...`) of a partition.
+///
 /// partition_df must be created by the same way of partition_by
-fn resolve_partition_dir<I, S>(rootdir: &Path, by: I, partition_df: &DataFrame) -> PathBuf
+pub fn resolve_partition_dir<I, S>(rootdir: &Path, by: I, partition_df: &DataFrame) -> PathBuf
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
@@ -20,14 +55,37 @@ where
     path.push(resolve_homedir(rootdir));
 
     for key in by.into_iter() {
-        let value = partition_df[key.as_ref()].get(0).unwrap().to_string();
+        let value = partition_value(partition_df[key.as_ref()].get(0).unwrap());
         path.push(format!("{}={}", key.as_ref(), value))
     }
     path
 }
 
+/// A random (version 4) UUID in the hyphenated form.
+fn random_uuid() -> String {
+    let mut bytes = rand::random::<u128>().to_be_bytes();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
 /// Write a DataFrame with disk partitioning
 ///
+/// Every partition is written to a directory `key=value` per partition column, in the layout
+/// that Hive and Spark use. Partitions can be split over multiple files with
+/// [`PartitionedWriter::with_max_rows_per_file`] and
+/// [`PartitionedWriter::with_max_bytes_per_file`]. The files are named by a template in which
+/// `{i}` is replaced by the index of the file, `{uuid}` by a random UUID that is shared by all
+/// files of a write and `{ext}` by the extension of the format.
+///
 /// # Example
 /// ```
 /// use polars_core::prelude::*;
@@ -37,6 +95,8 @@ where
 /// fn example(df: &mut DataFrame) -> PolarsResult<()> {
 ///     let option = IpcWriterOption::default();
 ///     PartitionedWriter::new(option, "./rootdir", ["a", "b"])
+///         .with_max_rows_per_file(Some(1_000_000))
+///         .with_filename_template("part-{i}-{uuid}.{ext}")
 ///         .finish(df)
 /// }
 /// ```
@@ -46,6 +106,9 @@ pub struct PartitionedWriter<F> {
     rootdir: PathBuf,
     by: Vec<String>,
     parallel: bool,
+    max_rows_per_file: Option<usize>,
+    max_bytes_per_file: Option<usize>,
+    filename_template: String,
 }
 
 impl<F> PartitionedWriter<F>
@@ -63,6 +126,9 @@ where
             rootdir: rootdir.into(),
             by: by.into_iter().map(|s| s.as_ref().to_string()).collect(),
             parallel: true,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+            filename_template: "data-{i}.{ext}".to_string(),
         }
     }
 
@@ -72,51 +138,124 @@ where
         self
     }
 
-    fn write_partition_df(&self, partition_df: &mut DataFrame, i: usize) -> PolarsResult<()> {
-        let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
-        std::fs::create_dir_all(&path)?;
+    /// Limit the number of rows of a file. Larger partitions are split over multiple files.
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
 
-        path.push(format!(
-            "data-{:04}.{}",
-            i,
-            self.option.extension().display()
-        ));
+    /// Limit the estimated in-memory size of the rows of a file. Larger partitions are split
+    /// over multiple files.
+    pub fn with_max_bytes_per_file(mut self, max_bytes_per_file: Option<usize>) -> Self {
+        self.max_bytes_per_file = max_bytes_per_file;
+        self
+    }
 
-        let file = std::fs::File::create(path)?;
-        let writer = BufWriter::new(file);
+    /// Set the template of the file names. Defaults to `"data-{i}.{ext}"`.
+    ///
+    /// `{i}` is replaced by the zero padded index of the file, `{uuid}` by a random UUID of this
+    /// write and `{ext}` by the extension of the format. The template must contain `{i}`.
+    pub fn with_filename_template<S: Into<String>>(mut self, template: S) -> Self {
+        self.filename_template = template.into();
+        self
+    }
+
+    /// The number of rows per file, based on the estimated size of the rows of `df`.
+    fn rows_per_file(&self, df: &DataFrame) -> usize {
+        let mut rows_per_file = self.max_rows_per_file.unwrap_or(usize::MAX);
+        if let Some(max_bytes) = self.max_bytes_per_file {
+            let bytes_per_row = df.estimated_size() / std::cmp::max(df.height(), 1);
+            rows_per_file =
+                std::cmp::min(rows_per_file, max_bytes / std::cmp::max(bytes_per_row, 1));
+        }
+        std::cmp::max(rows_per_file, 1)
+    }
 
-        self.option
-            .create_writer::<BufWriter<File>>(writer)
-            .finish(partition_df)
+    fn filename(&self, i: usize, uuid: &str) -> String {
+        let extension = self.option.extension();
+        let extension = extension.to_string_lossy();
+        self.filename_template
+            .replace("{i}", &format!("{:04}", i))
+            .replace("{uuid}", uuid)
+            .replace("{ext}", extension.trim_start_matches('.'))
+    }
+
+    /// Write a partition to the files with the indices starting at `first_file`.
+    fn write_partition_df(
+        &self,
+        partition_df: &DataFrame,
+        first_file: usize,
+        rows_per_file: usize,
+        uuid: &str,
+    ) -> PolarsResult<()> {
+        let dir = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
+        std::fs::create_dir_all(&dir)?;
+
+        for (i, offset) in (0..partition_df.height())
+            .step_by(rows_per_file)
+            .enumerate()
+        {
+            let mut file_df = partition_df.slice(offset as i64, rows_per_file);
+            let file = std::fs::File::create(dir.join(self.filename(first_file + i, uuid)))?;
+            let writer = BufWriter::new(file);
+
+            self.option
+                .create_writer::<BufWriter<File>>(writer)
+                .finish(&mut file_df)?;
+        }
+        Ok(())
     }
 
     pub fn finish(self, df: &DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            self.filename_template.contains("{i}"),
+            InvalidOperation: "the filename template '{}' must contain '{{i}}'",
+            self.filename_template
+        );
+        let rows_per_file = self.rows_per_file(df);
+        let uuid = if self.filename_template.contains("{uuid}") {
+            random_uuid()
+        } else {
+            String::new()
+        };
+
         let groups = df.group_by(self.by.clone())?;
         let groups = groups.get_groups();
 
+        // the index of the first file of every partition
+        let mut n_files = 0;
+        let first_files = groups
+            .iter()
+            .map(|group| {
+                let first_file = n_files;
+                n_files += (group.len() - 1) / rows_per_file + 1;
+                first_file
+            })
+            .collect::<Vec<_>>();
+
         // don't parallelize this
         // there is a lot of parallelization in take and this may easily SO
         POOL.install(|| {
             match groups {
                 GroupsProxy::Idx(idx) => {
                     idx.par_iter()
-                        .enumerate()
-                        .map(|(i, (_, group))| {
+                        .zip(first_files)
+                        .map(|((_, group), first_file)| {
                             // groups are in bounds
                             // and sorted
-                            let mut part_df = unsafe {
+                            let part_df = unsafe {
                                 df._take_unchecked_slice_sorted(group, false, IsSorted::Ascending)
                             };
-                            self.write_partition_df(&mut part_df, i)
+                            self.write_partition_df(&part_df, first_file, rows_per_file, &uuid)
                         })
                         .collect::<PolarsResult<Vec<_>>>()
                 },
                 GroupsProxy::Slice { groups, .. } => groups
                     .par_iter()
-                    .enumerate()
-                    .map(|(i, [first, len])| {
-                        let mut part_df = df.slice(*first as i64, *len as usize);
-                        self.write_partition_df(&mut part_df, i)
+                    .zip(first_files)
+                    .map(|([first, len], first_file)| {
+                        let part_df = df.slice(*first as i64, *len as usize);
+                        self.write_partition_df(&part_df, first_file, rows_per_file, &uuid)
                     })
                     .collect::<PolarsResult<Vec<_>>>(),
             }
@@ -182,4 +321,55 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_parquet_partition_max_rows_and_template() -> PolarsResult<()> {
+        use std::io::BufReader;
+
+        use crate::parquet::{ParquetReader, ParquetWriterOption};
+        use crate::SerReader;
+
+        let tmp_dir = tempfile::tempdir()?;
+        let rootdir = tmp_dir.path().join("parquet-partition");
+
+        let df = df!(
+            "a" => [Some("x/y"), Some("x/y"), Some("x/y"), None, Some("z")],
+            "b" => [1, 2, 3, 4, 5]
+        )?;
+        PartitionedWriter::new(ParquetWriterOption::new(), rootdir.clone(), ["a"])
+            .with_max_rows_per_file(Some(2))
+            .with_filename_template("part-{i}-{uuid}.{ext}")
+            .finish(&df)?;
+
+        let read_dir = |dir: &str| -> PolarsResult<Vec<PathBuf>> {
+            let mut paths = std::fs::read_dir(rootdir.join(dir))?
+                .map(|e| Ok(e?.path()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            paths.sort();
+            Ok(paths)
+        };
+
+        let paths = read_dir("a=x%2Fy")?;
+        assert_eq!(paths.len(), 2);
+        let mut heights = vec![];
+        for path in &paths {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            assert!(name.starts_with("part-") && name.ends_with(".parquet"));
+            // part-{i}-{uuid}.parquet
+            assert_eq!(name.len(), "part-0000-".len() + 36 + ".parquet".len());
+            let reader = BufReader::new(polars_utils::open_file(path)?);
+            heights.push(ParquetReader::new(reader).finish()?.height());
+        }
+        assert_eq!(heights, [2, 1]);
+
+        assert_eq!(read_dir("a=__HIVE_DEFAULT_PARTITION__")?.len(), 1);
+        assert_eq!(read_dir("a=z")?.len(), 1);
+
+        let result = PartitionedWriter::new(ParquetWriterOption::new(), rootdir, ["a"])
+            .with_filename_template("part-{uuid}.{ext}")
+            .finish(&df);
+        assert!(result.is_err());
+        Ok(())
+    }
 }
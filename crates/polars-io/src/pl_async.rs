@@ -62,6 +62,8 @@ impl RuntimeManager {
         F: Future + Send,
         F::Output: Send,
     {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("io_wait").entered();
         let thread_id = std::thread::current().id();
 
         if self.blocking_threads.read().unwrap().contains(&thread_id) {
@@ -78,6 +80,8 @@ impl RuntimeManager {
     where
         F: Future,
     {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("io_wait").entered();
         self.rt.block_on(future)
     }
 }
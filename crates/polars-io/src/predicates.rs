@@ -44,6 +44,7 @@ pub(crate) fn apply_predicate(
 /// - max value
 /// - min value
 /// - null_count
+/// and may hold a bloom filter of the values of a Parquet column chunk.
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -53,6 +54,9 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bloom_filter: Option<crate::parquet::BloomFilter>,
 }
 
 impl ColumnStats {
@@ -67,6 +71,8 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
@@ -77,9 +83,23 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
+    #[cfg(feature = "parquet")]
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<crate::parquet::BloomFilter>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
+    /// The bloom filter of the values, if the column chunk has one.
+    #[cfg(feature = "parquet")]
+    pub fn bloom_filter(&self) -> Option<&crate::parquet::BloomFilter> {
+        self.bloom_filter.as_ref()
+    }
+
     pub fn dtype(&self) -> &DataType {
         self.field.data_type()
     }
@@ -155,6 +175,7 @@ impl ColumnStats {
 
     fn use_min_max(dtype: &DataType) -> bool {
         dtype.is_numeric()
+            || dtype.is_temporal()
             || matches!(
                 dtype,
                 DataType::String | DataType::Binary | DataType::Boolean
@@ -168,11 +189,22 @@ impl ColumnStats {
 pub struct BatchStats {
     schema: SchemaRef,
     stats: Vec<ColumnStats>,
+    // This might not be available, as when pruning hive partitions.
+    num_rows: Option<usize>,
 }
 
 impl BatchStats {
-    pub fn new(schema: SchemaRef, stats: Vec<ColumnStats>) -> Self {
-        Self { schema, stats }
+    pub fn new(schema: SchemaRef, stats: Vec<ColumnStats>, num_rows: Option<usize>) -> Self {
+        Self {
+            schema,
+            stats,
+            num_rows,
+        }
+    }
+
+    /// The number of rows in the batch, if known.
+    pub fn num_rows(&self) -> Option<usize> {
+        self.num_rows
     }
 
     pub fn get_stats(&self, column: &str) -> polars_core::error::PolarsResult<&ColumnStats> {
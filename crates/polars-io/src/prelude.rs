@@ -3,6 +3,14 @@ use polars_core::prelude::*;
 
 #[cfg(feature = "csv")]
 pub use crate::csv::*;
+#[cfg(feature = "delta")]
+pub use crate::delta::*;
+#[cfg(feature = "excel")]
+pub use crate::excel::*;
+#[cfg(feature = "flight")]
+pub use crate::flight::*;
+#[cfg(feature = "iceberg")]
+pub use crate::iceberg::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
@@ -11,6 +19,9 @@ pub use crate::json::*;
 pub use crate::ndjson::core::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::*;
+#[cfg(feature = "partition")]
+pub use crate::partition::*;
+pub use crate::scan_stats::ScanStatistics;
 pub use crate::utils::*;
 pub use crate::{cloud, SerReader, SerWriter};
 
@@ -0,0 +1,102 @@
+//! Statistics that readers collect while scanning, to verify that pruning and pushdown work.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Counters of the work a scan did and the work it skipped.
+///
+/// The counters are atomic, so a single instance can be shared by the readers of all files of a
+/// scan, also when they run in parallel.
+#[derive(Debug, Default)]
+pub struct ScanStatistics {
+    files_listed: AtomicU64,
+    files_pruned: AtomicU64,
+    row_groups_read: AtomicU64,
+    row_groups_pruned_by_statistics: AtomicU64,
+    row_groups_pruned_by_bloom_filter: AtomicU64,
+    bytes_downloaded: AtomicU64,
+    decode_time_ns: AtomicU64,
+}
+
+impl ScanStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_files_listed(&self, n: usize) {
+        self.files_listed.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_files_pruned(&self, n: usize) {
+        self.files_pruned.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_row_groups_read(&self, n: usize) {
+        self.row_groups_read.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_row_groups_pruned_by_statistics(&self, n: usize) {
+        self.row_groups_pruned_by_statistics
+            .fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_row_groups_pruned_by_bloom_filter(&self, n: usize) {
+        self.row_groups_pruned_by_bloom_filter
+            .fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_bytes_downloaded(&self, n: usize) {
+        self.bytes_downloaded.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_decode_time(&self, duration: Duration) {
+        self.decode_time_ns
+            .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    /// Run `func` and add the time it took to the decode time.
+    pub fn time_decode<T, F: FnOnce() -> T>(&self, func: F) -> T {
+        let start = Instant::now();
+        let out = func();
+        self.add_decode_time(start.elapsed());
+        out
+    }
+
+    /// The number of files the scan was given, including the pruned ones.
+    pub fn files_listed(&self) -> u64 {
+        self.files_listed.load(Ordering::Relaxed)
+    }
+
+    /// The number of files that were skipped without being opened, e.g. by hive partition
+    /// filters.
+    pub fn files_pruned(&self) -> u64 {
+        self.files_pruned.load(Ordering::Relaxed)
+    }
+
+    /// The number of row groups that were decoded.
+    pub fn row_groups_read(&self) -> u64 {
+        self.row_groups_read.load(Ordering::Relaxed)
+    }
+
+    /// The number of row groups that were skipped because of their min/max and null count
+    /// statistics.
+    pub fn row_groups_pruned_by_statistics(&self) -> u64 {
+        self.row_groups_pruned_by_statistics.load(Ordering::Relaxed)
+    }
+
+    /// The number of row groups that the statistics couldn't rule out, but a bloom filter did.
+    pub fn row_groups_pruned_by_bloom_filter(&self) -> u64 {
+        self.row_groups_pruned_by_bloom_filter
+            .load(Ordering::Relaxed)
+    }
+
+    /// The number of bytes of column data that were downloaded from cloud storage.
+    pub fn bytes_downloaded(&self) -> u64 {
+        self.bytes_downloaded.load(Ordering::Relaxed)
+    }
+
+    /// The time spent decoding column data. The times of row groups that are decoded in parallel
+    /// add up.
+    pub fn decode_time(&self) -> Duration {
+        Duration::from_nanos(self.decode_time_ns.load(Ordering::Relaxed))
+    }
+}
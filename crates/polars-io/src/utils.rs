@@ -177,6 +177,11 @@ pub static FLOAT_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^\s*[-+]?((\d*\.\d+)([eE][-+]?\d+)?|inf|NaN|(\d+)[eE][-+]?\d+|\d+\.)$").unwrap()
 });
 
+/// Like [`FLOAT_RE`], but with a comma as the decimal separator.
+pub static FLOAT_RE_DECIMAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*[-+]?((\d*,\d+)([eE][-+]?\d+)?|inf|NaN|(\d+)[eE][-+]?\d+|\d+,)$").unwrap()
+});
+
 pub static INTEGER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*-?(\d+)$").unwrap());
 
 pub static BOOLEAN_RE: Lazy<Regex> = Lazy::new(|| {
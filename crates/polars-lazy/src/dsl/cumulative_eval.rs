@@ -0,0 +1,226 @@
+//! Incremental implementations of common `cumulative_eval` expressions. Instead of evaluating the
+//! expression on every prefix of the input, which is `O(n^2)`, these update a running state.
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_ops::prelude::{cum_max, cum_min, cum_sum};
+#[cfg(feature = "rank")]
+use polars_utils::total_ord::TotalOrd;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone)]
+pub(super) enum CumulativeFastPath {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// `element().rank().last()`
+    #[cfg(feature = "rank")]
+    Rank(RankOptions),
+}
+
+fn is_element(expr: &Expr) -> bool {
+    matches!(expr, Expr::Column(name) if name.is_empty())
+}
+
+impl CumulativeFastPath {
+    /// Find an incremental implementation of `expr` evaluated on prefixes of a column of `dtype`.
+    pub(super) fn new(expr: &Expr, dtype: &DataType) -> Option<Self> {
+        if !dtype.is_numeric() {
+            return None;
+        }
+        let Expr::Agg(agg) = expr else {
+            return None;
+        };
+        match agg {
+            AggExpr::Sum(e) if is_element(e) => Some(Self::Sum),
+            AggExpr::Mean(e) if is_element(e) => Some(Self::Mean),
+            // Floats are excluded as `min`/`max` treat NaNs differently than `cum_min`/`cum_max`.
+            AggExpr::Min { input, .. } if is_element(input) && dtype.is_integer() => {
+                Some(Self::Min)
+            },
+            AggExpr::Max { input, .. } if is_element(input) && dtype.is_integer() => {
+                Some(Self::Max)
+            },
+            #[cfg(feature = "rank")]
+            AggExpr::Last(e) => match e.as_ref() {
+                Expr::Function {
+                    input,
+                    function: FunctionExpr::Rank { options, .. },
+                    ..
+                } if input.len() == 1
+                    && is_element(&input[0])
+                    && matches!(
+                        options.method,
+                        RankMethod::Min | RankMethod::Max | RankMethod::Average
+                    ) =>
+                {
+                    Some(Self::Rank(*options))
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Evaluate the expression on every prefix of `s`. Prefixes with fewer than `min_periods`
+    /// non-null values evaluate to null.
+    pub(super) fn evaluate(self, s: &Series, min_periods: usize) -> PolarsResult<Series> {
+        let out = match self {
+            Self::Sum => cum_sum(&s.fill_null(FillNullStrategy::Zero)?, false)?,
+            Self::Mean => cumulative_mean(s)?,
+            Self::Min => cum_min(s, false)?.fill_null(FillNullStrategy::Forward(None))?,
+            Self::Max => cum_max(s, false)?.fill_null(FillNullStrategy::Forward(None))?,
+            #[cfg(feature = "rank")]
+            Self::Rank(options) => {
+                let s = s.to_physical_repr();
+                with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    cumulative_rank(ca, options)
+                })
+            },
+        };
+
+        if min_periods == 0 {
+            return Ok(out);
+        }
+        let mut valid = 0;
+        let mask: BooleanChunked = s
+            .is_not_null()
+            .into_iter()
+            .map(|is_valid| {
+                valid += is_valid.unwrap_or(false) as usize;
+                Some(valid >= min_periods)
+            })
+            .collect();
+        out.zip_with(&mask, &Series::full_null("", out.len(), out.dtype()))
+    }
+}
+
+fn cumulative_mean(s: &Series) -> PolarsResult<Series> {
+    let s = s.cast(&DataType::Float64)?;
+    let mut sum = 0.0;
+    let mut count = 0;
+    let out: Float64Chunked = s
+        .f64()?
+        .into_iter()
+        .map(|opt_v| {
+            if let Some(v) = opt_v {
+                sum += v;
+                count += 1;
+            }
+            (count > 0).then(|| sum / count as f64)
+        })
+        .collect();
+    Ok(out.into_series())
+}
+
+#[cfg(feature = "rank")]
+fn cumulative_rank<T>(ca: &ChunkedArray<T>, options: RankOptions) -> Series
+where
+    T: PolarsNumericType,
+{
+    // Sorted non-null values seen so far.
+    let mut seen: Vec<T::Native> = Vec::with_capacity(ca.len());
+    // 1-based ranks of the first and last occurrence of the current value in its prefix.
+    let ranks = ca
+        .into_iter()
+        .map(|opt_v| {
+            opt_v.map(|v| {
+                let lower = seen.partition_point(|x| x.tot_lt(&v));
+                let upper = seen.partition_point(|x| x.tot_le(&v));
+                let n = seen.len();
+                seen.insert(upper, v);
+                if options.descending {
+                    (n - upper + 1, n - lower + 1)
+                } else {
+                    (lower + 1, upper + 1)
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    match options.method {
+        RankMethod::Min => ranks
+            .into_iter()
+            .map(|r| r.map(|(first, _)| first as IdxSize))
+            .collect::<IdxCa>()
+            .into_series(),
+        RankMethod::Max => ranks
+            .into_iter()
+            .map(|r| r.map(|(_, last)| last as IdxSize))
+            .collect::<IdxCa>()
+            .into_series(),
+        _ => ranks
+            .into_iter()
+            .map(|r| r.map(|(first, last)| (first + last) as f64 / 2.0))
+            .collect::<Float64Chunked>()
+            .into_series(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cumulative_eval_fast_path() -> PolarsResult<()> {
+        let s = Series::new("", &[Some(3), None, Some(1), Some(3), Some(2)]);
+
+        let sum = CumulativeFastPath::new(&col("").sum(), s.dtype()).unwrap();
+        let out = sum.evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(3), Some(4), Some(7), Some(9)]
+        );
+        let out = sum.evaluate(&s, 3)?;
+        assert_eq!(Vec::from(out.i32()?), &[None, None, None, Some(7), Some(9)]);
+
+        let min = CumulativeFastPath::new(&col("").min(), s.dtype()).unwrap();
+        let out = min.evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(3), Some(1), Some(1), Some(1)]
+        );
+
+        let mean = CumulativeFastPath::new(&col("").mean(), s.dtype()).unwrap();
+        let out = mean.evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(3.0), Some(3.0), Some(2.0), Some(7.0 / 3.0), Some(2.25)]
+        );
+
+        assert!(CumulativeFastPath::new(&col("").median(), s.dtype()).is_none());
+        assert!(CumulativeFastPath::new(&col("a").sum(), s.dtype()).is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rank")]
+    fn test_cumulative_eval_rank_fast_path() -> PolarsResult<()> {
+        let s = Series::new("", &[Some(3), None, Some(1), Some(3), Some(2)]);
+        let rank = |method, descending| {
+            let expr = col("")
+                .rank(RankOptions { method, descending }, None)
+                .last();
+            CumulativeFastPath::new(&expr, s.dtype()).unwrap()
+        };
+
+        let out = rank(RankMethod::Min, false).evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[Some(1), None, Some(1), Some(2), Some(2)]
+        );
+        let out = rank(RankMethod::Max, true).evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[Some(1), None, Some(2), Some(2), Some(3)]
+        );
+        let out = rank(RankMethod::Average, false).evaluate(&s, 1)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), None, Some(1.0), Some(2.5), Some(2.0)]
+        );
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ use polars_core::prelude::*;
 use rayon::prelude::*;
 
 use super::*;
+#[cfg(feature = "cumulative_eval")]
+use crate::dsl::cumulative_eval::CumulativeFastPath;
 use crate::physical_plan::planner::create_physical_expr;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -42,7 +44,8 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
     ///
     /// # Warning
     /// This can be really slow as it can have `O(n^2)` complexity. Don't use this for operations
-    /// that visit all elements.
+    /// that visit all elements. The exceptions are `sum`, `mean`, `min`, `max` and `rank().last()`
+    /// of the element on numeric data, which are computed incrementally.
     fn cumulative_eval(self, expr: Expr, min_periods: usize, parallel: bool) -> Expr {
         let this = self.into_expr();
         let expr2 = expr.clone();
@@ -53,6 +56,20 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
             // Ensure we get the new schema.
             let output_field = eval_field_to_dtype(s.field().as_ref(), &expr, false);
 
+            let finish_output = |mut out: Series| {
+                out.rename(&name);
+                if out.dtype() != output_field.data_type() {
+                    out.cast(output_field.data_type()).map(Some)
+                } else {
+                    Ok(Some(out))
+                }
+            };
+
+            #[cfg(feature = "cumulative_eval")]
+            if let Some(fast_path) = CumulativeFastPath::new(&expr, s.dtype()) {
+                return finish_output(fast_path.evaluate(&s, min_periods)?);
+            }
+
             let expr = expr.clone();
             let mut arena = Arena::with_capacity(10);
             let aexpr = to_aexpr(expr, &mut arena);
@@ -108,13 +125,7 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
                     })
                     .collect::<PolarsResult<Vec<_>>>()?
             };
-            let s = Series::new(&name, avs);
-
-            if s.dtype() != output_field.data_type() {
-                s.cast(output_field.data_type()).map(Some)
-            } else {
-                Ok(Some(s))
-            }
+            finish_output(Series::new(&name, avs))
         };
 
         this.apply(
@@ -3,6 +3,8 @@
 //! Function on multiple expressions.
 //!
 use polars_core::prelude::*;
+#[cfg(feature = "merge_sorted")]
+use polars_core::series::IsSorted;
 pub use polars_plan::dsl::functions::*;
 use rayon::prelude::*;
 
@@ -239,6 +241,33 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     )
 }
 
+/// Merge [`LazyFrame`]s that are all sorted ascending by the `key` column into a single
+/// [`LazyFrame`] that is sorted by `key`, without re-sorting the data.
+///
+/// The frames are merged pairwise in a balanced tree of [`LazyFrame::merge_sorted`]s. The
+/// inputs of the merges can be executed by the streaming engine.
+#[cfg(feature = "merge_sorted")]
+pub fn concat_sorted<L: AsRef<[LazyFrame]>>(inputs: L, key: &str) -> PolarsResult<LazyFrame> {
+    let mut lfs = inputs.as_ref().to_vec();
+    polars_ensure!(!lfs.is_empty(), NoData: "empty container given");
+    if lfs.len() == 1 {
+        let lf = lfs.pop().unwrap();
+        return Ok(lf.with_column(col(key).set_sorted_flag(IsSorted::Ascending)));
+    }
+    while lfs.len() > 1 {
+        let mut merged = Vec::with_capacity((lfs.len() + 1) / 2);
+        let mut iter = lfs.into_iter();
+        while let Some(left) = iter.next() {
+            match iter.next() {
+                Some(right) => merged.push(left.merge_sorted(right, key)?),
+                None => merged.push(left),
+            }
+        }
+        lfs = merged;
+    }
+    Ok(lfs.pop().unwrap())
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
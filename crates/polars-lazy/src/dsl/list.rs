@@ -113,6 +113,24 @@ fn run_on_group_by_engine(
     lst: &ListChunked,
     expr: &Expr,
 ) -> PolarsResult<Option<Series>> {
+    // Null lists are not evaluated, but inserted back into the output.
+    if lst.null_count() > 0 {
+        let valid = lst.is_not_null();
+        let out = run_on_group_by_engine(name, &lst.filter(&valid)?, expr)?.unwrap();
+
+        let mut i: IdxSize = 0;
+        let idx: IdxCa = valid
+            .into_no_null_iter()
+            .map(|valid| {
+                valid.then(|| {
+                    i += 1;
+                    i - 1
+                })
+            })
+            .collect_trusted();
+        return out.take(&idx).map(Some);
+    }
+
     let lst = lst.rechunk();
     let arr = lst.downcast_iter().next().unwrap();
     let groups = offsets_to_groups(arr.offsets()).unwrap();
@@ -185,7 +203,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
                 expr.into_iter().any(|e| matches!(e, Expr::AnonymousFunction { options, .. } if options.fmt_str == MAP_LIST_NAME))
             };
 
-            if fits_idx_size && s.null_count() == 0 && !is_user_apply() {
+            if fits_idx_size && !is_user_apply() {
                 run_on_group_by_engine(s.name(), &lst, &expr)
             } else {
                 run_per_sublist(s, &lst, &expr, parallel, output_field)
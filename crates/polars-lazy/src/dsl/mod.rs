@@ -32,6 +32,8 @@
 //! These kinds of invalid operations will only yield an error at runtime, when
 //! [`collect`](crate::frame::LazyFrame::collect) is called on the [`LazyFrame`].
 
+#[cfg(feature = "cumulative_eval")]
+mod cumulative_eval;
 #[cfg(any(feature = "cumulative_eval", feature = "list_eval"))]
 mod eval;
 pub mod functions;
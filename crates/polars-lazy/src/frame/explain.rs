@@ -0,0 +1,408 @@
+use std::fmt::Write;
+
+use polars_core::prelude::*;
+use serde_json::{json, Value};
+
+use crate::prelude::*;
+
+// The fraction of the rows that is assumed to pass a filter.
+const FILTER_SELECTIVITY: f64 = 0.9;
+
+/// The output format of [`LazyFrame::describe_annotated_plan`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ExplainFormat {
+    /// An indented tree with a line per node.
+    #[default]
+    Text,
+    /// A JSON object per node, the inputs of a node are nested in `"inputs"`.
+    Json,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct RowEstimate {
+    rows: usize,
+    exact: bool,
+}
+
+impl RowEstimate {
+    fn exact(rows: usize) -> Self {
+        Self { rows, exact: true }
+    }
+
+    fn estimated(rows: usize) -> Self {
+        Self { rows, exact: false }
+    }
+
+    fn filtered(self) -> Self {
+        Self::estimated((self.rows as f64 * FILTER_SELECTIVITY) as usize)
+    }
+
+    fn inexact(self) -> Self {
+        Self::estimated(self.rows)
+    }
+
+    fn slice(self, offset: i64, len: usize) -> Self {
+        let rows = if offset >= 0 {
+            self.rows.saturating_sub(offset as usize)
+        } else {
+            std::cmp::min(self.rows, offset.unsigned_abs() as usize)
+        };
+        Self {
+            rows: std::cmp::min(rows, len),
+            exact: self.exact,
+        }
+    }
+}
+
+/// The algorithm the in-memory engine uses for a join.
+struct JoinStrategy {
+    algorithm: &'static str,
+    // the side of which the hash table is built
+    build_side: Option<JoinBuildSide>,
+}
+
+impl JoinStrategy {
+    fn new(
+        options: &JoinOptions,
+        left: Option<RowEstimate>,
+        right: Option<RowEstimate>,
+    ) -> JoinStrategy {
+        let hints = &options.args.hints;
+        let (algorithm, build_side) = match &options.args.how {
+            JoinType::Cross => ("cross", None),
+            #[cfg(feature = "asof_join")]
+            JoinType::AsOf(_) => ("asof", None),
+            JoinType::Inner | JoinType::Left if hints.force_sort_merge => ("sort-merge", None),
+            JoinType::Left => ("hash", Some(JoinBuildSide::Right)),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => ("hash", Some(JoinBuildSide::Right)),
+            JoinType::Inner | JoinType::Outer { .. } => {
+                // without a hint the hash table is built on the smaller relation
+                let build_side = hints.build_side().or_else(|| {
+                    let (left, right) = (left?, right?);
+                    Some(if left.rows > right.rows {
+                        JoinBuildSide::Right
+                    } else {
+                        JoinBuildSide::Left
+                    })
+                });
+                ("hash", build_side)
+            },
+        };
+        JoinStrategy {
+            algorithm,
+            build_side,
+        }
+    }
+
+    fn build_side_name(&self) -> Option<&'static str> {
+        self.build_side.map(|side| match side {
+            JoinBuildSide::Left => "left",
+            JoinBuildSide::Right => "right",
+        })
+    }
+}
+
+struct AnnotatedNode {
+    name: &'static str,
+    description: String,
+    rows: Option<RowEstimate>,
+    streamable: bool,
+    join_strategy: Option<JoinStrategy>,
+    inputs: Vec<AnnotatedNode>,
+}
+
+fn exprs_to_vec<'a>(
+    nodes: impl IntoIterator<Item = &'a Node>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<Expr> {
+    nodes
+        .into_iter()
+        .map(|node| node_to_expr(*node, expr_arena))
+        .collect()
+}
+
+fn describe(lp: &ALogicalPlan, expr_arena: &Arena<AExpr>) -> String {
+    use ALogicalPlan::*;
+    let exprs = |nodes: &[Node]| exprs_to_vec(nodes, expr_arena);
+    match lp {
+        #[cfg(feature = "python")]
+        PythonScan { .. } => "PYTHON SCAN".to_string(),
+        Scan {
+            paths, predicate, ..
+        } => {
+            let mut out = match paths.len() {
+                1 => format!("{} SCAN {}", lp.name().to_uppercase(), paths[0].display()),
+                n => format!("{} SCAN {n} files", lp.name().to_uppercase()),
+            };
+            if let Some(predicate) = predicate {
+                write!(
+                    out,
+                    "; SELECTION: {:?}",
+                    node_to_expr(*predicate, expr_arena)
+                )
+                .unwrap();
+            }
+            out
+        },
+        DataFrameScan {
+            schema,
+            projection,
+            selection,
+            ..
+        } => {
+            let n_columns = projection
+                .as_ref()
+                .map(|columns| columns.len().to_string())
+                .unwrap_or_else(|| "*".to_string());
+            let mut out = format!(
+                "DF {:?}; PROJECT {n_columns}/{} COLUMNS",
+                schema.iter_names().take(4).collect::<Vec<_>>(),
+                schema.len()
+            );
+            if let Some(selection) = selection {
+                write!(
+                    out,
+                    "; SELECTION: {:?}",
+                    node_to_expr(*selection, expr_arena)
+                )
+                .unwrap();
+            }
+            out
+        },
+        Selection { predicate, .. } => {
+            format!("FILTER {:?}", node_to_expr(*predicate, expr_arena))
+        },
+        Projection { expr, .. } => format!("SELECT {:?}", exprs_to_vec(expr.iter(), expr_arena)),
+        HStack { exprs: e, .. } => {
+            format!("WITH_COLUMNS {:?}", exprs_to_vec(e.iter(), expr_arena))
+        },
+        Sort { by_column, .. } => format!("SORT BY {:?}", exprs(by_column)),
+        Aggregate { keys, aggs, .. } => {
+            format!("AGGREGATE {:?} BY {:?}", exprs(aggs), exprs(keys))
+        },
+        Join {
+            left_on,
+            right_on,
+            options,
+            ..
+        } => format!(
+            "{} JOIN LEFT ON {:?} RIGHT ON {:?}",
+            options.args.how,
+            exprs(left_on),
+            exprs(right_on)
+        ),
+        Distinct { options, .. } => format!("UNIQUE BY {:?}", options.subset),
+        Slice { offset, len, .. } => format!("SLICE[offset: {offset}, len: {len}]"),
+        MapFunction { function, .. } => format!("{function}").trim_end().to_string(),
+        Union { options, .. } => match options.slice {
+            Some(slice) => format!("SLICED UNION: {slice:?}"),
+            None => "UNION".to_string(),
+        },
+        HConcat { .. } => "HCONCAT".to_string(),
+        Cache { id, count, .. } => format!("CACHE[id: {id:x}, count: {count}]"),
+        ExtContext { .. } => "EXTERNAL_CONTEXT".to_string(),
+        Sink { .. } => lp.name().to_uppercase(),
+    }
+}
+
+/// Estimate the number of rows a node produces, given the estimates of its inputs.
+fn estimate_rows(lp: &ALogicalPlan, inputs: &[Option<RowEstimate>]) -> Option<RowEstimate> {
+    use ALogicalPlan::*;
+    let input = || inputs.first().copied().flatten();
+    match lp {
+        #[cfg(feature = "python")]
+        PythonScan { .. } => None,
+        Scan {
+            file_info,
+            predicate,
+            file_options,
+            ..
+        } => {
+            let (known_size, estimated_size) = file_info.row_estimation;
+            let mut rows = match known_size {
+                Some(rows) => RowEstimate::exact(rows),
+                None if estimated_size > 0 && estimated_size < usize::MAX => {
+                    RowEstimate::estimated(estimated_size)
+                },
+                None => return None,
+            };
+            if let Some(n_rows) = file_options.n_rows {
+                rows = rows.slice(0, n_rows);
+            }
+            if predicate.is_some() {
+                rows = rows.filtered();
+            }
+            Some(rows)
+        },
+        DataFrameScan { df, selection, .. } => {
+            let rows = RowEstimate::exact(df.height());
+            Some(if selection.is_some() {
+                rows.filtered()
+            } else {
+                rows
+            })
+        },
+        Selection { .. } => input().map(RowEstimate::filtered),
+        Slice { offset, len, .. } => input().map(|rows| rows.slice(*offset, *len as usize)),
+        Sort { args, .. } => match args.slice {
+            Some((offset, len)) => input().map(|rows| rows.slice(offset, len)),
+            None => input(),
+        },
+        Projection { .. } | HStack { .. } | Cache { .. } | ExtContext { .. } | Sink { .. } => {
+            input()
+        },
+        MapFunction { function, .. } => match function {
+            FunctionNode::DropNulls { .. } => input().map(RowEstimate::inexact),
+            function if function.expands_rows() => None,
+            FunctionNode::Opaque { .. } => None,
+            #[cfg(feature = "python")]
+            FunctionNode::OpaquePython { .. } => None,
+            _ => input(),
+        },
+        Aggregate { keys, .. } if keys.is_empty() => Some(RowEstimate::exact(1)),
+        Aggregate { .. } => None,
+        Distinct { .. } => input().map(RowEstimate::inexact),
+        Union { options, .. } => {
+            let rows = inputs.iter().try_fold(RowEstimate::exact(0), |acc, rows| {
+                let rows = (*rows)?;
+                Some(RowEstimate {
+                    rows: acc.rows.saturating_add(rows.rows),
+                    exact: acc.exact && rows.exact,
+                })
+            })?;
+            Some(match options.slice {
+                Some((offset, len)) => rows.slice(offset, len),
+                None => rows,
+            })
+        },
+        HConcat { .. } => {
+            let rows = inputs.iter().copied().collect::<Option<Vec<_>>>()?;
+            let max = rows.iter().map(|rows| rows.rows).max().unwrap_or(0);
+            Some(RowEstimate {
+                rows: max,
+                exact: rows.iter().all(|rows| rows.exact),
+            })
+        },
+        Join { options, .. } => {
+            let left = inputs.first().copied().flatten();
+            let right = inputs.get(1).copied().flatten();
+            let rows = match &options.args.how {
+                JoinType::Cross => {
+                    let (left, right) = (left?, right?);
+                    RowEstimate {
+                        rows: left.rows.saturating_mul(right.rows),
+                        exact: left.exact && right.exact,
+                    }
+                },
+                JoinType::Outer { .. } => {
+                    let (left, right) = (left?, right?);
+                    RowEstimate::estimated(left.rows.saturating_add(right.rows))
+                },
+                JoinType::Inner => {
+                    let (left, right) = (left?, right?);
+                    RowEstimate::estimated(std::cmp::max(left.rows, right.rows))
+                },
+                // the left rows, possibly expanded or filtered by the right table
+                _ => left?.inexact(),
+            };
+            Some(match options.args.slice {
+                Some((offset, len)) => rows.slice(offset, len),
+                None => rows,
+            })
+        },
+    }
+}
+
+fn annotate(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> AnnotatedNode {
+    let lp = lp_arena.get(node);
+    let inputs = lp
+        .get_inputs()
+        .into_iter()
+        .map(|input| annotate(input, lp_arena, expr_arena))
+        .collect::<Vec<_>>();
+    let input_rows = inputs.iter().map(|input| input.rows).collect::<Vec<_>>();
+
+    let join_strategy = match lp {
+        ALogicalPlan::Join { options, .. } => {
+            Some(JoinStrategy::new(options, input_rows[0], input_rows[1]))
+        },
+        _ => None,
+    };
+    #[cfg(feature = "streaming")]
+    let streamable =
+        crate::physical_plan::streaming::is_streamable_node(node, lp_arena, expr_arena);
+    #[cfg(not(feature = "streaming"))]
+    let streamable = false;
+
+    AnnotatedNode {
+        name: lp.name(),
+        description: describe(lp, expr_arena),
+        rows: estimate_rows(lp, &input_rows),
+        streamable,
+        join_strategy,
+        inputs,
+    }
+}
+
+impl AnnotatedNode {
+    fn write_text(&self, out: &mut String, indent: usize) {
+        let rows = match self.rows {
+            Some(RowEstimate { rows, exact: true }) => format!("{rows}"),
+            Some(RowEstimate { rows, exact: false }) => format!("~{rows}"),
+            None => "?".to_string(),
+        };
+        write!(out, "{:indent$}{}  [rows: {rows}", "", self.description).unwrap();
+        if let Some(strategy) = &self.join_strategy {
+            write!(out, "; strategy: {}", strategy.algorithm).unwrap();
+            if let Some(side) = strategy.build_side_name() {
+                write!(out, ", build: {side}").unwrap();
+            }
+        }
+        let streaming = if self.streamable { "yes" } else { "no" };
+        writeln!(out, "; streaming: {streaming}]").unwrap();
+        for input in &self.inputs {
+            input.write_text(out, indent + 2);
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let join_strategy = self.join_strategy.as_ref().map(|strategy| {
+            json!({
+                "algorithm": strategy.algorithm,
+                "build_side": strategy.build_side_name(),
+            })
+        });
+        json!({
+            "node": self.name,
+            "description": self.description,
+            "estimated_rows": self.rows.map(|rows| rows.rows),
+            "exact_rows": self.rows.map(|rows| rows.exact).unwrap_or(false),
+            "streamable": self.streamable,
+            "join_strategy": join_strategy,
+            "inputs": self.inputs.iter().map(|input| input.to_json()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+pub(super) fn describe_annotated(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    format: ExplainFormat,
+) -> PolarsResult<String> {
+    let annotated = annotate(root, lp_arena, expr_arena);
+    match format {
+        ExplainFormat::Text => {
+            let mut out = String::new();
+            annotated.write_text(&mut out, 0);
+            Ok(out)
+        },
+        ExplainFormat::Json => serde_json::to_string_pretty(&annotated.to_json())
+            .map_err(|err| polars_err!(ComputeError: "could not serialize the plan: {}", err)),
+    }
+}
@@ -5,8 +5,13 @@ mod python;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod explain;
+#[cfg(feature = "partition_by")]
+mod partition;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(all(feature = "serde", feature = "ipc"))]
+mod result_cache;
 
 use std::borrow::Cow;
 #[cfg(any(
@@ -22,9 +27,16 @@ pub use anonymous_scan::*;
 use arrow::legacy::prelude::QuantileInterpolOptions;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
+pub use explain::ExplainFormat;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub use flight::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -34,7 +46,8 @@ pub use parquet::*;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
-pub use polars_plan::frame::{AllowedOptimizations, OptState};
+use polars_plan::constants::HAVING_NAME;
+pub use polars_plan::frame::{AllowedOptimizations, OptState, StreamingOptions};
 use polars_plan::global::FETCH_ROWS;
 #[cfg(any(
     feature = "ipc",
@@ -117,6 +130,40 @@ impl LazyFrame {
         }
     }
 
+    /// Serialize the logical plan, including the expressions and the options of the scans,
+    /// to bytes. These can be sent to another process and turned back into a [`LazyFrame`]
+    /// with [`LazyFrame::deserialize`].
+    ///
+    /// Returns `Err` if the plan contains a function that cannot be serialized, e.g. a Rust
+    /// closure passed to [`Expr::map`]. The optimization settings are not serialized.
+    #[cfg(feature = "serde")]
+    pub fn serialize(&self) -> PolarsResult<Vec<u8>> {
+        let mut bytes = vec![];
+        self.serialize_into(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Serialize the logical plan into `writer`, see [`LazyFrame::serialize`].
+    #[cfg(feature = "serde")]
+    pub fn serialize_into<W: std::io::Write>(&self, writer: W) -> PolarsResult<()> {
+        ciborium::ser::into_writer(&self.logical_plan, writer)
+            .map_err(|e| polars_err!(ComputeError: "cannot serialize the logical plan: {}", e))
+    }
+
+    /// Deserialize a logical plan that was serialized with [`LazyFrame::serialize`].
+    #[cfg(feature = "serde")]
+    pub fn deserialize(bytes: &[u8]) -> PolarsResult<LazyFrame> {
+        Self::deserialize_from(bytes)
+    }
+
+    /// Deserialize a logical plan from `reader`, see [`LazyFrame::deserialize`].
+    #[cfg(feature = "serde")]
+    pub fn deserialize_from<R: std::io::Read>(reader: R) -> PolarsResult<LazyFrame> {
+        let lp: LogicalPlan = ciborium::de::from_reader(reader)
+            .map_err(|e| polars_err!(ComputeError: "cannot deserialize the logical plan: {}", e))?;
+        Ok(LazyFrame::from(lp))
+    }
+
     /// Get current optimizations.
     pub fn get_current_optimizations(&self) -> OptState {
         self.opt_state
@@ -145,6 +192,8 @@ impl LazyFrame {
             streaming: false,
             eager: false,
             fast_projection: false,
+            join_reorder: false,
+            streaming_options: Default::default(),
         })
     }
 
@@ -186,6 +235,12 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle reordering of inner joins based on the estimated sizes of the joined relations.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Toggle slice pushdown optimization.
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.slice_pushdown = toggle;
@@ -198,6 +253,17 @@ impl LazyFrame {
         self
     }
 
+    /// Run this query in the streaming engine with an explicit memory budget.
+    ///
+    /// Chunk sizes, hash tables and sort buffers are sized against `options.memory_limit`
+    /// instead of the free memory of the system. If `options.maintain_order` is set, the rows
+    /// are returned in the same order as the in-memory engine would return them.
+    pub fn with_streaming_options(mut self, options: StreamingOptions) -> Self {
+        self.opt_state.streaming = true;
+        self.opt_state.streaming_options = options;
+        self
+    }
+
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
@@ -224,6 +290,21 @@ impl LazyFrame {
         Ok(logical_plan.describe())
     }
 
+    /// Return a String describing the optimized logical plan, where every node is annotated
+    /// with its estimated number of rows, whether the streaming engine can execute it and,
+    /// for joins, the algorithm and build side the in-memory engine uses.
+    ///
+    /// The plan is annotated before it is split into streaming pipelines. Row counts that are
+    /// not known from the data or the file metadata are estimated and marked with a `~`.
+    pub fn describe_annotated_plan(&self, format: ExplainFormat) -> PolarsResult<String> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(64);
+        let mut lf = self.clone();
+        lf.opt_state.streaming = false;
+        let lp_top = lf.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true)?;
+        explain::describe_annotated(lp_top, &lp_arena, &expr_arena, format)
+    }
+
     /// Return a String describing the logical plan.
     ///
     /// If `optimized` is `true`, explains the optimized plan. If `optimized` is `false,
@@ -587,7 +668,15 @@ impl LazyFrame {
         if streaming {
             #[cfg(feature = "streaming")]
             {
-                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt, true)?;
+                insert_streaming_nodes(
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                    scratch,
+                    _fmt,
+                    true,
+                    opt_state.streaming_options,
+                )?;
             }
             #[cfg(not(feature = "streaming"))]
             {
@@ -640,7 +729,11 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let physical_plan = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("create_physical_plan").entered();
+            create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?
+        };
 
         let state = ExecutionState::with_finger_prints(finger_prints);
         Ok((state, physical_plan, no_file_sink))
@@ -663,7 +756,24 @@ impl LazyFrame {
     ///       .collect()
     /// }
     /// ```
+    ///
+    /// If the `POLARS_RESULT_CACHE_DIR` environment variable is set, the result is cached in that
+    /// directory. A later query with the same optimized plan returns the cached result if the
+    /// files it scans didn't change. Queries on in-memory data, or with user defined functions
+    /// or random functions without a seed, are not cached.
     pub fn collect(self) -> PolarsResult<DataFrame> {
+        #[cfg(all(feature = "serde", feature = "ipc"))]
+        if let Some(cache_dir) = result_cache::result_cache_dir() {
+            return result_cache::collect_cached(self, &cache_dir);
+        }
+        self.collect_uncached()
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "collect", level = "info", skip_all)
+    )]
+    fn collect_uncached(self) -> PolarsResult<DataFrame> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
         let out = physical_plan.execute(&mut state);
         #[cfg(debug_assertions)]
@@ -689,6 +799,27 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Profile a LazyFrame and collect statistics of its scans.
+    ///
+    /// Like [`LazyFrame::profile`], but the tuple also contains a DataFrame with a row per
+    /// executed parquet or IPC scan, which tells how many files the scan was given
+    /// (`files_listed`) and how many of them were pruned by hive partition filters
+    /// (`files_pruned`). Parquet scans also report the row groups they decoded, the row groups
+    /// they skipped by their statistics or bloom filters, the bytes they downloaded from cloud
+    /// storage and the time they spent decoding. Use these to verify that predicates are pushed
+    /// down effectively.
+    ///
+    /// The `node` column matches the name of the scan in the timings. The unit of the decode
+    /// time is microseconds. Scans that run in the streaming engine aren't reported.
+    pub fn profile_with_scan_statistics(self) -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        state.time_nodes();
+        let out = physical_plan.execute(&mut state)?;
+        let scan_stats_df = state.scan_statistics();
+        let timer_df = state.finish_timer()?;
+        Ok((out, timer_df, scan_stats_df))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -931,6 +1062,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                having: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -943,6 +1075,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                having: None,
             }
         }
     }
@@ -979,6 +1112,7 @@ impl LazyFrame {
             opt_state,
             keys: by.as_ref().to_vec(),
             maintain_order: true,
+            having: None,
             dynamic_options: None,
             rolling_options: Some(options),
         }
@@ -1024,6 +1158,7 @@ impl LazyFrame {
             opt_state,
             keys: by.as_ref().to_vec(),
             maintain_order: true,
+            having: None,
             dynamic_options: Some(options),
             rolling_options: None,
         }
@@ -1045,6 +1180,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                having: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1057,6 +1193,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                having: None,
             }
         }
     }
@@ -1235,7 +1372,8 @@ impl LazyFrame {
             .right_on(right_on)
             .how(args.how)
             .validate(args.validation)
-            .join_nulls(args.join_nulls);
+            .join_nulls(args.join_nulls)
+            .hints(args.hints);
 
         if let Some(suffix) = args.suffix {
             builder = builder.suffix(suffix);
@@ -1468,13 +1606,24 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, columns: E) -> LazyFrame {
+        self.explode_with(columns, ExplodeMismatch::Error)
+    }
+
+    /// Explode multiple columns in lockstep, handling lists of different lengths in a row
+    /// according to `mismatch`.
+    /// [See eager explode_with](polars_core::frame::DataFrame::explode_with).
+    pub fn explode_with<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        columns: E,
+        mismatch: ExplodeMismatch,
+    ) -> LazyFrame {
         let columns = columns
             .as_ref()
             .iter()
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
-        let lp = self.get_plan_builder().explode(columns).build();
+        let lp = self.get_plan_builder().explode(columns, mismatch).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
@@ -1528,8 +1677,8 @@ impl LazyFrame {
 
     /// Drop rows containing None.
     ///
-    /// `subset` is an optional `Vec` of column names to consider for nulls; if None, all
-    /// columns are considered.
+    /// `subset` is an optional `Vec` of expressions to consider for nulls; a row is dropped if any
+    /// of them evaluates to null. If None, all columns are considered.
     pub fn drop_nulls(self, subset: Option<Vec<Expr>>) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().drop_nulls(subset).build();
@@ -1727,6 +1876,75 @@ impl LazyFrame {
             column: Arc::from(key),
         }))
     }
+
+    /// Insert a row for every time step of `every` that is missing between the first and the
+    /// last time of `time_column` in a group of `by`. The inserted rows have the group keys, the
+    /// other columns are null.
+    ///
+    /// The output is sorted by `by` and then by `time_column`.
+    #[cfg(feature = "fill_gaps")]
+    pub fn fill_gaps<E: AsRef<[S]>, S: AsRef<str>>(
+        self,
+        time_column: &str,
+        every: &str,
+        by: E,
+    ) -> LazyFrame {
+        let by = by.as_ref().iter().map(|s| s.as_ref().into()).collect();
+        self.fill_gaps_with(FillGapsArgs::new(
+            time_column,
+            polars_time::Duration::parse(every),
+            by,
+        ))
+    }
+
+    /// Fill the gaps in time like [`fill_gaps`](LazyFrame::fill_gaps), with the fill values
+    /// and options of `args`.
+    #[cfg(feature = "fill_gaps")]
+    pub fn fill_gaps_with(self, args: FillGapsArgs) -> LazyFrame {
+        self.map_private(FunctionNode::FillGaps {
+            args: Arc::new(args),
+        })
+    }
+
+    /// Upsample `time_column` to a row per time step of `every` in every group of `by`.
+    ///
+    /// The missing time steps are inserted like in [`fill_gaps`](LazyFrame::fill_gaps), so no
+    /// join is needed. The other columns of the inserted rows are null, unless `fill_strategy`
+    /// is given: then the nulls of these columns are filled with it within every group.
+    #[cfg(feature = "fill_gaps")]
+    pub fn upsample<E: AsRef<[S]>, S: AsRef<str>>(
+        self,
+        time_column: &str,
+        every: &str,
+        by: E,
+        fill_strategy: Option<FillNullStrategy>,
+    ) -> LazyFrame {
+        let by = by
+            .as_ref()
+            .iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let lf = self.fill_gaps(time_column, every, &by);
+        let Some(strategy) = fill_strategy else {
+            return lf;
+        };
+
+        let mut exclude = by.clone();
+        exclude.push(time_column.to_string());
+        let values = all().exclude(exclude);
+        let mut filled = match strategy {
+            FillNullStrategy::Forward(limit) => values.forward_fill(limit),
+            FillNullStrategy::Backward(limit) => values.backward_fill(limit),
+            strategy => values.apply(
+                move |s| s.fill_null(strategy).map(Some),
+                GetOutput::same_type(),
+            ),
+        };
+        if !by.is_empty() {
+            filled = filled.over(by.iter().map(|name| col(name)).collect::<Vec<_>>());
+        }
+        lf.with_columns([filled])
+    }
 }
 
 /// Utility struct for lazy group_by operation.
@@ -1736,6 +1954,7 @@ pub struct LazyGroupBy {
     opt_state: OptState,
     keys: Vec<Expr>,
     maintain_order: bool,
+    having: Option<Expr>,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
@@ -1775,6 +1994,11 @@ impl LazyGroupBy {
     /// }
     /// ```
     pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+        let mut aggs = aggs.as_ref().to_vec();
+        if let Some(predicate) = &self.having {
+            aggs.push(predicate.clone().alias(HAVING_NAME));
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         let lp = LogicalPlanBuilder::from(self.logical_plan)
             .group_by(
@@ -1791,7 +2015,24 @@ impl LazyGroupBy {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
             .group_by(self.keys, aggs, None, self.maintain_order)
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        let lf = LazyFrame::from_logical_plan(lp, self.opt_state);
+        if self.having.is_some() {
+            lf.filter(col(HAVING_NAME)).drop_columns([HAVING_NAME])
+        } else {
+            lf
+        }
+    }
+
+    /// Only keep the groups for which `predicate` is true, like a SQL `HAVING` clause.
+    ///
+    /// The predicate is an aggregation, e.g. `len().gt(lit(10))`. It is evaluated before the
+    /// other aggregations, which are only computed for the groups that are kept.
+    pub fn having(mut self, predicate: Expr) -> Self {
+        self.having = Some(match self.having.take() {
+            Some(having) => having.and(predicate),
+            None => predicate,
+        });
+        self
     }
 
     /// Return first n rows of each group
@@ -1820,9 +2061,50 @@ impl LazyGroupBy {
 
     /// Apply a function over the groups as a new DataFrame.
     ///
+    /// The function is called for one group at a time, see [`LazyGroupBy::map_groups`] to
+    /// call it on the groups in parallel.
+    ///
     /// **It is not recommended that you use this as materializing the DataFrame is very
     /// expensive.**
     pub fn apply<F>(self, f: F, schema: SchemaRef) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        self.map_groups_impl(f, schema, false)
+    }
+
+    /// Apply a function on the [`DataFrame`] of every group and concatenate the results.
+    ///
+    /// The groups are mapped in parallel, in the order of the groups. The function must return
+    /// frames with the declared `schema`, which is the schema of the query from here on. The
+    /// frames passed to the function hold all columns, including the keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Keep the two largest values per group.
+    /// fn example(lf: LazyFrame) -> LazyFrame {
+    ///     let schema = Arc::new(Schema::from_iter([
+    ///         Field::new("group", DataType::String),
+    ///         Field::new("value", DataType::Int64),
+    ///     ]));
+    ///     lf.group_by([col("group")]).map_groups(
+    ///         |df| df.sort(["value"], true, false).map(|df| df.head(Some(2))),
+    ///         schema,
+    ///     )
+    /// }
+    /// ```
+    pub fn map_groups<F>(self, f: F, schema: SchemaRef) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        self.map_groups_impl(f, schema, true)
+    }
+
+    fn map_groups_impl<F>(self, f: F, schema: SchemaRef, parallel_apply: bool) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
@@ -1831,10 +2113,16 @@ impl LazyGroupBy {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
             slice: None,
+            having: None,
+            parallel_apply,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            slice: None,
+            having: None,
+            parallel_apply,
+        };
 
         let lp = LogicalPlan::Aggregate {
             input: Box::new(self.logical_plan),
@@ -1861,6 +2149,7 @@ pub struct JoinBuilder {
     suffix: Option<String>,
     validation: JoinValidation,
     join_nulls: bool,
+    hints: JoinHints,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1876,6 +2165,7 @@ impl JoinBuilder {
             join_nulls: false,
             suffix: None,
             validation: Default::default(),
+            hints: Default::default(),
         }
     }
 
@@ -1940,6 +2230,37 @@ impl JoinBuilder {
         self
     }
 
+    /// Hints for the join algorithm, see [`JoinHints`]. The hints are shown in the
+    /// explained plan.
+    pub fn hints(mut self, hints: JoinHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Build the hash table on the given side, instead of on the smaller relation.
+    pub fn build_side(mut self, side: JoinBuildSide) -> Self {
+        self.hints.build_side = Some(side);
+        self
+    }
+
+    /// Materialize the right table and share it with all threads that probe it.
+    pub fn force_broadcast(mut self, force: bool) -> Self {
+        self.hints.force_broadcast = force;
+        self
+    }
+
+    /// Use a sort-merge join, also if the join keys need to be sorted first.
+    pub fn force_sort_merge(mut self, force: bool) -> Self {
+        self.hints.force_sort_merge = force;
+        self
+    }
+
+    /// Don't run this join in the streaming engine.
+    pub fn disable_streaming(mut self, disable: bool) -> Self {
+        self.hints.disable_streaming = disable;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"` if this method is never called.
     pub fn suffix<S: AsRef<str>>(mut self, suffix: S) -> Self {
@@ -1961,6 +2282,7 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             join_nulls: self.join_nulls,
+            hints: self.hints,
         };
 
         let lp = self
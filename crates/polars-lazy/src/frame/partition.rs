@@ -0,0 +1,83 @@
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Split the query into a [`LazyFrame`] per unique combination of the `by` columns. The
+    /// partitions are returned together with their key values, in the order the keys first
+    /// appear.
+    ///
+    /// Only the key columns are collected to find the partitions. Every partition filters the
+    /// original query on its key and is only computed when it is collected itself.
+    pub fn partition_by<I, S>(self, by: I) -> PolarsResult<Vec<(Vec<AnyValue<'static>>, LazyFrame)>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let by = by
+            .into_iter()
+            .map(|name| col(name.as_ref()))
+            .collect::<Vec<_>>();
+        polars_ensure!(!by.is_empty(), ComputeError: "cannot partition by zero columns");
+        let keys = self
+            .clone()
+            .select(by)
+            .unique_stable(None, UniqueKeepStrategy::Any)
+            .collect()?;
+
+        (0..keys.height())
+            .map(|idx| {
+                let mut key = Vec::with_capacity(keys.width());
+                let mut predicate: Option<Expr> = None;
+                for s in keys.get_columns() {
+                    let value = s.get(idx)?.into_static()?;
+                    let is_key = col(s.name())
+                        .eq_missing(Expr::Literal(LiteralValue::try_from(value.clone())?));
+                    predicate = Some(match predicate {
+                        None => is_key,
+                        Some(predicate) => predicate.and(is_key),
+                    });
+                    key.push(value);
+                }
+                Ok((key, self.clone().filter(predicate.unwrap())))
+            })
+            .collect()
+    }
+
+    /// Stream a query result into a Hive partitioned directory, with a file per unique
+    /// combination of the `by` columns at `{path}/{key}={value}/data-0000.{ext}`. This is the
+    /// layout of the eager [`polars_io::partition::PartitionedWriter`].
+    ///
+    /// The partitions are written while the query runs, so the result doesn't have to fit into
+    /// memory. This method will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv",
+        feature = "json"
+    ))]
+    pub fn sink_partitioned(
+        self,
+        path: PathBuf,
+        by: Vec<String>,
+        file_type: FileType,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partitioned {
+                path: Arc::new(path),
+                file_type,
+                by,
+            },
+            "collect().partition_by()",
+        )
+    }
+}
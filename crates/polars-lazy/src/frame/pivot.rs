@@ -5,6 +5,9 @@
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
 //!
+//! If the values of the pivoted column are known upfront, so is the schema, and
+//! [`LazyFrame::pivot`] plans the pivot as a group-by instead.
+//!
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
@@ -98,3 +101,100 @@ where
         separator,
     )
 }
+
+impl LazyFrame {
+    /// Pivot this [`LazyFrame`], given the values `on_values` that the column `on` can take.
+    ///
+    /// As the output columns are known upfront, the pivot is planned as a group-by on the `index`
+    /// columns with an aggregation of every `values` column per pivoted value, so it is optimized
+    /// like any other group-by. Rows whose `on` value isn't in `on_values` are ignored.
+    ///
+    /// `agg_expr` aggregates the values like in [`pivot`]; without it the first value is taken.
+    /// The output columns are named like in [`pivot`], unless `struct_output` is set: then every
+    /// pivoted value gets a single struct column with a field per `values` column.
+    #[allow(clippy::too_many_arguments)]
+    pub fn pivot(
+        self,
+        values: &[String],
+        index: &[String],
+        on: &str,
+        on_values: &Series,
+        agg_expr: Option<Expr>,
+        sort_columns: bool,
+        separator: Option<&str>,
+        struct_output: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let sep = separator.unwrap_or("_");
+        polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
+        polars_ensure!(!values.is_empty(), ComputeError: "values cannot be zero length");
+        #[cfg(not(feature = "dtype-struct"))]
+        polars_ensure!(!struct_output, ComputeError: "struct output requires the 'dtype-struct' feature");
+
+        let on_values = on_values.unique_stable()?;
+        let headers = on_values.cast(&DataType::String)?;
+        let mut pivoted = on_values
+            .iter()
+            .zip(headers.str()?)
+            .map(|(value, header)| {
+                let value = LiteralValue::try_from(value)?;
+                Ok((Expr::Literal(value), header.unwrap_or("null").to_string()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if sort_columns {
+            pivoted.sort_by(|a, b| a.1.cmp(&b.1));
+        }
+
+        // Every column in the aggregation refers to the values column, like in the eager pivot.
+        let agg_expr = agg_expr
+            .map(prepare_eval_expr)
+            .unwrap_or_else(|| col("").first());
+        // Like in the eager pivot, the output is null if a group has no rows for a pivoted value.
+        let aggregate = |value_col: &str, on_value: &Expr| {
+            let is_pivoted = col(on).eq_missing(on_value.clone());
+            let values = col(value_col).filter(is_pivoted.clone());
+            let mut agg_expr = agg_expr.clone();
+            agg_expr.mutate().apply(|e| {
+                if matches!(e, Expr::Column(name) if name.is_empty()) {
+                    *e = values.clone();
+                } else if matches!(e, Expr::Len) {
+                    *e = values.clone().len();
+                }
+                true
+            });
+            when(is_pivoted.any(false))
+                .then(agg_expr)
+                .otherwise(NULL.lit())
+        };
+
+        let index = index.iter().map(|name| col(name)).collect::<Vec<_>>();
+        #[cfg(feature = "dtype-struct")]
+        if struct_output && values.len() > 1 {
+            let aggs = pivoted
+                .iter()
+                .map(|(on_value, header)| {
+                    let fields = values
+                        .iter()
+                        .map(|v| aggregate(v, on_value).alias(v))
+                        .collect();
+                    as_struct(fields).alias(header)
+                })
+                .collect::<Vec<_>>();
+            return Ok(self.group_by_stable(index).agg(aggs));
+        }
+
+        let aggs = values
+            .iter()
+            .flat_map(|v| {
+                pivoted.iter().map(move |(on_value, header)| {
+                    let name = if values.len() > 1 {
+                        format!("{v}{sep}{on}{sep}{header}")
+                    } else {
+                        header.clone()
+                    };
+                    aggregate(v, on_value).alias(&name)
+                })
+            })
+            .collect::<Vec<_>>();
+        Ok(self.group_by_stable(index).agg(aggs))
+    }
+}
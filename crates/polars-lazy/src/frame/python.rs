@@ -15,4 +15,20 @@ impl LazyFrame {
         }
         .into()
     }
+
+    /// Scan from an IO plugin. The `scan_fn` is called with the projected columns, the
+    /// pushed down predicate serialized as JSON and the number of rows to read. The plugin may
+    /// use the predicate to prune data at the source, the predicate is applied again on its
+    /// output.
+    pub fn scan_from_io_plugin(schema: Schema, scan_fn: PyObject) -> Self {
+        LogicalPlan::PythonScan {
+            options: PythonOptions {
+                scan_fn: Some(scan_fn.into()),
+                schema: Arc::new(schema),
+                io_plugin: true,
+                ..Default::default()
+            },
+        }
+        .into()
+    }
 }
@@ -0,0 +1,153 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::{SerReader, SerWriter};
+use xxhash_rust::xxh3::xxh3_128;
+
+use crate::prelude::*;
+
+/// The directory in which the results of [`LazyFrame::collect`] are cached. The cache is
+/// disabled if this is not set.
+pub(crate) static POLARS_RESULT_CACHE_DIR: &str = "POLARS_RESULT_CACHE_DIR";
+
+pub(super) fn result_cache_dir() -> Option<PathBuf> {
+    std::env::var_os(POLARS_RESULT_CACHE_DIR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+// A result only depends on the plan and the files it scans if the plan doesn't contain
+// in-memory data, user defined functions or random functions without a seed.
+fn is_cacheable(root: Node, lp_arena: &Arena<ALogicalPlan>, expr_arena: &Arena<AExpr>) -> bool {
+    lp_arena.iter(root).all(|(_, lp)| {
+        let cacheable_node = match lp {
+            ALogicalPlan::Scan { .. } => true,
+            ALogicalPlan::DataFrameScan { .. } | ALogicalPlan::Sink { .. } => false,
+            #[cfg(feature = "python")]
+            ALogicalPlan::PythonScan { .. } => false,
+            ALogicalPlan::MapFunction { function, .. } => match function {
+                FunctionNode::Opaque { .. } => false,
+                #[cfg(feature = "python")]
+                FunctionNode::OpaquePython { .. } => false,
+                _ => true,
+            },
+            _ => true,
+        };
+        cacheable_node
+            && lp.get_exprs().into_iter().all(|e| {
+                expr_arena.iter(e).all(|(_, ae)| match ae {
+                    AExpr::AnonymousFunction { .. } => false,
+                    #[cfg(feature = "random")]
+                    AExpr::Function {
+                        function: FunctionExpr::Random { seed: None, .. },
+                        ..
+                    } => false,
+                    _ => true,
+                })
+            })
+    })
+}
+
+/// Compute the key of the result of a query. This is a hash of the optimized plan and of the
+/// paths, sizes and modification times of the files it scans.
+///
+/// Returns `None` if the result cannot be cached.
+fn result_cache_key(lf: &LazyFrame) -> PolarsResult<Option<u128>> {
+    let mut lp_arena = Arena::with_capacity(64);
+    let mut expr_arena = Arena::with_capacity(64);
+    // the result doesn't depend on the engine
+    let root = lf
+        .clone()
+        .with_streaming(false)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    if !is_cacheable(root, &lp_arena, &expr_arena) {
+        return Ok(None);
+    }
+
+    let nodes = (&lp_arena)
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+    let mut paths = vec![];
+    for node in nodes {
+        match lp_arena.get_mut(node) {
+            ALogicalPlan::Scan {
+                paths: scan_paths, ..
+            } => paths.extend(scan_paths.iter().cloned()),
+            // the ids of the caches differ between runs
+            ALogicalPlan::Cache { id, .. } => *id = 0,
+            _ => {},
+        }
+    }
+
+    let lp = node_to_lp_cloned(root, &expr_arena, &lp_arena);
+    let mut bytes = env!("CARGO_PKG_VERSION").as_bytes().to_vec();
+    if ciborium::ser::into_writer(&lp, &mut bytes).is_err() {
+        return Ok(None);
+    }
+    for path in paths {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            return Ok(None);
+        };
+        let Some(modified) = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        else {
+            return Ok(None);
+        };
+        bytes.extend_from_slice(path.to_string_lossy().as_bytes());
+        bytes.extend_from_slice(&metadata.len().to_le_bytes());
+        bytes.extend_from_slice(&modified.as_nanos().to_le_bytes());
+    }
+    Ok(Some(xxh3_128(&bytes)))
+}
+
+fn read_cached_result(path: &Path) -> PolarsResult<DataFrame> {
+    IpcReader::new(File::open(path)?).finish()
+}
+
+fn write_cached_result(path: &Path, df: &DataFrame) -> PolarsResult<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first, so that concurrent queries never read a partial result
+    let tmp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    IpcWriter::new(File::create(&tmp_path)?).finish(&mut df.clone())?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Collect the query, or return the result of a previous run of the same query if the files
+/// it scans didn't change since.
+pub(super) fn collect_cached(lf: LazyFrame, cache_dir: &Path) -> PolarsResult<DataFrame> {
+    let Some(key) = result_cache_key(&lf)? else {
+        return lf.collect_uncached();
+    };
+    let path = cache_dir.join(format!("{key:032x}.ipc"));
+    let verbose = verbose();
+    if let Ok(df) = read_cached_result(&path) {
+        if verbose {
+            eprintln!("result cache hit: {}", path.display());
+        }
+        return Ok(df);
+    }
+
+    let df = lf.collect_uncached()?;
+    // failing to cache the result doesn't fail the query
+    if let Err(e) = write_cached_result(&path, &df) {
+        if verbose {
+            eprintln!("could not write to the result cache: {e}");
+        }
+    } else if verbose {
+        eprintln!(
+            "result cache miss, cached the result in: {}",
+            path.display()
+        );
+    }
+    Ok(df)
+}
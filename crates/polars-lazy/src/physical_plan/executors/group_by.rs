@@ -19,15 +19,30 @@ pub(super) fn evaluate_aggs(
     })
 }
 
+/// Call the function of a `map_groups` on every group and concatenate the results.
+pub(super) fn apply_udf(
+    gb: &GroupBy,
+    f: &dyn DataFrameUdf,
+    parallel: bool,
+) -> PolarsResult<DataFrame> {
+    if parallel {
+        POOL.install(|| gb.par_apply(|df| f.call_udf(df)))
+    } else {
+        gb.apply(|df| f.call_udf(df))
+    }
+}
+
 /// Take an input Executor and a multiple expressions
 pub struct GroupByExec {
     input: Box<dyn Executor>,
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
     apply: Option<Arc<dyn DataFrameUdf>>,
+    parallel_apply: bool,
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
+    having: Option<usize>,
 }
 
 impl GroupByExec {
@@ -37,18 +52,22 @@ impl GroupByExec {
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        parallel_apply: bool,
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
+        having: Option<usize>,
     ) -> Self {
         Self {
             input,
             keys,
             aggs,
             apply,
+            parallel_apply,
             maintain_order,
             input_schema,
             slice,
+            having,
         }
     }
 }
@@ -59,15 +78,17 @@ pub(super) fn group_by_helper(
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
     apply: Option<Arc<dyn DataFrameUdf>>,
+    parallel_apply: bool,
     state: &ExecutionState,
     maintain_order: bool,
     slice: Option<(i64, usize)>,
+    having: Option<usize>,
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
 
     if let Some(f) = apply {
-        return gb.apply(move |df| f.call_udf(df));
+        return apply_udf(&gb, f.as_ref(), parallel_apply);
     }
 
     let mut groups = gb.get_groups();
@@ -81,15 +102,48 @@ pub(super) fn group_by_helper(
         groups = sliced_groups.as_deref().unwrap();
     }
 
-    let (mut columns, agg_columns) = POOL.install(|| {
-        let get_columns = || gb.keys_sliced(slice);
+    let Some(having) = having else {
+        let (mut columns, agg_columns) = POOL.install(|| {
+            let get_columns = || gb.keys_sliced(slice);
+
+            let get_agg = || evaluate_aggs(&df, aggs, groups, state);
+
+            rayon::join(get_columns, get_agg)
+        });
+        let agg_columns = agg_columns?;
+
+        columns.extend_from_slice(&agg_columns);
+        return DataFrame::new(columns);
+    };
+
+    // Filter the groups first, so that the other aggregations are only computed for the
+    // groups that are kept.
+    let having_column = evaluate_aggs(&df, &aggs[having..having + 1], groups, state)?.remove(0);
+    let mask = having_column.bool()?;
+    let filtered_groups = groups.filter(mask);
+    let other_aggs = aggs
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != having)
+        .map(|(_, agg)| agg.clone())
+        .collect::<Vec<_>>();
+
+    let (columns, agg_columns) = POOL.install(|| {
+        let get_columns = || {
+            gb.keys_sliced(slice)
+                .iter()
+                .map(|s| s.filter(mask))
+                .collect::<PolarsResult<Vec<_>>>()
+        };
 
-        let get_agg = || evaluate_aggs(&df, aggs, groups, state);
+        let get_agg = || evaluate_aggs(&df, &other_aggs, &filtered_groups, state);
 
         rayon::join(get_columns, get_agg)
     });
-    let agg_columns = agg_columns?;
+    let mut columns = columns?;
+    let mut agg_columns = agg_columns?;
 
+    agg_columns.insert(having, having_column.filter(mask)?);
     columns.extend_from_slice(&agg_columns);
     DataFrame::new(columns)
 }
@@ -106,9 +160,11 @@ impl GroupByExec {
             keys,
             &self.aggs,
             self.apply.take(),
+            self.parallel_apply,
             state,
             self.maintain_order,
             self.slice,
+            self.having,
         )
     }
 }
@@ -127,7 +183,7 @@ impl Executor for GroupByExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .keys
                 .iter()
@@ -139,7 +195,7 @@ impl Executor for GroupByExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
@@ -17,6 +17,7 @@ pub(crate) struct GroupByDynamicExec {
     pub(crate) input_schema: SchemaRef,
     pub(crate) slice: Option<(i64, usize)>,
     pub(crate) apply: Option<Arc<dyn DataFrameUdf>>,
+    pub(crate) parallel_apply: bool,
 }
 
 impl GroupByDynamicExec {
@@ -37,7 +38,7 @@ impl GroupByDynamicExec {
 
         if let Some(f) = &self.apply {
             let gb = GroupBy::new(&df, vec![], groups, None);
-            let out = gb.apply(move |df| f.call_udf(df))?;
+            let out = apply_udf(&gb, f.as_ref(), self.parallel_apply)?;
             return Ok(if let Some((offset, len)) = self.slice {
                 out.slice(offset, len)
             } else {
@@ -91,7 +92,7 @@ impl Executor for GroupByDynamicExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .keys
                 .iter()
@@ -103,7 +104,7 @@ impl Executor for GroupByDynamicExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
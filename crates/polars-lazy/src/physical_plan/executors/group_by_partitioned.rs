@@ -255,6 +255,7 @@ impl PartitionGroupByExec {
             &mut vec![],
             false,
             false,
+            Default::default(),
         )
         .unwrap();
 
@@ -288,9 +289,11 @@ impl PartitionGroupByExec {
                     keys,
                     &self.phys_aggs,
                     None,
+                    false,
                     state,
                     self.maintain_order,
                     self.slice,
+                    None,
                 );
             }
 
@@ -372,7 +375,7 @@ impl Executor for PartitionGroupByExec {
         }
         let original_df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .phys_keys
                 .iter()
@@ -383,7 +386,7 @@ impl Executor for PartitionGroupByExec {
         } else {
             Cow::Borrowed("")
         };
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, original_df), profile_name)
         } else {
@@ -71,7 +71,7 @@ impl Executor for JoinExec {
         let mut df_left = df_left?;
         let mut df_right = df_right?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .left_on
                 .iter()
@@ -137,8 +137,11 @@ impl Executor for JoinExec {
                                 let tolerance = duration.duration_ns();
                                 options.tolerance = Some(AnyValue::from(tolerance))
                             }
-                            _ => {
-                                panic!("can only use timedelta string language with Date/Datetime/Duration/Time dtypes")
+                            dt => {
+                                polars_bail!(
+                                    ComputeError:
+                                    "can only use a tolerance string with Date/Datetime/Duration/Time dtypes, got `{}`", dt
+                                )
                             }
                         }
                     }
@@ -60,7 +60,7 @@ impl Executor for ProjectionExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .expr
                 .iter()
@@ -78,7 +78,7 @@ impl Executor for ProjectionExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
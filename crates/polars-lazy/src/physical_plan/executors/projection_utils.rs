@@ -87,7 +87,7 @@ fn execute_projection_cached_window_fns(
             } = e
             {
                 let entry = match options {
-                    WindowType::Over(_) => {
+                    WindowType::Over(_) | WindowType::Frame(_) => {
                         let group_by = format_smartstring!("{:?}", partition_by.as_slice());
                         windows.entry(group_by).or_insert_with(Vec::new)
                     },
@@ -5,6 +5,9 @@ use super::*;
 
 pub(crate) struct PythonScanExec {
     pub(crate) options: PythonOptions,
+    // the predicate of an IO plugin, it is applied after the scan
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) predicate_columns: Vec<Arc<str>>,
 }
 
 impl Executor for PythonScanExec {
@@ -16,8 +19,24 @@ impl Executor for PythonScanExec {
                 println!("run PythonScanExec")
             }
         }
-        let with_columns = self.options.with_columns.take();
-        let pyarrow_predicate = self.options.predicate.take();
+        let mut with_columns = self.options.with_columns.take();
+        let predicate = self.options.predicate.take();
+
+        // the columns of the predicate must be read as well, they are projected away after
+        // the predicate is applied
+        let mut projection = None;
+        if let (Some(_), Some(cols)) = (&self.predicate, &mut with_columns) {
+            let missing = self
+                .predicate_columns
+                .iter()
+                .filter(|name| !cols.iter().any(|col| col.as_str() == name.as_ref()))
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                projection = Some(cols.clone());
+                Arc::make_mut(cols).extend(missing);
+            }
+        }
         let n_rows = self.options.n_rows.take();
         Python::with_gil(|py| {
             let pl = PyModule::import(py, "polars").unwrap();
@@ -30,12 +49,7 @@ impl Executor for PythonScanExec {
                 with_columns.map(|mut cols| std::mem::take(Arc::make_mut(&mut cols)));
 
             let out = callable
-                .call1((
-                    python_scan_function,
-                    with_columns,
-                    pyarrow_predicate,
-                    n_rows,
-                ))
+                .call1((python_scan_function, with_columns, predicate, n_rows))
                 .map_err(to_compute_err)?;
             let pydf = out.getattr("_df").unwrap();
             let raw_parts = pydf.call_method0("into_raw_parts").unwrap();
@@ -50,5 +64,18 @@ impl Executor for PythonScanExec {
                 )))
             }
         })
+        .and_then(|mut df| {
+            if let Some(predicate) = &self.predicate {
+                let s = predicate.evaluate(&df, state)?;
+                let mask = s.bool().map_err(
+                    |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
+                )?;
+                df = df.filter(mask)?;
+            }
+            match projection {
+                Some(cols) => df.select(cols.iter()),
+                None => Ok(df),
+            }
+        })
     }
 }
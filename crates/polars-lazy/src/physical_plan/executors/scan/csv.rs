@@ -45,6 +45,7 @@ impl CsvExec {
             .with_rechunk(self.file_options.rechunk)
             .with_row_index(std::mem::take(&mut self.file_options.row_index))
             .with_try_parse_dates(self.options.try_parse_dates)
+            .with_decimal_comma(self.options.decimal_comma)
             .truncate_ragged_lines(self.options.truncate_ragged_lines)
             .raise_if_empty(self.options.raise_if_empty)
             .finish()
@@ -62,7 +63,7 @@ impl Executor for CsvExec {
             slice: (self.options.skip_rows, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let mut ids = vec![self.path.to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
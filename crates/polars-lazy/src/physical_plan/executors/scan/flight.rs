@@ -0,0 +1,82 @@
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::flight::FlightReader;
+
+use super::*;
+
+pub struct FlightExec {
+    pub(crate) options: FlightScanOptions,
+    pub(crate) file_options: FileScanOptions,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+}
+
+impl FlightExec {
+    fn read(&mut self, state: &ExecutionState) -> PolarsResult<DataFrame> {
+        let projection = self.file_options.with_columns.clone();
+        let with_columns = projection.as_ref().map(|columns| {
+            let row_index = self.file_options.row_index.as_ref();
+            // The row index is added after reading.
+            columns
+                .iter()
+                .filter(|name| Some(name.as_str()) != row_index.map(|rc| rc.name.as_str()))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        let mut reader = FlightReader::new(&self.options.endpoint, self.options.ticket.clone())
+            .with_columns(with_columns);
+
+        let mut n_rows = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let mut offset = self
+            .file_options
+            .row_index
+            .as_ref()
+            .map_or(0, |rc| rc.offset);
+        let mut dfs = vec![];
+        while n_rows > 0 {
+            let Some(mut df) = reader.next_batch()? else {
+                break;
+            };
+            if df.height() > n_rows {
+                df = df.head(Some(n_rows));
+            }
+            n_rows -= df.height();
+            if let Some(rc) = &self.file_options.row_index {
+                df = df.with_row_index(&rc.name, Some(offset))?;
+                offset += df.height() as IdxSize;
+            }
+            if let Some(predicate) = &self.predicate {
+                let mask = predicate.evaluate(&df, state)?;
+                df = df.filter(mask.bool()?)?;
+            }
+            dfs.push(df);
+        }
+
+        let mut df = if dfs.is_empty() {
+            let schema = Schema::from(&reader.schema()?);
+            let mut df = DataFrame::from(&schema);
+            if let Some(rc) = &self.file_options.row_index {
+                df = df.with_row_index(&rc.name, None)?;
+            }
+            df
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+        if let Some(columns) = projection {
+            df = df.select(columns.as_ref())?;
+        }
+        Ok(df)
+    }
+}
+
+impl Executor for FlightExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.records_nodes() {
+            Cow::Owned(comma_delimited(
+                "flight".to_string(),
+                &[self.options.endpoint.clone().into()],
+            ))
+        } else {
+            Cow::Borrowed("")
+        };
+        state.record(|| self.read(state), profile_name)
+    }
+}
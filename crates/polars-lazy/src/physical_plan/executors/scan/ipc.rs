@@ -1,39 +1,149 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::RowIndex;
 
 use super::*;
 
 pub struct IpcExec {
-    pub(crate) path: PathBuf,
-    pub(crate) schema: SchemaRef,
+    pub(crate) paths: Arc<[PathBuf]>,
+    pub(crate) file_info: FileInfo,
     pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) options: IpcScanOptions,
     pub(crate) file_options: FileScanOptions,
 }
 
 impl IpcExec {
-    fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
-        let file = std::fs::File::open(&self.path)?;
-        let (projection, predicate) = prepare_scan_args(
+    /// Read a single file and add its hive partition columns.
+    ///
+    /// If `apply_predicate` is false, the predicate is returned instead of applied, so that
+    /// the caller can apply it after it has inspected the rows that were read.
+    fn read_file(
+        &self,
+        path: &Path,
+        n_rows: Option<usize>,
+        row_index: Option<RowIndex>,
+        apply_predicate: bool,
+        verbose: bool,
+    ) -> PolarsResult<(DataFrame, Predicate)> {
+        let mut file_info = self.file_info.clone();
+        file_info.update_hive_partitions(path)?;
+        let hive_partitions = file_info
+            .hive_parts
+            .as_ref()
+            .map(|hive| hive.materialize_partition_columns());
+
+        let (mut projection, predicate) = prepare_scan_args(
             self.predicate.clone(),
-            &mut self.file_options.with_columns,
-            &mut self.schema,
-            self.file_options.row_index.is_some(),
-            None,
+            &mut self.file_options.with_columns.clone(),
+            &mut self.file_info.schema.clone(),
+            row_index.is_some(),
+            hive_partitions.as_deref(),
         );
-        IpcReader::new(file)
-            .with_n_rows(self.file_options.n_rows)
-            .with_row_index(std::mem::take(&mut self.file_options.row_index))
-            .set_rechunk(self.file_options.rechunk)
+        // If only hive partition columns are projected, we still read the first column of the
+        // file to know the number of rows.
+        let only_hive_columns = matches!(&projection, Some(projection) if projection.is_empty());
+        if only_hive_columns {
+            projection = Some(vec![0]);
+        }
+        let (reader_predicate, predicate) = if apply_predicate {
+            (predicate, None)
+        } else {
+            (None, predicate)
+        };
+
+        let file = std::fs::File::open(path)?;
+        let mut df = IpcReader::new(file)
+            .with_n_rows(n_rows)
+            .with_row_index(row_index)
+            .set_rechunk(false)
             .with_projection(projection)
+            .with_struct_fields(self.options.struct_fields.clone())
             .memory_mapped(self.options.memmap)
-            .finish_with_scan_ops(predicate, verbose)
+            .finish_with_scan_ops(reader_predicate, verbose)?;
+
+        if let Some(hive_partitions) = hive_partitions {
+            let height = df.height();
+            if only_hive_columns {
+                let name = &self.file_info.reader_schema.as_ref().unwrap().fields[0].name;
+                df.drop_in_place(name)?;
+            }
+            for s in hive_partitions {
+                // SAFETY: the partition columns are not part of the file.
+                unsafe { df.with_column_unchecked(s.new_from_index(0, height)) };
+            }
+        }
+        Ok((df, predicate))
+    }
+
+    fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+        let row_index = self.file_options.row_index.take();
+        let n_rows = self.file_options.n_rows;
+        // The hive partition columns are not in the files, and the row index and slice continue
+        // over the files, so in those cases we apply the predicate after reading.
+        let sequential = self.paths.len() > 1 && (row_index.is_some() || n_rows.is_some());
+        let apply_predicate = self.file_info.hive_parts.is_none() && !sequential;
+
+        let filter = |df: DataFrame, predicate: Predicate| match predicate {
+            Some(predicate) if !df.is_empty() => {
+                let mask = predicate.evaluate_io(&df)?;
+                df.filter(mask.bool()?)
+            },
+            _ => Ok(df),
+        };
+
+        let dfs = if sequential {
+            let mut row_index = row_index;
+            let mut remaining_rows_to_read = n_rows.unwrap_or(usize::MAX);
+            let mut dfs = Vec::with_capacity(self.paths.len());
+            for path in self.paths.iter() {
+                if remaining_rows_to_read == 0 && !dfs.is_empty() {
+                    break;
+                }
+                let (df, predicate) = self.read_file(
+                    path,
+                    n_rows.map(|_| remaining_rows_to_read),
+                    row_index.clone(),
+                    apply_predicate,
+                    verbose,
+                )?;
+                remaining_rows_to_read = remaining_rows_to_read.saturating_sub(df.height());
+                if let Some(rc) = &mut row_index {
+                    rc.offset += df.height() as IdxSize;
+                }
+                dfs.push(filter(df, predicate)?);
+            }
+            dfs
+        } else {
+            POOL.install(|| {
+                self.paths
+                    .par_iter()
+                    .map(|path| {
+                        let (df, predicate) = self.read_file(
+                            path,
+                            n_rows,
+                            row_index.clone(),
+                            apply_predicate,
+                            verbose,
+                        )?;
+                        filter(df, predicate)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        };
+
+        let mut out = accumulate_dataframes_vertical(dfs)?;
+        if self.file_options.rechunk {
+            out.as_single_chunk_par();
+        }
+        Ok(out)
     }
 }
 
 impl Executor for IpcExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let finger_print = FileFingerPrint {
-            paths: Arc::new([self.path.clone()]),
+            paths: self.paths.clone(),
             predicate: self
                 .predicate
                 .as_ref()
@@ -41,8 +151,8 @@ impl Executor for IpcExec {
             slice: (0, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
-            let mut ids = vec![self.path.to_string_lossy().into()];
+        let profile_name = if state.records_nodes() {
+            let mut ids = vec![self.paths[0].to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
             }
@@ -52,6 +162,12 @@ impl Executor for IpcExec {
             Cow::Borrowed("")
         };
 
+        if let Some(scan_stats) = state.register_scan(&profile_name) {
+            let pruned_files = self.file_info.pruned_files;
+            scan_stats.add_files_listed(self.paths.len() + pruned_files);
+            scan_stats.add_files_pruned(pruned_files);
+        }
+
         state.record(
             || {
                 state
@@ -1,5 +1,7 @@
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "flight")]
+mod flight;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "json")]
@@ -13,6 +15,8 @@ use std::ops::Deref;
 
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvExec;
+#[cfg(feature = "flight")]
+pub(crate) use flight::FlightExec;
 #[cfg(feature = "ipc")]
 pub(crate) use ipc::IpcExec;
 #[cfg(feature = "parquet")]
@@ -63,6 +67,8 @@ pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
     pub(crate) selection: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) projection: Option<Arc<Vec<String>>>,
+    /// The indexed columns and the values the selection compares them to.
+    pub(crate) index_keys: Option<(Vec<String>, Vec<Series>)>,
     pub(crate) predicate_has_windows: bool,
 }
 
@@ -71,6 +77,15 @@ impl Executor for DataFrameExec {
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
+        // Only the rows the index finds can pass the selection, which is still applied to them.
+        if let Some((columns, keys)) = &self.index_keys {
+            if let Some(index) = df.get_index(columns) {
+                let rows = index.lookup(keys)?;
+                // SAFETY: the index returns rows in bounds of the DataFrame.
+                df = unsafe { df.take_unchecked(&rows) };
+            }
+        }
+
         // projection should be before selection as those are free
         // TODO: this is only the case if we don't create new columns
         if let Some(projection) = &self.projection {
@@ -1,14 +1,48 @@
+use polars_io::compression::decompressed_reader;
+use polars_io::predicates::PhysicalIoExpr;
+
 use super::*;
+use crate::physical_plan::expressions::phys_expr_to_io_expr;
+use crate::physical_plan::planner::create_physical_expr;
 use crate::prelude::{AnonymousScan, LazyJsonLineReader};
 
+/// Create the physical expression of a predicate that was pushed down into the scan.
+fn io_predicate(predicate: Expr, schema: &SchemaRef) -> PolarsResult<Arc<dyn PhysicalIoExpr>> {
+    let mut expr_arena = Arena::with_capacity(16);
+    let node = to_aexpr(predicate, &mut expr_arena);
+    let phys_expr = create_physical_expr(
+        node,
+        Context::Default,
+        &expr_arena,
+        Some(schema),
+        &mut Default::default(),
+    )?;
+    Ok(phys_expr_to_io_expr(phys_expr))
+}
+
 impl AnonymousScan for LazyJsonLineReader {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
     fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
-        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        let output_schema = scan_opts
+            .output_schema
+            .as_ref()
+            .unwrap_or(&scan_opts.schema);
+        let predicate = scan_opts
+            .predicate
+            .map(|predicate| io_predicate(predicate, output_schema))
+            .transpose()?;
+        // The reader gets the projection instead of the output schema, so that it skips the
+        // values of the keys that are not projected.
         JsonLineReader::from_path(&self.path)?
-            .with_schema(schema)
+            .with_schema(scan_opts.schema)
+            .with_columns(
+                scan_opts
+                    .with_columns
+                    .map(|columns| columns.as_ref().clone()),
+            )
+            .with_predicate(predicate)
             .with_rechunk(self.rechunk)
             .with_chunk_size(self.batch_size)
             .low_memory(self.low_memory)
@@ -25,12 +59,13 @@ impl AnonymousScan for LazyJsonLineReader {
         }
 
         let f = polars_utils::open_file(&self.path)?;
-        let mut reader = std::io::BufReader::new(f);
+        let mut reader = std::io::BufReader::new(decompressed_reader(f)?);
 
-        let schema = Arc::new(polars_io::ndjson::infer_schema(
+        let mut schema = Arc::new(polars_io::ndjson::infer_schema(
             &mut reader,
             infer_schema_length,
         )?);
+        self.apply_schema_overwrite(&mut schema);
         let mut guard = self.schema.write().unwrap();
         *guard = Some(schema.clone());
 
@@ -39,4 +74,7 @@ impl AnonymousScan for LazyJsonLineReader {
     fn allows_projection_pushdown(&self) -> bool {
         true
     }
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
 }
@@ -1,12 +1,17 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(feature = "cloud")]
-use polars_core::config::{get_file_prefetch_size, verbose};
+use polars_core::config::get_file_prefetch_size;
+use polars_core::config::{get_file_compaction_rows, verbose};
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::FileMetaData;
+use polars_io::scan_stats::ScanStatistics;
 use polars_io::{is_cloud_url, RowIndex};
 
+// The number of files of which the metadata is read at once when compacting small files.
+const METADATA_BATCH_SIZE: usize = 1024;
+
 use super::*;
 
 pub struct ParquetExec {
@@ -19,6 +24,7 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<Arc<FileMetaData>>,
+    scan_stats: Option<Arc<ScanStatistics>>,
 }
 
 impl ParquetExec {
@@ -39,6 +45,99 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            scan_stats: None,
+        }
+    }
+
+    /// The columns that are constant for all rows of a file: its hive partitions and, if
+    /// requested, its path.
+    fn constant_columns(&self, file_info: &FileInfo, path: &Path) -> Option<Vec<Series>> {
+        let mut columns = file_info
+            .hive_parts
+            .as_ref()
+            .map(|hive| hive.materialize_partition_columns());
+        if let Some(name) = self.file_options.include_file_paths.as_deref() {
+            let path = path.to_string_lossy();
+            columns
+                .get_or_insert_with(Vec::new)
+                .push(Series::new(name, [path.as_ref()]));
+        }
+        columns
+    }
+
+    /// If the schema of a file differs from the unified schema of the scan, returns the names of
+    /// the columns of the unified schema that are read from it and the projection into the file.
+    /// Such a file is read with its own schema and conformed to the unified schema afterwards.
+    fn drifted_projection(
+        &self,
+        file_schema: &ArrowSchema,
+        hive_partitions: Option<&[Series]>,
+    ) -> Option<(Vec<String>, Vec<usize>)> {
+        let reader_schema = self.file_info.reader_schema.as_deref()?;
+        if !self.options.schema_drift || reader_schema == file_schema {
+            return None;
+        }
+        let is_hive = |name: &str| {
+            hive_partitions.map_or(false, |hive| hive.iter().any(|s| s.name() == name))
+        };
+        let names: Vec<String> = match &self.file_options.with_columns {
+            Some(columns) => columns
+                .iter()
+                .filter(|name| !is_hive(name))
+                .cloned()
+                .collect(),
+            None => reader_schema
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect(),
+        };
+        let mut projection: Vec<usize> = names
+            .iter()
+            .filter_map(|name| file_schema.fields.iter().position(|f| &f.name == name))
+            .collect();
+        // We read the first column to know the number of rows if the file has none of the columns.
+        if projection.is_empty() {
+            projection.push(0);
+        }
+        projection.sort_unstable();
+        Some((names, projection))
+    }
+
+    /// Conform a DataFrame read from a drifted file to the unified schema of the scan. The
+    /// columns are cast to their unified type and the missing columns are inserted as nulls.
+    /// The predicate is applied afterwards, as it expects the unified schema.
+    fn conform_drifted(
+        &self,
+        df: DataFrame,
+        names: &[String],
+        row_index: Option<&RowIndex>,
+        hive_partitions: Option<&[Series]>,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> PolarsResult<DataFrame> {
+        let height = df.height();
+        let mut columns = Vec::with_capacity(names.len() + 1);
+        if let Some(rc) = row_index {
+            columns.push(df.column(&rc.name)?.clone());
+        }
+        for name in names {
+            let dtype = self.file_info.schema.try_get(name)?;
+            let s = match df.column(name) {
+                Ok(s) => s.cast(dtype)?,
+                Err(_) => Series::full_null(name, height, dtype),
+            };
+            columns.push(s);
+        }
+        for s in hive_partitions.unwrap_or_default() {
+            columns.push(df.column(s.name())?.clone());
+        }
+        let df = DataFrame::new_no_checks(columns);
+        match predicate {
+            Some(predicate) if !df.is_empty() => {
+                let mask = predicate.evaluate_io(&df)?;
+                df.filter(mask.bool()?)
+            },
+            _ => Ok(df),
         }
     }
 
@@ -73,10 +172,7 @@ impl ParquetExec {
                     let mut file_info = self.file_info.clone();
                     file_info.update_hive_partitions(path)?;
 
-                    let hive_partitions = file_info
-                        .hive_parts
-                        .as_ref()
-                        .map(|hive| hive.materialize_partition_columns());
+                    let hive_partitions = self.constant_columns(&file_info, path);
 
                     let file = std::fs::File::open(path)?;
                     let (projection, predicate) = prepare_scan_args(
@@ -87,23 +183,39 @@ impl ParquetExec {
                         hive_partitions.as_deref(),
                     );
 
-                    let mut reader = ParquetReader::new(file)
-                        .with_schema(self.file_info.reader_schema.clone())
+                    let mut reader = ParquetReader::new(file);
+                    let drift = if self.options.schema_drift {
+                        self.drifted_projection(&reader.schema()?, hive_partitions.as_deref())
+                    } else {
+                        None
+                    };
+                    let (projection, drift) = match drift {
+                        Some((names, file_projection)) => (
+                            Some(file_projection),
+                            Some((names, hive_partitions.clone())),
+                        ),
+                        None => {
+                            reader = reader.with_schema(self.file_info.reader_schema.clone());
+                            (projection, None)
+                        },
+                    };
+                    let mut reader = reader
                         .read_parallel(parallel)
                         .set_low_memory(self.options.low_memory)
                         .use_statistics(self.options.use_statistics)
                         .set_rechunk(false)
-                        .with_hive_partition_columns(hive_partitions);
+                        .with_hive_partition_columns(hive_partitions)
+                        .with_scan_statistics(self.scan_stats.clone());
 
                     reader
                         .num_rows()
-                        .map(|num_rows| (reader, num_rows, predicate, projection))
+                        .map(|num_rows| (reader, num_rows, predicate, projection, drift))
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
             let iter = readers_and_metadata
                 .iter()
-                .map(|(_, num_rows, _, _)| *num_rows);
+                .map(|(_, num_rows, _, _, _)| *num_rows);
 
             let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
 
@@ -113,7 +225,7 @@ impl ParquetExec {
                     .zip(rows_statistics.par_iter())
                     .map(
                         |(
-                            (reader, num_rows_this_file, predicate, projection),
+                            (reader, num_rows_this_file, predicate, projection, drift),
                             (remaining_rows_to_read, cumulative_read),
                         )| {
                             let remaining_rows_to_read = *remaining_rows_to_read;
@@ -128,12 +240,23 @@ impl ParquetExec {
                                 offset: rc.offset + *cumulative_read as IdxSize,
                             });
 
-                            reader
+                            let reader = reader
                                 .with_n_rows(remaining_rows_to_read)
-                                .with_row_index(row_index)
-                                .with_predicate(predicate.clone())
-                                .with_projection(projection.clone())
-                                .finish()
+                                .with_row_index(row_index.clone())
+                                .with_projection(projection.clone());
+                            match drift {
+                                Some((names, hive_partitions)) => {
+                                    let df = reader.finish()?;
+                                    self.conform_drifted(
+                                        df,
+                                        &names,
+                                        row_index.as_ref(),
+                                        hive_partitions.as_deref(),
+                                        predicate.as_ref(),
+                                    )
+                                },
+                                None => reader.with_predicate(predicate.clone()).finish(),
+                            }
                         },
                     )
                     .collect::<PolarsResult<Vec<_>>>()
@@ -153,6 +276,151 @@ impl ParquetExec {
         Ok(result)
     }
 
+    /// Read many small files per task. The metadata of the files is read in batches and the
+    /// files are assigned to tasks until a task reads about `POLARS_FILE_COMPACTION_ROWS` rows.
+    /// The row groups of the files of a task are merged into a single chunk.
+    fn read_compacted(&mut self) -> PolarsResult<Vec<DataFrame>> {
+        let target_rows = get_file_compaction_rows();
+        let mut result = vec![];
+
+        let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let mut base_row_index = self.file_options.row_index.take();
+
+        for paths in self.paths.chunks(METADATA_BATCH_SIZE) {
+            if remaining_rows_to_read == 0 && !result.is_empty() {
+                return Ok(result);
+            }
+
+            // The files are closed after reading the metadata, so that we don't hit the open
+            // file limits.
+            let metadata = POOL.install(|| {
+                paths
+                    .par_iter()
+                    .map(|path| {
+                        let file = std::fs::File::open(path)?;
+                        ParquetReader::new(file).get_metadata().cloned()
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+
+            let iter = metadata.iter().map(|md| md.num_rows);
+            let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
+
+            // Coalesce consecutive files until a task reads `target_rows`.
+            let mut tasks = vec![];
+            let mut start = 0;
+            let mut task_rows = 0;
+            for (i, md) in metadata.iter().enumerate() {
+                task_rows += md.num_rows;
+                if task_rows >= target_rows {
+                    tasks.push(start..i + 1);
+                    start = i + 1;
+                    task_rows = 0;
+                }
+            }
+            if start < metadata.len() {
+                tasks.push(start..metadata.len());
+            }
+            if verbose() {
+                eprintln!(
+                    "compacting {} parquet files into {} read tasks",
+                    paths.len(),
+                    tasks.len()
+                );
+            }
+
+            let read_file = |i: usize, parallel: ParallelStrategy| {
+                let path = &paths[i];
+                let (remaining_rows_to_read, cumulative_read) = rows_statistics[i];
+                let num_rows_this_file = metadata[i].num_rows;
+                let remaining_rows_to_read = if num_rows_this_file < remaining_rows_to_read {
+                    None
+                } else {
+                    Some(remaining_rows_to_read)
+                };
+                let row_index = base_row_index.as_ref().map(|rc| RowIndex {
+                    name: rc.name.clone(),
+                    offset: rc.offset + cumulative_read as IdxSize,
+                });
+
+                let mut file_info = self.file_info.clone();
+                file_info.update_hive_partitions(path)?;
+                let hive_partitions = self.constant_columns(&file_info, path);
+
+                let (projection, predicate) = prepare_scan_args(
+                    self.predicate.clone(),
+                    &mut self.file_options.with_columns.clone(),
+                    &mut self.file_info.schema.clone(),
+                    row_index.is_some(),
+                    hive_partitions.as_deref(),
+                );
+
+                let file = std::fs::File::open(path)?;
+                let mut reader = ParquetReader::new(file).with_metadata(metadata[i].clone());
+                let drift = if self.options.schema_drift {
+                    self.drifted_projection(&reader.schema()?, hive_partitions.as_deref())
+                } else {
+                    None
+                };
+                let reader = match &drift {
+                    Some((_, file_projection)) => {
+                        reader.with_projection(Some(file_projection.clone()))
+                    },
+                    None => reader
+                        .with_schema(self.file_info.reader_schema.clone())
+                        .with_predicate(predicate.clone())
+                        .with_projection(projection),
+                };
+                let df = reader
+                    .read_parallel(parallel)
+                    .set_low_memory(self.options.low_memory)
+                    .use_statistics(self.options.use_statistics)
+                    .set_rechunk(false)
+                    .with_hive_partition_columns(hive_partitions.clone())
+                    .with_n_rows(remaining_rows_to_read)
+                    .with_row_index(row_index.clone())
+                    .with_scan_statistics(self.scan_stats.clone())
+                    .finish()?;
+                match drift {
+                    Some((names, _)) => self.conform_drifted(
+                        df,
+                        &names,
+                        row_index.as_ref(),
+                        hive_partitions.as_deref(),
+                        predicate.as_ref(),
+                    ),
+                    None => Ok(df),
+                }
+            };
+
+            let out = POOL.install(|| {
+                tasks
+                    .into_par_iter()
+                    .map(|task| {
+                        // A large file is a task on its own and is still read in parallel.
+                        if task.len() == 1 {
+                            return read_file(task.start, self.options.parallel);
+                        }
+                        let dfs = task
+                            .map(|i| read_file(i, ParallelStrategy::None))
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        let mut df = accumulate_dataframes_vertical(dfs)?;
+                        df.as_single_chunk();
+                        Ok(df)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+
+            let n_read = out.iter().map(|df| df.height()).sum();
+            remaining_rows_to_read = remaining_rows_to_read.saturating_sub(n_read);
+            if let Some(rc) = &mut base_row_index {
+                rc.offset += n_read as IdxSize;
+            }
+            result.extend(out);
+        }
+        Ok(result)
+    }
+
     #[cfg(feature = "cloud")]
     async fn read_async(&mut self) -> PolarsResult<Vec<DataFrame>> {
         let verbose = verbose();
@@ -168,6 +436,8 @@ impl ParquetExec {
             .with_columns
             .as_ref()
             .map(|v| v.as_slice());
+        let schema_drift = self.options.schema_drift;
+        let scan_stats = &self.scan_stats;
 
         let mut result = vec![];
         let batch_size = get_file_prefetch_size();
@@ -204,13 +474,16 @@ impl ParquetExec {
                 let mut reader = ParquetAsyncReader::from_uri(
                     &path.to_string_lossy(),
                     cloud_options,
-                    // Schema must be the same for all files. The hive partitions are included in this schema.
-                    schema,
+                    // Schema must be the same for all files, unless they are conformed to the
+                    // unified schema. The hive partitions are included in this schema.
+                    schema.filter(|_| !schema_drift),
                     metadata,
                 )
-                .await?;
+                .await?
+                .with_scan_statistics(scan_stats.clone());
 
-                if !first_file {
+                // Drifted files are conformed to the unified schema after reading.
+                if !first_file && !schema_drift {
                     let schema = reader.schema().await?;
                     check_projected_arrow_schema(
                         first_schema.as_ref(),
@@ -240,6 +513,7 @@ impl ParquetExec {
             let use_statistics = self.options.use_statistics;
             let predicate = &self.predicate;
             let base_row_index_ref = &base_row_index;
+            let this = &*self;
 
             if verbose {
                 eprintln!("reading of {}/{} file...", processed, self.paths.len());
@@ -269,10 +543,7 @@ impl ParquetExec {
 
                         file_info.update_hive_partitions(path)?;
 
-                        let hive_partitions = file_info
-                            .hive_parts
-                            .as_ref()
-                            .map(|hive| hive.materialize_partition_columns());
+                        let hive_partitions = this.constant_columns(&file_info, path);
 
                         let (projection, predicate) = prepare_scan_args(
                             predicate.clone(),
@@ -282,17 +553,41 @@ impl ParquetExec {
                             hive_partitions.as_deref(),
                         );
 
-                        reader
+                        let mut reader = reader;
+                        let drift = if schema_drift {
+                            let schema = reader.schema().await?;
+                            this.drifted_projection(&schema, hive_partitions.as_deref())
+                        } else {
+                            None
+                        };
+                        let reader = reader
                             .with_n_rows(remaining_rows_to_read)
-                            .with_row_index(row_index)
-                            .with_projection(projection)
+                            .with_row_index(row_index.clone())
                             .use_statistics(use_statistics)
-                            .with_predicate(predicate)
                             .set_rechunk(false)
-                            .with_hive_partition_columns(hive_partitions)
-                            .finish()
-                            .await
-                            .map(Some)
+                            .with_hive_partition_columns(hive_partitions.clone());
+                        match drift {
+                            Some((names, file_projection)) => {
+                                let df = reader
+                                    .with_projection(Some(file_projection))
+                                    .finish()
+                                    .await?;
+                                this.conform_drifted(
+                                    df,
+                                    &names,
+                                    row_index.as_ref(),
+                                    hive_partitions.as_deref(),
+                                    predicate.as_ref(),
+                                )
+                                .map(Some)
+                            },
+                            None => reader
+                                .with_projection(projection)
+                                .with_predicate(predicate)
+                                .finish()
+                                .await
+                                .map(Some),
+                        }
                     },
                 );
 
@@ -315,11 +610,7 @@ impl ParquetExec {
         let is_cloud = match self.paths.first() {
             Some(p) => is_cloud_url(p.as_path()),
             None => {
-                let hive_partitions = self
-                    .file_info
-                    .hive_parts
-                    .as_ref()
-                    .map(|hive| hive.materialize_partition_columns());
+                let hive_partitions = self.constant_columns(&self.file_info, Path::new(""));
                 let (projection, _) = prepare_scan_args(
                     None,
                     &mut self.file_options.with_columns,
@@ -347,6 +638,8 @@ impl ParquetExec {
             {
                 polars_io::pl_async::get_runtime().block_on_potential_spawn(self.read_async())?
             }
+        } else if self.options.compact_small_files {
+            self.read_compacted()?
         } else {
             self.read_par()?
         };
@@ -370,7 +663,7 @@ impl Executor for ParquetExec {
             slice: (0, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let mut ids = vec![self.paths[0].to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
@@ -381,6 +674,13 @@ impl Executor for ParquetExec {
             Cow::Borrowed("")
         };
 
+        self.scan_stats = state.register_scan(&profile_name);
+        if let Some(scan_stats) = &self.scan_stats {
+            let pruned_files = self.file_info.pruned_files;
+            scan_stats.add_files_listed(self.paths.len() + pruned_files);
+            scan_stats.add_files_pruned(pruned_files);
+        }
+
         state.record(
             || {
                 state
@@ -53,7 +53,7 @@ impl Executor for SortExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .by_column
                 .iter()
@@ -65,7 +65,7 @@ impl Executor for SortExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
@@ -47,7 +47,7 @@ impl Executor for StackExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .exprs
                 .iter()
@@ -65,7 +65,7 @@ impl Executor for StackExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
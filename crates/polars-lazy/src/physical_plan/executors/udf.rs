@@ -16,7 +16,7 @@ impl Executor for UdfExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             Cow::Owned(format!("{}", self.function))
         } else {
             Cow::Borrowed("")
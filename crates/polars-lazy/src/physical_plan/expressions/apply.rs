@@ -6,7 +6,12 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{BatchStats, StatsEvaluator};
-#[cfg(feature = "parquet")]
+#[cfg(any(
+    feature = "parquet",
+    feature = "moment",
+    feature = "log",
+    feature = "mode"
+))]
 use polars_plan::dsl::FunctionExpr;
 use rayon::prelude::*;
 
@@ -181,6 +186,48 @@ impl ApplyExpr {
         self.finish_apply_groups(ac, ca.with_name(&name))
     }
 
+    /// Aggregate with a dedicated group-by kernel if the function has one, instead of calling
+    /// the function on every group. Returns `false` if no kernel applies.
+    #[cfg(any(feature = "moment", feature = "log", feature = "mode"))]
+    fn apply_group_kernel(&self, ac: &mut AggregationContext) -> PolarsResult<bool> {
+        let Expr::Function { function, .. } = &self.expr else {
+            return Ok(false);
+        };
+        let AggState::NotAggregated(s) = ac.agg_state() else {
+            return Ok(false);
+        };
+        let s = s.clone();
+        let groups = ac.groups().as_ref();
+
+        // SAFETY: the groups of a not aggregated state are in bounds of its series.
+        match function {
+            #[cfg(feature = "moment")]
+            FunctionExpr::Skew(bias) => {
+                let out = unsafe { polars_ops::prelude::agg_skew(&s, groups, *bias)? };
+                ac.with_agg_state(AggState::AggregatedScalar(out));
+            },
+            #[cfg(feature = "moment")]
+            FunctionExpr::Kurtosis(fisher, bias) => {
+                let out = unsafe { polars_ops::prelude::agg_kurtosis(&s, groups, *fisher, *bias)? };
+                ac.with_agg_state(AggState::AggregatedScalar(out));
+            },
+            #[cfg(feature = "log")]
+            FunctionExpr::Entropy { base, normalize } => {
+                let out =
+                    unsafe { polars_ops::prelude::agg_entropy(&s, groups, *base, *normalize)? };
+                ac.with_agg_state(AggState::AggregatedScalar(out));
+            },
+            #[cfg(feature = "mode")]
+            FunctionExpr::Mode => {
+                let out = unsafe { polars_ops::chunked_array::mode::agg_mode(&s, groups)? };
+                ac.with_series(out.into_series(), true, Some(&self.expr))?;
+                ac.with_update_groups(UpdateGroups::WithSeriesLen);
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
     /// Apply elementwise e.g. ignore the group/list indices.
     fn apply_single_elementwise<'a>(
         &self,
@@ -337,7 +384,13 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_single_group_aware(ac),
+                ApplyOptions::GroupWise => {
+                    #[cfg(any(feature = "moment", feature = "log", feature = "mode"))]
+                    if self.apply_group_kernel(&mut ac)? {
+                        return Ok(ac);
+                    }
+                    self.apply_single_group_aware(ac)
+                },
                 ApplyOptions::ElementWise => self.apply_single_elementwise(ac),
             }
         } else {
@@ -383,7 +436,9 @@ impl PhysicalExpr for ApplyExpr {
         };
 
         match function {
-            FunctionExpr::Boolean(BooleanFunction::IsNull) => Some(self),
+            FunctionExpr::Boolean(
+                BooleanFunction::IsNull | BooleanFunction::IsNotNull | BooleanFunction::Not,
+            ) => Some(self),
             #[cfg(feature = "is_in")]
             FunctionExpr::Boolean(BooleanFunction::IsIn) => Some(self),
             _ => None,
@@ -498,6 +553,26 @@ impl ApplyExpr {
                     None => Ok(true),
                 }
             },
+            FunctionExpr::Boolean(BooleanFunction::IsNotNull) => {
+                let root = expr_to_leaf_column_name(&self.expr)?;
+
+                // All values are null if the null count equals the number of rows.
+                match (stats.get_stats(&root).ok(), stats.num_rows()) {
+                    (Some(st), Some(num_rows)) => Ok(st.null_count() != Some(num_rows)),
+                    _ => Ok(true),
+                }
+            },
+            FunctionExpr::Boolean(BooleanFunction::Not) => {
+                let root = expr_to_leaf_column_name(&self.expr)?;
+
+                // If the minimum of a boolean column is `true`, no value is `false`.
+                match stats.get_stats(&root).ok().and_then(|st| st.to_min()) {
+                    Some(min) if min.dtype() == &DataType::Boolean => {
+                        Ok(min.bool()?.get(0) != Some(true))
+                    },
+                    _ => Ok(true),
+                }
+            },
             #[cfg(feature = "is_in")]
             FunctionExpr::Boolean(BooleanFunction::IsIn) => {
                 let should_read = || -> Option<bool> {
@@ -38,6 +38,12 @@ impl BinaryExpr {
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
     match op {
+        // temporal operands may fail to coerce
+        Operator::Plus | Operator::Minus
+            if left.dtype().is_temporal() || right.dtype().is_temporal() =>
+        {
+            apply_operator(&left, &right, op)
+        },
         Operator::Plus => Ok(left + right),
         Operator::Minus => Ok(left - right),
         Operator::Multiply => Ok(left * right),
@@ -54,11 +60,22 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::LtEq => ChunkCompare::lt_eq(left, right).map(|ca| ca.into_series()),
         Operator::Eq => ChunkCompare::equal(left, right).map(|ca| ca.into_series()),
         Operator::NotEq => ChunkCompare::not_equal(left, right).map(|ca| ca.into_series()),
-        Operator::Plus => Ok(left + right),
-        Operator::Minus => Ok(left - right),
+        Operator::Plus => left.try_add(right),
+        Operator::Minus => left.try_sub(right),
         Operator::Multiply => Ok(left * right),
         Operator::Divide => Ok(left / right),
         Operator::TrueDivide => match left.dtype() {
+            #[cfg(feature = "dtype-array")]
+            _ if matches!(left.dtype(), Array(_, _)) || matches!(right.dtype(), Array(_, _)) => {
+                let to_float = |s: &Series| match s.dtype() {
+                    Array(inner, width) if !inner.is_float() => {
+                        s.cast(&Array(Box::new(Float64), *width))
+                    },
+                    dt if !dt.is_float() && !matches!(dt, Array(_, _)) => s.cast(&Float64),
+                    _ => Ok(s.clone()),
+                };
+                Ok(&to_float(left)? / &to_float(right)?)
+            },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Ok(left / right),
             Date | Datetime(_, _) | Float32 | Float64 => Ok(left / right),
@@ -261,7 +278,7 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::predicates::{BatchStats, StatsEvaluator};
+    use polars_io::predicates::{BatchStats, ColumnStats, StatsEvaluator};
 
     use super::*;
 
@@ -337,7 +354,7 @@ mod stats {
         use ChunkCompare as C;
         match op {
             Operator::Eq => apply_operator_stats_eq(min_max, literal),
-            Operator::NotEq => apply_operator_stats_eq(min_max, literal),
+            Operator::NotEq => apply_operator_stats_neq(min_max, literal),
             Operator::Gt => {
                 // Literal is bigger than max value, selection needs all rows.
                 C::gt(literal, min_max).map(|ca| ca.any()).unwrap_or(false)
@@ -363,6 +380,14 @@ mod stats {
         }
     }
 
+    // A bloom filter can only prove that a column chunk doesn't contain a value.
+    fn bloom_filter_may_contain(stats: &ColumnStats, literal: &Series, op: Operator) -> bool {
+        match (op, stats.bloom_filter()) {
+            (Operator::Eq, Some(bloom_filter)) => bloom_filter.may_contain(literal),
+            _ => true,
+        }
+    }
+
     impl BinaryExpr {
         fn impl_should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
             // See: #5864 for the rationale behind this.
@@ -403,27 +428,29 @@ mod stats {
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    let read = match l.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && bloom_filter_may_contain(l, &lit_s, self.op))
                 },
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    let read = match r.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && bloom_filter_may_contain(r, &lit_s, self.op))
                 },
                 // Default: read the file
                 _ => Ok(true),
@@ -192,6 +192,22 @@ impl PhysicalExpr for ColumnExpr {
             )
         })
     }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl polars_io::predicates::StatsEvaluator for ColumnExpr {
+    /// A boolean column used as predicate, can skip the batch if its maximum is `false`.
+    fn should_read(&self, stats: &polars_io::predicates::BatchStats) -> PolarsResult<bool> {
+        match stats.get_stats(&self.name).ok().and_then(|st| st.to_max()) {
+            Some(max) if max.dtype() == &DataType::Boolean => Ok(max.bool()?.get(0) != Some(false)),
+            _ => Ok(true),
+        }
+    }
 }
 
 impl PartitionedAggregation for ColumnExpr {
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use arrow::array::{Array, BooleanArray};
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
+use arrow::legacy::array::default_arrays::FromData;
+use polars_core::frame::group_by::{GroupByMethod, GroupsProxy};
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+use crate::physical_plan::state::ExecutionState;
+use crate::physical_plan::PartitionedAggregation;
+use crate::prelude::AggState::AggregatedScalar;
+use crate::prelude::*;
+
+/// A `sum`, `mean` or `count` of `input.filter(predicate)` in a group-by context, where both the
+/// input and the predicate are element-wise.
+///
+/// Instead of filtering every group, which materializes new groups, the values for which the
+/// predicate doesn't hold are masked out as nulls and the aggregation runs over the original
+/// groups.
+pub(crate) struct MaskedAggregationExpr {
+    input: Arc<dyn PhysicalExpr>,
+    predicate: Arc<dyn PhysicalExpr>,
+    agg_type: GroupByMethod,
+    /// The unfused `AggregationExpr`, used if the fast path doesn't apply.
+    fallback: Arc<dyn PhysicalExpr>,
+}
+
+impl MaskedAggregationExpr {
+    pub fn new(
+        input: Arc<dyn PhysicalExpr>,
+        predicate: Arc<dyn PhysicalExpr>,
+        agg_type: GroupByMethod,
+        fallback: Arc<dyn PhysicalExpr>,
+    ) -> Self {
+        Self {
+            input,
+            predicate,
+            agg_type,
+            fallback,
+        }
+    }
+}
+
+/// The positions where `predicate` is true, with nulls counting as false.
+fn predicate_to_bitmap(predicate: &BooleanChunked) -> Bitmap {
+    let predicate = predicate.rechunk();
+    let arr = predicate.downcast_iter().next().unwrap();
+    match arr.validity() {
+        Some(validity) => arr.values() & validity,
+        None => arr.values().clone(),
+    }
+}
+
+impl PhysicalExpr for MaskedAggregationExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        self.fallback.evaluate(df, state)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let s_f = || self.input.evaluate(df, state);
+        let predicate_f = || self.predicate.evaluate(df, state);
+        let (s, predicate) = POOL.install(|| rayon::join(s_f, predicate_f));
+        let (s, predicate) = (s?, predicate?);
+
+        // Literals that aren't broadcasted yet and non-numeric sums/means take the regular path.
+        let height = df.height();
+        let is_count = matches!(self.agg_type, GroupByMethod::Count { .. });
+        if height == 0
+            || s.len() != height
+            || predicate.len() != height
+            || !(is_count || s.dtype().is_numeric())
+        {
+            return self.fallback.evaluate_on_groups(df, groups, state);
+        }
+        let keep = predicate_to_bitmap(predicate.bool()?);
+
+        // SAFETY: the series have the length of `df`, which the groups are in bounds of.
+        let mut out = unsafe {
+            match self.agg_type {
+                GroupByMethod::Count { include_nulls } => {
+                    let keep = if include_nulls {
+                        keep
+                    } else {
+                        &keep & &predicate_to_bitmap(&s.is_not_null())
+                    };
+                    let keep =
+                        BooleanChunked::with_chunk("", BooleanArray::from_data_default(keep, None));
+                    keep.cast(&IDX_DTYPE)?.agg_sum(groups)
+                },
+                _ => {
+                    let s = s.rechunk();
+                    let arr = &s.chunks()[0];
+                    let validity = combine_validities_and(arr.validity(), Some(&keep));
+                    let masked = Series::try_from(("", arr.with_validity(validity)))?;
+                    match self.agg_type {
+                        GroupByMethod::Sum => masked.agg_sum(groups),
+                        GroupByMethod::Mean => masked.agg_mean(groups),
+                        _ => unreachable!(),
+                    }
+                },
+            }
+        };
+        out.rename(s.name());
+
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(out),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.fallback.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.fallback.as_partitioned_aggregator()
+    }
+}
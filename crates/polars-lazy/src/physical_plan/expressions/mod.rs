@@ -8,6 +8,7 @@ mod count;
 mod filter;
 mod group_iter;
 mod literal;
+mod masked_aggregation;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
 mod slice;
@@ -31,6 +32,7 @@ pub(crate) use column::*;
 pub(crate) use count::*;
 pub(crate) use filter::*;
 pub(crate) use literal::*;
+pub(crate) use masked_aggregation::*;
 use polars_core::frame::group_by::GroupsProxy;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
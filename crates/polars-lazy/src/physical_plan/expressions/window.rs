@@ -3,8 +3,10 @@ use std::sync::Arc;
 
 use arrow::array::PrimitiveArray;
 use polars_core::export::arrow::bitmap::Bitmap;
-use polars_core::frame::group_by::{GroupBy, GroupsProxy};
+use polars_core::frame::group_by::{GroupBy, GroupsIndicator, GroupsProxy};
 use polars_core::prelude::*;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 use polars_core::utils::_split_offsets;
 use polars_core::{downcast_as_macro_arg_physical, POOL};
@@ -12,7 +14,11 @@ use polars_ops::frame::join::{
     default_join_ids, private_left_join_multiple_keys, ChunkJoinOptIds, JoinValidation,
 };
 use polars_ops::frame::SeriesJoin;
+#[cfg(feature = "diff")]
+use polars_ops::series::diff_by_group;
+use polars_ops::series::shift_by_group;
 use polars_utils::format_smartstring;
+use polars_utils::idx_vec::IdxVec;
 use polars_utils::sort::perfect_sort;
 use polars_utils::sync::SyncPtr;
 use rayon::prelude::*;
@@ -31,9 +37,27 @@ pub struct WindowExpr {
     pub(crate) function: Expr,
     pub(crate) phys_function: Arc<dyn PhysicalExpr>,
     pub(crate) mapping: WindowMapping,
+    /// Order of the rows within the groups
+    pub(crate) order_by: Option<(Arc<dyn PhysicalExpr>, SortOptions)>,
+    /// Rows of the group to aggregate for every row
+    pub(crate) frame: Option<WindowFrame>,
     pub(crate) expr: Expr,
 }
 
+enum GroupShiftKind {
+    Shift,
+    #[cfg(feature = "diff")]
+    Diff,
+}
+
+/// A shift or diff of a column over the groups, e.g. `col("x").shift(1).over("g")`.
+struct GroupShift {
+    kind: GroupShiftKind,
+    column: Arc<str>,
+    n: i64,
+    fill_value: Option<Series>,
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 enum MapStrategy {
     // Join by key, this the most expensive
@@ -196,6 +220,102 @@ impl WindowExpr {
         self.map_list_agg_by_arg_sort(out_column, flattened, ac, gb, state, cache_key)
     }
 
+    /// Reorder the rows of every group by the `order_by` expression, ties keep their order.
+    fn order_groups(
+        &self,
+        groups: GroupsProxy,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<GroupsProxy> {
+        let Some((order_by, options)) = &self.order_by else {
+            return Ok(groups);
+        };
+        let order_by = order_by.evaluate(df, state)?;
+        polars_ensure!(
+            order_by.len() == df.height(),
+            expr = self.expr, ShapeMismatch:
+            "the `order_by` expression of a window must have the same length as the DataFrame, \
+            got {} and {}", order_by.len(), df.height()
+        );
+
+        let mut group_ids = vec![0 as IdxSize; df.height()];
+        let mut all = Vec::with_capacity(groups.len());
+        for (group_id, group) in groups.iter().enumerate() {
+            all.push(IdxVec::with_capacity(group.len()));
+            match group {
+                GroupsIndicator::Idx((_, idx)) => {
+                    for &row in idx.iter() {
+                        group_ids[row as usize] = group_id as IdxSize;
+                    }
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    for row in first..first + len {
+                        group_ids[row as usize] = group_id as IdxSize;
+                    }
+                },
+            }
+        }
+        // Visit the rows in sorted order and append every row to its group.
+        let options = SortOptions {
+            maintain_order: true,
+            ..*options
+        };
+        for row in order_by.arg_sort(options).into_no_null_iter() {
+            all[group_ids[row as usize] as usize].push(row);
+        }
+        let first = all
+            .iter()
+            .map(|group| group.as_slice().first().copied().unwrap_or_default())
+            .collect();
+        Ok(GroupsProxy::Idx(GroupsIdx::new(first, all, false)))
+    }
+
+    /// Aggregate the function over the frame of every row. The frames are taken from the rows of
+    /// the groups, so they follow the `order_by` of the window.
+    fn evaluate_frames(
+        &self,
+        frame: WindowFrame,
+        df: &DataFrame,
+        gb: &GroupBy,
+        state: &ExecutionState,
+    ) -> PolarsResult<Series> {
+        let height = df.height();
+        let mut first = vec![0 as IdxSize; height];
+        let mut all = vec![IdxVec::new(); height];
+        for group in gb.get_groups().iter() {
+            let rows = match group {
+                GroupsIndicator::Idx((_, idx)) => Cow::Borrowed(idx.as_slice()),
+                GroupsIndicator::Slice([first, len]) => Cow::Owned((first..first + len).collect()),
+            };
+            let len = rows.len() as i64;
+            for (i, &row) in rows.iter().enumerate() {
+                let i = i as i64;
+                let start = frame.start.map_or(0, |offset| (i + offset).clamp(0, len)) as usize;
+                let end = frame
+                    .end
+                    .map_or(len, |offset| (i + offset + 1).clamp(0, len))
+                    as usize;
+                let frame_rows = &rows[start..end.max(start)];
+                first[row as usize] = frame_rows.first().copied().unwrap_or(row);
+                all[row as usize] = frame_rows.into();
+            }
+        }
+        let groups = GroupsProxy::Idx(GroupsIdx::new(first, all, false));
+
+        let mut ac = self.phys_function.evaluate_on_groups(df, &groups, state)?;
+        polars_ensure!(
+            matches!(ac.agg_state(), AggState::AggregatedScalar(_)),
+            expr = self.expr, InvalidOperation:
+            "a window frame requires an aggregation, e.g. `sum` or `mean`"
+        );
+        let mut out = ac.finalize();
+        polars_ensure!(out.len() == height, agg_len = out.len(), height);
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
+
     fn run_aggregation<'a>(
         &self,
         df: &DataFrame,
@@ -281,6 +401,63 @@ impl WindowExpr {
         agg_col
     }
 
+    /// Check if the function shifts the values of a column within the groups. These are
+    /// computed directly on the groups, without materializing the groups as lists.
+    ///
+    /// `col("x").shift(1).over("g")`
+    /// `col("x").shift_and_fill(1, 0).over("g")`
+    /// `col("x").diff(1, NullBehavior::Ignore).over("g")`
+    fn group_shift(&self) -> Option<GroupShift> {
+        if !matches!(self.mapping, WindowMapping::GroupsToRows) {
+            return None;
+        }
+        let literal = |e: &Expr| match e {
+            Expr::Literal(lv) => lv.to_anyvalue(),
+            _ => None,
+        };
+        let (kind, input, n, fill_value) = match &self.function {
+            Expr::Function {
+                input,
+                function: FunctionExpr::Shift,
+                ..
+            } => (
+                GroupShiftKind::Shift,
+                &input[0],
+                literal(&input[1])?.extract::<i64>()?,
+                None,
+            ),
+            Expr::Function {
+                input,
+                function: FunctionExpr::ShiftAndFill,
+                ..
+            } => {
+                let fill_value = literal(&input[2])?;
+                (
+                    GroupShiftKind::Shift,
+                    &input[0],
+                    literal(&input[1])?.extract::<i64>()?,
+                    Some(Series::from_any_values("", &[fill_value], true).ok()?),
+                )
+            },
+            #[cfg(feature = "diff")]
+            Expr::Function {
+                input,
+                function: FunctionExpr::Diff(n, NullBehavior::Ignore),
+                ..
+            } => (GroupShiftKind::Diff, &input[0], *n, None),
+            _ => return None,
+        };
+        let Expr::Column(column) = input else {
+            return None;
+        };
+        Some(GroupShift {
+            kind,
+            column: column.clone(),
+            n,
+            fill_value,
+        })
+    }
+
     /// Check if the branches have an aggregation
     /// when(a > sum)
     /// then (foo)
@@ -415,12 +592,16 @@ impl PhysicalExpr for WindowExpr {
             .collect::<PolarsResult<Vec<_>>>()?;
 
         // if the keys are sorted
-        let sorted_keys = group_by_columns.iter().all(|s| {
-            matches!(
-                s.is_sorted_flag(),
-                IsSorted::Ascending | IsSorted::Descending
-            )
-        });
+        // with an `order_by` the rows of the groups are no longer in the order of the keys
+        let sorted_keys = self.order_by.is_none()
+            && group_by_columns.iter().all(|s| {
+                matches!(
+                    s.is_sorted_flag(),
+                    IsSorted::Ascending | IsSorted::Descending
+                )
+            });
+        // the cached groups are shared with other windows, so they can't be reordered
+        let cache_window = state.cache_window() && self.order_by.is_none();
         let explicit_list_agg = self.is_explicit_list_agg();
 
         // if we flatten this column we need to make sure the groups are sorted.
@@ -448,7 +629,7 @@ impl PhysicalExpr for WindowExpr {
         };
 
         // Try to get cached grouptuples
-        let (mut groups, _, cache_key) = if state.cache_window() {
+        let (mut groups, _, cache_key) = if cache_window {
             let mut cache_key = String::with_capacity(32 * group_by_columns.len());
             write!(&mut cache_key, "{}", state.branch_idx).unwrap();
             for s in &group_by_columns {
@@ -486,11 +667,36 @@ impl PhysicalExpr for WindowExpr {
         // to make sure that the caches align we sort
         // the groups, so that the cached groups and join keys
         // are consistent among all windows
-        if sort_groups || state.cache_window() {
+        if sort_groups || cache_window {
             groups.sort()
         }
+        let groups = self.order_groups(groups, df, state)?;
         let gb = GroupBy::new(df, group_by_columns.clone(), groups, Some(apply_columns));
 
+        if let Some(frame) = self.frame {
+            let out = self.evaluate_frames(frame, df, &gb, state);
+            cache_gb(gb, state, &cache_key);
+            return out;
+        }
+
+        if let Some(shift) = self.group_shift() {
+            let s = df.column(&shift.column)?;
+            let mut out = match shift.kind {
+                GroupShiftKind::Shift => {
+                    shift_by_group(s, gb.get_groups(), shift.n, shift.fill_value.as_ref())?
+                },
+                #[cfg(feature = "diff")]
+                GroupShiftKind::Diff => {
+                    diff_by_group(s, gb.get_groups(), shift.n, shift.fill_value.as_ref())?
+                },
+            };
+            cache_gb(gb, state, &cache_key);
+            if let Some(name) = &self.out_name {
+                out.rename(name.as_ref());
+            }
+            return Ok(out);
+        }
+
         // If the aggregation creates categoricals and `MapStrategy` is `Join`,
         // the string cache was needed. So we hold it for that case.
         // Worst case is that a categorical is created with indexes from the string
@@ -577,7 +783,7 @@ impl PhysicalExpr for WindowExpr {
                         };
 
                         // try to get cached join_tuples
-                        let join_opt_ids = if state.cache_window() {
+                        let join_opt_ids = if cache_window {
                             let mut jt_map = state.join_tuples.lock().unwrap();
                             // we run sequential and partitioned
                             // and every partition run the cache should be empty so we expect a max of 1.
@@ -597,7 +803,7 @@ impl PhysicalExpr for WindowExpr {
                             out.rename(name.as_ref());
                         }
 
-                        if state.cache_window() {
+                        if cache_window {
                             let mut jt_map = state.join_tuples.lock().unwrap();
                             jt_map.insert(cache_key, join_opt_ids);
                         }
@@ -648,7 +854,8 @@ fn materialize_column(join_opt_ids: &ChunkJoinOptIds, out_column: &Series) -> Se
 }
 
 fn cache_gb(gb: GroupBy, state: &ExecutionState, cache_key: &str) {
-    if state.cache_window() {
+    // windows that don't use the cache have no key
+    if state.cache_window() && !cache_key.is_empty() {
         let groups = gb.take_groups();
         let mut gt_map = state.group_tuples.write().unwrap();
         gt_map.insert(cache_key.to_string(), groups);
@@ -3,17 +3,20 @@ use std::time::Instant;
 
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
+use polars_io::scan_stats::ScanStatistics;
 
 type StartInstant = Instant;
 type EndInstant = Instant;
 
 type Nodes = Vec<String>;
 type Ticks = Vec<(StartInstant, EndInstant)>;
+type Scans = Vec<(String, Arc<ScanStatistics>)>;
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
     data: Arc<Mutex<(Nodes, Ticks)>>,
+    scans: Arc<Mutex<Scans>>,
 }
 
 impl NodeTimer {
@@ -21,9 +24,55 @@ impl NodeTimer {
         Self {
             query_start: Instant::now(),
             data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            scans: Default::default(),
         }
     }
 
+    /// Register a scan node, its readers fill the returned statistics.
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
+    pub(super) fn register_scan(&self, name: String) -> Arc<ScanStatistics> {
+        let scan_stats = Arc::new(ScanStatistics::new());
+        self.scans.lock().unwrap().push((name, scan_stats.clone()));
+        scan_stats
+    }
+
+    /// The statistics of the scan nodes, in the order they were executed.
+    ///
+    /// The unit of the decode time is microseconds.
+    pub(super) fn scan_statistics(&self) -> DataFrame {
+        let scans = self.scans.lock().unwrap();
+        let counter = |name: &str, f: fn(&ScanStatistics) -> u64| {
+            let ca: NoNull<UInt64Chunked> = scans.iter().map(|(_, stats)| f(stats)).collect();
+            let mut ca = ca.into_inner();
+            ca.rename(name);
+            ca.into_series()
+        };
+        DataFrame::new_no_checks(vec![
+            Series::new(
+                "node",
+                scans
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            counter("files_listed", ScanStatistics::files_listed),
+            counter("files_pruned", ScanStatistics::files_pruned),
+            counter("row_groups_read", ScanStatistics::row_groups_read),
+            counter(
+                "row_groups_pruned_by_statistics",
+                ScanStatistics::row_groups_pruned_by_statistics,
+            ),
+            counter(
+                "row_groups_pruned_by_bloom_filter",
+                ScanStatistics::row_groups_pruned_by_bloom_filter,
+            ),
+            counter("bytes_downloaded", ScanStatistics::bytes_downloaded),
+            counter("decode_time", |stats| {
+                stats.decode_time().as_micros() as u64
+            }),
+        ])
+    }
+
     pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
         let mut data = self.data.lock().unwrap();
         let nodes = &mut data.0;
@@ -81,6 +81,23 @@ impl ExpressionConversionState {
     }
 }
 
+/// Whether `node` only consists of columns, scalar literals and element-wise operations, so that
+/// it gives the same result evaluated on the whole frame as on every group separately.
+fn is_elementwise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena.iter(node).all(|(_, ae)| match ae {
+        AExpr::Function { options, .. } | AExpr::AnonymousFunction { options, .. } => {
+            matches!(options.collect_groups, ApplyOptions::ElementWise)
+        },
+        AExpr::Literal(lv) => !matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+        AExpr::Column(_)
+        | AExpr::Alias(_, _)
+        | AExpr::BinaryExpr { .. }
+        | AExpr::Cast { .. }
+        | AExpr::Ternary { .. } => true,
+        _ => false,
+    })
+}
+
 pub(crate) fn create_physical_expr(
     expression: Node,
     ctxt: Context,
@@ -95,6 +112,7 @@ pub(crate) fn create_physical_expr(
         Window {
             mut function,
             partition_by,
+            order_by,
             options,
         } => {
             state.set_window();
@@ -112,8 +130,13 @@ pub(crate) fn create_physical_expr(
             // set again as the state can be reset
             state.set_window();
             match options {
-                WindowType::Over(mapping) => {
-                    // TODO! Order by
+                WindowType::Over(_) | WindowType::Frame(_) => {
+                    let (mapping, frame) = match options {
+                        WindowType::Frame(frame) => (WindowMapping::GroupsToRows, Some(frame)),
+                        WindowType::Over(mapping) => (mapping, None),
+                        #[cfg(feature = "dynamic_group_by")]
+                        WindowType::Rolling(_) => unreachable!(),
+                    };
                     let group_by = create_physical_expressions(
                         &partition_by,
                         Context::Default,
@@ -121,6 +144,18 @@ pub(crate) fn create_physical_expr(
                         schema,
                         state,
                     )?;
+                    let order_by = order_by
+                        .map(|(node, options)| {
+                            let e = create_physical_expr(
+                                node,
+                                Context::Default,
+                                expr_arena,
+                                schema,
+                                state,
+                            )?;
+                            PolarsResult::Ok((e, options))
+                        })
+                        .transpose()?;
                     let mut apply_columns = aexpr_to_leaf_names(function, expr_arena);
                     // sort and then dedup removes consecutive duplicates == all duplicates
                     apply_columns.sort();
@@ -148,6 +183,8 @@ pub(crate) fn create_physical_expr(
                         function: function_expr,
                         phys_function,
                         mapping,
+                        order_by,
+                        frame,
                         expr,
                     }))
                 },
@@ -426,7 +463,25 @@ pub(crate) fn create_physical_expr(
                         })
                         .transpose()?;
                     let agg_method: GroupByMethod = agg.into();
-                    Ok(Arc::new(AggregationExpr::new(input, agg_method, field)))
+                    let agg_expr = Arc::new(AggregationExpr::new(input, agg_method, field));
+
+                    // `col.filter(predicate).agg()`: mask instead of filtering every group.
+                    if let (
+                        Context::Aggregation,
+                        GroupByMethod::Sum | GroupByMethod::Mean | GroupByMethod::Count { .. },
+                        Filter { input, by },
+                    ) = (ctxt, agg_method, expr_arena.get(expr))
+                    {
+                        if is_elementwise(*input, expr_arena) && is_elementwise(*by, expr_arena) {
+                            let input =
+                                create_physical_expr(*input, ctxt, expr_arena, schema, state)?;
+                            let by = create_physical_expr(*by, ctxt, expr_arena, schema, state)?;
+                            return Ok(Arc::new(MaskedAggregationExpr::new(
+                                input, by, agg_method, agg_expr,
+                            )));
+                        }
+                    }
+                    Ok(agg_expr)
                 },
             }
         },
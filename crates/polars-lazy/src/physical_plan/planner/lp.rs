@@ -6,6 +6,56 @@ use super::super::executors::{self, Executor};
 use super::*;
 use crate::utils::*;
 
+/// The literal value of `node`, if it is a (cast) literal.
+fn literal_series(node: Node, expr_arena: &Arena<AExpr>) -> Option<Series> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => Series::from_any_values("", &[lv.to_anyvalue()?], true).ok(),
+        AExpr::Cast {
+            expr, data_type, ..
+        } => literal_series(*expr, expr_arena)?.cast(data_type).ok(),
+        _ => None,
+    }
+}
+
+/// Collect the `column == literal` terms of a conjunction of predicates by column, keeping only
+/// literals of the dtype of their column.
+fn equality_terms(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+    terms: &mut PlHashMap<Arc<str>, Series>,
+) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            equality_terms(*left, expr_arena, schema, terms);
+            equality_terms(*right, expr_arena, schema, terms);
+        },
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::Eq,
+            right,
+        } => {
+            let (column, value) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+                (AExpr::Column(name), _) => (name, *right),
+                (_, AExpr::Column(name)) => (name, *left),
+                _ => return,
+            };
+            if let (Some(key), Some(dtype)) =
+                (literal_series(value, expr_arena), schema.get(column))
+            {
+                if key.dtype() == dtype {
+                    terms.insert(column.clone(), key.with_name(column));
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
 fn partitionable_gb(
     keys: &[Node],
     aggs: &[Node],
@@ -150,12 +200,43 @@ pub fn create_physical_plan(
     let logical_plan = lp_arena.take(root);
     match logical_plan {
         #[cfg(feature = "python")]
-        PythonScan { options, .. } => Ok(Box::new(executors::PythonScanExec { options })),
+        PythonScan {
+            mut options,
+            predicate,
+        } => {
+            let mut predicate_columns = vec![];
+            // pyarrow evaluates its translated predicate. An IO plugin receives the serialized
+            // predicate, but it may prune the data only partially so we apply it again.
+            let predicate = match predicate {
+                Some(pred) if options.io_plugin => {
+                    #[cfg(feature = "serde")]
+                    {
+                        options.predicate =
+                            serde_json::to_string(&node_to_expr(pred, expr_arena)).ok();
+                    }
+                    predicate_columns = aexpr_to_leaf_names(pred, expr_arena);
+                    let mut state = ExpressionConversionState::default();
+                    Some(create_physical_expr(
+                        pred,
+                        Context::Default,
+                        expr_arena,
+                        Some(&options.schema),
+                        &mut state,
+                    )?)
+                },
+                _ => None,
+            };
+            Ok(Box::new(executors::PythonScanExec {
+                options,
+                predicate,
+                predicate_columns,
+            }))
+        },
         Sink { payload, .. } => match payload {
             SinkType::Memory => {
                 polars_bail!(InvalidOperation: "memory sink not supported in the standard engine")
             },
-            SinkType::File { file_type, .. } => {
+            SinkType::File { file_type, .. } | SinkType::Partitioned { file_type, .. } => {
                 polars_bail!(InvalidOperation:
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_parquet()'"
                 )
@@ -241,17 +322,13 @@ pub fn create_physical_plan(
                     }))
                 },
                 #[cfg(feature = "ipc")]
-                FileScan::Ipc { options } => {
-                    assert_eq!(paths.len(), 1);
-                    let path = paths[0].clone();
-                    Ok(Box::new(executors::IpcExec {
-                        path,
-                        schema: file_info.schema,
-                        predicate,
-                        options,
-                        file_options,
-                    }))
-                },
+                FileScan::Ipc { options } => Ok(Box::new(executors::IpcExec {
+                    paths,
+                    file_info,
+                    predicate,
+                    options,
+                    file_options,
+                })),
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet {
                     options,
@@ -266,6 +343,12 @@ pub fn create_physical_plan(
                     file_options,
                     metadata,
                 ))),
+                #[cfg(feature = "flight")]
+                FileScan::Flight { options } => Ok(Box::new(executors::FlightExec {
+                    options,
+                    file_options,
+                    predicate,
+                })),
                 FileScan::Anonymous { function, .. } => {
                     Ok(Box::new(executors::AnonymousScanExec {
                         function,
@@ -320,6 +403,35 @@ pub fn create_physical_plan(
             schema,
             ..
         } => {
+            // An index on columns that the predicate compares to literals narrows down the rows
+            // to filter.
+            let index_keys = predicate.and_then(|pred| {
+                let mut terms = PlHashMap::new();
+                equality_terms(pred, expr_arena, &schema, &mut terms);
+                let candidates = df
+                    .indexes()
+                    .filter(|index| {
+                        index
+                            .column_names()
+                            .iter()
+                            .all(|name| terms.contains_key(*name))
+                    })
+                    .collect::<Vec<_>>();
+                let index = candidates
+                    .iter()
+                    .find(|index| index.kind() == IndexKind::Hash)
+                    .or_else(|| candidates.first())?;
+                let columns = index
+                    .column_names()
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>();
+                let keys = columns
+                    .iter()
+                    .map(|name| terms[name.as_str()].clone())
+                    .collect();
+                Some((columns, keys))
+            });
             let mut state = ExpressionConversionState::default();
             let selection = predicate
                 .map(|pred| {
@@ -336,6 +448,7 @@ pub fn create_physical_plan(
                 df,
                 projection,
                 selection,
+                index_keys,
                 predicate_has_windows: state.has_windows,
             }))
         },
@@ -394,6 +507,7 @@ pub fn create_physical_plan(
             )?;
 
             let _slice = options.slice;
+            let parallel_apply = options.parallel_apply;
             #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.dynamic {
                 let input = create_physical_plan(input, lp_arena, expr_arena)?;
@@ -405,6 +519,7 @@ pub fn create_physical_plan(
                     input_schema,
                     slice: _slice,
                     apply,
+                    parallel_apply,
                 }));
             }
 
@@ -419,11 +534,20 @@ pub fn create_physical_plan(
                     input_schema,
                     slice: _slice,
                     apply,
+                    parallel_apply,
                 }));
             }
 
+            // The index of the aggregation that filters the groups.
+            let having = options
+                .having
+                .as_ref()
+                .map(|name| PolarsResult::Ok(schema.try_get_full(name)?.0 - keys.len()))
+                .transpose()?;
+
             // We first check if we can partition the group_by on the latest moment.
-            let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
+            let partitionable = having.is_none()
+                && partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
                 let from_partitioned_ds = (&*lp_arena).iter(input).any(|(_, lp)| {
                     if let Union { options, .. } = lp {
@@ -460,9 +584,11 @@ pub fn create_physical_plan(
                     phys_keys,
                     phys_aggs,
                     apply,
+                    parallel_apply,
                     maintain_order,
                     input_schema,
                     options.slice,
+                    having,
                 )))
             }
         },
@@ -7,6 +7,8 @@ use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::frame::group_by::GroupsProxy;
 use polars_core::prelude::*;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::scan_stats::ScanStatistics;
 use polars_ops::prelude::ChunkJoinOptIds;
 #[cfg(any(
     feature = "parquet",
@@ -125,7 +127,16 @@ impl ExecutionState {
     pub(crate) fn time_nodes(&mut self) {
         self.node_timer = Some(NodeTimer::new())
     }
-    pub(super) fn has_node_timer(&self) -> bool {
+
+    /// Whether the executed nodes are timed or traced, in which case the executors [`record`]
+    /// their execution under a name.
+    ///
+    /// [`record`]: ExecutionState::record
+    pub(super) fn records_nodes(&self) -> bool {
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::INFO) {
+            return true;
+        }
         self.node_timer.is_some()
     }
 
@@ -133,6 +144,19 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// The statistics of the scans that were executed while the nodes were timed.
+    pub(crate) fn scan_statistics(&self) -> DataFrame {
+        self.node_timer.as_ref().unwrap().scan_statistics()
+    }
+
+    /// Statistics for a scan node to fill, if the nodes are timed.
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
+    pub(super) fn register_scan(&self, name: &str) -> Option<Arc<ScanStatistics>> {
+        self.node_timer
+            .as_ref()
+            .map(|timer| timer.register_scan(name.to_string()))
+    }
+
     // This is wrong when the U64 overflows which will never happen.
     pub(super) fn should_stop(&self) -> PolarsResult<()> {
         polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
@@ -144,6 +168,8 @@ impl ExecutionState {
     }
 
     pub(super) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("execute", node = name.as_ref()).entered();
         match &self.node_timer {
             None => func(),
             Some(timer) => {
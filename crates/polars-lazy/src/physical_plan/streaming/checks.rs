@@ -1,3 +1,4 @@
+use polars_core::prelude::*;
 use polars_ops::prelude::*;
 use polars_plan::prelude::*;
 
@@ -82,5 +83,131 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         JoinType::Inner | JoinType::Left => true,
         _ => false,
     };
-    supported && !args.validation.needs_checks()
+    supported && !args.validation.needs_checks() && !args.hints.disable_streaming
+}
+
+/// Whether the streaming join produces the rows in the same order as the in-memory engine.
+pub(super) fn join_maintains_order(options: &JoinOptions) -> bool {
+    let hints = &options.args.hints;
+    match options.args.how {
+        // both engines stream the left table
+        JoinType::Left => true,
+        // both engines probe the larger table, but the streaming engine decides that on the
+        // estimated sizes. So only if the build side is given the order is the same.
+        JoinType::Inner => hints.build_side().is_some() && !hints.force_sort_merge,
+        _ => false,
+    }
+}
+
+/// Whether the `Aggregate` node can be executed by the streaming hash aggregation.
+#[allow(unused_variables)]
+pub(super) fn streamable_aggregate(
+    lp: &ALogicalPlan,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let ALogicalPlan::Aggregate {
+        input,
+        keys,
+        aggs,
+        maintain_order: false,
+        apply: None,
+        schema,
+        options,
+        ..
+    } = lp
+    else {
+        return false;
+    };
+    #[cfg(feature = "dtype-categorical")]
+    let string_cache = polars_core::using_string_cache();
+    #[cfg(not(feature = "dtype-categorical"))]
+    let string_cache = true;
+
+    #[allow(unused_variables)]
+    fn allowed_dtype(dt: &DataType, string_cache: bool) -> bool {
+        match dt {
+            #[cfg(feature = "object")]
+            DataType::Object(_, _) => false,
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) => string_cache,
+            DataType::List(inner) => allowed_dtype(inner, string_cache),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => fields
+                .iter()
+                .all(|fld| allowed_dtype(fld.data_type(), string_cache)),
+            // We need to be able to sink to disk or produce the aggregate return dtype.
+            DataType::Unknown => false,
+            _ => true,
+        }
+    }
+    let input_schema = lp_arena.get(*input).schema(lp_arena);
+    #[allow(unused_mut)]
+    let mut can_stream = options.having.is_none();
+
+    #[cfg(feature = "dynamic_group_by")]
+    {
+        if options.rolling.is_some() || options.dynamic.is_some() {
+            can_stream = false
+        }
+    }
+
+    let valid_agg = || {
+        aggs.iter().all(|node| {
+            polars_pipe::pipeline::can_convert_to_hash_agg(*node, expr_arena, &input_schema)
+        })
+    };
+
+    let valid_key = || {
+        keys.iter().all(|node| {
+            expr_arena
+                .get(*node)
+                .get_type(schema, Context::Default, expr_arena)
+                // ensure we don't group_by list
+                .map(|dt| !matches!(dt, DataType::List(_)))
+                .unwrap_or(false)
+        })
+    };
+
+    let valid_types = || {
+        schema
+            .iter_dtypes()
+            .all(|dt| allowed_dtype(dt, string_cache))
+    };
+
+    can_stream && valid_agg() && valid_key() && valid_types()
+}
+
+/// Whether the streaming engine can execute this node, its inputs are not checked.
+pub(crate) fn is_streamable_node(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        Selection { predicate, .. } => is_streamable(*predicate, expr_arena, Context::Default),
+        HStack { exprs, .. } => all_streamable(exprs, expr_arena, Context::Default),
+        Projection { expr, .. } => all_streamable(expr, expr_arena, Context::Default),
+        Slice { offset, .. } => *offset >= 0,
+        Sink { .. } | DataFrameScan { .. } | Union { .. } => true,
+        Sort {
+            by_column, args, ..
+        } => is_streamable_sort(args) && all_column(by_column, expr_arena),
+        MapFunction { function, .. } => {
+            matches!(function, FunctionNode::Rechunk) || function.is_streamable()
+        },
+        // the streaming sources don't add the file path column
+        Scan {
+            scan_type,
+            file_options,
+            ..
+        } => scan_type.streamable() && file_options.include_file_paths.is_none(),
+        Join { options, .. } => streamable_join(&options.args),
+        Distinct { options, .. } => {
+            !options.maintain_order && !matches!(options.keep_strategy, UniqueKeepStrategy::None)
+        },
+        lp @ Aggregate { .. } => streamable_aggregate(lp, lp_arena, expr_arena),
+        _ => false,
+    }
 }
@@ -14,6 +14,7 @@ use polars_utils::IdxSize;
 
 use crate::physical_plan::planner::{create_physical_expr, ExpressionConversionState};
 use crate::physical_plan::state::ExecutionState;
+use crate::physical_plan::streaming::checks::join_maintains_order;
 use crate::physical_plan::streaming::tree::{PipelineNode, Tree};
 use crate::prelude::*;
 
@@ -98,11 +99,26 @@ fn jit_insert_slice(
     sink_nodes.push((operator_offset + 1, slice_node, Rc::new(RefCell::new(1))));
 }
 
+/// Whether the streaming engine returns the rows of this node in the same order as the
+/// in-memory engine.
+fn node_maintains_order(pipeline_node: PipelineNode, lp_arena: &Arena<ALogicalPlan>) -> bool {
+    match pipeline_node {
+        // the inputs of the union are streamed concurrently
+        PipelineNode::Union(_) => false,
+        PipelineNode::Sink(node) => match lp_arena.get(node) {
+            ALogicalPlan::Join { options, .. } => join_maintains_order(options),
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
 pub(super) fn construct(
     tree: Tree,
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    options: StreamingOptions,
 ) -> PolarsResult<Option<Node>> {
     use ALogicalPlan::*;
 
@@ -127,6 +143,14 @@ pub(super) fn construct(
         }
     }
 
+    let maintains_order = options.maintain_order
+        || tree.iter().all(|branch| {
+            branch
+                .operators_sinks
+                .iter()
+                .all(|node| node_maintains_order(*node, lp_arena))
+        });
+
     // shared sinks are stored in a cache, so that they share info
     let mut sink_cache = PlHashMap::new();
     let mut final_sink = None;
@@ -162,13 +186,25 @@ pub(super) fn construct(
                 },
                 PipelineNode::Operator(node) => {
                     operator_nodes.push(node);
-                    let op = get_operator(node, lp_arena, expr_arena, &to_physical_piped_expr)?;
+                    let op = get_operator(
+                        node,
+                        lp_arena,
+                        expr_arena,
+                        &to_physical_piped_expr,
+                        &options,
+                    )?;
                     operators.push(op);
                 },
                 PipelineNode::Union(node) => {
                     operator_nodes.push(node);
                     jit_insert_slice(node, lp_arena, &mut sink_nodes, operator_offset);
-                    let op = get_operator(node, lp_arena, expr_arena, &to_physical_piped_expr)?;
+                    let op = get_operator(
+                        node,
+                        lp_arena,
+                        expr_arena,
+                        &to_physical_piped_expr,
+                        &options,
+                    )?;
                     operators.push(op);
                 },
                 PipelineNode::RhsJoin(node) => {
@@ -191,6 +227,7 @@ pub(super) fn construct(
             to_physical_piped_expr,
             is_verbose,
             &mut sink_cache,
+            &options,
         )?;
         pipelines.push((execution_id, pipeline));
     }
@@ -235,7 +272,8 @@ pub(super) fn construct(
         .get(insertion_location)
         .schema(lp_arena)
         .into_owned();
-    let pipeline_node = get_pipeline_node(lp_arena, most_left, schema, original_lp);
+    let pipeline_node =
+        get_pipeline_node(lp_arena, most_left, schema, original_lp, maintains_order);
     lp_arena.replace(insertion_location, pipeline_node);
 
     Ok(Some(final_sink))
@@ -256,6 +294,7 @@ fn get_pipeline_node(
     mut pipeline: PipeLine,
     schema: SchemaRef,
     original_lp: Option<LogicalPlan>,
+    maintains_order: bool,
 ) -> ALogicalPlan {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -280,6 +319,7 @@ fn get_pipeline_node(
             }),
             schema,
             original: original_lp.map(Arc::new),
+            maintains_order,
         },
         input: dummy,
     }
@@ -1,6 +1,7 @@
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
 use polars_pipe::pipeline::swap_join_order;
+use polars_plan::frame::StreamingOptions;
 use polars_plan::prelude::*;
 
 use super::checks::*;
@@ -58,6 +59,17 @@ fn process_non_streamable_node(
     lp: &ALogicalPlan,
 ) {
     lp.copy_inputs(scratch);
+    process_non_streamable_inputs(current_idx, state, stack, scratch, pipeline_trees)
+}
+
+// Create new streaming subtrees for the inputs in `scratch`.
+fn process_non_streamable_inputs(
+    current_idx: &mut CurrentIdx,
+    state: &mut Branch,
+    stack: &mut Vec<StackFrame>,
+    scratch: &mut Vec<Node>,
+    pipeline_trees: &mut Vec<Vec<Branch>>,
+) {
     while let Some(input) = scratch.pop() {
         if state.streamable {
             *current_idx += 1;
@@ -106,8 +118,12 @@ pub(crate) fn insert_streaming_nodes(
     // whether the full plan needs to be translated
     // to streaming
     allow_partial: bool,
+    options: StreamingOptions,
 ) -> PolarsResult<bool> {
     scratch.clear();
+    // if the order must be maintained, operations that don't preserve the order in
+    // the streaming engine are left to the in-memory engine.
+    let maintain_order = options.maintain_order;
 
     // this is needed to determine which side of the joins should be
     // traversed first
@@ -220,6 +236,32 @@ pub(crate) fn insert_streaming_nodes(
                 state.streamable = true;
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            // The inputs of a merge sorted are a union of which every input is a sorted
+            // chunk, these are run in memory so that the chunk structure is kept.
+            #[cfg(feature = "merge_sorted")]
+            MapFunction {
+                input,
+                function: FunctionNode::MergeSorted { .. },
+            } if allow_partial && matches!(lp_arena.get(*input), Union { .. }) => {
+                let Union { inputs, .. } = lp_arena.get(*input) else {
+                    unreachable!()
+                };
+                scratch.extend(inputs.iter().map(|input| match lp_arena.get(*input) {
+                    // keep the rechunk so that the left side is a single chunk
+                    MapFunction {
+                        input,
+                        function: FunctionNode::Rechunk,
+                    } => *input,
+                    _ => *input,
+                }));
+                process_non_streamable_inputs(
+                    &mut current_idx,
+                    &mut state,
+                    &mut stack,
+                    scratch,
+                    &mut pipeline_trees,
+                )
+            },
             // Streamable functions will be converted
             lp @ MapFunction { input, function } => {
                 if function.is_streamable() {
@@ -241,7 +283,7 @@ pub(crate) fn insert_streaming_nodes(
                 file_options: options,
                 scan_type,
                 ..
-            } if scan_type.streamable() => {
+            } if scan_type.streamable() && options.include_file_paths.is_none() => {
                 if state.streamable {
                     #[cfg(feature = "csv")]
                     if matches!(scan_type, FileScan::Csv { .. }) {
@@ -266,7 +308,9 @@ pub(crate) fn insert_streaming_nodes(
                 input_right,
                 options,
                 ..
-            } if streamable_join(&options.args) => {
+            } if streamable_join(&options.args)
+                && (!maintain_order || join_maintains_order(options)) =>
+            {
                 let input_left = *input_left;
                 let input_right = *input_right;
                 state.streamable = true;
@@ -320,7 +364,7 @@ pub(crate) fn insert_streaming_nodes(
                         ..
                     },
                 ..
-            } if *offset >= 0 => {
+            } if *offset >= 0 && !maintain_order => {
                 insert_slice(root, *offset, *len as IdxSize, lp_arena, &mut state);
                 state.streamable = true;
                 let Union { inputs, .. } = lp_arena.get(root) else {
@@ -344,7 +388,7 @@ pub(crate) fn insert_streaming_nodes(
             Union {
                 inputs,
                 options: UnionOptions { slice: None, .. },
-            } => {
+            } if !maintain_order => {
                 {
                     state.streamable = true;
                     for (i, input) in inputs.iter().enumerate() {
@@ -371,78 +415,13 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
-            #[allow(unused_variables)]
             lp @ Aggregate {
                 input,
-                keys,
-                aggs,
                 maintain_order: false,
                 apply: None,
-                schema,
-                options,
                 ..
             } => {
-                #[cfg(feature = "dtype-categorical")]
-                let string_cache = polars_core::using_string_cache();
-                #[cfg(not(feature = "dtype-categorical"))]
-                let string_cache = true;
-
-                #[allow(unused_variables)]
-                fn allowed_dtype(dt: &DataType, string_cache: bool) -> bool {
-                    match dt {
-                        #[cfg(feature = "object")]
-                        DataType::Object(_, _) => false,
-                        #[cfg(feature = "dtype-categorical")]
-                        DataType::Categorical(_, _) => string_cache,
-                        DataType::List(inner) => allowed_dtype(inner, string_cache),
-                        #[cfg(feature = "dtype-struct")]
-                        DataType::Struct(fields) => fields
-                            .iter()
-                            .all(|fld| allowed_dtype(fld.data_type(), string_cache)),
-                        // We need to be able to sink to disk or produce the aggregate return dtype.
-                        DataType::Unknown => false,
-                        _ => true,
-                    }
-                }
-                let input_schema = lp_arena.get(*input).schema(lp_arena);
-                #[allow(unused_mut)]
-                let mut can_stream = true;
-
-                #[cfg(feature = "dynamic_group_by")]
-                {
-                    if options.rolling.is_some() || options.dynamic.is_some() {
-                        can_stream = false
-                    }
-                }
-
-                let valid_agg = || {
-                    aggs.iter().all(|node| {
-                        polars_pipe::pipeline::can_convert_to_hash_agg(
-                            *node,
-                            expr_arena,
-                            &input_schema,
-                        )
-                    })
-                };
-
-                let valid_key = || {
-                    keys.iter().all(|node| {
-                        expr_arena
-                            .get(*node)
-                            .get_type(schema, Context::Default, expr_arena)
-                            // ensure we don't group_by list
-                            .map(|dt| !matches!(dt, DataType::List(_)))
-                            .unwrap_or(false)
-                    })
-                };
-
-                let valid_types = || {
-                    schema
-                        .iter_dtypes()
-                        .all(|dt| allowed_dtype(dt, string_cache))
-                };
-
-                if can_stream && valid_agg() && valid_key() && valid_types() {
+                if streamable_aggregate(lp, lp_arena, expr_arena) {
                     state.streamable = true;
                     state.operators_sinks.push(PipelineNode::Sink(root));
                     stack.push(StackFrame::new(*input, state, current_idx))
@@ -478,7 +457,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, options)?
+                .is_some()
         {
             inserted = true;
         }
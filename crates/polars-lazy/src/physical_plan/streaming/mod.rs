@@ -3,4 +3,5 @@ mod construct_pipeline;
 mod convert_alp;
 mod tree;
 
+pub(crate) use checks::is_streamable_node;
 pub(crate) use convert_alp::insert_streaming_nodes;
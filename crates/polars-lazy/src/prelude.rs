@@ -1,4 +1,6 @@
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "delta")]
+pub use polars_io::delta::DeltaVersion;
+pub use polars_ops::prelude::{JoinArgs, JoinBuildSide, JoinHints, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 pub use polars_plan::logical_plan::{
@@ -10,9 +12,11 @@ pub use polars_plan::prelude::CsvWriterOptions;
 pub use polars_plan::prelude::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_plan::prelude::JsonWriterOptions;
-#[cfg(feature = "parquet")]
-pub use polars_plan::prelude::ParquetWriteOptions;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(feature = "parquet")]
+pub use polars_plan::prelude::{MissingColumns, ParquetWriteOptions};
+#[cfg(feature = "fill_gaps")]
+pub use polars_time::FillGapsArgs;
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
 #[cfg(feature = "dynamic_group_by")]
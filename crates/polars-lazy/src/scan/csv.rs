@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
-use polars_io::csv::utils::infer_file_schema;
+use polars_io::csv::utils::{compile_dtype_overwrite_regex, infer_file_schema};
 use polars_io::csv::{CommentPrefix, CsvEncoding, NullValues};
 use polars_io::utils::get_reader_bytes;
 use polars_io::RowIndex;
@@ -22,6 +22,7 @@ pub struct LazyCsvReader<'a> {
     cache: bool,
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
+    dtype_overwrite_regex: Option<Vec<(String, DataType)>>,
     low_memory: bool,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
@@ -35,7 +36,9 @@ pub struct LazyCsvReader<'a> {
     encoding: CsvEncoding,
     row_index: Option<RowIndex>,
     try_parse_dates: bool,
+    decimal_comma: bool,
     raise_if_empty: bool,
+    include_file_paths: Option<Arc<str>>,
 }
 
 #[cfg(feature = "csv")]
@@ -56,6 +59,7 @@ impl<'a> LazyCsvReader<'a> {
             cache: true,
             schema: None,
             schema_overwrite: None,
+            dtype_overwrite_regex: None,
             low_memory: false,
             comment_prefix: None,
             quote_char: Some(b'"'),
@@ -68,8 +72,10 @@ impl<'a> LazyCsvReader<'a> {
             encoding: CsvEncoding::Utf8,
             row_index: None,
             try_parse_dates: false,
+            decimal_comma: false,
             raise_if_empty: true,
             truncate_ragged_lines: false,
+            include_file_paths: None,
         }
     }
 
@@ -87,6 +93,13 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Add a column with this name that holds the path of the file a row was read from.
+    #[must_use]
+    pub fn with_include_file_paths(mut self, include_file_paths: Option<Arc<str>>) -> Self {
+        self.include_file_paths = include_file_paths;
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -133,6 +146,15 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Overwrite the dtype of every column whose name matches a regex. This is useful for wide
+    /// files where many columns share a prefix. The first matching regex wins, and the dtypes
+    /// given by [`LazyCsvReader::with_dtype_overwrite`] take precedence.
+    #[must_use]
+    pub fn with_dtype_overwrite_regex(mut self, dtypes: Option<Vec<(String, DataType)>>) -> Self {
+        self.dtype_overwrite_regex = dtypes;
+        self
+    }
+
     /// Set whether the CSV file has headers
     #[must_use]
     pub fn has_header(mut self, has_header: bool) -> Self {
@@ -216,6 +238,14 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Parse floats with a comma as the decimal separator, e.g. `3,14`.
+    /// This can't be combined with a comma as the field separator.
+    #[must_use]
+    pub fn with_decimal_comma(mut self, toggle: bool) -> Self {
+        self.decimal_comma = toggle;
+        self
+    }
+
     /// Raise an error if CSV is empty (otherwise return an empty frame)
     #[must_use]
     pub fn raise_if_empty(mut self, toggle: bool) -> Self {
@@ -256,6 +286,7 @@ impl<'a> LazyCsvReader<'a> {
             self.has_header,
             // we set it to None and modify them after the schema is updated
             None,
+            None,
             &mut skip_rows,
             self.skip_rows_after_header,
             self.comment_prefix.as_ref(),
@@ -263,11 +294,21 @@ impl<'a> LazyCsvReader<'a> {
             self.eol_char,
             None,
             self.try_parse_dates,
+            self.decimal_comma,
             self.raise_if_empty,
         )?;
         let mut schema = f(schema)?;
 
         // the dtypes set may be for the new names, so update again
+        if let Some(dtypes) = self.dtype_overwrite_regex.as_deref() {
+            let dtypes = compile_dtype_overwrite_regex(dtypes)?;
+            for i in 0..schema.len() {
+                let (name, dtype) = schema.get_at_index_mut(i).unwrap();
+                if let Some((_, new)) = dtypes.iter().find(|(re, _)| re.is_match(name)) {
+                    *dtype = new.clone();
+                }
+            }
+        }
         if let Some(overwrite_schema) = self.schema_overwrite {
             for (name, dtype) in overwrite_schema.iter() {
                 schema.with_column(name.clone(), dtype.clone());
@@ -290,6 +331,7 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.cache,
             self.schema,
             self.schema_overwrite,
+            self.dtype_overwrite_regex.as_deref(),
             self.low_memory,
             self.comment_prefix,
             self.quote_char,
@@ -301,6 +343,7 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.encoding,
             self.row_index,
             self.try_parse_dates,
+            self.decimal_comma,
             self.raise_if_empty,
             self.truncate_ragged_lines,
         )?
@@ -350,6 +393,10 @@ impl LazyFileListReader for LazyCsvReader<'_> {
         self.row_index.as_ref()
     }
 
+    fn include_file_paths(&self) -> Option<&str> {
+        self.include_file_paths.as_deref()
+    }
+
     fn concat_impl(&self, lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
         // set to false, as the csv parser has full thread utilization
         concat_impl(&lfs, self.rechunk(), false, true, false)
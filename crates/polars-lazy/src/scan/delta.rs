@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::delta::{DeltaFile, DeltaSnapshot, DeltaVersion};
+use polars_io::parquet::ParallelStrategy;
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// The version of the table to read, by number or by commit timestamp.
+    pub version: DeltaVersion,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub parallel: ParallelStrategy,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub low_memory: bool,
+    pub use_statistics: bool,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: DeltaVersion::Latest,
+            n_rows: None,
+            cache: true,
+            parallel: Default::default(),
+            rechunk: false,
+            row_index: None,
+            low_memory: false,
+            use_statistics: true,
+        }
+    }
+}
+
+/// Scan a data file of a Delta table and conform it to the schema of the table.
+fn scan_delta_file(
+    file: &DeltaFile,
+    schema: &Schema,
+    args: &ScanArgsDelta,
+) -> PolarsResult<LazyFrame> {
+    let scan_args = ScanArgsParquet {
+        cache: args.cache,
+        parallel: args.parallel,
+        low_memory: args.low_memory,
+        use_statistics: args.use_statistics,
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(&file.path, scan_args)?;
+    let file_schema = lf.schema()?;
+    let partition_columns = file.partition_columns(schema)?;
+
+    let exprs = schema
+        .iter()
+        .map(|(name, dtype)| {
+            if let Some(s) = partition_columns.iter().find(|s| s.name() == name.as_str()) {
+                lit(s.clone())
+            } else if file_schema.contains(name) {
+                col(name).cast(dtype.clone())
+            } else {
+                // The column was added to the table after this file was written.
+                lit(NULL).cast(dtype.clone()).alias(name)
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(lf.select(exprs))
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a scan of a [Delta Lake](https://delta.io) table.
+    ///
+    /// The active data files of the table version are read from the transaction log and scanned
+    /// as parquet, the partition values of a file are added as constant columns.
+    pub fn scan_delta(path: impl AsRef<Path>, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot = DeltaSnapshot::read(path.as_ref(), args.version)?;
+        let mut lf = if snapshot.files.is_empty() {
+            DataFrame::from(&snapshot.schema).lazy()
+        } else {
+            let lfs = snapshot
+                .files
+                .iter()
+                .map(|file| {
+                    scan_delta_file(file, &snapshot.schema, &args).map_err(|e| {
+                        polars_err!(
+                            ComputeError: "error while reading {}: {}", file.path.display(), e
+                        )
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            concat_impl(&lfs, args.rechunk, true, true, false)?
+        };
+
+        if let Some(n_rows) = args.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize)
+        };
+        if let Some(rc) = args.row_index {
+            lf = lf.with_row_index(&rc.name, Some(rc.offset))
+        };
+        Ok(lf)
+    }
+}
@@ -9,6 +9,14 @@ use crate::prelude::*;
 
 pub type PathIterator = Box<dyn Iterator<Item = PolarsResult<PathBuf>>>;
 
+/// Add a column with the path of the scanned file, if a name for it is given.
+fn add_file_path_column(lf: LazyFrame, name: Option<&str>, path: &Path) -> LazyFrame {
+    match name {
+        Some(name) => lf.with_column(lit(path.to_string_lossy().as_ref()).alias(name)),
+        None => lf,
+    }
+}
+
 // cloud_options is used only with async feature
 #[allow(unused_variables)]
 fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<PathIterator> {
@@ -39,7 +47,8 @@ pub trait LazyFileListReader: Clone {
             let lfs = paths
                 .map(|r| {
                     let path = r?;
-                    self.clone()
+                    let lf = self
+                        .clone()
                         .with_path(path.clone())
                         .with_rechunk(false)
                         .finish_no_glob()
@@ -47,7 +56,8 @@ pub trait LazyFileListReader: Clone {
                             polars_err!(
                                 ComputeError: "error while reading {}: {}", path.display(), e
                             )
-                        })
+                        })?;
+                    Ok(add_file_path_column(lf, self.include_file_paths(), &path))
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -66,7 +76,10 @@ pub trait LazyFileListReader: Clone {
 
             Ok(lf)
         } else {
-            self.finish_no_glob()
+            let name = self.include_file_paths().map(|name| name.to_string());
+            let path = self.path().to_path_buf();
+            let lf = self.finish_no_glob()?;
+            Ok(add_file_path_column(lf, name.as_deref(), &path))
         }
     }
 
@@ -114,6 +127,11 @@ pub trait LazyFileListReader: Clone {
     /// Add a row index column.
     fn row_index(&self) -> Option<&RowIndex>;
 
+    /// Name of the column with the path of the file a row was read from, if it is added.
+    fn include_file_paths(&self) -> Option<&str> {
+        None
+    }
+
     /// [CloudOptions] used to list files.
     fn cloud_options(&self) -> Option<&CloudOptions> {
         None
@@ -0,0 +1,37 @@
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsFlight {
+    /// The schema of the stream. If `None`, the stream is requested once to read its schema
+    /// when the query is built, so tickets that can be redeemed only once need a schema.
+    pub schema: Option<SchemaRef>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the stream of record batches that the
+    /// [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) server at `endpoint`,
+    /// e.g. `grpc://localhost:8815`, returns for `ticket`.
+    ///
+    /// The record batches are decoded as they arrive, so in the streaming engine a query
+    /// result is processed without first being read into memory.
+    pub fn scan_flight(
+        endpoint: impl Into<String>,
+        ticket: impl Into<Vec<u8>>,
+        args: ScanArgsFlight,
+    ) -> PolarsResult<Self> {
+        let options = FlightScanOptions {
+            endpoint: endpoint.into(),
+            ticket: ticket.into(),
+        };
+        let lf: LazyFrame =
+            LogicalPlanBuilder::scan_flight(options, args.schema, args.n_rows, args.row_index)?
+                .build()
+                .into();
+        Ok(lf)
+    }
+}
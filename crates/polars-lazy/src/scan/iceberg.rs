@@ -0,0 +1,151 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::iceberg::{IcebergFile, IcebergSnapshot};
+use polars_io::parquet::ParallelStrategy;
+use polars_io::predicates::PhysicalIoExpr;
+use polars_io::RowIndex;
+
+use crate::physical_plan::expressions::phys_expr_to_io_expr;
+use crate::physical_plan::planner::create_physical_expr;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// The id of the snapshot to read, the current snapshot if `None`.
+    pub snapshot_id: Option<i64>,
+    /// Only read the data files that may contain rows for which this predicate holds, based on
+    /// their partition values and column statistics. The predicate is also applied to the rows.
+    pub predicate: Option<Expr>,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub parallel: ParallelStrategy,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub low_memory: bool,
+    pub use_statistics: bool,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            predicate: None,
+            n_rows: None,
+            cache: true,
+            parallel: Default::default(),
+            rechunk: false,
+            row_index: None,
+            low_memory: false,
+            use_statistics: true,
+        }
+    }
+}
+
+/// Create the evaluator of `predicate` that decides from the statistics of a data file whether
+/// it is read. Returns `None` if the predicate cannot be evaluated on statistics.
+fn stats_predicate(predicate: &Expr, schema: &SchemaRef) -> Option<Arc<dyn PhysicalIoExpr>> {
+    let mut expr_arena = Arena::with_capacity(16);
+    let node = to_aexpr(predicate.clone(), &mut expr_arena);
+    let phys_expr = create_physical_expr(
+        node,
+        Context::Default,
+        &expr_arena,
+        Some(schema),
+        &mut Default::default(),
+    )
+    .ok()?;
+    Some(phys_expr_to_io_expr(phys_expr))
+}
+
+/// Scan a data file of an Iceberg table and conform it to the schema of the table.
+fn scan_iceberg_file(
+    file: &IcebergFile,
+    schema: &Schema,
+    args: &ScanArgsIceberg,
+) -> PolarsResult<LazyFrame> {
+    let scan_args = ScanArgsParquet {
+        cache: args.cache,
+        parallel: args.parallel,
+        low_memory: args.low_memory,
+        use_statistics: args.use_statistics,
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(&file.path, scan_args)?;
+    let file_schema = lf.schema()?;
+
+    let exprs = schema
+        .iter()
+        .map(|(name, dtype)| {
+            if file_schema.contains(name) {
+                col(name).cast(dtype.clone())
+            } else {
+                // The column was added to the table after this file was written.
+                lit(NULL).cast(dtype.clone()).alias(name)
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(lf.select(exprs))
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a scan of an [Apache Iceberg](https://iceberg.apache.org) table.
+    ///
+    /// `path` is the directory of the table or one of its metadata files. The live data files of
+    /// the snapshot are read from its manifests and scanned as parquet, skipping the files that
+    /// cannot match [`ScanArgsIceberg::predicate`] by their partition values and column bounds.
+    pub fn scan_iceberg(path: impl AsRef<Path>, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let path = path.as_ref();
+        let snapshot = IcebergSnapshot::read(path, args.snapshot_id)?;
+        let evaluator = args
+            .predicate
+            .as_ref()
+            .and_then(|predicate| stats_predicate(predicate, &snapshot.schema));
+        let stats_evaluator = evaluator.as_ref().and_then(|e| e.as_stats_evaluator());
+
+        let mut files = Vec::with_capacity(snapshot.files.len());
+        for file in &snapshot.files {
+            if let Some(stats_evaluator) = stats_evaluator {
+                if !stats_evaluator.should_read(&file.stats)? {
+                    continue;
+                }
+            }
+            files.push(file);
+        }
+        if polars_core::config::verbose() && files.len() != snapshot.files.len() {
+            eprintln!(
+                "iceberg: skipped {} of {} data files of {}",
+                snapshot.files.len() - files.len(),
+                snapshot.files.len(),
+                path.display()
+            )
+        }
+
+        let mut lf = if files.is_empty() {
+            DataFrame::from(snapshot.schema.as_ref()).lazy()
+        } else {
+            let lfs = files
+                .iter()
+                .map(|file| {
+                    scan_iceberg_file(file, &snapshot.schema, &args).map_err(|e| {
+                        polars_err!(
+                            ComputeError: "error while reading {}: {}", file.path.display(), e
+                        )
+                    })
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            concat_impl(&lfs, args.rechunk, true, true, false)?
+        };
+
+        if let Some(predicate) = args.predicate {
+            lf = lf.filter(predicate)
+        };
+        if let Some(n_rows) = args.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize)
+        };
+        if let Some(rc) = args.row_index {
+            lf = lf.with_row_index(&rc.name, Some(rc.offset))
+        };
+        Ok(lf)
+    }
+}
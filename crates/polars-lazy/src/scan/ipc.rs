@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::ipc::OnDiskDataFrame;
 use polars_io::RowIndex;
 
 use crate::prelude::*;
@@ -12,6 +13,9 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_index: Option<RowIndex>,
     pub memmap: bool,
+    /// Parse `key=value` components of the paths into partition columns, so that the files of
+    /// the partitions that are filtered out are never opened.
+    pub hive_partitioning: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -22,6 +26,7 @@ impl Default for ScanArgsIpc {
             rechunk: false,
             row_index: None,
             memmap: true,
+            hive_partitioning: false,
         }
     }
 }
@@ -44,20 +49,41 @@ impl LazyIpcReader {
 }
 
 impl LazyFileListReader for LazyIpcReader {
+    /// Get the final [LazyFrame].
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
+        if let Some(paths) = self.iter_paths()? {
+            let paths = paths
+                .into_iter()
+                .collect::<PolarsResult<Arc<[PathBuf]>>>()?;
+            polars_ensure!(
+                !paths.is_empty(),
+                ComputeError: "no matching files found in {}", self.path.display()
+            );
+            self.paths = paths;
+        }
+        self.finish_no_glob()
+    }
+
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
         let args = self.args;
-        let path = self.path;
+        let paths = if self.paths.is_empty() {
+            Arc::new([self.path]) as Arc<[PathBuf]>
+        } else {
+            self.paths
+        };
 
         let options = IpcScanOptions {
             memmap: args.memmap,
+            struct_fields: None,
         };
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_ipc(
-            path,
+            paths,
             options,
             args.n_rows,
             args.cache,
-            args.row_index.clone(),
+            None,
             args.rechunk,
+            args.hive_partitioning,
         )?
         .build()
         .into();
@@ -118,4 +144,18 @@ impl LazyFrame {
             .with_paths(paths)
             .finish()
     }
+
+    /// Create a LazyFrame that memory-maps the rows and columns an [`OnDiskDataFrame`] refers
+    /// to.
+    pub fn scan_on_disk(df: &OnDiskDataFrame) -> PolarsResult<Self> {
+        let args = ScanArgsIpc {
+            memmap: true,
+            ..Default::default()
+        };
+        let mut lf = Self::scan_ipc_files(Arc::new([df.path().to_path_buf()]), args)?;
+        if let Some(columns) = df.columns() {
+            lf = lf.select(columns.iter().map(|name| col(name)).collect::<Vec<_>>());
+        }
+        Ok(lf.slice(df.offset() as i64, df.height() as IdxSize))
+    }
 }
@@ -1,7 +1,13 @@
 pub(super) mod anonymous_scan;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
+#[cfg(feature = "flight")]
+pub(super) mod flight;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
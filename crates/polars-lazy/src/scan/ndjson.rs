@@ -16,10 +16,12 @@ pub struct LazyJsonLineReader {
     pub(crate) low_memory: bool,
     pub(crate) rechunk: bool,
     pub(crate) schema: Arc<RwLock<Option<SchemaRef>>>,
+    pub(crate) schema_overwrite: Option<SchemaRef>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) include_file_paths: Option<Arc<str>>,
 }
 
 impl LazyJsonLineReader {
@@ -35,10 +37,12 @@ impl LazyJsonLineReader {
             low_memory: false,
             rechunk: false,
             schema: Arc::new(Default::default()),
+            schema_overwrite: None,
             row_index: None,
             infer_schema_length: Some(100),
             ignore_errors: false,
             n_rows: None,
+            include_file_paths: None,
         }
     }
     /// Add a row index column.
@@ -48,6 +52,13 @@ impl LazyJsonLineReader {
         self
     }
 
+    /// Add a column with this name that holds the path of the file a row was read from.
+    #[must_use]
+    pub fn with_include_file_paths(mut self, include_file_paths: Option<Arc<str>>) -> Self {
+        self.include_file_paths = include_file_paths;
+        self
+    }
+
     /// Set values as `Null` if parsing fails because of schema mismatches.
     #[must_use]
     pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
@@ -77,6 +88,24 @@ impl LazyJsonLineReader {
         self
     }
 
+    /// Overwrite the dtypes of these columns in the (inferred) schema. Columns that are not in
+    /// the schema are added, so keys that only appear after the rows used for inference are
+    /// read as well. Lines that miss a key of the schema get a `null` in that column.
+    #[must_use]
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    pub(crate) fn apply_schema_overwrite(&self, schema: &mut SchemaRef) {
+        if let Some(overwrite) = &self.schema_overwrite {
+            let schema = Arc::make_mut(schema);
+            for (name, dtype) in overwrite.iter() {
+                schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+    }
+
     /// Reduce memory usage in expensive of performance
     #[must_use]
     pub fn low_memory(mut self, toggle: bool) -> Self {
@@ -93,12 +122,16 @@ impl LazyJsonLineReader {
 
 impl LazyFileListReader for LazyJsonLineReader {
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let mut schema = self.schema.read().unwrap().clone();
+        if let Some(schema) = &mut schema {
+            self.apply_schema_overwrite(schema);
+        }
         let options = ScanArgsAnonymous {
             name: "JSON SCAN",
             infer_schema_length: self.infer_schema_length,
             n_rows: self.n_rows,
             row_index: self.row_index.clone(),
-            schema: self.schema.read().unwrap().clone(),
+            schema,
             ..ScanArgsAnonymous::default()
         };
 
@@ -144,4 +177,8 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn row_index(&self) -> Option<&RowIndex> {
         self.row_index.as_ref()
     }
+
+    fn include_file_paths(&self) -> Option<&str> {
+        self.include_file_paths.as_deref()
+    }
 }
@@ -18,6 +18,13 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
     pub hive_partitioning: bool,
+    /// Coalesce many small files into a single read task. This avoids that the scheduling
+    /// of the files dominates the runtime if a glob matches thousands of tiny files.
+    pub compact_small_files: bool,
+    /// What to do with the columns that exist in some of the files, but not in all of them.
+    pub missing_columns: MissingColumns,
+    /// Add a column with this name that holds the path of the file a row was read from.
+    pub include_file_paths: Option<Arc<str>>,
 }
 
 impl Default for ScanArgsParquet {
@@ -32,6 +39,9 @@ impl Default for ScanArgsParquet {
             cloud_options: None,
             use_statistics: true,
             hive_partitioning: false,
+            compact_small_files: false,
+            missing_columns: MissingColumns::Raise,
+            include_file_paths: None,
         }
     }
 }
@@ -84,6 +94,9 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.cloud_options,
             self.args.use_statistics,
             self.args.hive_partitioning,
+            self.args.compact_small_files,
+            self.args.missing_columns,
+            self.args.include_file_paths,
         )?
         .build()
         .into();
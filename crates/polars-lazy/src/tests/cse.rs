@@ -293,3 +293,79 @@ fn test_cse_columns_projections() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_reuse_projected_exprs() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+    let expensive = || (col("a") * col("b")).abs();
+
+    let q = df
+        .lazy()
+        .with_column(expensive().alias("x"))
+        .select([(expensive() + lit(1)).alias("y"), expensive().alias("z")])
+        .with_comm_subexpr_elim(true);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // only the `with_columns` computes the product
+    let n_products = (&lp_arena)
+        .iter(lp)
+        .flat_map(|(_, lp)| lp.get_exprs())
+        .filter(|e| {
+            (&expr_arena).iter(*e).any(|(_, ae)| {
+                matches!(
+                    ae,
+                    AExpr::BinaryExpr {
+                        op: Operator::Multiply,
+                        ..
+                    }
+                )
+            })
+        })
+        .count();
+    assert_eq!(n_products, 1);
+
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["y", "z"]);
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        &[Some(5), Some(11), Some(19)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_cse_scans_with_different_options() -> PolarsResult<()> {
+    let full = scan_foods_csv();
+    let head = LazyCsvReader::new(FOODS_CSV)
+        .with_n_rows(Some(3))
+        .finish()?;
+
+    let q = concat(
+        &[full.clone(), head, full],
+        UnionArgs {
+            rechunk: false,
+            parallel: false,
+            ..Default::default()
+        },
+    )?
+    .with_comm_subplan_elim(true);
+
+    // only the equal scans are cached
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let inputs = lp_arena.get(lp).get_inputs();
+    let is_cached = inputs
+        .iter()
+        .map(|input| matches!(lp_arena.get(*input), ALogicalPlan::Cache { .. }))
+        .collect::<Vec<_>>();
+    assert_eq!(is_cached, &[true, false, true]);
+
+    let out = q.collect()?;
+    assert_eq!(out.height(), 27 * 2 + 3);
+    Ok(())
+}
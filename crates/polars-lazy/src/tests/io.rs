@@ -167,6 +167,332 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_compact_small_files() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let glob = "../../examples/datasets/*.parquet";
+    let scan = |compact_small_files: bool, n_rows: Option<usize>| {
+        let args = ScanArgsParquet {
+            n_rows,
+            row_index: Some(RowIndex {
+                name: "index".into(),
+                offset: 1,
+            }),
+            compact_small_files,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(glob, args)?.collect()
+    };
+
+    let expected = scan(false, None)?;
+    let out = scan(true, None)?;
+    assert_eq!(out.shape(), (54, 5));
+    assert!(out.equals(&expected));
+
+    let out = scan(true, Some(30))?;
+    assert!(out.equals(&expected.head(Some(30))));
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_scan_include_file_paths() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+
+    let args = ScanArgsParquet {
+        include_file_paths: Some("path".into()),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(GLOB_PARQUET, args)?
+        .filter(col("path").eq(lit(FOODS_PARQUET)))
+        .select([col("calories"), col("path")])
+        .collect()?;
+    let expected = scan_foods_parquet(false)
+        .select([col("calories")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["calories", "path"]);
+    assert!(out.select(["calories"])?.equals(&expected));
+
+    let out = LazyCsvReader::new(GLOB_CSV)
+        .with_include_file_paths(Some("path".into()))
+        .finish()?
+        .filter(col("path").eq(lit(FOODS_CSV)))
+        .collect()?;
+    let expected = scan_foods_csv().collect()?;
+    assert_eq!(out.height(), expected.height());
+    assert_eq!(out.get_column_names().last(), Some(&"path"));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_schema_drift() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_parquet_schema_drift");
+    std::fs::create_dir_all(&dir)?;
+    let mut first = df![
+        "a" => [1i32, 2],
+        "b" => ["x", "y"],
+    ]?;
+    let mut second = df![
+        "a" => [3i64, 4],
+    ]?;
+    ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(dir.join("1.parquet"))?).finish(&mut second)?;
+    let glob = dir.join("*.parquet");
+    let scan = |missing_columns: MissingColumns| {
+        let args = ScanArgsParquet {
+            missing_columns,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(&glob, args)
+    };
+
+    assert!(scan(MissingColumns::Raise).is_err());
+
+    let out = scan(MissingColumns::InsertNull)?.collect()?;
+    let expected = df![
+        "a" => [1i64, 2, 3, 4],
+        "b" => [Some("x"), Some("y"), None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = scan(MissingColumns::InsertNull)?
+        .filter(col("a").gt(lit(1i64)))
+        .select([col("b"), col("a")])
+        .collect()?;
+    let expected = df![
+        "b" => [Some("y"), None, None],
+        "a" => [2i64, 3, 4],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_profile_scan_statistics() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_profile_scan_statistics");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("0.parquet");
+    let mut df = df!("a" => [1i64, 5, 3, 7], "b" => ["x", "y", "z", "w"])?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(2))
+        .with_bloom_filter_columns(vec!["b".to_string()])
+        .finish(&mut df)?;
+
+    let scan_statistics = |predicate: Expr| -> PolarsResult<Vec<u64>> {
+        let (out, _, stats) = LazyFrame::scan_parquet(&path, Default::default())?
+            .filter(predicate)
+            .profile_with_scan_statistics()?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(stats.height(), 1);
+        assert!(stats
+            .column("node")?
+            .str()?
+            .get(0)
+            .unwrap()
+            .starts_with("parquet"));
+        [
+            "files_listed",
+            "files_pruned",
+            "row_groups_read",
+            "row_groups_pruned_by_statistics",
+            "row_groups_pruned_by_bloom_filter",
+            "bytes_downloaded",
+        ]
+        .iter()
+        .map(|name| Ok(stats.column(name)?.u64()?.get(0).unwrap()))
+        .collect()
+    };
+
+    // The first row group has a maximum of 5.
+    assert_eq!(
+        scan_statistics(col("a").gt(lit(5i64)))?,
+        &[1, 0, 1, 1, 0, 0]
+    );
+    // "x" is within the minimum and maximum of the second row group, but not in its bloom filter.
+    assert_eq!(scan_statistics(col("b").eq(lit("x")))?, &[1, 0, 1, 0, 1, 0]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "delta")]
+fn test_scan_delta() -> PolarsResult<()> {
+    use std::io::Write;
+
+    let table = std::env::temp_dir().join("polars_test_scan_delta");
+    let _ = std::fs::remove_dir_all(&table);
+    let log_dir = table.join("_delta_log");
+    std::fs::create_dir_all(&log_dir)?;
+    std::fs::create_dir_all(table.join("p=1"))?;
+    let mut first = df!["a" => [1i64, 2]]?;
+    let mut second = df!["a" => [3i64], "b" => ["x"]]?;
+    ParquetWriter::new(std::fs::File::create(table.join("p=1/0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(table.join("1.parquet"))?).finish(&mut second)?;
+
+    let schema = |fields: &str| {
+        let fields = fields
+            .split(',')
+            .map(|f| {
+                let (name, dtype) = f.split_once(':').unwrap();
+                format!(
+                    r#"{{\"name\":\"{name}\",\"type\":\"{dtype}\",\"nullable\":true,\"metadata\":{{}}}}"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            r#"{{"metaData":{{"schemaString":"{{\"type\":\"struct\",\"fields\":[{fields}]}}","partitionColumns":["p"]}}}}"#
+        )
+    };
+    let commits = [
+        vec![
+            r#"{"commitInfo":{"timestamp":1000}}"#.to_string(),
+            schema("a:long,p:integer"),
+            r#"{"add":{"path":"p=1/0.parquet","partitionValues":{"p":"1"}}}"#.to_string(),
+        ],
+        vec![
+            r#"{"commitInfo":{"timestamp":2000}}"#.to_string(),
+            schema("a:long,p:integer,b:string"),
+            r#"{"add":{"path":"1.parquet","partitionValues":{"p":null}}}"#.to_string(),
+        ],
+        vec![
+            r#"{"commitInfo":{"timestamp":3000}}"#.to_string(),
+            r#"{"remove":{"path":"p=1/0.parquet"}}"#.to_string(),
+        ],
+    ];
+    for (version, actions) in commits.iter().enumerate() {
+        let mut f = std::fs::File::create(log_dir.join(format!("{version:020}.json")))?;
+        for action in actions {
+            writeln!(f, "{action}")?;
+        }
+    }
+
+    let scan = |version: DeltaVersion| {
+        let args = ScanArgsDelta {
+            version,
+            ..Default::default()
+        };
+        LazyFrame::scan_delta(&table, args)?.collect()
+    };
+
+    let out = scan(DeltaVersion::Version(1))?;
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "p" => [Some(1i32), Some(1), None],
+        "b" => [None, None, Some("x")],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = scan(DeltaVersion::Timestamp(1500))?;
+    assert_eq!(out.get_column_names(), &["a", "p"]);
+    assert_eq!(out.height(), 2);
+
+    let out = scan(DeltaVersion::Latest)?;
+    assert!(out.equals_missing(&expected.slice(2, 1)));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iceberg", feature = "dtype-struct"))]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    use polars_io::avro::AvroWriter;
+
+    let table = std::env::temp_dir().join("polars_test_scan_iceberg");
+    let _ = std::fs::remove_dir_all(&table);
+    let metadata_dir = table.join("metadata");
+    std::fs::create_dir_all(&metadata_dir)?;
+    let mut first = df!["a" => [1i64, 2], "p" => [1i32, 1]]?;
+    let mut second = df!["a" => [10i64], "p" => [2i32]]?;
+    ParquetWriter::new(std::fs::File::create(table.join("0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(table.join("1.parquet"))?).finish(&mut second)?;
+
+    // The third data file does not exist, it can only be skipped.
+    let entries = [
+        ("0.parquet", 1i32, 1i64, 2i64),
+        ("1.parquet", 2, 10, 10),
+        ("2.parquet", 3, 100, 200),
+    ];
+    let bound = |value: i64| {
+        let key = Series::new("key", &[1i32]);
+        let value = Series::new("value", &[&value.to_le_bytes()[..]]);
+        StructChunked::new("", &[key, value]).unwrap().into_series()
+    };
+    let paths = entries
+        .iter()
+        .map(|e| table.join(e.0).display().to_string())
+        .collect::<Vec<_>>();
+    let partition = StructChunked::new(
+        "partition",
+        &[Series::new(
+            "p",
+            entries.iter().map(|e| e.1).collect::<Vec<_>>(),
+        )],
+    )?;
+    let data_file = StructChunked::new(
+        "data_file",
+        &[
+            Series::new("file_path", paths),
+            Series::new("file_format", ["PARQUET"; 3]),
+            partition.into_series(),
+            Series::new(
+                "lower_bounds",
+                entries.iter().map(|e| bound(e.2)).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "upper_bounds",
+                entries.iter().map(|e| bound(e.3)).collect::<Vec<_>>(),
+            ),
+        ],
+    )?;
+    let mut manifest = DataFrame::new(vec![
+        Series::new("status", [1i32; 3]),
+        data_file.into_series(),
+    ])?;
+    let manifest_path = metadata_dir.join("manifest.avro");
+    AvroWriter::new(std::fs::File::create(&manifest_path)?).finish(&mut manifest)?;
+    let mut manifest_list = df!["manifest_path" => [manifest_path.display().to_string()]]?;
+    let manifest_list_path = metadata_dir.join("snap-1.avro");
+    AvroWriter::new(std::fs::File::create(&manifest_list_path)?).finish(&mut manifest_list)?;
+
+    let metadata = format!(
+        r#"{{
+            "format-version": 1,
+            "schema": {{"type": "struct", "fields": [
+                {{"id": 1, "name": "a", "required": false, "type": "long"}},
+                {{"id": 2, "name": "p", "required": false, "type": "int"}}
+            ]}},
+            "partition-spec": [
+                {{"name": "p", "transform": "identity", "source-id": 2, "field-id": 1000}}
+            ],
+            "current-snapshot-id": 1,
+            "snapshots": [{{"snapshot-id": 1, "manifest-list": "{}"}}]
+        }}"#,
+        manifest_list_path.display()
+    );
+    std::fs::write(metadata_dir.join("v1.metadata.json"), metadata)?;
+    std::fs::write(metadata_dir.join("version-hint.text"), "1")?;
+
+    let scan = |predicate: Expr| {
+        let args = ScanArgsIceberg {
+            predicate: Some(predicate),
+            ..Default::default()
+        };
+        LazyFrame::scan_iceberg(&table, args)?.collect()
+    };
+
+    // Skipped by the column bounds.
+    let out = scan(col("a").lt(lit(50i64)))?;
+    assert!(out.equals(&df!["a" => [1i64, 2, 10], "p" => [1i32, 1, 2]]?));
+    // Skipped by the partition values.
+    let out = scan(col("p").eq(lit(2i32)))?;
+    assert!(out.equals(&df!["a" => [10i64], "p" => [2i32]]?));
+    let out = LazyFrame::scan_iceberg(&table, Default::default()).and_then(|lf| lf.collect());
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -203,6 +529,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             rechunk: false,
             row_index: None,
             memmap: true,
+            hive_partitioning: false,
         },
     )?
     .collect()?;
@@ -273,6 +600,24 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_ndjson_projection_and_predicate_pushdown() -> PolarsResult<()> {
+    init_files();
+    let path = "../../examples/datasets/foods1.ndjson";
+    let q = LazyJsonLineReader::new(path)
+        .finish()?
+        .filter(col("calories").gt(lit(100)))
+        .select([col("sugars_g"), col("category")]);
+
+    let out = q.clone().collect()?;
+    let expected = q.without_optimizations().collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.get_column_names(), &["sugars_g", "category"]);
+
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -471,3 +816,80 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-i16")]
+fn scan_dtype_overwrite_regex() -> PolarsResult<()> {
+    let df = LazyCsvReader::new(FOODS_CSV)
+        .with_dtype_overwrite(Some(&Schema::from_iter([Field::new(
+            "sugars_g",
+            DataType::Int16,
+        )])))
+        .with_dtype_overwrite_regex(Some(vec![("^.*_g$".to_string(), DataType::Float32)]))
+        .finish()?
+        .collect()?;
+
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::String,
+            DataType::Int64,
+            DataType::Float32,
+            DataType::Int16
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "flight")]
+fn test_scan_flight_plan() -> PolarsResult<()> {
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::String),
+    ]);
+    let args = ScanArgsFlight {
+        schema: Some(Arc::new(schema)),
+        row_index: Some(RowIndex {
+            name: "index".into(),
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    // With a given schema the server is only contacted when the query runs.
+    let lf = LazyFrame::scan_flight("grpc://localhost:8815", "trips", args)?
+        .filter(col("a").gt(lit(1)))
+        .select([col("index"), col("b")]);
+    let schema = lf.schema()?;
+    assert_eq!(
+        schema
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>(),
+        &["index", "b"]
+    );
+    let plan = lf.explain(true)?;
+    assert!(plan.contains("Flight SCAN grpc://localhost:8815"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_on_disk() -> PolarsResult<()> {
+    init_files();
+    let on_disk = polars_io::ipc::OnDiskDataFrame::open(FOODS_IPC)?
+        .slice(5, 10)
+        .select(["fats_g", "category"])?;
+    let expected = on_disk.collect()?;
+    assert_eq!(expected.shape(), (10, 2));
+
+    let out = LazyFrame::scan_on_disk(&on_disk)?.collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let out = LazyFrame::scan_on_disk(&on_disk)?
+        .filter(col("fats_g").gt(lit(1.0)))
+        .collect()?;
+    let mask = expected.column("fats_g")?.gt(1.0)?;
+    assert!(out.equals_missing(&expected.filter(&mask)?));
+    Ok(())
+}
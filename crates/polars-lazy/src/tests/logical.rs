@@ -51,6 +51,47 @@ fn test_duration() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-duration"
+))]
+fn test_mixed_temporal_arithmetic() -> PolarsResult<()> {
+    let ns = TimeUnit::Nanoseconds;
+    let df = DataFrame::new(vec![
+        Int32Chunked::new("date", [1, 2]).into_date().into_series(),
+        Int64Chunked::new("dt", [0, 1])
+            .into_datetime(ns, None)
+            .into_series(),
+        Int64Chunked::new("dur", [1, 2])
+            .into_duration(TimeUnit::Milliseconds)
+            .into_series(),
+        Int64Chunked::new("dt_utc", [0, 1])
+            .into_datetime(ns, Some("UTC".into()))
+            .into_series(),
+    ])?;
+
+    let lf = df.clone().lazy().select([
+        (col("date") - col("dt")).alias("diff"),
+        (col("dur") + col("dt")).alias("shifted"),
+        col("date").gt(col("dt")).alias("gt"),
+    ]);
+    let schema = lf.schema()?;
+    let out = lf.collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(out.column("diff")?.dtype(), &DataType::Duration(ns));
+    assert_eq!(
+        out.column("shifted")?.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+
+    // mixing time zones is ambiguous
+    let lf = df.lazy().select([col("dt_utc") - col("dt")]);
+    assert!(lf.clone().schema().is_err() || lf.collect().is_err());
+    Ok(())
+}
+
 fn print_plans(lf: &LazyFrame) {
     println!("LOGICAL PLAN\n\n{}\n", lf.describe_plan());
     println!(
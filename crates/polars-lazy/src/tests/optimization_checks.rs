@@ -256,6 +256,40 @@ pub fn test_slice_pushdown_group_by() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_having_fused_into_group_by() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "x", "z", "x", "y"],
+        "b" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let q = df
+        .lazy()
+        .group_by_stable([col("a")])
+        .having(len().gt(lit(1)))
+        .having(col("b").min().lt(lit(3)))
+        .agg([col("b").sum()]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| {
+        use ALogicalPlan::*;
+        match lp {
+            Aggregate { options, .. } => options.having.is_some(),
+            Selection { .. } => false,
+            _ => true,
+        }
+    }));
+
+    let out = q.collect()?;
+    let expected = df![
+        "a" => ["x", "y"],
+        "b" => [9, 8],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 pub fn test_slice_pushdown_sort() -> PolarsResult<()> {
@@ -552,3 +586,42 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "csv"))]
+fn test_join_reorder_by_estimated_rows() -> PolarsResult<()> {
+    let df = df!["category" => ["vegetables", "seafood", "meat", "fruit"]]?;
+    // 27 rows
+    let large = scan_foods_parquet(false).select([col("category"), col("calories")]);
+    let small = LazyCsvReader::new(FOODS_CSV)
+        .with_n_rows(Some(5))
+        .finish()?
+        .select([col("category"), col("fats_g")]);
+    let q = df
+        .lazy()
+        .inner_join(large, col("category"), col("category"))
+        .inner_join(small, col("category"), col("category"));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let mut node = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    while !matches!(lp_arena.get(node), ALogicalPlan::Join { .. }) {
+        node = lp_arena.get(node).get_inputs()[0];
+    }
+    // the largest relation is joined last
+    let ALogicalPlan::Join { input_right, .. } = lp_arena.get(node) else {
+        unreachable!()
+    };
+    assert!((&lp_arena).iter(*input_right).any(|(_, lp)| matches!(
+        lp,
+        ALogicalPlan::Scan { paths, .. } if paths[0].extension() == Some("parquet".as_ref())
+    )));
+
+    let out = q.clone().collect()?;
+    let expected = q.with_join_reorder(false).collect()?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    let by = ["category", "calories", "fats_g"];
+    assert!(out
+        .sort(by, false, false)?
+        .equals_missing(&expected.sort(by, false, false)?));
+    Ok(())
+}
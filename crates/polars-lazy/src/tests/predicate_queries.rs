@@ -333,3 +333,105 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_push_null_monotone_predicate_below_outer_join() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "x" => [10, 20, 30],
+    ]?;
+    let right = df![
+        "a" => [2, 3, 4],
+        "y" => [1, 2, 3],
+    ]?;
+    let q = left
+        .lazy()
+        .join(
+            right.lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Outer { coalesce: true }),
+        )
+        .filter(col("x").gt(lit(15)));
+
+    assert!(predicate_at_scan(q.clone()));
+
+    // the right-only row is filtered after the join
+    let out = q.sort("a", Default::default()).collect()?;
+    let expected = df![
+        "a" => [2, 3],
+        "x" => [20, 30],
+        "y" => [1, 2],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "fill_gaps")]
+fn test_fill_gaps_predicate_pushdown() -> PolarsResult<()> {
+    let time = Int64Chunked::new("time", &[0i64, 2, 0, 3])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let df = DataFrame::new(vec![
+        time,
+        Series::new("g", &["a", "a", "b", "b"]),
+        Series::new("v", &[1, 2, 3, 4]),
+    ])?;
+
+    // predicates on the group keys are done before the gaps are filled
+    let q = df
+        .clone()
+        .lazy()
+        .fill_gaps("time", "1ms", ["g"])
+        .filter(col("g").eq(lit("b")));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(3), None, None, Some(4)]
+    );
+
+    // the other predicates also filter the inserted rows
+    let q = df
+        .lazy()
+        .fill_gaps("time", "1ms", ["g"])
+        .filter(col("v").is_null());
+    assert!(!predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(out.height(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_indexed_scan_and_join() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2, 3, 2],
+        "b" => ["x", "y", "z", "w"],
+    ]?;
+    df.create_index(["a"], IndexKind::Hash)?;
+
+    // the index finds the rows with `a == 2`, the rest of the predicate filters them
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("a").eq(lit(2)).and(col("b").neq(lit("w"))))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("b")?.str()?), &[Some("y")]);
+
+    let left = df!["a" => [2, 5, 1]]?;
+    let out = left
+        .lazy()
+        .join(
+            df.lazy(),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.str()?),
+        &[Some("y"), Some("w"), None, Some("x")]
+    );
+    Ok(())
+}
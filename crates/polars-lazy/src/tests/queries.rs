@@ -722,6 +722,47 @@ fn test_lazy_group_by_apply() {
         .unwrap();
 }
 
+#[test]
+fn test_lazy_group_by_map_groups() -> PolarsResult<()> {
+    let df = df! {
+        "g" => ["a", "b", "a", "b", "a"],
+        "x" => [1i64, 2, 3, 4, 5]
+    }?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("g", DataType::String),
+        Field::new("x", DataType::Int64),
+        Field::new("x_share", DataType::Float64),
+    ]));
+
+    let lf = df.lazy().group_by_stable([col("g")]).map_groups(
+        |df| {
+            let x = df.column("x")?;
+            let total = x.sum::<i64>()? as f64;
+            let share = x.cast(&DataType::Float64)? / total;
+            df.head(Some(2))
+                .hstack(&[share.head(Some(2)).with_name("x_share")])
+        },
+        schema.clone(),
+    );
+    assert_eq!(lf.schema()?, schema);
+
+    let out = lf.sort("x", Default::default()).collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i64()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("x_share")?.f64()?),
+        &[
+            Some(1.0 / 9.0),
+            Some(2.0 / 6.0),
+            Some(3.0 / 9.0),
+            Some(4.0 / 6.0)
+        ]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_shift_and_fill() {
     let df = df! {
@@ -1187,6 +1228,120 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_join_hints() -> PolarsResult<()> {
+    let left = df![
+        "key" => [3, 1, 2, 5, 1],
+        "l" => ["a", "b", "c", "d", "e"]
+    ]?;
+    let right = df![
+        "key" => [1, 5, 4, 3],
+        "r" => [10, 20, 30, 40]
+    ]?;
+    let join = |hints: JoinHints| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .on([col("key")])
+            .hints(hints)
+            .finish()
+            .sort_by_exprs([col("key"), col("l")], [false, false], false, false)
+    };
+    let expected = join(Default::default()).collect()?;
+    assert_eq!(expected.height(), 4);
+
+    for hints in [
+        JoinHints {
+            force_sort_merge: true,
+            ..Default::default()
+        },
+        JoinHints {
+            build_side: Some(JoinBuildSide::Left),
+            ..Default::default()
+        },
+        JoinHints {
+            force_broadcast: true,
+            disable_streaming: true,
+            ..Default::default()
+        },
+    ] {
+        let q = join(hints.clone());
+        assert!(q.explain(true)?.contains(&format!("HINTS: {hints}")));
+        assert!(q.collect()?.equals(&expected));
+    }
+
+    // a sort-merge join doesn't have a build side
+    let q = join(JoinHints {
+        force_sort_merge: true,
+        build_side: Some(JoinBuildSide::Right),
+        ..Default::default()
+    });
+    assert!(q.collect().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_describe_annotated_plan() -> PolarsResult<()> {
+    let left = df![
+        "key" => [3, 1, 2, 5, 1],
+        "l" => ["a", "b", "c", "d", "e"]
+    ]?;
+    let right = df![
+        "key" => [1, 5, 4, 3],
+        "r" => [10, 20, 30, 40]
+    ]?;
+    let q = left.lazy().inner_join(right.lazy(), col("key"), col("key"));
+
+    let text = q.describe_annotated_plan(ExplainFormat::Text)?;
+    assert!(text.starts_with("INNER JOIN"));
+    // the hash table is built on the smaller table
+    assert!(text.contains("[rows: ~5; strategy: hash, build: right;"));
+    assert!(text.contains("[rows: 4;"));
+
+    let json = q.describe_annotated_plan(ExplainFormat::Json)?;
+    let plan: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(plan["node"], "join");
+    assert_eq!(plan["join_strategy"]["algorithm"], "hash");
+    assert_eq!(plan["join_strategy"]["build_side"], "right");
+    assert_eq!(plan["exact_rows"], false);
+    assert_eq!(plan["inputs"][0]["estimated_rows"], 5);
+    assert_eq!(plan["inputs"][0]["exact_rows"], true);
+    #[cfg(feature = "streaming")]
+    assert_eq!(plan["streamable"], true);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "csv"))]
+fn test_serialize_plan() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]?;
+    let q = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .group_by([col("category")])
+        .agg([col("fats_g").sum()])
+        .join(
+            df.lazy().with_column(col("a").cast(DataType::Float64)),
+            [col("fats_g")],
+            [col("a")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .sort("category", Default::default());
+
+    let bytes = q.serialize()?;
+    let out = LazyFrame::deserialize(&bytes)?;
+    assert_eq!(out.describe_plan(), q.describe_plan());
+    assert!(out.collect()?.equals_missing(&q.collect()?));
+
+    // closures cannot be shipped to another process
+    let q = scan_foods_csv().select([col("calories").map(|s| Ok(Some(s)), GetOutput::same_type())]);
+    assert!(q.serialize().is_err());
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1925,3 +2080,57 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "fill_gaps")]
+fn test_upsample_fill_strategy() -> PolarsResult<()> {
+    let time = Int64Chunked::new("time", &[0i64, 2, 0, 3])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let df = DataFrame::new(vec![
+        time,
+        Series::new("g", &["a", "a", "b", "b"]),
+        Series::new("v", &[1, 2, 3, 4]),
+    ])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .upsample("time", "1ms", ["g"], None)
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), None, Some(2), Some(3), None, None, Some(4)]
+    );
+
+    // values are only filled within their group
+    let out = df
+        .lazy()
+        .upsample("time", "1ms", ["g"], Some(FillNullStrategy::Forward(None)))
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(4)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("g")?.str()?),
+        &[
+            Some("a"),
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            Some("b")
+        ]
+    );
+    Ok(())
+}
@@ -269,6 +269,37 @@ fn test_streaming_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_inner_join_slice() -> PolarsResult<()> {
+    let lf_left = df![
+        "a" => (0..100).map(|i| i % 5).collect::<Vec<i32>>(),
+        "b" => (0..100).collect::<Vec<i32>>()
+    ]?
+    .lazy();
+    let lf_right = df![
+        "a" => (0..50).map(|i| i % 5).collect::<Vec<i32>>(),
+        "c" => (0..50).collect::<Vec<i32>>()
+    ]?
+    .lazy();
+
+    let q = lf_left.inner_join(lf_right, col("a"), col("a"));
+    let (node, lp_arena, _) = q
+        .clone()
+        .slice(5, 10)
+        .with_streaming(true)
+        .to_alp_optimized()?;
+    assert!((&lp_arena).iter(node).any(|(_, lp)| matches!(
+        lp,
+        ALogicalPlan::Join { options, .. } if options.args.slice == Some((5, 10))
+    )));
+
+    let out = q.clone().slice(5, 10).with_streaming(true).collect()?;
+    assert_eq!(out.shape(), (10, 3));
+    let out = q.slice(0, 2000).with_streaming(true).collect()?;
+    assert_eq!(out.shape(), (1000, 3));
+    Ok(())
+}
+
 #[test]
 fn test_streaming_partial() -> PolarsResult<()> {
     let lf_left = df![
@@ -388,3 +419,167 @@ fn test_sort_maintain_order_streaming() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_streaming_memory_budget() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .group_by([col("sugars_g")])
+        .agg([col("calories").sum()])
+        .sort("sugars_g", Default::default());
+
+    let expected = q.clone().collect()?;
+    let out = q
+        .with_streaming_options(StreamingOptions {
+            memory_limit: 1 << 20,
+            spill: false,
+            ..Default::default()
+        })
+        .collect()?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_explode() -> PolarsResult<()> {
+    let mut values: Series = (0..3i32)
+        .map(|i| (0..10_000).map(|v| v * i).collect::<Vec<i32>>())
+        .collect();
+    values.rename("values");
+    let df = DataFrame::new(vec![Series::new("id", [1i32, 2, 3]), values])?;
+    let q = df.lazy().explode([col("values")]);
+    assert_streaming_with_default(q.clone(), true, false);
+
+    // a small budget splits the exploded lists over multiple chunks
+    let out = q
+        .with_streaming_options(StreamingOptions {
+            memory_limit: 1 << 20,
+            spill: false,
+            ..Default::default()
+        })
+        .collect()?;
+    assert_eq!(out.shape(), (30_000, 2));
+    assert_eq!(out.column("values")?.i32()?.get(29_999), Some(2 * 9_999));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_explode_mismatch() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1i32, 2, 3],
+        "a" => [Series::new("", [1i32, 2]), Series::new("", [3i32]), Series::new("", [4i32, 5])],
+        "b" => [Series::new("", [1i32]), Series::new("", [2i32, 3]), Series::new("", [6i32, 7])],
+    ]?;
+    for mismatch in [ExplodeMismatch::NullPad, ExplodeMismatch::Cartesian] {
+        let q = df
+            .clone()
+            .lazy()
+            .explode_with([col("a"), col("b")], mismatch);
+        assert_streaming_with_default(q, true, false);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_streaming_unnest() -> PolarsResult<()> {
+    let mut s: Series = (0..100i32).map(|i| (i, i * 2)).collect();
+    s.rename("s");
+    let df = DataFrame::new(vec![Series::new("id", 0..100i32), s])?;
+    let q = df.lazy().unnest(["s"]);
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_maintain_order() -> PolarsResult<()> {
+    let left = df![
+        "a" => [3i32, 1, 2, 1],
+        "left" => ["x", "y", "z", "w"],
+    ]?
+    .lazy();
+    let right = df![
+        "a" => (0..1000i32).rev().map(|i| i % 4).collect::<Vec<_>>(),
+        "right" => (0..1000i32).collect::<Vec<_>>(),
+    ]?
+    .lazy();
+    let options = StreamingOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+
+    let left_join = left.clone().left_join(right.clone(), col("a"), col("a"));
+    let inner_join = left
+        .clone()
+        .join_builder()
+        .with(right.clone())
+        .on([col("a")])
+        .build_side(JoinBuildSide::Right)
+        .finish();
+    for q in [left_join, inner_join] {
+        let expected = q.clone().collect()?;
+        let q = q.with_streaming_options(options);
+        assert!(optimization_checks::is_pipeline(q.clone()));
+        let plan = q.describe_optimized_plan()?;
+        assert!(plan.contains("--- STREAMING (order preserved)"));
+        assert_eq!(q.collect()?, expected);
+    }
+
+    // the streaming engine may probe the other table than the in-memory engine
+    let q = left.inner_join(right, col("a"), col("a"));
+    let plan = q.clone().with_streaming(true).describe_optimized_plan()?;
+    assert!(plan.contains("--- STREAMING (order not preserved)"));
+    let expected = q.clone().collect()?;
+    assert_eq!(q.with_streaming_options(options).collect()?, expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_streaming_concat_sorted() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let frames = [
+        df![
+            "a" => [1, 4, 7],
+            "b" => ["a", "d", "g"]
+        ]?,
+        df![
+            "a" => [2, 5, 8],
+            "b" => ["b", "e", "h"]
+        ]?,
+        df![
+            "a" => [0, 3, 6, 9],
+            "b" => ["z", "c", "f", "i"]
+        ]?,
+    ];
+    let lfs = frames
+        .iter()
+        .map(|df| df.clone().lazy().filter(col("a").gt_eq(lit(0))))
+        .collect::<Vec<_>>();
+    let expected = df![
+        "a" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        "b" => ["z", "a", "b", "c", "d", "e", "f", "g", "h", "i"]
+    ]?;
+
+    for streaming in [false, true] {
+        let out = concat_sorted(&lfs, "a")?
+            .with_streaming(streaming)
+            .collect()?;
+        assert!(out.equals(&expected));
+        assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_with_row_index() -> PolarsResult<()> {
+    let df = df!["a" => (0..10_000).collect::<Vec<i32>>()]?;
+    let q = df
+        .lazy()
+        .filter((col("a") % lit(3)).eq(lit(0)))
+        .with_row_index("index", Some(5))
+        .filter(col("index").gt(lit(100)));
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use super::min_max::AggType;
 use super::*;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+use crate::chunked_array::array::sum_mean::{mean_with_nulls, sum_with_nulls};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
-use crate::prelude::array::sum_mean::sum_array_numerical;
+use crate::prelude::array::sum_mean::{mean_array_numerical, sum_array_numerical};
 use crate::series::ArgAgg;
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
@@ -47,6 +47,19 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        if has_inner_nulls(ca) {
+            return mean_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => Ok(mean_array_numerical(ca, &dt)),
+            _ => mean_with_nulls(ca),
+        }
+    }
+
     fn array_unique(&self) -> PolarsResult<ListChunked> {
         let ca = self.as_array();
         ca.try_apply_amortized_to_list(|s| s.as_ref().unique())
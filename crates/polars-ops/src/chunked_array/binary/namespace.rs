@@ -1,5 +1,7 @@
-#[cfg(feature = "binary_encoding")]
+#[cfg(any(feature = "binary_encoding", feature = "binary_compression"))]
 use std::borrow::Cow;
+#[cfg(feature = "binary_compression")]
+use std::io::{Read, Write};
 
 #[cfg(feature = "binary_encoding")]
 use base64::engine::general_purpose;
@@ -7,10 +9,75 @@ use base64::engine::general_purpose;
 use base64::Engine as _;
 use memchr::memmem::find;
 use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+#[cfg(feature = "binary_compression")]
+use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
+/// The compression formats of [`BinaryNameSpaceImpl::compress`] and
+/// [`BinaryNameSpaceImpl::decompress`].
+#[cfg(feature = "binary_compression")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BinaryCompression {
+    Gzip,
+    Zstd,
+}
+
+#[cfg(feature = "binary_compression")]
+impl std::fmt::Display for BinaryCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BinaryCompression::Gzip => "gzip",
+            BinaryCompression::Zstd => "zstd",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[cfg(feature = "binary_compression")]
+fn compress_value(
+    value: &[u8],
+    method: BinaryCompression,
+    level: Option<i32>,
+) -> std::io::Result<Vec<u8>> {
+    match method {
+        BinaryCompression::Gzip => {
+            let level = level.map_or(flate2::Compression::default(), |level| {
+                flate2::Compression::new(level as u32)
+            });
+            let mut encoder = flate2::write::GzEncoder::new(vec![], level);
+            encoder.write_all(value)?;
+            encoder.finish()
+        },
+        // level 0 is the default level of zstd
+        BinaryCompression::Zstd => zstd::bulk::compress(value, level.unwrap_or(0)),
+    }
+}
+
+#[cfg(feature = "binary_compression")]
+fn decompress_value(value: &[u8], method: BinaryCompression) -> std::io::Result<Vec<u8>> {
+    let mut out = vec![];
+    match method {
+        BinaryCompression::Gzip => {
+            flate2::read::MultiGzDecoder::new(value).read_to_end(&mut out)?;
+        },
+        BinaryCompression::Zstd => {
+            zstd::stream::Decoder::new(value)?.read_to_end(&mut out)?;
+        },
+    }
+    Ok(out)
+}
+
 pub trait BinaryNameSpaceImpl: AsBinary {
+    /// Get the size of the binary values in bytes.
+    fn size_bytes(&self) -> UInt32Chunked {
+        let ca = self.as_binary();
+        ca.apply_values_generic(|v| v.len() as u32)
+    }
+
     /// Check if binary contains given literal
     fn contains(&self, lit: &[u8]) -> BooleanChunked {
         let ca = self.as_binary();
@@ -126,6 +193,80 @@ pub trait BinaryNameSpaceImpl: AsBinary {
                 .unwrap()
         }
     }
+
+    /// Compress every value with `method`, at the default compression level if `level` is
+    /// `None`.
+    #[cfg(feature = "binary_compression")]
+    fn compress(
+        &self,
+        method: BinaryCompression,
+        level: Option<i32>,
+    ) -> PolarsResult<BinaryChunked> {
+        if let Some(level) = level {
+            match method {
+                BinaryCompression::Gzip => polars_ensure!(
+                    (0..=9).contains(&level),
+                    ComputeError: "gzip compression level must be between 0 and 9, got {}", level
+                ),
+                BinaryCompression::Zstd => polars_ensure!(
+                    zstd::compression_level_range().contains(&level),
+                    ComputeError: "invalid zstd compression level {}", level
+                ),
+            }
+        }
+        let ca = self.as_binary();
+        ca.try_apply(|s| {
+            let bytes = compress_value(s, method, level).map_err(to_compute_err)?;
+            Ok(bytes.into())
+        })
+    }
+
+    /// Decompress every value that was compressed with `method`.
+    ///
+    /// If `strict` is false, values that cannot be decompressed are null instead of raising an
+    /// error.
+    #[cfg(feature = "binary_compression")]
+    fn decompress(&self, method: BinaryCompression, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply(|s| {
+                let bytes = decompress_value(s, method).map_err(|_| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `{}` compressed data found; try setting `strict=false` to ignore",
+                        method
+                    )
+                })?;
+                Ok(bytes.into())
+            })
+        } else {
+            Ok(ca.apply(|opt_s| {
+                opt_s.and_then(|s| decompress_value(s, method).ok().map(Cow::Owned))
+            }))
+        }
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
+
+#[cfg(all(test, feature = "binary_compression"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compress_roundtrip() -> PolarsResult<()> {
+        let values = [Some(b"foo".as_slice()), None, Some(b"".as_slice())];
+        let ca = BinaryChunked::from_iter(values);
+        for method in [BinaryCompression::Gzip, BinaryCompression::Zstd] {
+            let compressed = ca.compress(method, None)?;
+            let out = compressed.decompress(method, true)?;
+            assert_eq!(out.into_iter().collect::<Vec<_>>(), values);
+        }
+        assert!(ca.compress(BinaryCompression::Gzip, Some(10)).is_err());
+
+        assert!(ca.decompress(BinaryCompression::Zstd, true).is_err());
+        let out = ca.decompress(BinaryCompression::Zstd, false)?;
+        assert_eq!(out.get(0), None);
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use arrow::temporal_conversions::{
 };
 use chrono::NaiveDateTime;
 use chrono_tz::UTC;
-use polars_core::chunked_array::ops::arity::try_binary_elementwise;
+use polars_core::chunked_array::ops::arity::{try_binary_elementwise, try_unary_elementwise};
 use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
 
@@ -39,29 +39,25 @@ pub fn replace_time_zone(
         TimeUnit::Microseconds => datetime_to_timestamp_us,
         TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
     };
+    let convert = |timestamp: i64, ambiguous: Ambiguous| -> PolarsResult<Option<i64>> {
+        let ndt = timestamp_to_datetime(timestamp);
+        Ok(convert_to_naive_local(&from_tz, &to_tz, ndt, ambiguous)?.map(datetime_to_timestamp))
+    };
     let out = match ambiguous.len() {
         1 => match unsafe { ambiguous.get_unchecked(0) } {
-            Some(ambiguous) => datetime.0.try_apply(|timestamp| {
-                let ndt = timestamp_to_datetime(timestamp);
-                Ok(datetime_to_timestamp(convert_to_naive_local(
-                    &from_tz,
-                    &to_tz,
-                    ndt,
-                    Ambiguous::from_str(ambiguous)?,
-                )?))
-            }),
+            Some(ambiguous) => {
+                let ambiguous = Ambiguous::from_str(ambiguous)?;
+                try_unary_elementwise(datetime, |opt_timestamp| match opt_timestamp {
+                    Some(timestamp) => convert(timestamp, ambiguous),
+                    None => Ok(None),
+                })
+            },
             _ => Ok(datetime.0.apply(|_| None)),
         },
         _ => try_binary_elementwise(datetime, ambiguous, |timestamp_opt, ambiguous_opt| {
             match (timestamp_opt, ambiguous_opt) {
                 (Some(timestamp), Some(ambiguous)) => {
-                    let ndt = timestamp_to_datetime(timestamp);
-                    Ok(Some(datetime_to_timestamp(convert_to_naive_local(
-                        &from_tz,
-                        &to_tz,
-                        ndt,
-                        Ambiguous::from_str(ambiguous)?,
-                    )?)))
+                    convert(timestamp, Ambiguous::from_str(ambiguous)?)
                 },
                 _ => Ok(None),
             }
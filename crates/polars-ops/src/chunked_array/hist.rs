@@ -4,13 +4,45 @@ use arrow::legacy::index::IdxSize;
 use num_traits::ToPrimitive;
 use polars_core::datatypes::PolarsNumericType;
 use polars_core::prelude::{
-    ChunkCast, ChunkSort, ChunkedArray, DataType, StringChunkedBuilder, StructChunked, UInt32Type,
-    *,
+    ChunkCast, ChunkedArray, DataType, StringChunkedBuilder, StructChunked, UInt32Type, *,
 };
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
 use polars_error::PolarsResult;
 use polars_utils::float::IsFloat;
 use polars_utils::total_ord::TotalOrdWrap;
+use rayon::prelude::*;
+
+/// Count the values of every chunk in parallel and sum the counts per bin.
+///
+/// `bin_idx` returns the index of the bin a (non-null) value falls in, or `None` if the
+/// value doesn't fall in any bin.
+fn count_per_bin<T, F>(ca: &ChunkedArray<T>, n_bins: usize, bin_idx: F) -> Vec<IdxSize>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native) -> Option<usize> + Send + Sync,
+{
+    let chunks = ca.downcast_iter().collect::<Vec<_>>();
+    POOL.install(|| {
+        chunks
+            .par_iter()
+            .map(|arr| {
+                let mut count: Vec<IdxSize> = vec![0; n_bins];
+                for item in arr.non_null_values_iter() {
+                    if let Some(idx) = bin_idx(item) {
+                        count[idx] += 1;
+                    }
+                }
+                count
+            })
+            .reduce(
+                || vec![0; n_bins],
+                |mut acc, count| {
+                    acc.iter_mut().zip(count).for_each(|(a, c)| *a += c);
+                    acc
+                },
+            )
+    })
+}
 
 fn compute_hist<T>(
     ca: &ChunkedArray<T>,
@@ -29,47 +61,17 @@ where
         breaks.sort_unstable_by_key(|k| TotalOrdWrap(*k));
         breaks.push(f64::INFINITY);
 
-        let sorted = ca.sort(false);
-
-        let mut count: Vec<IdxSize> = Vec::with_capacity(breaks.len());
-        let mut current_count: IdxSize = 0;
-        let mut breaks_iter = breaks.iter();
-
-        // We start with the lower garbage bin.
-        // (-inf, B0]
-        let mut lower_bound = f64::NEG_INFINITY;
-        let mut upper_bound = *breaks_iter.next().unwrap();
-
-        for chunk in sorted.downcast_iter() {
-            for item in chunk.non_null_values_iter() {
-                let item = item.to_f64().unwrap();
-
-                // Not a member of current interval
-                if !(item <= upper_bound && item > lower_bound) {
-                    loop {
-                        // So we push the previous interval
-                        count.push(current_count);
-                        current_count = 0;
-                        lower_bound = upper_bound;
-                        upper_bound = *breaks_iter.next().unwrap();
-                        if item <= upper_bound && item > lower_bound {
-                            break;
-                        }
-                    }
-                }
-                current_count += 1;
-            }
-        }
-        // Add last value, this is the garbage bin. E.g. anything that doesn't fit in the bounds.
-        count.push(current_count);
-        // Add the remaining buckets
-        while count.len() < breaks.len() {
-            count.push(0)
-        }
+        // The bins are (-inf, B0], (B0, B1], ..., (Bn, inf], so a value falls in the bin of
+        // the first break it doesn't exceed. NaN doesn't fall in any bin.
+        let count = count_per_bin(ca, breaks.len(), |item| {
+            let item = item.to_f64().unwrap();
+            (!item.is_nan()).then(|| breaks.partition_point(|br| *br < item))
+        });
         (breaks, count)
     } else {
-        let min = ChunkAgg::min(ca).unwrap().to_f64().unwrap();
-        let max = ChunkAgg::max(ca).unwrap().to_f64().unwrap();
+        // Without any values we center the (empty) bins around zero.
+        let min = ChunkAgg::min(ca).map_or(0.0, |v| v.to_f64().unwrap());
+        let max = ChunkAgg::max(ca).map_or(0.0, |v| v.to_f64().unwrap());
 
         let start = min.floor() - 1.0;
         let end = max.ceil() + 1.0;
@@ -90,30 +92,29 @@ where
         breaks.extend(breaks_iter);
         breaks.push(f64::INFINITY);
 
-        let mut count: Vec<IdxSize> = vec![0; breaks.len()];
-        let end_idx = count.len() - 1;
+        let end_idx = breaks.len() - 1;
 
         // start is the closed rhs of the interval, so we subtract the bucket width
         let start_range = start - interval;
-        for chunk in ca.downcast_iter() {
-            for item in chunk.non_null_values_iter() {
-                let item = item.to_f64().unwrap() - start_range;
-
-                // This is needed for numeric stability.
-                // Only for integers.
-                // we can fall directly on a boundary with an integer.
-                let item = item / interval;
-                let item = if !T::Native::is_float() && (item.round() - item).abs() < 0.0000001 {
-                    item.round() - 1.0
-                } else {
-                    item.ceil() - 1.0
-                };
-
-                let idx = item as usize;
-                let idx = std::cmp::min(idx, end_idx);
-                count[idx] += 1;
+        let count = count_per_bin(ca, breaks.len(), |item| {
+            let item = item.to_f64().unwrap() - start_range;
+            if item.is_nan() {
+                return None;
             }
-        }
+
+            // This is needed for numeric stability.
+            // Only for integers.
+            // we can fall directly on a boundary with an integer.
+            let item = item / interval;
+            let item = if !T::Native::is_float() && (item.round() - item).abs() < 0.0000001 {
+                item.round() - 1.0
+            } else {
+                item.ceil() - 1.0
+            };
+
+            let idx = item as usize;
+            Some(std::cmp::min(idx, end_idx))
+        });
         (breaks, count)
     };
     let mut fields = Vec::with_capacity(3);
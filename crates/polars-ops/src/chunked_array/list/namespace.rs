@@ -2,12 +2,14 @@ use std::convert::TryFrom;
 use std::fmt::Write;
 
 use arrow::array::ValueSize;
-use arrow::legacy::kernels::list::sublist_get;
-use polars_core::chunked_array::builder::get_list_builder;
 #[cfg(feature = "list_gather")]
-use polars_core::export::num::ToPrimitive;
+use arrow::array::{Array, PrimitiveArray};
+#[cfg(feature = "list_gather")]
+use arrow::bitmap::MutableBitmap;
+use arrow::legacy::kernels::list::sublist_get;
 #[cfg(feature = "list_gather")]
-use polars_core::export::num::{NumCast, Signed, Zero};
+use arrow::offset::OffsetsBuffer;
+use polars_core::chunked_array::builder::get_list_builder;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
@@ -322,76 +324,38 @@ pub trait ListNameSpaceImpl: AsList {
     fn lst_gather(&self, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
         let list_ca = self.as_list();
 
-        let index_typed_index = |idx: &Series| {
-            let idx = idx.cast(&IDX_DTYPE).unwrap();
-            // SAFETY: unstable series never lives longer than the iterator.
-            unsafe {
-                list_ca
-                    .amortized_iter()
-                    .map(|s| {
-                        s.map(|s| {
-                            let s = s.as_ref();
-                            take_series(s, idx.clone(), null_on_oob)
-                        })
-                        .transpose()
-                    })
-                    .collect::<PolarsResult<ListChunked>>()
-                    .map(|mut ca| {
-                        ca.rename(list_ca.name());
-                        ca.into_series()
-                    })
-            }
-        };
-
         use DataType::*;
         match idx.dtype() {
-            List(_) => {
-                let idx_ca = idx.list().unwrap();
-                // SAFETY: unstable series never lives longer than the iterator.
-                let mut out = unsafe {
-                    list_ca
-                        .amortized_iter()
-                        .zip(idx_ca)
-                        .map(|(opt_s, opt_idx)| {
-                            {
-                                match (opt_s, opt_idx) {
-                                    (Some(s), Some(idx)) => {
-                                        Some(take_series(s.as_ref(), idx, null_on_oob))
-                                    },
-                                    _ => None,
-                                }
-                            }
-                            .transpose()
-                        })
-                        .collect::<PolarsResult<ListChunked>>()?
+            List(inner) if inner.is_integer() => {
+                let idx = if idx.len() == 1 {
+                    idx.new_from_index(0, list_ca.len())
+                } else {
+                    idx.clone()
                 };
-                out.rename(list_ca.name());
-
-                Ok(out.into_series())
+                polars_ensure!(
+                    idx.len() == list_ca.len(),
+                    ShapeMismatch: "`list.gather` got {} index lists while the list has {} elements",
+                    idx.len(), list_ca.len()
+                );
+                let idx_ca = idx.list()?.rechunk();
+                let idx_arr = idx_ca.downcast_iter().next().unwrap();
+                let idx_values = idx_ca.get_inner().cast(&Int64)?;
+                let idx_values = idx_values.i64()?.downcast_iter().next().unwrap();
+                let idx_offsets = idx_arr.offsets();
+
+                gather_flattened(
+                    list_ca,
+                    idx_values,
+                    |row| idx_arr.is_valid(row).then(|| idx_offsets.start_end(row)),
+                    null_on_oob,
+                )
             },
-            UInt32 | UInt64 => index_typed_index(idx),
-            dt if dt.is_signed_integer() => {
-                if let Some(min) = idx.min::<i64>().unwrap() {
-                    if min >= 0 {
-                        index_typed_index(idx)
-                    } else {
-                        // SAFETY: unstable series never lives longer than the iterator.
-                        let mut out = unsafe {
-                            list_ca
-                                .amortized_iter()
-                                .map(|opt_s| {
-                                    opt_s
-                                        .map(|s| take_series(s.as_ref(), idx.clone(), null_on_oob))
-                                        .transpose()
-                                })
-                                .collect::<PolarsResult<ListChunked>>()?
-                        };
-                        out.rename(list_ca.name());
-                        Ok(out.into_series())
-                    }
-                } else {
-                    polars_bail!(ComputeError: "all indices are null");
-                }
+            dt if dt.is_integer() => {
+                let idx = idx.cast(&Int64)?.rechunk();
+                let idx_values = idx.i64()?.downcast_iter().next().unwrap();
+                let len = idx_values.len();
+
+                gather_flattened(list_ca, idx_values, |_| Some((0, len)), null_on_oob)
             },
             dt => polars_bail!(ComputeError: "cannot use dtype `{}` as an index", dt),
         }
@@ -633,112 +597,81 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
+/// Gather the items of every sublist. `row_idx` returns the range of `idx` that holds the
+/// indices of a row, or `None` if the output row is null.
+///
+/// The indices are translated to indices into the flattened values, so that all sublists
+/// are gathered with a single `take`.
 #[cfg(feature = "list_gather")]
-fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
-    let len = s.len();
-    let idx = cast_index(idx, len, null_on_oob)?;
-    let idx = idx.idx().unwrap();
-    s.take(idx)
-}
-
-#[cfg(feature = "list_gather")]
-fn cast_signed_index_ca<T: PolarsNumericType>(idx: &ChunkedArray<T>, len: usize) -> Series
+fn gather_flattened<F>(
+    ca: &ListChunked,
+    idx: &PrimitiveArray<i64>,
+    row_idx: F,
+    null_on_oob: bool,
+) -> PolarsResult<Series>
 where
-    T::Native: Copy + PartialOrd + PartialEq + NumCast + Signed + Zero,
+    F: Fn(usize) -> Option<(usize, usize)>,
 {
-    idx.into_iter()
-        .map(|opt_idx| opt_idx.and_then(|idx| idx.negative_to_usize(len).map(|idx| idx as IdxSize)))
-        .collect::<IdxCa>()
-        .into_series()
-}
-
-#[cfg(feature = "list_gather")]
-fn cast_unsigned_index_ca<T: PolarsNumericType>(idx: &ChunkedArray<T>, len: usize) -> Series
-where
-    T::Native: Copy + PartialOrd + ToPrimitive,
-{
-    idx.into_iter()
-        .map(|opt_idx| {
-            opt_idx.and_then(|idx| {
-                let idx = idx.to_usize().unwrap();
-                if idx >= len {
-                    None
-                } else {
-                    Some(idx as IdxSize)
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets();
+
+    let mut gather_idx: Vec<Option<IdxSize>> = Vec::with_capacity(idx.len());
+    let mut out_offsets = Vec::with_capacity(arr.len() + 1);
+    out_offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(arr.len());
+
+    for row in 0..arr.len() {
+        match row_idx(row).filter(|_| arr.is_valid(row)) {
+            Some((idx_start, idx_end)) => {
+                let (start, end) = offsets.start_end(row);
+                let len = (end - start) as i64;
+
+                for i in idx_start..idx_end {
+                    let global_idx = match idx.get(i) {
+                        Some(i) => {
+                            let i = if i < 0 { i + len } else { i };
+                            if (0..len).contains(&i) {
+                                Some((start as i64 + i) as IdxSize)
+                            } else {
+                                polars_ensure!(null_on_oob, OutOfBounds: "gather indices are out of bounds");
+                                None
+                            }
+                        },
+                        None => None,
+                    };
+                    gather_idx.push(global_idx);
                 }
-            })
-        })
-        .collect::<IdxCa>()
-        .into_series()
-}
+                validity.push(true);
+            },
+            None => validity.push(false),
+        }
+        out_offsets.push(gather_idx.len() as i64);
+    }
 
-#[cfg(feature = "list_gather")]
-fn cast_index(idx: Series, len: usize, null_on_oob: bool) -> PolarsResult<Series> {
-    let idx_null_count = idx.null_count();
-    use DataType::*;
-    let out = match idx.dtype() {
-        #[cfg(feature = "big_idx")]
-        UInt32 => {
-            if null_on_oob {
-                let a = idx.u32().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx.cast(&IDX_DTYPE).unwrap()
-            }
-        },
-        #[cfg(feature = "big_idx")]
-        UInt64 => {
-            if null_on_oob {
-                let a = idx.u64().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx
-            }
-        },
-        #[cfg(not(feature = "big_idx"))]
-        UInt64 => {
-            if null_on_oob {
-                let a = idx.u64().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx.cast(&IDX_DTYPE).unwrap()
-            }
-        },
-        #[cfg(not(feature = "big_idx"))]
-        UInt32 => {
-            if null_on_oob {
-                let a = idx.u32().unwrap();
-                cast_unsigned_index_ca(a, len)
-            } else {
-                idx
-            }
-        },
-        dt if dt.is_unsigned_integer() => idx.cast(&IDX_DTYPE).unwrap(),
-        Int8 => {
-            let a = idx.i8().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int16 => {
-            let a = idx.i16().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int32 => {
-            let a = idx.i32().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        Int64 => {
-            let a = idx.i64().unwrap();
-            cast_signed_index_ca(a, len)
-        },
-        _ => {
-            unreachable!()
-        },
-    };
-    polars_ensure!(
-        out.null_count() == idx_null_count || null_on_oob,
-        OutOfBounds: "gather indices are out of bounds"
+    let gather_idx = gather_idx.into_iter().collect::<IdxCa>();
+    let values = ca.get_inner().take(&gather_idx)?.rechunk();
+    let inner_dtype = values.dtype().clone();
+    let values = values.chunks()[0].clone();
+
+    // SAFETY: the offsets are monotonically increasing and in bounds of the values.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(out_offsets.into()) };
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.data_type().clone()),
+        offsets,
+        values,
+        validity.into(),
     );
-    Ok(out)
+
+    // SAFETY: the physical type of the values matches the inner dtype.
+    let out = unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            vec![Box::new(arr)],
+            DataType::List(Box::new(inner_dtype)),
+        )
+    };
+    Ok(out.into_series())
 }
 
 // TODO: implement the above for ArrayChunked as well?
@@ -15,6 +15,8 @@ pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 #[cfg(feature = "mode")]
 pub mod mode;
@@ -44,6 +46,8 @@ pub use scatter::ChunkedSet;
 pub use strings::*;
 #[cfg(feature = "top_k")]
 pub use top_k::*;
+#[cfg(feature = "uuid")]
+pub use uuid::*;
 
 #[allow(unused_imports)]
 use crate::prelude::*;
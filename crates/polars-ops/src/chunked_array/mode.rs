@@ -1,7 +1,13 @@
+use arrow::array::LargeListArray;
 use arrow::legacy::utils::CustomIterTools;
+use arrow::offset::OffsetsBuffer;
 use polars_core::frame::group_by::IntoGroupsProxy;
 use polars_core::prelude::*;
-use polars_core::{with_match_physical_integer_polars_type, POOL};
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type, POOL,
+};
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrdWrap};
+use rayon::prelude::*;
 
 fn mode_primitive<T: PolarsDataType>(ca: &ChunkedArray<T>) -> PolarsResult<ChunkedArray<T>>
 where
@@ -82,11 +88,107 @@ pub fn mode(s: &Series) -> PolarsResult<Series> {
     unsafe { out.cast_unchecked(s.dtype()) }
 }
 
+/// The indices of the first occurrences of the most occurring values among the rows `idx` of
+/// `arr`, in the order of the rows.
+///
+/// # Safety
+/// The indices must be in bounds of `arr`.
+unsafe fn group_mode_indices<'a, A>(arr: &'a A, idx: impl Iterator<Item = usize>) -> Vec<IdxSize>
+where
+    A: StaticArray,
+    A::ValueT<'a>: TotalHash + TotalEq,
+{
+    // Maps every value to the index of its first occurrence and its count.
+    let mut counts = PlHashMap::new();
+    for i in idx {
+        counts
+            .entry(TotalOrdWrap(arr.get_unchecked(i)))
+            .and_modify(|v: &mut (IdxSize, IdxSize)| v.1 += 1)
+            .or_insert((i as IdxSize, 1));
+    }
+    let max_occur = counts.values().map(|v| v.1).max().unwrap_or(0);
+    let mut out = counts
+        .into_values()
+        .filter_map(|(first, count)| (count == max_occur).then_some(first))
+        .collect::<Vec<_>>();
+    out.sort_unstable();
+    out
+}
+
+/// # Safety
+/// The groups must be in bounds of `ca`.
+unsafe fn agg_mode_indices<T>(ca: &ChunkedArray<T>, groups: &GroupsProxy) -> Vec<Vec<IdxSize>>
+where
+    T: PolarsDataType,
+    for<'a> T::Physical<'a>: TotalHash + TotalEq,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    POOL.install(|| match groups {
+        GroupsProxy::Idx(groups) => groups
+            .all()
+            .par_iter()
+            .map(|idx| group_mode_indices(arr, idx.iter().map(|i| *i as usize)))
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|&[first, len]| group_mode_indices(arr, first as usize..(first + len) as usize))
+            .collect(),
+    })
+}
+
+/// Compute the mode(s) of every group. Unlike [`mode`], the modes of a group are ordered by
+/// their first occurrence.
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn agg_mode(s: &Series, groups: &GroupsProxy) -> PolarsResult<ListChunked> {
+    let s_phys = s.to_physical_repr();
+    let indices = match s_phys.dtype() {
+        DataType::Binary => agg_mode_indices(s_phys.binary().unwrap(), groups),
+        DataType::Boolean => agg_mode_indices(s_phys.bool().unwrap(), groups),
+        DataType::String => agg_mode_indices(&s_phys.str().unwrap().as_binary(), groups),
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s_phys.as_ref().as_ref().as_ref();
+                agg_mode_indices(ca, groups)
+            })
+        },
+        _ => polars_bail!(opq = mode, s.dtype()),
+    };
+
+    let mut offsets = Vec::with_capacity(indices.len() + 1);
+    offsets.push(0i64);
+    let mut take_idx = Vec::with_capacity(indices.iter().map(|idx| idx.len()).sum());
+    for idx in &indices {
+        take_idx.extend_from_slice(idx);
+        offsets.push(take_idx.len() as i64);
+    }
+    let values = s.take_unchecked_from_slice(&take_idx).rechunk();
+    let inner_dtype = values.dtype().clone();
+    let values = values.chunks()[0].clone();
+
+    // SAFETY: the offsets are monotonically increasing and in bounds of the values.
+    let offsets = OffsetsBuffer::new_unchecked(offsets.into());
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.data_type().clone()),
+        offsets,
+        values,
+        None,
+    );
+    // SAFETY: the physical type of the values matches the inner dtype.
+    Ok(ListChunked::from_chunks_and_dtype(
+        s.name(),
+        vec![Box::new(arr)],
+        DataType::List(Box::new(inner_dtype)),
+    ))
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::prelude::*;
 
-    use super::{mode, mode_primitive};
+    use super::{agg_mode, mode, mode_primitive};
 
     #[test]
     fn mode_test() {
@@ -125,4 +227,31 @@ mod test {
         assert_eq!(result.str_value(0).unwrap(), "test2");
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    fn agg_mode_test() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[Some(1), Some(2), Some(2), None, Some(3), None, Some(3)],
+        );
+
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 4], [7, 0]],
+            rolling: false,
+        };
+        let out = unsafe { agg_mode(&s, &groups)? };
+        assert_eq!(out.get_as_series(0).unwrap(), Series::new("a", &[2]));
+        assert_eq!(
+            out.get_as_series(1).unwrap(),
+            Series::new("a", &[None, Some(3)])
+        );
+        assert_eq!(out.get_as_series(2).unwrap().len(), 0);
+
+        let groups =
+            GroupsProxy::Idx(vec![(6, vec![6, 1, 4, 2].into()), (0, vec![0].into())].into());
+        let out = unsafe { agg_mode(&s, &groups)? };
+        assert_eq!(out.get_as_series(0).unwrap(), Series::new("a", &[2, 3]));
+        assert_eq!(out.get_as_series(1).unwrap(), Series::new("a", &[1]));
+        Ok(())
+    }
 }
@@ -0,0 +1,186 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
+use polars_core::prelude::*;
+
+fn check_lengths(ca: &StringChunked, other: &StringChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "cannot compute string distances between columns of different lengths: {} and {}",
+        ca.len(), other.len()
+    );
+    Ok(())
+}
+
+/// Levenshtein distance on characters, `row` is a scratch buffer.
+fn levenshtein_str(a: &str, b: &str, row: &mut Vec<u32>) -> u32 {
+    if a == b {
+        return 0;
+    }
+    row.clear();
+    row.extend(0..=b.chars().count() as u32);
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, cb) in b.chars().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diag
+            } else {
+                1 + diag.min(above).min(row[j])
+            };
+            diag = above;
+        }
+    }
+    row[row.len() - 1]
+}
+
+/// Hamming distance on characters, `None` if the strings differ in length.
+fn hamming_str(a: &str, b: &str) -> Option<u32> {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut count = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => count += (x != y) as u32,
+            (None, None) => return Some(count),
+            _ => return None,
+        }
+    }
+}
+
+/// Scratch buffers for the Jaro similarity, reused between rows.
+#[derive(Default)]
+struct JaroBuffers {
+    a: Vec<char>,
+    b: Vec<char>,
+    b_matched: Vec<bool>,
+    a_matches: Vec<char>,
+}
+
+fn jaro(buf: &mut JaroBuffers) -> f64 {
+    let JaroBuffers {
+        a,
+        b,
+        b_matched,
+        a_matches,
+    } = buf;
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    b_matched.clear();
+    b_matched.resize(b.len(), false);
+    a_matches.clear();
+
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    let matches = a_matches.len();
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(b_matched.iter())
+        .filter_map(|(c, matched)| matched.then_some(c));
+    let transpositions = b_matches
+        .zip(a_matches.iter())
+        .filter(|(x, y)| x != y)
+        .count()
+        / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+fn jaro_winkler_str(a: &str, b: &str, buf: &mut JaroBuffers) -> f64 {
+    buf.a.clear();
+    buf.a.extend(a.chars());
+    buf.b.clear();
+    buf.b.extend(b.chars());
+
+    let sim = jaro(buf);
+    let prefix = buf
+        .a
+        .iter()
+        .zip(buf.b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    sim + 0.1 * prefix as f64 * (1.0 - sim)
+}
+
+/// Compute the Levenshtein distance between the strings of `ca` and `other`. Unit length
+/// inputs are broadcasted.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+    check_lengths(ca, other)?;
+    let mut row = Vec::new();
+    Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+        levenshtein_str(a, b, &mut row)
+    }))
+}
+
+/// Compute the Hamming distance between the strings of `ca` and `other`. Strings of
+/// different lengths result in a null. Unit length inputs are broadcasted.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+    check_lengths(ca, other)?;
+    Ok(broadcast_binary_elementwise_values(ca, other, hamming_str))
+}
+
+/// Compute the Jaro-Winkler similarity between the strings of `ca` and `other`. Unit
+/// length inputs are broadcasted.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> PolarsResult<Float64Chunked> {
+    check_lengths(ca, other)?;
+    let mut buf = JaroBuffers::default();
+    Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+        jaro_winkler_str(a, b, &mut buf)
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        let mut row = vec![];
+        assert_eq!(levenshtein_str("kitten", "sitting", &mut row), 3);
+        assert_eq!(levenshtein_str("", "abc", &mut row), 3);
+        assert_eq!(levenshtein_str("flaw", "lawn", &mut row), 2);
+        assert_eq!(levenshtein_str("über", "uber", &mut row), 1);
+        assert_eq!(levenshtein_str("same", "same", &mut row), 0);
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(hamming_str("karolin", "kathrin"), Some(3));
+        assert_eq!(hamming_str("ä", "a"), Some(1));
+        assert_eq!(hamming_str("abc", "ab"), None);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let mut buf = JaroBuffers::default();
+        let mut check = |a, b, expected: f64| {
+            assert!((jaro_winkler_str(a, b, &mut buf) - expected).abs() < 1e-4)
+        };
+        check("MARTHA", "MARHTA", 0.9611);
+        check("DWAYNE", "DUANE", 0.84);
+        check("DIXON", "DICKSONX", 0.8133);
+        check("", "", 1.0);
+        check("", "x", 0.0);
+    }
+}
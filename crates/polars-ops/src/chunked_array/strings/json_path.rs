@@ -85,7 +85,7 @@ pub trait Utf8JsonPathImpl: AsString {
             ca.len(),
         )
         .map_err(|e| polars_err!(ComputeError: "error deserializing JSON: {}", e))?;
-        Series::try_from(("", array))
+        Series::try_from((ca.name(), array))
     }
 
     fn json_path_select(&self, json_path: &str) -> PolarsResult<StringChunked> {
@@ -2,6 +2,8 @@
 mod case;
 #[cfg(feature = "strings")]
 mod concat;
+#[cfg(feature = "string_distance")]
+mod distance;
 #[cfg(feature = "strings")]
 mod extract;
 #[cfg(feature = "find_many")]
@@ -28,6 +30,8 @@ mod unicode_internals;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
+#[cfg(feature = "string_distance")]
+pub use distance::*;
 #[cfg(feature = "find_many")]
 pub use find_many::*;
 #[cfg(feature = "extract_jsonpath")]
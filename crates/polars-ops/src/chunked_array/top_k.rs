@@ -1,6 +1,9 @@
 use either::Either;
 use polars_core::downcast_as_macro_arg_physical;
+use polars_core::prelude::sort::_broadcast_descending;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded;
 use polars_core::prelude::*;
+use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::total_ord::TotalOrd;
 
 fn arg_partition<T: TotalOrd>(v: &mut [T], k: usize, descending: bool) -> &[T] {
@@ -75,3 +78,90 @@ pub fn top_k(s: &[Series], descending: bool) -> PolarsResult<Series> {
         Ok(Series::full_null(src.name(), src.len(), dtype))
     }
 }
+
+/// Return the `k` elements of `s[0]` for which the columns `s[2..]` are largest, largest first.
+/// `s[1]` holds `k`. If `descending` is set for a column, its smallest values are considered
+/// the largest instead. Rows with nulls in the `by` columns are selected last.
+pub fn top_k_by(s: &[Series], mut descending: Vec<bool>) -> PolarsResult<Series> {
+    let src = &s[0];
+    let k_s = &s[1];
+    let by = &s[2..];
+
+    polars_ensure!(
+        k_s.len() == 1,
+        ComputeError: "k must be a single value."
+    );
+    polars_ensure!(!by.is_empty(), ComputeError: "`top_k_by` needs at least one `by` column");
+    for s in by {
+        polars_ensure!(
+            s.len() == src.len(),
+            ShapeMismatch: "`by` column '{}' has length {}, expected {}",
+            s.name(), s.len(), src.len()
+        );
+    }
+    _broadcast_descending(by.len(), &mut descending);
+    polars_ensure!(
+        descending.len() == by.len(),
+        ComputeError: "the length of `descending` ({}) does not match the number of `by` columns ({})",
+        descending.len(), by.len()
+    );
+
+    if src.is_empty() {
+        return Ok(src.clone());
+    }
+
+    let k_s = k_s.cast(&IDX_DTYPE)?;
+    let Some(k) = k_s.idx()?.get(0) else {
+        return Ok(Series::full_null(src.name(), src.len(), src.dtype()));
+    };
+    let k = std::cmp::min(k as usize, src.len());
+
+    // top-k is reverse from sort
+    let sort_descending = descending.iter().map(|d| !d).collect::<Vec<_>>();
+    let encoded = _get_rows_encoded(by, &sort_descending, true)?;
+    let arr = encoded.into_array();
+    let mut rows = arr
+        .values_iter()
+        .enumerate_idx()
+        .map(|(idx, bytes)| (bytes, idx))
+        .collect::<Vec<_>>();
+
+    // Only the first `k` rows need to be ordered.
+    let selected = if k < rows.len() {
+        let (lower, _el, _upper) = rows.select_nth_unstable(k);
+        lower
+    } else {
+        &mut rows[..]
+    };
+    selected.sort_unstable();
+
+    let idx: IdxCa = selected.iter().map(|(_, idx)| Some(*idx)).collect();
+    // SAFETY: the indices are in bounds as they were enumerated from rows of `src`'s length.
+    Ok(unsafe { src.take_unchecked(&idx) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_top_k_by() -> PolarsResult<()> {
+        let src = Series::new("a", &["a", "b", "c", "d", "e"]);
+        let k = Series::new("k", &[2 as IdxSize]);
+        let by = Series::new("by", &[Some(3), None, Some(5), Some(1), Some(4)]);
+
+        let out = top_k_by(&[src.clone(), k.clone(), by.clone()], vec![false])?;
+        assert_eq!(Vec::from(out.str()?), &[Some("c"), Some("e")]);
+
+        let out = top_k_by(&[src.clone(), k.clone(), by.clone()], vec![true])?;
+        assert_eq!(Vec::from(out.str()?), &[Some("d"), Some("a")]);
+
+        let k = Series::new("k", &[10 as IdxSize]);
+        let out = top_k_by(&[src, k, by], vec![false])?;
+        assert_eq!(
+            Vec::from(out.str()?),
+            &[Some("c"), Some("e"), Some("a"), Some("d"), Some("b")]
+        );
+        Ok(())
+    }
+}
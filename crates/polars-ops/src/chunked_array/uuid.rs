@@ -0,0 +1,174 @@
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::*;
+use rand::prelude::*;
+use rand::rngs::SmallRng;
+
+const UUID_LEN: usize = 16;
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parse a UUID in the hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`) or the simple
+/// (`67e5504410b1426f9247bb680e5fe0c8`) form. Both forms may be wrapped in braces or prefixed
+/// with `urn:uuid:`, and the hexadecimal digits are case insensitive.
+pub fn parse_uuid(s: &str) -> Option<[u8; UUID_LEN]> {
+    let s = s.as_bytes();
+    let s = if let Some(s) = s.strip_prefix(b"urn:uuid:") {
+        s
+    } else if let Some(s) = s.strip_prefix(b"{").and_then(|s| s.strip_suffix(b"}")) {
+        s
+    } else {
+        s
+    };
+    let hyphenated = match s.len() {
+        36 => true,
+        32 => false,
+        _ => return None,
+    };
+
+    let mut out = [0u8; UUID_LEN];
+    let mut digits = s.iter().enumerate().filter_map(|(i, &c)| {
+        if hyphenated && matches!(i, 8 | 13 | 18 | 23) {
+            // Signal an invalid separator with a non-hex character.
+            (c != b'-').then_some(b'x')
+        } else {
+            Some(c)
+        }
+    });
+    for byte in out.iter_mut() {
+        let hi = hex_value(digits.next()?)?;
+        let lo = hex_value(digits.next()?)?;
+        *byte = (hi << 4) | lo;
+    }
+    Some(out)
+}
+
+/// Format the bytes of a UUID in the lowercase hyphenated form.
+fn format_uuid<'a>(bytes: &[u8; UUID_LEN], buf: &'a mut [u8; 36]) -> &'a str {
+    let mut i = 0;
+    for (j, b) in bytes.iter().enumerate() {
+        if matches!(j, 4 | 6 | 8 | 10) {
+            buf[i] = b'-';
+            i += 1;
+        }
+        buf[i] = HEX_DIGITS[(b >> 4) as usize];
+        buf[i + 1] = HEX_DIGITS[(b & 0x0f) as usize];
+        i += 2;
+    }
+    // SAFETY: the buffer only contains ASCII characters.
+    unsafe { std::str::from_utf8_unchecked(buf) }
+}
+
+/// Check which strings are valid UUIDs, see [`parse_uuid`] for the accepted forms.
+pub fn is_uuid(ca: &StringChunked) -> BooleanChunked {
+    unary_elementwise_values(ca, |s: &str| parse_uuid(s).is_some())
+}
+
+/// Parse strings into the 16 bytes of a UUID, see [`parse_uuid`] for the accepted forms.
+///
+/// If `strict` is false, strings that are not valid UUIDs are null instead of raising an error.
+pub fn str_to_uuid(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut builder = BinaryChunkedBuilder::new(ca.name(), ca.len(), ca.len() * UUID_LEN);
+    for opt_s in ca {
+        match opt_s.map(|s| (s, parse_uuid(s))) {
+            Some((_, Some(bytes))) => builder.append_value(bytes),
+            Some((s, None)) if strict => polars_bail!(
+                ComputeError: "invalid UUID found: '{}'; try setting `strict=false` to ignore", s
+            ),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Format the 16 bytes of UUIDs in the lowercase hyphenated form.
+pub fn uuid_to_str(ca: &BinaryChunked) -> PolarsResult<StringChunked> {
+    let mut builder = StringChunkedBuilder::new(ca.name(), ca.len(), ca.len() * 36);
+    let mut buf = [0u8; 36];
+    for opt_v in ca {
+        match opt_v {
+            Some(v) => {
+                let bytes: &[u8; UUID_LEN] = v.try_into().map_err(|_| {
+                    polars_err!(
+                        ComputeError: "a UUID must be {} bytes long, got {} bytes", UUID_LEN, v.len()
+                    )
+                })?;
+                builder.append_value(format_uuid(bytes, &mut buf))
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Generate `len` random UUIDs of version 4 in the lowercase hyphenated form.
+pub fn uuid4(name: &str, len: usize, seed: Option<u64>) -> StringChunked {
+    let mut rng = match seed {
+        Some(seed) => SmallRng::seed_from_u64(seed),
+        None => SmallRng::from_entropy(),
+    };
+    let mut builder = StringChunkedBuilder::new(name, len, len * 36);
+    let mut buf = [0u8; 36];
+    for _ in 0..len {
+        let mut bytes = [0u8; UUID_LEN];
+        rng.fill_bytes(&mut bytes);
+        // Set the version (4) and the variant (RFC 4122).
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        builder.append_value(format_uuid(&bytes, &mut buf));
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid_roundtrip() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a",
+            &[
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("{67E5504410B1426F9247BB680E5FE0C8}"),
+                Some("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("67e55044-10b1-426f-9247_bb680e5fe0c8"),
+                None,
+            ],
+        );
+        assert_eq!(
+            Vec::from(&is_uuid(&ca)),
+            &[Some(true), Some(true), Some(true), Some(false), None]
+        );
+        assert!(str_to_uuid(&ca, true).is_err());
+
+        let bytes = str_to_uuid(&ca, false)?;
+        assert_eq!(bytes.get(0).map(|v| v.len()), Some(16));
+        let out = uuid_to_str(&bytes)?;
+        let expected = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(expected), Some(expected), Some(expected), None, None]
+        );
+        assert!(uuid_to_str(&BinaryChunked::from_slice("a", &[b"abc"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid4() {
+        let ca = uuid4("a", 10, Some(0));
+        assert_eq!(Vec::from(&ca), Vec::from(&uuid4("a", 10, Some(0))));
+        for s in ca.into_no_null_iter() {
+            let bytes = parse_uuid(s).unwrap();
+            assert_eq!(bytes[6] >> 4, 4);
+            assert_eq!(bytes[8] >> 6, 0b10);
+        }
+    }
+}
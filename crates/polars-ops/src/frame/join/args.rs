@@ -29,6 +29,8 @@ pub struct JoinArgs {
     pub suffix: Option<String>,
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hints: JoinHints,
 }
 
 impl Default for JoinArgs {
@@ -39,6 +41,7 @@ impl Default for JoinArgs {
             suffix: None,
             slice: None,
             join_nulls: false,
+            hints: Default::default(),
         }
     }
 }
@@ -51,6 +54,7 @@ impl JoinArgs {
             suffix: None,
             slice: None,
             join_nulls: false,
+            hints: Default::default(),
         }
     }
 
@@ -119,6 +123,91 @@ impl Debug for JoinType {
     }
 }
 
+/// The side of a join of which the hash table is built.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinBuildSide {
+    Left,
+    Right,
+}
+
+/// Hints that override the choices the join algorithms make based on the (estimated) sizes
+/// and sortedness of the join keys.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinHints {
+    /// Build the hash table of the join on this side, instead of on the smaller relation.
+    /// Respected by inner and outer joins, a left join always builds on the right side.
+    pub build_side: Option<JoinBuildSide>,
+    /// Broadcast the right relation: it is materialized completely and shared by all threads
+    /// that probe it, also if the left relation is smaller.
+    pub force_broadcast: bool,
+    /// Use a sort-merge join and sort the keys that are not sorted yet. Only applies to inner
+    /// and left joins on a single numeric key without nulls, a left join requires a sorted
+    /// left key. Requires the `performant` feature, other joins fall back to a hash join.
+    pub force_sort_merge: bool,
+    /// Don't run this join in the streaming engine.
+    pub disable_streaming: bool,
+}
+
+impl JoinHints {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// The side the hash table must be built on, if any.
+    pub fn build_side(&self) -> Option<JoinBuildSide> {
+        if self.force_broadcast {
+            Some(JoinBuildSide::Right)
+        } else {
+            self.build_side
+        }
+    }
+
+    pub fn validate(&self, how: &JoinType) -> PolarsResult<()> {
+        polars_ensure!(
+            !(self.force_broadcast && self.build_side == Some(JoinBuildSide::Left)),
+            InvalidOperation: "cannot broadcast the right side of a join that builds on the left side"
+        );
+        if self.force_sort_merge {
+            polars_ensure!(
+                self.build_side().is_none(),
+                InvalidOperation: "a sort-merge join has no build side"
+            );
+            polars_ensure!(
+                matches!(how, JoinType::Inner | JoinType::Left),
+                InvalidOperation: "a sort-merge join is not supported for a {} join", how
+            );
+        }
+        match (self.build_side, how) {
+            (None, _) | (Some(_), JoinType::Inner | JoinType::Outer { .. }) => Ok(()),
+            (Some(JoinBuildSide::Right), JoinType::Left) => Ok(()),
+            (Some(side), _) => polars_bail!(
+                InvalidOperation: "cannot set the build side of a {} join to {:?}", how, side
+            ),
+        }
+    }
+}
+
+impl Display for JoinHints {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut hints = vec![];
+        if self.force_broadcast {
+            hints.push("broadcast".to_string());
+        }
+        if let Some(side) = self.build_side {
+            hints.push(format!("build_side: {side:?}"));
+        }
+        if self.force_sort_merge {
+            hints.push("sort_merge".to_string());
+        }
+        if self.disable_streaming {
+            hints.push("no_streaming".to_string());
+        }
+        write!(f, "[{}]", hints.join(", "))
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinValidation {
@@ -3,7 +3,7 @@ use std::hash::Hash;
 use ahash::RandomState;
 use num_traits::Zero;
 use polars_core::hashing::{_df_rows_to_hashes_threaded_vertical, _HASHMAP_INIT_SIZE};
-use polars_core::utils::{split_ca, split_df};
+use polars_core::utils::{split_ca, split_df, try_get_supertype};
 use polars_core::POOL;
 use polars_utils::abs_diff::AbsDiff;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
@@ -309,15 +309,23 @@ where
     A: for<'a> AsofJoinState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    let out = if left_by.width() == 1 {
+    for (lhs, rhs) in left_by.get_columns().iter().zip(right_by.get_columns()) {
+        polars_ensure!(lhs.dtype() == rhs.dtype(),
+            ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`", lhs.dtype(), rhs.dtype()
+        );
+        #[cfg(feature = "dtype-categorical")]
+        _check_categorical_src(lhs.dtype(), rhs.dtype())?;
+    }
+    // A single key of a primitive type is probed directly, all other keys (multiple columns
+    // or nested/boolean dtypes) are joined on their row hashes.
+    let single_key = left_by.width() == 1 && {
+        let dtype = left_by.get_columns()[0].dtype().to_physical();
+        dtype.is_numeric() || matches!(dtype, DataType::String | DataType::Binary)
+    };
+    let out = if single_key {
         let left_by_s = left_by.get_columns()[0].to_physical_repr().into_owned();
         let right_by_s = right_by.get_columns()[0].to_physical_repr().into_owned();
-        let left_dtype = left_by_s.dtype();
-        let right_dtype = right_by_s.dtype();
-        polars_ensure!(left_dtype == right_dtype,
-            ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`", left_dtype, right_dtype
-        );
-        match left_dtype {
+        match left_by_s.dtype() {
             DataType::String => {
                 let left_by = &left_by_s.str().unwrap().as_binary();
                 let right_by = right_by_s.str().unwrap().as_binary();
@@ -345,13 +353,6 @@ where
             },
         }
     } else {
-        for (lhs, rhs) in left_by.get_columns().iter().zip(right_by.get_columns()) {
-            polars_ensure!(lhs.dtype() == rhs.dtype(),
-                ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`", lhs.dtype(), rhs.dtype()
-            );
-            #[cfg(feature = "dtype-categorical")]
-            _check_categorical_src(lhs.dtype(), rhs.dtype())?;
-        }
         asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
     };
     Ok(out)
@@ -524,6 +525,11 @@ pub trait AsofJoinBy: IntoDf {
             left_by.is_empty() && right_by.is_empty(),
         )?;
 
+        polars_ensure!(
+            left_by.len() == right_by.len(),
+            ComputeError: "the number of 'by' columns of an asof join must be equal: got {} and {}",
+            left_by.len(), right_by.len()
+        );
         let mut left_by = self_df.select(left_by)?;
         let mut right_by = other_df.select(right_by)?;
 
@@ -535,6 +541,12 @@ pub trait AsofJoinBy: IntoDf {
             {
                 #[cfg(feature = "dtype-categorical")]
                 _check_categorical_src(l.dtype(), r.dtype())?;
+                // numeric keys of a different width can still be matched
+                if l.dtype() != r.dtype() && l.dtype().is_numeric() && r.dtype().is_numeric() {
+                    let dtype = try_get_supertype(l.dtype(), r.dtype())?;
+                    *l = l.cast(&dtype)?;
+                    *r = r.cast(&dtype)?;
+                }
                 *l = l.to_physical_repr().into_owned();
                 *r = r.to_physical_repr().into_owned();
             }
@@ -814,4 +826,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_multiple_any_dtype() -> PolarsResult<()> {
+        let left = df![
+            "time" => [1i64, 5, 10, 10],
+            "flag" => [true, true, false, true],
+            "group" => [1i32, 1, 2, 2]
+        ]?;
+        let right = df![
+            "time" => [0i64, 2, 3, 4, 9, 10],
+            "flag" => [true, true, true, false, false, true],
+            "group" => [1i64, 1, 1, 2, 2, 2],
+            "right_vals" => [1, 2, 3, 4, 5, 6]
+        ]?;
+
+        // a single boolean key
+        let out = left.join_asof_by(
+            &right,
+            "time",
+            "time",
+            ["flag"],
+            ["flag"],
+            AsofStrategy::Backward,
+            None,
+        )?;
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(Vec::from(out), &[Some(1), Some(3), Some(5), Some(6)]);
+
+        // multiple keys of different integer widths
+        let out = left.join_asof_by(
+            &right,
+            "time",
+            "time",
+            ["flag", "group"],
+            ["flag", "group"],
+            AsofStrategy::Backward,
+            Some(AnyValue::Int64(2)),
+        )?;
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(Vec::from(out), &[Some(1), Some(3), Some(5), Some(6)]);
+        Ok(())
+    }
 }
@@ -24,6 +24,44 @@ trait AsofJoinState<T>: Default {
     ) -> Option<IdxSize>;
 }
 
+/// Exponential search over the sorted right values, starting at `offset`.
+///
+/// Returns the last index for which `skip` holds, such that the linear scan of the join states
+/// can continue from there. This makes a lookup `O(log n)` instead of `O(n)` if the state has
+/// to jump far ahead, e.g. on the first lookup in a group of an asof join `by` groups.
+/// Returns `offset` if no probed value could be skipped.
+#[inline]
+fn gallop<T, F, S>(offset: IdxSize, n_right: IdxSize, right: &mut F, skip: S) -> IdxSize
+where
+    F: FnMut(IdxSize) -> Option<T>,
+    S: Fn(&T) -> bool,
+{
+    let mut lo = offset;
+    let mut hi = n_right;
+    let mut step = 1;
+    while let Some(probe) = lo.checked_add(step).filter(|probe| *probe < n_right) {
+        match right(probe) {
+            Some(v) if skip(&v) => {
+                lo = probe;
+                step = step.saturating_mul(2);
+            },
+            _ => {
+                hi = probe;
+                break;
+            },
+        }
+    }
+    // Nulls are never skipped; the linear scan takes care of them.
+    while lo + 1 < hi {
+        let mid = lo + (hi - lo) / 2;
+        match right(mid) {
+            Some(v) if skip(&v) => lo = mid,
+            _ => hi = mid,
+        }
+    }
+    lo
+}
+
 #[derive(Default)]
 struct AsofJoinForwardState {
     scan_offset: IdxSize,
@@ -37,6 +75,8 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        // all values before the first value >= left_val can be skipped
+        self.scan_offset = gallop(self.scan_offset, n_right, &mut right, |v| v < left_val);
         while (self.scan_offset) < n_right {
             if let Some(right_val) = right(self.scan_offset) {
                 if right_val >= *left_val {
@@ -64,6 +104,11 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        let offset = gallop(self.scan_offset, n_right, &mut right, |v| v <= left_val);
+        if offset > self.scan_offset {
+            self.best_bound = Some(offset);
+            self.scan_offset = offset;
+        }
         while self.scan_offset < n_right {
             if let Some(right_val) = right(self.scan_offset) {
                 if right_val <= *left_val {
@@ -48,6 +48,14 @@ macro_rules! det_hash_prone_order {
             ($other, $self, true)
         }
     }};
+    // The hash table is built on the second relation, unless a build side is given.
+    ($self:expr, $other:expr, $build_side:expr) => {{
+        match $build_side {
+            Some(JoinBuildSide::Right) => ($self, $other, false),
+            Some(JoinBuildSide::Left) => ($other, $self, true),
+            None => det_hash_prone_order!($self, $other),
+        }
+    }};
 }
 
 #[cfg(feature = "performant")]
@@ -200,7 +208,14 @@ pub trait JoinDispatch: IntoDf {
             right.as_single_chunk_par();
             s_right = s_right.rechunk();
         }
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation, args.join_nulls)?;
+        let ids = sort_or_hash_left(
+            &s_left,
+            &s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            &args.hints,
+        )?;
         left._finish_left_join(ids, &right.drop(s_right.name()).unwrap(), args)
     }
 
@@ -249,8 +264,12 @@ pub trait JoinDispatch: IntoDf {
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
 
         // Get the indexes of the joined relations
-        let (mut join_idx_l, mut join_idx_r) =
-            s_left.hash_join_outer(s_right, args.validation, args.join_nulls)?;
+        let (mut join_idx_l, mut join_idx_r) = s_left.hash_join_outer(
+            s_right,
+            args.validation,
+            args.join_nulls,
+            args.hints.build_side(),
+        )?;
 
         if let Some((offset, len)) = args.slice {
             let (offset, len) = slice_offsets(offset, len, join_idx_l.len());
@@ -28,11 +28,17 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary(lhs, rhs, false);
+                let (lhs, rhs, _, _) = prepare_binary(lhs, rhs, Some(JoinBuildSide::Right));
                 let lhs = lhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 hash_join_tuples_left(lhs, rhs, None, None, validate, join_nulls)
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                let lhs = lhs.decimal().unwrap();
+                let rhs = rhs.decimal().unwrap();
+                num_group_join_left(&lhs.0, &rhs.0, validate, join_nulls)
+            },
             _ => {
                 if s_self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
@@ -62,7 +68,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, _, _) = prepare_binary(lhs, rhs, false);
+                let (lhs, rhs, _, _) = prepare_binary(lhs, rhs, Some(JoinBuildSide::Right));
                 let lhs = lhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 if anti {
@@ -71,6 +77,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     hash_join_tuples_left_semi(lhs, rhs)
                 }
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                let lhs = lhs.decimal().unwrap();
+                let rhs = rhs.decimal().unwrap();
+                num_group_join_anti_semi(&lhs.0, &rhs.0, anti)
+            },
             _ => {
                 if s_self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
@@ -91,22 +103,25 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         other: &Series,
         validate: JoinValidation,
         join_nulls: bool,
+        build_side: Option<JoinBuildSide>,
     ) -> PolarsResult<(InnerJoinIds, bool)> {
         let s_self = self.as_series();
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
         validate.validate_probe(&lhs, &rhs, true)?;
+        // the validation assumes that the hash table is built on the shortest relation
+        let build_side = build_side.filter(|_| !validate.needs_checks());
 
         use DataType::*;
         match lhs.dtype() {
             String => {
                 let lhs = lhs.cast(&Binary).unwrap();
                 let rhs = rhs.cast(&Binary).unwrap();
-                lhs.hash_join_inner(&rhs, JoinValidation::ManyToMany, join_nulls)
+                lhs.hash_join_inner(&rhs, JoinValidation::ManyToMany, join_nulls, build_side)
             },
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary(lhs, rhs, build_side);
                 let lhs = lhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 Ok((
@@ -114,15 +129,21 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     !swapped,
                 ))
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                let lhs = lhs.decimal().unwrap();
+                let rhs = rhs.decimal().unwrap();
+                group_join_inner::<Int128Type>(&lhs.0, &rhs.0, validate, join_nulls, build_side)
+            },
             _ => {
                 if s_self.bit_repr_is_large() {
                     let lhs = s_self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    group_join_inner::<UInt64Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt64Type>(&lhs, &rhs, validate, join_nulls, build_side)
                 } else {
                     let lhs = s_self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls, build_side)
                 }
             },
         }
@@ -133,35 +154,44 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         other: &Series,
         validate: JoinValidation,
         join_nulls: bool,
+        build_side: Option<JoinBuildSide>,
     ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
         let s_self = self.as_series();
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
         validate.validate_probe(&lhs, &rhs, true)?;
+        // the validation assumes that the hash table is built on the shortest relation
+        let build_side = build_side.filter(|_| !validate.needs_checks());
 
         use DataType::*;
         match lhs.dtype() {
             String => {
                 let lhs = lhs.cast(&Binary).unwrap();
                 let rhs = rhs.cast(&Binary).unwrap();
-                lhs.hash_join_outer(&rhs, JoinValidation::ManyToMany, join_nulls)
+                lhs.hash_join_outer(&rhs, JoinValidation::ManyToMany, join_nulls, build_side)
             },
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) = prepare_binary(lhs, rhs, build_side);
                 let lhs = lhs.iter().collect::<Vec<_>>();
                 let rhs = rhs.iter().collect::<Vec<_>>();
                 hash_join_tuples_outer(lhs, rhs, swapped, validate, join_nulls)
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                let lhs = lhs.decimal().unwrap();
+                let rhs = rhs.decimal().unwrap();
+                hash_join_outer(&lhs.0, &rhs.0, validate, join_nulls, build_side)
+            },
             _ => {
                 if s_self.bit_repr_is_large() {
                     let lhs = s_self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    hash_join_outer(&lhs, &rhs, validate, join_nulls)
+                    hash_join_outer(&lhs, &rhs, validate, join_nulls, build_side)
                 } else {
                     let lhs = s_self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    hash_join_outer(&lhs, &rhs, validate, join_nulls)
+                    hash_join_outer(&lhs, &rhs, validate, join_nulls, build_side)
                 }
             },
         }
@@ -189,6 +219,7 @@ fn group_join_inner<T>(
     right: &ChunkedArray<T>,
     validate: JoinValidation,
     join_nulls: bool,
+    build_side: Option<JoinBuildSide>,
 ) -> PolarsResult<(InnerJoinIds, bool)>
 where
     T: PolarsDataType,
@@ -196,7 +227,7 @@ where
     for<'a> T::Physical<'a>: Hash + Eq + Send + DirtyHash + Copy + Send + Sync + IsNull,
 {
     let n_threads = POOL.current_num_threads();
-    let (a, b, swapped) = det_hash_prone_order!(left, right);
+    let (a, b, swapped) = det_hash_prone_order!(left, right, build_side);
     let splitted_a = split_ca(a, n_threads).unwrap();
     let splitted_b = split_ca(b, n_threads).unwrap();
     let splitted_a = get_arrays(&splitted_a);
@@ -330,12 +361,13 @@ fn hash_join_outer<T>(
     other: &ChunkedArray<T>,
     validate: JoinValidation,
     join_nulls: bool,
+    build_side: Option<JoinBuildSide>,
 ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)>
 where
     T: PolarsIntegerType + Sync,
     T::Native: Eq + Hash + NumCast,
 {
-    let (a, b, swapped) = det_hash_prone_order!(ca_in, other);
+    let (a, b, swapped) = det_hash_prone_order!(ca_in, other, build_side);
 
     let n_partitions = _set_partition_size();
     let splitted_a = split_ca(a, n_partitions).unwrap();
@@ -389,9 +421,10 @@ pub fn prepare_bytes<'a>(
 fn prepare_binary<'a>(
     ca: &'a BinaryChunked,
     other: &'a BinaryChunked,
-    // In inner join and outer join, the shortest relation will be used to create a hash table.
+    // In inner join and outer join, the shortest relation will be used to create a hash table,
+    // unless a build side is given.
     // In left join, always use the right side to create.
-    build_shortest_table: bool,
+    build_side: Option<JoinBuildSide>,
 ) -> (
     Vec<Vec<BytesHash<'a>>>,
     Vec<Vec<BytesHash<'a>>>,
@@ -400,11 +433,7 @@ fn prepare_binary<'a>(
 ) {
     let n_threads = POOL.current_num_threads();
 
-    let (a, b, swapped) = if build_shortest_table {
-        det_hash_prone_order!(ca, other)
-    } else {
-        (ca, other, false)
-    };
+    let (a, b, swapped) = det_hash_prone_order!(ca, other, build_side);
 
     let hb = RandomState::default();
     let splitted_a = split_ca(a, n_threads).unwrap();
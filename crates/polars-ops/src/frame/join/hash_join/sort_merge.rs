@@ -180,8 +180,9 @@ pub fn _sort_or_hash_inner(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
-    s_left.hash_join_inner(s_right, validate, join_nulls)
+    s_left.hash_join_inner(s_right, validate, join_nulls, hints.build_side())
 }
 
 #[cfg(feature = "performant")]
@@ -191,6 +192,7 @@ pub fn _sort_or_hash_inner(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
@@ -202,12 +204,18 @@ pub fn _sort_or_hash_inner(
         .map(|s| s.parse::<f32>().unwrap())
         .unwrap_or(1.0);
     let is_numeric = s_left.dtype().to_physical().is_numeric();
+    let build_side = hints.build_side();
 
-    if validate.needs_checks() {
-        return s_left.hash_join_inner(s_right, validate, join_nulls);
+    // a hash join is requested by the build side hint
+    if validate.needs_checks() || build_side.is_some() {
+        return s_left.hash_join_inner(s_right, validate, join_nulls, build_side);
     }
+    let force = hints.force_sort_merge;
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
+    if force && verbose && !(is_numeric && no_nulls) {
+        eprintln!("inner join: sort-merge join requires numeric keys without nulls: use hash join");
+    }
     match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
         (IsSorted::Ascending, IsSorted::Ascending, true) if is_numeric => {
             if verbose {
@@ -216,7 +224,7 @@ pub fn _sort_or_hash_inner(
             Ok((par_sorted_merge_inner_no_nulls(s_left, s_right), true))
         },
         (IsSorted::Ascending, _, true)
-            if is_numeric && size_factor_rhs < size_factor_acceptable =>
+            if is_numeric && (force || size_factor_rhs < size_factor_acceptable) =>
         {
             if verbose {
                 eprintln!("right key will be descending sorted in inner join operation.")
@@ -243,7 +251,7 @@ pub fn _sort_or_hash_inner(
             Ok(((left, right), true))
         },
         (_, IsSorted::Ascending, true)
-            if is_numeric && size_factor_lhs < size_factor_acceptable =>
+            if is_numeric && (force || size_factor_lhs < size_factor_acceptable) =>
         {
             if verbose {
                 eprintln!("left key will be descending sorted in inner join operation.")
@@ -270,7 +278,36 @@ pub fn _sort_or_hash_inner(
             // set sorted to `false` as we descending sorted the left key.
             Ok(((left, right), false))
         },
-        _ => s_left.hash_join_inner(s_right, validate, join_nulls),
+        (_, _, true) if is_numeric && force => {
+            if verbose {
+                eprintln!("both keys will be sorted in inner join operation.")
+            }
+            let sort_options = SortOptions {
+                descending: false,
+                nulls_last: false,
+                multithreaded: true,
+                maintain_order: false,
+            };
+            let sort_idx_left = s_left.arg_sort(sort_options);
+            let sort_idx_right = s_right.arg_sort(sort_options);
+            let s_left = unsafe { s_left.take_unchecked(&sort_idx_left) };
+            let s_right = unsafe { s_right.take_unchecked(&sort_idx_right) };
+            let (mut left, mut right) = par_sorted_merge_inner_no_nulls(&s_left, &s_right);
+            let reverse_idx_map_left = create_reverse_map_from_arg_sort(sort_idx_left);
+            let reverse_idx_map_right = create_reverse_map_from_arg_sort(sort_idx_right);
+
+            POOL.install(|| {
+                left.par_iter_mut().for_each(|idx| {
+                    *idx = unsafe { *reverse_idx_map_left.get_unchecked(*idx as usize) };
+                });
+                right.par_iter_mut().for_each(|idx| {
+                    *idx = unsafe { *reverse_idx_map_right.get_unchecked(*idx as usize) };
+                });
+            });
+
+            Ok(((left, right), false))
+        },
+        _ => s_left.hash_join_inner(s_right, validate, join_nulls, None),
     }
 }
 
@@ -281,6 +318,7 @@ pub(super) fn sort_or_hash_left(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    _hints: &JoinHints,
 ) -> PolarsResult<LeftJoinIds> {
     s_left.hash_join_left(s_right, validate, join_nulls)
 }
@@ -292,6 +330,7 @@ pub(super) fn sort_or_hash_left(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hints: &JoinHints,
 ) -> PolarsResult<LeftJoinIds> {
     if validate.needs_checks() {
         return s_left.hash_join_left(s_right, validate, join_nulls);
@@ -314,7 +353,8 @@ pub(super) fn sort_or_hash_left(
             Ok(to_left_join_ids(left_idx, right_idx))
         },
         (IsSorted::Ascending, _, true)
-            if is_numeric && size_factor_rhs < size_factor_acceptable =>
+            if is_numeric
+                && (hints.force_sort_merge || size_factor_rhs < size_factor_acceptable) =>
         {
             if verbose {
                 eprintln!("right key will be reverse sorted in left join operation.")
@@ -1,6 +1,8 @@
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::series::IsSorted;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
+use rayon::prelude::*;
 
 pub fn _merge_sorted_dfs(
     left: &DataFrame,
@@ -46,6 +48,47 @@ pub fn _merge_sorted_dfs(
     Ok(DataFrame::new_no_checks(new_columns))
 }
 
+/// Merge frames that are all sorted ascending by the `key` column into a single frame that is
+/// sorted by `key`.
+///
+/// The frames are merged pairwise in a balanced tree, the merges of a level run in parallel.
+/// This needs `log2(dfs.len())` passes over the data instead of a full re-sort.
+pub fn merge_sorted_dfs(dfs: &[DataFrame], key: &str) -> PolarsResult<DataFrame> {
+    let Some(first) = dfs.first() else {
+        polars_bail!(NoData: "cannot merge an empty list of frames");
+    };
+    for df in &dfs[1..] {
+        first.schema_equal(df)?;
+    }
+
+    let mut dfs = dfs.to_vec();
+    while dfs.len() > 1 {
+        dfs = POOL.install(|| {
+            dfs.par_chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => {
+                        _merge_sorted_dfs(left, right, left.column(key)?, right.column(key)?, false)
+                    },
+                    _ => Ok(pair[0].clone()),
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+    }
+    let mut out = dfs.pop().unwrap();
+    set_sorted_key(&mut out, key)?;
+    Ok(out)
+}
+
+/// Mark the `key` column of a merged frame as sorted.
+pub fn set_sorted_key(df: &mut DataFrame, key: &str) -> PolarsResult<()> {
+    df.apply(key, |s| {
+        let mut s = s.clone();
+        s.set_sorted_flag(IsSorted::Ascending);
+        s
+    })?;
+    Ok(())
+}
+
 fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> Series {
     use DataType::*;
     match lhs.dtype() {
@@ -217,6 +260,49 @@ where
     out
 }
 
+#[test]
+fn test_merge_sorted_dfs() -> PolarsResult<()> {
+    use polars_core::df;
+
+    let dfs = [
+        df!["a" => [1, 4, 7], "b" => ["a", "b", "c"]]?,
+        df!["a" => [2, 4], "b" => ["d", "e"]]?,
+        df!["a" => [0, 9], "b" => ["f", "g"]]?,
+    ];
+    let out = merge_sorted_dfs(&dfs, "a")?;
+    let a = out.column("a")?;
+    assert_eq!(
+        Vec::from(a.i32()?),
+        &[
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(4),
+            Some(4),
+            Some(7),
+            Some(9)
+        ]
+    );
+    assert_eq!(a.is_sorted_flag(), IsSorted::Ascending);
+    // equal keys keep the order of the frames
+    assert_eq!(
+        Vec::from(out.column("b")?.str()?),
+        &[
+            Some("f"),
+            Some("a"),
+            Some("d"),
+            Some("b"),
+            Some("e"),
+            Some("c"),
+            Some("g")
+        ]
+    );
+
+    assert!(merge_sorted_dfs(&dfs[..1], "a")?.equals(&dfs[0]));
+    assert!(merge_sorted_dfs(&[], "a").is_err());
+    Ok(())
+}
+
 #[test]
 fn test_merge_sorted() {
     fn get_merge_indicator_sliced<T: PartialOrd + Default + Copy>(a: &[T], b: &[T]) -> Vec<bool> {
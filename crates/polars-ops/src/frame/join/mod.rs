@@ -30,10 +30,12 @@ pub use general::{_finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "merge_sorted")]
-pub use merge_sorted::_merge_sorted_dfs;
+pub use merge_sorted::{_merge_sorted_dfs, merge_sorted_dfs, set_sorted_key};
 use polars_core::hashing::{_df_rows_to_hashes_threaded_vertical, _HASHMAP_INIT_SIZE};
 use polars_core::prelude::*;
 pub(super) use polars_core::series::IsSorted;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::utils::try_get_supertype;
 use polars_core::utils::{_to_physical_and_bit_repr, slice_offsets, slice_slice};
 use polars_core::POOL;
 use polars_utils::hashing::BytesHash;
@@ -119,6 +121,72 @@ pub trait DataFrameJoinOps: IntoDf {
             return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
         }
 
+        // Probe an index of the right DataFrame on the join keys instead of building a hash table.
+        if matches!(args.how, JoinType::Inner | JoinType::Left)
+            && !args.join_nulls
+            && !args.validation.needs_checks()
+            && selected_left.len() == selected_right.len()
+            && selected_left
+                .iter()
+                .zip(&selected_right)
+                .all(|(l, r)| l.dtype() == r.dtype())
+        {
+            let names = selected_right.iter().map(|s| s.name()).collect::<Vec<_>>();
+            if let Some(index) = other
+                .get_index(&names)
+                .filter(|index| index.is_on(&selected_right))
+            {
+                if _verbose {
+                    eprintln!("{:?} join uses the index on {:?}", args.how, names);
+                }
+                let (join_idx_left, join_idx_right) = index.probe(&selected_left)?;
+                let other = remove_selected(other, &selected_right);
+                if let JoinType::Inner = args.how {
+                    let mut join_idx_left = &*join_idx_left;
+                    let mut join_idx_right = &*join_idx_right;
+                    if let Some((offset, len)) = args.slice {
+                        join_idx_left = slice_slice(join_idx_left, offset, len);
+                        join_idx_right = slice_slice(join_idx_right, offset, len);
+                    }
+                    // safety: the index returns rows in bounds of both DataFrames
+                    let (df_left, df_right) = POOL.join(
+                        || unsafe {
+                            left_df._create_left_df_from_slice(join_idx_left, false, true)
+                        },
+                        || unsafe { other._take_unchecked_slice(join_idx_right, true) },
+                    );
+                    return _finish_join(df_left, df_right, args.suffix.as_deref());
+                }
+
+                // Left rows without a match are kept with nulls on the right.
+                let mut left_idx = Vec::with_capacity(left_df.height());
+                let mut right_idx = Vec::with_capacity(left_df.height());
+                let mut matches = join_idx_left.iter().zip(&join_idx_right).peekable();
+                for row in 0..left_df.height() as IdxSize {
+                    let start = left_idx.len();
+                    while let Some((_, &r)) = matches.next_if(|(&l, _)| l == row) {
+                        left_idx.push(row);
+                        right_idx.push(Some(r));
+                    }
+                    if left_idx.len() == start {
+                        left_idx.push(row);
+                        right_idx.push(None);
+                    }
+                }
+                #[cfg(feature = "chunked_ids")]
+                let ids = (Either::Left(left_idx), Either::Left(right_idx));
+                #[cfg(not(feature = "chunked_ids"))]
+                let ids = match args.slice {
+                    Some((offset, len)) => (
+                        slice_slice(&left_idx, offset, len).to_vec(),
+                        slice_slice(&right_idx, offset, len).to_vec(),
+                    ),
+                    None => (left_idx, right_idx),
+                };
+                return left_df._finish_left_join(ids, &other, args);
+            }
+        }
+
         #[cfg(feature = "chunked_ids")]
         {
             // a left join create chunked-ids
@@ -168,6 +236,31 @@ pub trait DataFrameJoinOps: IntoDf {
                 )
         );
 
+        // Enum keys are joined on their physical representation without a global string
+        // cache, so a String or Categorical key on the other side is cast to the enum. Values
+        // that are not in the enum become null and therefore don't match.
+        #[cfg(feature = "dtype-categorical")]
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            let can_cast_to_enum =
+                |dt: &DataType| matches!(dt, DataType::String | DataType::Categorical(_, _));
+            if l.dtype().is_enum() && !r.dtype().is_enum() && can_cast_to_enum(r.dtype()) {
+                *r = r.cast(l.dtype())?;
+            } else if r.dtype().is_enum() && !l.dtype().is_enum() && can_cast_to_enum(l.dtype()) {
+                *l = l.cast(r.dtype())?;
+            }
+        }
+
+        // Decimal keys only need to agree on their scale to be compared, so bring both sides
+        // to a common precision and scale.
+        #[cfg(feature = "dtype-decimal")]
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            if l.dtype().is_decimal() && r.dtype().is_decimal() && l.dtype() != r.dtype() {
+                let st = try_get_supertype(l.dtype(), r.dtype())?;
+                *l = l.cast(&st)?;
+                *r = r.cast(&st)?;
+            }
+        }
+
         if let Some((l, r)) = selected_left
             .iter()
             .zip(&selected_right)
@@ -275,7 +368,8 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Inner => {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
-                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                let (mut left, mut right, swap) =
+                    det_hash_prone_order!(left, right, args.hints.build_side());
                 let (join_idx_left, join_idx_right) =
                     _inner_join_multiple_keys(&mut left, &mut right, swap, args.join_nulls);
                 let mut join_idx_left = &*join_idx_left;
@@ -312,7 +406,8 @@ pub trait DataFrameJoinOps: IntoDf {
                 let df_left = DataFrame::new_no_checks(selected_left_physical);
                 let df_right = DataFrame::new_no_checks(selected_right_physical);
 
-                let (mut left, mut right, swap) = det_hash_prone_order!(df_left, df_right);
+                let (mut left, mut right, swap) =
+                    det_hash_prone_order!(df_left, df_right, args.hints.build_side());
                 let (mut join_idx_l, mut join_idx_r) =
                     _outer_join_multiple_keys(&mut left, &mut right, swap, args.join_nulls);
 
@@ -485,8 +580,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.join_nulls)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = _sort_or_hash_inner(
+            s_left,
+            s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            &args.hints,
+        )?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
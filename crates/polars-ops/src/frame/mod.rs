@@ -87,6 +87,53 @@ pub trait DataFrameOps: IntoDf {
         self._to_dummies(Some(columns), separator, drop_first)
     }
 
+    /// Count the occurrences of the distinct combinations of values in the `subset` columns, or
+    /// in all columns if `subset` is `None`.
+    ///
+    /// The counts are added in a column `"count"` with dtype [`IdxType`]. If `normalize` is set,
+    /// the fraction of the rows is added in a column `"proportion"` instead. If `sort` is set,
+    /// the output is sorted by the counts in descending order.
+    fn value_counts(
+        &self,
+        subset: Option<&[String]>,
+        sort: bool,
+        normalize: bool,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        let name = if normalize { "proportion" } else { "count" };
+        let subset = match subset {
+            Some(subset) => subset.to_vec(),
+            None => df
+                .get_column_names()
+                .into_iter()
+                .map(|c| c.to_string())
+                .collect(),
+        };
+        polars_ensure!(
+            !subset.is_empty(),
+            InvalidOperation: "`value_counts` needs at least one column to count"
+        );
+        polars_ensure!(
+            !subset.iter().any(|c| c == name),
+            Duplicate: "using `value_counts` on a column named '{}' would lead to duplicate column names", name
+        );
+
+        let mut out = df.group_by_stable(&subset)?.len()?;
+        let counts = out.drop_in_place("len")?;
+        let counts = if normalize {
+            let counts = counts.cast(&DataType::Float64)?;
+            counts / df.height() as f64
+        } else {
+            counts
+        };
+        out.with_column(counts.with_name(name))?;
+        if sort {
+            out.sort([name], true, true)
+        } else {
+            Ok(out)
+        }
+    }
+
     #[cfg(feature = "to_dummies")]
     fn _to_dummies(
         &self,
@@ -0,0 +1,40 @@
+use arrow::array::Array;
+use polars_core::frame::group_by::aggregations::{_agg_helper_idx, _agg_helper_slice};
+use polars_core::prelude::*;
+
+/// Apply `f` to the non-null values of every group of `s`, cast to `Float64`.
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub(crate) unsafe fn agg_float_values<F>(
+    s: &Series,
+    groups: &GroupsProxy,
+    f: F,
+) -> PolarsResult<Series>
+where
+    F: Fn(&[f64]) -> Option<f64> + Send + Sync,
+{
+    let s_f64 = s.cast(&DataType::Float64)?;
+    let ca = s_f64.f64().unwrap().rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let gather = |idx: &mut dyn Iterator<Item = usize>| {
+        idx.filter_map(|i| arr.get_unchecked(i)).collect::<Vec<_>>()
+    };
+
+    let out = match groups {
+        GroupsProxy::Idx(groups) => _agg_helper_idx::<Float64Type, _>(groups, |(_, idx)| {
+            f(&gather(&mut idx.iter().map(|i| *i as usize)))
+        }),
+        GroupsProxy::Slice { groups, .. } => {
+            _agg_helper_slice::<Float64Type, _>(groups, |[first, len]| {
+                let (first, len) = (first as usize, len as usize);
+                if arr.null_count() == 0 {
+                    f(&arr.values()[first..first + len])
+                } else {
+                    f(&gather(&mut (first..first + len)))
+                }
+            })
+        },
+    };
+    Ok(out.with_name(s.name()))
+}
@@ -0,0 +1,245 @@
+//! # t-digest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest of Dunning & Ertl, which
+//! summarizes a distribution in a bounded number of centroids so that
+//! [`crate::series::approx_quantile`] can be computed in a single pass. Digests can be merged,
+//! which makes them suitable for partitioned and streaming aggregations.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_ops::prelude::*;
+//!     let mut digest = TDigest::new();
+//!     (1..=100).for_each(|v| digest.add(v as f64));
+//!
+//!     assert_eq!(digest.quantile(0.0), Some(1.0));
+//!     assert_eq!(digest.quantile(1.0), Some(100.0));
+//! ```
+
+use std::f64::consts::PI;
+
+use polars_utils::total_ord::TotalOrd;
+
+/// The default compression. Larger values give more accurate quantiles but use more memory: a
+/// digest holds at most about `compression` centroids.
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Copy, Clone, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Compressed centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values that have been added but not yet compressed into the centroids.
+    buffer: Vec<f64>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TDigest {
+    /// Creates a new, empty t-digest with the [`DEFAULT_COMPRESSION`].
+    pub fn new() -> Self {
+        Self::with_compression(DEFAULT_COMPRESSION)
+    }
+
+    /// Creates a new, empty t-digest with the given `compression`.
+    pub fn with_compression(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// The number of values summarized by this digest.
+    pub fn count(&self) -> usize {
+        self.count as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0.0
+    }
+
+    /// Adds a value to the digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.buffer.push(value);
+        self.count += 1.0;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress(&[]);
+        }
+    }
+
+    /// Merge the other [`TDigest`] into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        self.buffer.extend_from_slice(&other.buffer);
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.compress(&other.centroids);
+    }
+
+    /// Estimate the `quantile` of the added values, interpolating linearly between the centroids.
+    ///
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        self.compress(&[]);
+        let centroids = &self.centroids;
+        if centroids.len() == 1 {
+            return Some(centroids[0].mean);
+        }
+
+        let target = quantile.clamp(0.0, 1.0) * self.count;
+        let first = centroids[0];
+        if target < first.weight / 2.0 {
+            return Some(interpolate(
+                self.min,
+                first.mean,
+                target / (first.weight / 2.0),
+            ));
+        }
+        let last = centroids[centroids.len() - 1];
+        if target > self.count - last.weight / 2.0 {
+            let past_center = target - (self.count - last.weight / 2.0);
+            return Some(interpolate(
+                last.mean,
+                self.max,
+                past_center / (last.weight / 2.0),
+            ));
+        }
+
+        // The weight up to the center of the current centroid.
+        let mut center = first.weight / 2.0;
+        for pair in centroids.windows(2) {
+            let (left, right) = (pair[0], pair[1]);
+            let next_center = center + (left.weight + right.weight) / 2.0;
+            if target <= next_center {
+                let fraction = (target - center) / (next_center - center);
+                return Some(interpolate(left.mean, right.mean, fraction));
+            }
+            center = next_center;
+        }
+        Some(last.mean)
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression * 5.0) as usize
+    }
+
+    /// The scale function `k1`, which keeps centroids near the tails small.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_inv(&self, k: f64) -> f64 {
+        ((2.0 * PI * k / self.compression).sin() + 1.0) / 2.0
+    }
+
+    /// Merge the buffered values and `other` centroids into the centroids of this digest.
+    fn compress(&mut self, other: &[Centroid]) {
+        if self.buffer.is_empty() && other.is_empty() {
+            return;
+        }
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend_from_slice(other);
+        centroids.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        centroids.sort_unstable_by(|a, b| a.mean.tot_cmp(&b.mean));
+
+        let total = centroids.iter().map(|c| c.weight).sum::<f64>();
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut current = centroids[0];
+        let mut weight_so_far = 0.0;
+        let mut q_limit = self.k_inv(self.k(0.0) + 1.0);
+        for &next in &centroids[1..] {
+            let q = (weight_so_far + current.weight + next.weight) / total;
+            if q <= q_limit {
+                current.weight += next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / current.weight;
+            } else {
+                weight_so_far += current.weight;
+                merged.push(current);
+                q_limit = self.k_inv(self.k(weight_so_far / total) + 1.0);
+                current = next;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+}
+
+#[inline]
+fn interpolate(lower: f64, upper: f64, fraction: f64) -> f64 {
+    lower + (upper - lower) * fraction
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_small_is_exact() {
+        let mut digest = TDigest::new();
+        [5.0, 1.0, f64::NAN, 3.0, 2.0, 4.0]
+            .into_iter()
+            .for_each(|v| digest.add(v));
+        assert_eq!(digest.count(), 5);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+        assert_eq!(TDigest::new().quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_tdigest_merge() {
+        let n = 100_000;
+        let mut digests = (0..4).map(|_| TDigest::new()).collect::<Vec<_>>();
+        for i in 0..n {
+            // Spread the values over the digests out of order.
+            digests[i % 4].add(((i * 7919) % n) as f64);
+        }
+        let mut digest = TDigest::new();
+        digests.iter().for_each(|other| digest.merge(other));
+
+        assert_eq!(digest.count(), n);
+        assert!(digest.centroids.len() <= 2 * DEFAULT_COMPRESSION as usize);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = digest.quantile(q).unwrap();
+            let expected = q * (n - 1) as f64;
+            assert!(
+                (estimate - expected).abs() < 0.01 * n as f64,
+                "q={q}: {estimate} != {expected}"
+            );
+        }
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some((n - 1) as f64));
+    }
+}
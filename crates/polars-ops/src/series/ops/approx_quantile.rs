@@ -0,0 +1,29 @@
+use polars_core::prelude::*;
+
+use crate::series::ops::approx_algo::TDigest;
+
+/// Summarize the values of a numeric `s` in a [`TDigest`]. Nulls and NaNs are ignored.
+pub fn tdigest(s: &Series) -> PolarsResult<TDigest> {
+    polars_ensure!(s.dtype().is_numeric(), opq = approx_quantile, s.dtype());
+    let s = s.cast(&DataType::Float64)?;
+    let mut digest = TDigest::new();
+    s.f64()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .for_each(|v| digest.add(v));
+    Ok(digest)
+}
+
+/// Approximate the `quantile` of the values of `s`.
+///
+/// This is done with a t-digest, which needs a single pass over the data and a bounded amount of
+/// memory, and is most accurate for the quantiles near `0.0` and `1.0`. Nulls and NaNs are
+/// ignored; returns `None` if there are no other values.
+pub fn approx_quantile(s: &Series, quantile: f64) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+    );
+    Ok(tdigest(s)?.quantile(quantile))
+}
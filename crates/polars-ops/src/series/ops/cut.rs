@@ -105,15 +105,29 @@ pub fn qcut(
     allow_duplicates: bool,
     include_breaks: bool,
 ) -> PolarsResult<Series> {
+    polars_ensure!(
+        probs.iter().all(|p| (0.0..=1.0).contains(p)),
+        ComputeError: "quantiles must be between 0 and 1, got {:?}", probs
+    );
     let s = s.cast(&DataType::Float64)?;
     let s2 = s.sort(false);
     let ca = s2.f64()?;
-    let f = |&p| {
-        ca.quantile(p, QuantileInterpolOptions::Linear)
-            .unwrap()
-            .unwrap()
-    };
-    let mut qbreaks: Vec<_> = probs.iter().map(f).collect();
+
+    if ca.null_count() == ca.len() {
+        // Without any values there are no quantiles to cut at, every bin stays empty.
+        if let Some(ll) = &labels {
+            polars_ensure!(ll.len() == probs.len() + 1, ShapeMismatch: "Wrong number of labels");
+        }
+        return cut(&s, vec![], None, left_closed, include_breaks);
+    }
+
+    let mut qbreaks = probs
+        .iter()
+        .map(|&p| {
+            ca.quantile(p, QuantileInterpolOptions::Linear)
+                .map(|q| q.unwrap())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
     qbreaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
 
     // When probs are spaced too closely for the number of repeated values in the distribution
@@ -1,14 +1,21 @@
 use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 
-pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+use super::shift_by_group::{fill_group_boundaries, group_shift_idx};
+
+// Cast unsigned integers to a signed type, such that the differences can be negative.
+fn to_signed(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
-    let s = match s.dtype() {
-        UInt8 => s.cast(&Int16)?,
-        UInt16 => s.cast(&Int32)?,
-        UInt32 | UInt64 => s.cast(&Int64)?,
-        _ => s.clone(),
-    };
+    match s.dtype() {
+        UInt8 => s.cast(&Int16),
+        UInt16 => s.cast(&Int32),
+        UInt32 | UInt64 => s.cast(&Int64),
+        _ => Ok(s.clone()),
+    }
+}
+
+pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+    let s = to_signed(s)?;
 
     match null_behavior {
         NullBehavior::Ignore => Ok(&s - &s.shift(n)),
@@ -20,3 +27,52 @@ pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Ser
         },
     }
 }
+
+/// Compute the difference with the value `n` rows earlier in the same group, without
+/// materializing the groups.
+///
+/// The first `n` rows of every group have no earlier value, these are filled with
+/// `fill_value`, or are null if no `fill_value` is given.
+pub fn diff_by_group(
+    s: &Series,
+    groups: &GroupsProxy,
+    n: i64,
+    fill_value: Option<&Series>,
+) -> PolarsResult<Series> {
+    let s = to_signed(s)?;
+    let idx = group_shift_idx(groups, s.len(), n);
+    // Safety: the indices are in bounds.
+    let out = &s - &unsafe { s.take_unchecked(&idx) };
+    match fill_value {
+        Some(fill_value) => fill_group_boundaries(&out, &idx, fill_value),
+        None => Ok(out),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_by_group() -> PolarsResult<()> {
+        let s = Series::new("a", &[1u32, 10, 3, 30, 6, 60]);
+        // groups: [0, 2, 4] and [1, 3, 5]
+        let groups = GroupsProxy::Idx(GroupsIdx::new(
+            vec![0, 1],
+            vec![vec![0, 2, 4].into(), vec![1, 3, 5].into()],
+            true,
+        ));
+
+        let out = diff_by_group(&s, &groups, 1, None)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[None, None, Some(2), Some(20), Some(3), Some(30)]
+        );
+        let out = diff_by_group(&s, &groups, -1, Some(&Series::new("", &[0])))?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(-2), Some(-20), Some(-3), Some(-30), Some(0), Some(0)]
+        );
+        Ok(())
+    }
+}
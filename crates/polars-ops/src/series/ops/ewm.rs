@@ -3,7 +3,8 @@ use std::convert::TryFrom;
 use arrow::array::ArrayRef;
 pub use arrow::legacy::kernels::ewm::EWMOptions;
 use arrow::legacy::kernels::ewm::{
-    ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
+    ewm_mean as kernel_ewm_mean, ewm_mean_by as kernel_ewm_mean_by, ewm_std as kernel_ewm_std,
+    ewm_std_by as kernel_ewm_std_by, ewm_var as kernel_ewm_var, ewm_var_by as kernel_ewm_var_by,
 };
 use polars_core::prelude::*;
 
@@ -102,3 +103,151 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
 }
+
+fn check_times<'a>(s: &Series, times: &'a Int64Chunked, half_life: i64) -> PolarsResult<&'a [i64]> {
+    polars_ensure!(half_life > 0, ComputeError: "half_life must be strictly positive");
+    polars_ensure!(
+        s.len() == times.len(),
+        ShapeMismatch: "`by` column must have the same length as the values, got {} and {}",
+        times.len(), s.len()
+    );
+    let values = times.cont_slice().map_err(|_| {
+        polars_err!(
+            ComputeError:
+            "`by` column of an exponentially weighted function should not have null values"
+        )
+    })?;
+    polars_ensure!(
+        times.is_sorted_flag() == IsSorted::Ascending || values.windows(2).all(|w| w[0] <= w[1]),
+        InvalidOperation:
+        "`by` column of an exponentially weighted function must be sorted in ascending order"
+    );
+    Ok(values)
+}
+
+/// Exponentially weighted mean where the weights decay with the time elapsed between
+/// observations, `times` and `half_life` must be in the same unit.
+pub fn ewm_mean_by(
+    s: &Series,
+    times: &Int64Chunked,
+    half_life: i64,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Float32 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f32().unwrap();
+            let result = kernel_ewm_mean_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        DataType::Float64 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f64().unwrap();
+            let result = kernel_ewm_mean_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        _ => ewm_mean_by(&s.cast(&DataType::Float64)?, times, half_life, options),
+    }
+}
+
+/// Exponentially weighted standard deviation where the weights decay with the time
+/// elapsed between observations, see [`ewm_mean_by`].
+pub fn ewm_std_by(
+    s: &Series,
+    times: &Int64Chunked,
+    half_life: i64,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Float32 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f32().unwrap();
+            let result = kernel_ewm_std_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        DataType::Float64 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f64().unwrap();
+            let result = kernel_ewm_std_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        _ => ewm_std_by(&s.cast(&DataType::Float64)?, times, half_life, options),
+    }
+}
+
+/// Exponentially weighted variance where the weights decay with the time elapsed
+/// between observations, see [`ewm_mean_by`].
+pub fn ewm_var_by(
+    s: &Series,
+    times: &Int64Chunked,
+    half_life: i64,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Float32 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f32().unwrap();
+            let result = kernel_ewm_var_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        DataType::Float64 => {
+            let times = times.rechunk();
+            let times = check_times(s, &times, half_life)?;
+            let xs = s.f64().unwrap();
+            let result = kernel_ewm_var_by(
+                xs,
+                times,
+                half_life,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((s.name(), Box::new(result) as ArrayRef))
+        },
+        _ => ewm_var_by(&s.cast(&DataType::Float64)?, times, half_life, options),
+    }
+}
@@ -0,0 +1,175 @@
+use polars_core::prelude::arity::binary_elementwise_values;
+use polars_core::prelude::*;
+
+#[cfg(feature = "dtype-array")]
+use crate::chunked_array::array::ArrayNameSpace;
+
+/// The mean radius of the earth in kilometers.
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+fn to_float(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "coordinates must be numeric, got dtype {}", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.clone())
+}
+
+/// The x and y coordinates of points, stored as structs of two numeric fields or as arrays of
+/// width 2.
+fn coordinates(s: &Series) -> PolarsResult<(Float64Chunked, Float64Chunked)> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) if fields.len() == 2 => {
+            let fields = s.struct_()?.fields();
+            Ok((to_float(&fields[0])?, to_float(&fields[1])?))
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, 2) => {
+            let ca = s.array()?;
+            let x = ca.array_get(&Int64Chunked::from_slice("", &[0]))?;
+            let y = ca.array_get(&Int64Chunked::from_slice("", &[1]))?;
+            Ok((to_float(&x)?, to_float(&y)?))
+        },
+        dt => polars_bail!(
+            InvalidOperation: "points must be structs of two fields or arrays of width 2, got \
+            dtype {}", dt
+        ),
+    }
+}
+
+/// Broadcast a coordinate of length 1 to `len`.
+fn broadcast(ca: Float64Chunked, len: usize) -> PolarsResult<Float64Chunked> {
+    match ca.len() {
+        1 if len != 1 => Ok(ca.new_from_index(0, len)),
+        n => {
+            polars_ensure!(
+                n == len,
+                ShapeMismatch: "coordinates of length {} and {} cannot be combined", n, len
+            );
+            Ok(ca)
+        },
+    }
+}
+
+fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+/// The great-circle distance in kilometers between two points given by their latitude and
+/// longitude in degrees. Coordinates of length 1 are broadcast.
+pub fn haversine_distance(
+    lat1: &Series,
+    lon1: &Series,
+    lat2: &Series,
+    lon2: &Series,
+) -> PolarsResult<Series> {
+    let inputs = [lat1, lon1, lat2, lon2];
+    let len = inputs
+        .iter()
+        .map(|s| s.len())
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+    let [lat1_ca, lon1_ca, lat2_ca, lon2_ca] =
+        inputs.map(|s| to_float(s).and_then(|ca| broadcast(ca, len)));
+    let (lat1_ca, lon1_ca, lat2_ca, lon2_ca) = (lat1_ca?, lon1_ca?, lat2_ca?, lon2_ca?);
+
+    let out: Float64Chunked = lat1_ca
+        .into_iter()
+        .zip(&lon1_ca)
+        .zip(&lat2_ca)
+        .zip(&lon2_ca)
+        .map(|(((lat1, lon1), lat2), lon2)| Some(haversine(lat1?, lon1?, lat2?, lon2?)))
+        .collect();
+    Ok(out.with_name(lat1.name()).into_series())
+}
+
+/// Whether the point `(x, y)` lies inside the polygon, by the even-odd rule.
+fn contains(polygon: &[[f64; 2]], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for (i, &[xi, yi]) in polygon.iter().enumerate() {
+        let [xj, yj] = polygon[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Check which points lie inside the polygon with the given vertices. The polygon may be
+/// closed, i.e. its last vertex is equal to its first vertex, or not.
+pub fn point_in_polygon(s: &Series, polygon: &[[f64; 2]]) -> PolarsResult<Series> {
+    polars_ensure!(
+        polygon.len() >= 3,
+        ComputeError: "a polygon must have at least 3 vertices, got {}", polygon.len()
+    );
+    let (x, y) = coordinates(s)?;
+    let out: BooleanChunked =
+        binary_elementwise_values(&x, &y, |x: f64, y: f64| contains(polygon, x, y));
+    Ok(out.with_name(s.name()).into_series())
+}
+
+/// Check which points lie inside the bounding box, including its boundary.
+pub fn within_bbox(
+    s: &Series,
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+) -> PolarsResult<Series> {
+    let (x, y) = coordinates(s)?;
+    let out: BooleanChunked = binary_elementwise_values(&x, &y, |x: f64, y: f64| {
+        (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+    });
+    Ok(out.with_name(s.name()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_haversine_distance() -> PolarsResult<()> {
+        // Amsterdam and Paris.
+        let lat1 = Series::new("a", &[52.3676]);
+        let lon1 = Series::new("b", &[4.9041]);
+        let lat2 = Series::new("c", &[48.8566, 52.3676]);
+        let lon2 = Series::new("d", &[2.3522, 4.9041]);
+        let out = haversine_distance(&lat1, &lon1, &lat2, &lon2)?;
+        let out = out.f64()?;
+        assert_eq!(out.name(), "a");
+        assert!((out.get(0).unwrap() - 430.0).abs() < 5.0);
+        assert_eq!(out.get(1), Some(0.0));
+        Ok(())
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    #[test]
+    fn test_point_in_polygon() -> PolarsResult<()> {
+        let x = Series::new("x", &[Some(0.25), Some(1.5), Some(0.9), None]);
+        let y = Series::new("y", &[0.25, 0.5, 0.2, 0.5]);
+        let points = StructChunked::new("p", &[x, y])?.into_series();
+
+        let triangle = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        let out = point_in_polygon(&points, &triangle)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(false), Some(false), None]
+        );
+
+        let out = within_bbox(&points, 0.0, 0.0, 1.0, 1.0)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(false), Some(true), None]
+        );
+        assert!(point_in_polygon(&points, &triangle[..2]).is_err());
+        Ok(())
+    }
+}
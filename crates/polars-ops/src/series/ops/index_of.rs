@@ -0,0 +1,59 @@
+use polars_core::prelude::*;
+
+use super::{search_sorted, SearchSortedSide};
+
+/// Find the index of the first occurrence of `value` in `s`, or `None` if it doesn't occur.
+///
+/// A null `value` matches the first null in `s`. If `s` is flagged as sorted, a binary search
+/// is used instead of a linear scan.
+pub fn index_of(s: &Series, value: &Series) -> PolarsResult<Option<usize>> {
+    polars_ensure!(
+        value.len() == 1,
+        ComputeError: "`index_of` expects a single value, got {} values", value.len()
+    );
+    let value = value.cast(s.dtype())?;
+
+    let is_sorted = !matches!(s.is_sorted_flag(), IsSorted::Not);
+    if is_sorted && s.null_count() == 0 && value.null_count() == 0 {
+        let descending = matches!(s.is_sorted_flag(), IsSorted::Descending);
+        let idx = search_sorted(s, &value, SearchSortedSide::Left, descending)?;
+        let idx = idx.get(0).unwrap() as usize;
+        if idx < s.len() && s.slice(idx as i64, 1).equal_missing(&value)?.all() {
+            return Ok(Some(idx));
+        }
+        return Ok(None);
+    }
+
+    let mask = s.equal_missing(&value)?;
+    let mut offset = 0;
+    for arr in mask.downcast_iter() {
+        if let Some(idx) = arr.values().iter().position(|v| v) {
+            return Ok(Some(offset + idx));
+        }
+        offset += arr.len();
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_of() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(3), None, Some(1), Some(3)]);
+        assert_eq!(index_of(&s, &Series::new("", &[3]))?, Some(0));
+        assert_eq!(index_of(&s, &Series::new("", &[1]))?, Some(2));
+        assert_eq!(index_of(&s, &Series::new("", &[7]))?, None);
+        assert_eq!(
+            index_of(&s, &Series::full_null("", 1, &DataType::Int32))?,
+            Some(1)
+        );
+
+        let mut s = Series::new("a", &[5, 4, 4, 2]);
+        s.set_sorted_flag(IsSorted::Descending);
+        assert_eq!(index_of(&s, &Series::new("", &[4]))?, Some(1));
+        assert_eq!(index_of(&s, &Series::new("", &[3]))?, None);
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use polars_core::prelude::*;
 
+use crate::series::ops::agg_float::agg_float_values;
 use crate::series::ops::SeriesSealed;
 
 fn log<T: PolarsNumericType>(ca: &ChunkedArray<T>, base: f64) -> Float64Chunked {
@@ -14,6 +15,35 @@ fn exp<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
     ca.cast_and_apply_in_place(|v: f64| v.exp())
 }
 
+/// Compute the entropy of every group, see [`LogSeries::entropy`].
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn agg_entropy(
+    s: &Series,
+    groups: &GroupsProxy,
+    base: f64,
+    normalize: bool,
+) -> PolarsResult<Series> {
+    let out = agg_float_values(s, groups, |pk| {
+        let sum = pk.iter().sum::<f64>();
+        let scale = if normalize && sum != 1.0 { sum } else { 1.0 };
+        let entropy = pk
+            .iter()
+            .map(|p| {
+                let p = p / scale;
+                p * p.log(base)
+            })
+            .sum::<f64>();
+        Some(-entropy)
+    })?;
+    if matches!(s.dtype(), DataType::Float32) {
+        out.cast(&DataType::Float32)
+    } else {
+        Ok(out)
+    }
+}
+
 pub trait LogSeries: SeriesSealed {
     /// Compute the logarithm to a given base
     fn log(&self, base: f64) -> Series {
@@ -1,7 +1,11 @@
 #[cfg(feature = "abs")]
 mod abs;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "log", feature = "moment"))]
+mod agg_float;
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 mod approx_algo;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
@@ -18,8 +22,12 @@ mod ewm;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geo")]
+mod geo;
 mod horizontal;
 mod index;
+#[cfg(feature = "index_of")]
+mod index_of;
 #[cfg(feature = "is_between")]
 mod is_between;
 #[cfg(feature = "is_first_distinct")]
@@ -34,6 +42,8 @@ mod is_unique;
 mod log;
 #[cfg(feature = "moment")]
 mod moment;
+#[cfg(feature = "outliers")]
+mod outliers;
 #[cfg(feature = "pct_change")]
 mod pct_change;
 #[cfg(feature = "rank")]
@@ -48,16 +58,21 @@ mod rolling;
 mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+mod shift_by_group;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+#[cfg(feature = "weighted_stats")]
+mod weighted;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 pub use approx_algo::*;
+#[cfg(feature = "approx_quantile")]
+pub use approx_quantile::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
@@ -74,8 +89,12 @@ pub use ewm::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
 pub use horizontal::*;
 pub use index::*;
+#[cfg(feature = "index_of")]
+pub use index_of::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
 #[cfg(feature = "is_first_distinct")]
@@ -90,6 +109,8 @@ pub use is_unique::*;
 pub use log::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
+#[cfg(feature = "outliers")]
+pub use outliers::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
 use polars_core::prelude::*;
@@ -105,11 +126,14 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+pub use shift_by_group::shift_by_group;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+#[cfg(feature = "weighted_stats")]
+pub use weighted::*;
 
 pub trait SeriesSealed {
     fn as_series(&self) -> &Series;
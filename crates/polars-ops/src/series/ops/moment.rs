@@ -1,6 +1,7 @@
 use polars_core::prelude::*;
 
 use crate::prelude::SeriesSealed;
+use crate::series::ops::agg_float::agg_float_values;
 
 fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult<Option<f64>> {
     // see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L922
@@ -41,6 +42,80 @@ fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult
     Ok(out)
 }
 
+/// The 2nd, 3rd and 4th central moments of `values`, or `None` if there are no values.
+fn central_moments(values: &[f64]) -> Option<[f64; 3]> {
+    if values.is_empty() {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let mut moments = [0.0; 3];
+    for v in values {
+        let d = v - mean;
+        let d2 = d * d;
+        moments[0] += d2;
+        moments[1] += d2 * d;
+        moments[2] += d2 * d2;
+    }
+    Some(moments.map(|m| m / n))
+}
+
+fn skew_from_moments(m2: f64, m3: f64, n: f64, bias: bool) -> f64 {
+    let out = m3 / m2.powf(1.5);
+    if !bias {
+        ((n - 1.0) * n).sqrt() / (n - 2.0) * out
+    } else {
+        out
+    }
+}
+
+fn kurtosis_from_moments(m2: f64, m4: f64, n: f64, fisher: bool, bias: bool) -> f64 {
+    let out = if !bias {
+        3.0 + 1.0 / (n - 2.0) / (n - 3.0)
+            * ((n.powf(2.0) - 1.0) * m4 / m2.powf(2.0) - 3.0 * (n - 1.0).powf(2.0))
+    } else {
+        m4 / m2.powf(2.0)
+    };
+    if fisher {
+        out - 3.0
+    } else {
+        out
+    }
+}
+
+/// Compute the sample skewness of every group, see [`MomentSeries::skew`].
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn agg_skew(s: &Series, groups: &GroupsProxy, bias: bool) -> PolarsResult<Series> {
+    agg_float_values(s, groups, |values| {
+        let [m2, m3, _] = central_moments(values)?;
+        Some(skew_from_moments(m2, m3, values.len() as f64, bias))
+    })
+}
+
+/// Compute the kurtosis of every group, see [`MomentSeries::kurtosis`].
+///
+/// # Safety
+/// The groups must be in bounds of `s`.
+pub unsafe fn agg_kurtosis(
+    s: &Series,
+    groups: &GroupsProxy,
+    fisher: bool,
+    bias: bool,
+) -> PolarsResult<Series> {
+    agg_float_values(s, groups, |values| {
+        let [m2, _, m4] = central_moments(values)?;
+        Some(kurtosis_from_moments(
+            m2,
+            m4,
+            values.len() as f64,
+            fisher,
+            bias,
+        ))
+    })
+}
+
 pub trait MomentSeries: SeriesSealed {
     /// Compute the sample skewness of a data set.
     ///
@@ -62,14 +137,8 @@ pub trait MomentSeries: SeriesSealed {
         let m2 = moment_precomputed_mean(s, 2, mean)?.unwrap();
         let m3 = moment_precomputed_mean(s, 3, mean)?.unwrap();
 
-        let out = m3 / m2.powf(1.5);
-
-        if !bias {
-            let n = (s.len() - s.null_count()) as f64;
-            Ok(Some(((n - 1.0) * n).sqrt() / (n - 2.0) * out))
-        } else {
-            Ok(Some(out))
-        }
+        let n = (s.len() - s.null_count()) as f64;
+        Ok(Some(skew_from_moments(m2, m3, n, bias)))
     }
 
     /// Compute the kurtosis (Fisher or Pearson) of a dataset.
@@ -92,18 +161,8 @@ pub trait MomentSeries: SeriesSealed {
         let m2 = moment_precomputed_mean(s, 2, mean)?.unwrap();
         let m4 = moment_precomputed_mean(s, 4, mean)?.unwrap();
 
-        let out = if !bias {
-            let n = (s.len() - s.null_count()) as f64;
-            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
-                * ((n.powf(2.0) - 1.0) * m4 / m2.powf(2.0) - 3.0 * (n - 1.0).powf(2.0))
-        } else {
-            m4 / m2.powf(2.0)
-        };
-        if fisher {
-            Ok(Some(out - 3.0))
-        } else {
-            Ok(Some(out))
-        }
+        let n = (s.len() - s.null_count()) as f64;
+        Ok(Some(kurtosis_from_moments(m2, m4, n, fisher, bias)))
     }
 }
 
@@ -166,4 +225,38 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_agg_skew_kurtosis() -> PolarsResult<()> {
+        let s = Series::new(
+            "",
+            &[
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(23),
+                None,
+                Some(1),
+            ],
+        );
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 6], [6, 2], [8, 0]],
+            rolling: false,
+        };
+        for bias in [true, false] {
+            let out = unsafe { agg_skew(&s, &groups, bias)? };
+            let expected = s.slice(0, 6).skew(bias)?.unwrap();
+            assert!((out.f64()?.get(0).unwrap() - expected).abs() < 0.0001);
+            assert_eq!(out.null_count(), 1);
+            for fisher in [true, false] {
+                let out = unsafe { agg_kurtosis(&s, &groups, fisher, bias)? };
+                let expected = s.slice(0, 6).kurtosis(fisher, bias)?.unwrap();
+                assert!((out.f64()?.get(0).unwrap() - expected).abs() < 0.0001);
+                assert_eq!(out.f64()?.get(2), None);
+            }
+        }
+        Ok(())
+    }
 }
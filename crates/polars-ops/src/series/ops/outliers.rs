@@ -0,0 +1,135 @@
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::*;
+
+use super::clip;
+
+fn check_quantile(q: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&q),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", q
+    );
+    Ok(())
+}
+
+fn to_float(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "outliers are only defined for numeric data, got dtype {}", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.clone())
+}
+
+/// Flag the values outside of `[lower, upper]`. Null values stay null.
+fn outside(ca: &Float64Chunked, lower: f64, upper: f64) -> BooleanChunked {
+    unary_elementwise_values(ca, |v: f64| v < lower || v > upper)
+}
+
+/// Replace the values below the `lower_q` quantile with that quantile, and the values above the
+/// `upper_q` quantile with that quantile.
+///
+/// The quantiles are values of `s`, such that the dtype is maintained.
+pub fn winsorize(s: &Series, lower_q: f64, upper_q: f64) -> PolarsResult<Series> {
+    check_quantile(lower_q)?;
+    check_quantile(upper_q)?;
+    polars_ensure!(
+        lower_q <= upper_q,
+        ComputeError: "lower quantile {} should not be larger than upper quantile {}", lower_q, upper_q
+    );
+    let lower = s.quantile_as_series(lower_q, QuantileInterpolOptions::Nearest)?;
+    let upper = s.quantile_as_series(upper_q, QuantileInterpolOptions::Nearest)?;
+    clip(s, &lower, &upper)
+}
+
+/// Flag the values that are more than `k` times the interquartile range below the first
+/// quartile or above the third quartile.
+pub fn is_outlier_iqr(s: &Series, k: f64) -> PolarsResult<Series> {
+    let ca = to_float(s)?;
+    let q1 = ca.quantile(0.25, QuantileInterpolOptions::Linear)?;
+    let q3 = ca.quantile(0.75, QuantileInterpolOptions::Linear)?;
+    let out = match (q1, q3) {
+        (Some(q1), Some(q3)) => {
+            let iqr = q3 - q1;
+            outside(&ca, q1 - k * iqr, q3 + k * iqr)
+        },
+        // all values are null
+        _ => BooleanChunked::full_null(s.name(), s.len()),
+    };
+    Ok(out.with_name(s.name()).into_series())
+}
+
+/// Flag the values that are more than `threshold` sample standard deviations away from the
+/// mean.
+pub fn is_outlier_zscore(s: &Series, threshold: f64) -> PolarsResult<Series> {
+    let ca = to_float(s)?;
+    let out = match (ca.mean(), ca.std(1)) {
+        (Some(mean), Some(std)) => {
+            let max_deviation = threshold * std;
+            unary_elementwise_values(&ca, |v: f64| (v - mean).abs() > max_deviation)
+        },
+        // a single value is never an outlier
+        _ => unary_elementwise_values(&ca, |_: f64| false),
+    };
+    Ok(out.with_name(s.name()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_winsorize() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(4), Some(100)]);
+        let out = winsorize(&s, 0.0, 0.75)?;
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(2), None, Some(3), Some(4), Some(4)]
+        );
+        assert!(winsorize(&s, 0.8, 0.2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_outlier() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            &[
+                Some(1.0),
+                Some(2.0),
+                None,
+                Some(3.0),
+                Some(4.0),
+                Some(100.0),
+            ],
+        );
+        let out = is_outlier_iqr(&s, 1.5)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                Some(false),
+                Some(true)
+            ]
+        );
+
+        let out = is_outlier_zscore(&s, 1.5)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                Some(false),
+                Some(true)
+            ]
+        );
+        let out = is_outlier_zscore(&Series::new("a", &[1.0]), 1.5)?;
+        assert_eq!(Vec::from(out.bool()?), &[Some(false)]);
+        Ok(())
+    }
+}
@@ -2,10 +2,17 @@ use polars_core::prelude::*;
 
 /// Get the lengths of runs of identical values.
 pub fn rle(s: &Series) -> PolarsResult<Series> {
+    if s.is_empty() {
+        let outvals = vec![
+            Series::new_empty("lengths", &IDX_DTYPE),
+            Series::new_empty("values", s.dtype()),
+        ];
+        return Ok(StructChunked::new("rle", &outvals)?.into_series());
+    }
     let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
     let s_neq = s1.not_equal_missing(&s2)?;
     let n_runs = s_neq.sum().unwrap() + 1;
-    let mut lengths = Vec::with_capacity(n_runs as usize);
+    let mut lengths: Vec<IdxSize> = Vec::with_capacity(n_runs as usize);
     lengths.push(1);
     let mut vals = Series::new_empty("values", s.dtype());
     let vals = vals.extend(&s.head(Some(1)))?.extend(&s2.filter(&s_neq)?)?;
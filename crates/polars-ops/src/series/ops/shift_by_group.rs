@@ -0,0 +1,104 @@
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+
+/// Get the indices that shift the values of every group by `n` rows. The indices of the rows
+/// that are shifted in at the group boundaries are null.
+pub(super) fn group_shift_idx(groups: &GroupsProxy, len: usize, n: i64) -> IdxCa {
+    let mut idx = vec![0 as IdxSize; len];
+    let mut validity = MutableBitmap::from_len_zeroed(len);
+
+    let mut shift_group = |g: &dyn Fn(usize) -> IdxSize, group_len: usize| {
+        for j in 0..group_len {
+            let src = j as i64 - n;
+            if src >= 0 && (src as usize) < group_len {
+                let dst = g(j) as usize;
+                idx[dst] = g(src as usize);
+                validity.set(dst, true);
+            }
+        }
+    };
+    match groups {
+        GroupsProxy::Idx(groups) => {
+            for g in groups.all() {
+                shift_group(&|j| g[j], g.len())
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for &[first, group_len] in groups {
+                shift_group(&|j| first + j as IdxSize, group_len as usize)
+            }
+        },
+    }
+    IdxCa::from_vec_validity("", idx, Some(validity.into()))
+}
+
+/// Replace the values at the group boundaries, where `idx` is null, with `fill_value`.
+pub(super) fn fill_group_boundaries(
+    s: &Series,
+    idx: &IdxCa,
+    fill_value: &Series,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        fill_value.len() == 1,
+        ComputeError: "fill value must be a single value, got length {}", fill_value.len()
+    );
+    let fill_value = fill_value.cast(s.dtype())?.new_from_index(0, s.len());
+    s.zip_with(&idx.is_not_null(), &fill_value)
+}
+
+/// Shift the values of every group by `n` rows, without materializing the groups.
+///
+/// The rows that are shifted in at the group boundaries are filled with `fill_value`, or are
+/// null if no `fill_value` is given. Rows that are not part of any group are null.
+pub fn shift_by_group(
+    s: &Series,
+    groups: &GroupsProxy,
+    n: i64,
+    fill_value: Option<&Series>,
+) -> PolarsResult<Series> {
+    let idx = group_shift_idx(groups, s.len(), n);
+    // Safety: the indices are in bounds.
+    let out = unsafe { s.take_unchecked(&idx) };
+    match fill_value {
+        Some(fill_value) => fill_group_boundaries(&out, &idx, fill_value),
+        None => Ok(out),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shift_by_group() -> PolarsResult<()> {
+        let s = Series::new("a", &[1, 2, 3, 4, 5, 6]);
+        // groups: [0, 2, 4] and [1, 3, 5]
+        let groups = GroupsProxy::Idx(GroupsIdx::new(
+            vec![0, 1],
+            vec![vec![0, 2, 4].into(), vec![1, 3, 5].into()],
+            true,
+        ));
+
+        let out = shift_by_group(&s, &groups, 1, None)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[None, None, Some(1), Some(2), Some(3), Some(4)]
+        );
+        let out = shift_by_group(&s, &groups, -1, Some(&Series::new("", &[0])))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(4), Some(5), Some(6), Some(0), Some(0)]
+        );
+
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [2, 4]],
+            rolling: false,
+        };
+        let out = shift_by_group(&s, &groups, 2, Some(&Series::new("", &[-1])))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(-1), Some(-1), Some(-1), Some(-1), Some(3), Some(4)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//! Weighted aggregations. Weights are treated as frequency weights: a value with weight `2` counts
+//! as if it occurred twice. Pairs where either the value or the weight is null are ignored.
+use polars_core::prelude::*;
+use polars_utils::total_ord::TotalOrd;
+
+/// Call `f` with every `(value, weight)` pair in which neither is null.
+fn for_each_pair(
+    values: &Series,
+    weights: &Series,
+    mut f: impl FnMut(f64, f64),
+) -> PolarsResult<()> {
+    polars_ensure!(
+        values.len() == weights.len(),
+        ShapeMismatch: "weights have length {}, expected {} (the length of the values)",
+        weights.len(), values.len()
+    );
+    let values = values.cast(&DataType::Float64)?;
+    let weights = weights.cast(&DataType::Float64)?;
+    let values = values.f64().unwrap();
+    let weights = weights.f64().unwrap();
+
+    for (v, w) in values.into_iter().zip(weights) {
+        if let (Some(v), Some(w)) = (v, w) {
+            polars_ensure!(
+                w >= 0.0,
+                ComputeError: "weights must be non-negative and not NaN, got {}", w
+            );
+            f(v, w);
+        }
+    }
+    Ok(())
+}
+
+/// Compute the weighted mean of `values`.
+///
+/// Returns `None` if the weights of the non-null pairs sum to zero.
+pub fn weighted_mean(values: &Series, weights: &Series) -> PolarsResult<Option<f64>> {
+    let mut sum = 0.0;
+    let mut sum_weights = 0.0;
+    for_each_pair(values, weights, |v, w| {
+        if w > 0.0 {
+            sum += v * w;
+            sum_weights += w;
+        }
+    })?;
+    Ok((sum_weights > 0.0).then(|| sum / sum_weights))
+}
+
+/// Compute the weighted variance of `values`, using `sum(weights) - ddof` as the divisor.
+///
+/// Returns `None` if the divisor isn't positive.
+pub fn weighted_var(values: &Series, weights: &Series, ddof: u8) -> PolarsResult<Option<f64>> {
+    // West's weighted incremental algorithm, which avoids the cancellation of the naive
+    // `sum(w * x^2) - sum(w * x)^2` formula.
+    let mut sum_weights = 0.0;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for_each_pair(values, weights, |v, w| {
+        if w > 0.0 {
+            sum_weights += w;
+            let delta = v - mean;
+            mean += delta * w / sum_weights;
+            m2 += w * delta * (v - mean);
+        }
+    })?;
+    let denom = sum_weights - ddof as f64;
+    Ok((denom > 0.0).then(|| m2 / denom))
+}
+
+/// Compute the weighted `quantile` of `values`: the smallest value for which the weights of all
+/// values up to and including it make up at least `quantile` of the total weight.
+///
+/// Returns `None` if the weights of the non-null pairs sum to zero.
+pub fn weighted_quantile(
+    values: &Series,
+    weights: &Series,
+    quantile: f64,
+) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+    );
+    let mut pairs = Vec::with_capacity(values.len());
+    for_each_pair(values, weights, |v, w| {
+        if w > 0.0 {
+            pairs.push((v, w));
+        }
+    })?;
+    if pairs.is_empty() {
+        return Ok(None);
+    }
+    pairs.sort_unstable_by(|a, b| a.0.tot_cmp(&b.0));
+
+    let target = quantile * pairs.iter().map(|(_, w)| w).sum::<f64>();
+    let mut cum_weight = 0.0;
+    for &(v, w) in &pairs {
+        cum_weight += w;
+        if cum_weight >= target {
+            return Ok(Some(v));
+        }
+    }
+    // Only reachable through rounding in the cumulative sum.
+    Ok(pairs.last().map(|(v, _)| *v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_stats() -> PolarsResult<()> {
+        let values = Series::new("v", &[Some(1.0), Some(2.0), None, Some(4.0), Some(100.0)]);
+        let weights = Series::new("w", &[Some(1), Some(2), Some(5), Some(1), None]);
+
+        assert_eq!(weighted_mean(&values, &weights)?, Some(2.25));
+        // Equivalent to the unweighted variance of [1, 2, 2, 4].
+        let var = weighted_var(&values, &weights, 1)?.unwrap();
+        assert!((var - 4.75 / 3.0).abs() < 1e-12);
+        let var = weighted_var(&values, &weights, 0)?.unwrap();
+        assert!((var - 4.75 / 4.0).abs() < 1e-12);
+        assert_eq!(weighted_quantile(&values, &weights, 0.0)?, Some(1.0));
+        assert_eq!(weighted_quantile(&values, &weights, 0.5)?, Some(2.0));
+        assert_eq!(weighted_quantile(&values, &weights, 0.8)?, Some(4.0));
+        assert_eq!(weighted_quantile(&values, &weights, 1.0)?, Some(4.0));
+
+        let zero = Series::new("w", &[0, 0, 0, 0, 0]);
+        assert_eq!(weighted_mean(&values, &zero)?, None);
+        assert_eq!(weighted_var(&values, &zero, 1)?, None);
+        assert_eq!(weighted_quantile(&values, &zero, 0.5)?, None);
+
+        let negative = Series::new("w", &[1, -1, 1, 1, 1]);
+        assert!(weighted_mean(&values, &negative).is_err());
+        Ok(())
+    }
+}
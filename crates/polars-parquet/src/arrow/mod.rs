@@ -6,3 +6,6 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The key of the metadata of an arrow field that holds its parquet field id, as used by
+/// pyarrow.
+pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
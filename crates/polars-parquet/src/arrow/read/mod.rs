@@ -27,14 +27,17 @@ pub use schema::{infer_schema, FileMetaData};
 pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
+    encryption::KeyRetriever,
     error::Error as ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
         decompress, get_column_iterator, read_columns_indexes as _read_columns_indexes,
-        read_metadata as _read_metadata, read_pages_locations, BasicDecompressor, Decompressor,
-        MutStreamingIterator, PageFilter, PageReader, ReadColumnIterator, State,
+        read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption, read_pages_locations,
+        BasicDecompressor, Decompressor, MutStreamingIterator, PageFilter, PageReader,
+        ReadColumnIterator, State,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -63,6 +66,15 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetaDat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting the footer and the encrypted columns with
+/// the keys of `key_retriever`.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    key_retriever: &dyn KeyRetriever,
+) -> PolarsResult<FileMetaData> {
+    Ok(_read_metadata_with_decryption(reader, key_retriever)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -2,6 +2,7 @@
 use arrow::datatypes::{ArrowDataType, Field, IntervalUnit, TimeUnit};
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
+use crate::arrow::PARQUET_FIELD_ID_META_KEY;
 use crate::parquet::schema::types::{
     FieldInfo, GroupConvertedType, GroupLogicalType, IntegerType, ParquetType, PhysicalType,
     PrimitiveConvertedType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
@@ -303,15 +304,24 @@ pub(crate) fn is_nullable(field_info: &FieldInfo) -> bool {
     }
 }
 
-/// Converts parquet schema to arrow field.
+/// Converts parquet schema to arrow field. The field id is stored in the metadata of the field.
 /// Returns `None` iff the parquet type has no associated primitive types,
 /// i.e. if it is a column-less group type.
 fn to_field(type_: &ParquetType, options: &SchemaInferenceOptions) -> Option<Field> {
-    Some(Field::new(
-        &type_.get_field_info().name,
+    let field_info = type_.get_field_info();
+    let field = Field::new(
+        &field_info.name,
         to_data_type(type_, options)?,
-        is_nullable(type_.get_field_info()),
-    ))
+        is_nullable(field_info),
+    );
+    Some(match field_info.id {
+        Some(id) => field.with_metadata(
+            [(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]
+                .into_iter()
+                .collect(),
+        ),
+        None => field,
+    })
 }
 
 /// Converts a parquet list to arrow list.
@@ -4,7 +4,7 @@ use arrow::datatypes::ArrowSchema;
 use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
-use super::{to_parquet_schema, ThriftFileMetaData, WriteOptions};
+use super::{to_parquet_schema, FileEncryptionProperties, ThriftFileMetaData, WriteOptions};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIter, WriteOptions as FileWriteOptions};
 
@@ -70,11 +70,23 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Encrypts the file as described by `properties`.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIter<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a split block bloom filter of the column `column` of the last written row group.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filter(&mut self, column: usize, bitset: &[u8]) -> PolarsResult<()> {
+        Ok(self.writer.write_bloom_filter(column, bitset)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
@@ -36,6 +36,7 @@ pub use utils::write_def_levels;
 
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::{EncryptionKey, FileEncryptionProperties};
 pub use crate::parquet::metadata::{
     Descriptor, FileMetaData, KeyValue, SchemaDescriptor, ThriftFileMetaData,
 };
@@ -2,9 +2,9 @@ use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::engine::general_purpose;
 use base64::Engine as _;
-use polars_error::{polars_bail, PolarsResult};
+use polars_error::{polars_bail, polars_err, PolarsResult};
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::types::{
@@ -32,7 +32,22 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
     }
 }
 
+/// The parquet field id of `field`, stored in its metadata.
+fn field_id(field: &Field) -> PolarsResult<Option<i32>> {
+    field
+        .metadata
+        .get(PARQUET_FIELD_ID_META_KEY)
+        .map(|id| {
+            id.parse::<i32>().map_err(|_| {
+                polars_err!(ComputeError: "invalid parquet field id '{}' of field '{}'", id, field.name)
+            })
+        })
+        .transpose()
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
+///
+/// The field id is taken from the metadata key `"PARQUET:field_id"` of the field.
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
     let repetition = if field.is_nullable {
@@ -40,6 +55,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     } else {
         Repetition::Required
     };
+    let id = field_id(field)?;
     // create type from field
     match field.data_type().to_logical_type() {
         ArrowDataType::Null => Ok(ParquetType::try_from_primitive(
@@ -48,7 +64,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             Some(PrimitiveLogicalType::Unknown),
-            None,
+            id,
         )?),
         ArrowDataType::Boolean => Ok(ParquetType::try_from_primitive(
             name,
@@ -56,7 +72,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Int32 => Ok(ParquetType::try_from_primitive(
             name,
@@ -64,7 +80,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         // ArrowDataType::Duration(_) has no parquet representation => do not apply any logical type
         ArrowDataType::Int64 | ArrowDataType::Duration(_) => Ok(ParquetType::try_from_primitive(
@@ -73,7 +89,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
@@ -83,7 +99,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
@@ -91,7 +107,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Float64 => Ok(ParquetType::try_from_primitive(
             name,
@@ -99,7 +115,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => {
             Ok(ParquetType::try_from_primitive(
@@ -108,7 +124,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 repetition,
                 None,
                 None,
-                None,
+                id,
             )?)
         },
         ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => {
@@ -118,7 +134,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 repetition,
                 Some(PrimitiveConvertedType::Utf8),
                 Some(PrimitiveLogicalType::String),
-                None,
+                id,
             )?)
         },
         ArrowDataType::Date32 => Ok(ParquetType::try_from_primitive(
@@ -127,7 +143,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Date),
             Some(PrimitiveLogicalType::Date),
-            None,
+            id,
         )?),
         ArrowDataType::Int8 => Ok(ParquetType::try_from_primitive(
             name,
@@ -135,7 +151,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Int8),
             Some(PrimitiveLogicalType::Integer(IntegerType::Int8)),
-            None,
+            id,
         )?),
         ArrowDataType::Int16 => Ok(ParquetType::try_from_primitive(
             name,
@@ -143,7 +159,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Int16),
             Some(PrimitiveLogicalType::Integer(IntegerType::Int16)),
-            None,
+            id,
         )?),
         ArrowDataType::UInt8 => Ok(ParquetType::try_from_primitive(
             name,
@@ -151,7 +167,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Uint8),
             Some(PrimitiveLogicalType::Integer(IntegerType::UInt8)),
-            None,
+            id,
         )?),
         ArrowDataType::UInt16 => Ok(ParquetType::try_from_primitive(
             name,
@@ -159,7 +175,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Uint16),
             Some(PrimitiveLogicalType::Integer(IntegerType::UInt16)),
-            None,
+            id,
         )?),
         ArrowDataType::UInt32 => Ok(ParquetType::try_from_primitive(
             name,
@@ -167,7 +183,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Uint32),
             Some(PrimitiveLogicalType::Integer(IntegerType::UInt32)),
-            None,
+            id,
         )?),
         ArrowDataType::UInt64 => Ok(ParquetType::try_from_primitive(
             name,
@@ -175,7 +191,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Uint64),
             Some(PrimitiveLogicalType::Integer(IntegerType::UInt64)),
-            None,
+            id,
         )?),
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
@@ -185,7 +201,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Timestamp(time_unit, zone) => Ok(ParquetType::try_from_primitive(
             name,
@@ -201,7 +217,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     TimeUnit::Nanosecond => ParquetTimeUnit::Nanoseconds,
                 },
             }),
-            None,
+            id,
         )?),
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
@@ -211,7 +227,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Time32(TimeUnit::Millisecond) => Ok(ParquetType::try_from_primitive(
             name,
@@ -222,7 +238,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 is_adjusted_to_utc: false,
                 unit: ParquetTimeUnit::Milliseconds,
             }),
-            None,
+            id,
         )?),
         ArrowDataType::Time64(time_unit) => Ok(ParquetType::try_from_primitive(
             name,
@@ -241,7 +257,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     _ => unreachable!(),
                 },
             }),
-            None,
+            id,
         )?),
         ArrowDataType::Struct(fields) => {
             if fields.is_empty() {
@@ -255,11 +271,12 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 .map(to_parquet_type)
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(ParquetType::from_group(
-                name, repetition, None, None, fields, None,
+                name, repetition, None, None, fields, id,
             ))
         },
         ArrowDataType::Dictionary(_, value, _) => {
-            let dict_field = Field::new(name.as_str(), value.as_ref().clone(), field.is_nullable);
+            let dict_field = Field::new(name.as_str(), value.as_ref().clone(), field.is_nullable)
+                .with_metadata(field.metadata.clone());
             to_parquet_type(&dict_field)
         },
         ArrowDataType::FixedSizeBinary(size) => Ok(ParquetType::try_from_primitive(
@@ -268,7 +285,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             None,
             None,
-            None,
+            id,
         )?),
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
@@ -289,7 +306,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 repetition,
                 Some(PrimitiveConvertedType::Decimal(precision, scale)),
                 logical_type,
-                None,
+                id,
             )?)
         },
         ArrowDataType::Decimal256(precision, scale) => {
@@ -304,7 +321,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     repetition,
                     Some(PrimitiveConvertedType::Decimal(precision, scale)),
                     logical_type,
-                    None,
+                    id,
                 )?)
             } else if precision <= 18 {
                 Ok(ParquetType::try_from_primitive(
@@ -313,7 +330,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     repetition,
                     Some(PrimitiveConvertedType::Decimal(precision, scale)),
                     logical_type,
-                    None,
+                    id,
                 )?)
             } else if precision <= 38 {
                 let len = decimal_length_from_precision(precision);
@@ -323,7 +340,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     repetition,
                     Some(PrimitiveConvertedType::Decimal(precision, scale)),
                     logical_type,
-                    None,
+                    id,
                 )?)
             } else {
                 Ok(ParquetType::try_from_primitive(
@@ -332,7 +349,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     repetition,
                     None,
                     None,
-                    None,
+                    id,
                 )?)
             }
         },
@@ -342,7 +359,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             repetition,
             Some(PrimitiveConvertedType::Interval),
             None,
-            None,
+            id,
         )?),
         ArrowDataType::List(f)
         | ArrowDataType::FixedSizeList(f, _)
@@ -359,7 +376,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 vec![to_parquet_type(f)?],
                 None,
             )],
-            None,
+            id,
         )),
         ArrowDataType::Map(f, _) => Ok(ParquetType::from_group(
             name,
@@ -374,7 +391,7 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 vec![to_parquet_type(f)?],
                 None,
             )],
-            None,
+            id,
         )),
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     }
@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
 pub use read::read;
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,12 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_bytes() {
+        assert_eq!(optimal_num_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_bytes(10, 0.01), 32);
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+        assert_eq!(optimal_num_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+    }
 }
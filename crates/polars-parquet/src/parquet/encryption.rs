@@ -0,0 +1,705 @@
+//! Parquet [modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are written with the `AES_GCM_V1` algorithm and an encrypted footer. Every column is
+//! encrypted either with the footer key or with a key of its own, whose key metadata is stored
+//! in the file so that readers can obtain the key through a [`KeyRetriever`].
+//! Files with a plaintext footer can be read, but not written.
+use std::fmt::{Debug, Formatter};
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use parquet_format_safe::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use parquet_format_safe::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_error::PolarsResult;
+
+use crate::parquet::error::{Error, Feature, Result};
+use crate::parquet::metadata::{FileMetaData, SchemaDescriptor, ThriftFileMetaData};
+use crate::parquet::page::ParquetPageHeader;
+
+pub(crate) const PARQUET_MAGIC_ENCRYPTED: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// Retrieves the keys of an encrypted parquet file from the key metadata stored in it.
+///
+/// The key metadata is whatever was passed to [`EncryptionKey::with_key_metadata`] when the
+/// file was written (e.g. a key identifier in a key management service), or empty if the key
+/// was written without metadata.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the AES key (16, 24 or 32 bytes) that belongs to `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>>;
+}
+
+/// An AES key (16, 24 or 32 bytes) with the metadata readers use to retrieve it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+impl EncryptionKey {
+    pub fn new(key: Vec<u8>) -> Self {
+        Self {
+            key,
+            key_metadata: None,
+        }
+    }
+
+    /// Store `key_metadata` in the file, which is passed to the [`KeyRetriever`] on read.
+    pub fn with_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if matches!(self.key.len(), 16 | 24 | 32) {
+            Ok(())
+        } else {
+            Err(Error::InvalidParameter(format!(
+                "an AES key must have 16, 24 or 32 bytes, got {}",
+                self.key.len()
+            )))
+        }
+    }
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // never print the key itself
+        f.debug_struct("EncryptionKey")
+            .field("key_metadata", &self.key_metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How a parquet file is encrypted.
+///
+/// Without column keys all columns are encrypted with the footer key. With column keys, only
+/// the columns that have a key are encrypted and the other columns are written in plaintext;
+/// the footer is encrypted in both cases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEncryptionProperties {
+    footer_key: EncryptionKey,
+    column_keys: Vec<(String, EncryptionKey)>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileEncryptionProperties {
+    pub fn new(footer_key: EncryptionKey) -> Self {
+        Self {
+            footer_key,
+            column_keys: vec![],
+            aad_prefix: None,
+        }
+    }
+
+    /// Encrypt the column `path` with `key`. The path is the dot-separated path of a leaf column
+    /// in the parquet schema, or of a nested column, in which case all its leaves use `key`.
+    pub fn with_column_key(mut self, path: String, key: EncryptionKey) -> Self {
+        self.column_keys.push((path, key));
+        self
+    }
+
+    /// Bind the encrypted modules to `aad_prefix` (e.g. the file name), which is stored in the
+    /// file.
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+}
+
+/// The type of an encrypted module, which is part of its additional authenticated data.
+#[derive(Debug, Clone, Copy)]
+#[repr(u8)]
+enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+fn ordinal(ordinal: usize) -> Result<[u8; 2]> {
+    i16::try_from(ordinal)
+        .map(|ordinal| ordinal.to_le_bytes())
+        .map_err(|_| {
+            Error::FeatureNotSupported(format!(
+                "encrypted files can have at most {} row groups, columns and pages per column chunk",
+                i16::MAX
+            ))
+        })
+}
+
+/// The additional authenticated data of a module.
+fn module_aad(
+    file_aad: &[u8],
+    module: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> Result<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module as u8);
+    if !matches!(module, ModuleType::Footer) {
+        aad.extend_from_slice(&ordinal(row_group)?);
+        aad.extend_from_slice(&ordinal(column)?);
+    }
+    if let Some(page) = page {
+        aad.extend_from_slice(&ordinal(page)?);
+    }
+    Ok(aad)
+}
+
+#[cfg(feature = "encryption")]
+mod aes {
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::{Aead, AeadCore, KeyInit, Nonce, OsRng, Payload};
+    use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm};
+
+    use super::*;
+
+    type Aes192Gcm = AesGcm<aes_gcm::aes::Aes192, U12>;
+
+    const NONCE_LEN: usize = 12;
+    const TAG_LEN: usize = 16;
+
+    fn seal<C: KeyInit + Aead>(key: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let cipher = C::new_from_slice(key)
+            .map_err(|_| Error::InvalidParameter("invalid AES key".to_string()))?;
+        let nonce = C::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|_| Error::InvalidParameter("could not encrypt module".to_string()))?;
+
+        let len: u32 = (nonce.len() + ciphertext.len()).try_into()?;
+        let mut module = Vec::with_capacity(4 + len as usize);
+        module.extend_from_slice(&len.to_le_bytes());
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(&ciphertext);
+        Ok(module)
+    }
+
+    fn open<C: KeyInit + Aead>(key: &[u8], aad: &[u8], module: &[u8]) -> Result<Vec<u8>> {
+        if module.len() < NONCE_LEN + TAG_LEN {
+            return Err(Error::oos("An encrypted module is too short"));
+        }
+        let cipher = C::new_from_slice(key)
+            .map_err(|_| Error::InvalidParameter("invalid AES key".to_string()))?;
+        let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+        cipher
+            .decrypt(
+                Nonce::<C>::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| {
+                Error::InvalidParameter(
+                    "could not decrypt module: wrong key or corrupted file".to_string(),
+                )
+            })
+    }
+
+    pub(super) fn encrypt(key: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        match key.len() {
+            16 => seal::<Aes128Gcm>(key, aad, plaintext),
+            24 => seal::<Aes192Gcm>(key, aad, plaintext),
+            _ => seal::<Aes256Gcm>(key, aad, plaintext),
+        }
+    }
+
+    pub(super) fn decrypt(key: &[u8], aad: &[u8], module: &[u8]) -> Result<Vec<u8>> {
+        match key.len() {
+            16 => open::<Aes128Gcm>(key, aad, module),
+            24 => open::<Aes192Gcm>(key, aad, module),
+            _ => open::<Aes256Gcm>(key, aad, module),
+        }
+    }
+
+    pub(super) fn random_bytes(out: &mut [u8]) {
+        use aes_gcm::aead::rand_core::RngCore;
+        OsRng.fill_bytes(out)
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+mod aes {
+    use super::*;
+
+    fn not_active(action: &str) -> Error {
+        Error::FeatureNotActive(Feature::Encryption, action.to_string())
+    }
+
+    pub(super) fn encrypt(_key: &[u8], _aad: &[u8], _plaintext: &[u8]) -> Result<Vec<u8>> {
+        Err(not_active("write encrypted parquet files"))
+    }
+
+    pub(super) fn decrypt(_key: &[u8], _aad: &[u8], _module: &[u8]) -> Result<Vec<u8>> {
+        Err(not_active("read encrypted parquet files"))
+    }
+
+    pub(super) fn random_bytes(_out: &mut [u8]) {}
+}
+
+/// Encrypts `plaintext` into a module: its length, the nonce and the ciphertext with its tag.
+fn encrypt_module(key: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+    aes::encrypt(key, aad, plaintext)
+}
+
+/// Decrypts a module without its length prefix.
+fn decrypt_module(key: &[u8], aad: &[u8], module: &[u8]) -> Result<Vec<u8>> {
+    aes::decrypt(key, aad, module)
+}
+
+/// Decrypts a module, including its length prefix, from the start of `module`.
+fn decrypt_prefixed_module(key: &[u8], aad: &[u8], module: &[u8]) -> Result<Vec<u8>> {
+    let len = module
+        .get(..4)
+        .ok_or_else(|| Error::oos("An encrypted module must start with its length"))?;
+    let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+    let module = module
+        .get(4..4 + len)
+        .ok_or_else(|| Error::oos("An encrypted module is shorter than its length"))?;
+    decrypt_module(key, aad, module)
+}
+
+fn serialize<F>(write: F) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut TCompactOutputProtocol<&mut Vec<u8>>) -> Result<usize>,
+{
+    let mut buffer = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+    write(&mut protocol)?;
+    Ok(buffer)
+}
+
+#[derive(Debug, Clone)]
+enum ColumnKey {
+    Footer,
+    Column(EncryptionKey),
+}
+
+/// The state used to encrypt a file while it is written.
+#[derive(Debug)]
+pub(crate) struct FileEncryptor {
+    footer_key: EncryptionKey,
+    algorithm: EncryptionAlgorithm,
+    file_aad: Vec<u8>,
+    /// The key of every leaf column, `None` for plaintext columns.
+    columns: Vec<Option<ColumnKey>>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> Result<Self> {
+        if cfg!(not(feature = "encryption")) {
+            return Err(Error::FeatureNotActive(
+                Feature::Encryption,
+                "write encrypted parquet files".to_string(),
+            ));
+        }
+        properties.footer_key.validate()?;
+        for (_, key) in &properties.column_keys {
+            key.validate()?;
+        }
+
+        let columns = if properties.column_keys.is_empty() {
+            vec![Some(ColumnKey::Footer); schema.columns().len()]
+        } else {
+            let paths = schema
+                .columns()
+                .iter()
+                .map(|column| column.path_in_schema.join("."))
+                .collect::<Vec<_>>();
+            for (path, _) in &properties.column_keys {
+                if !paths.iter().any(|leaf| is_in_path(leaf, path)) {
+                    return Err(Error::InvalidParameter(format!(
+                        "cannot encrypt column \"{path}\": it is not in the schema"
+                    )));
+                }
+            }
+            paths
+                .iter()
+                .map(|leaf| {
+                    properties
+                        .column_keys
+                        .iter()
+                        .find(|(path, _)| is_in_path(leaf, path))
+                        .map(|(_, key)| ColumnKey::Column(key.clone()))
+                })
+                .collect()
+        };
+
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        aes::random_bytes(&mut aad_file_unique);
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        Ok(Self {
+            footer_key: properties.footer_key,
+            algorithm: EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix: properties.aad_prefix,
+                aad_file_unique: Some(aad_file_unique),
+                supply_aad_prefix: None,
+            }),
+            file_aad,
+            columns,
+        })
+    }
+
+    /// The encryptor of the column `column` of the row group `row_group`, or `None` if the
+    /// column is written in plaintext.
+    pub(crate) fn column(&self, row_group: usize, column: usize) -> Option<ColumnEncryptor<'_>> {
+        let key = match self.columns.get(column)?.as_ref()? {
+            ColumnKey::Footer => &self.footer_key.key,
+            ColumnKey::Column(key) => &key.key,
+        };
+        Some(ColumnEncryptor {
+            key,
+            file_aad: &self.file_aad,
+            row_group,
+            column,
+        })
+    }
+
+    /// Sets the crypto metadata of an encrypted column chunk. The metadata of columns with a
+    /// key of their own is moved into `encrypted_column_metadata`.
+    pub(crate) fn encrypt_column_chunk(
+        &self,
+        row_group: usize,
+        column: usize,
+        column_chunk: &mut ColumnChunk,
+    ) -> Result<()> {
+        match self.columns.get(column).and_then(|key| key.as_ref()) {
+            None => {},
+            Some(ColumnKey::Footer) => {
+                column_chunk.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    EncryptionWithFooterKey {},
+                ));
+            },
+            Some(ColumnKey::Column(key)) => {
+                let metadata = column_chunk
+                    .meta_data
+                    .take()
+                    .ok_or_else(|| Error::oos("Column chunk requires metadata"))?;
+                let aad = module_aad(
+                    &self.file_aad,
+                    ModuleType::ColumnMetaData,
+                    row_group,
+                    column,
+                    None,
+                )?;
+                let plaintext =
+                    serialize(|protocol| Ok(metadata.write_to_out_protocol(protocol)?))?;
+                column_chunk.encrypted_column_metadata =
+                    Some(encrypt_module(&key.key, &aad, &plaintext)?);
+                column_chunk.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                    EncryptionWithColumnKey {
+                        path_in_schema: metadata.path_in_schema,
+                        key_metadata: key.key_metadata.clone(),
+                    },
+                ));
+            },
+        }
+        Ok(())
+    }
+
+    /// Writes the crypto metadata and the encrypted footer, followed by their length and the
+    /// magic number of encrypted files.
+    pub(crate) fn write_footer<W: Write>(
+        &self,
+        writer: &mut W,
+        metadata: &ThriftFileMetaData,
+    ) -> Result<u64> {
+        let crypto_metadata = FileCryptoMetaData {
+            encryption_algorithm: self.algorithm.clone(),
+            key_metadata: self.footer_key.key_metadata.clone(),
+        };
+        let mut footer =
+            serialize(|protocol| Ok(crypto_metadata.write_to_out_protocol(protocol)?))?;
+
+        let plaintext = serialize(|protocol| Ok(metadata.write_to_out_protocol(protocol)?))?;
+        let aad = module_aad(&self.file_aad, ModuleType::Footer, 0, 0, None)?;
+        footer.extend(encrypt_module(&self.footer_key.key, &aad, &plaintext)?);
+
+        let footer_len: i32 = footer.len().try_into()?;
+        writer.write_all(&footer)?;
+        writer.write_all(&footer_len.to_le_bytes())?;
+        writer.write_all(&PARQUET_MAGIC_ENCRYPTED)?;
+        writer.flush()?;
+        Ok(footer.len() as u64 + 8)
+    }
+}
+
+/// Whether the leaf column `leaf` is the column `path` or one of its children.
+fn is_in_path(leaf: &str, path: &str) -> bool {
+    leaf.strip_prefix(path)
+        .map_or(false, |rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Encrypts the pages of a column chunk.
+pub(crate) struct ColumnEncryptor<'a> {
+    key: &'a [u8],
+    file_aad: &'a [u8],
+    row_group: usize,
+    column: usize,
+}
+
+impl ColumnEncryptor<'_> {
+    /// `page` is the ordinal of a data page within the column chunk; `None` for the
+    /// dictionary page.
+    fn aad(&self, header: bool, page: Option<usize>) -> Result<Vec<u8>> {
+        let module = match (header, page.is_some()) {
+            (false, true) => ModuleType::DataPage,
+            (false, false) => ModuleType::DictionaryPage,
+            (true, true) => ModuleType::DataPageHeader,
+            (true, false) => ModuleType::DictionaryPageHeader,
+        };
+        module_aad(self.file_aad, module, self.row_group, self.column, page)
+    }
+
+    pub(crate) fn encrypt_page(&self, data: &[u8], page: Option<usize>) -> Result<Vec<u8>> {
+        encrypt_module(self.key, &self.aad(false, page)?, data)
+    }
+
+    pub(crate) fn encrypt_page_header(
+        &self,
+        header: &ParquetPageHeader,
+        page: Option<usize>,
+    ) -> Result<Vec<u8>> {
+        let plaintext = serialize(|protocol| Ok(header.write_to_out_protocol(protocol)?))?;
+        encrypt_module(self.key, &self.aad(true, page)?, &plaintext)
+    }
+}
+
+/// Decrypts the pages of a column chunk.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct ColumnDecryptor {
+    key: Vec<u8>,
+    file_aad: Vec<u8>,
+    row_group: usize,
+    column: usize,
+    has_dictionary: bool,
+}
+
+impl Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("row_group", &self.row_group)
+            .field("column", &self.column)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnDecryptor {
+    /// The ordinal of the data page that is the `index`th page of the column chunk, or `None`
+    /// if it is the dictionary page.
+    pub(crate) fn page_ordinal(&self, index: usize) -> Option<usize> {
+        match (self.has_dictionary, index) {
+            (true, 0) => None,
+            (true, index) => Some(index - 1),
+            (false, index) => Some(index),
+        }
+    }
+
+    fn aad(&self, header: bool, page: Option<usize>) -> Result<Vec<u8>> {
+        ColumnEncryptor {
+            key: &self.key,
+            file_aad: &self.file_aad,
+            row_group: self.row_group,
+            column: self.column,
+        }
+        .aad(header, page)
+    }
+
+    /// Reads and decrypts the header of the page with ordinal `page`.
+    pub(crate) fn read_page_header<R: Read>(
+        &self,
+        reader: &mut R,
+        page: Option<usize>,
+        max_size: usize,
+    ) -> Result<ParquetPageHeader> {
+        let mut len = [0u8; 4];
+        reader.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len) as usize;
+        if len > max_size {
+            return Err(Error::WouldOverAllocate);
+        }
+        let mut module = vec![];
+        module.try_reserve(len)?;
+        reader.by_ref().take(len as u64).read_to_end(&mut module)?;
+        if module.len() != len {
+            return Err(Error::oos(
+                "An encrypted page header is shorter than its length",
+            ));
+        }
+
+        let plaintext = decrypt_module(&self.key, &self.aad(true, page)?, &module)?;
+        let mut prot = TCompactInputProtocol::new(plaintext.as_slice(), max_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Decrypts the (compressed) data of the page with ordinal `page`.
+    pub(crate) fn decrypt_page(&self, data: &[u8], page: Option<usize>) -> Result<Vec<u8>> {
+        decrypt_prefixed_module(&self.key, &self.aad(false, page)?, data)
+    }
+}
+
+fn retrieve_key(key_retriever: &dyn KeyRetriever, key_metadata: Option<&[u8]>) -> Result<Vec<u8>> {
+    key_retriever
+        .retrieve_key(key_metadata.unwrap_or_default())
+        .map_err(|e| Error::InvalidParameter(format!("could not retrieve key: {e}")))
+}
+
+fn file_aad(algorithm: &EncryptionAlgorithm) -> Result<Vec<u8>> {
+    let (aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+        EncryptionAlgorithm::AESGCMV1(a) => {
+            (&a.aad_prefix, &a.aad_file_unique, a.supply_aad_prefix)
+        },
+        EncryptionAlgorithm::AESGCMCTRV1(_) => {
+            return Err(Error::FeatureNotSupported(
+                "reading files encrypted with AES_GCM_CTR_V1".to_string(),
+            ))
+        },
+    };
+    if supply_aad_prefix == Some(true) {
+        return Err(Error::FeatureNotSupported(
+            "reading encrypted files whose AAD prefix is not stored in the file".to_string(),
+        ));
+    }
+    let mut file_aad = aad_prefix.clone().unwrap_or_default();
+    file_aad.extend_from_slice(aad_file_unique.as_deref().unwrap_or_default());
+    Ok(file_aad)
+}
+
+/// Whether any column of `metadata` is encrypted.
+pub(crate) fn has_encrypted_columns(metadata: &ThriftFileMetaData) -> bool {
+    metadata
+        .row_groups
+        .iter()
+        .flat_map(|rg| rg.columns.iter())
+        .any(|column| column.crypto_metadata.is_some())
+}
+
+/// Decrypts an encrypted footer, i.e. the crypto metadata followed by the encrypted
+/// [`ThriftFileMetaData`].
+pub(crate) fn decrypt_footer(
+    mut footer: &[u8],
+    key_retriever: &dyn KeyRetriever,
+    max_size: usize,
+) -> Result<FileMetaData> {
+    let crypto_metadata = {
+        let mut prot = TCompactInputProtocol::new(&mut footer, max_size);
+        FileCryptoMetaData::read_from_in_protocol(&mut prot)?
+    };
+
+    let file_aad = file_aad(&crypto_metadata.encryption_algorithm)?;
+    let footer_key = retrieve_key(key_retriever, crypto_metadata.key_metadata.as_deref())?;
+    let aad = module_aad(&file_aad, ModuleType::Footer, 0, 0, None)?;
+    let plaintext = decrypt_prefixed_module(&footer_key, &aad, footer)?;
+
+    let mut prot = TCompactInputProtocol::new(plaintext.as_slice(), max_size);
+    let metadata = ThriftFileMetaData::read_from_in_protocol(&mut prot)?;
+    decrypt_metadata(
+        metadata,
+        file_aad,
+        Some(footer_key),
+        key_retriever,
+        max_size,
+    )
+}
+
+/// Decrypts the column metadata of a file with a plaintext footer.
+pub(crate) fn decrypt_plaintext_footer(
+    metadata: ThriftFileMetaData,
+    key_retriever: &dyn KeyRetriever,
+    max_size: usize,
+) -> Result<FileMetaData> {
+    let algorithm = metadata.encryption_algorithm.as_ref().ok_or_else(|| {
+        Error::oos("A file with encrypted columns must declare its encryption algorithm")
+    })?;
+    let file_aad = file_aad(algorithm)?;
+    let footer_key = metadata
+        .footer_signing_key_metadata
+        .as_deref()
+        .map(|key_metadata| retrieve_key(key_retriever, Some(key_metadata)))
+        .transpose()?;
+    decrypt_metadata(metadata, file_aad, footer_key, key_retriever, max_size)
+}
+
+fn decrypt_metadata(
+    mut metadata: ThriftFileMetaData,
+    file_aad: Vec<u8>,
+    footer_key: Option<Vec<u8>>,
+    key_retriever: &dyn KeyRetriever,
+    max_size: usize,
+) -> Result<FileMetaData> {
+    let mut decryptors = Vec::with_capacity(metadata.row_groups.len());
+    for (row_group, rg) in metadata.row_groups.iter_mut().enumerate() {
+        let mut columns = Vec::with_capacity(rg.columns.len());
+        for (column, column_chunk) in rg.columns.iter_mut().enumerate() {
+            let key = match &column_chunk.crypto_metadata {
+                None => {
+                    columns.push(None);
+                    continue;
+                },
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                    footer_key.clone().ok_or_else(|| {
+                        Error::oos("A column encrypted with the footer key requires a footer key")
+                    })?
+                },
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(crypto)) => {
+                    let key = retrieve_key(key_retriever, crypto.key_metadata.as_deref())?;
+                    if let Some(encrypted) = &column_chunk.encrypted_column_metadata {
+                        let aad = module_aad(
+                            &file_aad,
+                            ModuleType::ColumnMetaData,
+                            row_group,
+                            column,
+                            None,
+                        )?;
+                        let plaintext = decrypt_prefixed_module(&key, &aad, encrypted)?;
+                        let mut prot = TCompactInputProtocol::new(plaintext.as_slice(), max_size);
+                        column_chunk.meta_data =
+                            Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+                    }
+                    key
+                },
+            };
+            let has_dictionary = column_chunk
+                .meta_data
+                .as_ref()
+                .map_or(false, |meta| meta.dictionary_page_offset.is_some());
+            columns.push(Some(Arc::new(ColumnDecryptor {
+                key,
+                file_aad: file_aad.clone(),
+                row_group,
+                column,
+                has_dictionary,
+            })));
+        }
+        decryptors.push(columns);
+    }
+
+    let mut metadata = FileMetaData::try_from_thrift(metadata)?;
+    for (rg, decryptors) in metadata.row_groups.iter_mut().zip(decryptors) {
+        for (column, decryptor) in rg.columns_mut().iter_mut().zip(decryptors) {
+            column.set_decryptor(decryptor);
+        }
+    }
+    Ok(metadata)
+}
@@ -4,6 +4,7 @@ use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::{deserialize_statistics, Statistics};
@@ -38,6 +39,8 @@ pub struct ColumnChunkMetaData {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -75,6 +78,7 @@ impl ColumnChunkMetaData {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -200,9 +204,19 @@ impl ColumnChunkMetaData {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         })
     }
 
+    /// The decryptor of the pages of this column chunk, if it is encrypted.
+    pub(crate) fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
+    pub(crate) fn set_decryptor(&mut self, decryptor: Option<Arc<ColumnDecryptor>>) {
+        self.decryptor = decryptor;
+    }
+
     /// Method to convert to Thrift.
     pub fn into_thrift(self) -> ColumnChunk {
         self.column_chunk
@@ -35,6 +35,10 @@ impl RowGroupMetaData {
         &self.columns
     }
 
+    pub(crate) fn columns_mut(&mut self) -> &mut [ColumnChunkMetaData] {
+        &mut self.columns
+    }
+
     /// Number of rows in this row group.
     pub fn num_rows(&self) -> usize {
         self.num_rows
@@ -5,6 +5,7 @@ pub mod bloom_filter;
 pub mod compression;
 pub mod deserialize;
 pub mod encoding;
+pub mod encryption;
 pub mod indexes;
 pub mod metadata;
 pub mod page;
@@ -5,6 +5,10 @@ use std::io::{Read, Seek, SeekFrom};
 use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
 use parquet_format_safe::FileMetaData as TFileMetaData;
 
+use super::super::encryption::{
+    decrypt_footer, decrypt_plaintext_footer, has_encrypted_columns, KeyRetriever,
+    PARQUET_MAGIC_ENCRYPTED,
+};
 use super::super::metadata::FileMetaData;
 use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
 use crate::parquet::error::{Error, Result};
@@ -38,6 +42,25 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> Result<FileMetaData> {
+    read_metadata_impl(reader, file_size, None)
+}
+
+/// Reads a [`FileMetaData`] from the reader of a file with encrypted columns or an encrypted
+/// footer, retrieving the keys with `key_retriever`. The decryption keys of the columns are
+/// kept in the metadata, so that their pages can be decrypted.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    key_retriever: &dyn KeyRetriever,
+) -> Result<FileMetaData> {
+    let file_size = stream_len(reader)?;
+    read_metadata_impl(reader, file_size, Some(key_retriever))
+}
+
+fn read_metadata_impl<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    key_retriever: Option<&dyn KeyRetriever>,
 ) -> Result<FileMetaData> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(Error::oos(
@@ -56,7 +79,9 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
+    let magic = &buffer[default_end_len - 4..];
+    let encrypted_footer = magic == PARQUET_MAGIC_ENCRYPTED;
+    if magic != PARQUET_MAGIC && !encrypted_footer {
         return Err(Error::oos("The file must end with PAR1"));
     }
 
@@ -89,7 +114,27 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     // a highly nested but sparse struct could result in many allocations
     let max_size = reader.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    if encrypted_footer {
+        let key_retriever = key_retriever.ok_or_else(|| {
+            Error::InvalidParameter(
+                "the file has an encrypted footer; a key retriever is required to read it"
+                    .to_string(),
+            )
+        })?;
+        return decrypt_footer(&reader[..metadata_len as usize], key_retriever, max_size);
+    }
+
+    let mut prot = TCompactInputProtocol::new(reader, max_size);
+    let metadata = TFileMetaData::read_from_in_protocol(&mut prot)?;
+    match key_retriever {
+        Some(key_retriever) if has_encrypted_columns(&metadata) => {
+            decrypt_plaintext_footer(metadata, key_retriever, max_size)
+        },
+        None if has_encrypted_columns(&metadata) => Err(Error::InvalidParameter(
+            "the file has encrypted columns; a key retriever is required to read it".to_string(),
+        )),
+        _ => FileMetaData::try_from_thrift(metadata),
+    }
 }
 
 /// Parse loaded metadata bytes
@@ -13,7 +13,9 @@ use std::sync::Arc;
 pub use column::*;
 pub use compression::{decompress, BasicDecompressor, Decompressor};
 pub use indexes::{read_columns_indexes, read_pages_locations};
-pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size};
+pub use metadata::{
+    deserialize_metadata, read_metadata, read_metadata_with_decryption, read_metadata_with_size,
+};
 #[cfg(feature = "async")]
 pub use page::{get_page_stream, get_page_stream_from_column_start};
 pub use page::{IndexedPageReader, PageFilter, PageIterator, PageMetaData, PageReader};
@@ -33,6 +33,9 @@ pub struct IndexedPageReader<R: Read + Seek> {
     // buffer to store the data [data] and re-use across pages
     data_buffer: Vec<u8>,
 
+    // encrypted pages can only be read by the `PageReader`
+    encrypted: bool,
+
     pages: VecDeque<FilteredPage>,
 
     state: State,
@@ -114,6 +117,7 @@ impl<R: Read + Seek> IndexedPageReader<R> {
             descriptor: column.descriptor,
             buffer,
             data_buffer,
+            encrypted: column.decryptor.is_some(),
             pages,
             state: State::MaybeDict,
         }
@@ -180,6 +184,13 @@ impl<R: Read + Seek> Iterator for IndexedPageReader<R> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.state {
+            State::MaybeDict if self.encrypted => {
+                self.state = State::Data;
+                self.pages.clear();
+                Some(Err(Error::FeatureNotSupported(
+                    "reading encrypted column chunks with a page index".to_string(),
+                )))
+            },
             State::MaybeDict => {
                 self.state = State::Data;
                 if let Some(dict) = self.read_dict() {
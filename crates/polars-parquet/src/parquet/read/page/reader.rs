@@ -6,6 +6,7 @@ use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
 
 use super::PageIterator;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::indexes::Interval;
 use crate::parquet::metadata::{ColumnChunkMetaData, Descriptor};
@@ -26,6 +27,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// The decryptor of the pages, if the column chunk is encrypted
+    pub(crate) decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 impl PageMetaData {
@@ -41,6 +44,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            decryptor: None,
         }
     }
 }
@@ -52,6 +56,7 @@ impl From<&ColumnChunkMetaData> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            decryptor: column.decryptor().cloned(),
         }
     }
 }
@@ -85,6 +90,11 @@ pub struct PageReader<R: Read> {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of pages we have read so far, which encrypted pages are bound to.
+    seen_num_pages: usize,
 }
 
 impl<R: Read> PageReader<R> {
@@ -121,6 +131,8 @@ impl<R: Read> PageReader<R> {
             pages_filter,
             scratch,
             max_page_size,
+            decryptor: reader_meta.decryptor,
+            seen_num_pages: 0,
         }
     }
 
@@ -185,7 +197,17 @@ pub(super) fn build_page<R: Read>(
     reader: &mut PageReader<R>,
     buffer: &mut Vec<u8>,
 ) -> Result<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_ordinal = reader
+        .decryptor
+        .as_ref()
+        .and_then(|decryptor| decryptor.page_ordinal(reader.seen_num_pages));
+    let page_header = match &reader.decryptor {
+        Some(decryptor) => {
+            decryptor.read_page_header(&mut reader.reader, page_ordinal, reader.max_page_size)?
+        },
+        None => read_page_header(&mut reader.reader, reader.max_page_size)?,
+    };
+    reader.seen_num_pages += 1;
 
     reader.seen_num_values += get_page_header(&page_header)?
         .map(|x| x.num_values() as i64)
@@ -211,6 +233,10 @@ pub(super) fn build_page<R: Read>(
         ));
     }
 
+    if let Some(decryptor) = &reader.decryptor {
+        *buffer = decryptor.decrypt_page(buffer, page_ordinal)?;
+    }
+
     finish_page(
         page_header,
         buffer,
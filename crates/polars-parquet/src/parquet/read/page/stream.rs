@@ -39,6 +39,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     max_header_size: usize,
 ) -> Result<impl Stream<Item = Result<CompressedPage>> + 'a> {
     let page_metadata: PageMetaData = column_metadata.into();
+    ensure_not_encrypted(&page_metadata)?;
     Ok(_get_page_stream(
         reader,
         page_metadata.num_values,
@@ -58,6 +59,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     pages_filter: PageFilter,
     max_page_size: usize,
 ) -> Result<impl Stream<Item = Result<CompressedPage>> + '_> {
+    ensure_not_encrypted(&page_metadata)?;
     let column_start = page_metadata.column_start;
     reader.seek(SeekFrom::Start(column_start)).await?;
     Ok(_get_page_stream(
@@ -71,6 +73,15 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     ))
 }
 
+fn ensure_not_encrypted(page_metadata: &PageMetaData) -> Result<()> {
+    if page_metadata.decryptor.is_some() {
+        return Err(Error::FeatureNotSupported(
+            "reading encrypted column chunks asynchronously".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
     total_num_values: i64,
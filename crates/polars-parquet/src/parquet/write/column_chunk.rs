@@ -10,11 +10,12 @@ use polars_utils::aliases::PlHashSet;
 
 #[cfg(feature = "async")]
 use super::page::write_page_async;
-use super::page::{write_page, PageWriteSpec};
+use super::page::{is_data_page, write_encrypted_page, write_page, PageWriteSpec};
 use super::statistics::reduce;
 use super::DynStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -26,6 +27,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<ColumnEncryptor<'_>>,
 ) -> Result<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -37,14 +39,40 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut num_data_pages = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = match &encryptor {
+            Some(encryptor) => {
+                let page_ordinal = match compressed_page {
+                    CompressedPage::Data(_) => {
+                        num_data_pages += 1;
+                        Some(num_data_pages - 1)
+                    },
+                    CompressedPage::Dict(_) => None,
+                };
+                write_encrypted_page(writer, offset, compressed_page, encryptor, page_ordinal)?
+            },
+            None => write_page(writer, offset, compressed_page)?,
+        };
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    if encryptor.is_some() {
+        // readers of encrypted files find the dictionary page through its offset, and the
+        // metadata is only written (encrypted) in the footer.
+        if let Some(spec) = specs.first().filter(|spec| !is_data_page(spec)) {
+            let metadata = column_chunk.meta_data.as_mut().unwrap();
+            metadata.dictionary_page_offset = Some(spec.offset as i64);
+            metadata.data_page_offset = specs
+                .get(1)
+                .map_or(metadata.data_page_offset, |spec| spec.offset as i64);
+        }
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
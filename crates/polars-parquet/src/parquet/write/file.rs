@@ -4,9 +4,12 @@ use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 use parquet_format_safe::RowGroup;
 
 use super::indexes::{write_column_index, write_offset_index};
-use super::page::PageWriteSpec;
+use super::page::{has_statistics, PageWriteSpec};
 use super::row_group::write_row_group;
 use super::{RowGroupIter, WriteOptions};
+use crate::parquet::encryption::{
+    FileEncryptionProperties, FileEncryptor, PARQUET_MAGIC_ENCRYPTED,
+};
 use crate::parquet::error::{Error, Result};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetaData};
@@ -65,6 +68,7 @@ pub struct FileWriter<W: Write> {
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetaData>,
+    encryptor: Option<FileEncryptor>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -122,9 +126,28 @@ impl<W: Write> FileWriter<W> {
             page_specs: vec![],
             state: State::Initialised,
             metadata: None,
+            encryptor: None,
         }
     }
 
+    /// Encrypts the file as described by `properties`.
+    ///
+    /// Encrypted column chunks are written without column and offset indexes.
+    ///
+    /// # Errors
+    /// Returns an error if a key is invalid, a column of `properties` is not in the schema or
+    /// the `encryption` feature is not active.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> Result<Self> {
+        self.encryptor = Some(FileEncryptor::try_new(properties, &self.schema)?);
+        Ok(self)
+    }
+
+    fn is_encrypted(&self, column: usize) -> bool {
+        self.encryptor
+            .as_ref()
+            .map_or(false, |encryptor| encryptor.column(0, column).is_some())
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -133,7 +156,12 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> Result<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = if self.encryptor.is_some() {
+                self.writer.write_all(&PARQUET_MAGIC_ENCRYPTED)?;
+                PARQUET_MAGIC_ENCRYPTED.len() as u64
+            } else {
+                start_file(&mut self.writer)?
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -161,6 +189,7 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
@@ -168,6 +197,32 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a bloom filter of the column `column` of the last row group written to the file.
+    ///
+    /// # Errors
+    /// Returns an error if no row group was written or if the row group has no column `column`.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filter(&mut self, column: usize, bitset: &[u8]) -> Result<()> {
+        if self.is_encrypted(column) {
+            return Err(Error::FeatureNotSupported(
+                "writing bloom filters of encrypted columns".to_string(),
+            ));
+        }
+        let metadata = self
+            .row_groups
+            .last_mut()
+            .and_then(|group| group.columns.get_mut(column))
+            .and_then(|column| column.meta_data.as_mut())
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!(
+                    "cannot write a bloom filter of column {column}: no such column chunk was written"
+                ))
+            })?;
+        metadata.bloom_filter_offset = Some(self.offset as i64);
+        self.offset += crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
@@ -183,22 +238,32 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        let encrypted = (0..self.schema.columns().len())
+            .map(|column| self.is_encrypted(column))
+            .collect::<Vec<_>>();
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
                 .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .zip(&encrypted)
+                        .try_for_each(|((column, pages), encrypted)| {
+                            if *encrypted || !has_statistics(pages) {
+                                return Result::Ok(());
+                            }
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             Result::Ok(())
-                        },
-                    )?;
+                        })?;
                     Result::Ok(())
                 })?;
         };
@@ -212,7 +277,11 @@ impl<W: Write> FileWriter<W> {
                     .columns
                     .iter_mut()
                     .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .zip(&encrypted)
+                    .try_for_each(|((column, pages), encrypted)| {
+                        if *encrypted {
+                            return Result::Ok(());
+                        }
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
                         self.offset += write_offset_index(&mut self.writer, pages)?;
@@ -222,6 +291,14 @@ impl<W: Write> FileWriter<W> {
                 Result::Ok(())
             })?;
 
+        if let Some(encryptor) = &self.encryptor {
+            for (row_group, group) in self.row_groups.iter_mut().enumerate() {
+                for (column, column_chunk) in group.columns.iter_mut().enumerate() {
+                    encryptor.encrypt_column_chunk(row_group, column, column_chunk)?;
+                }
+            }
+        }
+
         let metadata = ThriftFileMetaData::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
@@ -234,7 +311,10 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => encryptor.write_footer(&mut self.writer, &metadata)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
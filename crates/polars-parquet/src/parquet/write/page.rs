@@ -10,6 +10,7 @@ use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 use parquet_format_safe::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{Error, Result};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -20,6 +21,15 @@ pub(crate) fn is_data_page(page: &PageWriteSpec) -> bool {
     page.header.type_ == PageType::DATA_PAGE || page.header.type_ == PageType::DATA_PAGE_V2
 }
 
+/// Whether all data pages of a column chunk have statistics, which the column index requires.
+/// Columns can be written without statistics even when the file writes them.
+pub(crate) fn has_statistics(pages: &[PageWriteSpec]) -> bool {
+    pages
+        .iter()
+        .filter(|page| is_data_page(page))
+        .all(|page| page.statistics.is_some())
+}
+
 fn maybe_bytes(uncompressed: usize, compressed: usize) -> Result<(i32, i32)> {
     let uncompressed_page_size: i32 = uncompressed.try_into().map_err(|_| {
         Error::oos(format!(
@@ -55,28 +65,61 @@ pub fn write_page<W: Write>(
     offset: u64,
     compressed_page: &CompressedPage,
 ) -> Result<PageWriteSpec> {
-    let num_values = compressed_page.num_values();
-    let selected_rows = compressed_page.selected_rows();
+    let header = assemble_page_header(compressed_page)?;
 
-    let header = match &compressed_page {
+    let header_size = write_page_header(writer, &header)?;
+    let buffer = page_buffer(compressed_page);
+    writer.write_all(buffer)?;
+    let bytes_written = header_size + buffer.len() as u64;
+
+    page_write_spec(compressed_page, header, header_size, offset, bytes_written)
+}
+
+/// Writes an encrypted page. `page_ordinal` is the ordinal of the data page within its column
+/// chunk, `None` for the dictionary page.
+pub(crate) fn write_encrypted_page<W: Write>(
+    writer: &mut W,
+    offset: u64,
+    compressed_page: &CompressedPage,
+    encryptor: &ColumnEncryptor<'_>,
+    page_ordinal: Option<usize>,
+) -> Result<PageWriteSpec> {
+    let data = encryptor.encrypt_page(page_buffer(compressed_page), page_ordinal)?;
+
+    // SPEC: the compressed page size of an encrypted page is the size of its module
+    let mut header = assemble_page_header(compressed_page)?;
+    header.compressed_page_size = maybe_bytes(0, data.len())?.1;
+    let header_module = encryptor.encrypt_page_header(&header, page_ordinal)?;
+
+    writer.write_all(&header_module)?;
+    writer.write_all(&data)?;
+    let header_size = header_module.len() as u64;
+    let bytes_written = header_size + data.len() as u64;
+
+    page_write_spec(compressed_page, header, header_size, offset, bytes_written)
+}
+
+fn assemble_page_header(compressed_page: &CompressedPage) -> Result<ParquetPageHeader> {
+    match compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
-    }?;
-
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    }
+}
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-    };
+fn page_buffer(compressed_page: &CompressedPage) -> &[u8] {
+    match compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    }
+}
 
+fn page_write_spec(
+    compressed_page: &CompressedPage,
+    header: ParquetPageHeader,
+    header_size: u64,
+    offset: u64,
+    bytes_written: u64,
+) -> Result<PageWriteSpec> {
     let statistics = match &compressed_page {
         CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
         CompressedPage::Dict(_) => None,
@@ -89,8 +132,10 @@ pub fn write_page<W: Write>(
         bytes_written,
         compression: compressed_page.compression(),
         statistics,
-        num_rows: selected_rows.map(|x| x.last().unwrap().length),
-        num_values,
+        num_rows: compressed_page
+            .selected_rows()
+            .map(|x| x.last().unwrap().length),
+        num_values: compressed_page.num_values(),
     })
 }
 
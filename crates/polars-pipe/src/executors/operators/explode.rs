@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_plan::frame::StreamingOptions;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::pipeline::{determine_chunk_size, estimated_row_width};
+
+/// Explodes list columns of the incoming chunks.
+///
+/// The list lengths are known before exploding, so the input chunk is sliced such that
+/// every exploded output chunk has roughly the ideal chunk size. This keeps memory bounded
+/// if a chunk holds a few very long lists.
+#[derive(Clone)]
+pub(crate) struct ExplodeOperator {
+    columns: Arc<[Arc<str>]>,
+    mismatch: ExplodeMismatch,
+    // ideal number of rows in an output chunk
+    chunk_size: usize,
+    // the slices of the current input chunk that still have to be exploded
+    offsets: VecDeque<(usize, usize)>,
+}
+
+impl ExplodeOperator {
+    pub(crate) fn new(
+        columns: Arc<[Arc<str>]>,
+        mismatch: ExplodeMismatch,
+        output_schema: &Schema,
+        streaming_options: &StreamingOptions,
+    ) -> PolarsResult<Self> {
+        let row_width = estimated_row_width(output_schema.iter_dtypes());
+        let chunk_size =
+            determine_chunk_size(row_width, POOL.current_num_threads(), streaming_options)?;
+        Ok(Self {
+            columns,
+            mismatch,
+            chunk_size,
+            offsets: VecDeque::new(),
+        })
+    }
+
+    /// The number of rows every input row explodes to. `None` if that is not cheaply known.
+    fn output_lengths(&self, df: &DataFrame) -> PolarsResult<Option<Vec<usize>>> {
+        let Some(name) = self.columns.first() else {
+            return Ok(None);
+        };
+        let s = df.column(name)?;
+        let Ok(ca) = s.list() else {
+            return Ok(None);
+        };
+        let mut lengths = Vec::with_capacity(ca.len());
+        for arr in ca.downcast_iter() {
+            // empty and null lists explode to a single null
+            match arr.validity() {
+                Some(validity) => lengths.extend(
+                    arr.offsets()
+                        .lengths()
+                        .zip(validity.iter())
+                        .map(|(len, valid)| if valid { len.max(1) } else { 1 }),
+                ),
+                None => lengths.extend(arr.offsets().lengths().map(|len| len.max(1))),
+            }
+        }
+        Ok(Some(lengths))
+    }
+
+    // Slice the input rows such that every slice explodes to about `chunk_size` rows.
+    fn split_offsets(&mut self, lengths: &[usize]) {
+        let mut start = 0;
+        let mut acc = 0;
+        for (i, len) in lengths.iter().enumerate() {
+            acc += *len;
+            if acc >= self.chunk_size {
+                self.offsets.push_back((start, i + 1 - start));
+                start = i + 1;
+                acc = 0;
+            }
+        }
+        if start < lengths.len() {
+            self.offsets.push_back((start, lengths.len() - start));
+        }
+    }
+
+    fn explode(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        df.explode_with(self.columns.as_ref(), self.mismatch)
+    }
+}
+
+impl Operator for ExplodeOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        if self.offsets.is_empty() {
+            match self.output_lengths(&chunk.data)? {
+                Some(lengths) if lengths.iter().sum::<usize>() > self.chunk_size => {
+                    self.split_offsets(&lengths)
+                },
+                _ => {
+                    let out = self.explode(&chunk.data)?;
+                    return Ok(OperatorResult::Finished(chunk.with_data(out)));
+                },
+            }
+        }
+        let (offset, len) = self.offsets.pop_front().unwrap();
+        let out = self.explode(&chunk.data.slice(offset as i64, len))?;
+        let out = chunk.with_data(out);
+        if self.offsets.is_empty() {
+            Ok(OperatorResult::Finished(out))
+        } else {
+            Ok(OperatorResult::HaveMoreOutPut(out))
+        }
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "explode"
+    }
+}
@@ -3,10 +3,11 @@ use std::collections::VecDeque;
 use polars_core::error::PolarsResult;
 use polars_core::utils::_split_offsets;
 use polars_core::POOL;
+use polars_plan::frame::StreamingOptions;
 use polars_plan::prelude::*;
 
 use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
-use crate::pipeline::determine_chunk_size;
+use crate::pipeline::{determine_chunk_size, observed_row_width};
 
 #[derive(Clone)]
 pub struct FunctionOperator {
@@ -14,15 +15,17 @@ pub struct FunctionOperator {
     chunk_size: usize,
     offsets: VecDeque<(usize, usize)>,
     function: FunctionNode,
+    streaming_options: StreamingOptions,
 }
 
 impl FunctionOperator {
-    pub(crate) fn new(function: FunctionNode) -> Self {
+    pub(crate) fn new(function: FunctionNode, streaming_options: &StreamingOptions) -> Self {
         FunctionOperator {
             n_threads: POOL.current_num_threads(),
             function,
             chunk_size: 128,
             offsets: VecDeque::new(),
+            streaming_options: *streaming_options,
         }
     }
 
@@ -62,7 +65,9 @@ impl Operator for FunctionOperator {
             // ideal chunk size we want to have
             // we cannot rely on input chunk size as that can increase due to multiple explode calls
             // for instance.
-            let chunk_size_ambition = determine_chunk_size(chunk.data.width(), self.n_threads)?;
+            let row_width = observed_row_width(&chunk.data).unwrap_or(1);
+            let chunk_size_ambition =
+                determine_chunk_size(row_width, self.n_threads, &self.streaming_options)?;
 
             if self.offsets.is_empty() {
                 let n = input_height / self.chunk_size;
@@ -1,13 +1,21 @@
+mod explode;
 mod filter;
 mod function;
 mod pass;
 mod placeholder;
 mod projection;
 mod reproject;
+mod row_index;
+#[cfg(feature = "dtype-struct")]
+mod unnest;
 
+pub(crate) use explode::*;
 pub(crate) use filter::*;
 pub(crate) use function::*;
 pub(crate) use pass::Pass;
 pub(crate) use placeholder::PlaceHolder;
 pub(crate) use projection::*;
 pub(crate) use reproject::*;
+pub(crate) use row_index::*;
+#[cfg(feature = "dtype-struct")]
+pub(crate) use unnest::*;
@@ -57,17 +57,15 @@ impl Operator for ReProjectOperator {
         context: &PExecutionContext,
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult> {
-        let (mut chunk, finished) = match self.operator.execute(context, chunk)? {
-            OperatorResult::Finished(chunk) => (chunk, true),
-            OperatorResult::HaveMoreOutPut(chunk) => (chunk, false),
-            OperatorResult::NeedsNewData => return Ok(OperatorResult::NeedsNewData),
-        };
+        let (mut chunk, wrap): (_, fn(DataChunk) -> OperatorResult) =
+            match self.operator.execute(context, chunk)? {
+                OperatorResult::Finished(chunk) => (chunk, OperatorResult::Finished),
+                OperatorResult::HaveMoreOutPut(chunk) => (chunk, OperatorResult::HaveMoreOutPut),
+                OperatorResult::Stop(chunk) => (chunk, OperatorResult::Stop),
+                OperatorResult::NeedsNewData => return Ok(OperatorResult::NeedsNewData),
+            };
         reproject_chunk(&mut chunk, &mut self.positions, self.schema.as_ref())?;
-        Ok(if finished {
-            OperatorResult::Finished(chunk)
-        } else {
-            OperatorResult::HaveMoreOutPut(chunk)
-        })
+        Ok(wrap(chunk))
     }
 
     fn split(&self, thread_no: usize) -> Box<dyn Operator> {
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::IdxSize;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Adds a row index to the incoming chunks. The index continues where the index of the previous
+/// chunk stopped, so the chunks must be pushed in order.
+#[derive(Clone)]
+pub(crate) struct RowIndexOperator {
+    name: Arc<str>,
+    offset: IdxSize,
+}
+
+impl RowIndexOperator {
+    pub(crate) fn new(name: Arc<str>, offset: Option<IdxSize>) -> Self {
+        Self {
+            name,
+            offset: offset.unwrap_or(0),
+        }
+    }
+}
+
+impl Operator for RowIndexOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let out = chunk
+            .data
+            .with_row_index(self.name.as_ref(), Some(self.offset))?;
+        self.offset += chunk.data.height() as IdxSize;
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "row_index"
+    }
+
+    fn must_run_sequentially(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// Replaces struct columns of the incoming chunks by their fields.
+#[derive(Clone)]
+pub(crate) struct UnnestOperator {
+    columns: Arc<[Arc<str>]>,
+}
+
+impl UnnestOperator {
+    pub(crate) fn new(columns: Arc<[Arc<str>]>) -> Self {
+        Self { columns }
+    }
+}
+
+impl Operator for UnnestOperator {
+    fn execute(
+        &mut self,
+        _context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let out = chunk.data.unnest(self.columns.as_ref())?;
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "unnest"
+    }
+}
@@ -0,0 +1,168 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+#[cfg(feature = "approx_unique")]
+use polars_core::export::ahash::RandomState;
+#[cfg(feature = "approx_quantile")]
+use polars_core::export::num::NumCast;
+use polars_core::prelude::Series;
+#[cfg(feature = "approx_unique")]
+use polars_core::prelude::IDX_DTYPE;
+#[cfg(feature = "approx_unique")]
+use polars_ops::prelude::HyperLogLog;
+#[cfg(feature = "approx_quantile")]
+use polars_ops::prelude::TDigest;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Seeds for hashing the values before they are added to the HyperLogLog, fixed so that the
+/// sketches of all thread local tables can be merged.
+#[cfg(feature = "approx_unique")]
+const SEED: RandomState = RandomState::with_seeds(
+    0x4e0b_5b4e_3f6c_1a27_u64,
+    0x9d1c_7a0e_62f3_84b5_u64,
+    0x2b8f_c3d1_0e9a_7f46_u64,
+    0xf1a0_6d2c_84e7_3b19_u64,
+);
+
+/// Streaming `approx_n_unique`. Every thread local table keeps a HyperLogLog per group, which are
+/// merged by taking the maximum of their registers.
+#[cfg(feature = "approx_unique")]
+pub(crate) struct ApproxNUniqueAgg {
+    // Boxed, as the registers would otherwise blow up the size of every `AggregateFunction`.
+    hll: Box<HyperLogLog<u64>>,
+}
+
+#[cfg(feature = "approx_unique")]
+impl ApproxNUniqueAgg {
+    pub(crate) fn new() -> Self {
+        Self {
+            hll: Box::default(),
+        }
+    }
+
+    fn add(&mut self, value: AnyValue) {
+        self.hll.add(&SEED.hash_one(value));
+    }
+}
+
+#[cfg(feature = "approx_unique")]
+impl AggregateFn for ApproxNUniqueAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.add(item);
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for value in values.slice(offset as i64, length as usize).iter() {
+            self.add(value);
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        IDX_DTYPE
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.hll.merge(&other.hll);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        AnyValue::from(self.hll.count() as IdxSize)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Streaming `approx_quantile`. Every thread local table keeps a t-digest per group, which are
+/// merged into a single digest per group before the quantile is computed.
+#[cfg(feature = "approx_quantile")]
+pub(crate) struct ApproxQuantileAgg {
+    digest: Box<TDigest>,
+    quantile: f64,
+    dtype: DataType,
+}
+
+#[cfg(feature = "approx_quantile")]
+impl ApproxQuantileAgg {
+    /// `dtype` is the output type: `Float32` for `Float32` input and `Float64` otherwise.
+    pub(crate) fn new(quantile: f64, dtype: DataType) -> Self {
+        Self {
+            digest: Box::default(),
+            quantile,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.quantile, self.dtype.clone())
+    }
+}
+
+#[cfg(feature = "approx_quantile")]
+impl AggregateFn for ApproxQuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        if let Some(value) = item.and_then(|v| <f64 as NumCast>::from(v)) {
+            self.digest.add(value)
+        }
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(value) = item.extract::<f64>() {
+            self.digest.add(value)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values
+            .slice(offset as i64, length as usize)
+            .cast(&DataType::Float64)
+            .unwrap();
+        for value in values.f64().unwrap().into_iter().flatten() {
+            self.digest.add(value)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.digest.merge(&other.digest);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        match (self.digest.quantile(self.quantile), &self.dtype) {
+            (Some(v), DataType::Float32) => AnyValue::Float32(v as f32),
+            (Some(v), _) => AnyValue::Float64(v),
+            (None, _) => AnyValue::Null,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
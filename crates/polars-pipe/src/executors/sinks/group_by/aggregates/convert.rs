@@ -9,10 +9,16 @@ use polars_core::schema::Schema;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::dsl::Expr;
 use polars_plan::logical_plan::{ArenaExprIter, Context};
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
+use polars_plan::prelude::FunctionExpr;
 use polars_plan::prelude::{AAggExpr, AExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
+#[cfg(feature = "approx_unique")]
+use crate::executors::sinks::group_by::aggregates::approx::ApproxNUniqueAgg;
+#[cfg(feature = "approx_quantile")]
+use crate::executors::sinks::group_by::aggregates::approx::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
@@ -46,6 +52,32 @@ impl PhysicalPipedExpr for Len {
     }
 }
 
+/// Whether `ae` is a function that aggregates with a mergeable sketch.
+fn is_sketch_agg(ae: &AExpr) -> bool {
+    match ae {
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        } => true,
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            function: FunctionExpr::ApproxQuantile,
+            ..
+        } => true,
+        _ => false,
+    }
+}
+
+/// The quantile of an `approx_quantile`, which must be a literal to run on the streaming engine.
+#[cfg(feature = "approx_quantile")]
+fn literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv.to_anyvalue()?.extract::<f64>(),
+        _ => None,
+    }
+}
+
 pub fn can_convert_to_hash_agg(
     mut node: Node,
     expr_arena: &Arena<AExpr>,
@@ -64,13 +96,14 @@ pub fn can_convert_to_hash_agg(
                 | AExpr::BinaryExpr { .. }
                 | AExpr::Ternary { .. }
                 | AExpr::Alias(_, _) => {},
+                ae if is_sketch_agg(ae) => {},
                 _ => {
                     can_run_partitioned = false;
                 },
             }
             ae
         })
-        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Len))
+        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Len) || is_sketch_agg(ae))
         .count()
         == 1
         && can_run_partitioned
@@ -81,6 +114,23 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Len => true,
+            #[cfg(feature = "approx_unique")]
+            AExpr::Function {
+                function: FunctionExpr::ApproxNUnique,
+                ..
+            } => true,
+            #[cfg(feature = "approx_quantile")]
+            AExpr::Function {
+                input,
+                function: FunctionExpr::ApproxQuantile,
+                ..
+            } => {
+                literal_quantile(input[1], expr_arena).is_some_and(|q| (0.0..=1.0).contains(&q))
+                    && expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena)
+                        .is_ok_and(|field| field.dtype.is_numeric())
+            },
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
@@ -133,6 +183,39 @@ where
             Arc::new(Len {}),
             AggregateFunction::Count(CountAgg::new()),
         ),
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype,
+                phys_expr,
+                AggregateFunction::ApproxNUnique(ApproxNUniqueAgg::new()),
+            )
+        },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxQuantile,
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let quantile = literal_quantile(input[1], expr_arena).unwrap();
+            let out_dtype = match logical_dtype {
+                DataType::Float32 => DataType::Float32,
+                _ => DataType::Float64,
+            };
+            (
+                logical_dtype,
+                phys_expr,
+                AggregateFunction::ApproxQuantile(ApproxQuantileAgg::new(quantile, out_dtype)),
+            )
+        },
         AExpr::Agg(agg) => match agg {
             AAggExpr::Min { input, .. } => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
@@ -5,6 +5,10 @@ use num_traits::NumCast;
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{AnyValue, Series};
 
+#[cfg(feature = "approx_unique")]
+use crate::executors::sinks::group_by::aggregates::approx::ApproxNUniqueAgg;
+#[cfg(feature = "approx_quantile")]
+use crate::executors::sinks::group_by::aggregates::approx::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
@@ -66,6 +70,10 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(i16, i16) -> i16>),
     MinMaxI32(MinMaxAgg<i32, fn(i32, i32) -> i32>),
     MinMaxI64(MinMaxAgg<i64, fn(i64, i64) -> i64>),
+    #[cfg(feature = "approx_unique")]
+    ApproxNUnique(ApproxNUniqueAgg),
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile(ApproxQuantileAgg),
 }
 
 impl AggregateFunction {
@@ -94,6 +102,10 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            #[cfg(feature = "approx_unique")]
+            ApproxNUnique(_) => ApproxNUnique(ApproxNUniqueAgg::new()),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile(inner) => ApproxQuantile(inner.split()),
         }
     }
 }
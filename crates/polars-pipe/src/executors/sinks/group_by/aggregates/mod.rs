@@ -1,3 +1,5 @@
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
+mod approx;
 mod convert;
 mod count;
 mod first;
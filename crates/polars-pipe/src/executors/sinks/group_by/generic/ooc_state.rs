@@ -1,4 +1,5 @@
 use polars_core::config::verbose;
+use polars_plan::frame::StreamingOptions;
 
 use super::*;
 use crate::executors::sinks::io::IOThread;
@@ -17,22 +18,22 @@ pub(super) struct OocState {
     pub(super) io_thread: IOThreadRef,
     count: u16,
     to_disk_threshold: f64,
+    // whether we are allowed to go out-of-core
+    spill: bool,
 }
 
-impl Default for OocState {
-    fn default() -> Self {
-        let to_disk_threshold = if std::env::var(FORCE_OOC).is_ok() {
-            1.0
-        } else {
-            TO_DISK_THRESHOLD
-        };
+impl OocState {
+    pub(super) fn new(options: &StreamingOptions) -> Self {
+        let force_ooc = std::env::var(FORCE_OOC).is_ok();
+        let to_disk_threshold = if force_ooc { 1.0 } else { TO_DISK_THRESHOLD };
 
         Self {
-            mem_track: MemTracker::new(morsels_per_sink()),
+            mem_track: MemTracker::new(morsels_per_sink(), options),
             ooc: false,
             io_thread: Default::default(),
             count: 0,
             to_disk_threshold,
+            spill: options.spill || force_ooc,
         }
     }
 }
@@ -75,7 +76,7 @@ impl OocState {
         let free_frac = self.mem_track.free_memory_fraction_since_start();
         self.count += 1;
 
-        if free_frac < self.to_disk_threshold {
+        if free_frac < self.to_disk_threshold && self.spill {
             if let Some(schema) = spill_schema() {
                 self.init_ooc(schema)?;
                 Ok(SpillAction::Dump)
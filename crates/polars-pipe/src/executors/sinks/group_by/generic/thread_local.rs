@@ -1,10 +1,11 @@
 use arrow::array::MutableBinaryArray;
 use once_cell::sync::Lazy;
 use polars_core::export::once_cell;
+use polars_plan::frame::StreamingOptions;
 use polars_utils::hashing::hash_to_partition;
 
 use super::*;
-use crate::pipeline::PARTITION_SIZE;
+use crate::pipeline::{group_by_spill_size, morsels_per_sink, PARTITION_SIZE};
 
 const OB_SIZE: usize = 2048;
 
@@ -231,7 +232,14 @@ impl ThreadLocalTable {
         key_dtypes: Arc<[DataType]>,
         agg_dtypes: Arc<[DataType]>,
         output_schema: SchemaRef,
+        streaming_options: &StreamingOptions,
     ) -> Self {
+        let spill_size = group_by_spill_size(
+            streaming_options,
+            *SPILL_SIZE,
+            output_schema.len(),
+            morsels_per_sink(),
+        );
         let spill_partitions =
             SpillPartitions::new(key_dtypes.clone(), agg_dtypes, output_schema.clone());
 
@@ -240,7 +248,7 @@ impl ThreadLocalTable {
                 agg_constructors,
                 key_dtypes.as_ref(),
                 output_schema,
-                Some(*SPILL_SIZE),
+                Some(spill_size),
             ),
             spill_partitions,
         }
@@ -2,6 +2,7 @@ use std::sync::Mutex;
 
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_plan::frame::StreamingOptions;
 
 use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::memory::MemTracker;
@@ -25,7 +26,7 @@ pub(super) struct OocState {
 impl OocState {
     pub(super) fn new(io_thread: Option<Arc<Mutex<Option<IOThread>>>>, ooc: bool) -> Self {
         Self {
-            _mem_track: MemTracker::new(morsels_per_sink()),
+            _mem_track: MemTracker::new(morsels_per_sink(), &StreamingOptions::default()),
             ooc,
             io_thread: io_thread.unwrap_or_default(),
         }
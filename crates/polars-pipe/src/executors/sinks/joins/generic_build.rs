@@ -75,6 +75,8 @@ pub struct GenericBuild {
     // the join order is swapped to ensure we hash the smaller table
     swapped: bool,
     join_nulls: bool,
+    // the slice of the output, the probe stops once it is produced
+    slice: Option<(i64, usize)>,
 }
 
 impl GenericBuild {
@@ -85,6 +87,7 @@ impl GenericBuild {
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
         join_nulls: bool,
+        slice: Option<(i64, usize)>,
     ) -> Self {
         let hb: RandomState = Default::default();
         let partitions = _set_partition_size();
@@ -102,6 +105,7 @@ impl GenericBuild {
             hash_tables,
             hashes: vec![],
             join_nulls,
+            slice,
         }
     }
 }
@@ -284,6 +288,7 @@ impl Sink for GenericBuild {
             self.join_columns_left.clone(),
             self.join_columns_right.clone(),
             self.join_nulls,
+            self.slice,
         );
         new.hb = self.hb.clone();
         Box::new(new)
@@ -329,6 +334,7 @@ impl Sink for GenericBuild {
                     context,
                     self.join_type.clone(),
                     self.join_nulls,
+                    self.slice,
                 );
                 Ok(FinalizedSink::Operator(Box::new(probe_operator)))
             },
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use arrow::array::{Array, ArrayRef, BinaryArray};
@@ -63,6 +64,9 @@ pub struct GenericJoinProbe {
     output_names: Option<Vec<SmartString>>,
     how: JoinType,
     join_nulls: bool,
+    // stop probing once this many rows are produced by all threads
+    limit: Option<usize>,
+    produced: Arc<AtomicUsize>,
 }
 
 trait ToRow {
@@ -97,6 +101,7 @@ impl GenericJoinProbe {
         context: &PExecutionContext,
         how: JoinType,
         join_nulls: bool,
+        slice: Option<(i64, usize)>,
     ) -> Self {
         if swapped_or_left {
             let tmp = DataChunk {
@@ -135,6 +140,10 @@ impl GenericJoinProbe {
             output_names: None,
             how,
             join_nulls,
+            limit: slice
+                .filter(|(offset, _)| *offset >= 0)
+                .map(|(offset, len)| offset as usize + len),
+            produced: Default::default(),
         }
     }
     fn set_join_series(
@@ -382,10 +391,8 @@ impl GenericJoinProbe {
 
         Ok(OperatorResult::Finished(chunk.with_data(out)))
     }
-}
 
-impl Operator for GenericJoinProbe {
-    fn execute(
+    fn execute_how(
         &mut self,
         context: &PExecutionContext,
         chunk: &DataChunk,
@@ -396,6 +403,37 @@ impl Operator for GenericJoinProbe {
             _ => unreachable!(),
         }
     }
+}
+
+impl Operator for GenericJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let Some(limit) = self.limit else {
+            return self.execute_how(context, chunk);
+        };
+        if self.produced.load(Ordering::Relaxed) >= limit {
+            // the slice is already produced, only determine the output schema
+            let chunk = chunk.with_data(chunk.data.clear());
+            let OperatorResult::Finished(out) = self.execute_how(context, &chunk)? else {
+                unreachable!()
+            };
+            return Ok(OperatorResult::Stop(out));
+        }
+        match self.execute_how(context, chunk)? {
+            OperatorResult::Finished(out) => {
+                let height = out.data.height();
+                if self.produced.fetch_add(height, Ordering::Relaxed) + height >= limit {
+                    Ok(OperatorResult::Stop(out))
+                } else {
+                    Ok(OperatorResult::Finished(out))
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
 
     fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
         let new = self.clone();
@@ -1,6 +1,7 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use polars_plan::frame::StreamingOptions;
 use polars_utils::sys::MEMINFO;
 
 use crate::pipeline::FORCE_OOC;
@@ -16,10 +17,12 @@ pub(super) struct MemTracker {
     thread_count: usize,
     available_at_start: usize,
     refresh_interval: usize,
+    // explicit memory budget set by the user
+    memory_limit: Option<usize>,
 }
 
 impl MemTracker {
-    pub(super) fn new(thread_count: usize) -> Self {
+    pub(super) fn new(thread_count: usize, options: &StreamingOptions) -> Self {
         let refresh_interval = if std::env::var(FORCE_OOC).is_ok() {
             1
         } else {
@@ -33,6 +36,7 @@ impl MemTracker {
             thread_count,
             available_at_start: 0,
             refresh_interval,
+            memory_limit: options.memory_budget(),
         };
         let available = MEMINFO.free() as usize;
         out.available_mem.store(available, Ordering::Relaxed);
@@ -47,6 +51,10 @@ impl MemTracker {
     }
 
     /// Get available memory of the system measured on latest refresh.
+    ///
+    /// If a memory budget is set, this is the part of the budget that is not yet used. The
+    /// used memory is the maximum of what this node registered and what the system lost
+    /// since the start of this node.
     pub(super) fn get_available(&self) -> usize {
         // once in every n passes we fetch mem usage.
         let fetch_count = self.fetch_count.fetch_add(1, Ordering::Relaxed);
@@ -54,12 +62,23 @@ impl MemTracker {
         if fetch_count % (self.refresh_interval * self.thread_count) == 0 {
             self.refresh_memory()
         }
-        self.available_mem.load(Ordering::Relaxed)
+        let available = self.available_mem.load(Ordering::Relaxed);
+        match self.memory_limit {
+            Some(limit) => {
+                let used = std::cmp::max(
+                    self.available_at_start.saturating_sub(available),
+                    self.used_by_node.load(Ordering::Relaxed),
+                );
+                limit.saturating_sub(used)
+            },
+            None => available,
+        }
     }
 
     pub(super) fn free_memory_fraction_since_start(&self) -> f64 {
+        let available_at_start = self.memory_limit.unwrap_or(self.available_at_start);
         // we divide first to reduce the precision loss in floats
-        let available_at_start = (self.available_at_start / TO_MB) as f64;
+        let available_at_start = (available_at_start / TO_MB).max(1) as f64;
         let available = (self.get_available() / TO_MB) as f64;
         available / available_at_start
     }
@@ -24,7 +24,9 @@ impl OrderedSink {
     }
 
     fn sort(&mut self) {
-        self.chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        // the sort must be stable, operators (e.g. joins) may produce multiple chunks
+        // with the same index, that are pushed in order.
+        self.chunks.sort_by_key(|chunk| chunk.chunk_index);
     }
 }
 
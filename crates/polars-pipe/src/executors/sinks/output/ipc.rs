@@ -9,16 +9,24 @@ use polars_plan::prelude::IpcWriterOptions;
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::pipeline::morsels_per_sink;
 
+pub(super) fn ipc_writer(
+    path: &Path,
+    options: &IpcWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    let file = std::fs::File::create(path)?;
+    let writer = IpcWriter::new(file)
+        .with_compression(options.compression)
+        .batched(schema)?;
+
+    Ok(Box::new(writer))
+}
+
 pub struct IpcSink {}
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let writer = ipc_writer(path, &options, schema)?;
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -18,6 +18,11 @@ impl SinkWriter for BatchedWriter<std::fs::File> {
     }
 }
 
+pub(super) fn json_writer(path: &Path) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    let file = std::fs::File::create(path)?;
+    Ok(Box::new(BatchedWriter::new(file)))
+}
+
 pub struct JsonSink {}
 impl JsonSink {
     #[allow(clippy::new_ret_no_self)]
@@ -26,10 +31,7 @@ impl JsonSink {
         options: JsonWriterOptions,
         _schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = BatchedWriter::new(file);
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+        let writer = json_writer(path)?;
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -13,6 +13,13 @@ mod ipc;
 mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+mod partitioned;
 
 #[cfg(feature = "csv")]
 pub use csv::*;
@@ -22,3 +29,10 @@ pub use ipc::*;
 pub use json::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use partitioned::*;
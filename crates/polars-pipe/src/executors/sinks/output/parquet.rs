@@ -8,6 +8,27 @@ use polars_plan::prelude::ParquetWriteOptions;
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::pipeline::morsels_per_sink;
 
+pub(super) fn parquet_writer(
+    path: &Path,
+    options: &ParquetWriteOptions,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    let file = std::fs::File::create(path)?;
+    let writer = ParquetWriter::new(file)
+        .with_compression(options.compression)
+        .with_data_page_size(options.data_pagesize_limit)
+        .with_statistics(options.statistics)
+        .with_row_group_size(options.row_group_size)
+        .with_row_group_size_bytes(options.row_group_size_bytes)
+        .with_column_options(options.column_options.clone())
+        // This is important! Otherwise we will deadlock
+        // See: #7074
+        .set_parallel(false)
+        .batched(schema)?;
+
+    Ok(Box::new(writer))
+}
+
 pub struct ParquetSink {}
 impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
@@ -16,18 +37,7 @@ impl ParquetSink {
         options: ParquetWriteOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let writer = parquet_writer(path, &options, schema)?;
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -65,6 +75,8 @@ impl ParquetCloudSink {
             .with_data_page_size(parquet_options.data_pagesize_limit)
             .with_statistics(parquet_options.statistics)
             .with_row_group_size(parquet_options.row_group_size)
+            .with_row_group_size_bytes(parquet_options.row_group_size_bytes)
+            .with_column_options(parquet_options.column_options)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use hashbrown::hash_map::Entry;
+use polars_core::prelude::*;
+use polars_io::partition::resolve_partition_dir;
+use polars_plan::prelude::FileType;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+fn file_writer(
+    path: &Path,
+    file_type: &FileType,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => super::parquet::parquet_writer(path, options, schema),
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => super::ipc::ipc_writer(path, options, schema),
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => super::csv::csv_writer(path, options, schema),
+        #[cfg(feature = "json")]
+        FileType::Json(_) => super::json::json_writer(path),
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    }
+}
+
+fn file_extension(file_type: &FileType) -> &'static str {
+    match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(_) => "parquet",
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(_) => "ipc",
+        #[cfg(feature = "csv")]
+        FileType::Csv(_) => "csv",
+        #[cfg(feature = "json")]
+        FileType::Json(_) => "json",
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    }
+}
+
+fn maintain_order(file_type: &FileType) -> bool {
+    match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => options.maintain_order,
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => options.maintain_order,
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => options.maintain_order,
+        #[cfg(feature = "json")]
+        FileType::Json(options) => options.maintain_order,
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    }
+}
+
+/// Splits every batch by the partition keys and writes every partition to its own file in a
+/// Hive partitioned directory, like the eager [`polars_io::partition::PartitionedWriter`].
+struct PartitionedWriter {
+    path: PathBuf,
+    file_type: FileType,
+    by: Vec<String>,
+    schema: Schema,
+    writers: PlHashMap<PathBuf, Box<dyn SinkWriter + Send>>,
+}
+
+impl SinkWriter for PartitionedWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for partition in df.partition_by_stable(self.by.as_slice(), true)? {
+            let dir = resolve_partition_dir(&self.path, &self.by, &partition);
+            let writer = match self.writers.entry(dir) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    std::fs::create_dir_all(entry.key())?;
+                    let file_name = format!("data-0000.{}", file_extension(&self.file_type));
+                    let path = entry.key().join(file_name);
+                    entry.insert(file_writer(&path, &self.file_type, &self.schema)?)
+                },
+            };
+            writer._write_batch(&partition)?;
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.writers
+            .values_mut()
+            .try_for_each(|writer| writer._finish())
+    }
+}
+
+pub struct PartitionedSink {}
+impl PartitionedSink {
+    /// Every partition keeps its file open until the sink is finished.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        file_type: &FileType,
+        by: &[String],
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(!by.is_empty(), ComputeError: "partitioned sink needs at least one key column");
+        for name in by {
+            polars_ensure!(schema.contains(name), ColumnNotFound: "{}", name);
+        }
+        std::fs::create_dir_all(path)?;
+
+        let writer = Box::new(PartitionedWriter {
+            path: path.to_path_buf(),
+            file_type: file_type.clone(),
+            by: by.to_vec(),
+            schema: schema.clone(),
+            writers: PlHashMap::new(),
+        }) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order(file_type),
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
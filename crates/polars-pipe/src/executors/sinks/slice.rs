@@ -41,7 +41,7 @@ impl SliceSink {
 
     fn sort(&mut self) {
         let mut chunks = self.chunks.lock().unwrap();
-        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        chunks.sort_by_key(|chunk| chunk.chunk_index);
     }
 }
 
@@ -65,7 +65,7 @@ impl Sink for SliceSink {
 
             chunks.push(chunk);
 
-            if current_len > (self.len + current_offset) {
+            if current_len + height >= self.len + current_offset {
                 Ok(SinkResult::Finished)
             } else {
                 Ok(SinkResult::CanHaveMoreInput)
@@ -6,13 +6,14 @@ use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::frame::StreamingOptions;
 use polars_plan::prelude::SortArguments;
 
 use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::memory::MemTracker;
 use crate::executors::sinks::sort::ooc::sort_ooc;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
-use crate::pipeline::{morsels_per_sink, FORCE_OOC};
+use crate::pipeline::{morsels_per_sink, sort_buffer_size, FORCE_OOC};
 
 pub struct SortSink {
     schema: SchemaRef,
@@ -22,6 +23,10 @@ pub struct SortSink {
     mem_track: MemTracker,
     // sort in-memory or out-of-core
     ooc: bool,
+    // whether we are allowed to go out-of-core
+    spill: bool,
+    // number of bytes we buffer before we dump to disk
+    buffer_size: usize,
     // when ooc, we write to disk using an IO thread
     // RwLock as we want to have multiple readers at once.
     io_thread: Arc<RwLock<Option<IOThread>>>,
@@ -38,7 +43,12 @@ pub struct SortSink {
 }
 
 impl SortSink {
-    pub(crate) fn new(sort_idx: usize, sort_args: SortArguments, schema: SchemaRef) -> Self {
+    pub(crate) fn new(
+        sort_idx: usize,
+        sort_args: SortArguments,
+        schema: SchemaRef,
+        streaming_options: &StreamingOptions,
+    ) -> Self {
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC).is_ok();
         let n_morsels_per_sink = morsels_per_sink();
@@ -46,8 +56,10 @@ impl SortSink {
         let mut out = Self {
             schema,
             chunks: Default::default(),
-            mem_track: MemTracker::new(n_morsels_per_sink),
+            mem_track: MemTracker::new(n_morsels_per_sink, streaming_options),
             ooc,
+            spill: streaming_options.spill || ooc,
+            buffer_size: sort_buffer_size(streaming_options, n_morsels_per_sink),
             io_thread: Default::default(),
             sort_idx,
             sort_args,
@@ -78,7 +90,7 @@ impl SortSink {
 
     fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
         let chunk_bytes = chunk.data.estimated_size();
-        if !self.ooc {
+        if !self.ooc && self.spill {
             let used = self.mem_track.fetch_add(chunk_bytes);
             let free = self.mem_track.get_available();
 
@@ -99,8 +111,8 @@ impl SortSink {
     }
 
     fn dump(&mut self, force: bool) -> PolarsResult<()> {
-        let larger_than_32_mb = self.current_chunks_size > 1 << 25;
-        if (force || larger_than_32_mb || self.current_chunk_rows > 50_000)
+        let larger_than_buffer = self.current_chunks_size > self.buffer_size;
+        if (force || larger_than_buffer || self.current_chunk_rows > 50_000)
             && !self.chunks.is_empty()
         {
             // into a single chunk because multiple file IO's is expensive
@@ -157,6 +169,8 @@ impl Sink for SortSink {
             chunks: Default::default(),
             mem_track: self.mem_track.clone(),
             ooc: self.ooc,
+            spill: self.spill,
+            buffer_size: self.buffer_size,
             io_thread: self.io_thread.clone(),
             sort_idx: self.sort_idx,
             sort_args: self.sort_args.clone(),
@@ -5,6 +5,7 @@ use polars_core::prelude::sort::_broadcast_descending;
 use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_compat_array;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+use polars_plan::frame::StreamingOptions;
 use polars_plan::prelude::*;
 use polars_row::decode::decode_rows_from_binary;
 use polars_row::SortField;
@@ -143,6 +144,7 @@ impl SortSinkMultiple {
         sort_args: SortArguments,
         output_schema: SchemaRef,
         sort_idx: Vec<usize>,
+        streaming_options: &StreamingOptions,
     ) -> PolarsResult<Self> {
         let can_decode = sort_column_can_be_decoded(&output_schema, &sort_idx);
         let mut schema = (*output_schema).clone();
@@ -179,6 +181,7 @@ impl SortSinkMultiple {
                 maintain_order: false,
             },
             Arc::new(schema),
+            streaming_options,
         ));
 
         Ok(SortSinkMultiple {
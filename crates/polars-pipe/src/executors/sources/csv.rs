@@ -5,11 +5,12 @@ use polars_core::export::arrow::Either;
 use polars_core::POOL;
 use polars_io::csv::read_impl::{BatchedCsvReaderMmap, BatchedCsvReaderRead};
 use polars_io::csv::{CsvEncoding, CsvReader};
+use polars_plan::frame::StreamingOptions;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::{CsvParserOptions, FileScanOptions};
 
 use super::*;
-use crate::pipeline::determine_chunk_size;
+use crate::pipeline::{determine_chunk_size, estimated_row_width};
 
 pub(crate) struct CsvSource {
     #[allow(dead_code)]
@@ -24,6 +25,7 @@ pub(crate) struct CsvSource {
     options: Option<CsvParserOptions>,
     file_options: Option<FileScanOptions>,
     verbose: bool,
+    streaming_options: StreamingOptions,
 }
 
 impl CsvSource {
@@ -45,15 +47,20 @@ impl CsvSource {
             with_columns = None;
         }
 
-        let n_cols = if projected_len > 0 {
-            projected_len
-        } else {
-            self.schema.len()
+        let row_width = match &with_columns {
+            Some(columns) => {
+                estimated_row_width(columns.iter().filter_map(|name| self.schema.get(name)))
+            },
+            None => estimated_row_width(self.schema.iter_dtypes()),
         };
         let n_rows = _set_n_rows_for_scan(file_options.n_rows);
         // inversely scale the chunk size by the number of threads so that we reduce memory pressure
         // in streaming
-        let chunk_size = determine_chunk_size(n_cols, POOL.current_num_threads())?;
+        let chunk_size = determine_chunk_size(
+            row_width,
+            POOL.current_num_threads(),
+            &self.streaming_options,
+        )?;
 
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -80,6 +87,7 @@ impl CsvSource {
             .with_chunk_size(chunk_size)
             .with_row_index(file_options.row_index)
             .with_try_parse_dates(options.try_parse_dates)
+            .with_decimal_comma(options.decimal_comma)
             .truncate_ragged_lines(options.truncate_ragged_lines)
             .raise_if_empty(options.raise_if_empty);
 
@@ -106,6 +114,7 @@ impl CsvSource {
         options: CsvParserOptions,
         file_options: FileScanOptions,
         verbose: bool,
+        streaming_options: StreamingOptions,
     ) -> PolarsResult<Self> {
         Ok(CsvSource {
             schema,
@@ -117,6 +126,7 @@ impl CsvSource {
             options: Some(options),
             file_options: Some(file_options),
             verbose,
+            streaming_options,
         })
     }
 }
@@ -0,0 +1,73 @@
+use polars_core::prelude::*;
+use polars_io::flight::FlightReader;
+use polars_io::RowIndex;
+use polars_plan::global::_set_n_rows_for_scan;
+use polars_plan::prelude::{FileScanOptions, FlightScanOptions};
+
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Streams the record batches of an Arrow Flight stream as they arrive.
+pub(crate) struct FlightSource {
+    reader: FlightReader,
+    row_index: Option<RowIndex>,
+    n_rows: usize,
+    chunk_index: IdxSize,
+}
+
+impl FlightSource {
+    pub(crate) fn new(options: FlightScanOptions, file_options: FileScanOptions) -> Self {
+        let row_index = file_options.row_index;
+        // The row index is added to the batches after reading.
+        let with_columns = file_options.with_columns.map(|columns| {
+            columns
+                .iter()
+                .filter(|name| Some(name.as_str()) != row_index.as_ref().map(|rc| rc.name.as_str()))
+                .cloned()
+                .collect()
+        });
+        let reader = FlightReader::new(options.endpoint, options.ticket).with_columns(with_columns);
+        Self {
+            reader,
+            row_index,
+            n_rows: _set_n_rows_for_scan(file_options.n_rows).unwrap_or(usize::MAX),
+            chunk_index: 0,
+        }
+    }
+}
+
+impl Source for FlightSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        if self.n_rows == 0 {
+            return Ok(SourceResult::Finished);
+        }
+        let mut df = loop {
+            match self.reader.next_batch()? {
+                Some(df) if df.height() == 0 => continue,
+                Some(df) => break df,
+                None => return Ok(SourceResult::Finished),
+            }
+        };
+        if df.height() > self.n_rows {
+            df = df.head(Some(self.n_rows));
+        }
+        self.n_rows -= df.height();
+        if let Some(rc) = &mut self.row_index {
+            df.with_row_index_mut(&rc.name, Some(rc.offset));
+            rc.offset += df.height() as IdxSize;
+        }
+
+        let chunk = DataChunk {
+            chunk_index: self.chunk_index,
+            data: df,
+        };
+        self.chunk_index += 1;
+        Ok(SourceResult::GotMoreData(vec![chunk]))
+    }
+
+    fn fmt(&self) -> &str {
+        "flight"
+    }
+}
+
+// The reader is only accessed through `&mut self`.
+unsafe impl Sync for FlightSource {}
@@ -1,5 +1,7 @@
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "flight")]
+mod flight;
 mod frame;
 mod ipc_one_shot;
 #[cfg(feature = "parquet")]
@@ -9,6 +11,8 @@ mod union;
 
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvSource;
+#[cfg(feature = "flight")]
+pub(crate) use flight::FlightSource;
 pub(crate) use frame::*;
 pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
@@ -17,12 +17,13 @@ use polars_io::prelude::materialize_projection;
 use polars_io::prelude::ParquetAsyncReader;
 use polars_io::utils::check_projected_arrow_schema;
 use polars_io::{is_cloud_url, SerReader};
+use polars_plan::frame::StreamingOptions;
 use polars_plan::logical_plan::FileInfo;
 use polars_plan::prelude::{FileScanOptions, ParquetOptions};
 use polars_utils::IdxSize;
 
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
-use crate::pipeline::determine_chunk_size;
+use crate::pipeline::{determine_chunk_size, estimated_row_width, observed_row_width};
 
 pub struct ParquetSource {
     batched_readers: VecDeque<BatchedParquetReader>,
@@ -41,6 +42,9 @@ pub struct ParquetSource {
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    streaming_options: StreamingOptions,
+    // width of a row in bytes as seen in the batches we already read
+    row_width: Option<usize>,
 }
 
 impl ParquetSource {
@@ -93,8 +97,16 @@ impl ParquetSource {
             false,
         );
 
-        let n_cols = projection.as_ref().map(|v| v.len()).unwrap_or(schema.len());
-        let chunk_size = determine_chunk_size(n_cols, self.n_threads)?;
+        // once we have seen data, we size the chunks by the width of those rows
+        let row_width = self.row_width.unwrap_or_else(|| match &projection {
+            Some(projection) => estimated_row_width(
+                projection
+                    .iter()
+                    .filter_map(|i| schema.get_at_index(*i).map(|(_, dtype)| dtype)),
+            ),
+            None => estimated_row_width(schema.iter_dtypes()),
+        });
+        let chunk_size = determine_chunk_size(row_width, self.n_threads, &self.streaming_options)?;
 
         if self.verbose {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
@@ -194,6 +206,7 @@ impl ParquetSource {
         file_info: FileInfo,
         verbose: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        streaming_options: StreamingOptions,
     ) -> PolarsResult<Self> {
         let n_threads = POOL.current_num_threads();
 
@@ -222,6 +235,8 @@ impl ParquetSource {
             run_async,
             prefetch_size,
             predicate,
+            streaming_options,
+            row_width: None,
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
@@ -289,6 +304,9 @@ impl Source for ParquetSource {
                 return self.get_batches(_context);
             },
             Some(batches) => {
+                if let Some(width) = batches.first().and_then(observed_row_width) {
+                    self.row_width = Some(width);
+                }
                 let result = SourceResult::GotMoreData(
                     batches
                         .into_iter()
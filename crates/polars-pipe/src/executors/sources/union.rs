@@ -1,10 +1,15 @@
 use polars_core::error::PolarsResult;
+use polars_utils::IdxSize;
 
 use crate::operators::{PExecutionContext, Source, SourceResult};
 
 pub struct UnionSource {
     sources: Vec<Box<dyn Source>>,
     source_index: usize,
+    // every source starts counting its chunks at 0, we offset the chunk indexes of
+    // a source by the chunks of the sources before it, so that the order is maintained.
+    chunk_offset: IdxSize,
+    next_chunk_offset: IdxSize,
 }
 
 impl UnionSource {
@@ -12,6 +17,8 @@ impl UnionSource {
         Self {
             sources,
             source_index: 0,
+            chunk_offset: 0,
+            next_chunk_offset: 0,
         }
     }
 }
@@ -23,8 +30,18 @@ impl Source for UnionSource {
         while self.source_index < self.sources.len() {
             let src = &mut self.sources[self.source_index];
             match src.get_batches(context)? {
-                SourceResult::Finished => self.source_index += 1,
-                SourceResult::GotMoreData(chunks) => return Ok(SourceResult::GotMoreData(chunks)),
+                SourceResult::Finished => {
+                    self.source_index += 1;
+                    self.chunk_offset = self.next_chunk_offset;
+                },
+                SourceResult::GotMoreData(mut chunks) => {
+                    for chunk in chunks.iter_mut() {
+                        chunk.chunk_index += self.chunk_offset;
+                        self.next_chunk_offset =
+                            std::cmp::max(self.next_chunk_offset, chunk.chunk_index + 1);
+                    }
+                    return Ok(SourceResult::GotMoreData(chunks));
+                },
             }
         }
         Ok(SourceResult::Finished)
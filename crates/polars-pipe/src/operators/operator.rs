@@ -7,6 +7,9 @@ pub enum OperatorResult {
     HaveMoreOutPut(DataChunk),
     /// this operator is finished
     Finished(DataChunk),
+    /// this operator is finished and will not produce any output anymore, e.g. because a
+    /// slice is satisfied. The remaining chunks are not processed and the pipeline stops early.
+    Stop(DataChunk),
 }
 
 pub trait Operator: Send + Sync {
@@ -19,4 +22,11 @@ pub trait Operator: Send + Sync {
     fn split(&self, thread_no: usize) -> Box<dyn Operator>;
 
     fn fmt(&self) -> &str;
+
+    /// Whether the operator must see all chunks in order, e.g. because its output depends on the
+    /// number of rows that came before. Such an operator runs on a single thread, after the
+    /// operators before it processed the chunks of a batch in parallel.
+    fn must_run_sequentially(&self) -> bool {
+        false
+    }
 }
@@ -1 +1,92 @@
+use polars_core::prelude::*;
+use polars_plan::frame::StreamingOptions;
 
+// Rough number of bytes a single value takes in a chunk or hash table. Used to translate a
+// memory budget into a number of rows.
+const BYTES_PER_VALUE_ESTIMATE: usize = 8;
+// Rough number of bytes of a string/binary value, including its offset.
+const BYTES_PER_STRING_ESTIMATE: usize = 24;
+// Rough number of elements in a list value.
+const LIST_LEN_ESTIMATE: usize = 4;
+// The chunks in flight may take up `1 / CHUNK_BUDGET_FRACTION` of the memory budget.
+const CHUNK_BUDGET_FRACTION: usize = 8;
+// The thread local hash tables may take up `1 / HASH_TABLE_BUDGET_FRACTION` of the memory budget.
+const HASH_TABLE_BUDGET_FRACTION: usize = 4;
+// Default size of the buffers an out-of-core sort accumulates before writing to disk.
+const SORT_BUFFER_SIZE: usize = 1 << 25;
+
+fn estimated_value_width(dtype: &DataType) -> usize {
+    match dtype.to_physical() {
+        DataType::Boolean | DataType::UInt8 | DataType::Int8 | DataType::Null => 1,
+        DataType::UInt16 | DataType::Int16 => 2,
+        DataType::UInt32 | DataType::Int32 | DataType::Float32 => 4,
+        DataType::String | DataType::Binary => BYTES_PER_STRING_ESTIMATE,
+        DataType::List(inner) => {
+            BYTES_PER_VALUE_ESTIMATE + LIST_LEN_ESTIMATE * estimated_value_width(&inner)
+        },
+        _ => BYTES_PER_VALUE_ESTIMATE,
+    }
+}
+
+/// Estimate the number of bytes a single row with the given dtypes takes in memory.
+pub(crate) fn estimated_row_width<'a, I>(dtypes: I) -> usize
+where
+    I: IntoIterator<Item = &'a DataType>,
+{
+    dtypes
+        .into_iter()
+        .map(estimated_value_width)
+        .sum::<usize>()
+        .max(1)
+}
+
+/// The number of bytes a single row of `df` takes in memory.
+pub(crate) fn observed_row_width(df: &DataFrame) -> Option<usize> {
+    let height = df.height();
+    (height > 0).then(|| std::cmp::max(df.estimated_size() / height, 1))
+}
+
+fn rows_in_budget(budget: usize, fraction: usize, n_threads: usize, row_width: usize) -> usize {
+    std::cmp::max(budget / fraction / n_threads.max(1) / row_width.max(1), 1)
+}
+
+/// Maximum number of rows in a chunk, such that the chunks of all threads fit in the memory
+/// budget. `None` if there is no explicit budget.
+pub(crate) fn max_chunk_size(
+    options: &StreamingOptions,
+    row_width: usize,
+    n_threads: usize,
+) -> Option<usize> {
+    options
+        .memory_budget()
+        .map(|budget| rows_in_budget(budget, CHUNK_BUDGET_FRACTION, n_threads, row_width))
+}
+
+/// Number of groups a thread local hash table may hold before it spills to the global table.
+pub(crate) fn group_by_spill_size(
+    options: &StreamingOptions,
+    default: usize,
+    n_cols: usize,
+    n_threads: usize,
+) -> usize {
+    match options.memory_budget() {
+        Some(budget) => std::cmp::min(
+            default,
+            rows_in_budget(
+                budget,
+                HASH_TABLE_BUDGET_FRACTION,
+                n_threads,
+                n_cols * BYTES_PER_VALUE_ESTIMATE,
+            ),
+        ),
+        None => default,
+    }
+}
+
+/// Number of bytes an out-of-core sort may buffer per thread before writing to disk.
+pub(crate) fn sort_buffer_size(options: &StreamingOptions, n_threads: usize) -> usize {
+    match options.memory_budget() {
+        Some(budget) => std::cmp::min(SORT_BUFFER_SIZE, budget / 4 / n_threads.max(1)).max(1),
+        None => SORT_BUFFER_SIZE,
+    }
+}
@@ -7,7 +7,8 @@ use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
-use polars_ops::prelude::JoinType;
+use polars_ops::prelude::{JoinBuildSide, JoinType};
+use polars_plan::frame::StreamingOptions;
 use polars_plan::prelude::*;
 
 use crate::executors::operators::HstackOperator;
@@ -42,6 +43,7 @@ fn get_source<F>(
     to_physical: &F,
     push_predicate: bool,
     verbose: bool,
+    streaming_options: &StreamingOptions,
 ) -> PolarsResult<Box<dyn Source>>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -105,6 +107,7 @@ where
                         csv_options,
                         file_options,
                         verbose,
+                        *streaming_options,
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
@@ -144,9 +147,15 @@ where
                         file_info,
                         verbose,
                         predicate,
+                        *streaming_options,
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                #[cfg(feature = "flight")]
+                FileScan::Flight { options } => {
+                    let src = sources::FlightSource::new(options, file_options);
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
                 _ => todo!(),
             }
         },
@@ -159,6 +168,7 @@ pub fn get_sink<F>(
     lp_arena: &Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     to_physical: &F,
+    streaming_options: &StreamingOptions,
 ) -> PolarsResult<Box<dyn SinkTrait>>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -178,10 +188,12 @@ where
                     let path = path.as_ref().as_path();
                     match &file_type {
                         #[cfg(feature = "parquet")]
-                        FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
+                        FileType::Parquet(options) => Box::new(ParquetSink::new(
+                            path,
+                            options.clone(),
+                            input_schema.as_ref(),
+                        )?)
+                            as Box<dyn SinkTrait>,
                         #[cfg(feature = "ipc")]
                         FileType::Ipc(options) => {
                             Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
@@ -201,6 +213,30 @@ where
                         _ => unreachable!(),
                     }
                 },
+                #[allow(unused_variables)]
+                SinkType::Partitioned {
+                    path,
+                    file_type,
+                    by,
+                } => {
+                    let path = path.as_ref().as_path();
+                    match &file_type {
+                        #[cfg(any(
+                            feature = "parquet",
+                            feature = "ipc",
+                            feature = "csv",
+                            feature = "json"
+                        ))]
+                        file_type => Box::new(PartitionedSink::new(
+                            path,
+                            file_type,
+                            by,
+                            input_schema.as_ref(),
+                        )?) as Box<dyn SinkTrait>,
+                        #[allow(unreachable_patterns)]
+                        _ => unreachable!(),
+                    }
+                },
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     uri,
@@ -215,7 +251,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri,
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             input_schema.as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -241,8 +277,6 @@ where
             right_on,
             ..
         } => {
-            // slice pushdown optimization should not set this one in a streaming query.
-            assert!(options.args.slice.is_none());
             let swapped = swap_join_order(options);
 
             match &options.args.how {
@@ -278,6 +312,9 @@ where
                         join_columns_left,
                         join_columns_right,
                         options.args.join_nulls,
+                        // the probe stops early, the slice itself is applied by the slice
+                        // sink that is inserted after the join
+                        options.args.slice,
                     )) as Box<dyn SinkTrait>
                 },
                 _ => unimplemented!(),
@@ -300,7 +337,7 @@ where
                     .unwrap();
                 let index = input_schema.try_index_of(by_column.as_ref())?;
 
-                let sort_sink = SortSink::new(index, args.clone(), input_schema);
+                let sort_sink = SortSink::new(index, args.clone(), input_schema, streaming_options);
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             } else {
                 let sort_idx = by_column
@@ -311,7 +348,8 @@ where
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let sort_sink = SortSinkMultiple::new(args.clone(), input_schema, sort_idx)?;
+                let sort_sink =
+                    SortSinkMultiple::new(args.clone(), input_schema, sort_idx, streaming_options)?;
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             }
         },
@@ -395,6 +433,7 @@ where
                 output_schema,
                 input_agg_dtypes,
                 options.slice,
+                streaming_options,
             ));
 
             Box::new(ReProjectSink::new(input_schema, group_by_sink))
@@ -436,6 +475,7 @@ where
                     output_schema.clone(),
                     input_agg_dtypes,
                     options.slice,
+                    streaming_options,
                 ))
             } else {
                 match (
@@ -469,6 +509,7 @@ where
                         output_schema.clone(),
                         input_agg_dtypes,
                         options.slice,
+                        streaming_options,
                     )),
                 }
             }
@@ -508,6 +549,7 @@ pub fn get_operator<F>(
     lp_arena: &Arena<ALogicalPlan>,
     expr_arena: &Arena<AExpr>,
     to_physical: &F,
+    streaming_options: &StreamingOptions,
 ) -> PolarsResult<Box<dyn Operator>>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -584,8 +626,40 @@ where
                 operators::FastProjectionOperator::new(columns.clone(), input_schema.into_owned());
             Box::new(op) as Box<dyn Operator>
         },
+        MapFunction {
+            function:
+                FunctionNode::Explode {
+                    columns,
+                    mismatch,
+                    schema,
+                },
+            ..
+        } => {
+            let op = operators::ExplodeOperator::new(
+                columns.clone(),
+                *mismatch,
+                schema,
+                streaming_options,
+            )?;
+            Box::new(op) as Box<dyn Operator>
+        },
+        #[cfg(feature = "dtype-struct")]
+        MapFunction {
+            function: FunctionNode::Unnest { columns },
+            ..
+        } => {
+            let op = operators::UnnestOperator::new(columns.clone());
+            Box::new(op) as Box<dyn Operator>
+        },
+        MapFunction {
+            function: FunctionNode::RowIndex { name, offset, .. },
+            ..
+        } => {
+            let op = operators::RowIndexOperator::new(name.clone(), *offset);
+            Box::new(op) as Box<dyn Operator>
+        },
         MapFunction { function, .. } => {
-            let op = operators::FunctionOperator::new(function.clone());
+            let op = operators::FunctionOperator::new(function.clone(), streaming_options);
             Box::new(op) as Box<dyn Operator>
         },
         Union { .. } => {
@@ -611,6 +685,7 @@ pub fn create_pipeline<F>(
     to_physical: F,
     verbose: bool,
     sink_cache: &mut PlHashMap<usize, Box<dyn SinkTrait>>,
+    streaming_options: &StreamingOptions,
 ) -> PolarsResult<PipeLine>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -629,6 +704,7 @@ where
                 &to_physical,
                 true,
                 verbose,
+                streaming_options,
             )?,
             lp @ Scan { .. } => get_source(
                 lp.clone(),
@@ -637,6 +713,7 @@ where
                 &to_physical,
                 true,
                 verbose,
+                streaming_options,
             )?,
             Union { inputs, .. } => {
                 let sources = inputs
@@ -652,6 +729,7 @@ where
                             &to_physical,
                             i == 0,
                             verbose && i == 0,
+                            streaming_options,
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -674,11 +752,12 @@ where
             // ensure that shared sinks are really shared
             // to achieve this we store/fetch them in a cache
             let sink = if *shared_count.borrow() == 1 {
-                get_sink(node, lp_arena, expr_arena, &to_physical)?
+                get_sink(node, lp_arena, expr_arena, &to_physical, streaming_options)?
             } else {
                 match sink_cache.entry(node.0) {
                     Entry::Vacant(entry) => {
-                        let sink = get_sink(node, lp_arena, expr_arena, &to_physical)?;
+                        let sink =
+                            get_sink(node, lp_arena, expr_arena, &to_physical, streaming_options)?;
                         entry.insert(sink.split(0));
                         sink
                     },
@@ -701,9 +780,15 @@ where
 }
 
 pub fn swap_join_order(options: &JoinOptions) -> bool {
-    matches!(options.args.how, JoinType::Left)
-        || match (options.rows_left, options.rows_right) {
-            ((Some(left), _), (Some(right), _)) => left > right,
-            ((_, left), (_, right)) => left > right,
-        }
+    // a left join always streams the left table
+    if matches!(options.args.how, JoinType::Left) {
+        return true;
+    }
+    if let Some(build_side) = options.args.hints.build_side() {
+        return build_side == JoinBuildSide::Right;
+    }
+    match (options.rows_left, options.rows_right) {
+        ((Some(left), _), (Some(right), _)) => left > right,
+        ((_, left), (_, right)) => left > right,
+    }
 }
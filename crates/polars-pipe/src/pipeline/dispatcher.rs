@@ -192,7 +192,7 @@ impl PipeLine {
             pipe: &PipeLine,
             operator_start: usize,
             operator_end: usize,
-            chunk: DataChunk,
+            chunks: Vec<DataChunk>,
             sink: &mut Box<dyn Sink>,
             operator_pipe: &mut [Box<dyn Operator>],
             ec: &PExecutionContext,
@@ -200,12 +200,37 @@ impl PipeLine {
             // truncate the operators that should run into the current sink.
             let operator_pipe = &mut operator_pipe[operator_start..operator_end];
 
-            if operator_pipe.is_empty() {
-                sink.sink(ec, chunk)
-            } else {
-                pipe.push_operators(chunk, ec, operator_pipe, sink)
+            for chunk in chunks {
+                let result = if operator_pipe.is_empty() {
+                    sink.sink(ec, chunk)?
+                } else {
+                    pipe.push_operators(chunk, ec, operator_pipe, |chunk| sink.sink(ec, chunk))?
+                };
+                if let SinkResult::Finished = result {
+                    return Ok(SinkResult::Finished);
+                }
             }
+            Ok(SinkResult::CanHaveMoreInput)
+        }
+
+        // Operators that must see the chunks in order split the pipeline: the operators before
+        // such an operator process the chunks in parallel, after which it runs on a single thread.
+        let mut chunks = chunks;
+        let mut operator_start = operator_start;
+        let mut stopped = false;
+        while let Some(operator_i) =
+            (operator_start..operator_end).find(|&i| self.operators[0][i].must_run_sequentially())
+        {
+            let (mut out, stopped_before) =
+                self.par_collect_chunks(chunks, ec, operator_start, operator_i)?;
+            // The sort is stable, so chunks with the same index keep the order of their output.
+            out.sort_by_key(|chunk| chunk.chunk_index);
+            let (out, stopped_at) = self.run_sequential_operator(out, ec, operator_i)?;
+            chunks = out;
+            stopped |= stopped_before || stopped_at;
+            operator_start = operator_i + 1;
         }
+
         let sink_results = Arc::new(Mutex::new(None));
         let mut next_batches: Option<PolarsResult<SourceResult>> = None;
         let next_batches_ptr = &mut next_batches as *mut Option<PolarsResult<SourceResult>>;
@@ -227,7 +252,7 @@ impl PipeLine {
         // borrow as ref and move into the closure
         let pipeline = &*self;
         POOL.scope(|s| {
-            for ((chunk, sink), operator_pipe) in chunks
+            for ((chunks, sink), operator_pipe) in split_chunks(chunks, sink.len())
                 .into_iter()
                 .zip(sink.iter_mut())
                 .zip(operators.iter_mut())
@@ -238,7 +263,7 @@ impl PipeLine {
                         pipeline,
                         operator_start,
                         operator_end,
-                        chunk,
+                        chunks,
                         sink,
                         operator_pipe,
                         ec,
@@ -255,6 +280,8 @@ impl PipeLine {
             // already get batches on the thread pool
             // if one job is finished earlier we can already start that work
             s.spawn(|_| {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!("source", name = src.fmt()).entered();
                 let out = src.get_batches(ec);
                 unsafe {
                     let ptr = next_batches_ptr.get();
@@ -266,23 +293,111 @@ impl PipeLine {
 
         let next_batches = next_batches.unwrap()?;
         let mut lock = sink_results.lock().unwrap();
-        lock.take()
-            .transpose()
-            .map(|sink_result| (sink_result, next_batches))
+        let sink_result = lock.take().transpose()?;
+        let sink_result = match sink_result {
+            None if stopped => Some(SinkResult::Finished),
+            sink_result => sink_result,
+        };
+        Ok((sink_result, next_batches))
+    }
+
+    /// Push the chunks through the operators `operator_start..operator_end` in parallel and
+    /// collect their output in the order of the input chunks. Also returns whether an operator
+    /// stopped the pipeline.
+    fn par_collect_chunks(
+        &mut self,
+        chunks: Vec<DataChunk>,
+        ec: &PExecutionContext,
+        operator_start: usize,
+        operator_end: usize,
+    ) -> PolarsResult<(Vec<DataChunk>, bool)> {
+        // temporarily take to please the borrow checker
+        let mut operators = std::mem::take(&mut self.operators);
+        let pipeline = &*self;
+        let groups = split_chunks(chunks, operators.len());
+        let out = POOL.install(|| {
+            groups
+                .into_par_iter()
+                .zip(operators.par_iter_mut())
+                .map(|(chunks, operator_pipe)| {
+                    let operator_pipe = &mut operator_pipe[operator_start..operator_end];
+                    if operator_pipe.is_empty() {
+                        return Ok((chunks, false));
+                    }
+                    let mut out = Vec::with_capacity(chunks.len());
+                    for chunk in chunks {
+                        let result =
+                            pipeline.push_operators(chunk, ec, operator_pipe, |chunk| {
+                                out.push(chunk);
+                                Ok(SinkResult::CanHaveMoreInput)
+                            })?;
+                        if let SinkResult::Finished = result {
+                            return Ok((out, true));
+                        }
+                    }
+                    Ok((out, false))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        });
+        self.operators = operators;
+
+        let mut chunks = vec![];
+        let mut stopped = false;
+        for (out, stopped_group) in out? {
+            chunks.extend(out);
+            stopped |= stopped_group;
+        }
+        Ok((chunks, stopped))
+    }
+
+    /// Push the chunks in order through an operator that must run sequentially. Also returns
+    /// whether the operator stopped the pipeline.
+    fn run_sequential_operator(
+        &mut self,
+        chunks: Vec<DataChunk>,
+        ec: &PExecutionContext,
+        operator_i: usize,
+    ) -> PolarsResult<(Vec<DataChunk>, bool)> {
+        // The operator of the first thread sees all chunks, so its state is global.
+        let operator = &mut self.operators[0][operator_i];
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("sequential_operator", name = operator.fmt()).entered();
+        let mut out = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            loop {
+                match operator.execute(ec, &chunk)? {
+                    OperatorResult::Finished(output_chunk) => {
+                        out.push(output_chunk);
+                        break;
+                    },
+                    OperatorResult::HaveMoreOutPut(output_chunk) => out.push(output_chunk),
+                    OperatorResult::NeedsNewData => break,
+                    OperatorResult::Stop(output_chunk) => {
+                        out.push(output_chunk);
+                        return Ok((out, true));
+                    },
+                }
+            }
+        }
+        Ok((out, false))
     }
 
     /// This thread local logic that pushed a data chunk into the operators + sink
     /// It can be that a single operator needs to be called multiple times, this is for instance the
     /// case with joins that produce many tuples, that's why we keep a stack of `in_process`
     /// operators.
-    fn push_operators(
+    fn push_operators<F>(
         &self,
         chunk: DataChunk,
         ec: &PExecutionContext,
         operators: &mut [Box<dyn Operator>],
-        sink: &mut Box<dyn Sink>,
-    ) -> PolarsResult<SinkResult> {
+        mut sink: F,
+    ) -> PolarsResult<SinkResult>
+    where
+        F: FnMut(DataChunk) -> PolarsResult<SinkResult>,
+    {
         debug_assert!(!operators.is_empty());
+        let mut stopped = false;
         let mut in_process = vec![];
         let operator_offset = 0usize;
         in_process.push((operator_offset, chunk));
@@ -290,11 +405,13 @@ impl PipeLine {
         while let Some((op_i, chunk)) = in_process.pop() {
             match operators.get_mut(op_i) {
                 None => {
-                    if let SinkResult::Finished = sink.sink(ec, chunk)? {
+                    if let SinkResult::Finished = sink(chunk)? {
                         return Ok(SinkResult::Finished);
                     }
                 },
                 Some(op) => {
+                    #[cfg(feature = "tracing")]
+                    let _span = tracing::trace_span!("operator", name = op.fmt()).entered();
                     match op.execute(ec, &chunk)? {
                         OperatorResult::Finished(chunk) => in_process.push((op_i + 1, chunk)),
                         OperatorResult::HaveMoreOutPut(output_chunk) => {
@@ -311,11 +428,22 @@ impl PipeLine {
                         OperatorResult::NeedsNewData => {
                             // done, take another chunk from the stack
                         },
+                        OperatorResult::Stop(output_chunk) => {
+                            // the pending chunks of this and the previous operators would
+                            // only flow into this operator, so they can be dropped.
+                            in_process.retain(|(i, _)| *i > op_i);
+                            in_process.push((op_i + 1, output_chunk));
+                            stopped = true;
+                        },
                     }
                 },
             }
         }
-        Ok(SinkResult::CanHaveMoreInput)
+        if stopped {
+            Ok(SinkResult::Finished)
+        } else {
+            Ok(SinkResult::CanHaveMoreInput)
+        }
     }
 
     /// Replace the current sources with a [`DataFrameSource`].
@@ -402,6 +530,8 @@ impl PipeLine {
             }
 
             if i != last_i {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!("finalize", sink = reduced_sink.fmt()).entered();
                 let sink_result = reduced_sink.finalize(ec)?;
                 match sink_result {
                     // turn this sink an a new source
@@ -424,6 +554,7 @@ impl PipeLine {
     /// thread and finalize in a sink.
     ///
     /// The sink can be finished, but can also become a new source and then rinse and repeat.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip_all))]
     pub fn run_pipeline(
         &mut self,
         ec: &PExecutionContext,
@@ -432,11 +563,17 @@ impl PipeLine {
         let (sink_shared_count, mut reduced_sink) =
             self.run_pipeline_no_finalize(ec, pipeline_q)?;
         assert_eq!(sink_shared_count, 0);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("finalize", sink = reduced_sink.fmt()).entered();
         Ok(reduced_sink.finalize(ec).ok())
     }
 
     /// Executes all branches and replaces operators and sinks during execution to ensure
     /// we materialize.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "streaming_execute", level = "info", skip_all)
+    )]
     pub fn execute(&mut self, state: Box<dyn SExecutionContext>) -> PolarsResult<DataFrame> {
         let ec = PExecutionContext::new(state, self.verbose);
 
@@ -508,6 +645,21 @@ impl Debug for PipeLine {
     }
 }
 
+/// Split the chunks into at most `n` contiguous groups of about equal size.
+fn split_chunks(chunks: Vec<DataChunk>, n: usize) -> Vec<Vec<DataChunk>> {
+    let n_groups = std::cmp::min(chunks.len(), n);
+    if n_groups == 0 {
+        return vec![];
+    }
+    let group_size = (chunks.len() + n_groups - 1) / n_groups;
+    let mut groups = Vec::with_capacity(n_groups);
+    let mut chunks = chunks.into_iter().peekable();
+    while chunks.peek().is_some() {
+        groups.push(chunks.by_ref().take(group_size).collect());
+    }
+    groups
+}
+
 /// Take a source and materialize it into a [`DataFrame`].
 fn consume_source(src: &mut dyn Source, context: &PExecutionContext) -> PolarsResult<DataFrame> {
     let mut frames = Vec::with_capacity(32);
@@ -2,10 +2,12 @@ mod config;
 mod convert;
 mod dispatcher;
 
+pub(crate) use config::*;
 pub use convert::{create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order};
 pub use dispatcher::PipeLine;
 use polars_core::prelude::*;
 use polars_core::POOL;
+use polars_plan::frame::StreamingOptions;
 
 pub use crate::executors::sinks::group_by::aggregates::can_convert_to_hash_agg;
 
@@ -20,16 +22,52 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 // env vars
 pub(crate) static FORCE_OOC: &str = "POLARS_FORCE_OOC";
 
+// Number of bytes we strive to have in a chunk (per thread factor).
+// This is 50_000 values of 8 bytes.
+const IDEAL_CHUNK_BYTES: usize = 400_000;
+const MIN_CHUNK_SIZE: usize = 1000;
+const MAX_CHUNK_SIZE: usize = 1 << 20;
+
 /// ideal chunk size we strive to have
-/// scale the chunk size depending on the number of
-/// columns. With 10 columns we use a chunk size of 40_000
-pub(crate) fn determine_chunk_size(n_cols: usize, n_threads: usize) -> PolarsResult<usize> {
+/// scale the chunk size inversely with the estimated width of a row in bytes, so that
+/// every chunk takes roughly the same amount of memory. Wide rows (many columns, long strings)
+/// lead to smaller chunks, narrow rows to larger chunks.
+/// With 10 columns of 8 bytes we use a chunk size of 5_000 rows times the thread factor.
+/// If a memory budget is set, the chunk size is capped so that the chunks fit in that budget.
+pub(crate) fn determine_chunk_size(
+    row_width: usize,
+    n_threads: usize,
+    options: &StreamingOptions,
+) -> PolarsResult<usize> {
     if let Ok(val) = std::env::var("POLARS_STREAMING_CHUNK_SIZE") {
         val.parse().map_err(
             |_| polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_CHUNK_SIZE' env var"),
         )
     } else {
         let thread_factor = std::cmp::max(12 / n_threads, 1);
-        Ok(std::cmp::max(50_000 / n_cols.max(1) * thread_factor, 1000))
+        let chunk_size = (IDEAL_CHUNK_BYTES / row_width.max(1) * thread_factor)
+            .clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE);
+        match max_chunk_size(options, row_width, n_threads) {
+            Some(max) => Ok(std::cmp::min(chunk_size, max)),
+            None => Ok(chunk_size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chunk_size_scales_with_row_width() {
+        let options = StreamingOptions::default();
+        let narrow = estimated_row_width(&[DataType::Boolean]);
+        let wide = estimated_row_width(&vec![DataType::String; 100]);
+
+        let narrow = determine_chunk_size(narrow, 4, &options).unwrap();
+        let wide = determine_chunk_size(wide, 4, &options).unwrap();
+        assert!(narrow > wide);
+        assert!(wide >= MIN_CHUNK_SIZE);
+        assert!(narrow <= MAX_CHUNK_SIZE);
     }
 }
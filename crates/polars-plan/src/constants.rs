@@ -1,2 +1,3 @@
 pub static MAP_LIST_NAME: &str = "map_list";
 pub static CSE_REPLACED: &str = "__POLARS_CSER_";
+pub static HAVING_NAME: &str = "__POLARS_HAVING";
@@ -398,6 +398,7 @@ impl LogicalPlan {
                     fmt: match payload {
                         SinkType::Memory => "SINK (MEMORY)",
                         SinkType::File { .. } => "SINK (FILE)",
+                        SinkType::Partitioned { .. } => "SINK (PARTITIONED)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
                     },
@@ -28,6 +28,12 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Sum))
     }
 
+    /// Compute the mean of the items in every subarray.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
+    }
+
     /// Keep only the unique values in every sub-array.
     pub fn unique(self) -> Expr {
         self.0
@@ -1,3 +1,6 @@
+#[cfg(feature = "binary_compression")]
+use polars_ops::prelude::BinaryCompression;
+
 use super::function_expr::BinaryFunction;
 use super::*;
 /// Specialized expressions for [`Series`] of [`DataType::String`].
@@ -34,6 +37,12 @@ impl BinaryNameSpace {
         )
     }
 
+    /// Get the size of the binary values in bytes.
+    pub fn size_bytes(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Size))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn hex_decode(self, strict: bool) -> Expr {
         self.0
@@ -59,4 +68,31 @@ impl BinaryNameSpace {
         self.0
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
+
+    /// Compress every value with `method`, at the default compression level if `level` is
+    /// `None`.
+    #[cfg(feature = "binary_compression")]
+    pub fn compress(self, method: BinaryCompression, level: Option<i32>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Compress(
+                method, level,
+            )))
+    }
+
+    /// Decompress every value that was compressed with `method`. If `strict` is false, values
+    /// that cannot be decompressed are null.
+    #[cfg(feature = "binary_compression")]
+    pub fn decompress(self, method: BinaryCompression, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Decompress(
+                method, strict,
+            )))
+    }
+
+    /// Format UUIDs of 16 bytes in the lowercase hyphenated form.
+    #[cfg(feature = "uuid")]
+    pub fn format_uuid(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::FormatUuid))
+    }
 }
@@ -2,6 +2,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 
 use polars_core::prelude::*;
+use polars_core::utils::TemporalOp;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -122,6 +123,8 @@ pub enum Expr {
         /// Also has the input. i.e. avg("foo")
         function: Box<Expr>,
         partition_by: Vec<Expr>,
+        /// Order of the rows within a partition
+        order_by: Option<(Box<Expr>, SortOptions)>,
         options: WindowType,
     },
     Wildcard,
@@ -348,4 +351,21 @@ impl Operator {
     pub(crate) fn is_arithmetic(&self) -> bool {
         !(self.is_comparison())
     }
+
+    /// The operation this operator performs between temporal dtypes, if any.
+    pub(crate) fn temporal_op(&self) -> Option<TemporalOp> {
+        match self {
+            Self::Plus => Some(TemporalOp::Add),
+            Self::Minus => Some(TemporalOp::Sub),
+            Self::Eq
+            | Self::NotEq
+            | Self::Lt
+            | Self::LtEq
+            | Self::Gt
+            | Self::GtEq
+            | Self::EqValidity
+            | Self::NotEqValidity => Some(TemporalOp::Compare),
+            _ => None,
+        }
+    }
 }
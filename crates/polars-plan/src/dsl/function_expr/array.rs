@@ -9,6 +9,7 @@ pub enum ArrayFunction {
     Min,
     Max,
     Sum,
+    Mean,
     ToList,
     Unique(bool),
     #[cfg(feature = "array_any_all")]
@@ -31,6 +32,11 @@ impl ArrayFunction {
         match self {
             Min | Max => mapper.map_to_list_and_array_inner_dtype(),
             Sum => mapper.nested_sum_type(),
+            Mean => mapper.try_map_dtype(|dt| match dt {
+                DataType::Array(inner, _) if **inner == DataType::Float32 => Ok(DataType::Float32),
+                DataType::Array(_, _) => Ok(DataType::Float64),
+                _ => polars_bail!(ComputeError: "expected array dtype"),
+            }),
             ToList => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             Unique(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
             #[cfg(feature = "array_any_all")]
@@ -61,6 +67,7 @@ impl Display for ArrayFunction {
             Min => "min",
             Max => "max",
             Sum => "sum",
+            Mean => "mean",
             ToList => "to_list",
             Unique(_) => "unique",
             #[cfg(feature = "array_any_all")]
@@ -87,6 +94,7 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Min => map!(min),
             Max => map!(max),
             Sum => map!(sum),
+            Mean => map!(mean),
             ToList => map!(to_list),
             Unique(stable) => map!(unique, stable),
             #[cfg(feature = "array_any_all")]
@@ -117,6 +125,10 @@ pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.array()?.array_sum()
 }
 
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
 pub(super) fn unique(s: &Series, stable: bool) -> PolarsResult<Series> {
     let ca = s.array()?;
     let out = if stable {
@@ -1,10 +1,10 @@
+#[cfg(feature = "binary_compression")]
+use polars_ops::prelude::BinaryCompression;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::*;
-#[cfg(feature = "binary_encoding")]
-use crate::map;
-use crate::map_as_slice;
+use crate::{map, map_as_slice};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -12,6 +12,7 @@ pub enum BinaryFunction {
     Contains,
     StartsWith,
     EndsWith,
+    Size,
     #[cfg(feature = "binary_encoding")]
     HexDecode(bool),
     #[cfg(feature = "binary_encoding")]
@@ -20,6 +21,12 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_compression")]
+    Compress(BinaryCompression, Option<i32>),
+    #[cfg(feature = "binary_compression")]
+    Decompress(BinaryCompression, bool),
+    #[cfg(feature = "uuid")]
+    FormatUuid,
 }
 
 impl BinaryFunction {
@@ -28,10 +35,15 @@ impl BinaryFunction {
         match self {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
+            Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "binary_compression")]
+            Compress(..) | Decompress(..) => mapper.with_same_dtype(),
+            #[cfg(feature = "uuid")]
+            FormatUuid => mapper.with_dtype(DataType::String),
         }
     }
 }
@@ -43,6 +55,7 @@ impl Display for BinaryFunction {
             Contains { .. } => "contains",
             StartsWith => "starts_with",
             EndsWith => "ends_with",
+            Size => "size",
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) => "hex_decode",
             #[cfg(feature = "binary_encoding")]
@@ -51,6 +64,12 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_compression")]
+            Compress(..) => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress(..) => "decompress",
+            #[cfg(feature = "uuid")]
+            FormatUuid => "format_uuid",
         };
         write!(f, "bin.{s}")
     }
@@ -69,6 +88,7 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             StartsWith => {
                 map_as_slice!(starts_with)
             },
+            Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             HexDecode(strict) => map!(hex_decode, strict),
             #[cfg(feature = "binary_encoding")]
@@ -77,6 +97,12 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_compression")]
+            Compress(method, level) => map!(compress, method, level),
+            #[cfg(feature = "binary_compression")]
+            Decompress(method, strict) => map!(decompress, method, strict),
+            #[cfg(feature = "uuid")]
+            FormatUuid => map!(format_uuid),
         }
     }
 }
@@ -87,6 +113,11 @@ pub(super) fn contains(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.contains_chunked(lit).with_name(ca.name()).into_series())
 }
 
+pub(super) fn size_bytes(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.size_bytes().into_series())
+}
+
 pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = s[0].binary()?;
     let suffix = s[1].binary()?;
@@ -136,3 +167,29 @@ impl From<BinaryFunction> for FunctionExpr {
         FunctionExpr::BinaryExpr(b)
     }
 }
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn compress(
+    s: &Series,
+    method: BinaryCompression,
+    level: Option<i32>,
+) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.compress(method, level).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn decompress(
+    s: &Series,
+    method: BinaryCompression,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.decompress(method, strict).map(|ok| ok.into_series())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn format_uuid(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    uuid_to_str(ca).map(|ok| ok.into_series())
+}
@@ -4,11 +4,35 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
     Ok(s.reverse())
 }
 
+pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
+    polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
+    Ok(s.gather_every(n, offset))
+}
+
 #[cfg(feature = "approx_unique")]
 pub(super) fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
     polars_ops::prelude::approx_n_unique(s)
 }
 
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &[Series]) -> PolarsResult<Series> {
+    let (values, quantile) = (&s[0], &s[1]);
+    polars_ensure!(
+        quantile.len() == 1,
+        ComputeError: "quantile must be a single value, got {} values", quantile.len()
+    );
+    let quantile = quantile.cast(&DataType::Float64)?;
+    let out = match quantile.f64()?.get(0) {
+        Some(quantile) => polars_ops::prelude::approx_quantile(values, quantile)?,
+        None => None,
+    };
+    let out = Series::new(values.name(), &[out]);
+    match values.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
     polars_ops::prelude::diff(s, n, null_behavior)
@@ -19,6 +43,42 @@ pub(super) fn pct_change(s: &[Series]) -> PolarsResult<Series> {
     polars_ops::prelude::pct_change(&s[0], &s[1])
 }
 
+#[cfg(feature = "outliers")]
+pub(super) fn winsorize(s: &Series, lower_q: f64, upper_q: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::winsorize(s, lower_q, upper_q)
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn is_outlier_iqr(s: &Series, k: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::is_outlier_iqr(s, k)
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn is_outlier_zscore(s: &Series, threshold: f64) -> PolarsResult<Series> {
+    polars_ops::prelude::is_outlier_zscore(s, threshold)
+}
+
+#[cfg(feature = "geo")]
+pub(super) fn haversine_distance(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::haversine_distance(&s[0], &s[1], &s[2], &s[3])
+}
+
+#[cfg(feature = "geo")]
+pub(super) fn point_in_polygon(s: &Series, polygon: &[[f64; 2]]) -> PolarsResult<Series> {
+    polars_ops::prelude::point_in_polygon(s, polygon)
+}
+
+#[cfg(feature = "geo")]
+pub(super) fn within_bbox(
+    s: &Series,
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::within_bbox(s, min_x, min_y, max_x, max_y)
+}
+
 #[cfg(feature = "interpolate")]
 pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
     Ok(polars_ops::prelude::interpolate(s, method))
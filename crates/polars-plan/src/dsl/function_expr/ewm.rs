@@ -11,3 +11,65 @@ pub(super) fn ewm_std(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
 pub(super) fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
     polars_ops::prelude::ewm_var(s, options)
 }
+
+/// Returns the physical time values of `by` and `half_life` expressed in the same unit.
+#[cfg(feature = "ewma_by")]
+fn prepare_by(by: &Series, half_life: Duration) -> PolarsResult<(Int64Chunked, i64)> {
+    polars_ensure!(
+        half_life.months() == 0
+            && !half_life.parsed_int
+            && !half_life.negative()
+            && half_life.duration_ns() > 0,
+        InvalidOperation: "half_life should be a strictly positive duration without months or \
+        years, e.g. '3d' or '12h', got {}", half_life
+    );
+    let (by, tu) = match by.dtype() {
+        DataType::Datetime(tu, _) => (by.clone(), *tu),
+        DataType::Date => (
+            by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            TimeUnit::Milliseconds,
+        ),
+        dt => polars_bail!(op = "`ewm_*_by`", got = dt, expected = "date/datetime"),
+    };
+    let half_life = match tu {
+        TimeUnit::Nanoseconds => half_life.duration_ns(),
+        TimeUnit::Microseconds => half_life.duration_us(),
+        TimeUnit::Milliseconds => half_life.duration_ms(),
+    };
+    polars_ensure!(
+        half_life > 0,
+        InvalidOperation: "half_life is smaller than the time unit of the `by` column"
+    );
+    let by = by.to_physical_repr();
+    Ok((by.i64()?.clone(), half_life))
+}
+
+#[cfg(feature = "ewma_by")]
+pub(super) fn ewm_mean_by(
+    s: &[Series],
+    half_life: Duration,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    let (by, half_life) = prepare_by(&s[1], half_life)?;
+    polars_ops::prelude::ewm_mean_by(&s[0], &by, half_life, options)
+}
+
+#[cfg(feature = "ewma_by")]
+pub(super) fn ewm_std_by(
+    s: &[Series],
+    half_life: Duration,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    let (by, half_life) = prepare_by(&s[1], half_life)?;
+    polars_ops::prelude::ewm_std_by(&s[0], &by, half_life, options)
+}
+
+#[cfg(feature = "ewma_by")]
+pub(super) fn ewm_var_by(
+    s: &[Series],
+    half_life: Duration,
+    options: EWMOptions,
+) -> PolarsResult<Series> {
+    let (by, half_life) = prepare_by(&s[1], half_life)?;
+    polars_ops::prelude::ewm_var_by(&s[0], &by, half_life, options)
+}
@@ -0,0 +1,11 @@
+use polars_ops::prelude::index_of;
+
+use super::*;
+
+pub(super) fn index_of_impl(s: &mut [Series]) -> PolarsResult<Series> {
+    let series = &s[0];
+    let value = &s[1];
+
+    let idx = index_of(series, value)?.map(|idx| idx as IdxSize);
+    Ok(IdxCa::from_slice_options(series.name(), &[idx]).into_series())
+}
@@ -26,6 +26,8 @@ mod ewm;
 mod fill_null;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "index_of")]
+mod index_of;
 mod list;
 #[cfg(feature = "log")]
 mod log;
@@ -61,6 +63,8 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
 mod unique;
+#[cfg(feature = "weighted_stats")]
+mod weighted;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -97,7 +101,7 @@ pub(super) use self::rolling::RollingFunction;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
-pub(super) use self::struct_::StructFunction;
+pub(crate) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -136,7 +140,12 @@ pub enum FunctionExpr {
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "search_sorted")]
-    SearchSorted(SearchSortedSide),
+    SearchSorted {
+        side: SearchSortedSide,
+        descending: bool,
+    },
+    #[cfg(feature = "index_of")]
+    IndexOf,
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "date_offset")]
@@ -180,6 +189,10 @@ pub enum FunctionExpr {
     AsStruct,
     #[cfg(feature = "top_k")]
     TopK(bool),
+    #[cfg(feature = "top_k")]
+    TopKBy {
+        descending: Vec<bool>,
+    },
     #[cfg(feature = "cum_agg")]
     CumCount {
         reverse: bool,
@@ -201,6 +214,10 @@ pub enum FunctionExpr {
         reverse: bool,
     },
     Reverse,
+    GatherEvery {
+        n: usize,
+        offset: usize,
+    },
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
         sort: bool,
@@ -210,12 +227,38 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile,
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
+    #[cfg(feature = "outliers")]
+    Winsorize {
+        lower_q: f64,
+        upper_q: f64,
+    },
+    #[cfg(feature = "outliers")]
+    IsOutlierIqr {
+        k: f64,
+    },
+    #[cfg(feature = "outliers")]
+    IsOutlierZScore {
+        threshold: f64,
+    },
+    #[cfg(feature = "geo")]
+    HaversineDistance,
+    #[cfg(feature = "geo")]
+    PointInPolygon(Vec<[f64; 2]>),
+    #[cfg(feature = "geo")]
+    WithinBbox {
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+    },
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "log")]
@@ -254,6 +297,14 @@ pub enum FunctionExpr {
         method: correlation::CorrelationMethod,
         ddof: u8,
     },
+    #[cfg(feature = "weighted_stats")]
+    WeightedMean,
+    #[cfg(feature = "weighted_stats")]
+    WeightedVar {
+        ddof: u8,
+    },
+    #[cfg(feature = "weighted_stats")]
+    WeightedQuantile,
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -316,6 +367,21 @@ pub enum FunctionExpr {
     EwmVar {
         options: EWMOptions,
     },
+    #[cfg(feature = "ewma_by")]
+    EwmMeanBy {
+        half_life: Duration,
+        options: EWMOptions,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmStdBy {
+        half_life: Duration,
+        options: EWMOptions,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmVarBy {
+        half_life: Duration,
+        options: EWMOptions,
+    },
     #[cfg(feature = "replace")]
     Replace {
         return_dtype: Option<DataType>,
@@ -345,11 +411,18 @@ impl Hash for FunctionExpr {
             Boolean(f) => f.hash(state),
             Pow(f) => f.hash(state),
             #[cfg(feature = "search_sorted")]
-            SearchSorted(f) => f.hash(state),
+            SearchSorted { side, descending } => {
+                side.hash(state);
+                descending.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "weighted_stats")]
+            WeightedVar { ddof } => ddof.hash(state),
+            #[cfg(feature = "weighted_stats")]
+            WeightedMean | WeightedQuantile => {},
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -372,6 +445,10 @@ impl Hash for FunctionExpr {
             },
             SumHorizontal | MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse
             | ArgUnique | Shift | ShiftAndFill => {},
+            GatherEvery { n, offset } => {
+                n.hash(state);
+                offset.hash(state);
+            },
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -381,6 +458,8 @@ impl Hash for FunctionExpr {
             DateOffset => {},
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
+            #[cfg(feature = "index_of")]
+            IndexOf => {},
             #[cfg(feature = "trigonometry")]
             Atan2 => {},
             #[cfg(feature = "dtype-struct")]
@@ -413,6 +492,8 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "top_k")]
             TopK(a) => a.hash(state),
+            #[cfg(feature = "top_k")]
+            TopKBy { descending } => descending.hash(state),
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -432,10 +513,41 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => {},
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
+            #[cfg(feature = "outliers")]
+            Winsorize { lower_q, upper_q } => {
+                lower_q.to_bits().hash(state);
+                upper_q.to_bits().hash(state);
+            },
+            #[cfg(feature = "outliers")]
+            IsOutlierIqr { k } => k.to_bits().hash(state),
+            #[cfg(feature = "outliers")]
+            IsOutlierZScore { threshold } => threshold.to_bits().hash(state),
+            #[cfg(feature = "geo")]
+            HaversineDistance => {},
+            #[cfg(feature = "geo")]
+            PointInPolygon(polygon) => {
+                for [x, y] in polygon {
+                    x.to_bits().hash(state);
+                    y.to_bits().hash(state);
+                }
+            },
+            #[cfg(feature = "geo")]
+            WithinBbox {
+                min_x,
+                min_y,
+                max_x,
+                max_y,
+            } => {
+                for v in [min_x, min_y, max_x, max_y] {
+                    v.to_bits().hash(state);
+                }
+            },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => {
                 base.to_bits().hash(state);
@@ -509,6 +621,13 @@ impl Hash for FunctionExpr {
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
             EwmVar { options } => options.hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmMeanBy { half_life, options }
+            | EwmStdBy { half_life, options }
+            | EwmVarBy { half_life, options } => {
+                half_life.hash(state);
+                options.hash(state)
+            },
             #[cfg(feature = "hist")]
             Hist {
                 bin_count,
@@ -554,7 +673,9 @@ impl Display for FunctionExpr {
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "search_sorted")]
-            SearchSorted(_) => "search_sorted",
+            SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "index_of")]
+            IndexOf => "index_of",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "date_offset")]
@@ -597,6 +718,8 @@ impl Display for FunctionExpr {
                     "top_k"
                 }
             },
+            #[cfg(feature = "top_k")]
+            TopKBy { .. } => "top_k_by",
             Shift => "shift",
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
@@ -613,14 +736,29 @@ impl Display for FunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
+            GatherEvery { .. } => "gather_every",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => "approx_quantile",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
             #[cfg(feature = "pct_change")]
             PctChange => "pct_change",
+            #[cfg(feature = "outliers")]
+            Winsorize { .. } => "winsorize",
+            #[cfg(feature = "outliers")]
+            IsOutlierIqr { .. } => "is_outlier_iqr",
+            #[cfg(feature = "outliers")]
+            IsOutlierZScore { .. } => "is_outlier_zscore",
+            #[cfg(feature = "geo")]
+            HaversineDistance => "haversine_distance",
+            #[cfg(feature = "geo")]
+            PointInPolygon(_) => "point_in_polygon",
+            #[cfg(feature = "geo")]
+            WithinBbox { .. } => "within_bbox",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "log")]
@@ -653,6 +791,12 @@ impl Display for FunctionExpr {
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "weighted_stats")]
+            WeightedMean => "wmean",
+            #[cfg(feature = "weighted_stats")]
+            WeightedVar { .. } => "wvar",
+            #[cfg(feature = "weighted_stats")]
+            WeightedQuantile => "wquantile",
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
@@ -685,6 +829,12 @@ impl Display for FunctionExpr {
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
             EwmVar { .. } => "ewm_var",
+            #[cfg(feature = "ewma_by")]
+            EwmMeanBy { .. } => "ewm_mean_by",
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } => "ewm_std_by",
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { .. } => "ewm_var_by",
             #[cfg(feature = "hist")]
             Hist { .. } => "hist",
             #[cfg(feature = "replace")]
@@ -811,8 +961,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 wrap!(arg_where::arg_where)
             },
             #[cfg(feature = "search_sorted")]
-            SearchSorted(side) => {
-                map_as_slice!(search_sorted::search_sorted_impl, side)
+            SearchSorted { side, descending } => {
+                map_as_slice!(search_sorted::search_sorted_impl, side, descending)
+            },
+            #[cfg(feature = "index_of")]
+            IndexOf => {
+                map_as_slice!(index_of::index_of_impl)
             },
             #[cfg(feature = "range")]
             Range(func) => func.into(),
@@ -901,6 +1055,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             TopK(descending) => {
                 map_as_slice!(top_k, descending)
             },
+            #[cfg(feature = "top_k")]
+            TopKBy { descending } => {
+                map_as_slice!(top_k_by, descending.clone())
+            },
             Shift => map_as_slice!(shift_and_fill::shift),
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => map!(cum::cum_count, reverse),
@@ -917,14 +1075,34 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => map!(dispatch::unique_counts),
             Reverse => map!(dispatch::reverse),
+            GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => map_as_slice!(dispatch::approx_quantile),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "pct_change")]
             PctChange => map_as_slice!(dispatch::pct_change),
+            #[cfg(feature = "outliers")]
+            Winsorize { lower_q, upper_q } => map!(dispatch::winsorize, lower_q, upper_q),
+            #[cfg(feature = "outliers")]
+            IsOutlierIqr { k } => map!(dispatch::is_outlier_iqr, k),
+            #[cfg(feature = "outliers")]
+            IsOutlierZScore { threshold } => map!(dispatch::is_outlier_zscore, threshold),
+            #[cfg(feature = "geo")]
+            HaversineDistance => map_as_slice!(dispatch::haversine_distance),
+            #[cfg(feature = "geo")]
+            PointInPolygon(polygon) => map!(dispatch::point_in_polygon, &polygon),
+            #[cfg(feature = "geo")]
+            WithinBbox {
+                min_x,
+                min_y,
+                max_x,
+                max_y,
+            } => map!(dispatch::within_bbox, min_x, min_y, max_x, max_y),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => {
                 map!(dispatch::interpolate, method)
@@ -953,6 +1131,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
             #[cfg(feature = "cov")]
             Correlation { method, ddof } => map_as_slice!(correlation::corr, ddof, method),
+            #[cfg(feature = "weighted_stats")]
+            WeightedMean => map_as_slice!(weighted::wmean),
+            #[cfg(feature = "weighted_stats")]
+            WeightedVar { ddof } => map_as_slice!(weighted::wvar, ddof),
+            #[cfg(feature = "weighted_stats")]
+            WeightedQuantile => map_as_slice!(weighted::wquantile),
             #[cfg(feature = "peaks")]
             PeakMin => map!(peaks::peak_min),
             #[cfg(feature = "peaks")]
@@ -1009,6 +1193,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    #[cfg(feature = "uuid")]
+                    Uuid4 => map!(random::uuid4, seed),
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
@@ -1036,6 +1222,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
             EwmVar { options } => map!(ewm::ewm_var, options),
+            #[cfg(feature = "ewma_by")]
+            EwmMeanBy { half_life, options } => {
+                map_as_slice!(ewm::ewm_mean_by, half_life, options)
+            },
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life, options } => map_as_slice!(ewm::ewm_std_by, half_life, options),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life, options } => map_as_slice!(ewm::ewm_var_by, half_life, options),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => {
                 map_as_slice!(dispatch::replace, return_dtype.clone())
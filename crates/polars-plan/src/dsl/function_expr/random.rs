@@ -15,6 +15,8 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "uuid")]
+    Uuid4,
 }
 
 impl Hash for RandomMethod {
@@ -27,6 +29,11 @@ pub(super) fn shuffle(s: &Series, seed: Option<u64>) -> PolarsResult<Series> {
     Ok(s.shuffle(seed))
 }
 
+#[cfg(feature = "uuid")]
+pub(super) fn uuid4(s: &Series, seed: Option<u64>) -> PolarsResult<Series> {
+    Ok(polars_ops::chunked_array::uuid4(s.name(), s.len(), seed).into_series())
+}
+
 pub(super) fn sample_frac(
     s: &[Series],
     with_replacement: bool,
@@ -128,7 +128,7 @@ pub(super) fn datetime_ranges(
         (DataType::Datetime(_, _), None) => start.dtype().clone(),
         // overwrite time unit, keep timezone
         (DataType::Datetime(_, tz), Some(tu)) => DataType::Datetime(tu, tz.clone()),
-        _ => unreachable!(),
+        (dt, _) => polars_bail!(InvalidOperation: "expected a temporal datatype, got {}", dt),
     };
 
     // overwrite time zone, if specified
@@ -215,15 +215,17 @@ pub(super) fn datetime_ranges(
 impl<'a> FieldsMapper<'a> {
     pub(super) fn map_to_datetime_range_dtype(
         &self,
+        interval: &Duration,
         time_unit: Option<&TimeUnit>,
         time_zone: Option<&str>,
     ) -> PolarsResult<DataType> {
         let data_dtype = self.map_to_supertype()?.dtype;
 
-        let (data_tu, data_tz) = if let DataType::Datetime(tu, tz) = data_dtype {
-            (tu, tz)
-        } else {
-            (TimeUnit::Microseconds, None)
+        let (data_tu, data_tz) = match data_dtype {
+            DataType::Datetime(tu, tz) => (tu, tz),
+            // Dates are upcast to a precision that can represent the interval.
+            DataType::Date if interval.nanoseconds() % 1_000 != 0 => (TimeUnit::Nanoseconds, None),
+            _ => (TimeUnit::Microseconds, None),
         };
 
         let tu = match time_unit {
@@ -107,26 +107,32 @@ impl RangeFunction {
             },
             #[cfg(feature = "temporal")]
             DatetimeRange {
-                interval: _,
+                interval,
                 closed: _,
                 time_unit,
                 time_zone,
             } => {
                 // output dtype may change based on `interval`, `time_unit`, and `time_zone`
-                let dtype =
-                    mapper.map_to_datetime_range_dtype(time_unit.as_ref(), time_zone.as_deref())?;
+                let dtype = mapper.map_to_datetime_range_dtype(
+                    interval,
+                    time_unit.as_ref(),
+                    time_zone.as_deref(),
+                )?;
                 mapper.with_dtype(dtype)
             },
             #[cfg(feature = "temporal")]
             DatetimeRanges {
-                interval: _,
+                interval,
                 closed: _,
                 time_unit,
                 time_zone,
             } => {
                 // output dtype may change based on `interval`, `time_unit`, and `time_zone`
-                let inner_dtype =
-                    mapper.map_to_datetime_range_dtype(time_unit.as_ref(), time_zone.as_deref())?;
+                let inner_dtype = mapper.map_to_datetime_range_dtype(
+                    interval,
+                    time_unit.as_ref(),
+                    time_zone.as_deref(),
+                )?;
                 mapper.with_dtype(DataType::List(Box::new(inner_dtype)))
             },
             #[cfg(feature = "dtype-time")]
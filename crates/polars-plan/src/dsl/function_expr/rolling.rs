@@ -73,6 +73,12 @@ fn convert<'a>(
         let mut by = ss[1].clone();
         by = by.rechunk();
 
+        polars_ensure!(
+            ss[0].len() == by.len(),
+            ComputeError: "`by` column in {} must be the same length as the values column ({} != {})",
+            expr_name, by.len(), ss[0].len()
+        );
+
         polars_ensure!(
             options.weights.is_none(),
             ComputeError: "`weights` is not supported in 'rolling by' expression"
@@ -83,7 +89,11 @@ fn convert<'a>(
                 by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
                 &None,
             ),
-            dt => polars_bail!(opq = expr_name, got = dt, expected = "date/datetime"),
+            dt => polars_bail!(
+                op = format!("`{}`", expr_name),
+                got = dt,
+                expected = "date/datetime"
+            ),
         };
         if by.is_sorted_flag() != IsSorted::Ascending && options.warn_if_unsorted {
             polars_warn!(format!(
@@ -40,7 +40,9 @@ impl FunctionExpr {
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
-            SearchSorted(_) => mapper.with_dtype(IDX_DTYPE),
+            SearchSorted { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "index_of")]
+            IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "range")]
             Range(func) => func.get_field(mapper),
             #[cfg(feature = "date_offset")]
@@ -89,6 +91,8 @@ impl FunctionExpr {
             )),
             #[cfg(feature = "top_k")]
             TopK(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "top_k")]
+            TopKBy { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
@@ -98,7 +102,7 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "unique_counts")]
             UniqueCounts => mapper.with_dtype(IDX_DTYPE),
-            Shift | Reverse => mapper.with_same_dtype(),
+            Shift | Reverse | GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "cum_agg")]
@@ -111,6 +115,8 @@ impl FunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => mapper.map_to_float_dtype(),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -152,6 +158,14 @@ impl FunctionExpr {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
                 _ => DataType::Float64,
             }),
+            #[cfg(feature = "outliers")]
+            Winsorize { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "outliers")]
+            IsOutlierIqr { .. } | IsOutlierZScore { .. } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "geo")]
+            HaversineDistance => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "geo")]
+            PointInPolygon(_) | WithinBbox { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
                 InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
@@ -191,6 +205,8 @@ impl FunctionExpr {
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "weighted_stats")]
+            WeightedMean | WeightedVar { .. } | WeightedQuantile => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
             PeakMin => mapper.with_same_dtype(),
             #[cfg(feature = "peaks")]
@@ -250,13 +266,18 @@ impl FunctionExpr {
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
-                    Field::new("lengths", DataType::UInt64),
+                    Field::new("lengths", IDX_DTYPE),
                     Field::new("values", dt.clone()),
                 ])
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(DataType::UInt32),
             ToPhysical => mapper.to_physical_type(),
+            #[cfg(feature = "uuid")]
+            Random {
+                method: RandomMethod::Uuid4,
+                ..
+            } => mapper.with_dtype(DataType::String),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
@@ -275,6 +296,8 @@ impl FunctionExpr {
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmVar { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ewma_by")]
+            EwmMeanBy { .. } | EwmStdBy { .. } | EwmVarBy { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
         }
@@ -310,6 +333,11 @@ impl<'a> FieldsMapper<'a> {
         self.fields.len()
     }
 
+    /// The fields of all inputs.
+    pub fn args(&self) -> &[Field] {
+        self.fields
+    }
+
     /// Map a single field with a potentially failing mapper function.
     pub fn try_map_field(
         &self,
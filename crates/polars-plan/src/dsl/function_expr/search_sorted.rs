@@ -2,9 +2,13 @@ use polars_ops::prelude::search_sorted;
 
 use super::*;
 
-pub(super) fn search_sorted_impl(s: &mut [Series], side: SearchSortedSide) -> PolarsResult<Series> {
+pub(super) fn search_sorted_impl(
+    s: &mut [Series],
+    side: SearchSortedSide,
+    descending: bool,
+) -> PolarsResult<Series> {
     let sorted_array = &s[0];
     let search_value = &s[1];
 
-    search_sorted(sorted_array, search_value, side, false).map(|ca| ca.into_series())
+    search_sorted(sorted_array, search_value, side, descending).map(|ca| ca.into_series())
 }
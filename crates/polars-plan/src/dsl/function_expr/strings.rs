@@ -48,6 +48,12 @@ pub enum StringFunction {
         literal: bool,
         strict: bool,
     },
+    #[cfg(feature = "string_distance")]
+    Hamming,
+    #[cfg(feature = "string_distance")]
+    JaroWinkler,
+    #[cfg(feature = "string_distance")]
+    Levenshtein,
     #[cfg(feature = "string_to_integer")]
     ToInteger(u32, bool),
     LenBytes,
@@ -86,6 +92,10 @@ pub enum StringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "uuid")]
+    IsUuid,
+    #[cfg(feature = "uuid")]
+    ToUuid(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -133,7 +143,20 @@ impl StringFunction {
             Extract(_) => mapper.with_same_dtype(),
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "extract_groups")]
-            ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            ExtractGroups { dtype, .. } => match dtype {
+                // Without capture groups a struct with a single null field is returned.
+                DataType::Struct(fields) if fields.is_empty() => mapper.try_map_field(|fld| {
+                    Ok(Field::new(
+                        fld.name(),
+                        DataType::Struct(vec![Field::new(fld.name(), DataType::Null)]),
+                    ))
+                }),
+                _ => mapper.with_dtype(dtype.clone()),
+            },
+            #[cfg(feature = "string_distance")]
+            Hamming | Levenshtein => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => mapper.with_dtype(DataType::Int64),
             Find { .. } => mapper.with_dtype(DataType::UInt32),
@@ -160,6 +183,10 @@ impl StringFunction {
             Base64Encode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "uuid")]
+            IsUuid => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "uuid")]
+            ToUuid(_) => mapper.with_dtype(DataType::Binary),
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
             | StripSuffix | Slice => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
@@ -201,6 +228,12 @@ impl Display for StringFunction {
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "string_distance")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_distance")]
+            Levenshtein => "levenshtein",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
             Find { .. } => "find",
@@ -225,6 +258,10 @@ impl Display for StringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "uuid")]
+            IsUuid => "is_uuid",
+            #[cfg(feature = "uuid")]
+            ToUuid(_) => "to_uuid",
             Slice => "slice",
             StartsWith { .. } => "starts_with",
             StripChars => "strip_chars",
@@ -287,6 +324,12 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map!(strings::extract_groups, &pat, &dtype)
             },
             Find { literal, strict } => map_as_slice!(strings::find, literal, strict),
+            #[cfg(feature = "string_distance")]
+            Hamming => map_as_slice!(strings::hamming),
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_distance")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
             LenBytes => map!(strings::len_bytes),
             LenChars => map!(strings::len_chars),
             #[cfg(feature = "string_pad")]
@@ -343,6 +386,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Base64Encode => map!(strings::base64_encode),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(strict) => map!(strings::base64_decode, strict),
+            #[cfg(feature = "uuid")]
+            IsUuid => map!(strings::is_uuid),
+            #[cfg(feature = "uuid")]
+            ToUuid(strict) => map!(strings::to_uuid, strict),
             Explode => map!(strings::explode),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
@@ -445,6 +492,27 @@ pub(super) fn starts_with(s: &[Series]) -> PolarsResult<Series> {
     Ok(ca.starts_with_chunked(prefix).into_series())
 }
 
+#[cfg(feature = "string_distance")]
+pub(super) fn hamming(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::hamming(ca, other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::jaro_winkler(ca, other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn levenshtein(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ops::chunked_array::strings::levenshtein(ca, other).map(|ca| ca.into_series())
+}
+
 /// Extract a regex pattern from the a string value.
 pub(super) fn extract(s: &[Series], group_index: usize) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -559,6 +627,8 @@ pub(super) fn strptime(
             to_datetime(s, &time_unit, time_zone.as_ref(), options)
         },
         DataType::Time => to_time(&s[0], options),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(time_unit) => to_duration(&s[0], time_unit, options),
         dt => polars_bail!(ComputeError: "not implemented for dtype {}", dt),
     }
 }
@@ -683,6 +753,19 @@ fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-duration")]
+fn to_duration(s: &Series, time_unit: TimeUnit, options: &StrptimeOptions) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca
+        .as_duration(options.format.as_deref(), time_unit)?
+        .into_series();
+
+    if options.strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn concat(s: &Series, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Series> {
     let str_s = s.cast(&DataType::String)?;
@@ -911,6 +994,16 @@ pub(super) fn base64_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
     s.str()?.base64_decode(strict).map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "uuid")]
+pub(super) fn is_uuid(s: &Series) -> PolarsResult<Series> {
+    Ok(polars_ops::chunked_array::is_uuid(s.str()?).into_series())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn to_uuid(s: &Series, strict: bool) -> PolarsResult<Series> {
+    polars_ops::chunked_array::str_to_uuid(s.str()?, strict).map(|ca| ca.into_series())
+}
+
 pub(super) fn explode(s: &Series) -> PolarsResult<Series> {
     let ca = s.str()?;
     ca.explode()
@@ -1,7 +1,7 @@
 use polars_core::utils::slice_offsets;
 
 use super::*;
-use crate::map;
+use crate::{map, map_as_slice};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -11,6 +11,7 @@ pub enum StructFunction {
     RenameFields(Arc<Vec<String>>),
     #[cfg(feature = "json")]
     JsonEncode,
+    WithFields,
 }
 
 impl StructFunction {
@@ -62,6 +63,34 @@ impl StructFunction {
             }),
             #[cfg(feature = "json")]
             JsonEncode => mapper.with_dtype(DataType::String),
+            WithFields => {
+                let args = mapper.args();
+                let struct_ = &args[0];
+
+                if let DataType::Struct(fields) = struct_.data_type() {
+                    let mut name_2_dtype = PlIndexMap::with_capacity(fields.len() * 2);
+
+                    for field in fields {
+                        name_2_dtype.insert(field.name(), field.data_type());
+                    }
+                    for arg in &args[1..] {
+                        name_2_dtype.insert(arg.name(), arg.data_type());
+                    }
+                    let dtype = DataType::Struct(
+                        name_2_dtype
+                            .iter()
+                            .map(|(name, dtype)| Field::new(name.as_str(), (*dtype).clone()))
+                            .collect(),
+                    );
+                    let mut out = struct_.clone();
+                    out.coerce(dtype);
+                    Ok(out)
+                } else {
+                    polars_bail!(
+                        ComputeError: "expected struct dtype, got: `{}`", struct_.data_type()
+                    )
+                }
+            },
         }
     }
 }
@@ -75,6 +104,7 @@ impl Display for StructFunction {
             RenameFields(names) => write!(f, "struct.rename_fields({:?})", names),
             #[cfg(feature = "json")]
             JsonEncode => write!(f, "struct.to_json"),
+            WithFields => write!(f, "with_fields"),
         }
     }
 }
@@ -88,6 +118,7 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             RenameFields(names) => map!(struct_::rename_fields, names.clone()),
             #[cfg(feature = "json")]
             JsonEncode => map!(struct_::to_json),
+            WithFields => map_as_slice!(struct_::with_fields),
         }
     }
 }
@@ -120,6 +151,25 @@ pub(super) fn rename_fields(s: &Series, names: Arc<Vec<String>>) -> PolarsResult
     StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
 }
 
+pub(super) fn with_fields(args: &[Series]) -> PolarsResult<Series> {
+    let s = &args[0];
+    let ca = s.struct_()?;
+    let current = ca.fields();
+
+    let mut fields = PlIndexMap::with_capacity(current.len() + args.len() - 1);
+
+    for field in current {
+        fields.insert(field.name(), field);
+    }
+    // New fields replace existing fields with the same name in place.
+    for field in &args[1..] {
+        fields.insert(field.name(), field);
+    }
+
+    let new_fields = fields.into_values().cloned().collect::<Vec<_>>();
+    StructChunked::new(ca.name(), &new_fields).map(|ca| ca.into_series())
+}
+
 #[cfg(feature = "json")]
 pub(super) fn to_json(s: &Series) -> PolarsResult<Series> {
     let ca = s.struct_()?;
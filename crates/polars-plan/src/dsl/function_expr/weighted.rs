@@ -0,0 +1,35 @@
+use polars_ops::prelude::{weighted_mean, weighted_quantile, weighted_var};
+
+use super::*;
+
+/// Wrap a weighted aggregate in a unit length `Series` with the float type of the values.
+fn finish(values: &Series, out: Option<f64>) -> PolarsResult<Series> {
+    let out = Series::new(values.name(), &[out]);
+    match values.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
+pub(super) fn wmean(s: &[Series]) -> PolarsResult<Series> {
+    let (values, weights) = (&s[0], &s[1]);
+    finish(values, weighted_mean(values, weights)?)
+}
+
+pub(super) fn wvar(s: &[Series], ddof: u8) -> PolarsResult<Series> {
+    let (values, weights) = (&s[0], &s[1]);
+    finish(values, weighted_var(values, weights, ddof)?)
+}
+
+pub(super) fn wquantile(s: &[Series]) -> PolarsResult<Series> {
+    let (values, weights, quantile) = (&s[0], &s[1], &s[2]);
+    polars_ensure!(
+        quantile.len() == 1,
+        ComputeError: "quantile must be a single value, got {} values", quantile.len()
+    );
+    let quantile = quantile.cast(&DataType::Float64)?;
+    let Some(quantile) = quantile.f64()?.get(0) else {
+        return finish(values, None);
+    };
+    finish(values, weighted_quantile(values, weights, quantile)?)
+}
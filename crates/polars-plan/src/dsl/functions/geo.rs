@@ -0,0 +1,12 @@
+use super::*;
+
+/// Compute the great-circle distance in kilometers between two points given by their latitude
+/// and longitude in degrees.
+pub fn haversine_distance(lat1: Expr, lon1: Expr, lat2: Expr, lon2: Expr) -> Expr {
+    lat1.map_many_private(
+        FunctionExpr::HaversineDistance,
+        &[lon1, lat2, lon2],
+        false,
+        false,
+    )
+}
@@ -7,6 +7,8 @@ mod coerce;
 mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
+#[cfg(feature = "geo")]
+mod geo;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
@@ -23,6 +25,8 @@ pub use coerce::*;
 pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
@@ -147,7 +147,7 @@ impl ListNameSpace {
     /// - `null_on_oob`: Return a null when an index is out of bounds.
     /// This behavior is more expensive than defaulting to returning an `Error`.
     #[cfg(feature = "list_gather")]
-    pub fn take(self, index: Expr, null_on_oob: bool) -> Expr {
+    pub fn gather(self, index: Expr, null_on_oob: bool) -> Expr {
         self.0.map_many_private(
             FunctionExpr::ListExpr(ListFunction::Gather(null_on_oob)),
             &[index],
@@ -156,6 +156,12 @@ impl ListNameSpace {
         )
     }
 
+    #[cfg(feature = "list_gather")]
+    #[deprecated(since = "0.36.2", note = "use `gather` instead")]
+    pub fn take(self, index: Expr, null_on_oob: bool) -> Expr {
+        self.gather(index, null_on_oob)
+    }
+
     /// Get first item of every sublist.
     pub fn first(self) -> Expr {
         self.get(lit(0i64))
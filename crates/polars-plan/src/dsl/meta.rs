@@ -4,6 +4,7 @@ use std::ops::BitAnd;
 use super::*;
 use crate::dsl::selector::Selector;
 use crate::logical_plan::projection::is_regex_projection;
+use crate::logical_plan::sql_predicate::predicate_to_sql;
 use crate::logical_plan::tree_format::TreeFmtVisitor;
 use crate::logical_plan::visitor::{AexprNode, TreeWalker};
 
@@ -132,6 +133,13 @@ impl MetaNameSpace {
         }
     }
 
+    /// Translate a predicate into a SQL condition that selects at least the rows the predicate
+    /// selects, so that a database can prune rows before they are sent. `None` if no part of
+    /// the predicate can be translated.
+    pub fn _to_sql_predicate(&self) -> Option<String> {
+        predicate_to_sql(&self.0)
+    }
+
     /// Get a hold to an implementor of the `Display` trait that will format as
     /// the expression as a tree
     pub fn into_tree_formatter(self) -> PolarsResult<impl Display> {
@@ -224,6 +224,33 @@ impl Expr {
         .into()
     }
 
+    /// Compute the weighted mean per group. Weights are treated as frequency weights and
+    /// values or weights that are null are ignored.
+    #[cfg(feature = "weighted_stats")]
+    pub fn wmean(self, weights: Expr) -> Self {
+        self.apply_many_private(FunctionExpr::WeightedMean, &[weights], true, false)
+    }
+
+    /// Compute the weighted variance per group, dividing by `sum(weights) - ddof`. Weights are
+    /// treated as frequency weights and values or weights that are null are ignored.
+    #[cfg(feature = "weighted_stats")]
+    pub fn wvar(self, weights: Expr, ddof: u8) -> Self {
+        self.apply_many_private(FunctionExpr::WeightedVar { ddof }, &[weights], true, false)
+    }
+
+    /// Compute the weighted quantile per group: the smallest value for which the weights up to
+    /// and including it make up at least `quantile` of the total weight. Values or weights that
+    /// are null are ignored.
+    #[cfg(feature = "weighted_stats")]
+    pub fn wquantile(self, weights: Expr, quantile: Expr) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedQuantile,
+            &[weights, quantile],
+            true,
+            false,
+        )
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Box::new(self)).into()
@@ -359,11 +386,18 @@ impl Expr {
 
     #[cfg(feature = "search_sorted")]
     /// Find indices where elements should be inserted to maintain order.
-    pub fn search_sorted<E: Into<Expr>>(self, element: E, side: SearchSortedSide) -> Expr {
+    ///
+    /// Set `descending` if `self` is sorted in descending order.
+    pub fn search_sorted<E: Into<Expr>>(
+        self,
+        element: E,
+        side: SearchSortedSide,
+        descending: bool,
+    ) -> Expr {
         let element = element.into();
         Expr::Function {
             input: vec![self, element],
-            function: FunctionExpr::SearchSorted(side),
+            function: FunctionExpr::SearchSorted { side, descending },
             options: FunctionOptions {
                 collect_groups: ApplyOptions::GroupWise,
                 returns_scalar: true,
@@ -374,6 +408,23 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "index_of")]
+    /// Find the index of the first occurrence of `element`, or null if it doesn't occur.
+    pub fn index_of<E: Into<Expr>>(self, element: E) -> Expr {
+        let element = element.into();
+        Expr::Function {
+            input: vec![self, element],
+            function: FunctionExpr::IndexOf,
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                fmt_str: "index_of",
+                cast_to_supertypes: true,
+                ..Default::default()
+            },
+        }
+    }
+
     /// Cast expression to another data type.
     /// Throws an error if conversion had overflows.
     pub fn strict_cast(self, data_type: DataType) -> Self {
@@ -446,11 +497,37 @@ impl Expr {
         self.apply_many_private(FunctionExpr::TopK(true), &[k], false, false)
     }
 
+    /// Returns the elements corresponding to the `k` largest elements of the `by` columns.
+    ///
+    /// Columns with `descending` set consider their smallest elements the largest instead. This
+    /// has time complexity `O(n + k log(n))`.
+    #[cfg(feature = "top_k")]
+    pub fn top_k_by<E: AsRef<[Expr]>>(self, k: Expr, by: E, descending: Vec<bool>) -> Self {
+        let mut args = vec![k];
+        args.extend_from_slice(by.as_ref());
+        self.apply_many_private(FunctionExpr::TopKBy { descending }, &args, false, false)
+    }
+
+    /// Returns the elements corresponding to the `k` smallest elements of the `by` columns.
+    ///
+    /// Columns with `descending` set consider their largest elements the smallest instead. This
+    /// has time complexity `O(n + k log(n))`.
+    #[cfg(feature = "top_k")]
+    pub fn bottom_k_by<E: AsRef<[Expr]>>(self, k: Expr, by: E, descending: Vec<bool>) -> Self {
+        let descending = descending.into_iter().map(|d| !d).collect();
+        self.top_k_by(k, by, descending)
+    }
+
     /// Reverse column
     pub fn reverse(self) -> Self {
         self.apply_private(FunctionExpr::Reverse)
     }
 
+    /// Take every `n`th value, starting at index `offset`.
+    pub fn gather_every(self, n: usize, offset: usize) -> Self {
+        self.apply_private(FunctionExpr::GatherEvery { n, offset })
+    }
+
     /// Apply a function/closure once the logical plan get executed.
     ///
     /// This function is very similar to [`Expr::apply`], but differs in how it handles aggregations.
@@ -899,6 +976,31 @@ impl Expr {
         self,
         partition_by: E,
         options: WindowMapping,
+    ) -> Self {
+        self.over_with_order(partition_by, None, options)
+    }
+
+    /// Apply the window function over the partitions, with the rows of every partition in the
+    /// order of `order_by` instead of the order of the frame.
+    ///
+    /// The `options` are either a [`WindowMapping`] or a [`WindowFrame`]. With a frame, the
+    /// expression must be an aggregation and is computed for every row over the rows of its frame,
+    /// e.g. a moving average over the 3 preceding rows and the current row:
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_plan::prelude::*;
+    /// col("price").mean().over_with_order(
+    ///     [col("ticker")],
+    ///     Some((col("date"), SortOptions::default())),
+    ///     WindowFrame::new(Some(-3), Some(0)),
+    /// );
+    /// ```
+    pub fn over_with_order<E: AsRef<[IE]>, IE: Into<Expr> + Clone, O: Into<WindowType>>(
+        self,
+        partition_by: E,
+        order_by: Option<(Expr, SortOptions)>,
+        options: O,
     ) -> Self {
         let partition_by = partition_by
             .as_ref()
@@ -908,6 +1010,7 @@ impl Expr {
         Expr::Window {
             function: Box::new(self),
             partition_by,
+            order_by: order_by.map(|(e, options)| (Box::new(e), options)),
             options: options.into(),
         }
     }
@@ -920,6 +1023,7 @@ impl Expr {
         Expr::Window {
             function: Box::new(self),
             partition_by: vec![index_col],
+            order_by: None,
             options: WindowType::Rolling(options),
         }
     }
@@ -992,6 +1096,13 @@ impl Expr {
         self.apply_private(BooleanFunction::IsUnique.into())
     }
 
+    /// Get an approximation of the `quantile` of the values, computed with a t-digest. This is
+    /// cheaper than [`Expr::quantile`] on large groups and can be evaluated by the streaming engine.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: Expr) -> Self {
+        self.apply_many_private(FunctionExpr::ApproxQuantile, &[quantile], true, false)
+    }
+
     /// Get the approximate count of unique values.
     #[cfg(feature = "approx_unique")]
     pub fn approx_n_unique(self) -> Self {
@@ -1163,13 +1274,8 @@ impl Expr {
         rolling_function_by: fn(RollingOptions) -> RollingFunction,
     ) -> Expr {
         if let Some(ref by) = options.by {
-            let name = by.clone();
-            self.apply_many_private(
-                FunctionExpr::RollingExpr(rolling_function_by(options)),
-                &[col(&name)],
-                true,
-                false,
-            )
+            let by = col(by);
+            self.finish_rolling_by(by, options, rolling_function_by)
         } else {
             if !options.window_size.parsed_int {
                 panic!("if dynamic windows are used in a rolling aggregation, the 'by' argument must be set")
@@ -1178,6 +1284,21 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "rolling_window")]
+    fn finish_rolling_by(
+        self,
+        by: Expr,
+        options: RollingOptions,
+        rolling_function_by: fn(RollingOptions) -> RollingFunction,
+    ) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::RollingExpr(rolling_function_by(options)),
+            &[by],
+            false,
+            false,
+        )
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1252,6 +1373,81 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Std, RollingFunction::StdBy)
     }
 
+    /// Apply a rolling minimum based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_min_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::MinBy)
+    }
+
+    /// Apply a rolling maximum based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_max_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::MaxBy)
+    }
+
+    /// Apply a rolling mean based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mean_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::MeanBy)
+    }
+
+    /// Apply a rolling sum based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_sum_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::SumBy)
+    }
+
+    /// Apply a rolling median based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_median_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.rolling_quantile_by(by, QuantileInterpolOptions::Linear, 0.5, options)
+    }
+
+    /// Apply a rolling quantile based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_quantile_by(
+        self,
+        by: Expr,
+        interpol: QuantileInterpolOptions,
+        quantile: f64,
+        mut options: RollingOptions,
+    ) -> Expr {
+        options.fn_params = Some(Arc::new(RollingQuantileParams {
+            prob: quantile,
+            interpol,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        self.finish_rolling_by(by, options, RollingFunction::QuantileBy)
+    }
+
+    /// Apply a rolling variance based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_var_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::VarBy)
+    }
+
+    /// Apply a rolling std-dev based on another column.
+    ///
+    /// The window is defined by the temporal `by` column, `options.by` is ignored.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_std_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunction::StdBy)
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
@@ -1332,6 +1528,94 @@ impl Expr {
         self.apply_private(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Assign ranks without gaps to the values, ties get the same rank.
+    pub fn dense_rank(self, descending: bool) -> Expr {
+        self.rank(
+            RankOptions {
+                method: RankMethod::Dense,
+                descending,
+            },
+            None,
+        )
+    }
+
+    #[cfg(feature = "rank")]
+    /// The relative rank `(rank - 1) / (n - 1)` of the values, with the [`RankMethod::Min`] rank
+    /// and `n` the number of non-null values. Like the SQL `PERCENT_RANK` window function, a
+    /// single value has a relative rank of `0.0`.
+    pub fn percent_rank(self, descending: bool) -> Expr {
+        let rank = self
+            .clone()
+            .rank(
+                RankOptions {
+                    method: RankMethod::Min,
+                    descending,
+                },
+                None,
+            )
+            .cast(DataType::Float64);
+        let n = self.count().cast(DataType::Float64);
+        let denominator = when(n.clone().gt(lit(1.0)))
+            .then(n - lit(1.0))
+            .otherwise(lit(1.0));
+        (rank - lit(1.0)) / denominator
+    }
+
+    #[cfg(feature = "rank")]
+    /// The cumulative distribution `rank / n` of the values, with the [`RankMethod::Max`] rank and
+    /// `n` the number of non-null values. This is the fraction of the values that are smaller
+    /// than or equal to a value, like the SQL `CUME_DIST` window function.
+    pub fn cume_dist(self, descending: bool) -> Expr {
+        let rank = self
+            .clone()
+            .rank(
+                RankOptions {
+                    method: RankMethod::Max,
+                    descending,
+                },
+                None,
+            )
+            .cast(DataType::Float64);
+        rank / self.count().cast(DataType::Float64)
+    }
+
+    #[cfg(feature = "rank")]
+    /// Divide the values into `n` buckets of (nearly) equal size, numbered from 1, like the SQL
+    /// `NTILE` window function. The values are ordered like the [`RankMethod::Ordinal`] rank. If
+    /// the number of values isn't a multiple of `n`, the first buckets hold a value more.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn ntile(self, n: u32, descending: bool) -> Expr {
+        assert!(n > 0, "the number of buckets of `ntile` must be positive");
+        let row = self
+            .clone()
+            .rank(
+                RankOptions {
+                    method: RankMethod::Ordinal,
+                    descending,
+                },
+                None,
+            )
+            .cast(DataType::Int64)
+            - lit(1i64);
+        let count = self.count().cast(DataType::Int64);
+        let n = lit(n as i64);
+        let size = count.clone().floor_div(n.clone());
+        // The first `remainder` buckets hold `size + 1` values.
+        let remainder = count - size.clone() * n;
+        let large_size = size.clone() + lit(1i64);
+        let in_large = remainder.clone() * large_size.clone();
+        let small_size = when(size.clone().gt(lit(0i64)))
+            .then(size)
+            .otherwise(lit(1i64));
+        let bucket = when(row.clone().lt(in_large.clone()))
+            .then(row.clone().floor_div(large_size))
+            .otherwise(remainder + (row - in_large).floor_div(small_size));
+        (bucket + lit(1i64)).cast(DataType::UInt32)
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(
@@ -1438,6 +1722,47 @@ impl Expr {
         self.apply_many_private(FunctionExpr::PctChange, &[n], false, false)
     }
 
+    #[cfg(feature = "outliers")]
+    /// Replace the values below the `lower_q` quantile and above the `upper_q` quantile with
+    /// those quantiles. In a group context the quantiles are computed per group.
+    pub fn winsorize(self, lower_q: f64, upper_q: f64) -> Expr {
+        self.apply_private(FunctionExpr::Winsorize { lower_q, upper_q })
+    }
+
+    #[cfg(feature = "outliers")]
+    /// Flag the values that are more than `k` times the interquartile range below the first
+    /// quartile or above the third quartile. In a group context the quartiles are computed
+    /// per group.
+    pub fn is_outlier_iqr(self, k: f64) -> Expr {
+        self.apply_private(FunctionExpr::IsOutlierIqr { k })
+    }
+
+    #[cfg(feature = "outliers")]
+    /// Flag the values that are more than `threshold` standard deviations away from the mean.
+    /// In a group context the mean and standard deviation are computed per group.
+    pub fn is_outlier_zscore(self, threshold: f64) -> Expr {
+        self.apply_private(FunctionExpr::IsOutlierZScore { threshold })
+    }
+
+    #[cfg(feature = "geo")]
+    /// Check which points lie inside the polygon with the given `(x, y)` vertices. The points
+    /// are structs of two numeric fields or arrays of width 2.
+    pub fn point_in_polygon(self, polygon: Vec<[f64; 2]>) -> Expr {
+        self.map_private(FunctionExpr::PointInPolygon(polygon))
+    }
+
+    #[cfg(feature = "geo")]
+    /// Check which points lie inside the bounding box, including its boundary. The points
+    /// are structs of two numeric fields or arrays of width 2.
+    pub fn within_bbox(self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Expr {
+        self.map_private(FunctionExpr::WithinBbox {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        })
+    }
+
     #[cfg(feature = "moment")]
     /// Compute the sample skewness of a data set.
     ///
@@ -1505,6 +1830,44 @@ impl Expr {
         self.apply_private(FunctionExpr::EwmVar { options })
     }
 
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving average, where the weight of a value
+    /// halves every `half_life` that passed according to the temporal `by` column.
+    ///
+    /// `options.alpha` is ignored.
+    pub fn ewm_mean_by(self, by: Expr, half_life: Duration, options: EWMOptions) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmMeanBy { half_life, options },
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving standard deviation based on a temporal
+    /// `by` column, see [`Expr::ewm_mean_by`].
+    pub fn ewm_std_by(self, by: Expr, half_life: Duration, options: EWMOptions) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmStdBy { half_life, options },
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving variance based on a temporal `by`
+    /// column, see [`Expr::ewm_mean_by`].
+    pub fn ewm_var_by(self, by: Expr, half_life: Duration, options: EWMOptions) -> Self {
+        self.apply_many_private(
+            FunctionExpr::EwmVarBy { half_life, options },
+            &[by],
+            false,
+            false,
+        )
+    }
+
     /// Returns whether any of the values in the column are `true`.
     ///
     /// If `ignore_nulls` is `False`, [Kleene logic] is used to deal with nulls:
@@ -68,6 +68,8 @@ pub enum WindowType {
     /// Explode the aggregated list and just do a hstack instead of a join
     /// this requires the groups to be sorted to make any sense
     Over(WindowMapping),
+    /// Aggregate over a frame of rows around every row of the window partition
+    Frame(WindowFrame),
     #[cfg(feature = "dynamic_group_by")]
     Rolling(RollingGroupOptions),
 }
@@ -78,6 +80,12 @@ impl From<WindowMapping> for WindowType {
     }
 }
 
+impl From<WindowFrame> for WindowType {
+    fn from(value: WindowFrame) -> Self {
+        Self::Frame(value)
+    }
+}
+
 impl Default for WindowType {
     fn default() -> Self {
         Self::Over(WindowMapping::default())
@@ -97,3 +105,22 @@ pub enum WindowMapping {
     /// warning: this can be memory intensive
     Join,
 }
+
+/// The rows of a window partition that are aggregated for a row, like the SQL
+/// `ROWS BETWEEN start AND end` frame clause.
+///
+/// The bounds are row offsets relative to the current row in the (ordered) partition. A negative
+/// offset is a preceding row, a positive offset a following row and `None` the start resp. end
+/// of the partition. `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW` is `start: Some(-2), end: Some(0)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindowFrame {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+impl WindowFrame {
+    pub fn new(start: Option<i64>, end: Option<i64>) -> Self {
+        Self { start, end }
+    }
+}
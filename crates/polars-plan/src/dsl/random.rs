@@ -51,4 +51,14 @@ impl Expr {
             false,
         )
     }
+
+    /// Generate a random UUID of version 4 for every value of this expression, in the lowercase
+    /// hyphenated form.
+    #[cfg(feature = "uuid")]
+    pub fn uuid4(self, seed: Option<u64>) -> Self {
+        self.map_private(FunctionExpr::Random {
+            method: RandomMethod::Uuid4,
+            seed,
+        })
+    }
 }
@@ -93,6 +93,40 @@ impl StringNameSpace {
         )
     }
 
+    /// Compute the Hamming distance to the strings in `other`. Strings of different
+    /// lengths result in a null.
+    #[cfg(feature = "string_distance")]
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Hamming),
+            &[other],
+            false,
+            true,
+        )
+    }
+
+    /// Compute the Jaro-Winkler similarity to the strings in `other`.
+    #[cfg(feature = "string_distance")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            true,
+        )
+    }
+
+    /// Compute the Levenshtein distance to the strings in `other`.
+    #[cfg(feature = "string_distance")]
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein),
+            &[other],
+            false,
+            true,
+        )
+    }
+
     #[cfg(feature = "string_encoding")]
     pub fn hex_encode(self) -> Expr {
         self.0
@@ -105,6 +139,21 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::HexDecode(strict)))
     }
 
+    /// Check which values are valid UUIDs, in the hyphenated or the simple form.
+    #[cfg(feature = "uuid")]
+    pub fn is_uuid(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::IsUuid))
+    }
+
+    /// Parse UUIDs into their 16 bytes. If `strict` is false, values that are not valid UUIDs
+    /// are null.
+    #[cfg(feature = "uuid")]
+    pub fn to_uuid(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::ToUuid(strict)))
+    }
+
     #[cfg(feature = "string_encoding")]
     pub fn base64_encode(self) -> Expr {
         self.0
@@ -293,6 +342,13 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column into a Duration column. The format is either `"polars"`
+    /// (default), e.g. `1d 2h 3m`, or `"iso"` for ISO 8601 durations, e.g. `P1DT2H3M`.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, options: StrptimeOptions) -> Expr {
+        self.strptime(DataType::Duration(time_unit), options, lit("raise"))
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -36,6 +36,17 @@ impl StructNameSpace {
             )))
     }
 
+    /// Add or replace fields of the [`StructChunked`]. Fields are matched by
+    /// the output name of the given expressions.
+    pub fn with_fields(self, fields: Vec<Expr>) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StructExpr(StructFunction::WithFields),
+            &fields,
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
         self.0
@@ -14,6 +14,9 @@ pub struct OptState {
     pub streaming: bool,
     pub eager: bool,
     pub fast_projection: bool,
+    /// Reorder chains of inner joins based on the estimated sizes of the relations.
+    pub join_reorder: bool,
+    pub streaming_options: StreamingOptions,
 }
 
 impl Default for OptState {
@@ -33,9 +36,42 @@ impl Default for OptState {
             streaming: false,
             fast_projection: true,
             eager: false,
+            join_reorder: true,
+            streaming_options: Default::default(),
         }
     }
 }
 
 /// AllowedOptimizations
 pub type AllowedOptimizations = OptState;
+
+/// Resource settings of the streaming engine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StreamingOptions {
+    /// Number of bytes the streaming engine may use for chunks, hash tables and sort buffers.
+    /// If `0`, the engine sizes these based on the free memory of the system.
+    pub memory_limit: usize,
+    /// Allow operators to spill to disk if they exceed the memory budget.
+    pub spill: bool,
+    /// Guarantee that the rows are returned in the same order as the in-memory engine would
+    /// return them. Operations of which the streaming implementation doesn't preserve the
+    /// order are then executed by the in-memory engine.
+    pub maintain_order: bool,
+}
+
+impl Default for StreamingOptions {
+    fn default() -> Self {
+        StreamingOptions {
+            memory_limit: 0,
+            spill: true,
+            maintain_order: false,
+        }
+    }
+}
+
+impl StreamingOptions {
+    /// The explicit memory budget, if any.
+    pub fn memory_budget(&self) -> Option<usize> {
+        (self.memory_limit > 0).then_some(self.memory_limit)
+    }
+}
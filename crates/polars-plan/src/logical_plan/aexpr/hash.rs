@@ -24,7 +24,12 @@ impl Hash for AExpr {
             AExpr::Agg(agg) => agg.hash(state),
             AExpr::SortBy { descending, .. } => descending.hash(state),
             AExpr::Cast { strict, .. } => strict.hash(state),
-            AExpr::Window { options, .. } => options.hash(state),
+            AExpr::Window {
+                order_by, options, ..
+            } => {
+                order_by.map(|(_, options)| options).hash(state);
+                options.hash(state)
+            },
             AExpr::BinaryExpr { op, .. } => op.hash(state),
             _ => {},
         }
@@ -5,9 +5,13 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use arrow::legacy::prelude::QuantileInterpolOptions;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal_add_sub_dtype, _get_decimal_div_dtype, _get_decimal_mul_dtype,
+};
 use polars_core::frame::group_by::GroupByMethod;
 use polars_core::prelude::*;
-use polars_core::utils::{get_time_units, try_get_supertype};
+use polars_core::utils::{temporal_coercion, try_get_supertype};
 use polars_utils::arena::{Arena, Node};
 use strum_macros::IntoStaticStr;
 
@@ -179,6 +183,7 @@ pub enum AExpr {
     Window {
         function: Node,
         partition_by: Vec<Node>,
+        order_by: Option<(Node, SortOptions)>,
         options: WindowType,
     },
     #[default]
@@ -314,8 +319,12 @@ impl AExpr {
             Window {
                 function,
                 partition_by,
+                order_by,
                 options: _,
             } => {
+                if let Some((e, _)) = order_by {
+                    container.push(*e);
+                }
                 for e in partition_by.iter().rev() {
                     container.push(*e);
                 }
@@ -404,11 +413,16 @@ impl AExpr {
             Window {
                 function,
                 partition_by,
+                order_by,
                 ..
             } => {
+                let offset = order_by.is_some() as usize;
+                if let Some((order_by, _)) = order_by {
+                    *order_by = inputs[0];
+                }
                 *function = *inputs.last().unwrap();
                 partition_by.clear();
-                partition_by.extend_from_slice(&inputs[..inputs.len() - 1]);
+                partition_by.extend_from_slice(&inputs[offset..inputs.len() - 1]);
 
                 return self;
             },
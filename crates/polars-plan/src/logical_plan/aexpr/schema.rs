@@ -1,7 +1,9 @@
 use super::*;
 
 fn float_type(field: &mut Field) {
-    if field.dtype.is_numeric() && !matches!(&field.dtype, DataType::Float32) {
+    if (field.dtype.is_numeric() || field.dtype.is_decimal())
+        && !matches!(&field.dtype, DataType::Float32)
+    {
         field.coerce(DataType::Float64)
     }
 }
@@ -74,7 +76,9 @@ impl AExpr {
                         };
                         Field::new(out_name, Boolean)
                     },
-                    Operator::TrueDivide => return get_truediv_field(*left, arena, ctxt, schema),
+                    Operator::TrueDivide => {
+                        return get_truediv_field(*left, *right, arena, ctxt, schema)
+                    },
                     _ => return get_arithmetic_field(*left, *right, arena, *op, ctxt, schema),
                 };
 
@@ -250,13 +254,12 @@ fn get_arithmetic_field(
     let mut left_field = left_ae.to_field(schema, ctxt, arena)?;
 
     let super_type = match op {
-        Operator::Minus if left_field.dtype.is_temporal() => {
+        Operator::Minus | Operator::Plus if left_field.dtype.is_temporal() => {
             let right_type = right_ae.get_type(schema, ctxt, arena)?;
-            match (&left_field.dtype, right_type) {
-                // T - T != T if T is a datetime / date
-                (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, &tur)),
-                (Date, Date) => Duration(TimeUnit::Milliseconds),
-                (left, right) => try_get_supertype(left, &right)?,
+            // T - T != T if T is a datetime / date
+            match temporal_coercion(&left_field.dtype, &right_type, op.temporal_op().unwrap())? {
+                Some(coercion) => coercion.output,
+                None => try_get_supertype(&left_field.dtype, &right_type)?,
             }
         },
         Operator::Plus
@@ -268,6 +271,12 @@ fn get_arithmetic_field(
         _ => {
             let right_type = right_ae.get_type(schema, ctxt, arena)?;
 
+            #[cfg(feature = "dtype-array")]
+            if let Some(dtype) = get_array_arithmetic_dtype(&left_field.dtype, &right_type)? {
+                left_field.coerce(dtype);
+                return Ok(left_field);
+            }
+
             // Avoid needlessly type casting numeric columns during arithmetic
             // with literals.
             if (left_field.dtype.is_integer() && right_type.is_integer())
@@ -290,24 +299,81 @@ fn get_arithmetic_field(
             try_get_supertype(&left_field.dtype, &right_type)?
         },
     };
+    // Both sides are cast to the supertype, but decimal arithmetic changes the
+    // precision and scale of the result.
+    #[cfg(feature = "dtype-decimal")]
+    let super_type = match (op, &super_type) {
+        (Operator::Plus | Operator::Minus, Decimal(_, _)) => {
+            _get_decimal_add_sub_dtype(&super_type, &super_type)?
+        },
+        (Operator::Multiply, Decimal(_, _)) => _get_decimal_mul_dtype(&super_type, &super_type)?,
+        _ => super_type,
+    };
 
     left_field.coerce(super_type);
     Ok(left_field)
 }
 
+/// Arithmetic with a fixed-size array is applied on its elements, so the output is an array
+/// of the supertype of the elements and the other operand.
+#[cfg(feature = "dtype-array")]
+fn get_array_arithmetic_dtype(left: &DataType, right: &DataType) -> PolarsResult<Option<DataType>> {
+    use DataType::*;
+    let (inner, width) = match (left, right) {
+        (Array(inner_l, width_l), Array(inner_r, width_r)) => {
+            polars_ensure!(
+                width_l == width_r,
+                ShapeMismatch: "cannot apply arithmetic on arrays of different widths: {} and {}", width_l, width_r
+            );
+            (try_get_supertype(inner_l, inner_r)?, *width_l)
+        },
+        (Array(inner, width), other) | (other, Array(inner, width)) => {
+            (try_get_supertype(inner, other)?, *width)
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(Array(Box::new(inner), width)))
+}
+
+#[cfg_attr(
+    not(any(feature = "dtype-decimal", feature = "dtype-array")),
+    allow(unused_variables)
+)]
 fn get_truediv_field(
     left: Node,
+    right: Node,
     arena: &Arena<AExpr>,
     ctxt: Context,
     schema: &Schema,
 ) -> PolarsResult<Field> {
     let mut left_field = arena.get(left).to_field(schema, ctxt, arena)?;
     use DataType::*;
+
+    #[cfg(feature = "dtype-array")]
+    {
+        let right_type = arena.get(right).get_type(schema, ctxt, arena)?;
+        if let Some(Array(inner, width)) =
+            get_array_arithmetic_dtype(left_field.data_type(), &right_type)?
+        {
+            let inner = if *inner == Float32 { Float32 } else { Float64 };
+            left_field.coerce(Array(Box::new(inner), width));
+            return Ok(left_field);
+        }
+    }
+
     let out_type = match left_field.data_type() {
         Float32 => Float32,
         dt if dt.is_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
         Duration(_) => Float64,
+        #[cfg(feature = "dtype-decimal")]
+        dt @ Decimal(_, _) => {
+            let right_type = arena.get(right).get_type(schema, ctxt, arena)?;
+            match try_get_supertype(dt, &right_type)? {
+                st @ Decimal(_, _) => _get_decimal_div_dtype(&st, &st)?,
+                st => st,
+            }
+        },
         // we don't know what to do here, best return the dtype
         dt => dt.clone(),
     };
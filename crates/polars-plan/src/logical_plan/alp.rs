@@ -161,6 +161,7 @@ impl ALogicalPlan {
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
                 SinkType::File { .. } => "sink (file)",
+                SinkType::Partitioned { .. } => "sink (partitioned)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
             },
@@ -3,6 +3,10 @@ use std::io::{Read, Seek};
 
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
+#[cfg(any(feature = "parquet", feature = "dtype-decimal"))]
+use polars_core::utils::try_get_supertype;
+#[cfg(feature = "parquet")]
+use polars_core::POOL;
 #[cfg(feature = "parquet")]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "ipc")]
@@ -17,17 +21,21 @@ use polars_io::pl_async::get_runtime;
     feature = "parquet",
     feature = "parquet_async",
     feature = "csv",
-    feature = "ipc"
+    feature = "ipc",
+    feature = "flight"
 ))]
 use polars_io::RowIndex;
 #[cfg(feature = "csv")]
 use polars_io::{
-    csv::utils::{infer_file_schema, is_compressed},
+    compression::maybe_decompress_reader_bytes,
+    csv::utils::{compile_dtype_overwrite_regex, infer_file_schema},
     csv::CommentPrefix,
     csv::CsvEncoding,
     csv::NullValues,
     utils::get_reader_bytes,
 };
+#[cfg(feature = "parquet")]
+use rayon::prelude::*;
 
 use super::builder_functions::*;
 use crate::dsl::functions::horizontal::all_horizontal;
@@ -85,6 +93,48 @@ macro_rules! try_delayed {
     };
 }
 
+/// Some join keys are compared on their physical values, so they are cast to a common dtype
+/// first: decimal keys with a different precision or scale go to their supertype, and
+/// String or Categorical keys joined with an Enum are cast to that Enum.
+#[cfg(any(feature = "dtype-decimal", feature = "dtype-categorical"))]
+fn cast_join_keys(
+    schema_left: &Schema,
+    schema_right: &Schema,
+    mut left_on: Vec<Expr>,
+    mut right_on: Vec<Expr>,
+) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+    let mut arena = Arena::with_capacity(8);
+    for (l, r) in left_on.iter_mut().zip(right_on.iter_mut()) {
+        let dtype_l = l
+            .to_field_amortized(schema_left, Context::Default, &mut arena)?
+            .dtype;
+        let dtype_r = r
+            .to_field_amortized(schema_right, Context::Default, &mut arena)?
+            .dtype;
+        arena.clear();
+
+        #[cfg(feature = "dtype-decimal")]
+        if dtype_l.is_decimal() && dtype_r.is_decimal() && dtype_l != dtype_r {
+            let st = try_get_supertype(&dtype_l, &dtype_r)?;
+            *l = l.clone().strict_cast(st.clone());
+            *r = r.clone().strict_cast(st);
+            continue;
+        }
+
+        #[cfg(feature = "dtype-categorical")]
+        {
+            let can_cast_to_enum =
+                |dt: &DataType| matches!(dt, DataType::String | DataType::Categorical(_, _));
+            if dtype_l.is_enum() && !dtype_r.is_enum() && can_cast_to_enum(&dtype_r) {
+                *r = r.clone().cast(dtype_l);
+            } else if dtype_r.is_enum() && !dtype_l.is_enum() && can_cast_to_enum(&dtype_l) {
+                *l = l.clone().cast(dtype_r);
+            }
+        }
+    }
+    Ok((left_on, right_on))
+}
+
 #[cfg(any(feature = "parquet", feature = "parquet_async",))]
 fn prepare_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
@@ -93,6 +143,63 @@ fn prepare_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef
     Arc::new(schema)
 }
 
+/// Unify the schemas of the files of a scan. The dtypes of a numeric column are promoted to
+/// their supertype, and columns that are missing in some files are added if `missing_columns`
+/// allows it.
+///
+/// Returns the unified schema and whether the schemas differ.
+#[cfg(feature = "parquet")]
+fn unify_file_schemas(
+    paths: &[std::path::PathBuf],
+    schemas: &[Arc<ArrowSchema>],
+    missing_columns: MissingColumns,
+) -> PolarsResult<(Arc<ArrowSchema>, bool)> {
+    let first = &schemas[0];
+    if schemas.iter().all(|schema| schema == first) {
+        return Ok((first.clone(), false));
+    }
+    let mut unified = Schema::from(first.as_ref());
+    for (path, schema) in paths.iter().zip(schemas).skip(1) {
+        let schema = Schema::from(schema.as_ref());
+        for (name, dtype) in schema.iter() {
+            match unified.get(name) {
+                Some(current) if current == dtype => {},
+                Some(current) => {
+                    let supertype = if current.is_numeric() && dtype.is_numeric() {
+                        try_get_supertype(current, dtype).ok()
+                    } else {
+                        None
+                    };
+                    let Some(supertype) = supertype else {
+                        polars_bail!(
+                            SchemaMismatch: "column '{}' has type {} in file '{}', but type {} in \
+                            an earlier file", name, dtype, path.display(), current
+                        )
+                    };
+                    unified.with_column(name.clone(), supertype);
+                },
+                None => {
+                    polars_ensure!(
+                        missing_columns == MissingColumns::InsertNull,
+                        SchemaMismatch: "column '{}' of file '{}' is missing in an earlier file; \
+                        set `missing_columns` to insert it as nulls", name, path.display()
+                    );
+                    unified.with_column(name.clone(), dtype.clone());
+                },
+            }
+        }
+        if missing_columns == MissingColumns::Raise {
+            if let Some(name) = unified.iter_names().find(|name| !schema.contains(name)) {
+                polars_bail!(
+                    SchemaMismatch: "column '{}' is missing in file '{}'; set `missing_columns` \
+                    to insert it as nulls", name, path.display()
+                )
+            }
+        }
+    }
+    Ok((Arc::new(unified.to_arrow(true)), true))
+}
+
 impl LogicalPlanBuilder {
     pub fn anonymous_scan(
         function: Arc<dyn AnonymousScan>,
@@ -116,6 +223,7 @@ impl LogicalPlanBuilder {
             rechunk: false,
             file_counter: Default::default(),
             hive_partitioning: false,
+            include_file_paths: None,
         };
 
         Ok(LogicalPlan::Scan {
@@ -147,6 +255,9 @@ impl LogicalPlanBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_partitioning: bool,
+        compact_small_files: bool,
+        missing_columns: MissingColumns,
+        include_file_paths: Option<Arc<str>>,
     ) -> PolarsResult<Self> {
         use polars_io::{is_cloud_url, SerReader as _};
 
@@ -156,7 +267,8 @@ impl LogicalPlanBuilder {
         // Use first path to get schema.
         let path = &paths[0];
 
-        let (schema, reader_schema, num_rows, metadata) = if is_cloud_url(path) {
+        // The footers of the other files are read concurrently to unify the schemas.
+        let (reader_schemas, num_rows, metadata) = if is_cloud_url(path) {
             #[cfg(not(feature = "cloud"))]
             panic!(
                 "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
@@ -173,22 +285,40 @@ impl LogicalPlanBuilder {
                     let num_rows = reader.num_rows().await?;
                     let metadata = reader.get_metadata().await?.clone();
 
-                    let schema = prepare_schema((&reader_schema).into(), row_index.as_ref());
-                    PolarsResult::Ok((schema, reader_schema, Some(num_rows), Some(metadata)))
+                    let cloud_options = cloud_options.as_ref();
+                    let iter = paths[1..].iter().map(|path| async move {
+                        let uri = path.to_string_lossy();
+                        ParquetAsyncReader::from_uri(&uri, cloud_options, None, None)
+                            .await?
+                            .schema()
+                            .await
+                    });
+                    let mut reader_schemas = vec![reader_schema];
+                    reader_schemas.extend(futures::future::try_join_all(iter).await?);
+                    PolarsResult::Ok((reader_schemas, Some(num_rows), Some(metadata)))
                 })?
             }
         } else {
             let file = polars_utils::open_file(path)?;
             let mut reader = ParquetReader::new(file);
             let reader_schema = reader.schema()?;
-            let schema = prepare_schema((&reader_schema).into(), row_index.as_ref());
+            let other_schemas = POOL.install(|| {
+                paths[1..]
+                    .par_iter()
+                    .map(|path| ParquetReader::new(polars_utils::open_file(path)?).schema())
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+            let mut reader_schemas = vec![reader_schema];
+            reader_schemas.extend(other_schemas);
             (
-                schema,
-                reader_schema,
+                reader_schemas,
                 Some(reader.num_rows()?),
                 Some(reader.get_metadata()?.clone()),
             )
         };
+        let (reader_schema, schema_drift) =
+            unify_file_schemas(&paths, &reader_schemas, missing_columns)?;
+        let schema = prepare_schema((&reader_schema).into(), row_index.as_ref());
 
         let mut file_info = FileInfo::new(
             schema,
@@ -201,6 +331,9 @@ impl LogicalPlanBuilder {
         if hive_partitioning {
             file_info.init_hive_partitions(path.as_path())?;
         }
+        if let Some(name) = &include_file_paths {
+            file_info.add_file_path_column(name)?;
+        }
 
         let options = FileScanOptions {
             with_columns: None,
@@ -210,6 +343,7 @@ impl LogicalPlanBuilder {
             row_index,
             file_counter: Default::default(),
             hive_partitioning,
+            include_file_paths,
         };
         Ok(LogicalPlan::Scan {
             paths,
@@ -221,6 +355,9 @@ impl LogicalPlanBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    compact_small_files,
+                    missing_columns,
+                    schema_drift,
                 },
                 cloud_options,
                 metadata,
@@ -230,18 +367,23 @@ impl LogicalPlanBuilder {
     }
 
     #[cfg(feature = "ipc")]
-    pub fn scan_ipc<P: Into<std::path::PathBuf>>(
-        path: P,
+    pub fn scan_ipc<P: Into<Arc<[std::path::PathBuf]>>>(
+        paths: P,
         options: IpcScanOptions,
         n_rows: Option<usize>,
         cache: bool,
         row_index: Option<RowIndex>,
         rechunk: bool,
+        hive_partitioning: bool,
     ) -> PolarsResult<Self> {
         use polars_io::SerReader as _;
 
-        let path = path.into();
-        let file = polars_utils::open_file(&path)?;
+        let paths = paths.into();
+        polars_ensure!(paths.len() >= 1, ComputeError: "expected at least 1 path");
+
+        // Use first path to get schema.
+        let path = &paths[0];
+        let file = polars_utils::open_file(path)?;
         let mut reader = IpcReader::new(file);
 
         let reader_schema = reader.schema()?;
@@ -251,7 +393,13 @@ impl LogicalPlanBuilder {
         }
 
         let num_rows = reader._num_rows()?;
-        let file_info = FileInfo::new(Arc::new(schema), Some(reader_schema), (None, num_rows));
+        let mut file_info = FileInfo::new(Arc::new(schema), Some(reader_schema), (None, num_rows));
+
+        // We set the hive partitions of the first path to determine the schema.
+        // On iteration the partition values will be re-set per file.
+        if hive_partitioning {
+            file_info.init_hive_partitions(path.as_path())?;
+        }
 
         let file_options = FileScanOptions {
             with_columns: None,
@@ -260,11 +408,11 @@ impl LogicalPlanBuilder {
             rechunk,
             row_index,
             file_counter: Default::default(),
-            // TODO! add
-            hive_partitioning: false,
+            hive_partitioning,
+            include_file_paths: None,
         };
         Ok(LogicalPlan::Scan {
-            paths: Arc::new([path]),
+            paths,
             file_info,
             file_options,
             predicate: None,
@@ -273,6 +421,51 @@ impl LogicalPlanBuilder {
         .into())
     }
 
+    #[cfg(feature = "flight")]
+    pub fn scan_flight(
+        options: FlightScanOptions,
+        schema: Option<SchemaRef>,
+        n_rows: Option<usize>,
+        row_index: Option<RowIndex>,
+    ) -> PolarsResult<Self> {
+        // Without a given schema, the stream is requested for its schema.
+        let schema = match schema {
+            Some(schema) => schema,
+            None => {
+                let mut reader =
+                    polars_io::flight::FlightReader::new(&options.endpoint, options.ticket.clone());
+                Arc::new(Schema::from(&reader.schema()?))
+            },
+        };
+        let mut schema = (*schema).clone();
+        if let Some(rc) = &row_index {
+            let _ = schema.insert_at_index(0, rc.name.as_str().into(), IDX_DTYPE);
+        }
+        let file_info = FileInfo::new(
+            Arc::new(schema),
+            None,
+            (n_rows, n_rows.unwrap_or(usize::MAX)),
+        );
+        let file_options = FileScanOptions {
+            with_columns: None,
+            cache: false,
+            n_rows,
+            rechunk: false,
+            row_index,
+            file_counter: Default::default(),
+            hive_partitioning: false,
+            include_file_paths: None,
+        };
+        Ok(LogicalPlan::Scan {
+            paths: Arc::new([options.endpoint.as_str().into()]),
+            file_info,
+            file_options,
+            predicate: None,
+            scan_type: FileScan::Flight { options },
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv<P: Into<std::path::PathBuf>>(
@@ -285,6 +478,7 @@ impl LogicalPlanBuilder {
         cache: bool,
         mut schema: Option<Arc<Schema>>,
         schema_overwrite: Option<&Schema>,
+        dtype_overwrite_regex: Option<&[(String, DataType)]>,
         low_memory: bool,
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
@@ -296,6 +490,7 @@ impl LogicalPlanBuilder {
         encoding: CsvEncoding,
         row_index: Option<RowIndex>,
         try_parse_dates: bool,
+        decimal_comma: bool,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
     ) -> PolarsResult<Self> {
@@ -306,19 +501,18 @@ impl LogicalPlanBuilder {
 
         let mut magic_nr = [0u8; 4];
         let res_len = file.read(&mut magic_nr)?;
-        if res_len < 2 {
-            if raise_if_empty {
-                polars_bail!(NoData: "empty CSV")
-            }
-        } else {
-            polars_ensure!(
-            !is_compressed(&magic_nr),
-            ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
-            );
+        if res_len < 2 && raise_if_empty {
+            polars_bail!(NoData: "empty CSV")
         }
 
         file.rewind()?;
         let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        // the scan decompresses compressed files again when they are read
+        let reader_bytes = maybe_decompress_reader_bytes(reader_bytes)?;
+
+        let dtype_overwrite_regex = dtype_overwrite_regex
+            .map(compile_dtype_overwrite_regex)
+            .transpose()?;
 
         // TODO! delay inferring schema until absolutely necessary
         // this needs a way to estimated bytes/rows.
@@ -328,6 +522,7 @@ impl LogicalPlanBuilder {
             infer_schema_length,
             has_header,
             schema_overwrite,
+            dtype_overwrite_regex.as_deref(),
             &mut skip_rows,
             skip_rows_after_header,
             comment_prefix.as_ref(),
@@ -335,6 +530,7 @@ impl LogicalPlanBuilder {
             eol_char,
             null_values.as_ref(),
             try_parse_dates,
+            decimal_comma,
             raise_if_empty,
         )?;
 
@@ -369,6 +565,7 @@ impl LogicalPlanBuilder {
             file_counter: Default::default(),
             // TODO! add
             hive_partitioning: false,
+            include_file_paths: None,
         };
         Ok(LogicalPlan::Scan {
             paths,
@@ -388,6 +585,7 @@ impl LogicalPlanBuilder {
                     null_values,
                     encoding,
                     try_parse_dates,
+                    decimal_comma,
                     raise_if_empty,
                     truncate_ragged_lines,
                 },
@@ -744,6 +942,8 @@ impl LogicalPlanBuilder {
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
             slice: None,
+            having: None,
+            parallel_apply: false,
         };
 
         LogicalPlan::Aggregate {
@@ -796,7 +996,7 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn explode(self, columns: Vec<Expr>) -> Self {
+    pub fn explode(self, columns: Vec<Expr>, mismatch: ExplodeMismatch) -> Self {
         let schema = try_delayed!(self.0.schema(), &self.0, into);
         let columns = try_delayed!(rewrite_projections(columns, &schema, &[]), &self.0, into);
 
@@ -819,6 +1019,7 @@ impl LogicalPlanBuilder {
             input: Box::new(self.0),
             function: FunctionNode::Explode {
                 columns,
+                mismatch,
                 schema: Arc::new(schema),
             },
         }
@@ -827,7 +1028,8 @@ impl LogicalPlanBuilder {
 
     pub fn melt(self, args: Arc<MeltArgs>) -> Self {
         let schema = try_delayed!(self.0.schema(), &self.0, into);
-        let schema = det_melt_schema(&args, &schema);
+        let args = try_delayed!(expand_melt_args(args, &schema), &self.0, into);
+        let schema = try_delayed!(det_melt_schema(&args, &schema), &self.0, into);
         LogicalPlan::MapFunction {
             input: Box::new(self.0),
             function: FunctionNode::Melt { args, schema },
@@ -889,9 +1091,21 @@ impl LogicalPlanBuilder {
             }
         }
 
+        try_delayed!(
+            options.args.hints.validate(&options.args.how),
+            &self.0,
+            into
+        );
         let schema_left = try_delayed!(self.0.schema(), &self.0, into);
         let schema_right = try_delayed!(other.schema(), &self.0, into);
 
+        #[cfg(any(feature = "dtype-decimal", feature = "dtype-categorical"))]
+        let (left_on, right_on) = try_delayed!(
+            cast_join_keys(&schema_left, &schema_right, left_on, right_on),
+            &self.0,
+            into
+        );
+
         let schema = try_delayed!(
             det_join_schema(&schema_left, &schema_right, &left_on, &right_on, &options),
             self.0,
@@ -96,7 +96,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
     }
 
     // call this if the schema needs to be updated
-    pub(crate) fn explode(self, columns: Arc<[Arc<str>]>) -> Self {
+    pub(crate) fn explode(self, columns: Arc<[Arc<str>]>, mismatch: ExplodeMismatch) -> Self {
         let mut schema = (*self.schema().into_owned()).clone();
         explode_schema(&mut schema, &columns).unwrap();
 
@@ -104,6 +104,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
             input: self.root,
             function: FunctionNode::Explode {
                 columns,
+                mismatch,
                 schema: Arc::new(schema),
             },
         };
@@ -204,7 +205,8 @@ impl<'a> ALogicalPlanBuilder<'a> {
 
     pub fn melt(self, args: Arc<MeltArgs>) -> Self {
         let schema = self.schema();
-        let schema = det_melt_schema(&args, &schema);
+        // The arguments were validated when the logical plan was built.
+        let schema = det_melt_schema(&args, &schema).unwrap();
         let lp = ALogicalPlan::MapFunction {
             input: self.root,
             function: FunctionNode::Melt { args, schema },
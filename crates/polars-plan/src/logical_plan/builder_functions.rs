@@ -1,5 +1,7 @@
 use polars_core::utils::try_get_supertype;
+use smartstring::alias::String as SmartString;
 
+use super::projection::is_regex_projection;
 use super::*;
 
 // Has functions that create schema's for both the `LogicalPlan` and the `AlogicalPlan` builders.
@@ -15,12 +17,69 @@ pub(super) fn explode_schema(schema: &mut Schema, columns: &[Arc<str>]) -> Polar
     })
 }
 
-pub(super) fn det_melt_schema(args: &MeltArgs, input_schema: &Schema) -> SchemaRef {
+/// Expand the regexes (`^...$`) in the `id_vars` and `value_vars` of `args` into the columns of
+/// the input schema that match them.
+pub(super) fn expand_melt_args(
+    args: Arc<MeltArgs>,
+    input_schema: &Schema,
+) -> PolarsResult<Arc<MeltArgs>> {
+    if !args
+        .id_vars
+        .iter()
+        .chain(&args.value_vars)
+        .any(|name| is_regex_projection(name))
+    {
+        return Ok(args);
+    }
+    let mut args = (*args).clone();
+    args.id_vars = expand_melt_vars(&args.id_vars, input_schema)?;
+    args.value_vars = expand_melt_vars(&args.value_vars, input_schema)?;
+    Ok(Arc::new(args))
+}
+
+#[cfg(feature = "regex")]
+fn expand_melt_vars(vars: &[SmartString], input_schema: &Schema) -> PolarsResult<Vec<SmartString>> {
+    let mut out: Vec<SmartString> = Vec::with_capacity(vars.len());
+    for name in vars {
+        if is_regex_projection(name) {
+            let re = regex::Regex::new(name)
+                .map_err(|e| polars_err!(ComputeError: "invalid regex {}", e))?;
+            let matches = input_schema
+                .iter_names()
+                .filter(|c| re.is_match(c) && !out.contains(c))
+                .cloned()
+                .collect::<Vec<_>>();
+            out.extend(matches);
+        } else if !out.contains(name) {
+            out.push(name.clone());
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(not(feature = "regex"))]
+fn expand_melt_vars(
+    vars: &[SmartString],
+    _input_schema: &Schema,
+) -> PolarsResult<Vec<SmartString>> {
+    polars_ensure!(
+        !vars.iter().any(|name| is_regex_projection(name)),
+        ComputeError: "regexes in `melt` require the 'regex' feature"
+    );
+    Ok(vars.to_vec())
+}
+
+pub(super) fn det_melt_schema(args: &MeltArgs, input_schema: &Schema) -> PolarsResult<SchemaRef> {
+    let get_dtype = |name: &str| {
+        input_schema
+            .get(name)
+            .ok_or_else(|| polars_err!(ColumnNotFound: "{}", name))
+    };
     let mut new_schema = args
         .id_vars
         .iter()
-        .map(|id| Field::new(id, input_schema.get(id).unwrap().clone()))
-        .collect::<Schema>();
+        .map(|id| Ok(Field::new(id, get_dtype(id)?.clone())))
+        .collect::<PolarsResult<Schema>>()?;
     let variable_name = args
         .variable_name
         .as_ref()
@@ -44,21 +103,21 @@ pub(super) fn det_melt_schema(args: &MeltArgs, input_schema: &Schema) -> SchemaR
             if !id_vars.contains(name) {
                 match &st {
                     None => st = Some(dtype.clone()),
-                    Some(st_) => st = Some(try_get_supertype(st_, dtype).unwrap()),
+                    Some(st_) => st = Some(try_get_supertype(st_, dtype)?),
                 }
             }
         }
     } else {
         for name in &args.value_vars {
-            let dtype = input_schema.get(name).unwrap();
+            let dtype = get_dtype(name)?;
             match &st {
                 None => st = Some(dtype.clone()),
-                Some(st_) => st = Some(try_get_supertype(st_, dtype).unwrap()),
+                Some(st_) => st = Some(try_get_supertype(st_, dtype)?),
             }
         }
     }
     new_schema.with_column(value_name, st.unwrap());
-    Arc::new(new_schema)
+    Ok(Arc::new(new_schema))
 }
 
 pub(super) fn row_index_schema(schema: &mut Schema, name: &str) {
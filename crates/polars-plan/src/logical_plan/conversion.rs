@@ -135,10 +135,12 @@ pub fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
         Expr::Window {
             function,
             partition_by,
+            order_by,
             options,
         } => AExpr::Window {
             function: to_aexpr(*function, arena),
             partition_by: to_aexprs(partition_by, arena),
+            order_by: order_by.map(|(e, options)| (to_aexpr(*e, arena), options)),
             options,
         },
         Expr::Slice {
@@ -579,13 +581,17 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         AExpr::Window {
             function,
             partition_by,
+            order_by,
             options,
         } => {
             let function = Box::new(node_to_expr(function, expr_arena));
             let partition_by = nodes_to_exprs(&partition_by, expr_arena);
+            let order_by =
+                order_by.map(|(e, options)| (Box::new(node_to_expr(e, expr_arena)), options));
             Expr::Window {
                 function,
                 partition_by,
+                order_by,
                 options,
             }
         },
@@ -638,7 +644,15 @@ impl ALogicalPlan {
                 file_options: options,
             },
             #[cfg(feature = "python")]
-            ALogicalPlan::PythonScan { options, .. } => LogicalPlan::PythonScan { options },
+            ALogicalPlan::PythonScan { options, predicate } => match predicate {
+                // pyarrow evaluates the translated predicate that is stored in the options,
+                // the predicate of an IO plugin must be applied after the scan.
+                Some(predicate) if options.io_plugin => LogicalPlan::Selection {
+                    input: Box::new(LogicalPlan::PythonScan { options }),
+                    predicate: node_to_expr(predicate, expr_arena),
+                },
+                _ => LogicalPlan::PythonScan { options },
+            },
             ALogicalPlan::Union { inputs, options } => {
                 let inputs = inputs
                     .into_iter()
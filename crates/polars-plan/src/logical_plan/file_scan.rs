@@ -17,6 +17,8 @@ pub enum FileScan {
     },
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
+    #[cfg(feature = "flight")]
+    Flight { options: FlightScanOptions },
     #[cfg_attr(feature = "serde", serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
@@ -44,6 +46,8 @@ impl PartialEq for FileScan {
             ) => opt_l == opt_r && c_l == c_r,
             #[cfg(feature = "ipc")]
             (FileScan::Ipc { options: l }, FileScan::Ipc { options: r }) => l == r,
+            #[cfg(feature = "flight")]
+            (FileScan::Flight { options: l }, FileScan::Flight { options: r }) => l == r,
             _ => false,
         }
     }
@@ -78,6 +82,8 @@ impl FileScan {
             Self::Ipc { .. } => _file_options.row_index.is_some(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => _file_options.row_index.is_some(),
+            #[cfg(feature = "flight")]
+            Self::Flight { .. } => _file_options.row_index.is_some(),
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -90,9 +96,23 @@ impl FileScan {
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
             #[cfg(feature = "parquet")]
-            Self::Parquet { .. } => true,
+            // the streaming source can't conform files to the unified schema
+            Self::Parquet { options, .. } => !options.schema_drift,
+            #[cfg(feature = "flight")]
+            Self::Flight { .. } => true,
             #[allow(unreachable_patterns)]
             _ => false,
         }
     }
+
+    /// Whether scans of the same paths read the same data, so that they can share a read.
+    #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv", feature = "cse"))]
+    pub(crate) fn is_identified_by_paths(&self) -> bool {
+        match self {
+            // The ticket rather than the server identifies the data of a flight scan.
+            #[cfg(feature = "flight")]
+            Self::Flight { .. } => false,
+            _ => true,
+        }
+    }
 }
@@ -174,10 +174,18 @@ impl LogicalPlan {
                 input._format(f, sub_indent)
             },
             Aggregate {
-                input, keys, aggs, ..
+                input,
+                keys,
+                aggs,
+                options,
+                ..
             } => {
                 write!(f, "{:indent$}AGGREGATE", "")?;
-                write!(f, "\n{:indent$}\t{aggs:?} BY {keys:?} FROM", "")?;
+                write!(f, "\n{:indent$}\t{aggs:?} BY {keys:?}", "")?;
+                if let Some(having) = &options.having {
+                    write!(f, " HAVING col(\"{having}\")")?;
+                }
+                write!(f, " FROM")?;
                 input._format(f, sub_indent)
             },
             Join {
@@ -190,6 +198,9 @@ impl LogicalPlan {
             } => {
                 let how = &options.args.how;
                 write!(f, "{:indent$}{how} JOIN:", "")?;
+                if !options.args.hints.is_empty() {
+                    write!(f, "\n{:indent$}HINTS: {}", "", options.args.hints)?;
+                }
                 write!(f, "\n{:indent$}LEFT PLAN ON: {left_on:?}", "")?;
                 input_left._format(f, sub_indent)?;
                 write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on:?}", "")?;
@@ -225,6 +236,7 @@ impl LogicalPlan {
                 let name = match payload {
                     SinkType::Memory => "SINK (memory)",
                     SinkType::File { .. } => "SINK (file)",
+                    SinkType::Partitioned { .. } => "SINK (partitioned)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
                 };
@@ -254,6 +266,7 @@ impl Debug for Expr {
             Window {
                 function,
                 partition_by,
+                order_by,
                 options,
             } => match options {
                 #[cfg(feature = "dynamic_group_by")]
@@ -265,7 +278,14 @@ impl Debug for Expr {
                     )
                 },
                 _ => {
-                    write!(f, "{function:?}.over({partition_by:?})")
+                    write!(f, "{function:?}.over({partition_by:?}")?;
+                    if let Some((order_by, _)) = order_by {
+                        write!(f, ", order_by={order_by:?}")?;
+                    }
+                    if let WindowType::Frame(frame) = options {
+                        write!(f, ", frame=({:?}, {:?})", frame.start, frame.end)?;
+                    }
+                    write!(f, ")")
                 },
             },
             Nth(i) => write!(f, "nth({i})"),
@@ -34,5 +34,7 @@ pub(super) fn merge_sorted(df: &DataFrame, column: &str) -> PolarsResult<DataFra
 
     let lhs = left.column(column)?;
     let rhs = right.column(column)?;
-    _merge_sorted_dfs(&left, &right, lhs, rhs, true)
+    let mut out = _merge_sorted_dfs(&left, &right, lhs, rhs, true)?;
+    set_sorted_key(&mut out, column)?;
+    Ok(out)
 }
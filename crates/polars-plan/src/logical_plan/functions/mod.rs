@@ -11,6 +11,8 @@ use std::sync::Arc;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::StringCacheHolder;
+#[cfg(feature = "fill_gaps")]
+use polars_time::{FillGapsArgs, PolarsFillGaps};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -53,6 +55,8 @@ pub enum FunctionNode {
         function: Arc<dyn DataFrameUdfMut>,
         schema: SchemaRef,
         original: Option<Arc<LogicalPlan>>,
+        /// Whether the pipeline returns the rows in the same order as the in-memory engine.
+        maintains_order: bool,
     },
     Unnest {
         columns: Arc<[Arc<str>]>,
@@ -82,6 +86,7 @@ pub enum FunctionNode {
     },
     Explode {
         columns: Arc<[Arc<str>]>,
+        mismatch: ExplodeMismatch,
         schema: SchemaRef,
     },
     Melt {
@@ -93,6 +98,10 @@ pub enum FunctionNode {
         schema: SchemaRef,
         offset: Option<IdxSize>,
     },
+    #[cfg(feature = "fill_gaps")]
+    FillGaps {
+        args: Arc<FillGapsArgs>,
+    },
 }
 
 impl PartialEq for FunctionNode {
@@ -123,9 +132,22 @@ impl PartialEq for FunctionNode {
                     ..
                 },
             ) => existing_l == existing_r && new_l == new_r,
-            (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+            (
+                Explode {
+                    columns: l,
+                    mismatch: mismatch_l,
+                    ..
+                },
+                Explode {
+                    columns: r,
+                    mismatch: mismatch_r,
+                    ..
+                },
+            ) => l == r && mismatch_l == mismatch_r,
             (Melt { args: l, .. }, Melt { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            #[cfg(feature = "fill_gaps")]
+            (FillGaps { args: l }, FillGaps { args: r }) => l == r,
             _ => false,
         }
     }
@@ -143,12 +165,14 @@ impl FunctionNode {
             | FastProjection { .. }
             | Unnest { .. }
             | Rename { .. }
-            | Explode { .. } => true,
+            | Explode { .. }
+            | RowIndex { .. } => true,
             Melt { args, .. } => args.streamable,
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
-            RowIndex { .. } => false,
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { .. } => false,
         }
     }
 
@@ -158,6 +182,8 @@ impl FunctionNode {
         match self {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { .. } => true,
             Explode { .. } | Melt { .. } => true,
             _ => false,
         }
@@ -236,6 +262,8 @@ impl FunctionNode {
             Explode { schema, .. } | RowIndex { schema, .. } | Melt { schema, .. } => {
                 Ok(Cow::Owned(schema.clone()))
             },
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { .. } => Ok(Cow::Borrowed(input_schema)),
         }
     }
 
@@ -255,6 +283,9 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } => false,
+            // predicates on the group keys select whole groups
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -275,6 +306,8 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             RowIndex { .. } => true,
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -286,6 +319,14 @@ impl FunctionNode {
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { args } => Cow::Owned(
+                args.by
+                    .iter()
+                    .chain(std::iter::once(&args.time_column))
+                    .map(|name| Arc::from(name.as_str()))
+                    .collect(),
+            ),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -342,12 +383,16 @@ impl FunctionNode {
                 }
             },
             Rename { existing, new, .. } => rename::rename_impl(df, existing, new),
-            Explode { columns, .. } => df.explode(columns.as_ref()),
+            Explode {
+                columns, mismatch, ..
+            } => df.explode_with(columns.as_ref(), *mismatch),
             Melt { args, .. } => {
                 let args = (**args).clone();
                 df.melt2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { args } => df.fill_gaps(args),
         }
     }
 }
@@ -383,9 +428,17 @@ impl Display for FunctionNode {
             },
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => write!(f, "MERGE SORTED"),
-            Pipeline { original, .. } => {
+            Pipeline {
+                original,
+                maintains_order,
+                ..
+            } => {
                 if let Some(original) = original {
-                    writeln!(f, "--- STREAMING")?;
+                    if *maintains_order {
+                        writeln!(f, "--- STREAMING (order preserved)")?;
+                    } else {
+                        writeln!(f, "--- STREAMING (order not preserved)")?;
+                    }
                     write!(f, "{:?}", original.as_ref())?;
                     let indent = 2;
                     writeln!(f, "{:indent$}--- END STREAMING", "")
@@ -397,6 +450,8 @@ impl Display for FunctionNode {
             Explode { .. } => write!(f, "EXPLODE"),
             Melt { .. } => write!(f, "MELT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
+            #[cfg(feature = "fill_gaps")]
+            FillGaps { args } => write!(f, "FILL GAPS by: {:?}, every: {}", args.by, args.every),
         }
     }
 }
@@ -68,8 +68,12 @@ macro_rules! push_expr {
             Window {
                 function,
                 partition_by,
+                order_by,
                 ..
             } => {
+                if let Some((e, _)) = order_by {
+                    $push(e)
+                }
                 for e in partition_by.into_iter().rev() {
                     $push(e)
                 }
@@ -104,33 +104,41 @@ fn lp_node_equal(a: &ALogicalPlan, b: &ALogicalPlan, expr_arena: &Arena<AExpr>)
         (
             DataFrameScan {
                 df: left_df,
-                projection: None,
-                selection: None,
+                projection: projection_left,
+                selection: selection_left,
                 ..
             },
             DataFrameScan {
                 df: right_df,
-                projection: None,
-                selection: None,
+                projection: projection_right,
+                selection: selection_right,
                 ..
             },
-        ) => Arc::ptr_eq(left_df, right_df),
+        ) => {
+            Arc::ptr_eq(left_df, right_df)
+                && projection_left == projection_right
+                && predicate_equal(*selection_left, *selection_right, expr_arena)
+        },
         (
             Scan {
                 paths: path_left,
                 predicate: predicate_left,
                 scan_type: scan_type_left,
+                file_options: options_left,
                 ..
             },
             Scan {
                 paths: path_right,
                 predicate: predicate_right,
                 scan_type: scan_type_right,
+                file_options: options_right,
                 ..
             },
         ) => {
             path_left == path_right
                 && scan_type_left == scan_type_right
+                // e.g. scans that read a different number of rows are not the same
+                && options_left == options_right
                 && predicate_equal(*predicate_left, *predicate_right, expr_arena)
         },
         (Selection { predicate: l, .. }, Selection { predicate: r, .. }) => {
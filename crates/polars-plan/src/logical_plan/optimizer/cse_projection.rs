@@ -0,0 +1,151 @@
+use super::*;
+use crate::logical_plan::projection_expr::ProjectionExprs;
+
+/// An aliased expression that is computed by a `with_columns` and can be referred to by name
+/// in the projections on top of it.
+struct Projected {
+    expr: Node,
+    name: Arc<str>,
+}
+
+fn is_reusable(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    let mut has_column = false;
+    let refused = expr_arena.iter(node).any(|(_, ae)| match ae {
+        AExpr::Column(_) => {
+            has_column = true;
+            false
+        },
+        AExpr::AnonymousFunction { .. } => true,
+        #[cfg(feature = "random")]
+        AExpr::Function {
+            function: FunctionExpr::Random { .. },
+            ..
+        } => true,
+        _ => false,
+    });
+    // Without a column the expression may produce a scalar, that is broadcasted by
+    // `with_columns`, but not in a `select`.
+    !refused && has_column && aexpr_is_elementwise(node, expr_arena)
+}
+
+/// The names of the columns an expression of a `with_columns` may (over)write.
+fn output_names(node: Node, expr_arena: &Arena<AExpr>) -> Vec<Arc<str>> {
+    match expr_arena.get(node) {
+        AExpr::Alias(_, name) => vec![name.clone()],
+        _ => aexpr_to_leaf_names(node, expr_arena),
+    }
+}
+
+/// Collect the expressions of the chain of `with_columns` starting at `node` that are still
+/// valid in the projection on top of that chain.
+fn collect_projected(
+    mut node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<Projected> {
+    let mut projected = vec![];
+    // columns that are overwritten by the `with_columns` above the current one
+    let mut overwritten = PlHashSet::new();
+    while let ALogicalPlan::HStack { input, exprs, .. } = lp_arena.get(node) {
+        for &e in exprs.iter() {
+            if let AExpr::Alias(expr, name) = expr_arena.get(e) {
+                if !overwritten.contains(name)
+                    && !matches!(expr_arena.get(*expr), AExpr::Column(_))
+                    && is_reusable(*expr, expr_arena)
+                {
+                    projected.push(Projected {
+                        expr: *expr,
+                        name: name.clone(),
+                    });
+                }
+            }
+        }
+        overwritten.extend(exprs.iter().flat_map(|e| output_names(*e, expr_arena)));
+        // the columns an expression refers to must still be the same in the projection
+        projected.retain(|p| {
+            aexpr_to_leaf_names_iter(p.expr, expr_arena).all(|name| !overwritten.contains(&name))
+        });
+        node = *input;
+    }
+    projected
+}
+
+fn replace_projected(
+    node: Node,
+    projected: &[Projected],
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Node> {
+    if let Some(p) = projected
+        .iter()
+        .find(|p| AExpr::is_equal(node, p.expr, expr_arena))
+    {
+        return Some(expr_arena.add(AExpr::Column(p.name.clone())));
+    }
+    let ae = expr_arena.get(node).clone();
+    let mut inputs = Vec::with_capacity(2);
+    ae.nodes(&mut inputs);
+    let mut changed = false;
+    for input in inputs.iter_mut() {
+        if let Some(new) = replace_projected(*input, projected, expr_arena) {
+            *input = new;
+            changed = true;
+        }
+    }
+    changed.then(|| expr_arena.add(ae.replace_inputs(&inputs)))
+}
+
+fn replace_in_exprs(
+    exprs: &ProjectionExprs,
+    projected: &[Projected],
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<ProjectionExprs> {
+    let mut changed = false;
+    let exprs = exprs
+        .iter()
+        .map(|&e| match replace_projected(e, projected, expr_arena) {
+            Some(new) => {
+                changed = true;
+                new
+            },
+            None => e,
+        })
+        .collect::<ProjectionExprs>();
+    changed.then_some(exprs)
+}
+
+/// Replace subexpressions of a `select`/`with_columns` that are already computed and aliased
+/// by a `with_columns` below it with a reference to that column.
+///
+/// E.g. `with_columns([expensive.alias("x")]).select([expensive + 1])` evaluates `expensive`
+/// only once: `with_columns([expensive.alias("x")]).select([col("x") + 1])`.
+///
+/// This must run before projection pushdown, which may prune the reused columns otherwise.
+pub(super) fn reuse_projected_exprs(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+) {
+    scratch.clear();
+    scratch.push(root);
+    while let Some(node) = scratch.pop() {
+        let lp = lp_arena.get(node);
+        lp.copy_inputs(scratch);
+        let (ALogicalPlan::Projection { input, .. } | ALogicalPlan::HStack { input, .. }) = lp
+        else {
+            continue;
+        };
+        let projected = collect_projected(*input, lp_arena, expr_arena);
+        if projected.is_empty() {
+            continue;
+        }
+        match lp_arena.get_mut(node) {
+            ALogicalPlan::Projection { expr: exprs, .. } | ALogicalPlan::HStack { exprs, .. } => {
+                if let Some(new) = replace_in_exprs(exprs, &projected, expr_arena) {
+                    *exprs = new;
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
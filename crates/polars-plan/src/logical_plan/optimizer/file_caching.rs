@@ -64,7 +64,7 @@ pub fn collect_fingerprints(
             predicate,
             scan_type,
             ..
-        } => {
+        } if scan_type.is_identified_by_paths() => {
             let slice = (scan_type.skip_rows(), options.n_rows);
             let predicate = predicate.map(|node| node_to_expr(node, expr_arena));
             let fp = FileFingerPrint {
@@ -102,7 +102,7 @@ pub fn find_column_union_and_fingerprints(
             file_info,
             scan_type,
             ..
-        } => {
+        } if scan_type.is_identified_by_paths() => {
             let slice = (scan_type.skip_rows(), options.n_rows);
             let predicate = predicate.map(|node| node_to_expr(node, expr_arena));
             process_with_columns(
@@ -210,7 +210,7 @@ impl FileCacher {
                     output_schema,
                     scan_type,
                     file_options: mut options,
-                } => {
+                } if scan_type.is_identified_by_paths() => {
                     let predicate_expr = predicate.map(|node| node_to_expr(node, expr_arena));
                     let finger_print = FileFingerPrint {
                         paths,
@@ -0,0 +1,57 @@
+use super::*;
+
+/// Fuse a filter on a boolean aggregation into the aggregation itself, like a SQL `HAVING`
+/// clause. The groups that are filtered out are then dropped before the other aggregations
+/// are computed.
+pub(super) struct FuseHaving {}
+
+impl OptimizationRule for FuseHaving {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let ALogicalPlan::Selection { input, predicate } = lp_arena.get(node) else {
+            return None;
+        };
+        let AExpr::Column(name) = expr_arena.get(*predicate) else {
+            return None;
+        };
+        let ALogicalPlan::Aggregate {
+            input,
+            keys,
+            aggs,
+            schema,
+            apply: None,
+            maintain_order,
+            options,
+        } = lp_arena.get(*input)
+        else {
+            return None;
+        };
+        #[cfg(feature = "dynamic_group_by")]
+        if options.dynamic.is_some() || options.rolling.is_some() {
+            return None;
+        }
+        if options.having.is_some() {
+            return None;
+        }
+        let (idx, _, dtype) = schema.get_full(name)?;
+        if idx < keys.len() || dtype != &DataType::Boolean {
+            return None;
+        }
+
+        let mut options = options.as_ref().clone();
+        options.having = Some(name.clone());
+        Some(ALogicalPlan::Aggregate {
+            input: *input,
+            keys: keys.clone(),
+            aggs: aggs.clone(),
+            schema: schema.clone(),
+            apply: None,
+            maintain_order: *maintain_order,
+            options: Arc::new(options),
+        })
+    }
+}
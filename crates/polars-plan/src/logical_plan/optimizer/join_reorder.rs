@@ -0,0 +1,236 @@
+use polars_core::prelude::*;
+use polars_ops::prelude::JoinValidation;
+
+use super::*;
+use crate::logical_plan::builder_alp::ALogicalPlanBuilder;
+
+// The fraction of the rows that is assumed to pass a filter.
+const FILTER_SELECTIVITY: f64 = 0.9;
+
+/// An inner join of the accumulated left-hand side with a relation.
+struct JoinLink {
+    input: Node,
+    left_on: Vec<Node>,
+    right_on: Vec<Node>,
+    options: Arc<JoinOptions>,
+    // names of the key columns
+    left_keys: Vec<Arc<str>>,
+    right_keys: Vec<Arc<str>>,
+    estimated_rows: usize,
+}
+
+fn is_reorderable(options: &JoinOptions) -> bool {
+    matches!(options.args.how, JoinType::Inner)
+        && options.args.slice.is_none()
+        && options.args.validation == JoinValidation::ManyToMany
+        // the hints refer to the sides of the join as written
+        && options.args.hints.is_empty()
+}
+
+fn column_names(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<str>>> {
+    nodes
+        .iter()
+        .map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Estimate the number of rows a relation produces from the metadata of the files it scans.
+fn estimated_rows(node: Node, lp_arena: &Arena<ALogicalPlan>) -> Option<usize> {
+    use ALogicalPlan::*;
+    let filtered = |rows: usize| (rows as f64 * FILTER_SELECTIVITY) as usize;
+    match lp_arena.get(node) {
+        Scan {
+            file_info,
+            file_options,
+            predicate,
+            ..
+        } => {
+            let (known_size, estimated_size) = file_info.row_estimation;
+            let mut rows = known_size.or_else(|| {
+                (estimated_size > 0 && estimated_size < usize::MAX).then_some(estimated_size)
+            })?;
+            if let Some(n_rows) = file_options.n_rows {
+                rows = std::cmp::min(rows, n_rows);
+            }
+            if predicate.is_some() {
+                rows = filtered(rows);
+            }
+            Some(rows)
+        },
+        Selection { input, .. } => estimated_rows(*input, lp_arena).map(filtered),
+        Slice { input, len, .. } => {
+            estimated_rows(*input, lp_arena).map(|rows| std::cmp::min(rows, *len as usize))
+        },
+        Projection { input, .. } | HStack { input, .. } | Cache { input, .. } => {
+            estimated_rows(*input, lp_arena)
+        },
+        _ => None,
+    }
+}
+
+/// Reorder the chain of inner joins that ends in `root`, such that the relations with the
+/// fewest estimated rows are joined first. The output columns are projected in the original
+/// order.
+///
+/// Returns the relations of the chain if `root` is a chain of inner joins.
+fn reorder_join_chain(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Vec<Node>> {
+    // collect the left-deep chain of inner joins
+    let mut links = vec![];
+    let mut current = root;
+    while let ALogicalPlan::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(current)
+    {
+        if !is_reorderable(options) {
+            break;
+        }
+        links.push((
+            *input_right,
+            left_on.clone(),
+            right_on.clone(),
+            options.clone(),
+        ));
+        current = *input_left;
+    }
+    let base = current;
+    if links.is_empty() {
+        return None;
+    }
+    links.reverse();
+    let mut relations = Vec::with_capacity(links.len() + 1);
+    relations.push(base);
+    relations.extend(links.iter().map(|link| link.0));
+
+    if links.len() < 2 {
+        return Some(relations);
+    }
+
+    let links = links
+        .into_iter()
+        .map(|(input, left_on, right_on, options)| {
+            Some(JoinLink {
+                left_keys: column_names(&left_on, expr_arena)?,
+                right_keys: column_names(&right_on, expr_arena)?,
+                estimated_rows: estimated_rows(input, lp_arena)?,
+                input,
+                left_on,
+                right_on,
+                options,
+            })
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(links) = links else {
+        return Some(relations);
+    };
+
+    // the columns every relation adds to the output. The right key columns are dropped.
+    let base_schema = lp_arena.get(base).schema(lp_arena).into_owned();
+    let added_columns = links
+        .iter()
+        .map(|link| {
+            let schema = lp_arena.get(link.input).schema(lp_arena);
+            schema
+                .iter_names()
+                .filter(|name| {
+                    !link
+                        .right_keys
+                        .iter()
+                        .any(|key| key.as_ref() == name.as_str())
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // if a column name occurs in multiple relations it gets a suffix that depends on the
+    // join order, we don't reorder those chains.
+    let mut all_columns = PlHashSet::with_capacity(base_schema.len() * (links.len() + 1));
+    for name in base_schema
+        .iter_names()
+        .chain(added_columns.iter().flatten())
+    {
+        if !all_columns.insert(name.clone()) {
+            return Some(relations);
+        }
+    }
+
+    // greedily join the smallest relation of which the left keys are available
+    let mut available = base_schema.iter_names().cloned().collect::<PlHashSet<_>>();
+    let mut remaining = (0..links.len()).collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(links.len());
+    while !remaining.is_empty() {
+        let Some((pos, _)) = remaining
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| {
+                links[**i]
+                    .left_keys
+                    .iter()
+                    .all(|key| available.contains(key.as_ref()))
+            })
+            .min_by_key(|(_, i)| links[**i].estimated_rows)
+        else {
+            return Some(relations);
+        };
+        let i = remaining.remove(pos);
+        available.extend(added_columns[i].iter().cloned());
+        order.push(i);
+    }
+    if order.iter().enumerate().all(|(pos, i)| pos == *i) {
+        return Some(relations);
+    }
+
+    let original_schema = lp_arena.get(root).schema(lp_arena).into_owned();
+    let exprs = original_schema
+        .iter_names()
+        .map(|name| expr_arena.add(AExpr::Column(Arc::from(name.as_str()))))
+        .collect::<Vec<_>>();
+
+    let mut builder = ALogicalPlanBuilder::new(base, expr_arena, lp_arena);
+    for i in order {
+        let link = &links[i];
+        builder = builder.join(
+            link.input,
+            link.left_on.clone(),
+            link.right_on.clone(),
+            link.options.clone(),
+        );
+    }
+    let lp = builder.project(exprs, Default::default()).build();
+    lp_arena.replace(root, lp);
+    Some(relations)
+}
+
+/// Reorder chains of inner joins, such that the relations with the fewest estimated rows are
+/// joined first. This keeps the intermediate results small.
+///
+/// The row counts are estimated from file metadata (e.g. the number of rows of a Parquet file
+/// or an estimate based on the size of a CSV file), chains with relations of which the size
+/// is unknown are not reordered.
+pub(super) fn reorder_joins(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+) {
+    scratch.clear();
+    scratch.push(root);
+    while let Some(node) = scratch.pop() {
+        match reorder_join_chain(node, lp_arena, expr_arena) {
+            Some(relations) => scratch.extend(relations),
+            None => lp_arena.get(node).copy_inputs(scratch),
+        }
+    }
+}
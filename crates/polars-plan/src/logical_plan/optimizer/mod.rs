@@ -12,6 +12,8 @@ mod drop_nulls;
 mod collect_members;
 #[cfg(feature = "cse")]
 mod cse_expr;
+#[cfg(feature = "cse")]
+mod cse_projection;
 mod fast_projection;
 #[cfg(any(
     feature = "ipc",
@@ -22,8 +24,10 @@ mod fast_projection;
 ))]
 pub(crate) mod file_caching;
 mod flatten_union;
+mod fuse_having;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -31,6 +35,8 @@ mod simplify_functions;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+#[cfg(all(feature = "ipc", feature = "dtype-struct"))]
+mod struct_field_pushdown;
 mod type_coercion;
 
 use delay_rechunk::DelayRechunk;
@@ -38,6 +44,7 @@ use drop_nulls::ReplaceDropNulls;
 use fast_projection::FastProjectionAndCollapse;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use file_caching::{find_column_union_and_fingerprints, FileCacher};
+use fuse_having::FuseHaving;
 use polars_io::predicates::PhysicalIoExpr;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
@@ -66,6 +73,15 @@ pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
 
+/// Enter a `tracing` span for an optimization pass that lasts until the end of the block.
+macro_rules! pass_span {
+    ($pass:literal) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("optimization_pass", pass = $pass).entered();
+    };
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip_all))]
 pub fn optimize(
     logical_plan: LogicalPlan,
     opt_state: OptState,
@@ -82,6 +98,7 @@ pub fn optimize(
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
+    let join_reorder = opt_state.join_reorder;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
@@ -114,6 +131,7 @@ pub fn optimize(
 
     #[cfg(feature = "cse")]
     let cse_plan_changed = if comm_subplan_elim {
+        pass_span!("comm_subplan_elim");
         let (lp, changed) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
         lp_top = lp;
         members.has_cache |= changed;
@@ -124,6 +142,19 @@ pub fn optimize(
     #[cfg(not(feature = "cse"))]
     let cse_plan_changed = false;
 
+    // should be run before the pushdowns, so that they optimize the reordered joins
+    if join_reorder && !eager {
+        pass_span!("join_reorder");
+        join_reorder::reorder_joins(lp_top, lp_arena, expr_arena, scratch);
+    }
+
+    // should be run before projection pushdown, as that prunes the columns that are reused
+    #[cfg(feature = "cse")]
+    if comm_subexpr_elim && !eager && !members.has_ext_context {
+        pass_span!("reuse_projected_exprs");
+        cse_projection::reuse_projected_exprs(lp_top, lp_arena, expr_arena, scratch);
+    }
+
     // we do simplification
     if simplify_expr {
         rules.push(Box::new(SimplifyExprRule {}));
@@ -133,6 +164,7 @@ pub fn optimize(
 
     // should be run before predicate pushdown
     if projection_pushdown {
+        pass_span!("projection_pushdown");
         let mut projection_pushdown_opt = ProjectionPushDown::new();
         let alp = lp_arena.take(lp_top);
         let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -144,12 +176,18 @@ pub fn optimize(
     }
 
     if predicate_pushdown {
+        pass_span!("predicate_pushdown");
         let predicate_pushdown_opt = PredicatePushDown::new(hive_partition_eval);
         let alp = lp_arena.take(lp_top);
         let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
         lp_arena.replace(lp_top, alp);
     }
 
+    // the streaming engine aggregates in a different node
+    if predicate_pushdown && !streaming {
+        rules.push(Box::new(FuseHaving {}));
+    }
+
     // make sure its before slice pushdown.
     if fast_projection {
         rules.push(Box::new(FastProjectionAndCollapse::new(eager)));
@@ -160,6 +198,7 @@ pub fn optimize(
     }
 
     if slice_pushdown {
+        pass_span!("slice_pushdown");
         let slice_pushdown_opt = SlicePushDown::new(streaming);
         let alp = lp_arena.take(lp_top);
         let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -183,6 +222,7 @@ pub fn optimize(
     // the file fingerprints are finished.
     #[cfg(any(feature = "cse", feature = "parquet", feature = "ipc", feature = "csv"))]
     if agg_scan_projection || cse_plan_changed {
+        pass_span!("file_caching");
         // we do this so that expressions are simplified created by the pushdown optimizations
         // we must clean up the predicates, because the agg_scan_projection
         // uses them in the hashtable to determine duplicates.
@@ -214,11 +254,22 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
-    lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    {
+        pass_span!("expression_rules");
+        lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    }
+
+    // must run after file caching, as the struct fields of shared scans can't be pruned
+    #[cfg(all(feature = "ipc", feature = "dtype-struct"))]
+    if projection_pushdown && !eager {
+        pass_span!("struct_field_pushdown");
+        struct_field_pushdown::push_down_struct_fields(lp_top, lp_arena, expr_arena, scratch);
+    }
 
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !members.has_ext_context {
+        pass_span!("comm_subexpr_elim");
         let mut optimizer = CommonSubExprOptimizer::new(expr_arena);
         lp_top = ALogicalPlanNode::with_context(lp_top, lp_arena, |alp_node| {
             alp_node.rewrite(&mut optimizer)
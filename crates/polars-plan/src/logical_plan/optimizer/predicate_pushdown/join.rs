@@ -66,6 +66,21 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     }
 }
 
+/// Whether the predicate can only become `false` or `null` if some of its inputs are replaced by
+/// nulls (its truth value is monotone under three-valued logic).
+///
+/// A join that null-extends one side (e.g. the right side of a left join) turns a row that fails
+/// such a predicate into a null-extended row that fails it as well. Therefore the predicate can
+/// be pushed down to that side, as long as it is also applied after the join.
+fn is_null_monotone(predicate: Node, expr_arena: &Arena<AExpr>) -> bool {
+    use AExpr::*;
+    expr_arena.iter(predicate).all(|(_, ae)| match ae {
+        Column(_) | Literal(_) | Alias(_, _) | Cast { .. } => true,
+        BinaryExpr { op, .. } => !matches!(op, Operator::EqValidity | Operator::NotEqValidity),
+        _ => false,
+    })
+}
+
 fn all_pred_cols_in_left_on(
     predicate: Node,
     expr_arena: &mut Arena<AExpr>,
@@ -104,13 +119,23 @@ pub(super) fn process_join(
 
     for (_, predicate) in acc_predicates {
         // check if predicate can pass the joins node
-        let block_pushdown_left = has_aexpr(predicate, expr_arena, |ae| {
+        let mut block_pushdown_left = has_aexpr(predicate, expr_arena, |ae| {
             should_block_join_specific(ae, &options.args.how).0
         });
-        let block_pushdown_right = has_aexpr(predicate, expr_arena, |ae| {
+        let mut block_pushdown_right = has_aexpr(predicate, expr_arena, |ae| {
             should_block_join_specific(ae, &options.args.how).1
         });
 
+        // a side that is null-extended by the join still accepts a null monotone predicate,
+        // but then the predicate must also be applied after the join.
+        let null_monotone = matches!(options.args.how, JoinType::Left | JoinType::Outer { .. })
+            && is_null_monotone(predicate, expr_arena);
+        let null_extended = join_produces_null(&options.args.how);
+        if null_monotone {
+            block_pushdown_left &= !null_extended.0;
+            block_pushdown_right &= !null_extended.1;
+        }
+
         // these indicate to which tables we are going to push down the predicate
         let mut filter_left = false;
         let mut filter_right = false;
@@ -160,6 +185,13 @@ pub(super) fn process_join(
             // business as usual
             _ => {}
         }
+        // the null-extended side was filtered, apply the predicate after the join as well
+        if null_monotone
+            && ((filter_left && null_extended.0)
+                || (filter_right && !filter_left && null_extended.1))
+        {
+            local_predicates.push(predicate);
+        }
     }
 
     opt.pushdown_and_assign(input_left, pushdown_left, lp_arena, expr_arena)?;
@@ -4,6 +4,8 @@ mod keys;
 mod rename;
 mod utils;
 
+use std::path::Path;
+
 use polars_core::config::verbose;
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
@@ -299,7 +301,7 @@ impl<'a> PredicatePushDown<'a> {
                     debug_assert_aexpr_allows_predicate_pushdown(*node, expr_arena);
                 }
 
-                let local_predicates = match &scan_type {
+                let mut local_predicates = match &scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => vec![],
                     #[cfg(feature = "ipc")]
@@ -318,19 +320,36 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     }
                 };
+                // The file path column is added after the reader applied the predicate.
+                if let Some(file_path_column) = options.include_file_paths.as_deref() {
+                    local_predicates.extend(transfer_to_local_by_name(expr_arena, &mut acc_predicates, |name| {
+                        name.as_ref() == file_path_column
+                    }));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 if let (true, Some(predicate)) = (file_info.hive_parts.is_some(), predicate) {
                     if let Some(io_expr) = self.hive_partition_eval.unwrap()(predicate, expr_arena) {
                         if let Some(stats_evaluator) = io_expr.as_stats_evaluator() {
                             let mut new_paths = Vec::with_capacity(paths.len());
-
+                            // All files in a partition directory have the same partition values,
+                            // so we decide once per directory whether its files are read.
+                            let mut previous_dir: Option<(&Path, bool)> = None;
 
                             for path in paths.as_ref().iter() {
-                                file_info.update_hive_partitions(path)?;
-                                let hive_part_stats = file_info.hive_parts.as_deref().ok_or_else(|| polars_err!(ComputeError: "cannot combine hive partitioned directories with non-hive partitioned ones"))?;
+                                let dir = path.parent().filter(|_| !path.file_name().is_some_and(|name| name.to_string_lossy().contains('=')));
+                                let should_read = match (previous_dir, dir) {
+                                    (Some((previous_dir, should_read)), Some(dir)) if previous_dir == dir => should_read,
+                                    _ => {
+                                        file_info.update_hive_partitions(path)?;
+                                        let hive_part_stats = file_info.hive_parts.as_deref().ok_or_else(|| polars_err!(ComputeError: "cannot combine hive partitioned directories with non-hive partitioned ones"))?;
+                                        let should_read = stats_evaluator.should_read(hive_part_stats.get_statistics())?;
+                                        previous_dir = dir.map(|dir| (dir, should_read));
+                                        should_read
+                                    },
+                                };
 
-                                if stats_evaluator.should_read(hive_part_stats.get_statistics())? {
+                                if should_read {
                                     new_paths.push(path.clone());
                                 }
                             }
@@ -340,8 +359,9 @@ impl<'a> PredicatePushDown<'a> {
                                     eprintln!("hive partitioning: skipped {} files, first file : {}", paths.len() - new_paths.len(), paths[0].display())
                                 }
                                 scan_type.remove_metadata();
+                                file_info.pruned_files += paths.len() - new_paths.len();
                             }
-                            if paths.is_empty() {
+                            if new_paths.is_empty() {
                                 let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
                                 let df = DataFrame::from(schema.as_ref());
 
@@ -511,6 +531,16 @@ impl<'a> PredicatePushDown<'a> {
                             Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
 
                         }
+                        #[cfg(feature = "fill_gaps")]
+                        FunctionNode::FillGaps { args } => {
+                            // only predicates on the group keys can be done before the gaps are filled
+                            let condition = |name: Arc<str>| !args.by.iter().any(|s| s.as_str() == &*name);
+                            let local_predicates =
+                                transfer_to_local_by_name(expr_arena, &mut acc_predicates, condition);
+
+                            let lp = self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)?;
+                            Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                        }
                         _ => {
                             self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                         }
@@ -600,6 +630,11 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     }
                     Ok(PythonScan {options, predicate})
+                } else if options.io_plugin {
+                    // an IO plugin receives the serialized predicate to prune data at the source,
+                    // the predicate is applied again after the scan.
+                    let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
+                    Ok(PythonScan {options, predicate})
                 } else {
                     self.no_pushdown_restart_opt(PythonScan {options, predicate}, acc_predicates, lp_arena, expr_arena)
                 }
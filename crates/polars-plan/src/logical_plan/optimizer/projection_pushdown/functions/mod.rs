@@ -45,7 +45,9 @@ pub(super) fn process_functions(
             };
             Ok(lp)
         },
-        Explode { columns, .. } => {
+        Explode {
+            columns, mismatch, ..
+        } => {
             columns.iter().for_each(|name| {
                 add_str_to_accumulated(name, &mut acc_projections, &mut projected_names, expr_arena)
             });
@@ -58,7 +60,7 @@ pub(super) fn process_functions(
                 expr_arena,
             )?;
             Ok(ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
-                .explode(columns.clone())
+                .explode(columns.clone(), *mismatch)
                 .build())
         },
         Melt { args, .. } => {
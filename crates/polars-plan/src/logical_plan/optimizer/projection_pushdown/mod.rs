@@ -42,20 +42,18 @@ fn get_scan_columns(
     acc_projections: &mut Vec<Node>,
     expr_arena: &Arena<AExpr>,
     row_index: Option<&RowIndex>,
+    file_path_column: Option<&str>,
 ) -> Option<Arc<Vec<String>>> {
     let mut with_columns = None;
     if !acc_projections.is_empty() {
         let mut columns = Vec::with_capacity(acc_projections.len());
         for expr in acc_projections {
             for name in aexpr_to_leaf_names(*expr, expr_arena) {
-                // we shouldn't project the row-count column, as that is generated
-                // in the scan
-                let push = match row_index {
-                    Some(rc) if name.as_ref() != rc.name.as_str() => true,
-                    None => true,
-                    _ => false,
-                };
-                if push {
+                // we shouldn't project the row-count and file path columns, as those are
+                // generated in the scan
+                let is_row_index = row_index.map_or(false, |rc| name.as_ref() == rc.name.as_str());
+                let is_file_path = file_path_column == Some(name.as_ref());
+                if !is_row_index && !is_file_path {
                     columns.push((*name).to_owned())
                 }
             }
@@ -348,7 +346,7 @@ impl ProjectionPushDown {
                         &schema,
                         false,
                     )?));
-                    projection = get_scan_columns(&mut acc_projections, expr_arena, None);
+                    projection = get_scan_columns(&mut acc_projections, expr_arena, None, None);
                 }
                 let lp = DataFrameScan {
                     df,
@@ -364,7 +362,8 @@ impl ProjectionPushDown {
                 mut options,
                 predicate,
             } => {
-                options.with_columns = get_scan_columns(&mut acc_projections, expr_arena, None);
+                options.with_columns =
+                    get_scan_columns(&mut acc_projections, expr_arena, None, None);
 
                 options.output_schema = if options.with_columns.is_none() {
                     None
@@ -397,6 +396,7 @@ impl ProjectionPushDown {
                         &mut acc_projections,
                         expr_arena,
                         file_options.row_index.as_ref(),
+                        file_options.include_file_paths.as_deref(),
                     );
 
                     output_schema = if file_options.with_columns.is_none() {
@@ -419,6 +419,12 @@ impl ProjectionPushDown {
                                 }
                             }
                         }
+                        // The file path column is added after the hive partitions.
+                        if let Some(name) = file_options.include_file_paths.as_deref() {
+                            if let Some(dt) = schema.shift_remove(name) {
+                                schema.with_column(name.into(), dt);
+                            }
+                        }
                         Some(Arc::new(schema))
                     };
                 }
@@ -173,6 +173,7 @@ impl SlicePushDown {
                 }
                 Ok(Union {inputs, options})
             },
+            // In streaming only the inner join can stop early, the other joins block.
             (Join {
                 input_left,
                 input_right,
@@ -180,7 +181,7 @@ impl SlicePushDown {
                 left_on,
                 right_on,
                 mut options
-            }, Some(state)) if !self.streaming => {
+            }, Some(state)) if !self.streaming || matches!(options.args.how, JoinType::Inner) => {
                 // first restart optimization in both inputs and get the updated LP
                 let lp_left = lp_arena.take(input_left);
                 let lp_left = self.pushdown(lp_left, None, lp_arena, expr_arena)?;
@@ -284,6 +285,12 @@ impl SlicePushDown {
                 });
                 self.pushdown(alp, state, lp_arena, expr_arena)
             }
+            // the rows depend on all rows of the group
+            #[cfg(feature = "fill_gaps")]
+            m @ (MapFunction {function: FunctionNode::FillGaps {..}, ..}, _) => {
+                let (lp, state) = m;
+                self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+            }
             // [Do not pushdown] boundary
             // here we do not pushdown.
             // we reset the state and then start the optimization again
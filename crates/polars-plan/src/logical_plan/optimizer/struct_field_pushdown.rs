@@ -0,0 +1,188 @@
+use polars_core::prelude::*;
+
+use super::*;
+use crate::dsl::function_expr::{FunctionExpr, StructFunction};
+
+/// How a struct column of a scan is used by the expressions on top of it.
+enum StructUse {
+    /// Only the fields with these names are accessed.
+    Fields(Vec<Arc<str>>),
+    /// The column itself is used.
+    Full,
+}
+
+/// Record how the columns are used in the expression `root`.
+fn collect_uses(
+    root: Node,
+    expr_arena: &Arena<AExpr>,
+    uses: &mut PlHashMap<Arc<str>, StructUse>,
+    stack: &mut Vec<Node>,
+) {
+    stack.clear();
+    stack.push(root);
+    while let Some(node) = stack.pop() {
+        let ae = expr_arena.get(node);
+        match ae {
+            AExpr::Function {
+                input,
+                function: FunctionExpr::StructExpr(StructFunction::FieldByName(field)),
+                ..
+            } if input.len() == 1 => {
+                if let AExpr::Column(name) = expr_arena.get(input[0]) {
+                    match uses.get_mut(name) {
+                        Some(StructUse::Fields(fields)) => {
+                            if !fields.contains(field) {
+                                fields.push(field.clone())
+                            }
+                        },
+                        Some(StructUse::Full) => {},
+                        None => {
+                            uses.insert(name.clone(), StructUse::Fields(vec![field.clone()]));
+                        },
+                    }
+                } else {
+                    ae.nodes(stack)
+                }
+            },
+            AExpr::Column(name) => {
+                uses.insert(name.clone(), StructUse::Full);
+            },
+            _ => ae.nodes(stack),
+        }
+    }
+}
+
+/// Prune the fields of the struct columns of `schema` that are only accessed by the fields in
+/// `uses`. Returns the pruned schema and the fields to read per struct column.
+fn prune_schema(
+    schema: &Schema,
+    uses: PlHashMap<Arc<str>, StructUse>,
+) -> Option<(Schema, Vec<(String, Vec<String>)>)> {
+    let mut schema = schema.clone();
+    let mut struct_fields = vec![];
+    for (name, struct_use) in uses {
+        let StructUse::Fields(used) = struct_use else {
+            continue;
+        };
+        let Some(DataType::Struct(fields)) = schema.get(&name) else {
+            continue;
+        };
+        // keep the fields in the order of the file
+        let pruned = fields
+            .iter()
+            .filter(|fld| used.iter().any(|used| used.as_ref() == fld.name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        // a missing field is reported by the expression
+        if pruned.len() == fields.len() || pruned.len() != used.len() {
+            continue;
+        }
+        struct_fields.push((
+            name.to_string(),
+            pruned.iter().map(|fld| fld.name.to_string()).collect(),
+        ));
+        schema.set_dtype(&name, DataType::Struct(pruned));
+    }
+    (!struct_fields.is_empty()).then_some((schema, struct_fields))
+}
+
+/// Push the accessed fields of struct columns into the IPC scans below projections, so that
+/// the other fields of those columns are never read.
+///
+/// This must run after the file caching, as scans that are shared can't be pruned.
+pub(super) fn push_down_struct_fields(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        lp.copy_inputs(&mut stack);
+
+        let ALogicalPlan::Projection { input, expr, .. } = lp else {
+            continue;
+        };
+        let scan_node = *input;
+        let ALogicalPlan::Scan {
+            scan_type: FileScan::Ipc { options },
+            file_options,
+            predicate,
+            ..
+        } = lp_arena.get(scan_node)
+        else {
+            continue;
+        };
+        if options.struct_fields.is_some() || file_options.file_counter > 1 {
+            continue;
+        }
+
+        let mut uses = PlHashMap::new();
+        for e in expr.iter().chain(predicate.iter()) {
+            collect_uses(*e, expr_arena, &mut uses, scratch);
+        }
+        let schema = lp_arena.get(scan_node).schema(lp_arena);
+        let Some((schema, struct_fields)) = prune_schema(&schema, uses) else {
+            continue;
+        };
+
+        if let ALogicalPlan::Scan {
+            scan_type: FileScan::Ipc { options },
+            output_schema,
+            ..
+        } = lp_arena.get_mut(scan_node)
+        {
+            options.struct_fields = Some(struct_fields);
+            *output_schema = Some(Arc::new(schema));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_prune_schema() {
+        let s = DataType::Struct(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("y", DataType::String),
+            Field::new("z", DataType::Boolean),
+        ]);
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Int64),
+            Field::new("s", s.clone()),
+            Field::new("t", s),
+        ]);
+
+        let mut uses = PlHashMap::new();
+        uses.insert(
+            Arc::from("s"),
+            StructUse::Fields(vec![Arc::from("z"), Arc::from("x")]),
+        );
+        uses.insert(Arc::from("t"), StructUse::Full);
+        uses.insert(Arc::from("a"), StructUse::Full);
+        let (pruned, struct_fields) = prune_schema(&schema, uses).unwrap();
+        assert_eq!(
+            struct_fields,
+            [("s".to_string(), vec!["x".to_string(), "z".to_string()])]
+        );
+        assert_eq!(
+            pruned.get("s"),
+            Some(&DataType::Struct(vec![
+                Field::new("x", DataType::Int32),
+                Field::new("z", DataType::Boolean),
+            ]))
+        );
+        assert_eq!(pruned.get("t"), schema.get("t"));
+
+        // a field that doesn't exist is left to the expression to report
+        let mut uses = PlHashMap::new();
+        uses.insert(
+            Arc::from("s"),
+            StructUse::Fields(vec![Arc::from("x"), Arc::from("missing")]),
+        );
+        assert!(prune_schema(&schema, uses).is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use polars_core::utils::temporal_coercion;
 use polars_utils::matches_any_order;
 
 use super::*;
@@ -29,17 +30,6 @@ fn compares_cat_to_string(type_left: &DataType, type_right: &DataType, op: Opera
     }
 }
 
-#[allow(unused_variables)]
-fn is_datetime_arithmetic(type_left: &DataType, type_right: &DataType, op: Operator) -> bool {
-    matches!(op, Operator::Minus | Operator::Plus)
-        && matches_any_order!(
-            &type_left,
-            &type_right,
-            DataType::Datetime(_, _) | DataType::Date,
-            DataType::Duration(_)
-        )
-}
-
 #[cfg(feature = "dtype-struct")]
 fn is_struct_numeric_arithmetic(type_left: &DataType, type_right: &DataType, op: Operator) -> bool {
     {
@@ -198,6 +188,18 @@ fn err_date_str_compare() -> PolarsResult<()> {
     }
 }
 
+fn cast_to(expr_arena: &mut Arena<AExpr>, node: Node, dtype: &DataType, to: DataType) -> Node {
+    if dtype == &to {
+        node
+    } else {
+        expr_arena.add(AExpr::Cast {
+            expr: node,
+            data_type: to,
+            strict: false,
+        })
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     lp_arena: &Arena<ALogicalPlan>,
@@ -241,8 +243,21 @@ pub(super) fn process_binary(
         (Struct(_), Struct(_), _op) => return Ok(None),
         _ => {},
     }
+    // Temporal operands are resolved by the same rules as in the eager engine.
+    if let Some(temporal_op) = op.temporal_op() {
+        if let Some(coercion) = temporal_coercion(&type_left, &type_right, temporal_op)? {
+            if type_left == coercion.lhs && type_right == coercion.rhs {
+                return Ok(None);
+            }
+            return Ok(Some(AExpr::BinaryExpr {
+                left: cast_to(expr_arena, node_left, &type_left, coercion.lhs),
+                op,
+                right: cast_to(expr_arena, node_right, &type_right, coercion.rhs),
+            }));
+        }
+    }
+
     let compare_cat_to_string = compares_cat_to_string(&type_left, &type_right, op);
-    let datetime_arithmetic = is_datetime_arithmetic(&type_left, &type_right, op);
     let list_arithmetic = is_list_arithmetic(&type_left, &type_right, op);
     str_numeric_arithmetic(&type_left, &type_right)?;
 
@@ -265,10 +280,7 @@ pub(super) fn process_binary(
     }
 
     // All early return paths
-    if compare_cat_to_string
-        || datetime_arithmetic
-        || early_escape(&type_left, &type_right).is_none()
-    {
+    if compare_cat_to_string || early_escape(&type_left, &type_right).is_none() {
         Ok(None)
     } else {
         // Coerce types:
@@ -10,7 +10,7 @@ use polars_io::csv::{CommentPrefix, CsvEncoding, NullValues};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcCompression;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::ParquetCompression;
+use polars_io::parquet::{ParquetColumnOptions, ParquetCompression};
 use polars_io::RowIndex;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -37,6 +37,7 @@ pub struct CsvParserOptions {
     pub null_values: Option<NullValues>,
     pub encoding: CsvEncoding,
     pub try_parse_dates: bool,
+    pub decimal_comma: bool,
     pub raise_if_empty: bool,
     pub truncate_ragged_lines: bool,
 }
@@ -48,10 +49,29 @@ pub struct ParquetOptions {
     pub parallel: polars_io::parquet::ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Read many small files per task instead of scheduling every file separately.
+    pub compact_small_files: bool,
+    /// What to do with the columns that are missing in some of the files.
+    pub missing_columns: MissingColumns,
+    /// Whether the schemas of the files differ. If so, the files of which the schema differs
+    /// from the unified schema of the scan are conformed to it after reading.
+    pub schema_drift: bool,
 }
 
+/// What to do if a column exists in some of the files of a scan, but not in all of them.
 #[cfg(feature = "parquet")]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MissingColumns {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Insert the column as nulls in the files that don't have it.
+    InsertNull,
+}
+
+#[cfg(feature = "parquet")]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -60,6 +80,10 @@ pub struct ParquetWriteOptions {
     pub statistics: bool,
     /// If `None` will be all written to a single row group.
     pub row_group_size: Option<usize>,
+    /// Target estimated size of a row group in bytes.
+    pub row_group_size_bytes: Option<usize>,
+    /// Compression, encoding and statistics overrides of some columns.
+    pub column_options: Vec<(String, ParquetColumnOptions)>,
     /// if `None` will be 1024^2 bytes
     pub data_pagesize_limit: Option<usize>,
     /// maintain the order the data was processed
@@ -112,6 +136,19 @@ pub struct JsonWriterOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
     pub memmap: bool,
+    /// The fields to read of struct columns that are only accessed by field. Set by the
+    /// optimizer.
+    pub struct_fields: Option<Vec<(String, Vec<String>)>>,
+}
+
+#[cfg(feature = "flight")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlightScanOptions {
+    /// The location of the Flight server, e.g. `grpc://localhost:8815`.
+    pub endpoint: String,
+    /// The ticket that identifies the stream to read.
+    pub ticket: Vec<u8>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -125,6 +162,8 @@ pub struct FileScanOptions {
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_partitioning: bool,
+    /// Add a column with this name that holds the path of the file a row was read from.
+    pub include_file_paths: Option<Arc<str>>,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq)]
@@ -154,6 +193,11 @@ pub struct GroupbyOptions {
     pub rolling: Option<RollingGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    /// Only keep the groups for which the boolean aggregation with this output name is true.
+    /// The other aggregations are not computed for the groups that are dropped.
+    pub having: Option<Arc<str>>,
+    /// Call the `apply` function of the aggregation on the groups in parallel.
+    pub parallel_apply: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -312,6 +356,8 @@ pub struct PythonOptions {
     pub output_schema: Option<SchemaRef>,
     pub with_columns: Option<Arc<Vec<String>>>,
     pub pyarrow: bool,
+    // an IO plugin receives the pushed down predicate as a JSON serialized expression
+    pub io_plugin: bool,
     // a pyarrow predicate python expression
     // can be evaluated with python.eval
     pub predicate: Option<String>,
@@ -334,6 +380,13 @@ pub enum SinkType {
         path: Arc<PathBuf>,
         file_type: FileType,
     },
+    /// Write the rows of every unique combination of the `by` columns to its own file in a
+    /// Hive partitioned directory (`{path}/{key}={value}/data-0000.{ext}`).
+    Partitioned {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        by: Vec<String>,
+    },
     #[cfg(feature = "cloud")]
     Cloud {
         uri: Arc<String>,
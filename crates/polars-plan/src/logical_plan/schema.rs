@@ -54,6 +54,8 @@ pub struct FileInfo {
     /// - estimated size
     pub row_estimation: (Option<usize>, usize),
     pub hive_parts: Option<Arc<hive::HivePartitions>>,
+    /// The number of files that were removed from the scan by hive partition pruning.
+    pub pruned_files: usize,
 }
 
 impl FileInfo {
@@ -67,6 +69,7 @@ impl FileInfo {
             reader_schema,
             row_estimation,
             hive_parts: None,
+            pruned_files: 0,
         }
     }
 
@@ -103,6 +106,16 @@ impl FileInfo {
         }
         Ok(())
     }
+
+    /// Adds the column with the paths of the files to the end of the schema.
+    pub fn add_file_path_column(&mut self, name: &str) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.schema.contains(name),
+            Duplicate: "cannot add the file path column '{}', the scan already has a column with that name", name
+        );
+        Arc::make_mut(&mut self.schema).with_column(name.into(), DataType::String);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "streaming")]
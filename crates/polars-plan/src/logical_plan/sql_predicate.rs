@@ -0,0 +1,151 @@
+use std::fmt::Write;
+
+use polars_core::datatypes::AnyValue;
+
+use crate::prelude::*;
+
+/// Quote an identifier, so that it keeps its case and may contain any character.
+fn quote_sql_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn literal_to_sql(av: AnyValue) -> Option<String> {
+    let dtype = av.dtype();
+    match av {
+        AnyValue::String(s) => Some(format!("'{}'", s.replace('\'', "''"))),
+        AnyValue::StringOwned(s) => Some(format!("'{}'", s.replace('\'', "''"))),
+        AnyValue::Boolean(v) => Some(if v { "TRUE" } else { "FALSE" }.to_string()),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(_) => Some(format!("DATE '{av}'")),
+        // Without a time zone the meaning of the timestamp would depend on the database.
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(_, _, None) => Some(format!("TIMESTAMP '{av}'")),
+        av => {
+            if dtype.is_float() {
+                let val = av.extract::<f64>()?;
+                val.is_finite().then(|| format!("{val:?}"))
+            } else if dtype.is_integer() {
+                let val = av.extract::<i64>()?;
+                Some(format!("{val}"))
+            } else {
+                None
+            }
+        },
+    }
+}
+
+/// Translate a predicate into a SQL `WHERE` condition.
+///
+/// If `exact` is false, the condition may select more rows than the predicate, which allows
+/// dropping the parts of a conjunction that cannot be translated. Such a condition can only prune
+/// data at the source, the predicate has to be applied again on the result. Only conjunctions
+/// and disjunctions keep selecting more rows if their operands do, everything else needs exact
+/// operands.
+fn to_sql(expr: &Expr, exact: bool) -> Option<String> {
+    match expr {
+        Expr::BinaryExpr { left, op, right } => {
+            let op = match op {
+                Operator::And | Operator::LogicalAnd => {
+                    let left = to_sql(left, exact);
+                    let right = to_sql(right, exact);
+                    return match (left, right) {
+                        (Some(left), Some(right)) => Some(format!("({left} AND {right})")),
+                        (Some(cond), None) | (None, Some(cond)) if !exact => Some(cond),
+                        _ => None,
+                    };
+                },
+                Operator::Or | Operator::LogicalOr => "OR",
+                Operator::Eq => "=",
+                Operator::NotEq => "<>",
+                Operator::Lt => "<",
+                Operator::LtEq => "<=",
+                Operator::Gt => ">",
+                Operator::GtEq => ">=",
+                _ => return None,
+            };
+            let exact = exact || op != "OR";
+            let left = to_sql(left, exact)?;
+            let right = to_sql(right, exact)?;
+            Some(format!("({left} {op} {right})"))
+        },
+        Expr::Column(name) => Some(quote_sql_identifier(name)),
+        Expr::Alias(input, _) => to_sql(input, exact),
+        Expr::Literal(LiteralValue::Series(_)) => None,
+        Expr::Literal(lv) => literal_to_sql(lv.to_anyvalue()?),
+        Expr::Function {
+            input, function, ..
+        } => match function {
+            // Negating a condition that selects too many rows would select too few.
+            FunctionExpr::Boolean(BooleanFunction::Not) => {
+                Some(format!("(NOT {})", to_sql(input.first()?, true)?))
+            },
+            FunctionExpr::Boolean(BooleanFunction::IsNull) => {
+                Some(format!("({} IS NULL)", to_sql(input.first()?, true)?))
+            },
+            FunctionExpr::Boolean(BooleanFunction::IsNotNull) => {
+                Some(format!("({} IS NOT NULL)", to_sql(input.first()?, true)?))
+            },
+            #[cfg(feature = "is_in")]
+            FunctionExpr::Boolean(BooleanFunction::IsIn) => {
+                let column = to_sql(input.first()?, true)?;
+                let Expr::Literal(LiteralValue::Series(s)) = input.get(1)? else {
+                    return None;
+                };
+                // Null values never compare equal in SQL.
+                if s.is_empty() || s.null_count() > 0 {
+                    return None;
+                }
+                let mut values = String::with_capacity(s.len() * 5);
+                for av in s.iter() {
+                    write!(values, "{},", literal_to_sql(av)?).unwrap();
+                }
+                // pop last comma
+                values.pop();
+                Some(format!("({column} IN ({values}))"))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Translate a predicate into a SQL condition that selects at least the rows the predicate
+/// selects. `None` if no part of the predicate can be translated.
+pub(crate) fn predicate_to_sql(predicate: &Expr) -> Option<String> {
+    to_sql(predicate, false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_predicate_to_sql() {
+        let predicate = col("a").gt(lit(1)).and(col("b").eq(lit("it's")));
+        assert_eq!(
+            predicate_to_sql(&predicate).as_deref(),
+            Some(r#"(("a" > 1) AND ("b" = 'it''s'))"#)
+        );
+
+        // The untranslatable part of a conjunction is dropped...
+        let predicate = col("a").lt_eq(lit(2.5)).and((col("b") + lit(1)).gt(lit(3)));
+        assert_eq!(
+            predicate_to_sql(&predicate).as_deref(),
+            Some(r#"("a" <= 2.5)"#)
+        );
+        assert_eq!(
+            predicate_to_sql(&predicate.clone().or(col("c"))).as_deref(),
+            Some(r#"(("a" <= 2.5) OR "c")"#)
+        );
+        // ...but not under a negation or in a comparison.
+        assert_eq!(predicate_to_sql(&predicate.clone().not()), None);
+        assert_eq!(predicate_to_sql(&predicate.eq(lit(false))), None);
+
+        let predicate = col("a").is_null().or(col("c").not());
+        assert_eq!(
+            predicate_to_sql(&predicate).as_deref(),
+            Some(r#"(("a" IS NULL) OR (NOT "c"))"#)
+        );
+        assert_eq!(quote_sql_identifier(r#"say "hi""#), r#""say ""hi""""#);
+    }
+}
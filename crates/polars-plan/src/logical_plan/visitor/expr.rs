@@ -136,7 +136,22 @@ impl AexprNode {
                 (Column(l), Column(r)) => l == r,
                 (Literal(l), Literal(r)) => l == r,
                 (Nth(l), Nth(r)) => l == r,
-                (Window { options: l, .. }, Window { options: r, .. }) => l == r,
+                (
+                    Window {
+                        order_by: order_by_l,
+                        options: l,
+                        ..
+                    },
+                    Window {
+                        order_by: order_by_r,
+                        options: r,
+                        ..
+                    },
+                ) => {
+                    l == r
+                        && order_by_l.map(|(_, options)| options)
+                            == order_by_r.map(|(_, options)| options)
+                },
                 (
                     Cast {
                         strict: strict_l,
@@ -66,6 +66,10 @@ unsafe fn decode(rows: &mut [&[u8]], field: &SortField, data_type: &ArrowDataTyp
                 .collect();
             StructArray::new(data_type.clone(), values, None).to_boxed()
         },
+        // The decimal precision and scale are not part of the row encoding.
+        ArrowDataType::Decimal(_, _) => decode_primitive::<i128>(rows, field)
+            .to(data_type.clone())
+            .to_boxed(),
         dt => {
             with_match_arrow_primitive_type!(dt, |$T| {
                 decode_primitive::<$T>(rows, field).to_boxed()
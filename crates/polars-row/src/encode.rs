@@ -158,6 +158,7 @@ pub fn encoded_size(data_type: &ArrowDataType) -> usize {
         Int16 => i16::ENCODED_LEN,
         Int32 => i32::ENCODED_LEN,
         Int64 => i64::ENCODED_LEN,
+        Decimal(_, _) => i128::ENCODED_LEN,
         Float32 => f32::ENCODED_LEN,
         Float64 => f64::ENCODED_LEN,
         Boolean => bool::ENCODED_LEN,
@@ -397,6 +398,29 @@ mod test {
         assert_eq!(decoded, arr);
     }
 
+    #[test]
+    fn test_decimal_round_trip() {
+        let dtype = ArrowDataType::Decimal(10, 2);
+        let a = PrimitiveArray::<i128>::from([Some(-12345), None, Some(0), Some(i128::MAX)])
+            .to(dtype.clone());
+
+        let fields = &[SortField {
+            descending: false,
+            nulls_last: false,
+        }];
+
+        unsafe {
+            let encoded = convert_columns(&[a.clone().boxed()], fields);
+            let out = decode_rows_from_binary(&encoded.into_array(), fields, &[dtype], &mut vec![]);
+
+            let decoded = out[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            assert_eq!(decoded, &a);
+        }
+    }
+
     #[test]
     fn test_reverse_variable() {
         let a = Utf8Array::<i64>::from_slice(["one", "two", "three", "four", "five", "six"]);
@@ -0,0 +1,173 @@
+//! Parse duration strings into nanoseconds, either in the format used to print a
+//! Duration (e.g. `1d 2h 3m`) or in ISO 8601 (e.g. `P1DT2H3M`).
+//!
+//! Parsing is done in `i128` so that values which don't fit in an `i64` can be
+//! detected after converting to the target time unit.
+use crate::windows::calendar::{
+    NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
+};
+
+/// Parse a duration in the format that is used to display a Duration, e.g. `1d 2h 3m`.
+/// Every component may be negative, e.g. `-1d -2h`, and whitespace between the
+/// components is optional.
+pub(super) fn parse_duration_polars(s: &str) -> Option<i128> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total: i128 = 0;
+    while !rest.is_empty() {
+        let negative = match rest.strip_prefix('-') {
+            Some(r) => {
+                rest = r;
+                true
+            },
+            None => false,
+        };
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        let n: i128 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '-' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "w" => NS_WEEK,
+            "d" => NS_DAY,
+            "h" => NS_HOUR,
+            "m" => NS_MINUTE,
+            "s" => NS_SECOND,
+            "ms" => NS_MILLISECOND,
+            "us" | "µs" => NS_MICROSECOND,
+            "ns" => 1,
+            _ => return None,
+        };
+        rest = rest[unit_len..].trim_start();
+
+        let v = n.checked_mul(unit as i128)?;
+        total = if negative {
+            total.checked_sub(v)?
+        } else {
+            total.checked_add(v)?
+        };
+    }
+    Some(total)
+}
+
+/// Parse an ISO 8601 duration, e.g. `P1DT2H3M4.5S`. Years and months are rejected as
+/// their length isn't fixed.
+pub(super) fn parse_duration_iso(s: &str) -> Option<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let s = s.strip_prefix('P')?;
+    let (date_part, time_part) = match s.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+
+    let mut total: i128 = 0;
+    let mut any = false;
+    let mut rest = date_part;
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !c.is_ascii_digit())?;
+        if end == 0 {
+            return None;
+        }
+        let n: i128 = rest[..end].parse().ok()?;
+        let unit = match rest.as_bytes()[end] {
+            b'W' => NS_WEEK,
+            b'D' => NS_DAY,
+            _ => return None,
+        };
+        total = total.checked_add(n.checked_mul(unit as i128)?)?;
+        rest = &rest[end + 1..];
+        any = true;
+    }
+
+    if let Some(mut rest) = time_part {
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+            if end == 0 {
+                return None;
+            }
+            let number = &rest[..end];
+            let v = match rest.as_bytes()[end] {
+                b'H' => number.parse::<i128>().ok()?.checked_mul(NS_HOUR as i128)?,
+                b'M' => number
+                    .parse::<i128>()
+                    .ok()?
+                    .checked_mul(NS_MINUTE as i128)?,
+                b'S' => parse_seconds(number)?,
+                _ => return None,
+            };
+            total = total.checked_add(v)?;
+            rest = &rest[end + 1..];
+            any = true;
+        }
+    }
+
+    any.then_some(if negative { -total } else { total })
+}
+
+/// Parse (fractional) seconds with up to nanosecond precision.
+fn parse_seconds(s: &str) -> Option<i128> {
+    let (whole, frac) = s.split_once(['.', ',']).unwrap_or((s, ""));
+    if whole.is_empty() || frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let whole: i128 = whole.parse().ok()?;
+    let frac: i128 = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<i128>().ok()? * 10_i128.pow(9 - frac.len() as u32)
+    };
+    whole.checked_mul(NS_SECOND as i128)?.checked_add(frac)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_polars() {
+        let ns = |d: i64, h: i64, m: i64, s: i64| {
+            (d * NS_DAY + h * NS_HOUR + m * NS_MINUTE + s * NS_SECOND) as i128
+        };
+        assert_eq!(parse_duration_polars("1d 2h 3m 4s"), Some(ns(1, 2, 3, 4)));
+        assert_eq!(parse_duration_polars("1d2h"), Some(ns(1, 2, 0, 0)));
+        assert_eq!(parse_duration_polars("-1d -2h"), Some(-ns(1, 2, 0, 0)));
+        assert_eq!(parse_duration_polars("1w"), Some(ns(7, 0, 0, 0)));
+        assert_eq!(parse_duration_polars("3ms 2µs 1ns"), Some(3_002_001));
+        assert_eq!(parse_duration_polars("5us"), Some(5_000));
+        assert_eq!(parse_duration_polars(""), None);
+        assert_eq!(parse_duration_polars("1mo"), None);
+        assert_eq!(parse_duration_polars("1 d"), None);
+        assert_eq!(parse_duration_polars("d"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_iso() {
+        let ns = |d: i64, h: i64, m: i64, s: i64| {
+            (d * NS_DAY + h * NS_HOUR + m * NS_MINUTE + s * NS_SECOND) as i128
+        };
+        assert_eq!(parse_duration_iso("P1DT2H3M4S"), Some(ns(1, 2, 3, 4)));
+        assert_eq!(parse_duration_iso("P2W"), Some(ns(14, 0, 0, 0)));
+        assert_eq!(parse_duration_iso("PT0S"), Some(0));
+        assert_eq!(parse_duration_iso("-PT1.5S"), Some(-1_500_000_000));
+        assert_eq!(parse_duration_iso("PT0,000000001S"), Some(1));
+        assert_eq!(parse_duration_iso("P1Y"), None);
+        assert_eq!(parse_duration_iso("P1M"), None);
+        assert_eq!(parse_duration_iso("PT1.5H"), None);
+        assert_eq!(parse_duration_iso("P1DT"), None);
+        assert_eq!(parse_duration_iso("P"), None);
+        assert_eq!(parse_duration_iso("1D"), None);
+    }
+}
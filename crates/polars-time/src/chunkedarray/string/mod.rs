@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
 use chrono::DateTime;
 mod patterns;
@@ -101,6 +103,31 @@ fn sniff_fmt_time(ca_string: &StringChunked) -> PolarsResult<&'static str> {
 }
 
 pub trait StringMethods: AsString {
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values and return a [`DurationChunked`]. The format is either
+    /// `"polars"` (default), e.g. `1d 2h 3m`, or `"iso"`, e.g. `P1DT2H3M`. Values that
+    /// can't be parsed or don't fit in the time unit become null.
+    fn as_duration(&self, fmt: Option<&str>, tu: TimeUnit) -> PolarsResult<DurationChunked> {
+        let string_ca = self.as_string();
+        let parse: fn(&str) -> Option<i128> = match fmt.unwrap_or("polars") {
+            "polars" => duration::parse_duration_polars,
+            "iso" => duration::parse_duration_iso,
+            fmt => polars_bail!(
+                InvalidOperation: "invalid format '{}' for Duration, expected one of 'iso' or 'polars'", fmt
+            ),
+        };
+        let ns_per_unit: i128 = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let ca: Int64Chunked = string_ca.apply_generic(|opt_s| {
+            let ns = parse(opt_s?)?;
+            i64::try_from(ns / ns_per_unit).ok()
+        });
+        Ok(ca.with_name(string_ca.name()).into_duration(tu))
+    }
+
     #[cfg(feature = "dtype-time")]
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
@@ -0,0 +1,229 @@
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use smartstring::alias::String as SmartString;
+
+use crate::date_range::datetime_range_i64;
+use crate::prelude::*;
+
+/// Arguments for [`PolarsFillGaps::fill_gaps`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FillGapsArgs {
+    /// The column of type `Date` or `Datetime` with the time steps.
+    pub time_column: SmartString,
+    /// The interval between two time steps.
+    pub every: Duration,
+    /// Fill the gaps of every group of these columns separately.
+    pub by: Vec<SmartString>,
+    /// The values of the other columns in the inserted rows, as series of length 1 named after
+    /// the column. The columns without a fill value are null in the inserted rows.
+    pub fill_values: Vec<Series>,
+}
+
+impl FillGapsArgs {
+    pub fn new(time_column: &str, every: Duration, by: Vec<SmartString>) -> Self {
+        Self {
+            time_column: time_column.into(),
+            every,
+            by,
+            fill_values: vec![],
+        }
+    }
+
+    pub fn with_fill_values(mut self, fill_values: Vec<Series>) -> Self {
+        self.fill_values = fill_values;
+        self
+    }
+}
+
+impl PartialEq for FillGapsArgs {
+    fn eq(&self, other: &Self) -> bool {
+        self.time_column == other.time_column
+            && self.every == other.every
+            && self.by == other.by
+            && self.fill_values.len() == other.fill_values.len()
+            && self
+                .fill_values
+                .iter()
+                .zip(&other.fill_values)
+                .all(|(l, r)| l.name() == r.name() && l.equals_missing(r))
+    }
+}
+
+pub trait PolarsFillGaps {
+    /// Insert a row for every time step that is missing between the first and the last time
+    /// step of a group.
+    ///
+    /// The time steps of a group start at its first time and are `every` apart. The inserted rows
+    /// have the keys of their group and the fill values of [`FillGapsArgs`], the columns
+    /// without a fill value are null. The rows of the output are sorted by the group keys and
+    /// then by time; the order of equal rows is maintained.
+    fn fill_gaps(&self, args: &FillGapsArgs) -> PolarsResult<DataFrame>;
+}
+
+impl PolarsFillGaps for DataFrame {
+    fn fill_gaps(&self, args: &FillGapsArgs) -> PolarsResult<DataFrame> {
+        fill_gaps_impl(self, args)
+    }
+}
+
+fn fill_gaps_impl(df: &DataFrame, args: &FillGapsArgs) -> PolarsResult<DataFrame> {
+    let time = df.column(&args.time_column)?;
+    let (tu, tz) = match time.dtype() {
+        DataType::Date => {
+            polars_ensure!(
+                args.every.is_full_days(),
+                InvalidOperation: "`every` must be a whole number of days to fill the gaps of a \
+                column of type Date, got {}", args.every
+            );
+            (TimeUnit::Milliseconds, None)
+        },
+        DataType::Datetime(tu, tz) => (*tu, tz.clone()),
+        dt => polars_bail!(
+            InvalidOperation: "cannot fill the gaps of a time column of type {}", dt
+        ),
+    };
+    for fill_value in &args.fill_values {
+        polars_ensure!(
+            fill_value.len() == 1,
+            ComputeError: "fill value of column '{}' must be a single value, got length {}",
+            fill_value.name(), fill_value.len()
+        );
+    }
+    let parsed_tz = match &tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => Some(parse_time_zone(tz)?),
+        _ => None,
+    };
+
+    let timestamps = match time.dtype() {
+        DataType::Date => time.cast(&DataType::Datetime(tu, None))?,
+        _ => time.clone(),
+    }
+    .to_physical_repr()
+    .rechunk();
+    let timestamps = timestamps.i64()?;
+
+    let (keys, groups) = if args.by.is_empty() {
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, df.height() as IdxSize]],
+            rolling: false,
+        };
+        (vec![], groups)
+    } else {
+        let gb = df.group_by_stable(&args.by)?;
+        (gb.keys(), gb.take_groups())
+    };
+
+    // The timestamps and the group of the rows to insert.
+    let mut missing_timestamps = vec![];
+    let mut missing_groups: Vec<IdxSize> = vec![];
+    let mut present = PlHashSet::new();
+    for (group_idx, group) in groups.iter().enumerate() {
+        present.clear();
+        let mut insert = |idx: IdxSize| {
+            if let Some(t) = timestamps.get(idx as usize) {
+                present.insert(t);
+            }
+        };
+        match group {
+            GroupsIndicator::Idx((_, idx)) => idx.iter().copied().for_each(&mut insert),
+            GroupsIndicator::Slice([first, len]) => (first..first + len).for_each(&mut insert),
+        }
+        let (Some(&start), Some(&end)) = (present.iter().min(), present.iter().max()) else {
+            continue;
+        };
+        for t in datetime_range_i64(
+            start,
+            end,
+            args.every,
+            ClosedWindow::Both,
+            tu,
+            parsed_tz.as_ref(),
+        )? {
+            if !present.contains(&t) {
+                missing_timestamps.push(t);
+                missing_groups.push(group_idx as IdxSize);
+            }
+        }
+    }
+
+    let n_missing = missing_timestamps.len();
+    let missing_groups = IdxCa::from_vec("", missing_groups);
+    let missing = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let name = s.name();
+            if name == args.time_column.as_str() {
+                Int64Chunked::from_vec(name, std::mem::take(&mut missing_timestamps))
+                    .into_datetime(tu, tz.clone())
+                    .into_series()
+                    .cast(s.dtype())
+            } else if let Some(i) = args.by.iter().position(|by| by.as_str() == name) {
+                // SAFETY: the group indices are in bounds of the keys.
+                Ok(unsafe { keys[i].take_unchecked(&missing_groups) })
+            } else if let Some(fill_value) = args.fill_values.iter().find(|v| v.name() == name) {
+                Ok(fill_value.cast(s.dtype())?.new_from_index(0, n_missing))
+            } else {
+                Ok(Series::full_null(name, n_missing, s.dtype()))
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut out = df.clone();
+    out.vstack_mut(&DataFrame::new_no_checks(missing))?;
+    let mut sort_by = args.by.clone();
+    sort_by.push(args.time_column.clone());
+    let descending = vec![false; sort_by.len()];
+    out.sort(sort_by, descending, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_gaps() -> PolarsResult<()> {
+        let time = Int64Chunked::new("time", &[0i64, 3, 1, 4, 2, 6])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_series();
+        let df = DataFrame::new(vec![
+            time,
+            Series::new("g", &["a", "a", "b", "b", "c", "c"]),
+            Series::new("v", &[1, 2, 3, 4, 5, 6]),
+        ])?;
+
+        let args = FillGapsArgs::new("time", Duration::parse("1ms"), vec!["g".into()])
+            .with_fill_values(vec![Series::new("v", &[0])]);
+        let out = df.fill_gaps(&args)?;
+
+        let time = out.column("time")?.to_physical_repr().into_owned();
+        assert_eq!(
+            Vec::from(time.i64()?),
+            &[0, 1, 2, 3, 1, 2, 3, 4, 2, 3, 4, 5, 6].map(Some)
+        );
+        let g = out.column("g")?;
+        assert_eq!(
+            Vec::from(g.str()?),
+            &["a", "a", "a", "a", "b", "b", "b", "b", "c", "c", "c", "c", "c"].map(Some)
+        );
+        let v = out.column("v")?;
+        assert_eq!(
+            Vec::from(v.i32()?),
+            &[1, 0, 0, 2, 3, 0, 0, 4, 5, 0, 0, 0, 6].map(Some)
+        );
+
+        let out = df.select(["time"])?.fill_gaps(&FillGapsArgs::new(
+            "time",
+            Duration::parse("1ms"),
+            vec![],
+        ))?;
+        let time = out.column("time")?.to_physical_repr().into_owned();
+        assert_eq!(Vec::from(time.i64()?), &[0, 1, 2, 3, 4, 5, 6].map(Some));
+        Ok(())
+    }
+}
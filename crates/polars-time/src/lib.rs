@@ -3,6 +3,7 @@ mod base_utc_offset;
 pub mod chunkedarray;
 mod date_range;
 mod dst_offset;
+mod fill_gaps;
 mod group_by;
 mod month_end;
 mod month_start;
@@ -19,6 +20,7 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+pub use fill_gaps::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 pub use month_end::*;
@@ -49,7 +49,10 @@ pub(crate) fn roll_backward(
     let ndt = NaiveDateTime::new(date, time);
     let t = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => datetime_to_timestamp(try_localize_datetime(ndt, tz, Ambiguous::Raise)?),
+        Some(tz) => datetime_to_timestamp(
+            try_localize_datetime(ndt, tz, Ambiguous::Raise)?
+                .expect("we didn't use Ambiguous::Null"),
+        ),
         _ => datetime_to_timestamp(ndt),
     };
     Ok(t)
@@ -211,6 +211,10 @@ pub trait TemporalMethods: AsSeries {
                 .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             #[cfg(feature = "dtype-time")]
             DataType::Time => s.time().map(|ca| ca.to_string(format).into_series()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => s
+                .duration()
+                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
             dt => polars_bail!(opq = to_string, dt),
         }
     }
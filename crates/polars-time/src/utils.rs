@@ -14,7 +14,7 @@ pub(crate) fn try_localize_datetime(
     ndt: NaiveDateTime,
     tz: &Tz,
     ambiguous: Ambiguous,
-) -> PolarsResult<NaiveDateTime> {
+) -> PolarsResult<Option<NaiveDateTime>> {
     // e.g. '2021-01-01 03:00' -> '2021-01-01 03:00CDT'
     convert_to_naive_local(&chrono_tz::UTC, tz, ndt, ambiguous)
 }
@@ -331,6 +331,11 @@ impl Duration {
         self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
     }
 
+    /// `true` if the duration is negative.
+    pub fn negative(&self) -> bool {
+        self.negative
+    }
+
     pub fn months_only(&self) -> bool {
         self.months != 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
     }
@@ -462,14 +467,22 @@ impl Duration {
         match localize_datetime_opt(result_dt_local, tz, Ambiguous::Raise) {
             Some(dt) => dt,
             None => {
-                if try_localize_datetime(original_dt_local, tz, Ambiguous::Earliest).unwrap()
+                if try_localize_datetime(original_dt_local, tz, Ambiguous::Earliest)
+                    .unwrap()
+                    .unwrap()
                     == original_dt_utc
                 {
-                    try_localize_datetime(result_dt_local, tz, Ambiguous::Earliest).unwrap()
-                } else if try_localize_datetime(original_dt_local, tz, Ambiguous::Latest).unwrap()
+                    try_localize_datetime(result_dt_local, tz, Ambiguous::Earliest)
+                        .unwrap()
+                        .unwrap()
+                } else if try_localize_datetime(original_dt_local, tz, Ambiguous::Latest)
+                    .unwrap()
+                    .unwrap()
                     == original_dt_utc
                 {
-                    try_localize_datetime(result_dt_local, tz, Ambiguous::Latest).unwrap()
+                    try_localize_datetime(result_dt_local, tz, Ambiguous::Latest)
+                        .unwrap()
+                        .unwrap()
                 } else {
                     unreachable!()
                 }
@@ -785,9 +798,10 @@ impl Duration {
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
-                Some(tz) if tz != &chrono_tz::UTC => {
-                    datetime_to_timestamp(try_localize_datetime(dt, tz, Ambiguous::Raise)?)
-                },
+                Some(tz) if tz != &chrono_tz::UTC => datetime_to_timestamp(
+                    try_localize_datetime(dt, tz, Ambiguous::Raise)?
+                        .expect("we didn't use Ambiguous::Null"),
+                ),
                 _ => datetime_to_timestamp(dt),
             };
         }
@@ -801,11 +815,10 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_weeks } else { t_weeks };
-                    new_t = datetime_to_timestamp(try_localize_datetime(
-                        timestamp_to_datetime(new_t),
-                        tz,
-                        Ambiguous::Raise,
-                    )?);
+                    new_t = datetime_to_timestamp(
+                        try_localize_datetime(timestamp_to_datetime(new_t), tz, Ambiguous::Raise)?
+                            .expect("we didn't use Ambiguous::Null"),
+                    );
                 },
                 _ => new_t += if d.negative { -t_weeks } else { t_weeks },
             };
@@ -820,11 +833,10 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_days } else { t_days };
-                    new_t = datetime_to_timestamp(try_localize_datetime(
-                        timestamp_to_datetime(new_t),
-                        tz,
-                        Ambiguous::Raise,
-                    )?);
+                    new_t = datetime_to_timestamp(
+                        try_localize_datetime(timestamp_to_datetime(new_t), tz, Ambiguous::Raise)?
+                            .expect("we didn't use Ambiguous::Null"),
+                    );
                 },
                 _ => new_t += if d.negative { -t_days } else { t_days },
             };
@@ -86,6 +86,13 @@ impl_hash_partition_as_u64!(i16);
 impl_hash_partition_as_u64!(i32);
 impl_hash_partition_as_u64!(i64);
 
+impl DirtyHash for i128 {
+    fn dirty_hash(&self) -> u64 {
+        // Fold the high bits in, otherwise values that only differ there collide.
+        ((*self as u64) ^ ((*self >> 64) as u64)).wrapping_mul(RANDOM_ODD)
+    }
+}
+
 impl<'a> DirtyHash for BytesHash<'a> {
     fn dirty_hash(&self) -> u64 {
         self.hash
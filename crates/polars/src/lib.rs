@@ -184,12 +184,15 @@
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to polars.
 //! * `streaming` - Be able to process datasets that are larger than RAM.
+//! * `tracing` - Emit [tracing](https://docs.rs/tracing/) spans for the optimization passes, the
+//!               executed nodes and IO waits of lazy queries.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_distance` - `levenshtein`, `hamming` and `jaro_winkler`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -206,12 +209,20 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write Parquet files with modular (column-level) encryption
+//!     - `delta` - Read Delta Lake tables
+//!     - `iceberg` - Read Apache Iceberg tables
+//!     - `flight` - Read from Arrow Flight servers
+//!     - `excel` - Read and write Excel (xlsx) workbooks
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
-//!     - `decompress` - Automatically infer compression of csvs and decompress them.
+//!     - `partition` - Write Hive partitioned datasets
+//!     - `decompress` - Automatically infer compression of CSV, NDJSON and IPC files and decompress them.
 //!                      Supported compressions:
-//!                         * zip
+//!                         * zlib
 //!                         * gzip
+//!                         * zstd
+//!                         * bzip2
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -232,7 +243,7 @@
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `horizontal_concat` - Concat horizontally and extend with null values if lengths don't match
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
-//!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `partition_by` - Split into multiple [`DataFrame`]s or `LazyFrame`s partitioned by groups.
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
@@ -256,11 +267,14 @@
 //!     - `rank` - Ranking algorithms.
 //!     - `moment` - kurtosis and skew statistics
 //!     - `ewma` - Exponential moving average windows
+//!     - `ewma_by` - Exponential moving average windows with time-based decay
 //!     - `abs` - Get absolute values of [`Series`].
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
 //!     - `diff` - [`diff`] operation.
 //!     - `pct_change` - Compute change percentages.
+//!     - `outliers` - Winsorize and flag outliers.
+//!     - `geo` - Point in polygon, bounding box and haversine distance of coordinates.
 //!     - `unique_counts` - Count unique values in expressions.
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
@@ -270,12 +284,15 @@
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
+//!     - `index_of` - Find the index of the first occurrence of a value.
 //!     - `date_offset` - Add an offset to dates that take months and leap years into account.
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
+//!     - `weighted_stats` - Weighted mean, variance and quantile aggregations.
+//!     - `approx_quantile` - Approximate quantiles computed with a t-digest.
 //!     - `find_many` - Find/replace multiple string patterns at once.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
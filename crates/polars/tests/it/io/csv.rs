@@ -1174,3 +1174,150 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_decimal_comma() -> PolarsResult<()> {
+    let csv = "a;b;c
+1,5;x;2
+-0,25;y;3
+;z;4";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_separator(b';')
+        .with_decimal_comma(true)
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(1.5), Some(-0.25), None]
+    );
+    assert_eq!(df.column("c")?.dtype(), &DataType::Int64);
+
+    // Also when the dtype is given.
+    let file = Cursor::new(csv);
+    let schema = Schema::from_iter([Field::new("c", DataType::Float32)]);
+    let df = CsvReader::new(file)
+        .with_separator(b';')
+        .with_decimal_comma(true)
+        .with_dtypes(Some(Arc::new(schema)))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("c")?.f32()?),
+        &[Some(2.0), Some(3.0), Some(4.0)]
+    );
+
+    let file = Cursor::new(csv);
+    assert!(CsvReader::new(file)
+        .with_decimal_comma(true)
+        .finish()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_null_values_per_column() -> PolarsResult<()> {
+    let csv = "a,b
+NA,1
+-,x
+3,-";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_null_values(Some(NullValues::Named(vec![
+            ("a".to_string(), "NA".to_string()),
+            ("a".to_string(), "-".to_string()),
+            ("b".to_string(), "x".to_string()),
+        ])))
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[None, None, Some(3)]);
+    assert_eq!(
+        Vec::from(df.column("b")?.str()?),
+        &[Some("1"), None, Some("-")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dtypes_regex() -> PolarsResult<()> {
+    let csv = "id,sensor_1,sensor_2,sensor_3
+1,10,20,30
+2,11,21,31";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_dtypes(Some(Arc::new(Schema::from_iter([Field::new(
+            "sensor_3",
+            DataType::Float64,
+        )]))))
+        .with_dtypes_regex(Some(vec![
+            ("^sensor_1$".to_string(), DataType::Int16),
+            ("^sensor_.*$".to_string(), DataType::Float32),
+        ]))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::Int64,
+            DataType::Int16,
+            DataType::Float32,
+            DataType::Float64
+        ]
+    );
+    assert_eq!(
+        Vec::from(df.column("sensor_1")?.i16()?),
+        &[Some(10), Some(11)]
+    );
+
+    let file = Cursor::new(csv);
+    let report = CsvReader::new(file)
+        .with_dtypes_regex(Some(vec![("^sensor_.*$".to_string(), DataType::Float32)]))
+        .infer_schema_report()?;
+    assert_eq!(report.rows_read, 2);
+    assert_eq!(report.schema.get("sensor_2"), Some(&DataType::Float32));
+    assert!(report.columns[2].overwritten);
+    assert_eq!(report.columns[2].candidates, &[(DataType::Int64, 2)]);
+
+    let file = Cursor::new(csv);
+    assert!(CsvReader::new(file)
+        .with_dtypes_regex(Some(vec![("^sensor_[$".to_string(), DataType::Float32)]))
+        .finish()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parallel_quoted_multiline_fields() -> PolarsResult<()> {
+    use std::fmt::Write;
+
+    // The embedded lines have as many fields as the records, so they can't be told apart by
+    // counting fields.
+    let mut csv = String::from("id,text,value\n");
+    for i in 0..2000 {
+        writeln!(csv, "{i},\"{i},a,b\n{i},c,d\n{i},\"\"e\"\",f\",{i}").unwrap();
+    }
+
+    let df = CsvReader::new(Cursor::new(csv.as_bytes()))
+        .with_n_threads(Some(4))
+        .finish()?;
+    assert_eq!(df.height(), 2000);
+    assert_eq!(
+        df.column("text")?.str()?.get(1234),
+        Some("1234,a,b\n1234,c,d\n1234,\"e\",f")
+    );
+    assert!(df.column("id")?.equals(df.column("value")?));
+
+    let mut reader = CsvReader::new(Cursor::new(csv.as_bytes())).with_chunk_size(100);
+    let mut batched = reader.batched_borrowed_mmap()?;
+    let mut height = 0;
+    while let Some(batches) = batched.next_batches(4)? {
+        for batch in batches {
+            assert!(batch.column("text")?.str()?.into_iter().all(|text| text
+                .unwrap()
+                .matches('\n')
+                .count()
+                == 2));
+            height += batch.height();
+        }
+    }
+    assert_eq!(height, 2000);
+    Ok(())
+}
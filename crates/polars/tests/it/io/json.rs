@@ -179,3 +179,29 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_columns_missing_keys() {
+    let jsonlines = r#"{"a": 1, "b": "x", "c": [1, 2]}
+{"c": {"nested": true}, "a": 2}
+{"b": "z", "d": 3.0}
+{"a": 4, "b": "w\"", "e": null}
+"#;
+    let mut schema = Schema::new();
+    schema.with_column("a".into(), DataType::Int64);
+    schema.with_column("b".into(), DataType::String);
+    schema.with_column("c".into(), DataType::Int64);
+
+    let df = JsonLineReader::new(Cursor::new(jsonlines))
+        .with_schema(Arc::new(schema))
+        .with_columns(Some(vec!["b".into(), "a".into()]))
+        .finish()
+        .unwrap();
+
+    let expected = df![
+        "b" => [Some("x"), None, Some("z"), Some("w\"")],
+        "a" => [Some(1i64), Some(2), None, Some(4)],
+    ]
+    .unwrap();
+    assert!(df.equals_missing(&expected));
+}
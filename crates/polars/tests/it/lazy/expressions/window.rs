@@ -395,3 +395,45 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_window_order_by_and_frame() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "b", "a", "a"],
+        "t" => [3, 1, 1, 2, 2, 4],
+        "x" => [30i64, 10, 10, 20, 20, 40]
+    ]?;
+    let order_by = Some((col("t"), SortOptions::default()));
+    let out = df
+        .lazy()
+        .select([
+            col("x")
+                .cum_sum(false)
+                .over_with_order([col("g")], order_by.clone(), WindowMapping::GroupsToRows)
+                .alias("cum_sum"),
+            col("x")
+                .last()
+                .over_with_order([col("g")], order_by.clone(), WindowMapping::GroupsToRows)
+                .alias("last"),
+            col("x")
+                .sum()
+                .over_with_order([col("g")], order_by, WindowFrame::new(Some(-1), Some(0)))
+                .alias("moving_sum"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("cum_sum")?.i64()?),
+        &[Some(60), Some(10), Some(10), Some(30), Some(30), Some(100)]
+    );
+    assert_eq!(
+        Vec::from(out.column("last")?.i64()?),
+        &[Some(40), Some(20), Some(40), Some(20), Some(40), Some(40)]
+    );
+    assert_eq!(
+        Vec::from(out.column("moving_sum")?.i64()?),
+        &[Some(50), Some(10), Some(10), Some(30), Some(30), Some(70)]
+    );
+    Ok(())
+}
@@ -106,6 +106,7 @@ from polars.functions import (
     collect_all_async,
     concat,
     concat_list,
+    concat_sorted,
     concat_str,
     corr,
     count,
@@ -133,6 +134,7 @@ from polars.functions import (
     format,
     from_epoch,
     groups,
+    haversine_distance,
     head,
     implode,
     int_range,
@@ -186,8 +188,10 @@ from polars.io import (
     read_ndjson,
     read_ods,
     read_parquet,
+    read_parquet_field_ids,
     read_parquet_schema,
     scan_csv,
+    scan_database,
     scan_delta,
     scan_iceberg,
     scan_ipc,
@@ -296,8 +300,10 @@ __all__ = [
     "read_ndjson",
     "read_ods",
     "read_parquet",
+    "read_parquet_field_ids",
     "read_parquet_schema",
     "scan_csv",
+    "scan_database",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
@@ -317,6 +323,7 @@ __all__ = [
     "align_frames",
     "arg_where",
     "concat",
+    "concat_sorted",
     "date_range",
     "date_ranges",
     "datetime_range",
@@ -372,6 +379,7 @@ __all__ = [
     "format",
     "from_epoch",
     "groups",
+    "haversine_distance",
     "head",
     "implode",
     "int_range",
@@ -62,6 +62,7 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_RESULT_CACHE_DIR",
     "POLARS_STREAMING_CHUNK_SIZE",
     "POLARS_TABLE_WIDTH",
     "POLARS_VERBOSE",
@@ -741,6 +742,35 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_result_cache_dir(cls, path: str | Path | None) -> type[Config]:
+        """
+        Cache the results of `LazyFrame.collect` in a directory.
+
+        The results are keyed by a hash of the optimized query plan and of the
+        paths, sizes and modification times of the files that the query scans.
+        A later query with the same key returns the cached result instead of
+        recomputing it. Queries on in-memory data, or with user defined
+        functions or random functions without a seed, are not cached.
+
+        Parameters
+        ----------
+        path
+            Directory in which the results are stored as IPC files. Set to
+            `None` to disable the cache.
+
+        Examples
+        --------
+        >>> pl.Config.set_result_cache_dir("/tmp/polars_results")  # doctest: +SKIP
+        """
+        if path is None:
+            os.environ.pop("POLARS_RESULT_CACHE_DIR", None)
+        else:
+            os.environ["POLARS_RESULT_CACHE_DIR"] = normalize_filepath(
+                path, check_not_directory=False
+            )
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -532,8 +532,13 @@ class Enum(DataType):
     """
 
     categories: Series
+    ordering: CategoricalOrdering
 
-    def __init__(self, categories: Series | Iterable[str]):
+    def __init__(
+        self,
+        categories: Series | Iterable[str],
+        ordering: CategoricalOrdering = "physical",
+    ):
         """
         A fixed set categorical encoding of a set of strings.
 
@@ -541,7 +546,12 @@ class Enum(DataType):
         ----------
         categories
             Valid categories in the dataset.
+        ordering : {'physical', 'lexical'}
+            Ordering by order of the categories (default), or by the lexical
+            value of the strings.
         """
+        self.ordering = ordering
+
         if not isinstance(categories, pl.Series):
             categories = pl.Series(values=categories)
 
@@ -569,16 +579,24 @@ class Enum(DataType):
         if type(other) is DataTypeClass and issubclass(other, Enum):
             return True
         elif isinstance(other, Enum):
-            return self.categories.equals(other.categories)
+            return (
+                self.categories.equals(other.categories)
+                and self.ordering == other.ordering
+            )
         else:
             return False
 
     def __hash__(self) -> int:
-        return hash((self.__class__, tuple(self.categories)))
+        return hash((self.__class__, tuple(self.categories), self.ordering))
 
     def __repr__(self) -> str:
         class_name = self.__class__.__name__
-        return f"{class_name}(categories={self.categories.to_list()!r})"
+        if self.ordering == "physical":
+            return f"{class_name}(categories={self.categories.to_list()!r})"
+        return (
+            f"{class_name}(categories={self.categories.to_list()!r},"
+            f" ordering={self.ordering!r})"
+        )
 
 
 class Object(DataType):
@@ -4,10 +4,16 @@ from typing import TYPE_CHECKING
 
 from polars.utils._parse_expr_input import parse_as_expression
 from polars.utils._wrap import wrap_expr
+from polars.utils.various import scale_bytes
 
 if TYPE_CHECKING:
     from polars import Expr
-    from polars.type_aliases import IntoExpr, TransferEncoding
+    from polars.type_aliases import (
+        BinaryCompression,
+        IntoExpr,
+        SizeUnit,
+        TransferEncoding,
+    )
 
 
 class ExprBinaryNameSpace:
@@ -161,6 +167,43 @@ class ExprBinaryNameSpace:
         prefix = parse_as_expression(prefix, str_as_lit=True)
         return wrap_expr(self._pyexpr.bin_starts_with(prefix))
 
+    def size(self, unit: SizeUnit = "b") -> Expr:
+        r"""
+        Get the size of binary values in the given unit.
+
+        Parameters
+        ----------
+        unit : {'b', 'kb', 'mb', 'gb', 'tb'}
+            Scale the returned size to the given unit.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` for the unit `'b'`, and
+            :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"\x00\x01", b"", None, b"\xff" * 2048]})
+        >>> df.select(
+        ...     n_bytes=pl.col("data").bin.size(),
+        ...     n_kb=pl.col("data").bin.size("kb"),
+        ... )
+        shape: (4, 2)
+        ┌─────────┬──────────┐
+        │ n_bytes ┆ n_kb     │
+        │ ---     ┆ ---      │
+        │ u32     ┆ f64      │
+        ╞═════════╪══════════╡
+        │ 2       ┆ 0.001953 │
+        │ 0       ┆ 0.0      │
+        │ null    ┆ null     │
+        │ 2048    ┆ 2.0      │
+        └─────────┴──────────┘
+        """
+        sz = wrap_expr(self._pyexpr.bin_size_bytes())
+        return scale_bytes(sz, unit)  # type: ignore[arg-type, return-value]
+
     def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Expr:
         """
         Decode a value using the provided encoding.
@@ -225,3 +268,77 @@ class ExprBinaryNameSpace:
         else:
             msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
             raise ValueError(msg)
+
+    def compress(
+        self, compression: BinaryCompression, *, level: int | None = None
+    ) -> Expr:
+        """
+        Compress the values with the provided compression.
+
+        Parameters
+        ----------
+        compression : {'gzip', 'zstd'}
+            The compression to use.
+        level
+            The compression level; between 0 and 9 for gzip and at most 22 for zstd,
+            where higher levels compress better but slower. If not set, the default
+            level of the compression is used.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary` with the compressed values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"polars" * 100, None]})
+        >>> df.select(
+        ...     pl.col("data").bin.compress("zstd").bin.decompress("zstd") == pl.col("data")
+        ... )
+        shape: (2, 1)
+        ┌───────┐
+        │ data  │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ null  │
+        └───────┘
+        """
+        return wrap_expr(self._pyexpr.bin_compress(compression, level))
+
+    def decompress(
+        self, compression: BinaryCompression, *, strict: bool = True
+    ) -> Expr:
+        """
+        Decompress the values that were compressed with the provided compression.
+
+        Parameters
+        ----------
+        compression : {'gzip', 'zstd'}
+            The compression the values were compressed with.
+        strict
+            Raise an error if the underlying value cannot be decompressed,
+            otherwise mask out with a null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary` with the decompressed values.
+        """
+        return wrap_expr(self._pyexpr.bin_decompress(compression, strict))
+
+    def format_uuid(self) -> Expr:
+        """
+        Format the 16 bytes of UUIDs in the lowercase hyphenated form.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        Expr.str.to_uuid : Parse strings as UUIDs into their 16 bytes.
+        """
+        return wrap_expr(self._pyexpr.bin_format_uuid())
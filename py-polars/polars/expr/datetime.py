@@ -407,7 +407,7 @@ class ExprDateTimeNameSpace:
 
     def to_string(self, format: str) -> Expr:
         """
-        Convert a Date/Time/Datetime column into a String column with the given format.
+        Convert a Date/Time/Datetime/Duration column into a String column.
 
         Similar to `cast(pl.String)`, but this method allows you to customize the
         formatting of the resulting string.
@@ -419,6 +419,10 @@ class ExprDateTimeNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
 
+            Duration columns take either `"polars"`, the format used to display a
+            duration (e.g. `"1d 2h 3m"`), or `"iso"` for ISO 8601 (e.g.
+            `"P1DT2H3M"`). These can be parsed back with `str.to_duration`.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -1528,6 +1532,7 @@ class ExprDateTimeNameSpace:
             - `'raise'` (default): raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
         Examples
         --------
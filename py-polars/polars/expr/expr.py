@@ -2069,6 +2069,158 @@ class Expr:
         k = parse_as_expression(k)
         return self._from_pyexpr(self._pyexpr.bottom_k(k))
 
+    def top_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int | IntoExprColumn = 5,
+        *,
+        descending: bool | Sequence[bool] = False,
+    ) -> Self:
+        r"""
+        Return the elements corresponding to the `k` largest elements of `by`.
+
+        Rather than sorting, the `k` rows are found with a partial selection, which
+        has time complexity:
+
+        .. math:: O(n + k \\log{}n - \frac{k}{2})
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the largest elements. Accepts expression
+            input. Strings are parsed as column names.
+        k
+            Number of elements to return.
+        descending
+            Consider the smallest elements of the `by` column(s) to be the largest
+            instead. Specify this per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        bottom_k_by
+        top_k
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 3, 4, 5, 6],
+        ...         "b": [6, 5, 4, 3, 2, 1],
+        ...         "c": ["Apple", "Orange", "Apple", "Apple", "Banana", "Banana"],
+        ...     }
+        ... )
+
+        Get the rows with the largest values of `a`.
+
+        >>> df.select(pl.all().top_k_by("a", 2))
+        shape: (2, 3)
+        ┌─────┬─────┬────────┐
+        │ a   ┆ b   ┆ c      │
+        │ --- ┆ --- ┆ ---    │
+        │ i64 ┆ i64 ┆ str    │
+        ╞═════╪═════╪════════╡
+        │ 6   ┆ 1   ┆ Banana │
+        │ 5   ┆ 2   ┆ Banana │
+        └─────┴─────┴────────┘
+
+        Break ties in `c` on the `a` column, using its smallest values first.
+
+        >>> df.select(pl.all().top_k_by(["c", "a"], 2, descending=[False, True]))
+        shape: (2, 3)
+        ┌─────┬─────┬────────┐
+        │ a   ┆ b   ┆ c      │
+        │ --- ┆ --- ┆ ---    │
+        │ i64 ┆ i64 ┆ str    │
+        ╞═════╪═════╪════════╡
+        │ 2   ┆ 5   ┆ Orange │
+        │ 5   ┆ 2   ┆ Banana │
+        └─────┴─────┴────────┘
+        """
+        k = parse_as_expression(k)
+        by = parse_as_list_of_expressions(by)
+        if isinstance(descending, bool):
+            descending = [descending]
+        elif len(by) != len(descending):
+            msg = f"the length of `descending` ({len(descending)}) does not match the length of `by` ({len(by)})"
+            raise ValueError(msg)
+        return self._from_pyexpr(self._pyexpr.top_k_by(k, by, descending))
+
+    def bottom_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int | IntoExprColumn = 5,
+        *,
+        descending: bool | Sequence[bool] = False,
+    ) -> Self:
+        r"""
+        Return the elements corresponding to the `k` smallest elements of `by`.
+
+        Rather than sorting, the `k` rows are found with a partial selection, which
+        has time complexity:
+
+        .. math:: O(n + k \\log{}n - \frac{k}{2})
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the smallest elements. Accepts expression
+            input. Strings are parsed as column names.
+        k
+            Number of elements to return.
+        descending
+            Consider the largest elements of the `by` column(s) to be the smallest
+            instead. Specify this per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        top_k_by
+        bottom_k
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 3, 4, 5, 6],
+        ...         "b": [6, 5, 4, 3, 2, 1],
+        ...         "c": ["Apple", "Orange", "Apple", "Apple", "Banana", "Banana"],
+        ...     }
+        ... )
+
+        Get the rows with the smallest values of `a`.
+
+        >>> df.select(pl.all().bottom_k_by("a", 2))
+        shape: (2, 3)
+        ┌─────┬─────┬────────┐
+        │ a   ┆ b   ┆ c      │
+        │ --- ┆ --- ┆ ---    │
+        │ i64 ┆ i64 ┆ str    │
+        ╞═════╪═════╪════════╡
+        │ 1   ┆ 6   ┆ Apple  │
+        │ 2   ┆ 5   ┆ Orange │
+        └─────┴─────┴────────┘
+
+        Break ties in `c` on the `a` column, using its largest values first.
+
+        >>> df.select(pl.all().bottom_k_by(["c", "a"], 2, descending=[False, True]))
+        shape: (2, 3)
+        ┌─────┬─────┬────────┐
+        │ a   ┆ b   ┆ c      │
+        │ --- ┆ --- ┆ ---    │
+        │ i64 ┆ i64 ┆ str    │
+        ╞═════╪═════╪════════╡
+        │ 4   ┆ 3   ┆ Apple  │
+        │ 3   ┆ 4   ┆ Apple  │
+        └─────┴─────┴────────┘
+        """
+        k = parse_as_expression(k)
+        by = parse_as_list_of_expressions(by)
+        if isinstance(descending, bool):
+            descending = [descending]
+        elif len(by) != len(descending):
+            msg = f"the length of `descending` ({len(descending)}) does not match the length of `by` ({len(by)})"
+            raise ValueError(msg)
+        return self._from_pyexpr(self._pyexpr.bottom_k_by(k, by, descending))
+
     def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Self:
         """
         Get the index values that would sort this column.
@@ -2152,7 +2304,13 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.arg_min())
 
-    def search_sorted(self, element: IntoExpr, side: SearchSortedSide = "any") -> Self:
+    def search_sorted(
+        self,
+        element: IntoExpr,
+        side: SearchSortedSide = "any",
+        *,
+        descending: bool = False,
+    ) -> Self:
         """
         Find indices where elements should be inserted to maintain order.
 
@@ -2166,6 +2324,8 @@ class Expr:
             If 'any', the index of the first suitable location found is given.
             If 'left', the index of the leftmost suitable location found is given.
             If 'right', return the rightmost suitable location found is given.
+        descending
+            Whether the column is sorted in descending order.
 
         Examples
         --------
@@ -2191,7 +2351,40 @@ class Expr:
         └──────┴───────┴─────┘
         """
         element = parse_as_expression(element)
-        return self._from_pyexpr(self._pyexpr.search_sorted(element, side))
+        return self._from_pyexpr(
+            self._pyexpr.search_sorted(element, side, descending)
+        )
+
+    def index_of(self, element: IntoExpr) -> Self:
+        """
+        Get the index of the first occurrence of a value, or null if it's not found.
+
+        Parameters
+        ----------
+        element
+            Value to find. Accepts expression input. Strings are parsed as column
+            names; use :func:`lit` to search for a string literal. A null value
+            finds the first null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, None, 17]})
+        >>> df.select(
+        ...     pl.col("a").index_of(17).alias("seventeen"),
+        ...     pl.col("a").index_of(None).alias("null"),
+        ...     pl.col("a").index_of(55).alias("fiftyfive"),
+        ... )
+        shape: (1, 3)
+        ┌───────────┬──────┬───────────┐
+        │ seventeen ┆ null ┆ fiftyfive │
+        │ ---       ┆ ---  ┆ ---       │
+        │ u32       ┆ u32  ┆ u32       │
+        ╞═══════════╪══════╪═══════════╡
+        │ 2         ┆ 1    ┆ null      │
+        └───────────┴──────┴───────────┘
+        """
+        element = parse_as_expression(element)
+        return self._from_pyexpr(self._pyexpr.index_of(element))
 
     def sort_by(
         self,
@@ -2788,6 +2981,118 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.var(ddof))
 
+    def wmean(self, weights: IntoExpr) -> Self:
+        """
+        Get the weighted mean.
+
+        Weights are treated as frequency weights: a value with weight 2 counts as if
+        it occurred twice. Pairs where either the value or the weight is null are
+        ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values. Accepts expression input. Strings are
+            parsed as column names.
+
+        See Also
+        --------
+        wvar
+        wquantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 4, None], "w": [1, 2, 1, 5]})
+        >>> df.select(pl.col("a").wmean("w"))
+        shape: (1, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 2.25 │
+        └──────┘
+        """
+        weights = parse_as_expression(weights)
+        return self._from_pyexpr(self._pyexpr.wmean(weights))
+
+    def wvar(self, weights: IntoExpr, ddof: int = 1) -> Self:
+        """
+        Get the weighted variance.
+
+        Weights are treated as frequency weights: a value with weight 2 counts as if
+        it occurred twice. Pairs where either the value or the weight is null are
+        ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values. Accepts expression input. Strings are
+            parsed as column names.
+        ddof
+            “Delta Degrees of Freedom”: the divisor used in the calculation is
+            W - ddof, where W represents the sum of the weights.
+            By default ddof is 1.
+
+        See Also
+        --------
+        wmean
+        wquantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 4, None], "w": [1, 2, 1, 5]})
+        >>> df.select(pl.col("a").wvar("w"))
+        shape: (1, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 1.583333 │
+        └──────────┘
+        """
+        weights = parse_as_expression(weights)
+        return self._from_pyexpr(self._pyexpr.wvar(weights, ddof))
+
+    def wquantile(self, weights: IntoExpr, quantile: float | Expr) -> Self:
+        """
+        Get the weighted quantile.
+
+        This is the smallest value for which the weights of all values up to and
+        including it make up at least `quantile` of the total weight. Pairs where
+        either the value or the weight is null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values. Accepts expression input. Strings are
+            parsed as column names.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        wmean
+        wvar
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 4, None], "w": [1, 2, 1, 5]})
+        >>> df.select(pl.col("a").wquantile("w", 0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.0 │
+        └─────┘
+        """
+        weights = parse_as_expression(weights)
+        quantile = parse_as_expression(quantile)
+        return self._from_pyexpr(self._pyexpr.wquantile(weights, quantile))
+
     def max(self) -> Self:
         """
         Get maximum value.
@@ -3011,6 +3316,42 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.approx_n_unique())
 
+    def approx_quantile(self, quantile: float | Expr) -> Self:
+        """
+        Get an approximation of a quantile value.
+
+        This is done using a t-digest, which summarizes the values in a bounded number
+        of centroids. It needs a single pass over the data and is most accurate for
+        quantiles close to 0.0 and 1.0. Null and NaN values are ignored.
+
+        Unlike :func:`quantile`, this aggregation can be evaluated by the streaming
+        engine in a `group_by` if `quantile` is a literal.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.5 │
+        └─────┘
+        """
+        quantile = parse_as_expression(quantile)
+        return self._from_pyexpr(self._pyexpr.approx_quantile(quantile))
+
     def null_count(self) -> Self:
         """
         Count null values.
@@ -3152,6 +3493,10 @@ class Expr:
         expr: IntoExpr | Iterable[IntoExpr],
         *more_exprs: IntoExpr,
         mapping_strategy: WindowMappingStrategy = "group_to_rows",
+        order_by: IntoExpr | Iterable[IntoExpr] | None = None,
+        descending: bool = False,
+        nulls_last: bool = False,
+        frame: tuple[int | None, int | None] | None = None,
     ) -> Self:
         """
         Compute expressions over the given groups.
@@ -3181,6 +3526,21 @@ class Expr:
             - explode
                 Don't do any mapping, but simply flatten the group.
                 This only makes sense if the input data is sorted.
+        order_by
+            Column(s) to order the rows of every group by, instead of their order in
+            the DataFrame. Accepts expression input. Strings are parsed as column
+            names. The results are still assigned to the original row positions.
+        descending
+            Order the rows of the groups in descending order.
+        nulls_last
+            Place null values of `order_by` last.
+        frame
+            The rows to aggregate for every row, as a `(start, end)` tuple of row
+            offsets relative to the current row, like the SQL `ROWS BETWEEN` clause.
+            Negative offsets are preceding rows, positive offsets following rows and
+            `None` is the start or end of the group, e.g. `(-2, 0)` for
+            `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`. The expression must be an
+            aggregation, which is computed once per row.
 
         Examples
         --------
@@ -3262,9 +3622,47 @@ class Expr:
         │ b   ┆ 5   ┆ 2   ┆ 1     │
         │ b   ┆ 3   ┆ 1   ┆ 1     │
         └─────┴─────┴─────┴───────┘
+
+        Order the rows of the groups to compute running aggregations without sorting
+        the DataFrame, or aggregate over a frame of rows around every row.
+
+        >>> df.with_columns(
+        ...     pl.col("c").cum_sum().over("a", order_by="b").alias("c_cum_sum"),
+        ...     pl.col("c")
+        ...     .sum()
+        ...     .over("a", order_by="b", frame=(-1, 0))
+        ...     .alias("c_moving_sum"),
+        ... )
+        shape: (5, 5)
+        ┌─────┬─────┬─────┬───────────┬──────────────┐
+        │ a   ┆ b   ┆ c   ┆ c_cum_sum ┆ c_moving_sum │
+        │ --- ┆ --- ┆ --- ┆ ---       ┆ ---          │
+        │ str ┆ i64 ┆ i64 ┆ i64       ┆ i64          │
+        ╞═════╪═════╪═════╪═══════════╪══════════════╡
+        │ a   ┆ 1   ┆ 5   ┆ 5         ┆ 5            │
+        │ a   ┆ 2   ┆ 4   ┆ 9         ┆ 9            │
+        │ b   ┆ 3   ┆ 3   ┆ 3         ┆ 3            │
+        │ b   ┆ 5   ┆ 2   ┆ 6         ┆ 3            │
+        │ b   ┆ 3   ┆ 1   ┆ 4         ┆ 4            │
+        └─────┴─────┴─────┴───────────┴──────────────┘
         """
         exprs = parse_as_list_of_expressions(expr, *more_exprs)
-        return self._from_pyexpr(self._pyexpr.over(exprs, mapping_strategy))
+        order_by_expr = None
+        if order_by is not None:
+            order_by_exprs = parse_as_list_of_expressions(order_by)
+            order_by_expr = (
+                order_by_exprs[0]
+                if len(order_by_exprs) == 1
+                else F.struct(order_by_exprs)._pyexpr
+            )
+        if frame is not None and mapping_strategy != "group_to_rows":
+            msg = "a window `frame` requires `mapping_strategy='group_to_rows'`"
+            raise ValueError(msg)
+        return self._from_pyexpr(
+            self._pyexpr.over(
+                exprs, mapping_strategy, order_by_expr, descending, nulls_last, frame
+            )
+        )
 
     def rolling(
         self,
@@ -3814,7 +4212,7 @@ class Expr:
         ┌─────────┬────────┐
         │ lengths ┆ values │
         │ ---     ┆ ---    │
-        │ i32     ┆ i64    │
+        │ u32     ┆ i64    │
         ╞═════════╪════════╡
         │ 2       ┆ 1      │
         │ 1       ┆ 2      │
@@ -7437,32 +7835,910 @@ class Expr:
             )
         )
 
-    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Self:
+    def rolling_min_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
         """
-        Compute a rolling skew.
+        Compute a rolling minimum based on another column.
 
-        The window at a given row includes the row itself and the
-        `window_size - 1` elements before it.
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
 
         Parameters
         ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
         window_size
-            Integer size of the rolling window.
-        bias
-            If False, the calculations are corrected for statistical bias.
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
 
-        Examples
-        --------
-        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
-        >>> df.select(pl.col("a").rolling_skew(3))
-        shape: (4, 1)
-        ┌──────────┐
-        │ a        │
-        │ ---      │
-        │ f64      │
-        ╞══════════╡
-        │ null     │
-        │ null     │
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_min_by("date", window_size="2d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ i64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1       │
+        │ 2001-01-02 ┆ 4     ┆ 1       │
+        │ 2001-01-03 ┆ 2     ┆ 2       │
+        │ 2001-01-04 ┆ 5     ┆ 2       │
+        │ 2001-01-05 ┆ 3     ┆ 3       │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_min_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_max_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling maximum based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_max_by("date", window_size="2d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ i64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1       │
+        │ 2001-01-02 ┆ 4     ┆ 4       │
+        │ 2001-01-03 ┆ 2     ┆ 4       │
+        │ 2001-01-04 ┆ 5     ┆ 5       │
+        │ 2001-01-05 ┆ 3     ┆ 5       │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_max_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_mean_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling mean based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_mean_by("date", window_size="2d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ f64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1.0     │
+        │ 2001-01-02 ┆ 4     ┆ 2.5     │
+        │ 2001-01-03 ┆ 2     ┆ 3.0     │
+        │ 2001-01-04 ┆ 5     ┆ 3.5     │
+        │ 2001-01-05 ┆ 3     ┆ 4.0     │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mean_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_sum_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling sum based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_sum_by("date", window_size="2d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ i64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1       │
+        │ 2001-01-02 ┆ 4     ┆ 5       │
+        │ 2001-01-03 ┆ 2     ┆ 6       │
+        │ 2001-01-04 ┆ 5     ┆ 7       │
+        │ 2001-01-05 ┆ 3     ┆ 8       │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_sum_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_median_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling median based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_median_by("date", window_size="2d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ f64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1.0     │
+        │ 2001-01-02 ┆ 4     ┆ 2.5     │
+        │ 2001-01-03 ┆ 2     ┆ 3.0     │
+        │ 2001-01-04 ┆ 5     ┆ 3.5     │
+        │ 2001-01-05 ┆ 3     ┆ 4.0     │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_median_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_std_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        ddof: int = 1,
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling standard deviation based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_std_by(
+        ...         "date", window_size="2d", min_periods=2
+        ...     ),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬──────────┐
+        │ date       ┆ value ┆ rolling  │
+        │ ---        ┆ ---   ┆ ---      │
+        │ date       ┆ i64   ┆ f64      │
+        ╞════════════╪═══════╪══════════╡
+        │ 2001-01-01 ┆ 1     ┆ null     │
+        │ 2001-01-02 ┆ 4     ┆ 2.12132  │
+        │ 2001-01-03 ┆ 2     ┆ 1.414214 │
+        │ 2001-01-04 ┆ 5     ┆ 2.12132  │
+        │ 2001-01-05 ┆ 3     ┆ 1.414214 │
+        └────────────┴───────┴──────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_std_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                ddof,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_var_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        ddof: int = 1,
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling variance based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_var_by(
+        ...         "date", window_size="2d", min_periods=2
+        ...     ),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ f64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ null    │
+        │ 2001-01-02 ┆ 4     ┆ 4.5     │
+        │ 2001-01-03 ┆ 2     ┆ 2.0     │
+        │ 2001-01-04 ┆ 5     ┆ 4.5     │
+        │ 2001-01-05 ┆ 3     ┆ 2.0     │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_var_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+                ddof,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_quantile_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        quantile: float,
+        interpolation: RollingInterpolationMethod = "nearest",
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        warn_if_unsorted: bool = True,
+    ) -> Self:
+        """
+        Compute a rolling quantile based on another column.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            This column must be of dtype Datetime or Date.
+
+            .. warning::
+                The column must be sorted in ascending order. Otherwise,
+                results will not be correct.
+        window_size
+            The length of the window. Can be a dynamic temporal size indicated by a
+            timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        quantile
+            Quantile between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
+            Interpolation method.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        warn_if_unsorted
+            Warn if data is not known to be sorted by `by` column.
+
+        Warnings
+        --------
+        This functionality is experimental and may change without it being considered a
+        breaking change.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 5), eager=True
+        ...         ),
+        ...         "value": [1, 4, 2, 5, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling=pl.col("value").rolling_quantile_by(
+        ...         "date", window_size="2d", quantile=0.5, interpolation="linear"
+        ...     ),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬───────┬─────────┐
+        │ date       ┆ value ┆ rolling │
+        │ ---        ┆ ---   ┆ ---     │
+        │ date       ┆ i64   ┆ f64     │
+        ╞════════════╪═══════╪═════════╡
+        │ 2001-01-01 ┆ 1     ┆ 1.0     │
+        │ 2001-01-02 ┆ 4     ┆ 2.5     │
+        │ 2001-01-03 ┆ 2     ┆ 3.0     │
+        │ 2001-01-04 ┆ 5     ┆ 3.5     │
+        │ 2001-01-05 ┆ 3     ┆ 4.0     │
+        └────────────┴───────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_as_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_quantile_by(
+                by,
+                quantile,
+                interpolation,
+                window_size,
+                min_periods,
+                closed,
+                warn_if_unsorted,
+            )
+        )
+
+    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Self:
+        """
+        Compute a rolling skew.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_skew(3))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ null     │
         │ 0.381802 │
         │ 0.47033  │
         └──────────┘
@@ -7561,97 +8837,250 @@ class Expr:
         │ 2.0 │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.abs())
+        return self._from_pyexpr(self._pyexpr.abs())
+
+    def rank(
+        self,
+        method: RankMethod = "average",
+        *,
+        descending: bool = False,
+        seed: int | None = None,
+    ) -> Self:
+        """
+        Assign ranks to data, dealing with ties appropriately.
+
+        Parameters
+        ----------
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value. (This is also referred to
+              as "competition" ranking.)
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
+            - 'ordinal' : All values are given a distinct rank, corresponding to
+              the order that the values occur in the Series.
+            - 'random' : Like 'ordinal', but the rank for ties is not dependent
+              on the order that the values occur in the Series.
+        descending
+            Rank in descending order.
+        seed
+            If `method="random"`, use this as seed.
+
+        Examples
+        --------
+        The 'average' method:
+
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
+        >>> df.select(pl.col("a").rank())
+        shape: (5, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        │ 4.5 │
+        │ 1.5 │
+        │ 1.5 │
+        │ 4.5 │
+        └─────┘
+
+        The 'ordinal' method:
+
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
+        >>> df.select(pl.col("a").rank("ordinal"))
+        shape: (5, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 3   │
+        │ 4   │
+        │ 1   │
+        │ 2   │
+        │ 5   │
+        └─────┘
+
+        Use 'rank' with 'over' to rank within groups:
+
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 7, 5, 14, 11]})
+        >>> df.with_columns(pl.col("b").rank().over("a").alias("rank"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────┐
+        │ a   ┆ b   ┆ rank │
+        │ --- ┆ --- ┆ ---  │
+        │ i64 ┆ i64 ┆ f64  │
+        ╞═════╪═════╪══════╡
+        │ 1   ┆ 6   ┆ 1.0  │
+        │ 1   ┆ 7   ┆ 2.0  │
+        │ 2   ┆ 5   ┆ 1.0  │
+        │ 2   ┆ 14  ┆ 3.0  │
+        │ 2   ┆ 11  ┆ 2.0  │
+        └─────┴─────┴──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+
+    def dense_rank(self, *, descending: bool = False) -> Self:
+        """
+        Assign ranks without gaps to the values, tied values get the same rank.
+
+        This is a shortcut for `rank("dense")` and matches the SQL `DENSE_RANK`
+        window function when used with :meth:`over`.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        rank
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 6, 5, 14, 5]})
+        >>> df.with_columns(pl.col("b").dense_rank().over("a").alias("rank"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────┐
+        │ a   ┆ b   ┆ rank │
+        │ --- ┆ --- ┆ ---  │
+        │ i64 ┆ i64 ┆ u32  │
+        ╞═════╪═════╪══════╡
+        │ 1   ┆ 6   ┆ 1    │
+        │ 1   ┆ 6   ┆ 1    │
+        │ 2   ┆ 5   ┆ 1    │
+        │ 2   ┆ 14  ┆ 2    │
+        │ 2   ┆ 5   ┆ 1    │
+        └─────┴─────┴──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.dense_rank(descending))
+
+    def percent_rank(self, *, descending: bool = False) -> Self:
+        """
+        Compute the relative rank of the values.
+
+        The relative rank is `(rank - 1) / (n - 1)`, where `rank` is the 'min' rank
+        and `n` the number of non-null values. A single value has a relative rank of
+        0.0. This matches the SQL `PERCENT_RANK` window function when used with
+        :meth:`over`.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        rank
+        cume_dist
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
+        >>> df.select(pl.col("a").percent_rank())
+        shape: (5, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 0.5  │
+        │ 0.75 │
+        │ 0.0  │
+        │ 0.0  │
+        │ 0.75 │
+        └──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.percent_rank(descending))
 
-    def rank(
-        self,
-        method: RankMethod = "average",
-        *,
-        descending: bool = False,
-        seed: int | None = None,
-    ) -> Self:
+    def cume_dist(self, *, descending: bool = False) -> Self:
         """
-        Assign ranks to data, dealing with ties appropriately.
+        Compute the cumulative distribution of the values.
+
+        The cumulative distribution is `rank / n`, where `rank` is the 'max' rank
+        and `n` the number of non-null values; the fraction of the values that are
+        smaller than or equal to a value. This matches the SQL `CUME_DIST` window
+        function when used with :meth:`over`.
 
         Parameters
         ----------
-        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
-            The method used to assign ranks to tied elements.
-            The following methods are available (default is 'average'):
-
-            - 'average' : The average of the ranks that would have been assigned to
-              all the tied values is assigned to each value.
-            - 'min' : The minimum of the ranks that would have been assigned to all
-              the tied values is assigned to each value. (This is also referred to
-              as "competition" ranking.)
-            - 'max' : The maximum of the ranks that would have been assigned to all
-              the tied values is assigned to each value.
-            - 'dense' : Like 'min', but the rank of the next highest element is
-              assigned the rank immediately after those assigned to the tied
-              elements.
-            - 'ordinal' : All values are given a distinct rank, corresponding to
-              the order that the values occur in the Series.
-            - 'random' : Like 'ordinal', but the rank for ties is not dependent
-              on the order that the values occur in the Series.
         descending
             Rank in descending order.
-        seed
-            If `method="random"`, use this as seed.
 
-        Examples
+        See Also
         --------
-        The 'average' method:
+        rank
+        percent_rank
 
+        Examples
+        --------
         >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
-        >>> df.select(pl.col("a").rank())
+        >>> df.select(pl.col("a").cume_dist())
         shape: (5, 1)
         ┌─────┐
         │ a   │
         │ --- │
         │ f64 │
         ╞═════╡
-        │ 3.0 │
-        │ 4.5 │
-        │ 1.5 │
-        │ 1.5 │
-        │ 4.5 │
+        │ 0.6 │
+        │ 1.0 │
+        │ 0.4 │
+        │ 0.4 │
+        │ 1.0 │
         └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cume_dist(descending))
 
-        The 'ordinal' method:
+    def ntile(self, n: int, *, descending: bool = False) -> Self:
+        """
+        Divide the values into `n` buckets of (nearly) equal size.
+
+        The buckets are numbered from 1 and filled in the order of the 'ordinal'
+        rank. If the number of values is not a multiple of `n`, the first buckets
+        hold one value more. This matches the SQL `NTILE` window function when used
+        with :meth:`over`.
+
+        Parameters
+        ----------
+        n
+            Number of buckets.
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        rank
 
+        Examples
+        --------
         >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6]})
-        >>> df.select(pl.col("a").rank("ordinal"))
+        >>> df.select(pl.col("a").ntile(2))
         shape: (5, 1)
         ┌─────┐
         │ a   │
         │ --- │
         │ u32 │
         ╞═════╡
-        │ 3   │
-        │ 4   │
         │ 1   │
         │ 2   │
-        │ 5   │
+        │ 1   │
+        │ 1   │
+        │ 2   │
         └─────┘
-
-        Use 'rank' with 'over' to rank within groups:
-
-        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 7, 5, 14, 11]})
-        >>> df.with_columns(pl.col("b").rank().over("a").alias("rank"))
-        shape: (5, 3)
-        ┌─────┬─────┬──────┐
-        │ a   ┆ b   ┆ rank │
-        │ --- ┆ --- ┆ ---  │
-        │ i64 ┆ i64 ┆ f64  │
-        ╞═════╪═════╪══════╡
-        │ 1   ┆ 6   ┆ 1.0  │
-        │ 1   ┆ 7   ┆ 2.0  │
-        │ 2   ┆ 5   ┆ 1.0  │
-        │ 2   ┆ 14  ┆ 3.0  │
-        │ 2   ┆ 11  ┆ 2.0  │
-        └─────┴─────┴──────┘
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        if n <= 0:
+            msg = f"`n` must be positive, got {n}"
+            raise ValueError(msg)
+        return self._from_pyexpr(self._pyexpr.ntile(n, descending))
 
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Self:
         """
@@ -7747,6 +9176,191 @@ class Expr:
         n = parse_as_expression(n)
         return self._from_pyexpr(self._pyexpr.pct_change(n))
 
+    def winsorize(self, lower_quantile: float, upper_quantile: float) -> Self:
+        """
+        Limit the values to the given quantiles.
+
+        Values below the `lower_quantile` quantile are replaced by that quantile, and
+        values above the `upper_quantile` quantile are replaced by that quantile. The
+        quantiles are values of the column, such that the data type is maintained.
+
+        In a group by or window context, the quantiles are computed per group.
+
+        Parameters
+        ----------
+        lower_quantile
+            Quantile between 0.0 and 1.0 below which values are replaced.
+        upper_quantile
+            Quantile between 0.0 and 1.0 above which values are replaced.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 100]})
+        >>> df.with_columns(pl.col("a").winsorize(0.0, 0.75).alias("winsorized"))
+        shape: (5, 2)
+        ┌─────┬────────────┐
+        │ a   ┆ winsorized │
+        │ --- ┆ ---        │
+        │ i64 ┆ i64        │
+        ╞═════╪════════════╡
+        │ 1   ┆ 1          │
+        │ 2   ┆ 2          │
+        │ 3   ┆ 3          │
+        │ 4   ┆ 4          │
+        │ 100 ┆ 4          │
+        └─────┴────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.winsorize(lower_quantile, upper_quantile)
+        )
+
+    def is_outlier_iqr(self, k: float = 1.5) -> Self:
+        """
+        Flag outliers with the interquartile range.
+
+        A value is an outlier if it is more than `k` times the interquartile range
+        below the first quartile or above the third quartile.
+
+        In a group by or window context, the quartiles are computed per group.
+
+        Parameters
+        ----------
+        k
+            The number of interquartile ranges a value may be away from the
+            quartiles.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 100]})
+        >>> df.with_columns(pl.col("a").is_outlier_iqr().alias("is_outlier"))
+        shape: (5, 2)
+        ┌─────┬────────────┐
+        │ a   ┆ is_outlier │
+        │ --- ┆ ---        │
+        │ i64 ┆ bool       │
+        ╞═════╪════════════╡
+        │ 1   ┆ false      │
+        │ 2   ┆ false      │
+        │ 3   ┆ false      │
+        │ 4   ┆ false      │
+        │ 100 ┆ true       │
+        └─────┴────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_outlier_iqr(k))
+
+    def is_outlier_zscore(self, threshold: float = 3.0) -> Self:
+        """
+        Flag outliers with the z-score.
+
+        A value is an outlier if it is more than `threshold` sample standard
+        deviations away from the mean.
+
+        In a group by or window context, the mean and standard deviation are computed
+        per group.
+
+        Parameters
+        ----------
+        threshold
+            The number of standard deviations a value may be away from the mean.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 100]})
+        >>> df.with_columns(pl.col("a").is_outlier_zscore(1.5).alias("is_outlier"))
+        shape: (5, 2)
+        ┌─────┬────────────┐
+        │ a   ┆ is_outlier │
+        │ --- ┆ ---        │
+        │ i64 ┆ bool       │
+        ╞═════╪════════════╡
+        │ 1   ┆ false      │
+        │ 2   ┆ false      │
+        │ 3   ┆ false      │
+        │ 4   ┆ false      │
+        │ 100 ┆ true       │
+        └─────┴────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_outlier_zscore(threshold))
+
+    def point_in_polygon(self, polygon: Sequence[tuple[float, float]]) -> Self:
+        """
+        Check which points lie inside a polygon.
+
+        The points are structs of two numeric fields or arrays of width 2, holding the
+        x and y coordinate. Points on the boundary of the polygon may be inside or
+        outside.
+
+        Parameters
+        ----------
+        polygon
+            The `(x, y)` vertices of the polygon, at least 3. The polygon may be
+            closed, i.e. its last vertex is equal to its first vertex, or not.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [0.25, 1.5], "y": [0.25, 0.5]})
+        >>> df.select(
+        ...     pl.struct("x", "y").point_in_polygon([(0, 0), (1, 0), (0, 1)]).alias("in")
+        ... )
+        shape: (2, 1)
+        ┌───────┐
+        │ in    │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.point_in_polygon(list(polygon)))
+
+    def within_bbox(
+        self, min_x: float, min_y: float, max_x: float, max_y: float
+    ) -> Self:
+        """
+        Check which points lie inside a bounding box, including its boundary.
+
+        The points are structs of two numeric fields or arrays of width 2, holding the
+        x and y coordinate.
+
+        Parameters
+        ----------
+        min_x
+            The lower bound of the x coordinate.
+        min_y
+            The lower bound of the y coordinate.
+        max_x
+            The upper bound of the x coordinate.
+        max_y
+            The upper bound of the y coordinate.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"lon": [4.9, 2.35], "lat": [52.37, 48.86]})
+        >>> df.filter(pl.struct("lon", "lat").within_bbox(3.3, 50.7, 7.2, 53.6))
+        shape: (1, 2)
+        ┌─────┬───────┐
+        │ lon ┆ lat   │
+        │ --- ┆ ---   │
+        │ f64 ┆ f64   │
+        ╞═════╪═══════╡
+        │ 4.9 ┆ 52.37 │
+        └─────┴───────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.within_bbox(min_x, min_y, max_x, max_y)
+        )
+
     def skew(self, *, bias: bool = True) -> Self:
         r"""
         Compute the sample skewness of a data set.
@@ -8418,6 +10032,36 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.shuffle(seed))
 
+    def uuid4(self, seed: int | None = None) -> Self:
+        """
+        Generate a random UUID of version 4 for every value of this expression.
+
+        The UUIDs are in the lowercase hyphenated form; the values of this expression
+        only determine the length and the name of the result.
+
+        Parameters
+        ----------
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated each time the UUIDs are generated.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3]})
+        >>> df.select(pl.col("a").uuid4().str.is_uuid())
+        shape: (3, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ bool │
+        ╞══════╡
+        │ true │
+        │ true │
+        │ true │
+        └──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.uuid4(seed))
+
     def sample(
         self,
         n: int | IntoExprColumn | None = None,
@@ -8482,12 +10126,13 @@ class Expr:
         self,
         com: float | None = None,
         span: float | None = None,
-        half_life: float | None = None,
+        half_life: float | timedelta | str | None = None,
         alpha: float | None = None,
         *,
         adjust: bool = True,
         min_periods: int = 1,
         ignore_nulls: bool = True,
+        by: IntoExpr | None = None,
     ) -> Self:
         r"""
         Exponentially-weighted moving average.
@@ -8510,6 +10155,9 @@ class Expr:
                 .. math::
                     \alpha = 1 - \exp \left\{ \frac{ -\ln(2) }{ \lambda } \right\} \;
                     \forall \; \lambda > 0
+
+            If `by` is set, the half-life is a duration instead, given as a timedelta
+            or a string such as `"3d"` or `"12h"`.
         alpha
             Specify smoothing factor alpha directly, :math:`0 < \alpha \leq 1`.
         adjust
@@ -8530,7 +10178,7 @@ class Expr:
         ignore_nulls
             Ignore missing values when calculating weights.
 
-                - When `ignore_nulls=False` (default), weights are based on absolute
+                - When `ignore_nulls=False`, weights are based on absolute
                   positions.
                   For example, the weights of :math:`x_0` and :math:`x_2` used in
                   calculating the final weighted average of
@@ -8538,13 +10186,22 @@ class Expr:
                   :math:`(1-\alpha)^2` and :math:`1` if `adjust=True`, and
                   :math:`(1-\alpha)^2` and :math:`\alpha` if `adjust=False`.
 
-                - When `ignore_nulls=True`, weights are based
+                - When `ignore_nulls=True` (default), weights are based
                   on relative positions. For example, the weights of
                   :math:`x_0` and :math:`x_2` used in calculating the final weighted
                   average of [:math:`x_0`, None, :math:`x_2`] are
                   :math:`1-\alpha` and :math:`1` if `adjust=True`,
                   and :math:`1-\alpha` and :math:`\alpha` if `adjust=False`.
 
+            If `by` is set, the weights are based on the time that has passed, so
+            nulls only have an effect if `adjust=False`.
+        by
+            Temporal column of dtype Date or Datetime, sorted in ascending order, with
+            the times of the observations. If set, the weight of an observation halves
+            every `half_life` that has passed since it was observed, which allows for
+            irregularly spaced observations. Only `half_life` can be used to specify
+            the decay.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -8559,7 +10216,36 @@ class Expr:
         │ 1.666667 │
         │ 2.428571 │
         └──────────┘
+
+        Let the weights decay with the time that has passed between observations:
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 5)],
+        ...         "a": [1, 2, 3],
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").ewm_mean(half_life="1d", by="date"))
+        shape: (3, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 1.0      │
+        │ 1.666667 │
+        │ 2.789474 │
+        └──────────┘
         """
+        if by is not None:
+            half_life = _prepare_half_life_by(com, span, half_life, alpha)
+            by = parse_as_expression(by)
+            return self._from_pyexpr(
+                self._pyexpr.ewm_mean_by(
+                    by, half_life, adjust, min_periods, ignore_nulls
+                )
+            )
         alpha = _prepare_alpha(com, span, half_life, alpha)
         return self._from_pyexpr(
             self._pyexpr.ewm_mean(alpha, adjust, min_periods, ignore_nulls)
@@ -8570,13 +10256,14 @@ class Expr:
         self,
         com: float | None = None,
         span: float | None = None,
-        half_life: float | None = None,
+        half_life: float | timedelta | str | None = None,
         alpha: float | None = None,
         *,
         adjust: bool = True,
         bias: bool = False,
         min_periods: int = 1,
         ignore_nulls: bool = True,
+        by: IntoExpr | None = None,
     ) -> Self:
         r"""
         Exponentially-weighted moving standard deviation.
@@ -8599,6 +10286,9 @@ class Expr:
                 .. math::
                     \alpha = 1 - \exp \left\{ \frac{ -\ln(2) }{ \lambda } \right\} \;
                     \forall \; \lambda > 0
+
+            If `by` is set, the half-life is a duration instead, given as a timedelta
+            or a string such as `"3d"` or `"12h"`.
         alpha
             Specify smoothing factor alpha directly, :math:`0 < \alpha \leq 1`.
         adjust
@@ -8622,7 +10312,7 @@ class Expr:
         ignore_nulls
             Ignore missing values when calculating weights.
 
-                - When `ignore_nulls=False` (default), weights are based on absolute
+                - When `ignore_nulls=False`, weights are based on absolute
                   positions.
                   For example, the weights of :math:`x_0` and :math:`x_2` used in
                   calculating the final weighted average of
@@ -8630,13 +10320,22 @@ class Expr:
                   :math:`(1-\alpha)^2` and :math:`1` if `adjust=True`, and
                   :math:`(1-\alpha)^2` and :math:`\alpha` if `adjust=False`.
 
-                - When `ignore_nulls=True`, weights are based
+                - When `ignore_nulls=True` (default), weights are based
                   on relative positions. For example, the weights of
                   :math:`x_0` and :math:`x_2` used in calculating the final weighted
                   average of [:math:`x_0`, None, :math:`x_2`] are
                   :math:`1-\alpha` and :math:`1` if `adjust=True`,
                   and :math:`1-\alpha` and :math:`\alpha` if `adjust=False`.
 
+            If `by` is set, the weights are based on the time that has passed, so
+            nulls only have an effect if `adjust=False`.
+        by
+            Temporal column of dtype Date or Datetime, sorted in ascending order, with
+            the times of the observations. If set, the weight of an observation halves
+            every `half_life` that has passed since it was observed, which allows for
+            irregularly spaced observations. Only `half_life` can be used to specify
+            the decay.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -8652,6 +10351,14 @@ class Expr:
         │ 0.963624 │
         └──────────┘
         """
+        if by is not None:
+            half_life = _prepare_half_life_by(com, span, half_life, alpha)
+            by = parse_as_expression(by)
+            return self._from_pyexpr(
+                self._pyexpr.ewm_std_by(
+                    by, half_life, adjust, bias, min_periods, ignore_nulls
+                )
+            )
         alpha = _prepare_alpha(com, span, half_life, alpha)
         return self._from_pyexpr(
             self._pyexpr.ewm_std(alpha, adjust, bias, min_periods, ignore_nulls)
@@ -8662,13 +10369,14 @@ class Expr:
         self,
         com: float | None = None,
         span: float | None = None,
-        half_life: float | None = None,
+        half_life: float | timedelta | str | None = None,
         alpha: float | None = None,
         *,
         adjust: bool = True,
         bias: bool = False,
         min_periods: int = 1,
         ignore_nulls: bool = True,
+        by: IntoExpr | None = None,
     ) -> Self:
         r"""
         Exponentially-weighted moving variance.
@@ -8691,6 +10399,9 @@ class Expr:
                 .. math::
                     \alpha = 1 - \exp \left\{ \frac{ -\ln(2) }{ \lambda } \right\} \;
                     \forall \; \lambda > 0
+
+            If `by` is set, the half-life is a duration instead, given as a timedelta
+            or a string such as `"3d"` or `"12h"`.
         alpha
             Specify smoothing factor alpha directly, :math:`0 < \alpha \leq 1`.
         adjust
@@ -8714,7 +10425,7 @@ class Expr:
         ignore_nulls
             Ignore missing values when calculating weights.
 
-                - When `ignore_nulls=False` (default), weights are based on absolute
+                - When `ignore_nulls=False`, weights are based on absolute
                   positions.
                   For example, the weights of :math:`x_0` and :math:`x_2` used in
                   calculating the final weighted average of
@@ -8722,13 +10433,22 @@ class Expr:
                   :math:`(1-\alpha)^2` and :math:`1` if `adjust=True`, and
                   :math:`(1-\alpha)^2` and :math:`\alpha` if `adjust=False`.
 
-                - When `ignore_nulls=True`, weights are based
+                - When `ignore_nulls=True` (default), weights are based
                   on relative positions. For example, the weights of
                   :math:`x_0` and :math:`x_2` used in calculating the final weighted
                   average of [:math:`x_0`, None, :math:`x_2`] are
                   :math:`1-\alpha` and :math:`1` if `adjust=True`,
                   and :math:`1-\alpha` and :math:`\alpha` if `adjust=False`.
 
+            If `by` is set, the weights are based on the time that has passed, so
+            nulls only have an effect if `adjust=False`.
+        by
+            Temporal column of dtype Date or Datetime, sorted in ascending order, with
+            the times of the observations. If set, the weight of an observation halves
+            every `half_life` that has passed since it was observed, which allows for
+            irregularly spaced observations. Only `half_life` can be used to specify
+            the decay.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -8744,6 +10464,14 @@ class Expr:
         │ 0.928571 │
         └──────────┘
         """
+        if by is not None:
+            half_life = _prepare_half_life_by(com, span, half_life, alpha)
+            by = parse_as_expression(by)
+            return self._from_pyexpr(
+                self._pyexpr.ewm_var_by(
+                    by, half_life, adjust, bias, min_periods, ignore_nulls
+                )
+            )
         alpha = _prepare_alpha(com, span, half_life, alpha)
         return self._from_pyexpr(
             self._pyexpr.ewm_var(alpha, adjust, bias, min_periods, ignore_nulls)
@@ -8981,7 +10709,9 @@ class Expr:
         breaking change.
 
         This can be really slow as it can have `O(n^2)` complexity. Don't use this
-        for operations that visit all elements.
+        for operations that visit all elements. The exceptions are the `sum`, `mean`,
+        `min`, `max` and `rank().last()` of :func:`element` on numeric data, which are
+        computed incrementally.
 
         Examples
         --------
@@ -9914,7 +11644,7 @@ class Expr:
 def _prepare_alpha(
     com: float | int | None = None,
     span: float | int | None = None,
-    half_life: float | int | None = None,
+    half_life: float | int | timedelta | str | None = None,
     alpha: float | int | None = None,
 ) -> float:
     """Normalise EWM decay specification in terms of smoothing factor 'alpha'."""
@@ -9936,6 +11666,9 @@ def _prepare_alpha(
         alpha = 2.0 / (span + 1.0)
 
     elif half_life is not None:
+        if isinstance(half_life, (str, timedelta)):
+            msg = "a duration `half_life` can only be used if `by` is set"
+            raise TypeError(msg)
         if half_life <= 0.0:
             msg = f"require `half_life` > 0 (found {half_life!r})"
             raise ValueError(msg)
@@ -9952,6 +11685,30 @@ def _prepare_alpha(
     return alpha
 
 
+def _prepare_half_life_by(
+    com: float | int | None = None,
+    span: float | int | None = None,
+    half_life: float | int | timedelta | str | None = None,
+    alpha: float | int | None = None,
+) -> str:
+    """Normalise EWM decay specification of a time-based EWM as a duration string."""
+    if com is not None or span is not None or alpha is not None:
+        msg = "only `half_life` can be used to specify the decay if `by` is set"
+        raise ValueError(msg)
+    if half_life is None:
+        msg = "`half_life` must be set if `by` is set"
+        raise ValueError(msg)
+    if isinstance(half_life, timedelta):
+        return _timedelta_to_pl_duration(half_life)
+    if not isinstance(half_life, str):
+        msg = (
+            "`half_life` must be a timedelta or a duration string if `by` is set"
+            f" (found {half_life!r})"
+        )
+        raise TypeError(msg)
+    return half_life
+
+
 def _prepare_rolling_window_args(
     window_size: int | timedelta | str,
     min_periods: int | None = None,
@@ -9969,3 +11726,9 @@ def _prepare_rolling_window_args(
     if min_periods is None:
         min_periods = 1
     return window_size, min_periods
+
+
+def _prepare_rolling_by_window_args(window_size: timedelta | str) -> str:
+    if isinstance(window_size, timedelta):
+        window_size = _timedelta_to_pl_duration(window_size)
+    return window_size
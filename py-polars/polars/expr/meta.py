@@ -217,6 +217,10 @@ class ExprMetaNameSpace:
         """& selectors."""
         return wrap_expr(self._pyexpr._meta_selector_and(other._pyexpr))
 
+    def _to_sql_predicate(self) -> str | None:
+        """SQL condition that selects at least the rows this predicate selects."""
+        return self._pyexpr._meta_to_sql_predicate()
+
     @overload
     def write_json(self, file: None = ...) -> str:
         ...
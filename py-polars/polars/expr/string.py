@@ -20,6 +20,7 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars.type_aliases import (
         Ambiguous,
+        DurationFormat,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
@@ -202,6 +203,60 @@ class ExprStringNameSpace:
         _validate_format_argument(format)
         return wrap_expr(self._pyexpr.str_to_time(format, strict, cache))
 
+    def to_duration(
+        self,
+        format: DurationFormat = "polars",
+        *,
+        time_unit: TimeUnit = "us",
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Convert a String column into a Duration column.
+
+        Parameters
+        ----------
+        format : {'polars', 'iso'}
+            Format of the durations.
+
+            - `'polars'`: the format used to display a duration, e.g. `"1d 2h 3m"`.
+              The units `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns` are supported.
+            - `'iso'`: an ISO 8601 duration, e.g. `"P1DT2H3M"`. Years and months
+              are not supported as their length isn't fixed.
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration.
+        strict
+            Raise an error if any conversion fails. If set to False, values that
+            cannot be parsed or don't fit in the time unit are set to null.
+
+        See Also
+        --------
+        Expr.dt.to_string : Convert a Duration back into a String.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "polars": ["1d 2h 3m", "-5s", None],
+        ...         "iso": ["P1DT2H3M", "-PT5S", None],
+        ...     }
+        ... )
+        >>> df.select(
+        ...     pl.col("polars").str.to_duration(),
+        ...     pl.col("iso").str.to_duration("iso", time_unit="ms"),
+        ... )
+        shape: (3, 2)
+        ┌──────────────┬──────────────┐
+        │ polars       ┆ iso          │
+        │ ---          ┆ ---          │
+        │ duration[μs] ┆ duration[ms] │
+        ╞══════════════╪══════════════╡
+        │ 1d 2h 3m     ┆ 1d 2h 3m     │
+        │ -5s          ┆ -5s          │
+        │ null         ┆ null         │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(format, time_unit, strict))
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -1240,6 +1295,112 @@ class ExprStringNameSpace:
         prefix = parse_as_expression(prefix, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_starts_with(prefix))
 
+    def levenshtein(self, other: str | Expr) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the number of single character insertions, deletions and
+        substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        See Also
+        --------
+        hamming : Compute the Hamming distance to another string.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["kitten", "flaw", "polars"], "b": ["sitting", "lawn", None]}
+        ... )
+        >>> df.with_columns(distance=pl.col("a").str.levenshtein(pl.col("b")))
+        shape: (3, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ distance │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ u32      │
+        ╞════════╪═════════╪══════════╡
+        │ kitten ┆ sitting ┆ 3        │
+        │ flaw   ┆ lawn    ┆ 2        │
+        │ polars ┆ null    ┆ null     │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other))
+
+    def hamming(self, other: str | Expr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of different lengths result in a null.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["karolin", "kathrin", "kart"]})
+        >>> df.with_columns(distance=pl.col("a").str.hamming("kathrin"))
+        shape: (3, 2)
+        ┌─────────┬──────────┐
+        │ a       ┆ distance │
+        │ ---     ┆ ---      │
+        │ str     ┆ u32      │
+        ╞═════════╪══════════╡
+        │ karolin ┆ 3        │
+        │ kathrin ┆ 0        │
+        │ kart    ┆ null     │
+        └─────────┴──────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other))
+
+    def jaro_winkler(self, other: str | Expr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0.0 for completely different strings and 1.0 for
+        equal strings. Strings sharing a common prefix get a higher similarity.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+        hamming : Compute the Hamming distance to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["MARTHA", "DWAYNE"], "b": ["MARHTA", "DUANE"]})
+        >>> df.with_columns(similarity=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (2, 3)
+        ┌────────┬────────┬────────────┐
+        │ a      ┆ b      ┆ similarity │
+        │ ---    ┆ ---    ┆ ---        │
+        │ str    ┆ str    ┆ f64        │
+        ╞════════╪════════╪════════════╡
+        │ MARTHA ┆ MARHTA ┆ 0.961111   │
+        │ DWAYNE ┆ DUANE  ┆ 0.84       │
+        └────────┴────────┴────────────┘
+        """
+        other = parse_as_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
     def json_decode(
         self, dtype: PolarsDataType | None = None, infer_schema_length: int | None = 100
     ) -> Expr:
@@ -1384,6 +1545,74 @@ class ExprStringNameSpace:
             msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
             raise ValueError(msg)
 
+    def is_uuid(self) -> Expr:
+        """
+        Check if the string values are valid UUIDs.
+
+        Both the hyphenated and the simple form are accepted, optionally wrapped in
+        braces or prefixed with `urn:uuid:`. The hexadecimal digits are case
+        insensitive.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"id": ["67e55044-10b1-426f-9247-bb680e5fe0c8", "67e55044", None]}
+        ... )
+        >>> df.with_columns(is_uuid=pl.col("id").str.is_uuid())
+        shape: (3, 2)
+        ┌──────────────────────────────────────┬─────────┐
+        │ id                                   ┆ is_uuid │
+        │ ---                                  ┆ ---     │
+        │ str                                  ┆ bool    │
+        ╞══════════════════════════════════════╪═════════╡
+        │ 67e55044-10b1-426f-9247-bb680e5fe0c8 ┆ true    │
+        │ 67e55044                             ┆ false   │
+        │ null                                 ┆ null    │
+        └──────────────────────────────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_is_uuid())
+
+    def to_uuid(self, *, strict: bool = True) -> Expr:
+        """
+        Parse the string values as UUIDs into their 16 bytes.
+
+        The accepted forms are the same as for :meth:`is_uuid`.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid UUID, otherwise mask out with a
+            null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        Expr.bin.format_uuid : Format the 16 bytes of a UUID as a string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": ["{67E5504410B1426F9247BB680E5FE0C8}"]})
+        >>> df.select(pl.col("id").str.to_uuid().bin.format_uuid())
+        shape: (1, 1)
+        ┌──────────────────────────────────────┐
+        │ id                                   │
+        │ ---                                  │
+        │ str                                  │
+        ╞══════════════════════════════════════╡
+        │ 67e55044-10b1-426f-9247-bb680e5fe0c8 │
+        └──────────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_uuid(strict))
+
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Expr:
         r"""
         Extract the target capture group from provided patterns.
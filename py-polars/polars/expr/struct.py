@@ -1,11 +1,13 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Sequence
+from typing import TYPE_CHECKING, Iterable, Sequence
 
+from polars.utils._parse_expr_input import parse_as_list_of_expressions
 from polars.utils._wrap import wrap_expr
 
 if TYPE_CHECKING:
     from polars import Expr
+    from polars.type_aliases import IntoExpr
 
 
 class ExprStructNameSpace:
@@ -168,3 +170,52 @@ class ExprStructNameSpace:
         └──────────────────┴────────────────────────┘
         """
         return wrap_expr(self._pyexpr.struct_json_encode())
+
+    def with_fields(
+        self,
+        *exprs: IntoExpr | Iterable[IntoExpr],
+        **named_exprs: IntoExpr,
+    ) -> Expr:
+        """
+        Add or overwrite fields of this struct.
+
+        Fields are matched on the output name of the given expressions. Existing
+        fields keep their position, new fields are appended at the end.
+
+        Parameters
+        ----------
+        *exprs
+            Field(s) to add, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_exprs
+            Additional fields to add, specified as keyword arguments.
+            The fields will be renamed to the keyword used.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "coords": [{"x": 1, "y": 4}, {"x": 4, "y": 9}],
+        ...         "multiply": [10, 2],
+        ...     }
+        ... )
+        >>> df.select(
+        ...     pl.col("coords").struct.with_fields(
+        ...         (pl.col("coords").struct.field("x") * pl.col("multiply")).alias("x"),
+        ...         x_y=pl.col("coords").struct.field("x")
+        ...         * pl.col("coords").struct.field("y"),
+        ...     )
+        ... ).unnest("coords")
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ x   ┆ y   ┆ x_y │
+        │ --- ┆ --- ┆ --- │
+        │ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ 10  ┆ 4   ┆ 4   │
+        │ 8   ┆ 9   ┆ 36  │
+        └─────┴─────┴─────┘
+        """
+        pyexprs = parse_as_list_of_expressions(*exprs, **named_exprs)
+        return wrap_expr(self._pyexpr.struct_with_fields(pyexprs))
@@ -25,7 +25,7 @@ from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
 from polars.functions.col import col
-from polars.functions.eager import align_frames, concat
+from polars.functions.eager import align_frames, concat, concat_sorted
 from polars.functions.lazy import (
     apply,
     approx_n_unique,
@@ -50,6 +50,7 @@ from polars.functions.lazy import (
     fold,
     from_epoch,
     groups,
+    haversine_distance,
     head,
     implode,
     last,
@@ -107,6 +108,7 @@ __all__ = [
     "approx_n_unique",
     "arg_where",
     "concat",
+    "concat_sorted",
     "date_range",
     "date_ranges",
     "datetime_range",
@@ -146,6 +148,7 @@ __all__ = [
     "format",
     "from_epoch",
     "groups",
+    "haversine_distance",
     "head",
     "implode",
     "int_range",
@@ -75,6 +75,7 @@ def datetime_(
         - `'raise'` (default): raise
         - `'earliest'`: use the earliest datetime
         - `'latest'`: use the latest datetime
+        - `'null'`: set to null
 
 
     Returns
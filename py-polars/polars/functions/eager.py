@@ -253,6 +253,63 @@ def concat(
     return out
 
 
+def concat_sorted(items: Iterable[FrameType], by: str) -> FrameType:
+    """
+    Merge multiple DataFrames or LazyFrames that are sorted by a key into one.
+
+    The frames are merged pairwise, without re-sorting the data. The output is
+    sorted by `by` and has its sorted flag set.
+    It is the callers responsibility that all frames are sorted ascending by that
+    key, otherwise the output will not make sense.
+
+    The schemas of all frames must be equal.
+
+    Parameters
+    ----------
+    items
+        DataFrames or LazyFrames that are sorted by `by`.
+    by
+        Key that is sorted.
+
+    Examples
+    --------
+    >>> df1 = pl.DataFrame({"day": [1, 3, 5], "value": ["a", "b", "c"]})
+    >>> df2 = pl.DataFrame({"day": [2, 3, 6], "value": ["d", "e", "f"]})
+    >>> df3 = pl.DataFrame({"day": [4], "value": ["g"]})
+    >>> pl.concat_sorted([df1, df2, df3], by="day")
+    shape: (7, 2)
+    ┌─────┬───────┐
+    │ day ┆ value │
+    │ --- ┆ ---   │
+    │ i64 ┆ str   │
+    ╞═════╪═══════╡
+    │ 1   ┆ a     │
+    │ 2   ┆ d     │
+    │ 3   ┆ b     │
+    │ 3   ┆ e     │
+    │ 4   ┆ g     │
+    │ 5   ┆ c     │
+    │ 6   ┆ f     │
+    └─────┴───────┘
+    """
+    elems = list(items)
+
+    if not len(elems) > 0:
+        msg = "cannot concat empty list"
+        raise ValueError(msg)
+
+    first = elems[0]
+    if isinstance(first, pl.DataFrame):
+        return wrap_ldf(
+            plr.concat_lf_sorted([df.lazy() for df in elems], by)
+        ).collect(no_optimization=True)
+    elif isinstance(first, pl.LazyFrame):
+        return wrap_ldf(plr.concat_lf_sorted(elems, by))
+    else:
+        msg = f"did not expect type: {type(first).__name__!r} in `concat_sorted`"
+        raise TypeError(msg)
+
+
 def _alignment_join(
     *idx_frames: tuple[int, LazyFrame],
     align_on: list[str],
@@ -1430,6 +1430,59 @@ def arctan2d(y: str | Expr, x: str | Expr) -> Expr:
     return wrap_expr(plr.arctan2d(y._pyexpr, x._pyexpr))
 
 
+def haversine_distance(
+    lat1: IntoExpr, lon1: IntoExpr, lat2: IntoExpr, lon2: IntoExpr
+) -> Expr:
+    """
+    Compute the great-circle distance between two points on the earth.
+
+    The points are given by their latitude and longitude in degrees, the distance is
+    in kilometers.
+
+    Parameters
+    ----------
+    lat1
+        The latitude of the first point. Accepts expression input, strings are
+        parsed as column names.
+    lon1
+        The longitude of the first point.
+    lat2
+        The latitude of the second point.
+    lon2
+        The longitude of the second point.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "city": ["Paris", "London"],
+    ...         "lat": [48.8566, 51.5074],
+    ...         "lon": [2.3522, -0.1278],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     from_amsterdam=pl.haversine_distance(52.3676, 4.9041, "lat", "lon").round(1)
+    ... )
+    shape: (2, 4)
+    ┌────────┬─────────┬─────────┬────────────────┐
+    │ city   ┆ lat     ┆ lon     ┆ from_amsterdam │
+    │ ---    ┆ ---     ┆ ---     ┆ ---            │
+    │ str    ┆ f64     ┆ f64     ┆ f64            │
+    ╞════════╪═════════╪═════════╪════════════════╡
+    │ Paris  ┆ 48.8566 ┆ 2.3522  ┆ 429.9          │
+    │ London ┆ 51.5074 ┆ -0.1278 ┆ 357.9          │
+    └────────┴─────────┴─────────┴────────────────┘
+    """
+    return wrap_expr(
+        plr.haversine_distance(
+            parse_as_expression(lat1),
+            parse_as_expression(lon1),
+            parse_as_expression(lat2),
+            parse_as_expression(lon2),
+        )
+    )
+
+
 def exclude(
     columns: str | PolarsDataType | Collection[str] | Collection[PolarsDataType],
     *more_columns: str | PolarsDataType,
@@ -36,10 +36,11 @@ class BatchedCsvReader:
         quote_char: str | None = '"',
         skip_rows: int = 0,
         dtypes: None | (SchemaDict | Sequence[PolarsDataType]) = None,
-        null_values: str | Sequence[str] | dict[str, str] | None = None,
+        null_values: str | Sequence[str] | dict[str, str | Sequence[str]] | None = None,
         missing_utf8_is_empty_string: bool = False,
         ignore_errors: bool = False,
         try_parse_dates: bool = False,
+        decimal_comma: bool = False,
         n_threads: int | None = None,
         infer_schema_length: int | None = N_INFER_DEFAULT,
         batch_size: int = 50_000,
@@ -98,6 +99,7 @@ class BatchedCsvReader:
             null_values=processed_null_values,
             missing_utf8_is_empty_string=missing_utf8_is_empty_string,
             try_parse_dates=try_parse_dates,
+            decimal_comma=decimal_comma,
             skip_rows_after_header=skip_rows_after_header,
             row_index=_prepare_row_index_args(row_index_name, row_index_offset),
             sample_size=sample_size,
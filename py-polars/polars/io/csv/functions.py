@@ -35,10 +35,11 @@ def read_csv(
     skip_rows: int = 0,
     dtypes: Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None = None,
     schema: SchemaDict | None = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str | Sequence[str] | dict[str, str | Sequence[str]] | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     try_parse_dates: bool = False,
+    decimal_comma: bool = False,
     n_threads: int | None = None,
     infer_schema_length: int | None = N_INFER_DEFAULT,
     batch_size: int = 8192,
@@ -90,6 +91,9 @@ def read_csv(
         Start reading after `skip_rows` lines.
     dtypes
         Overwrite dtypes for specific or all columns during schema inference.
+        A column name key that starts with `^` and ends with `$` is a regular
+        expression, which sets the dtype of all columns whose name matches it;
+        the dtypes of columns that are named explicitly take precedence.
     schema
         Provide the schema. This means that polars doesn't do schema inference.
         This argument expects the complete schema, whereas `dtypes` can be used
@@ -99,8 +103,8 @@ def read_csv(
 
         - `str`: All values equal to this string will be null.
         - `List[str]`: All values equal to any string in this list will be null.
-        - `Dict[str, str]`: A dictionary that maps column name to a
-          null value string.
+        - `Dict[str, str | List[str]]`: A dictionary that maps column name to a
+          null value string, or to a list of null value strings.
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
         utf8 values to be treated as the empty string you can set this param True.
@@ -116,6 +120,9 @@ def read_csv(
         be inferred, as well as a handful of others. If this does not succeed,
         the column remains of data type `pl.String`.
         If `use_pyarrow=True`, dates will always be parsed.
+    decimal_comma
+        Parse floats using a comma as the decimal separator instead of a period,
+        e.g. `3,14`. This can't be combined with `separator=","`.
     n_threads
         Number of threads to use in csv parsing.
         Defaults to the number of physical cpu's of your system.
@@ -238,6 +245,7 @@ def read_csv(
         and n_threads is None
         and not low_memory
         and null_values is None
+        and not decimal_comma
     ):
         include_columns: Sequence[str] | None = None
         if columns:
@@ -408,6 +416,7 @@ def read_csv(
             missing_utf8_is_empty_string=missing_utf8_is_empty_string,
             ignore_errors=ignore_errors,
             try_parse_dates=try_parse_dates,
+            decimal_comma=decimal_comma,
             n_threads=n_threads,
             infer_schema_length=infer_schema_length,
             batch_size=batch_size,
@@ -445,10 +454,11 @@ def read_csv_batched(
     quote_char: str | None = '"',
     skip_rows: int = 0,
     dtypes: Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str | Sequence[str] | dict[str, str | Sequence[str]] | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     try_parse_dates: bool = False,
+    decimal_comma: bool = False,
     n_threads: int | None = None,
     infer_schema_length: int | None = N_INFER_DEFAULT,
     batch_size: int = 50_000,
@@ -506,8 +516,8 @@ def read_csv_batched(
 
         - `str`: All values equal to this string will be null.
         - `List[str]`: All values equal to any string in this list will be null.
-        - `Dict[str, str]`: A dictionary that maps column name to a
-          null value string.
+        - `Dict[str, str | List[str]]`: A dictionary that maps column name to a
+          null value string, or to a list of null value strings.
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
         utf8 values to be treated as the empty string you can set this param True.
@@ -519,6 +529,9 @@ def read_csv_batched(
         Try to automatically parse dates. Most ISO8601-like formats can
         be inferred, as well as a handful of others. If this does not succeed,
         the column remains of data type `pl.String`.
+    decimal_comma
+        Parse floats using a comma as the decimal separator instead of a period,
+        e.g. `3,14`. This can't be combined with `separator=","`.
     n_threads
         Number of threads to use in csv parsing.
         Defaults to the number of physical cpu's of your system.
@@ -708,6 +721,7 @@ def read_csv_batched(
         missing_utf8_is_empty_string=missing_utf8_is_empty_string,
         ignore_errors=ignore_errors,
         try_parse_dates=try_parse_dates,
+        decimal_comma=decimal_comma,
         n_threads=n_threads,
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
@@ -740,7 +754,7 @@ def scan_csv(
     skip_rows: int = 0,
     dtypes: SchemaDict | Sequence[PolarsDataType] | None = None,
     schema: SchemaDict | None = None,
-    null_values: str | Sequence[str] | dict[str, str] | None = None,
+    null_values: str | Sequence[str] | dict[str, str | Sequence[str]] | None = None,
     missing_utf8_is_empty_string: bool = False,
     ignore_errors: bool = False,
     cache: bool = True,
@@ -754,10 +768,12 @@ def scan_csv(
     row_index_name: str | None = None,
     row_index_offset: int = 0,
     try_parse_dates: bool = False,
+    decimal_comma: bool = False,
     eol_char: str = "\n",
     new_columns: Sequence[str] | None = None,
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
+    include_file_paths: str | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -789,7 +805,9 @@ def scan_csv(
     dtypes
         Overwrite dtypes during inference; should be a {colname:dtype,} dict or,
         if providing a list of strings to `new_columns`, a list of dtypes of
-        the same length.
+        the same length. A column name key that starts with `^` and ends with `$`
+        is a regular expression, which sets the dtype of all columns whose name
+        matches it.
     schema
         Provide the schema. This means that polars doesn't do schema inference.
         This argument expects the complete schema, whereas `dtypes` can be used
@@ -799,8 +817,8 @@ def scan_csv(
 
         - `str`: All values equal to this string will be null.
         - `List[str]`: All values equal to any string in this list will be null.
-        - `Dict[str, str]`: A dictionary that maps column name to a
-          null value string.
+        - `Dict[str, str | List[str]]`: A dictionary that maps column name to a
+          null value string, or to a list of null value strings.
     missing_utf8_is_empty_string
         By default a missing value is considered to be null; if you would prefer missing
         utf8 values to be treated as the empty string you can set this param True.
@@ -837,6 +855,9 @@ def scan_csv(
         Try to automatically parse dates. Most ISO8601-like formats
         can be inferred, as well as a handful of others. If this does not succeed,
         the column remains of data type `pl.String`.
+    decimal_comma
+        Parse floats using a comma as the decimal separator instead of a period,
+        e.g. `3,14`. This can't be combined with `separator=","`.
     eol_char
         Single byte end of line character (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
@@ -850,6 +871,9 @@ def scan_csv(
         is set to False, an empty LazyFrame (with no columns) is returned instead.
     truncate_ragged_lines
         Truncate lines that are longer than the schema.
+    include_file_paths
+        Insert a column with this name that holds the path of the file each row was
+        read from. This is useful when scanning multiple files.
 
     Returns
     -------
@@ -965,7 +989,9 @@ def scan_csv(
         row_index_name=row_index_name,
         row_index_offset=row_index_offset,
         try_parse_dates=try_parse_dates,
+        decimal_comma=decimal_comma,
         eol_char=eol_char,
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
+        include_file_paths=include_file_paths,
     )
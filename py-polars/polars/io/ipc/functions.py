@@ -224,6 +224,7 @@ def scan_ipc(
     row_index_offset: int = 0,
     storage_options: dict[str, Any] | None = None,
     memory_map: bool = True,
+    hive_partitioning: bool = True,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -254,6 +255,9 @@ def scan_ipc(
         Try to memory map the file. This can greatly improve performance on repeated
         queries as the OS may cache pages.
         Only uncompressed IPC files can be memory mapped.
+    hive_partitioning
+        Infer statistics and schema from hive partitioned URL and use them
+        to prune reads.
     """
     return pl.LazyFrame._scan_ipc(
         source,
@@ -264,4 +268,5 @@ def scan_ipc(
         row_index_offset=row_index_offset,
         storage_options=storage_options,
         memory_map=memory_map,
+        hive_partitioning=hive_partitioning,
     )
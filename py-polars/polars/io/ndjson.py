@@ -67,13 +67,19 @@ def scan_ndjson(
     row_index_name: str | None = None,
     row_index_offset: int = 0,
     schema: SchemaDefinition | None = None,
+    schema_overrides: SchemaDefinition | None = None,
     ignore_errors: bool = False,
+    include_file_paths: str | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
 
     This allows the query optimizer to push down predicates and projections to the scan
-    level, thereby potentially reducing memory overhead.
+    level, thereby potentially reducing memory overhead. Only the keys of the projected
+    columns are parsed; the values of all other keys are skipped.
+
+    Lines do not need to contain the same keys: keys that are not in the schema are
+    ignored and keys of the schema that are missing in a line are read as `null`.
 
     Parameters
     ----------
@@ -104,13 +110,22 @@ def scan_ndjson(
         If you supply a list of column names that does not match the names in the
         underlying data, the names given here will overwrite them. The number
         of names given in the schema should match the underlying data dimensions.
+    schema_overrides : dict, default None
+        Support type specification or override of one or more columns; note that
+        any dtypes inferred from the schema param will be overridden. Columns that
+        are not in the (inferred) schema are added, which allows reading keys that
+        only appear after the first `infer_schema_length` rows.
     ignore_errors
         Return `Null` if parsing fails because of schema mismatches.
+    include_file_paths
+        Insert a column with this name that holds the path of the file each row was
+        read from. This is useful when scanning multiple files.
     """
     return pl.LazyFrame._scan_ndjson(
         source,
         infer_schema_length=infer_schema_length,
         schema=schema,
+        schema_overrides=schema_overrides,
         batch_size=batch_size,
         n_rows=n_rows,
         low_memory=low_memory,
@@ -118,4 +133,5 @@ def scan_ndjson(
         row_index_name=row_index_name,
         row_index_offset=row_index_offset,
         ignore_errors=ignore_errors,
+        include_file_paths=include_file_paths,
     )
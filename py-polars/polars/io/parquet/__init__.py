@@ -1,7 +1,13 @@
-from polars.io.parquet.functions import read_parquet, read_parquet_schema, scan_parquet
+from polars.io.parquet.functions import (
+    read_parquet,
+    read_parquet_field_ids,
+    read_parquet_schema,
+    scan_parquet,
+)
 
 __all__ = [
     "read_parquet",
+    "read_parquet_field_ids",
     "read_parquet_schema",
     "scan_parquet",
 ]
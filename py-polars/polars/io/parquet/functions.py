@@ -13,11 +13,12 @@ from polars.utils.deprecation import deprecate_renamed_parameter
 from polars.utils.various import is_int_sequence, normalize_filepath
 
 with contextlib.suppress(ImportError):
+    from polars.polars import read_parquet_field_ids as _read_parquet_field_ids
     from polars.polars import read_parquet_schema as _read_parquet_schema
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
-    from polars.type_aliases import ParallelStrategy
+    from polars.type_aliases import MissingColumns, ParallelStrategy
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -36,6 +37,7 @@ def read_parquet(
     low_memory: bool = False,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    missing_columns: MissingColumns = "raise",
     use_pyarrow: bool = False,
     pyarrow_options: dict[str, Any] | None = None,
     memory_map: bool = True,
@@ -92,6 +94,15 @@ def read_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    missing_columns : {'raise', 'insert_null'}
+        What to do if a column exists in some of the files, but not in all of them.
+
+        * 'raise': Raise an error.
+        * 'insert_null': Insert the column as nulls in the files that don't have it.
+
+        Numeric columns of which the type differs between the files are read as their
+        supertype, e.g. a column that is `Int32` in one file and `Int64` in another
+        is read as `Int64`.
     use_pyarrow
         Use pyarrow instead of the Rust native parquet reader. The pyarrow reader is
         more stable.
@@ -181,6 +192,7 @@ def read_parquet(
         cache=False,
         storage_options=storage_options,
         retries=retries,
+        missing_columns=missing_columns,
     )
 
     if columns is not None:
@@ -213,6 +225,45 @@ def read_parquet_schema(source: str | Path | IO[bytes] | bytes) -> dict[str, Dat
     return _read_parquet_schema(source)
 
 
+def read_parquet_field_ids(source: str | Path | IO[bytes] | bytes) -> dict[str, int]:
+    """
+    Get the field ids of a Parquet file without reading data.
+
+    Field ids identify the fields of a file independent of their names, as required
+    by table formats such as Apache Iceberg. Pass them to the `field_ids` parameter
+    of :meth:`DataFrame.write_parquet` to preserve them when a file is rewritten.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by file-like object, we refer to objects
+        that have a `read()` method, such as a file handler (e.g. via builtin `open`
+        function) or `BytesIO`).
+
+    Returns
+    -------
+    dict
+        Dictionary mapping the paths of the fields that have an id to their id. The
+        path of a nested field joins the names of the field and its parents with
+        `"."`, where the items of a list are addressed by the name of the list's
+        element field.
+
+    Examples
+    --------
+    >>> import pathlib
+    >>>
+    >>> df = pl.DataFrame({"a": [1, 2], "s": [{"x": 1}, {"x": 2}]})
+    >>> path: pathlib.Path = dirpath / "field_ids.parquet"
+    >>> df.write_parquet(path, field_ids={"a": 1, "s": 2, "s.x": 3})
+    >>> pl.read_parquet_field_ids(path)
+    {'a': 1, 's': 2, 's.x': 3}
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+
+    return _read_parquet_field_ids(source)
+
+
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
 @deprecate_renamed_parameter("row_count_offset", "row_index_offset", version="0.20.4")
 def scan_parquet(
@@ -229,6 +280,8 @@ def scan_parquet(
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    missing_columns: MissingColumns = "raise",
+    include_file_paths: str | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -280,6 +333,18 @@ def scan_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    missing_columns : {'raise', 'insert_null'}
+        What to do if a column exists in some of the files, but not in all of them.
+
+        * 'raise': Raise an error.
+        * 'insert_null': Insert the column as nulls in the files that don't have it.
+
+        Numeric columns of which the type differs between the files are read as their
+        supertype, e.g. a column that is `Int32` in one file and `Int64` in another
+        is read as `Int64`.
+    include_file_paths
+        Insert a column with this name that holds the path of the file each row was
+        read from. This is useful when scanning multiple files.
 
     See Also
     --------
@@ -321,4 +386,6 @@ def scan_parquet(
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
         retries=retries,
+        missing_columns=missing_columns,
+        include_file_paths=include_file_paths,
     )
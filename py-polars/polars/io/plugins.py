@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING, Callable
+
+import polars._reexport as pl
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame, Expr, LazyFrame
+    from polars.type_aliases import SchemaDict
+
+
+def register_io_source(
+    callable: Callable[[list[str] | None, Expr | None, int | None], DataFrame],
+    schema: SchemaDict,
+) -> LazyFrame:
+    """
+    Register your IO plugin and initialize a LazyFrame.
+
+    The `callable` is called with the columns that are projected, the predicate that is
+    pushed down and the number of rows that should be read. The predicate can be used to
+    prune data at the source (e.g. a REST API or an internal file format). Polars applies
+    the predicate again on the returned data, so pruning doesn't have to be exact.
+
+    Parameters
+    ----------
+    callable
+        Function that accepts the projected columns, the predicate as an `Expr` (or
+        `None`) and the number of rows (or `None`) and returns a `DataFrame`.
+    schema
+        Schema of the data the source produces.
+
+    Examples
+    --------
+    >>> from polars.io.plugins import register_io_source
+    >>> def source(with_columns, predicate, n_rows):
+    ...     df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    ...     if predicate is not None:
+    ...         df = df.filter(predicate)
+    ...     if with_columns is not None:
+    ...         df = df.select(with_columns)
+    ...     return df if n_rows is None else df.head(n_rows)
+    >>> lf = register_io_source(source, schema={"a": pl.Int64, "b": pl.String})
+    >>> lf.filter(pl.col("a") > 1).select("b").collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ str │
+    ╞═════╡
+    │ y   │
+    │ z   │
+    └─────┘
+    """
+
+    def wrapped(
+        with_columns: list[str] | None, predicate: str | None, n_rows: int | None
+    ) -> DataFrame:
+        parsed_predicate = None
+        if predicate is not None:
+            parsed_predicate = pl.Expr.from_json(predicate)
+        return callable(with_columns, parsed_predicate, n_rows)
+
+    return pl.LazyFrame._from_pyldf(
+        PyLazyFrame.scan_from_io_plugin(list(schema.items()), wrapped)
+    )
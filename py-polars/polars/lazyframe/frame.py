@@ -74,6 +74,7 @@ from polars.utils.various import (
     _in_notebook,
     _prepare_row_index_args,
     _process_null_values,
+    _split_regex_dtypes,
     is_bool_sequence,
     is_sequence,
     normalize_filepath,
@@ -97,6 +98,7 @@ if TYPE_CHECKING:
         ColumnNameOrSelector,
         CsvEncoding,
         CsvQuoteStyle,
+        ExplodeMismatch,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -104,8 +106,10 @@ if TYPE_CHECKING:
         JoinStrategy,
         JoinValidation,
         Label,
+        MissingColumns,
         Orientation,
         ParallelStrategy,
+        PivotAgg,
         PolarsDataType,
         RollingInterpolationMethod,
         SchemaDefinition,
@@ -328,7 +332,7 @@ class LazyFrame:
         skip_rows: int = 0,
         dtypes: SchemaDict | None = None,
         schema: SchemaDict | None = None,
-        null_values: str | Sequence[str] | dict[str, str] | None = None,
+        null_values: str | Sequence[str] | dict[str, str | Sequence[str]] | None = None,
         missing_utf8_is_empty_string: bool = False,
         ignore_errors: bool = False,
         cache: bool = True,
@@ -342,9 +346,11 @@ class LazyFrame:
         row_index_name: str | None = None,
         row_index_offset: int = 0,
         try_parse_dates: bool = False,
+        decimal_comma: bool = False,
         eol_char: str = "\n",
         raise_if_empty: bool = True,
         truncate_ragged_lines: bool = True,
+        include_file_paths: str | None = None,
     ) -> Self:
         """
         Lazily read from a CSV file or multiple files via glob patterns.
@@ -356,10 +362,9 @@ class LazyFrame:
         polars.io.scan_csv
         """
         dtype_list: list[tuple[str, PolarsDataType]] | None = None
+        dtype_regex: list[tuple[str, PolarsDataType]] | None = None
         if dtypes is not None:
-            dtype_list = []
-            for k, v in dtypes.items():
-                dtype_list.append((k, py_type_to_dtype(v)))
+            dtype_list, dtype_regex = _split_regex_dtypes(dtypes)
         processed_null_values = _process_null_values(null_values)
 
         if isinstance(source, list):
@@ -379,6 +384,7 @@ class LazyFrame:
             n_rows,
             cache,
             dtype_list,
+            dtype_regex,
             low_memory,
             comment_prefix,
             quote_char,
@@ -391,10 +397,12 @@ class LazyFrame:
             encoding,
             _prepare_row_index_args(row_index_name, row_index_offset),
             try_parse_dates,
+            decimal_comma,
             eol_char=eol_char,
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             schema=schema,
+            include_file_paths=include_file_paths,
         )
         return self
 
@@ -414,6 +422,8 @@ class LazyFrame:
         use_statistics: bool = True,
         hive_partitioning: bool = True,
         retries: int = 0,
+        missing_columns: MissingColumns = "raise",
+        include_file_paths: str | None = None,
     ) -> Self:
         """
         Lazily read from a parquet file or multiple files via glob patterns.
@@ -465,6 +475,8 @@ class LazyFrame:
             use_statistics=use_statistics,
             hive_partitioning=hive_partitioning,
             retries=retries,
+            missing_columns=missing_columns,
+            include_file_paths=include_file_paths,
         )
         return self
 
@@ -480,6 +492,7 @@ class LazyFrame:
         row_index_offset: int = 0,
         storage_options: dict[str, object] | None = None,
         memory_map: bool = True,
+        hive_partitioning: bool = True,
     ) -> Self:
         """
         Lazily read from an Arrow IPC (Feather v2) file.
@@ -517,6 +530,7 @@ class LazyFrame:
             rechunk,
             _prepare_row_index_args(row_index_name, row_index_offset),
             memory_map=memory_map,
+            hive_partitioning=hive_partitioning,
         )
         return self
 
@@ -527,6 +541,7 @@ class LazyFrame:
         *,
         infer_schema_length: int | None = None,
         schema: SchemaDefinition | None = None,
+        schema_overrides: SchemaDefinition | None = None,
         batch_size: int | None = None,
         n_rows: int | None = None,
         low_memory: bool = False,
@@ -534,6 +549,7 @@ class LazyFrame:
         row_index_name: str | None = None,
         row_index_offset: int = 0,
         ignore_errors: bool = False,
+        include_file_paths: str | None = None,
     ) -> Self:
         """
         Lazily read from a newline delimited JSON file.
@@ -557,12 +573,14 @@ class LazyFrame:
             sources,
             infer_schema_length,
             schema,
+            schema_overrides,
             batch_size,
             n_rows,
             low_memory,
             rechunk,
             _prepare_row_index_args(row_index_name, row_index_offset),
             ignore_errors,
+            include_file_paths,
         )
         return self
 
@@ -944,6 +962,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         comm_subplan_elim: bool = True,
         comm_subexpr_elim: bool = True,
         streaming: bool = False,
+        annotate: bool = False,
+        format: Literal["text", "json"] = "text",
     ) -> str:
         """
         Create a string representation of the query plan.
@@ -973,6 +993,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Common subexpressions will be cached and reused.
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        annotate
+            Annotate every node of the optimized plan with its estimated number of
+            rows, whether the streaming engine can execute it and, for joins, the
+            algorithm and the side the hash table is built on. Row counts that are
+            estimated are prefixed with `~`.
+        format : {'text', 'json'}
+            The output format of the annotated plan. Only applies if `annotate` is set.
 
         Examples
         --------
@@ -987,6 +1014,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ...     "a"
         ... ).explain()  # doctest: +SKIP
         """
+        if annotate and not optimized:
+            raise ValueError("`annotate` requires `optimized=True`")
         if optimized:
             ldf = self._ldf.optimization_toggle(
                 type_coercion,
@@ -999,6 +1028,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 streaming,
                 _eager=False,
             )
+            if annotate:
+                return ldf.describe_annotated_plan(format)
             return ldf.describe_optimized_plan()
         return self._ldf.describe_plan()
 
@@ -1050,6 +1081,23 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Common subexpressions will be cached and reused.
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        scan_statistics
+            Also return a DataFrame with statistics of every parquet or IPC scan that
+            was executed, to verify that predicates are pushed down effectively:
+
+            * `files_listed`: the number of files the scan was given.
+            * `files_pruned`: the files that hive partition filters skipped.
+            * `row_groups_read`: the parquet row groups that were decoded.
+            * `row_groups_pruned_by_statistics`: the parquet row groups that were
+              skipped because of their min/max statistics.
+            * `row_groups_pruned_by_bloom_filter`: the parquet row groups that a bloom
+              filter ruled out after the statistics couldn't.
+            * `bytes_downloaded`: the bytes of column data downloaded from cloud
+              storage.
+            * `decode_time`: the time spent decoding, in microseconds.
+
+            The `node` column matches the name of the scan in the timings. Scans that
+            run in the streaming engine are not reported.
 
         Examples
         --------
@@ -1416,6 +1464,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             self._ldf.bottom_k(k, by, descending, nulls_last, maintain_order)
         )
 
+    @overload
     def profile(
         self,
         *,
@@ -1431,7 +1480,47 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         truncate_nodes: int = 0,
         figsize: tuple[int, int] = (18, 8),
         streaming: bool = False,
+        scan_statistics: Literal[False] = False,
     ) -> tuple[DataFrame, DataFrame]:
+        ...
+
+    @overload
+    def profile(
+        self,
+        *,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        no_optimization: bool = False,
+        slice_pushdown: bool = True,
+        comm_subplan_elim: bool = True,
+        comm_subexpr_elim: bool = True,
+        show_plot: bool = False,
+        truncate_nodes: int = 0,
+        figsize: tuple[int, int] = (18, 8),
+        streaming: bool = False,
+        scan_statistics: Literal[True],
+    ) -> tuple[DataFrame, DataFrame, DataFrame]:
+        ...
+
+    def profile(
+        self,
+        *,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        no_optimization: bool = False,
+        slice_pushdown: bool = True,
+        comm_subplan_elim: bool = True,
+        comm_subexpr_elim: bool = True,
+        show_plot: bool = False,
+        truncate_nodes: int = 0,
+        figsize: tuple[int, int] = (18, 8),
+        streaming: bool = False,
+        scan_statistics: bool = False,
+    ) -> tuple[DataFrame, DataFrame] | tuple[DataFrame, DataFrame, DataFrame]:
         """
         Profile a LazyFrame.
 
@@ -1519,7 +1608,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming,
             _eager=False,
         )
-        df, timings = ldf.profile()
+        scan_stats = None
+        if scan_statistics:
+            df, timings, scan_stats = ldf.profile_with_scan_statistics()
+        else:
+            df, timings = ldf.profile()
         (df, timings) = wrap_df(df), wrap_df(timings)
 
         if show_plot:
@@ -1562,6 +1655,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 msg = "matplotlib should be installed to show profiling plot"
                 raise ModuleNotFoundError(msg) from None
 
+        if scan_stats is not None:
+            return df, timings, wrap_df(scan_stats)
         return df, timings
 
     @overload
@@ -1894,7 +1989,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression_level: int | None = None,
         statistics: bool = False,
         row_group_size: int | None = None,
+        row_group_size_bytes: int | None = None,
         data_pagesize_limit: int | None = None,
+        column_options: dict[str, dict[str, Any]] | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
@@ -1931,9 +2028,17 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             If None (default), the chunks of the `DataFrame` are
             used. Writing in smaller chunks may reduce memory pressure and improve
             writing speeds.
+        row_group_size_bytes
+            Maximum size of the row groups in bytes, estimated from the in-memory size
+            of the data. Larger row groups are split.
         data_pagesize_limit
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        column_options
+            Override the options of some columns, given as a dictionary that maps a
+            column name to a dictionary with any of the keys "compression",
+            "compression_level", "encoding" and "statistics". See
+            :meth:`DataFrame.write_parquet` for the supported values.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
@@ -1974,7 +2079,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             compression_level=compression_level,
             statistics=statistics,
             row_group_size=row_group_size,
+            row_group_size_bytes=row_group_size_bytes,
             data_pagesize_limit=data_pagesize_limit,
+            column_options=list(column_options.items()) if column_options else None,
             maintain_order=maintain_order,
         )
 
@@ -2239,6 +2346,81 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return lf.sink_json(path=path, maintain_order=maintain_order)
 
+    def sink_partitioned(
+        self,
+        path: str | Path,
+        by: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        *more_by: ColumnNameOrSelector,
+        file_format: Literal["parquet", "ipc", "csv"] = "parquet",
+        maintain_order: bool = True,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+    ) -> DataFrame:
+        """
+        Evaluate the query in streaming mode and write a file per group to a directory.
+
+        The rows of every group of the `by` columns are written to
+        `{path}/{key}={value}/data-0000.{ext}`, the Hive partitioned layout of
+        :meth:`DataFrame.write_parquet_partitioned`. The groups are written while
+        the query runs, so the result doesn't have to fit into RAM.
+
+        Parameters
+        ----------
+        path
+            Directory to which the files should be written.
+        by
+            Column name(s) or selector(s) to partition by.
+        *more_by
+            Additional names of columns to partition by, specified as positional
+            arguments.
+        file_format : {'parquet', 'ipc', 'csv'}
+            The format of the files, written with the default options of the format.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_partitioned("out", "year")  # doctest: +SKIP
+        """
+        by_parsed = _expand_selectors(self, by, *more_by)
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+
+        return lf.sink_partitioned(
+            path=normalize_filepath(path),
+            by=by_parsed,
+            file_format=file_format,
+            maintain_order=maintain_order,
+        )
+
     def _set_sink_optimizations(
         self,
         *,
@@ -3469,6 +3651,87 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
+    def upsample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        by: str | Sequence[str] | None = None,
+        fill_strategy: FillNullStrategy | None = None,
+        limit: int | None = None,
+    ) -> Self:
+        """
+        Upsample a LazyFrame at a regular frequency.
+
+        A row is inserted for every time step of `every` that is missing between the
+        first and the last time of a group. The rows are inserted without joining the
+        LazyFrame to a date range, so this is cheaper than
+        :meth:`DataFrame.upsample`.
+
+        Parameters
+        ----------
+        time_column
+            Time column of type `Date` or `Datetime` to upsample.
+        every
+            Interval between two time steps, in the string language of
+            :meth:`DataFrame.upsample`.
+        by
+            First group by these columns and then upsample for every group.
+        fill_strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+            Fill the null values of the other columns within every group with this
+            strategy, like :meth:`fill_null`. Without it, the other columns of the
+            inserted rows are null.
+        limit
+            Number of consecutive null values to fill when using the 'forward' or
+            'backward' strategy.
+
+        Returns
+        -------
+        LazyFrame
+            Result will be sorted by `by` and then by `time_column`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 2, 1),
+        ...             datetime(2021, 4, 1),
+        ...             datetime(2021, 5, 1),
+        ...             datetime(2021, 6, 1),
+        ...         ],
+        ...         "groups": ["A", "B", "A", "B"],
+        ...         "values": [0, 1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.upsample(
+        ...     "time", every="1mo", by="groups", fill_strategy="forward"
+        ... ).collect()
+        shape: (7, 3)
+        ┌─────────────────────┬────────┬────────┐
+        │ time                ┆ groups ┆ values │
+        │ ---                 ┆ ---    ┆ ---    │
+        │ datetime[μs]        ┆ str    ┆ i64    │
+        ╞═════════════════════╪════════╪════════╡
+        │ 2021-02-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-03-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-04-01 00:00:00 ┆ A      ┆ 0      │
+        │ 2021-05-01 00:00:00 ┆ A      ┆ 2      │
+        │ 2021-04-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-05-01 00:00:00 ┆ B      ┆ 1      │
+        │ 2021-06-01 00:00:00 ┆ B      ┆ 3      │
+        └─────────────────────┴────────┴────────┘
+        """
+        if by is None:
+            by = []
+        elif isinstance(by, str):
+            by = [by]
+        every = _timedelta_to_pl_duration(every)
+        return self._from_pyldf(
+            self._ldf.upsample(time_column, every, by, fill_strategy, limit)
+        )
+
     def join_asof(
         self,
         other: LazyFrame,
@@ -5161,6 +5424,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         columns: str | Expr | Sequence[str | Expr],
         *more_columns: str | Expr,
+        mismatch: ExplodeMismatch = "error",
     ) -> Self:
         """
         Explode the DataFrame to long format by exploding the given columns.
@@ -5172,6 +5436,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             columns being exploded must be of List or String datatype.
         *more_columns
             Additional names of columns to explode, specified as positional arguments.
+        mismatch : {'error', 'null_pad', 'cartesian'}
+            Multiple columns are exploded in lockstep: the elements at the same
+            position in the lists of a row end up in the same output row. This
+            determines what happens if those lists have different lengths.
+
+            * 'error': Raise an error.
+            * 'null_pad': Pad the shorter lists with nulls.
+            * 'cartesian': Explode the row to every combination of the elements of
+              its lists.
 
         Examples
         --------
@@ -5201,7 +5474,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         columns = parse_as_list_of_expressions(
             *_expand_selectors(self, columns, *more_columns)
         )
-        return self._from_pyldf(self._ldf.explode(columns))
+        return self._from_pyldf(self._ldf.explode(columns, mismatch))
 
     def unique(
         self,
@@ -5289,7 +5562,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
     def drop_nulls(
         self,
-        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        subset: IntoExpr | Iterable[IntoExpr] | None = None,
     ) -> Self:
         """
         Drop all rows that contain null values.
@@ -5299,7 +5572,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         Parameters
         ----------
         subset
-            Column name(s) for which null values are considered.
+            Column name(s), selector(s) or expression(s) for which null values are
+            considered. A row is dropped if any of these evaluate to null.
             If set to `None` (default), use all columns.
 
         Examples
@@ -5341,6 +5615,20 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 3   ┆ 8   ┆ null │
         └─────┴─────┴──────┘
 
+        Expressions are accepted as well, in which case a row is dropped if any of the
+        expressions evaluate to null for that row:
+
+        >>> lf.drop_nulls(subset=pl.col("bar") * 2).collect()
+        shape: (2, 3)
+        ┌─────┬─────┬──────┐
+        │ foo ┆ bar ┆ ham  │
+        │ --- ┆ --- ┆ ---  │
+        │ i64 ┆ i64 ┆ str  │
+        ╞═════╪═════╪══════╡
+        │ 1   ┆ 6   ┆ a    │
+        │ 3   ┆ 8   ┆ null │
+        └─────┴─────┴──────┘
+
         This method drops a row if any single value of the row is null.
 
         Below are some example snippets that show how you could drop null
@@ -5381,9 +5669,209 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         └──────┴─────┴──────┘
         """
         if subset is not None:
-            subset = _expand_selectors(self, subset)
+            subset = parse_as_list_of_expressions(subset)
         return self._from_pyldf(self._ldf.drop_nulls(subset))
 
+    @overload
+    def partition_by(
+        self,
+        by: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        *more_by: ColumnNameOrSelector,
+        as_dict: Literal[False] = ...,
+    ) -> list[Self]:
+        ...
+
+    @overload
+    def partition_by(
+        self,
+        by: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        *more_by: ColumnNameOrSelector,
+        as_dict: Literal[True],
+    ) -> dict[tuple[Any, ...], Self]:
+        ...
+
+    def partition_by(
+        self,
+        by: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        *more_by: ColumnNameOrSelector,
+        as_dict: bool = False,
+    ) -> list[Self] | dict[tuple[Any, ...], Self]:
+        """
+        Split the LazyFrame into a LazyFrame per group of the given columns.
+
+        Only the columns used to partition are collected to find the groups. Every
+        partition filters this LazyFrame on the values of its group and is only
+        computed when it is collected itself.
+
+        Parameters
+        ----------
+        by
+            Column name(s) or selector(s) to group by.
+        *more_by
+            Additional names of columns to group by, specified as positional arguments.
+        as_dict
+            Return a dictionary instead of a list. The dictionary keys are tuples of
+            the distinct group values that identify each group.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": ["a", "b", "a", "b", "c"],
+        ...         "b": [1, 2, 1, 3, 3],
+        ...     }
+        ... )
+        >>> partitions = lf.partition_by("a", as_dict=True)
+        >>> list(partitions)
+        [('a',), ('b',), ('c',)]
+        >>> partitions[("b",)].collect()
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ str ┆ i64 │
+        ╞═════╪═════╡
+        │ b   ┆ 2   │
+        │ b   ┆ 3   │
+        └─────┴─────┘
+        """
+        by_parsed = _expand_selectors(self, by, *more_by)
+        partitions = [
+            (key, self._from_pyldf(ldf))
+            for key, ldf in self._ldf.partition_by(by_parsed)
+        ]
+        if as_dict:
+            return dict(partitions)
+        return [lf for _, lf in partitions]
+
+    def pivot(
+        self,
+        values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        columns: str,
+        column_values: Series | Sequence[Any],
+        aggregate_function: PivotAgg | Expr | None = None,
+        *,
+        sort_columns: bool = False,
+        separator: str = "_",
+        struct_output: bool = False,
+    ) -> Self:
+        """
+        Create a spreadsheet-style pivot table, given the values of the pivoted column.
+
+        As the values of the `columns` column, and therefore the output columns, are
+        known upfront, the pivot is planned as a group by on the `index` columns, which
+        is optimized like any other query. Rows with other values of the `columns`
+        column are ignored.
+
+        Parameters
+        ----------
+        values
+            Column values to aggregate.
+        index
+            One or multiple keys to group by.
+        columns
+            Name of the column whose values will be used as the header of the output.
+        column_values
+            The values of the `columns` column to pivot into output columns.
+        aggregate_function
+            Choose from:
+
+            - None: take the first value of every group.
+            - A predefined aggregate function string, one of
+              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
+            - An expression to do the aggregation.
+        sort_columns
+            Sort the transposed columns by name. Default is the order of
+            `column_values`.
+        separator
+            Used as separator/delimiter in generated column names.
+        struct_output
+            If there are multiple `values` columns, output a struct column per
+            pivoted value with a field per `values` column, instead of a column
+            per combination of a `values` column and a pivoted value.
+
+        See Also
+        --------
+        DataFrame.pivot
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "foo": ["one", "one", "two", "two", "one", "two"],
+        ...         "bar": ["y", "y", "y", "x", "x", "x"],
+        ...         "baz": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> lf.pivot(
+        ...     values="baz", index="foo", columns="bar", column_values=["x", "y"]
+        ... ).collect()
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ foo ┆ x   ┆ y   │
+        │ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ one ┆ 5   ┆ 1   │
+        │ two ┆ 4   ┆ 3   │
+        └─────┴─────┴─────┘
+
+        Aggregate with an expression.
+
+        >>> lf.pivot(
+        ...     values="baz",
+        ...     index="foo",
+        ...     columns="bar",
+        ...     column_values=["x", "y"],
+        ...     aggregate_function=pl.element().sum() * 10,
+        ... ).collect()
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ foo ┆ x   ┆ y   │
+        │ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ one ┆ 50  ┆ 30  │
+        │ two ┆ 100 ┆ 30  │
+        └─────┴─────┴─────┘
+        """
+        values = _expand_selectors(self, values)
+        index = _expand_selectors(self, index)
+        if not isinstance(column_values, pl.Series):
+            column_values = pl.Series(column_values)
+
+        if aggregate_function is None or isinstance(aggregate_function, pl.Expr):
+            aggregate_expr = aggregate_function
+        elif aggregate_function == "len":
+            aggregate_expr = F.len()
+        elif aggregate_function in (
+            "first",
+            "last",
+            "sum",
+            "max",
+            "min",
+            "mean",
+            "median",
+        ):
+            aggregate_expr = getattr(F.element(), aggregate_function)()
+        else:
+            msg = f"invalid input for `aggregate_function` argument: {aggregate_function!r}"
+            raise ValueError(msg)
+
+        return self._from_pyldf(
+            self._ldf.pivot(
+                values,
+                index,
+                columns,
+                column_values._s,
+                aggregate_expr._pyexpr if aggregate_expr is not None else None,
+                sort_columns,
+                separator,
+                struct_output,
+            )
+        )
+
     def melt(
         self,
         id_vars: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
@@ -5406,10 +5894,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         Parameters
         ----------
         id_vars
-            Column(s) or selector(s) to use as identifier variables.
+            Column(s) or selector(s) to use as identifier variables. Column names
+            starting with `^` and ending with `$` are treated as regular expressions.
         value_vars
             Column(s) or selector(s) to use as values variables; if `value_vars`
-            is empty all columns that are not in `id_vars` will be used.
+            is empty all columns that are not in `id_vars` will be used. Column
+            names starting with `^` and ending with `$` are treated as regular
+            expressions.
         variable_name
             Name to give to the `variable` column. Defaults to "variable"
         value_name
@@ -145,6 +145,46 @@ class LazyGroupBy:
         pyexprs = parse_as_list_of_expressions(*aggs, **named_aggs)
         return wrap_ldf(self.lgb.agg(pyexprs))
 
+    def having(self, *predicates: IntoExpr | Iterable[IntoExpr]) -> LazyGroupBy:
+        """
+        Only keep the groups for which all predicates are true.
+
+        Like a SQL `HAVING` clause, the predicates are aggregations. They are evaluated
+        before the aggregations passed to :func:`agg`, which are then only computed
+        for the groups that are kept.
+
+        Parameters
+        ----------
+        *predicates
+            Aggregations that evaluate to a single boolean per group.
+            Accepts expression input. Strings are parsed as column names.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": ["x", "y", "x", "z", "x", "y"],
+        ...         "b": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> lf.group_by("a").having(pl.len() > 1).agg(pl.col("b").sum()).sort(
+        ...     "a"
+        ... ).collect()
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ str ┆ i64 │
+        ╞═════╪═════╡
+        │ x   ┆ 9   │
+        │ y   ┆ 8   │
+        └─────┴─────┘
+        """
+        lgb = self.lgb
+        for predicate in parse_as_list_of_expressions(*predicates):
+            lgb = lgb.having(predicate)
+        return LazyGroupBy(lgb)
+
     def map_groups(
         self,
         function: Callable[[DataFrame], DataFrame],
@@ -75,6 +75,22 @@ class ArrayNameSpace:
         └─────┘
         """
 
+    def mean(self) -> Series:
+        """
+        Compute the mean of the values of the sub-arrays.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [4, 3]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.mean()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            1.5
+            3.5
+        ]
+        """
+
     def unique(self, *, maintain_order: bool = False) -> Series:
         """
         Get the unique/distinct values in the array.
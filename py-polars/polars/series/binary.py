@@ -7,7 +7,12 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from polars import Series
     from polars.polars import PySeries
-    from polars.type_aliases import IntoExpr, TransferEncoding
+    from polars.type_aliases import (
+        BinaryCompression,
+        IntoExpr,
+        SizeUnit,
+        TransferEncoding,
+    )
 
 
 @expr_dispatch
@@ -54,6 +59,34 @@ class BinaryNameSpace:
             Prefix substring.
         """
 
+    def size(self, unit: SizeUnit = "b") -> Series:
+        r"""
+        Get the size of the binary values in a Series in the given unit.
+
+        Parameters
+        ----------
+        unit : {'b', 'kb', 'mb', 'gb', 'tb'}
+            Scale the returned size to the given unit.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` for the unit `'b'`, and
+            :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x00\x01", b"", None])
+        >>> s.bin.size()
+        shape: (3,)
+        Series: '' [u32]
+        [
+            2
+            0
+            null
+        ]
+        """
+
     def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Series:
         """
         Decode a value using the provided encoding.
@@ -81,3 +114,54 @@ class BinaryNameSpace:
         Series
             Series of data type :class:`Boolean`.
         """
+
+    def compress(
+        self, compression: BinaryCompression, *, level: int | None = None
+    ) -> Series:
+        """
+        Compress the values with the provided compression.
+
+        Parameters
+        ----------
+        compression : {'gzip', 'zstd'}
+            The compression to use.
+        level
+            The compression level; between 0 and 9 for gzip and at most 22 for zstd,
+            where higher levels compress better but slower. If not set, the default
+            level of the compression is used.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary` with the compressed values.
+        """
+
+    def decompress(
+        self, compression: BinaryCompression, *, strict: bool = True
+    ) -> Series:
+        """
+        Decompress the values that were compressed with the provided compression.
+
+        Parameters
+        ----------
+        compression : {'gzip', 'zstd'}
+            The compression the values were compressed with.
+        strict
+            Raise an error if the underlying value cannot be decompressed,
+            otherwise mask out with a null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary` with the decompressed values.
+        """
+
+    def format_uuid(self) -> Series:
+        """
+        Format the 16 bytes of UUIDs in the lowercase hyphenated form.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+        """
@@ -113,7 +113,7 @@ class DateTimeNameSpace:
 
     def to_string(self, format: str) -> Series:
         """
-        Convert a Date/Time/Datetime column into a String column with the given format.
+        Convert a Date/Time/Datetime/Duration column into a String column.
 
         Similar to `cast(pl.String)`, but this method allows you to customize the
         formatting of the resulting string.
@@ -125,6 +125,10 @@ class DateTimeNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: `"%y-%m-%d"`.
 
+            Duration columns take either `"polars"`, the format used to display a
+            duration (e.g. `"1d 2h 3m"`), or `"iso"` for ISO 8601 (e.g.
+            `"P1DT2H3M"`). These can be parsed back with `str.to_duration`.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -1075,6 +1079,7 @@ class DateTimeNameSpace:
             - `'raise'` (default): raise
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
+            - `'null'`: set to null
 
         Examples
         --------
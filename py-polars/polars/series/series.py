@@ -128,6 +128,7 @@ if TYPE_CHECKING:
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        NonNestedLiteral,
         NullBehavior,
         NumericLiteral,
         OneOrMoreDataTypes,
@@ -958,6 +959,9 @@ class Series:
         if isinstance(other, pl.Expr):
             # expand pl.lit, pl.datetime, pl.duration Exprs to compatible Series
             other = self.to_frame().select_seq(other).to_series()
+        elif isinstance(other, (int, float)) and self.dtype == Array:
+            # the scalar is applied on every element of the arrays
+            other = Series([other])
         if isinstance(other, Series):
             return self._from_pyseries(getattr(self._s, op_s)(other._s))
         if _check_for_numpy(other) and isinstance(other, np.ndarray):
@@ -973,9 +977,8 @@ class Series:
                 return self._from_pyseries(getattr(self._s, op_s)(_s))
         if isinstance(other, (PyDecimal, int)) and self.dtype.is_decimal():
             # Infer the number's scale.  Then use the max of the inferred scale and the
-            # Series' scale.  At present, this will cause arithmetic to fail with a
-            # PyDecimal that has a scale greater than the Series' scale, but will ensure
-            # that scale is not lost.
+            # Series' scale, so that no scale is lost.  The result scale of the
+            # operation itself is determined by the decimal arithmetic rules.
             _s = sequence_to_pyseries(self.name, [other], dtype=Decimal)
             _s = _s.cast(
                 Decimal(
@@ -1050,6 +1053,9 @@ class Series:
             msg = "first cast to integer before dividing datelike dtypes"
             raise TypeError(msg)
 
+        if self.dtype == Array or (isinstance(other, Series) and other.dtype == Array):
+            return self.to_frame().select(F.col(self.name) / other).to_series()
+
         # this branch is exactly the floordiv function without rounding the floats
         if self.dtype.is_float() or self.dtype == Decimal:
             return self._arithmetic(other, "div", "div_<>")
@@ -2615,7 +2621,7 @@ class Series:
         ┌─────────┬────────┐
         │ lengths ┆ values │
         │ ---     ┆ ---    │
-        │ i32     ┆ i64    │
+        │ u32     ┆ i64    │
         ╞═════════╪════════╡
         │ 2       ┆ 1      │
         │ 1       ┆ 2      │
@@ -2852,7 +2858,9 @@ class Series:
         breaking change.
 
         This can be really slow as it can have `O(n^2)` complexity. Don't use this
-        for operations that visit all elements.
+        for operations that visit all elements. The exceptions are the `sum`, `mean`,
+        `min`, `max` and `rank().last()` of :func:`element` on numeric data, which are
+        computed incrementally.
 
         Examples
         --------
@@ -3555,21 +3563,31 @@ class Series:
         return self._s.arg_max()
 
     @overload
-    def search_sorted(self, element: int | float, side: SearchSortedSide = ...) -> int:
+    def search_sorted(
+        self,
+        element: NonNestedLiteral,
+        side: SearchSortedSide = ...,
+        *,
+        descending: bool = ...,
+    ) -> int:
         ...
 
     @overload
     def search_sorted(
         self,
-        element: Series | np.ndarray[Any, Any] | list[int] | list[float],
+        element: Series | np.ndarray[Any, Any] | list[Any],
         side: SearchSortedSide = ...,
+        *,
+        descending: bool = ...,
     ) -> Series:
         ...
 
     def search_sorted(
         self,
-        element: int | float | Series | np.ndarray[Any, Any] | list[int] | list[float],
+        element: NonNestedLiteral | Series | np.ndarray[Any, Any] | list[Any],
         side: SearchSortedSide = "any",
+        *,
+        descending: bool = False,
     ) -> int | Series:
         """
         Find indices where elements should be inserted to maintain order.
@@ -3584,11 +3602,39 @@ class Series:
             If 'any', the index of the first suitable location found is given.
             If 'left', the index of the leftmost suitable location found is given.
             If 'right', return the rightmost suitable location found is given.
+        descending
+            Whether the Series is sorted in descending order.
         """
-        if isinstance(element, (int, float)):
-            return F.select(F.lit(self).search_sorted(element, side)).item()
+        expr = F.lit(self)
+        if isinstance(
+            element, (int, float, PyDecimal, str, bytes, date, time, timedelta)
+        ):
+            expr = expr.search_sorted(F.lit(element), side, descending=descending)
+            return F.select(expr).item()
         element = Series(element)
-        return F.select(F.lit(self).search_sorted(element, side)).to_series()
+        expr = expr.search_sorted(element, side, descending=descending)
+        return F.select(expr).to_series()
+
+    def index_of(self, element: NonNestedLiteral) -> int | None:
+        """
+        Get the index of the first occurrence of a value, or `None` if it's not found.
+
+        Parameters
+        ----------
+        element
+            Value to find. `None` finds the first null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, None, 17])
+        >>> s.index_of(17)
+        2
+        >>> s.index_of(None)  # search for a null
+        1
+        >>> s.index_of(55) is None
+        True
+        """
+        return F.select(F.lit(self).index_of(F.lit(element))).item()
 
     def unique(self, *, maintain_order: bool = False) -> Series:
         """
@@ -6703,6 +6749,20 @@ class Series:
         ]
         """
 
+    def uuid4(self, seed: int | None = None) -> Series:
+        """
+        Generate a random UUID of version 4 for every value of this Series.
+
+        The UUIDs are in the lowercase hyphenated form; the values of this Series only
+        determine the length and the name of the result.
+
+        Parameters
+        ----------
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated each time the UUIDs are generated.
+        """
+
     @deprecate_nonkeyword_arguments(version="0.19.10")
     def ewm_mean(
         self,
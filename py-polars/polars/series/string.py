@@ -13,6 +13,7 @@ if TYPE_CHECKING:
     from polars.polars import PySeries
     from polars.type_aliases import (
         Ambiguous,
+        DurationFormat,
         IntoExpr,
         IntoExprColumn,
         PolarsDataType,
@@ -187,6 +188,48 @@ class StringNameSpace:
         ]
         """
 
+    def to_duration(
+        self,
+        format: DurationFormat = "polars",
+        *,
+        time_unit: TimeUnit = "us",
+        strict: bool = True,
+    ) -> Series:
+        """
+        Convert a String column into a Duration column.
+
+        Parameters
+        ----------
+        format : {'polars', 'iso'}
+            Format of the durations.
+
+            - `'polars'`: the format used to display a duration, e.g. `"1d 2h 3m"`.
+              The units `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns` are supported.
+            - `'iso'`: an ISO 8601 duration, e.g. `"P1DT2H3M"`. Years and months
+              are not supported as their length isn't fixed.
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration.
+        strict
+            Raise an error if any conversion fails. If set to False, values that
+            cannot be parsed or don't fit in the time unit are set to null.
+
+        See Also
+        --------
+        Series.dt.to_string : Convert a Duration back into a String.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1d 2h 3m", "-5s", None])
+        >>> s.str.to_duration()
+        shape: (3,)
+        Series: '' [duration[μs]]
+        [
+                1d 2h 3m
+                -5s
+                null
+        ]
+        """
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -626,6 +669,80 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein(self, other: str | Expr) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the number of single character insertions, deletions and
+        substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", None])
+        >>> s.str.levenshtein("sitting")
+        shape: (3,)
+        Series: '' [u32]
+        [
+            3
+            0
+            null
+        ]
+        """
+
+    def hamming(self, other: str | Expr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of different lengths result in a null.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        Examples
+        --------
+        >>> s = pl.Series(["karolin", "kart"])
+        >>> s.str.hamming("kathrin")
+        shape: (2,)
+        Series: '' [u32]
+        [
+            3
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: str | Expr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0.0 for completely different strings and 1.0 for
+        equal strings. Strings sharing a common prefix get a higher similarity.
+
+        Parameters
+        ----------
+        other
+            String or expression that evaluates to strings to compare with.
+
+        Examples
+        --------
+        >>> s = pl.Series(["MARTHA", "MARTHA", "xyz"])
+        >>> s.str.jaro_winkler("MARHTA")
+        shape: (3,)
+        Series: '' [f64]
+        [
+            0.961111
+            0.961111
+            0.0
+        ]
+        """
+
     def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Series:
         """
         Decode a value using the provided encoding.
@@ -666,6 +783,50 @@ class StringNameSpace:
         ]
         """
 
+    def is_uuid(self) -> Series:
+        """
+        Check if the string values are valid UUIDs.
+
+        Both the hyphenated and the simple form are accepted, optionally wrapped in
+        braces or prefixed with `urn:uuid:`. The hexadecimal digits are case
+        insensitive.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["67e55044-10b1-426f-9247-bb680e5fe0c8", "67e55044", None])
+        >>> s.str.is_uuid()
+        shape: (3,)
+        Series: '' [bool]
+        [
+            true
+            false
+            null
+        ]
+        """
+
+    def to_uuid(self, *, strict: bool = True) -> Series:
+        """
+        Parse the string values as UUIDs into their 16 bytes.
+
+        The accepted forms are the same as for :meth:`is_uuid`.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid UUID, otherwise mask out with a
+            null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+        """
+
     def json_decode(
         self, dtype: PolarsDataType | None = None, infer_schema_length: int | None = 100
     ) -> Series:
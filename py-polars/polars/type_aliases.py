@@ -64,10 +64,9 @@ SchemaDict: TypeAlias = Mapping[str, PolarsDataType]
 
 NumericLiteral: TypeAlias = Union[int, float, Decimal]
 TemporalLiteral: TypeAlias = Union[date, time, datetime, timedelta]
+NonNestedLiteral: TypeAlias = Union[NumericLiteral, TemporalLiteral, str, bool, bytes]
 # Python literal types (can convert into a `lit` expression)
-PythonLiteral: TypeAlias = Union[
-    NumericLiteral, TemporalLiteral, str, bool, bytes, List[Any]
-]
+PythonLiteral: TypeAlias = Union[NonNestedLiteral, List[Any]]
 # Inputs that can convert into a `col` expression
 IntoExprColumn: TypeAlias = Union["Expr", "Series", str]
 # Inputs that can convert into an expression
@@ -85,10 +84,12 @@ AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+ExplodeMismatch: TypeAlias = Literal["error", "null_pad", "cartesian"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
+IndexKind: TypeAlias = Literal["sorted", "hash"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
@@ -96,6 +97,7 @@ Label: TypeAlias = Literal["left", "right", "datapoint"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 NullStrategy: TypeAlias = Literal["ignore", "propagate"]
 ParallelStrategy: TypeAlias = Literal["auto", "columns", "row_groups", "none"]
+MissingColumns: TypeAlias = Literal["raise", "insert_null"]
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
@@ -146,7 +148,7 @@ ToStructStrategy: TypeAlias = Literal[
 ]  # ListToStructWidthStrategy
 
 # The following have no equivalent on the Rust side
-Ambiguous: TypeAlias = Literal["earliest", "latest", "raise"]
+Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 ConcatMethod = Literal[
     "vertical",
     "vertical_relaxed",
@@ -155,10 +157,12 @@ ConcatMethod = Literal[
     "horizontal",
     "align",
 ]
+DurationFormat: TypeAlias = Literal["polars", "iso"]
 EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
+BinaryCompression: TypeAlias = Literal["gzip", "zstd"]
 CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
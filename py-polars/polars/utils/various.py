@@ -8,7 +8,16 @@ import warnings
 from collections.abc import MappingView, Sized
 from enum import Enum
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, Generator, Iterable, Literal, Sequence, TypeVar
+from typing import (
+    TYPE_CHECKING,
+    Any,
+    Generator,
+    Iterable,
+    Literal,
+    Mapping,
+    Sequence,
+    TypeVar,
+)
 
 import polars as pl
 from polars import functions as F
@@ -23,6 +32,7 @@ from polars.datatypes import (
     Int64,
     String,
     Time,
+    py_type_to_dtype,
 )
 from polars.dependencies import _check_for_numpy
 from polars.dependencies import numpy as np
@@ -48,14 +58,36 @@ if sys.version_info >= (3, 11):
 
 
 def _process_null_values(
-    null_values: None | str | Sequence[str] | dict[str, str] = None,
+    null_values: None | str | Sequence[str] | dict[str, str | Sequence[str]] = None,
 ) -> None | str | Sequence[str] | list[tuple[str, str]]:
     if isinstance(null_values, dict):
-        return list(null_values.items())
+        processed = []
+        for name, values in null_values.items():
+            if isinstance(values, str):
+                processed.append((name, values))
+            else:
+                processed.extend((name, value) for value in values)
+        return processed
     else:
         return null_values
 
 
+def _split_regex_dtypes(
+    dtypes: Mapping[str, PolarsDataType],
+) -> tuple[
+    list[tuple[str, PolarsDataType]] | None, list[tuple[str, PolarsDataType]] | None
+]:
+    """Split dtypes keyed by column name from dtypes keyed by a `^...$` regex."""
+    by_name = []
+    by_regex = []
+    for name, dtype in dtypes.items():
+        if name.startswith("^") and name.endswith("$"):
+            by_regex.append((name, py_type_to_dtype(dtype)))
+        else:
+            by_name.append((name, py_type_to_dtype(dtype)))
+    return by_name or None, by_regex or None
+
+
 def _is_generator(val: object) -> bool:
     return (
         (isinstance(val, (Generator, Iterable)) and not isinstance(val, Sized))
@@ -29,8 +29,8 @@ impl PyBatchedCsv {
         infer_schema_length, chunk_size, has_header, ignore_errors, n_rows, skip_rows,
         projection, separator, rechunk, columns, encoding, n_threads, path, overwrite_dtype,
         overwrite_dtype_slice, low_memory, comment_prefix, quote_char, null_values,
-        missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header, row_index,
-        sample_size, eol_char, raise_if_empty, truncate_ragged_lines)
+        missing_utf8_is_empty_string, try_parse_dates, decimal_comma, skip_rows_after_header,
+        row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines)
     )]
     fn new(
         infer_schema_length: Option<usize>,
@@ -54,6 +54,7 @@ impl PyBatchedCsv {
         null_values: Option<Wrap<NullValues>>,
         missing_utf8_is_empty_string: bool,
         try_parse_dates: bool,
+        decimal_comma: bool,
         skip_rows_after_header: usize,
         row_index: Option<(String, IdxSize)>,
         sample_size: usize,
@@ -112,6 +113,7 @@ impl PyBatchedCsv {
             .with_comment_prefix(comment_prefix)
             .with_null_values(null_values)
             .with_try_parse_dates(try_parse_dates)
+            .with_decimal_comma(decimal_comma)
             .with_quote_char(quote_char)
             .with_end_of_line_char(eol_char)
             .with_skip_rows_after_header(skip_rows_after_header)
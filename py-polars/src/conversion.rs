@@ -10,6 +10,8 @@ use polars::io::avro::AvroCompression;
 #[cfg(feature = "ipc")]
 use polars::io::ipc::IpcCompression;
 use polars::prelude::AnyValue;
+#[cfg(feature = "binary_compression")]
+use polars::prelude::BinaryCompression;
 use polars::series::ops::NullBehavior;
 use polars_core::frame::row::any_values_to_dtype;
 use polars_core::prelude::{IndexOrder, QuantileInterpolOptions};
@@ -399,7 +401,10 @@ impl ToPyObject for Wrap<DataType> {
                         let class = pl.getattr(intern!(py, "Enum")).unwrap();
                         let s = Series::from_arrow("category", categories.to_boxed()).unwrap();
                         let series = to_series(py, s.into());
-                        return class.call1((series,)).unwrap().into();
+                        return class
+                            .call1((series, Wrap(*ordering).to_object(py)))
+                            .unwrap()
+                            .into();
                     }
                 }
                 let class = pl.getattr(intern!(py, "Categorical")).unwrap();
@@ -512,7 +517,9 @@ impl FromPyObject<'_> for Wrap<DataType> {
                 let s = get_series(categories)?;
                 let ca = s.str().map_err(PyPolarsErr::from)?;
                 let arr = ca.downcast_iter().next().unwrap();
-                create_enum_data_type(arr.clone())
+                let ordering = ob.getattr(intern!(py, "ordering")).unwrap();
+                let ordering = ordering.extract::<Wrap<CategoricalOrdering>>()?.0;
+                create_enum_data_type(arr.clone(), ordering)
             },
             "Date" => DataType::Date,
             "Time" => DataType::Time,
@@ -1277,6 +1284,22 @@ impl FromPyObject<'_> for Wrap<StartBy> {
     }
 }
 
+#[cfg(feature = "binary_compression")]
+impl FromPyObject<'_> for Wrap<BinaryCompression> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "gzip" => BinaryCompression::Gzip,
+            "zstd" => BinaryCompression::Zstd,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`compression` must be one of {{'gzip', 'zstd'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<ClosedWindow> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match ob.extract::<&str>()? {
@@ -1427,6 +1450,69 @@ impl FromPyObject<'_> for Wrap<ParallelStrategy> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<MissingColumns> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "raise" => MissingColumns::Raise,
+            "insert_null" => MissingColumns::InsertNull,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`missing_columns` must be one of {{'raise', 'insert_null'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<ParquetEncoding> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "plain" => ParquetEncoding::Plain,
+            "rle_dictionary" => ParquetEncoding::RleDictionary,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "parquet `encoding` must be one of {{'plain', 'rle_dictionary', 'delta_binary_packed', 'delta_length_byte_array'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<ParquetColumnOptions> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+        let mut options = ParquetColumnOptions::default();
+        let mut compression = None;
+        let mut compression_level = None;
+        for (key, value) in dict {
+            match key.extract::<&str>()? {
+                "compression" => compression = value.extract::<Option<&str>>()?,
+                "compression_level" => compression_level = value.extract()?,
+                "encoding" => {
+                    options.encoding = value.extract::<Option<Wrap<ParquetEncoding>>>()?.map(|e| e.0)
+                },
+                "statistics" => options.statistics = value.extract()?,
+                k => {
+                    return Err(PyValueError::new_err(format!(
+                        "parquet column options must be one of {{'compression', 'compression_level', 'encoding', 'statistics'}}, got {k}",
+                    )))
+                },
+            }
+        }
+        options.compression = compression
+            .map(|c| parse_parquet_compression(c, compression_level))
+            .transpose()?;
+        Ok(Wrap(options))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<IndexOrder> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match ob.extract::<&str>()? {
@@ -1495,6 +1581,22 @@ impl FromPyObject<'_> for Wrap<TimeUnit> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<ExplodeMismatch> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "error" => ExplodeMismatch::Error,
+            "null_pad" => ExplodeMismatch::NullPad,
+            "cartesian" => ExplodeMismatch::Cartesian,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mismatch` must be one of {{'error', 'null_pad', 'cartesian'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<UniqueKeepStrategy> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match ob.extract::<&str>()? {
@@ -1578,6 +1680,36 @@ impl FromPyObject<'_> for Wrap<WindowMapping> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<ExplainFormat> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "text" => ExplainFormat::Text,
+            "json" => ExplainFormat::Json,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`format` must be one of {{'text', 'json'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl FromPyObject<'_> for Wrap<IndexKind> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "sorted" => IndexKind::Sorted,
+            "hash" => IndexKind::Hash,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`kind` must be one of {{'sorted', 'hash'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<JoinValidation> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match ob.extract::<&str>()? {
@@ -1,6 +1,7 @@
 use std::io::{BufWriter, Cursor};
 use std::num::NonZeroUsize;
 use std::ops::Deref;
+use std::path::PathBuf;
 
 use either::Either;
 use numpy::IntoPyArray;
@@ -175,9 +176,9 @@ impl PyDataFrame {
     #[pyo3(signature = (
         py_f, infer_schema_length, chunk_size, has_header, ignore_errors, n_rows,
         skip_rows, projection, separator, rechunk, columns, encoding, n_threads, path,
-        overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
-        null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-        row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, schema)
+        overwrite_dtype, overwrite_dtype_slice, overwrite_dtype_regex, low_memory, comment_prefix,
+        quote_char, null_values, missing_utf8_is_empty_string, try_parse_dates, decimal_comma,
+        skip_rows_after_header, row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, schema)
     )]
     pub fn read_csv(
         py_f: &PyAny,
@@ -196,12 +197,14 @@ impl PyDataFrame {
         path: Option<String>,
         overwrite_dtype: Option<Vec<(&str, Wrap<DataType>)>>,
         overwrite_dtype_slice: Option<Vec<Wrap<DataType>>>,
+        overwrite_dtype_regex: Option<Vec<(String, Wrap<DataType>)>>,
         low_memory: bool,
         comment_prefix: Option<&str>,
         quote_char: Option<&str>,
         null_values: Option<Wrap<NullValues>>,
         missing_utf8_is_empty_string: bool,
         try_parse_dates: bool,
+        decimal_comma: bool,
         skip_rows_after_header: usize,
         row_index: Option<(String, IdxSize)>,
         sample_size: usize,
@@ -232,6 +235,13 @@ impl PyDataFrame {
                 .collect::<Vec<_>>()
         });
 
+        let overwrite_dtype_regex = overwrite_dtype_regex.map(|overwrite_dtype| {
+            overwrite_dtype
+                .into_iter()
+                .map(|(pattern, dtype)| (pattern, dtype.0))
+                .collect::<Vec<_>>()
+        });
+
         let mmap_bytes_r = get_mmap_bytes_reader(py_f)?;
         let df = CsvReader::new(mmap_bytes_r)
             .infer_schema(infer_schema_length)
@@ -249,12 +259,14 @@ impl PyDataFrame {
             .with_path(path)
             .with_dtypes(overwrite_dtype.map(Arc::new))
             .with_dtypes_slice(overwrite_dtype_slice.as_deref())
+            .with_dtypes_regex(overwrite_dtype_regex)
             .with_schema(schema.map(|schema| Arc::new(schema.0)))
             .low_memory(low_memory)
             .with_null_values(null_values)
             .with_missing_is_null(!missing_utf8_is_empty_string)
             .with_comment_prefix(comment_prefix)
             .with_try_parse_dates(try_parse_dates)
+            .with_decimal_comma(decimal_comma)
             .with_quote_char(quote_char)
             .with_end_of_line_char(eol_char)
             .with_skip_rows_after_header(skip_rows_after_header)
@@ -790,7 +802,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, row_group_size_bytes, data_page_size, bloom_filter_columns, column_options, field_ids))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -799,9 +811,20 @@ impl PyDataFrame {
         compression_level: Option<i32>,
         statistics: bool,
         row_group_size: Option<usize>,
+        row_group_size_bytes: Option<usize>,
         data_page_size: Option<usize>,
+        bloom_filter_columns: Option<Vec<String>>,
+        column_options: Option<Vec<(String, Wrap<ParquetColumnOptions>)>>,
+        field_ids: Option<Vec<(String, i32)>>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let field_ids = field_ids.unwrap_or_default();
+        let bloom_filter_columns = bloom_filter_columns.unwrap_or_default();
+        let column_options = column_options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, options)| (name, options.0))
+            .collect();
 
         if let Ok(s) = py_f.extract::<&str>(py) {
             let f = std::fs::File::create(s)?;
@@ -810,7 +833,11 @@ impl PyDataFrame {
                     .with_compression(compression)
                     .with_statistics(statistics)
                     .with_row_group_size(row_group_size)
+                    .with_row_group_size_bytes(row_group_size_bytes)
                     .with_data_page_size(data_page_size)
+                    .with_bloom_filter_columns(bloom_filter_columns)
+                    .with_column_options(column_options)
+                    .with_field_ids(field_ids)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -820,7 +847,11 @@ impl PyDataFrame {
                 .with_compression(compression)
                 .with_statistics(statistics)
                 .with_row_group_size(row_group_size)
+                .with_row_group_size_bytes(row_group_size_bytes)
                 .with_data_page_size(data_page_size)
+                .with_bloom_filter_columns(bloom_filter_columns)
+                .with_column_options(column_options)
+                .with_field_ids(field_ids)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -828,6 +859,40 @@ impl PyDataFrame {
         Ok(())
     }
 
+    #[cfg(feature = "parquet")]
+    #[pyo3(signature = (path, partition_by, compression, compression_level, statistics, row_group_size, data_page_size, max_rows_per_file, max_bytes_per_file, filename_template))]
+    pub fn write_parquet_partitioned(
+        &self,
+        py: Python,
+        path: PathBuf,
+        partition_by: Vec<String>,
+        compression: &str,
+        compression_level: Option<i32>,
+        statistics: bool,
+        row_group_size: Option<usize>,
+        data_page_size: Option<usize>,
+        max_rows_per_file: Option<usize>,
+        max_bytes_per_file: Option<usize>,
+        filename_template: String,
+    ) -> PyResult<()> {
+        let compression = parse_parquet_compression(compression, compression_level)?;
+        let option = ParquetWriterOption::new()
+            .with_compression(compression)
+            .with_statistics(statistics)
+            .with_row_group_size(row_group_size)
+            .with_data_page_size(data_page_size);
+
+        py.allow_threads(|| {
+            PartitionedWriter::new(option, path, partition_by)
+                .with_max_rows_per_file(max_rows_per_file)
+                .with_max_bytes_per_file(max_bytes_per_file)
+                .with_filename_template(filename_template)
+                .finish(&self.df)
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
     pub fn to_arrow(&mut self) -> PyResult<Vec<PyObject>> {
         self.df.align_chunks();
         Python::with_gil(|py| {
@@ -1003,6 +1068,28 @@ impl PyDataFrame {
         self.df.n_chunks()
     }
 
+    pub fn chunk_lengths(&self) -> Vec<Vec<usize>> {
+        self.df.chunk_lengths()
+    }
+
+    pub fn align_chunks(&self) -> Self {
+        let mut df = self.df.clone();
+        df.align_chunks();
+        df.into()
+    }
+
+    pub fn rechunk_diagnostics(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        for diagnostic in self.df.rechunk_diagnostics() {
+            let columns = diagnostic.columns.iter().map(|name| name.as_str());
+            let columns = PyList::new(py, columns);
+            for operation in diagnostic.operations {
+                dict.set_item(*operation, columns)?;
+            }
+        }
+        Ok(dict.to_object(py))
+    }
+
     pub fn shape(&self) -> (usize, usize) {
         self.df.shape()
     }
@@ -1313,6 +1400,19 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
+    pub fn value_counts(
+        &self,
+        subset: Option<Vec<String>>,
+        sort: bool,
+        normalize: bool,
+    ) -> PyResult<Self> {
+        let df = self
+            .df
+            .value_counts(subset.as_deref(), sort, normalize)
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn null_count(&self) -> Self {
         let df = self.df.null_count();
         df.into()
@@ -1354,6 +1454,47 @@ impl PyDataFrame {
         self.df.shrink_to_fit();
     }
 
+    pub fn create_index(&mut self, columns: Vec<String>, kind: Wrap<IndexKind>) -> PyResult<()> {
+        self.df
+            .create_index(columns, kind.0)
+            .map_err(PyPolarsErr::from)?;
+        Ok(())
+    }
+
+    pub fn drop_indexes(&mut self) {
+        self.df.drop_indexes();
+    }
+
+    pub fn with_column_cow(&self, py: Python, name: &str, lambda: PyObject) -> PyResult<Self> {
+        let wrap_s = py_modules::POLARS.getattr(py, "wrap_s")?;
+        let df = self
+            .df
+            .with_column_cow(name, |chunk| {
+                let out = wrap_s
+                    .call1(py, (PySeries::new(chunk.clone()),))
+                    .and_then(|chunk| lambda.call1(py, (chunk,)))
+                    .map_err(|e| polars_err!(ComputeError: "{e}"))?;
+                if out.is_none(py) {
+                    return Ok(None);
+                }
+                let out = out
+                    .getattr(py, "_s")
+                    .and_then(|s| s.extract::<PySeries>(py))
+                    .map_err(|_| {
+                        polars_err!(
+                            ComputeError: "expected the function to return a Series or None"
+                        )
+                    })?;
+                Ok(Some(out.series))
+            })
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn shared_chunk_counts(&self, other: &PyDataFrame) -> Vec<usize> {
+        self.df.shared_chunk_counts(&other.df)
+    }
+
     pub fn hash_rows(&mut self, k0: u64, k1: u64, k2: u64, k3: u64) -> PyResult<PySeries> {
         let hb = ahash::RandomState::with_seeds(k0, k1, k2, k3);
         let hash = self.df.hash_rows(Some(hb)).map_err(PyPolarsErr::from)?;
@@ -17,6 +17,10 @@ impl PyExpr {
         self.inner.clone().arr().sum().into()
     }
 
+    fn arr_mean(&self) -> Self {
+        self.inner.clone().arr().mean().into()
+    }
+
     fn arr_unique(&self, maintain_order: bool) -> Self {
         if maintain_order {
             self.inner.clone().arr().unique_stable().into()
@@ -1,5 +1,9 @@
+#[cfg(feature = "binary_compression")]
+use polars::prelude::BinaryCompression;
 use pyo3::prelude::*;
 
+#[cfg(feature = "binary_compression")]
+use crate::conversion::Wrap;
 use crate::PyExpr;
 
 #[pymethods]
@@ -20,6 +24,10 @@ impl PyExpr {
         self.inner.clone().binary().starts_with(sub.inner).into()
     }
 
+    fn bin_size_bytes(&self) -> Self {
+        self.inner.clone().binary().size_bytes().into()
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn bin_hex_decode(&self, strict: bool) -> Self {
         self.inner.clone().binary().hex_decode(strict).into()
@@ -39,4 +47,27 @@ impl PyExpr {
     fn bin_base64_encode(&self) -> Self {
         self.inner.clone().binary().base64_encode().into()
     }
+
+    #[cfg(feature = "binary_compression")]
+    fn bin_compress(&self, compression: Wrap<BinaryCompression>, level: Option<i32>) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .compress(compression.0, level)
+            .into()
+    }
+
+    #[cfg(feature = "binary_compression")]
+    fn bin_decompress(&self, compression: Wrap<BinaryCompression>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .decompress(compression.0, strict)
+            .into()
+    }
+
+    #[cfg(feature = "uuid")]
+    fn bin_format_uuid(&self) -> Self {
+        self.inner.clone().binary().format_uuid().into()
+    }
 }
@@ -296,6 +296,21 @@ impl PyExpr {
         self.inner.clone().bottom_k(k.inner).into()
     }
 
+    #[cfg(feature = "top_k")]
+    fn top_k_by(&self, k: Self, by: Vec<Self>, descending: Vec<bool>) -> Self {
+        let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        self.inner.clone().top_k_by(k.inner, by, descending).into()
+    }
+
+    #[cfg(feature = "top_k")]
+    fn bottom_k_by(&self, k: Self, by: Vec<Self>, descending: Vec<bool>) -> Self {
+        let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        self.inner
+            .clone()
+            .bottom_k_by(k.inner, by, descending)
+            .into()
+    }
+
     #[cfg(feature = "peaks")]
     fn peak_min(&self) -> Self {
         self.inner.clone().peak_min().into()
@@ -315,12 +330,16 @@ impl PyExpr {
     }
 
     #[cfg(feature = "search_sorted")]
-    fn search_sorted(&self, element: Self, side: Wrap<SearchSortedSide>) -> Self {
+    fn search_sorted(&self, element: Self, side: Wrap<SearchSortedSide>, descending: bool) -> Self {
         self.inner
             .clone()
-            .search_sorted(element.inner, side.0)
+            .search_sorted(element.inner, side.0, descending)
             .into()
     }
+    #[cfg(feature = "index_of")]
+    fn index_of(&self, element: Self) -> Self {
+        self.inner.clone().index_of(element.inner).into()
+    }
     fn gather(&self, idx: Self) -> Self {
         self.inner.clone().gather(idx.inner).into()
     }
@@ -396,6 +415,24 @@ impl PyExpr {
         self.inner.clone().var(ddof).into()
     }
 
+    #[cfg(feature = "weighted_stats")]
+    fn wmean(&self, weights: Self) -> Self {
+        self.inner.clone().wmean(weights.inner).into()
+    }
+
+    #[cfg(feature = "weighted_stats")]
+    fn wvar(&self, weights: Self, ddof: u8) -> Self {
+        self.inner.clone().wvar(weights.inner, ddof).into()
+    }
+
+    #[cfg(feature = "weighted_stats")]
+    fn wquantile(&self, weights: Self, quantile: Self) -> Self {
+        self.inner
+            .clone()
+            .wquantile(weights.inner, quantile.inner)
+            .into()
+    }
+
     fn is_unique(&self) -> Self {
         self.inner.clone().is_unique().into()
     }
@@ -411,6 +448,10 @@ impl PyExpr {
         self.inner.clone().approx_n_unique().into()
     }
 
+    fn approx_quantile(&self, quantile: Self) -> Self {
+        self.inner.clone().approx_quantile(quantile.inner).into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -424,17 +465,7 @@ impl PyExpr {
     }
 
     fn gather_every(&self, n: usize, offset: usize) -> Self {
-        self.inner
-            .clone()
-            .map(
-                move |s: Series| {
-                    polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n can't be zero");
-                    Ok(Some(s.gather_every(n, offset)))
-                },
-                GetOutput::same_type(),
-            )
-            .with_fmt("gather_every")
-            .into()
+        self.inner.clone().gather_every(n, offset).into()
     }
     fn tail(&self, n: usize) -> Self {
         self.inner.clone().tail(Some(n)).into()
@@ -579,15 +610,37 @@ impl PyExpr {
         self.inner.clone().is_duplicated().into()
     }
 
-    fn over(&self, partition_by: Vec<Self>, mapping: Wrap<WindowMapping>) -> Self {
+    #[pyo3(signature = (partition_by, mapping, order_by, descending, nulls_last, frame))]
+    fn over(
+        &self,
+        partition_by: Vec<Self>,
+        mapping: Wrap<WindowMapping>,
+        order_by: Option<Self>,
+        descending: bool,
+        nulls_last: bool,
+        frame: Option<(Option<i64>, Option<i64>)>,
+    ) -> Self {
         let partition_by = partition_by
             .into_iter()
             .map(|e| e.inner)
             .collect::<Vec<Expr>>();
-        self.inner
-            .clone()
-            .over_with_options(partition_by, mapping.0)
-            .into()
+        let order_by = order_by.map(|e| {
+            let options = SortOptions {
+                descending,
+                nulls_last,
+                multithreaded: true,
+                maintain_order: true,
+            };
+            (e.inner, options)
+        });
+        let expr = self.inner.clone();
+        match frame {
+            Some((start, end)) => {
+                expr.over_with_order(partition_by, order_by, WindowFrame::new(start, end))
+            },
+            None => expr.over_with_order(partition_by, order_by, mapping.0),
+        }
+        .into()
     }
 
     fn rolling(
@@ -732,6 +785,22 @@ impl PyExpr {
         self.inner.clone().rank(options, seed).into()
     }
 
+    fn dense_rank(&self, descending: bool) -> Self {
+        self.inner.clone().dense_rank(descending).into()
+    }
+
+    fn percent_rank(&self, descending: bool) -> Self {
+        self.inner.clone().percent_rank(descending).into()
+    }
+
+    fn cume_dist(&self, descending: bool) -> Self {
+        self.inner.clone().cume_dist(descending).into()
+    }
+
+    fn ntile(&self, n: u32, descending: bool) -> Self {
+        self.inner.clone().ntile(n, descending).into()
+    }
+
     fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> Self {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
@@ -741,6 +810,38 @@ impl PyExpr {
         self.inner.clone().pct_change(n.inner).into()
     }
 
+    #[cfg(feature = "outliers")]
+    fn winsorize(&self, lower_quantile: f64, upper_quantile: f64) -> Self {
+        self.inner
+            .clone()
+            .winsorize(lower_quantile, upper_quantile)
+            .into()
+    }
+
+    #[cfg(feature = "outliers")]
+    fn is_outlier_iqr(&self, k: f64) -> Self {
+        self.inner.clone().is_outlier_iqr(k).into()
+    }
+
+    #[cfg(feature = "outliers")]
+    fn is_outlier_zscore(&self, threshold: f64) -> Self {
+        self.inner.clone().is_outlier_zscore(threshold).into()
+    }
+
+    #[cfg(feature = "geo")]
+    fn point_in_polygon(&self, polygon: Vec<(f64, f64)>) -> Self {
+        let polygon = polygon.into_iter().map(|(x, y)| [x, y]).collect();
+        self.inner.clone().point_in_polygon(polygon).into()
+    }
+
+    #[cfg(feature = "geo")]
+    fn within_bbox(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        self.inner
+            .clone()
+            .within_bbox(min_x, min_y, max_x, max_y)
+            .into()
+    }
+
     fn skew(&self, bias: bool) -> Self {
         self.inner.clone().skew(bias).into()
     }
@@ -761,6 +862,12 @@ impl PyExpr {
         self.inner.clone().shuffle(seed).into()
     }
 
+    #[cfg(feature = "uuid")]
+    #[pyo3(signature = (seed))]
+    fn uuid4(&self, seed: Option<u64>) -> Self {
+        self.inner.clone().uuid4(seed).into()
+    }
+
     #[pyo3(signature = (n, with_replacement, shuffle, seed))]
     fn sample_n(&self, n: Self, with_replacement: bool, shuffle: bool, seed: Option<u64>) -> Self {
         self.inner
@@ -827,6 +934,67 @@ impl PyExpr {
         };
         self.inner.clone().ewm_var(options).into()
     }
+    fn ewm_mean_by(
+        &self,
+        by: PyExpr,
+        half_life: &str,
+        adjust: bool,
+        min_periods: usize,
+        ignore_nulls: bool,
+    ) -> Self {
+        let options = EWMOptions {
+            adjust,
+            min_periods,
+            ignore_nulls,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .ewm_mean_by(by.inner, Duration::parse(half_life), options)
+            .into()
+    }
+    fn ewm_std_by(
+        &self,
+        by: PyExpr,
+        half_life: &str,
+        adjust: bool,
+        bias: bool,
+        min_periods: usize,
+        ignore_nulls: bool,
+    ) -> Self {
+        let options = EWMOptions {
+            adjust,
+            bias,
+            min_periods,
+            ignore_nulls,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .ewm_std_by(by.inner, Duration::parse(half_life), options)
+            .into()
+    }
+    fn ewm_var_by(
+        &self,
+        by: PyExpr,
+        half_life: &str,
+        adjust: bool,
+        bias: bool,
+        min_periods: usize,
+        ignore_nulls: bool,
+    ) -> Self {
+        let options = EWMOptions {
+            adjust,
+            bias,
+            min_periods,
+            ignore_nulls,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .ewm_var_by(by.inner, Duration::parse(half_life), options)
+            .into()
+    }
     fn extend_constant(&self, py: Python, value: Wrap<AnyValue>, n: usize) -> Self {
         let value = value.into_py(py);
         self.inner
@@ -84,6 +84,10 @@ impl PyExpr {
         Ok(out.into())
     }
 
+    fn _meta_to_sql_predicate(&self) -> Option<String> {
+        self.inner.clone().meta()._to_sql_predicate()
+    }
+
     fn _meta_as_selector(&self) -> PyExpr {
         self.inner.clone().meta()._into_selector().into()
     }
@@ -214,6 +214,171 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_sum_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_sum_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_min_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_min_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_max_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_max_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_mean_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_mean_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, ddof, warn_if_unsorted))]
+    fn rolling_std_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        ddof: u8,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            fn_params: Some(Arc::new(RollingVarParams { ddof }) as Arc<dyn Any + Send + Sync>),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_std_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, ddof, warn_if_unsorted))]
+    fn rolling_var_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        ddof: u8,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            fn_params: Some(Arc::new(RollingVarParams { ddof }) as Arc<dyn Any + Send + Sync>),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_var_by(by.inner, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_median_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .rolling_median_by(by.inner, options)
+            .into()
+    }
+
+    #[pyo3(signature = (by, quantile, interpolation, window_size, min_periods, closed, warn_if_unsorted))]
+    fn rolling_quantile_by(
+        &self,
+        by: PyExpr,
+        quantile: f64,
+        interpolation: Wrap<QuantileInterpolOptions>,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        warn_if_unsorted: bool,
+    ) -> Self {
+        let options = RollingOptions {
+            window_size: Duration::parse(window_size),
+            min_periods,
+            closed_window: Some(closed.0),
+            warn_if_unsorted,
+            ..Default::default()
+        };
+
+        self.inner
+            .clone()
+            .rolling_quantile_by(by.inner, interpolation.0, quantile, options)
+            .into()
+    }
+
     fn rolling_skew(&self, window_size: usize, bias: bool) -> Self {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
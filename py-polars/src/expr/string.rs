@@ -66,6 +66,26 @@ impl PyExpr {
         self.inner.clone().str().to_time(options).into()
     }
 
+    #[pyo3(signature = (format, time_unit, strict))]
+    fn str_to_duration(
+        &self,
+        format: Option<String>,
+        time_unit: Wrap<TimeUnit>,
+        strict: bool,
+    ) -> Self {
+        let options = StrptimeOptions {
+            format,
+            strict,
+            exact: true,
+            cache: false,
+        };
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, options)
+            .into()
+    }
+
     fn str_strip_chars(&self, matches: Self) -> Self {
         self.inner.clone().str().strip_chars(matches.inner).into()
     }
@@ -187,6 +207,18 @@ impl PyExpr {
         self.inner.clone().str().starts_with(sub.inner).into()
     }
 
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
     fn str_hex_encode(&self) -> Self {
         self.inner.clone().str().hex_encode().into()
     }
@@ -205,6 +237,16 @@ impl PyExpr {
         self.inner.clone().str().base64_decode(strict).into()
     }
 
+    #[cfg(feature = "uuid")]
+    fn str_is_uuid(&self) -> Self {
+        self.inner.clone().str().is_uuid().into()
+    }
+
+    #[cfg(feature = "uuid")]
+    fn str_to_uuid(&self, strict: bool) -> Self {
+        self.inner.clone().str().to_uuid(strict).into()
+    }
+
     fn str_to_integer(&self, base: u32, strict: bool) -> Self {
         self.inner
             .clone()
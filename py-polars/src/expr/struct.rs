@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 
+use crate::expr::ToExprs;
 use crate::PyExpr;
 
 #[pymethods]
@@ -16,6 +17,14 @@ impl PyExpr {
         self.inner.clone().struct_().rename_fields(names).into()
     }
 
+    fn struct_with_fields(&self, fields: Vec<PyExpr>) -> Self {
+        self.inner
+            .clone()
+            .struct_()
+            .with_fields(fields.to_exprs())
+            .into()
+    }
+
     fn struct_json_encode(&self) -> Self {
         self.inner.clone().struct_().json_encode().into()
     }
@@ -43,3 +43,21 @@ pub fn read_parquet_schema(py: Python, py_f: PyObject) -> PyResult<PyObject> {
     }
     Ok(dict.to_object(py))
 }
+
+#[cfg(feature = "parquet")]
+#[pyfunction]
+pub fn read_parquet_field_ids(py: Python, py_f: PyObject) -> PyResult<PyObject> {
+    use polars::io::parquet::read_field_ids;
+    use polars_parquet::read::read_metadata;
+
+    let metadata = match get_either_file(py_f, false)? {
+        EitherRustPythonFile::Rust(mut r) => read_metadata(&mut r).map_err(PyPolarsErr::from)?,
+        EitherRustPythonFile::Py(mut r) => read_metadata(&mut r).map_err(PyPolarsErr::from)?,
+    };
+
+    let dict = PyDict::new(py);
+    for (path, id) in read_field_ids(&metadata) {
+        dict.set_item(path, id)?;
+    }
+    Ok(dict.to_object(py))
+}
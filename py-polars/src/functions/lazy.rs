@@ -204,6 +204,12 @@ pub fn arctan2d(y: PyExpr, x: PyExpr) -> PyExpr {
     y.inner.arctan2(x.inner).degrees().into()
 }
 
+#[pyfunction]
+#[cfg(feature = "geo")]
+pub fn haversine_distance(lat1: PyExpr, lon1: PyExpr, lat2: PyExpr, lon2: PyExpr) -> PyExpr {
+    dsl::haversine_distance(lat1.inner, lon1.inner, lat2.inner, lon2.inner).into()
+}
+
 #[pyfunction]
 pub fn cum_fold(acc: PyExpr, lambda: PyObject, exprs: Vec<PyExpr>, include_init: bool) -> PyExpr {
     let exprs = exprs.to_exprs();
@@ -285,6 +291,22 @@ pub fn concat_lf_diagonal(
     Ok(lf.into())
 }
 
+#[pyfunction]
+#[cfg(feature = "merge_sorted")]
+pub fn concat_lf_sorted(lfs: &PyAny, key: &str) -> PyResult<PyLazyFrame> {
+    let iter = lfs.iter()?;
+
+    let lfs = iter
+        .map(|item| {
+            let item = item?;
+            get_lf(item)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let lf = dsl::functions::concat_sorted(lfs, key).map_err(PyPolarsErr::from)?;
+    Ok(lf.into())
+}
+
 #[pyfunction]
 pub fn concat_lf_horizontal(lfs: &PyAny, parallel: bool) -> PyResult<PyLazyFrame> {
     let iter = lfs.iter()?;
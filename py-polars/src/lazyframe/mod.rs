@@ -26,15 +26,15 @@ use polars_ops::prelude::AsOfOptions;
 use polars_rs::io::cloud::CloudOptions;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict, PyList};
+use pyo3::types::{PyBytes, PyDict, PyList, PyTuple};
 
 use crate::arrow_interop::to_rust::pyarrow_schema_to_rust;
-use crate::conversion::Wrap;
+use crate::conversion::{parse_fill_null_strategy, Wrap};
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::file::get_file_like;
 use crate::prelude::*;
-use crate::{PyDataFrame, PyExpr, PyLazyGroupBy};
+use crate::{PyDataFrame, PyExpr, PyLazyGroupBy, PySeries};
 
 #[pyclass]
 #[repr(transparent)]
@@ -61,20 +61,15 @@ impl From<LazyFrame> for PyLazyFrame {
 impl PyLazyFrame {
     fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
         // Used in pickle/pickling
-        let mut writer: Vec<u8> = vec![];
-        ciborium::ser::into_writer(&self.ldf.logical_plan, &mut writer)
-            .map_err(|e| PyPolarsErr::Other(format!("{}", e)))?;
-
-        Ok(PyBytes::new(py, &writer).to_object(py))
+        let bytes = self.ldf.serialize().map_err(PyPolarsErr::from)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         // Used in pickle/pickling
         match state.extract::<&PyBytes>(py) {
             Ok(s) => {
-                let lp: LogicalPlan = ciborium::de::from_reader(s.as_bytes())
-                    .map_err(|e| PyPolarsErr::Other(format!("{}", e)))?;
-                self.ldf = LazyFrame::from(lp);
+                self.ldf = LazyFrame::deserialize(s.as_bytes()).map_err(PyPolarsErr::from)?;
                 Ok(())
             },
             Err(e) => Err(e),
@@ -115,18 +110,20 @@ impl PyLazyFrame {
     #[staticmethod]
     #[cfg(feature = "json")]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (path, paths, infer_schema_length, schema, batch_size, n_rows, low_memory, rechunk, row_index, ignore_errors))]
+    #[pyo3(signature = (path, paths, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk, row_index, ignore_errors, include_file_paths))]
     fn new_from_ndjson(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
         infer_schema_length: Option<usize>,
         schema: Option<Wrap<Schema>>,
+        schema_overrides: Option<Wrap<Schema>>,
         batch_size: Option<NonZeroUsize>,
         n_rows: Option<usize>,
         low_memory: bool,
         rechunk: bool,
         row_index: Option<(String, IdxSize)>,
         ignore_errors: bool,
+        include_file_paths: Option<String>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
 
@@ -143,8 +140,10 @@ impl PyLazyFrame {
             .low_memory(low_memory)
             .with_rechunk(rechunk)
             .with_schema(schema.map(|schema| Arc::new(schema.0)))
+            .with_schema_overwrite(schema_overrides.map(|schema| Arc::new(schema.0)))
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
+            .with_include_file_paths(include_file_paths.map(Arc::from))
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -154,9 +153,10 @@ impl PyLazyFrame {
     #[staticmethod]
     #[cfg(feature = "csv")]
     #[pyo3(signature = (path, paths, separator, has_header, ignore_errors, skip_rows, n_rows, cache, overwrite_dtype,
-        low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
+        overwrite_dtype_regex, low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
-        encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, schema
+        encoding, row_index, try_parse_dates, decimal_comma, eol_char, raise_if_empty, truncate_ragged_lines, schema,
+        include_file_paths
     )
     )]
     fn new_from_csv(
@@ -169,6 +169,7 @@ impl PyLazyFrame {
         n_rows: Option<usize>,
         cache: bool,
         overwrite_dtype: Option<Vec<(&str, Wrap<DataType>)>>,
+        overwrite_dtype_regex: Option<Vec<(String, Wrap<DataType>)>>,
         low_memory: bool,
         comment_prefix: Option<&str>,
         quote_char: Option<&str>,
@@ -181,10 +182,12 @@ impl PyLazyFrame {
         encoding: Wrap<CsvEncoding>,
         row_index: Option<(String, IdxSize)>,
         try_parse_dates: bool,
+        decimal_comma: bool,
         eol_char: &str,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
         schema: Option<Wrap<Schema>>,
+        include_file_paths: Option<String>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
@@ -198,6 +201,12 @@ impl PyLazyFrame {
                 .map(|(name, dtype)| Field::new(name, dtype.0))
                 .collect::<Schema>()
         });
+        let overwrite_dtype_regex = overwrite_dtype_regex.map(|overwrite_dtype| {
+            overwrite_dtype
+                .into_iter()
+                .map(|(pattern, dtype)| (pattern, dtype.0))
+                .collect::<Vec<_>>()
+        });
 
         let r = if let Some(path) = path.as_ref() {
             LazyCsvReader::new(path)
@@ -214,6 +223,7 @@ impl PyLazyFrame {
             .with_n_rows(n_rows)
             .with_cache(cache)
             .with_dtype_overwrite(overwrite_dtype.as_ref())
+            .with_dtype_overwrite_regex(overwrite_dtype_regex)
             .with_schema(schema.map(|schema| Arc::new(schema.0)))
             .low_memory(low_memory)
             .with_comment_prefix(comment_prefix)
@@ -224,9 +234,11 @@ impl PyLazyFrame {
             .with_encoding(encoding.0)
             .with_row_index(row_index)
             .with_try_parse_dates(try_parse_dates)
+            .with_decimal_comma(decimal_comma)
             .with_null_values(null_values)
             .with_missing_is_null(!missing_utf8_is_empty_string)
             .truncate_ragged_lines(truncate_ragged_lines)
+            .with_include_file_paths(include_file_paths.map(Arc::from))
             .raise_if_empty(raise_if_empty);
 
         if let Some(lambda) = with_schema_modify {
@@ -258,7 +270,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, retries)
+        low_memory, cloud_options, use_statistics, hive_partitioning, retries, missing_columns,
+        include_file_paths)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -273,6 +286,8 @@ impl PyLazyFrame {
         use_statistics: bool,
         hive_partitioning: bool,
         retries: usize,
+        missing_columns: Wrap<MissingColumns>,
+        include_file_paths: Option<String>,
     ) -> PyResult<Self> {
         let first_path = if let Some(path) = &path {
             path
@@ -306,6 +321,9 @@ impl PyLazyFrame {
             cloud_options,
             use_statistics,
             hive_partitioning,
+            compact_small_files: false,
+            missing_columns: missing_columns.0,
+            include_file_paths: include_file_paths.map(Arc::from),
         };
 
         let lf = if path.is_some() {
@@ -319,7 +337,8 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, hive_partitioning))]
     fn new_from_ipc(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
@@ -328,6 +347,7 @@ impl PyLazyFrame {
         rechunk: bool,
         row_index: Option<(String, IdxSize)>,
         memory_map: bool,
+        hive_partitioning: bool,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
         let args = ScanArgsIpc {
@@ -336,6 +356,7 @@ impl PyLazyFrame {
             rechunk,
             row_index,
             memmap: memory_map,
+            hive_partitioning,
         };
 
         let lf = if let Some(path) = &path {
@@ -367,6 +388,15 @@ impl PyLazyFrame {
         Ok(LazyFrame::scan_from_python_function(schema, scan_fn, pyarrow).into())
     }
 
+    #[staticmethod]
+    fn scan_from_io_plugin(
+        schema: Vec<(&str, Wrap<DataType>)>,
+        scan_fn: PyObject,
+    ) -> PyResult<Self> {
+        let schema = Schema::from_iter(schema.into_iter().map(|(name, dt)| Field::new(name, dt.0)));
+        Ok(LazyFrame::scan_from_io_plugin(schema, scan_fn).into())
+    }
+
     fn describe_plan(&self) -> String {
         self.ldf.describe_plan()
     }
@@ -378,6 +408,15 @@ impl PyLazyFrame {
             .map_err(PyPolarsErr::from)?;
         Ok(result)
     }
+
+    fn describe_annotated_plan(&self, format: Wrap<ExplainFormat>) -> PyResult<String> {
+        let result = self
+            .ldf
+            .describe_annotated_plan(format.0)
+            .map_err(PyPolarsErr::from)?;
+        Ok(result)
+    }
+
     fn to_dot(&self, optimized: bool) -> PyResult<String> {
         let result = self.ldf.to_dot(optimized).map_err(PyPolarsErr::from)?;
         Ok(result)
@@ -490,6 +529,18 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
+    fn profile_with_scan_statistics(
+        &self,
+        py: Python,
+    ) -> PyResult<(PyDataFrame, PyDataFrame, PyDataFrame)> {
+        let (df, time_df, scan_stats_df) = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.profile_with_scan_statistics()
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok((df.into(), time_df.into(), scan_stats_df.into()))
+    }
+
     fn collect(&self, py: Python) -> PyResult<PyDataFrame> {
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -527,7 +578,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, row_group_size_bytes, data_pagesize_limit, column_options, maintain_order))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -536,16 +587,25 @@ impl PyLazyFrame {
         compression_level: Option<i32>,
         statistics: bool,
         row_group_size: Option<usize>,
+        row_group_size_bytes: Option<usize>,
         data_pagesize_limit: Option<usize>,
+        column_options: Option<Vec<(String, Wrap<ParquetColumnOptions>)>>,
         maintain_order: bool,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let column_options = column_options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, options)| (name, options.0))
+            .collect();
 
         let options = ParquetWriteOptions {
             compression,
             statistics,
             row_group_size,
+            row_group_size_bytes,
             data_pagesize_limit,
+            column_options,
             maintain_order,
         };
 
@@ -648,6 +708,78 @@ impl PyLazyFrame {
         Ok(())
     }
 
+    #[cfg(feature = "streaming")]
+    #[pyo3(signature = (path, by, file_format, maintain_order))]
+    fn sink_partitioned(
+        &self,
+        py: Python,
+        path: PathBuf,
+        by: Vec<String>,
+        file_format: &str,
+        maintain_order: bool,
+    ) -> PyResult<()> {
+        let file_type = match file_format {
+            #[cfg(feature = "parquet")]
+            "parquet" => FileType::Parquet(ParquetWriteOptions {
+                maintain_order,
+                ..Default::default()
+            }),
+            #[cfg(feature = "ipc")]
+            "ipc" => FileType::Ipc(IpcWriterOptions {
+                maintain_order,
+                ..Default::default()
+            }),
+            #[cfg(feature = "csv")]
+            "csv" => FileType::Csv(CsvWriterOptions {
+                maintain_order,
+                ..Default::default()
+            }),
+            e => {
+                return Err(PyValueError::new_err(format!(
+                    "`file_format` must be one of {{'parquet', 'ipc', 'csv'}}, got {e}",
+                )))
+            },
+        };
+
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_partitioned(path, by, file_type)
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
+    fn partition_by(&self, py: Python, by: Vec<String>) -> PyResult<Vec<(PyObject, Self)>> {
+        let ldf = self.ldf.clone();
+        let partitions = py.allow_threads(|| ldf.partition_by(by).map_err(PyPolarsErr::from))?;
+        Ok(partitions
+            .into_iter()
+            .map(|(key, ldf)| {
+                let key = PyTuple::new(py, key.into_iter().map(|av| Wrap(av).into_py(py)));
+                (key.into_py(py), ldf.into())
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "fill_gaps")]
+    #[pyo3(signature = (time_column, every, by, fill_strategy, limit))]
+    fn upsample(
+        &self,
+        time_column: &str,
+        every: &str,
+        by: Vec<String>,
+        fill_strategy: Option<&str>,
+        limit: FillNullLimit,
+    ) -> PyResult<Self> {
+        let fill_strategy = fill_strategy
+            .map(|strategy| parse_fill_null_strategy(strategy, limit))
+            .transpose()?;
+        let ldf = self.ldf.clone();
+        Ok(ldf.upsample(time_column, every, by, fill_strategy).into())
+    }
+
     fn fetch(&self, py: Python, n_rows: usize) -> PyResult<PyDataFrame> {
         let ldf = self.ldf.clone();
         let df = py.allow_threads(|| ldf.fetch(n_rows).map_err(PyPolarsErr::from))?;
@@ -926,10 +1058,10 @@ impl PyLazyFrame {
         Ok(out.into())
     }
 
-    fn explode(&self, column: Vec<PyExpr>) -> Self {
+    fn explode(&self, column: Vec<PyExpr>, mismatch: Wrap<ExplodeMismatch>) -> Self {
         let ldf = self.ldf.clone();
         let column = column.to_exprs();
-        ldf.explode(column).into()
+        ldf.explode_with(column, mismatch.0).into()
     }
 
     fn null_count(&self) -> Self {
@@ -952,10 +1084,9 @@ impl PyLazyFrame {
         .into()
     }
 
-    fn drop_nulls(&self, subset: Option<Vec<String>>) -> Self {
+    fn drop_nulls(&self, subset: Option<Vec<PyExpr>>) -> Self {
         let ldf = self.ldf.clone();
-        ldf.drop_nulls(subset.map(|v| v.into_iter().map(|s| col(&s)).collect()))
-            .into()
+        ldf.drop_nulls(subset.map(|v| v.to_exprs())).into()
     }
 
     fn slice(&self, offset: i64, len: Option<IdxSize>) -> Self {
@@ -1069,6 +1200,35 @@ impl PyLazyFrame {
         Ok(schema_dict.to_object(py))
     }
 
+    #[cfg(feature = "pivot")]
+    #[allow(clippy::too_many_arguments)]
+    fn pivot(
+        &self,
+        values: Vec<String>,
+        index: Vec<String>,
+        columns: &str,
+        column_values: PySeries,
+        aggregate_expr: Option<PyExpr>,
+        sort_columns: bool,
+        separator: Option<&str>,
+        struct_output: bool,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .pivot(
+                &values,
+                &index,
+                columns,
+                &column_values.series,
+                aggregate_expr.map(|expr| expr.inner),
+                sort_columns,
+                separator,
+                struct_output,
+            )
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     fn unnest(&self, columns: Vec<String>) -> Self {
         self.ldf.clone().unnest(columns).into()
     }
@@ -24,6 +24,13 @@ impl PyLazyGroupBy {
         lgb.agg(aggs).into()
     }
 
+    fn having(&mut self, predicate: PyExpr) -> PyLazyGroupBy {
+        let lgb = self.lgb.clone().unwrap();
+        PyLazyGroupBy {
+            lgb: Some(lgb.having(predicate.inner)),
+        }
+    }
+
     fn head(&mut self, n: usize) -> PyLazyFrame {
         let lgb = self.lgb.clone().unwrap();
         lgb.head(Some(n)).into()
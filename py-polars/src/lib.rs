@@ -159,6 +159,9 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
     #[cfg(feature = "trigonometry")]
     m.add_wrapped(wrap_pyfunction!(functions::arctan2d))
         .unwrap();
+    #[cfg(feature = "geo")]
+    m.add_wrapped(wrap_pyfunction!(functions::haversine_distance))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::datetime))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_expr))
@@ -167,6 +170,9 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_horizontal))
         .unwrap();
+    #[cfg(feature = "merge_sorted")]
+    m.add_wrapped(wrap_pyfunction!(functions::concat_lf_sorted))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::dtype_cols))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::duration))
@@ -199,6 +205,9 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_schema))
         .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(functions::read_parquet_field_ids))
+        .unwrap();
 
     // Functions - meta
     m.add_wrapped(wrap_pyfunction!(functions::get_polars_version))
@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_with_column_cow() -> None:
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}),
+            pl.DataFrame({"a": [4, 5], "b": ["u", "v"]}),
+            pl.DataFrame({"a": [6], "b": ["w"]}),
+        ],
+        rechunk=False,
+    )
+    assert df.shared_chunk_counts(df) == {"a": 3, "b": 3}
+
+    updated = df.with_column_cow(
+        "a", lambda chunk: chunk * 10 if chunk.len() == 2 else None
+    )
+    assert_frame_equal(
+        updated,
+        pl.DataFrame(
+            {"a": [1, 2, 3, 40, 50, 6], "b": ["x", "y", "z", "u", "v", "w"]}
+        ),
+    )
+    assert updated.n_chunks("all") == [3, 3]
+    assert updated.shared_chunk_counts(df) == {"a": 2, "b": 3}
+    assert df["a"].to_list() == [1, 2, 3, 4, 5, 6]
+
+    assert pl.DataFrame({"b": ["x"]}).shared_chunk_counts(df) == {"b": 0}
+
+
+def test_with_column_cow_invalid() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(pl.ShapeError):
+        df.with_column_cow("a", lambda chunk: chunk.head(1))
+    with pytest.raises(pl.SchemaError):
+        df.with_column_cow("a", lambda chunk: chunk.cast(pl.Float64))
+    with pytest.raises(pl.ComputeError):
+        df.with_column_cow("a", lambda chunk: 1)  # type: ignore[arg-type, return-value]
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.with_column_cow("c", lambda chunk: None)
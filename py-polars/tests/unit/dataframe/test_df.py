@@ -533,6 +533,28 @@ def test_multiple_columns_drop() -> None:
     assert out.columns == ["a"]
 
 
+def test_align_chunks() -> None:
+    df = pl.concat(
+        [pl.DataFrame({"a": [1, 2]}), pl.DataFrame({"a": [3, 4, 5]})], rechunk=False
+    ).hstack([pl.Series("b", [1, 2, 3, 4, 5])])
+    assert df.chunk_lengths() == [[2, 3], [5]]
+    assert df.rechunk_diagnostics() == {"sort": ["a"], "unique": ["a"], "join": ["a"]}
+
+    # aligned by splitting the chunks of "b"
+    out = df.align_chunks()
+    assert out.chunk_lengths() == [[2, 3], [2, 3]]
+    assert_frame_equal(out, df)
+
+    # aligning these would fragment the chunks, so they are rechunked
+    c = pl.concat([pl.Series("c", [1, 2, 3, 4]), pl.Series("c", [5])], rechunk=False)
+    df = out.hstack([c])
+    assert "align_chunks" in df.rechunk_diagnostics()
+    out = df.align_chunks()
+    assert out.chunk_lengths() == [[5], [5], [5]]
+    assert out.rechunk_diagnostics() == {}
+    assert_frame_equal(out, df)
+
+
 def test_concat() -> None:
     df1 = pl.DataFrame({"a": [2, 1, 3], "b": [1, 2, 3], "c": [1, 2, 3]})
     df2 = pl.concat([df1, df1])
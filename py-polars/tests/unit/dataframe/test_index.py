@@ -0,0 +1,41 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.parametrize("kind", ["sorted", "hash"])
+def test_index_filter_and_join(kind: str) -> None:
+    df = pl.DataFrame({"a": [1, 2, None, 2], "b": ["x", "y", "z", "w"]})
+    assert df.create_index("a", kind=kind) is df  # type: ignore[arg-type]
+
+    assert_frame_equal(
+        df.filter(pl.col("a") == 2, pl.col("b") != "w"),
+        pl.DataFrame({"a": [2], "b": ["y"]}),
+    )
+
+    left = pl.DataFrame({"a": [2, 5, None, 1]})
+    assert_frame_equal(
+        left.join(df, on="a", how="left"),
+        pl.DataFrame({"a": [2, 2, 5, None, 1], "b": ["y", "w", None, None, "x"]}),
+    )
+    assert_frame_equal(
+        left.join(df, on="a", how="inner"),
+        pl.DataFrame({"a": [2, 2, 1], "b": ["y", "w", "x"]}),
+    )
+
+
+def test_index_invalidation() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    df.create_index(["a", "b"], kind="hash")
+    df.replace_column(0, pl.Series("a", [3, 2, 1]))
+    assert df.filter(pl.col("a") == 1, pl.col("b") == 6).rows() == [(1, 6)]
+    assert df.drop_indexes() is df
+
+
+def test_index_invalid_kind() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="`kind` must be one of"):
+        df.create_index("a", kind="btree")  # type: ignore[arg-type]
@@ -4,6 +4,7 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
+from polars.testing import assert_frame_equal
 
 
 @pytest.fixture()
@@ -99,3 +100,28 @@ def test_partition_by_as_dict_include_keys_false_large() -> None:
 
     partitions = df.partition_by(["a", "b"], as_dict=True, include_key=False)
     assert all(key == value.row(0) for key, value in partitions.items())
+
+
+def test_lazy_partition_by() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["a", "b", None, "b", "a"],
+            "b": [1, 2, 3, 2, 1],
+            "c": [5, 4, 3, 2, 1],
+        }
+    )
+    lf = df.lazy()
+
+    result = [p.collect() for p in lf.partition_by("a", "b")]
+    expected = df.partition_by("a", "b")
+    assert len(result) == len(expected)
+    for r, e in zip(result, expected):
+        assert_frame_equal(r, e)
+
+    partitions = lf.with_columns(pl.col("c") * 10).partition_by(["a"], as_dict=True)
+    assert list(partitions) == [("a",), ("b",), (None,)]
+    assert partitions[(None,)].collect().to_dict(as_series=False) == {
+        "a": [None],
+        "b": [3],
+        "c": [30],
+    }
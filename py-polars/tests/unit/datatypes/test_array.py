@@ -1,11 +1,16 @@
+from __future__ import annotations
+
 import datetime
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import polars as pl
 from polars.exceptions import InvalidOperationError
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
 
 
 def test_cast_list_array() -> None:
@@ -213,3 +218,95 @@ def test_array_repeat() -> None:
     expected = pl.Series("repeat", [[42], [42], [42]], dtype=dtype)
     assert s.dtype == dtype
     assert_series_equal(s, expected)
+
+
+def test_array_arithmetic() -> None:
+    dtype = pl.Array(pl.Int64, 2)
+    a = pl.Series("a", [[1, 2], [3, 4], None], dtype=dtype)
+    b = pl.Series("b", [[10, 20], None, [50, 60]], dtype=dtype)
+
+    assert_series_equal(
+        a + b, pl.Series("a", [[11, 22], None, None], dtype=dtype)
+    )
+    assert_series_equal(
+        b - a, pl.Series("b", [[9, 18], None, None], dtype=dtype)
+    )
+    assert_series_equal(
+        a * b, pl.Series("a", [[10, 40], None, None], dtype=dtype)
+    )
+    assert_series_equal(
+        b % 7, pl.Series("b", [[3, 6], None, [1, 4]], dtype=dtype)
+    )
+    assert_series_equal(
+        b / a,
+        pl.Series("b", [[10.0, 10.0], None, None], dtype=pl.Array(pl.Float64, 2)),
+    )
+
+    # scalars are applied on every element
+    assert_series_equal(a + 1, pl.Series("a", [[2, 3], [4, 5], None], dtype=dtype))
+    assert_series_equal(
+        a * 0.5,
+        pl.Series("a", [[0.5, 1.0], [1.5, 2.0], None], dtype=pl.Array(pl.Float64, 2)),
+    )
+
+    # a series is applied on every element of the array in the same row
+    c = pl.Series("c", [10, 100, 1000])
+    assert_series_equal(
+        a * c, pl.Series("a", [[10, 20], [300, 400], None], dtype=dtype)
+    )
+
+
+def test_array_arithmetic_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "emb": pl.Series([[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float32, 2)),
+            "other": pl.Series([[1.0, 1.0], [2.0, 2.0]], dtype=pl.Array(pl.Float32, 2)),
+            "w": [2, 3],
+        }
+    )
+    out = df.lazy().select(
+        add=pl.col("emb") + pl.col("other"),
+        scaled=pl.col("emb") * pl.col("w"),
+        div=pl.col("emb") / 2,
+        norm=(pl.col("emb") * pl.col("emb")).arr.sum().sqrt(),
+    )
+    assert out.schema == {
+        "add": pl.Array(pl.Float32, 2),
+        "scaled": pl.Array(pl.Float64, 2),
+        "div": pl.Array(pl.Float64, 2),
+        "norm": pl.Float32,
+    }
+    result = out.collect()
+    assert result.schema == out.schema
+    assert result.to_dict(as_series=False) == {
+        "add": [[2.0, 3.0], [5.0, 6.0]],
+        "scaled": [[2.0, 4.0], [9.0, 12.0]],
+        "div": [[0.5, 1.0], [1.5, 2.0]],
+        "norm": [pytest.approx(5**0.5), 5.0],
+    }
+
+
+def test_array_arithmetic_width_mismatch() -> None:
+    a = pl.Series([[1, 2]], dtype=pl.Array(pl.Int64, 2))
+    b = pl.Series([[1, 2, 3]], dtype=pl.Array(pl.Int64, 3))
+    with pytest.raises(pl.ShapeError, match="different widths"):
+        a + b
+
+
+@pytest.mark.write_disk()
+def test_array_parquet_round_trip(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "emb": pl.Series(
+                [[0.1, 0.2, 0.3], None, [0.4, None, 0.6]],
+                dtype=pl.Array(pl.Float32, 3),
+            ),
+            "ids": pl.Series([[1, 2], [3, 4], [5, 6]], dtype=pl.Array(pl.Int64, 2)),
+        }
+    )
+    path = tmp_path / "array.parquet"
+    df.write_parquet(path)
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    out = pl.scan_parquet(path).select(pl.col("ids").arr.sum()).collect()
+    assert out.to_series().to_list() == [3, 7, 11]
@@ -4,7 +4,7 @@ import io
 import itertools
 from dataclasses import dataclass
 from decimal import Decimal as D
-from typing import Any, NamedTuple
+from typing import TYPE_CHECKING, Any, NamedTuple
 
 import numpy as np
 import pytest
@@ -13,6 +13,9 @@ from numpy.testing import assert_array_equal
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -203,14 +206,14 @@ def test_decimal_arithmetic() -> None:
         out4=pl.col("a") - pl.col("b"),
     )
     assert out.dtypes == [
-        pl.Decimal(precision=None, scale=2),
+        pl.Decimal(precision=None, scale=4),
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=2),
     ]
 
     assert out.to_dict(as_series=False) == {
-        "out1": [D("2.01"), D("102.91"), D("3921.39")],
+        "out1": [D("2.0100"), D("102.9190"), D("3921.3921")],
         "out2": [D("20.20"), D("20.29"), D("139.22")],
         "out3": [D("0.00"), D("0.99"), D("2.55")],
         "out4": [D("-20.00"), D("-0.09"), D("60.80")],
@@ -222,25 +225,22 @@ def test_decimal_series_value_arithmetic() -> None:
 
     out1 = s + 10
     out2 = s + D("10")
-    with pytest.raises(pl.InvalidOperationError):
-        s + D("10.0001")
+    out3 = s + D("10.0001")
     out4 = s * 2 / 3
     out5 = s / D("1.5")
     out6 = s - 5
 
     assert out1.dtype == pl.Decimal(precision=None, scale=2)
     assert out2.dtype == pl.Decimal(precision=None, scale=2)
-    assert out4.dtype == pl.Decimal(precision=None, scale=2)
+    assert out3.dtype == pl.Decimal(precision=None, scale=4)
+    assert out4.dtype == pl.Decimal(precision=None, scale=4)
     assert out5.dtype == pl.Decimal(precision=None, scale=2)
     assert out6.dtype == pl.Decimal(precision=None, scale=2)
 
     assert out1.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out2.to_list() == [D("10.1"), D("20.1"), D("110.01")]
-    assert out4.to_list() == [
-        D("0.06"),
-        D("6.73"),
-        D("66.67"),
-    ]  # TODO: do we want floor instead of round?
+    assert out3.to_list() == [D("10.1001"), D("20.1001"), D("110.0101")]
+    assert out4.to_list() == [D("0.0666"), D("6.7333"), D("66.6733")]
     assert out5.to_list() == [D("0.06"), D("6.73"), D("66.67")]
     assert out6.to_list() == [D("-4.9"), D("5.1"), D("95.01")]
 
@@ -275,6 +275,144 @@ def test_decimal_aggregations() -> None:
     }
 
 
+def test_decimal_arithmetic_precision() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([D("1.23"), D("-4.56")], dtype=pl.Decimal(5, 2)),
+            "b": pl.Series([D("0.100"), D("2.000")], dtype=pl.Decimal(4, 3)),
+        }
+    )
+    out = df.select(
+        add=pl.col("a") + pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+    )
+    assert out.schema == {
+        "add": pl.Decimal(7, 3),
+        "mul": pl.Decimal(12, 6),
+        "div": pl.Decimal(9, 3),
+    }
+    assert out.to_dict(as_series=False) == {
+        "add": [D("1.330"), D("-2.560")],
+        "mul": [D("0.123000"), D("-9.120000")],
+        "div": [D("12.300"), D("-2.280")],
+    }
+
+
+def test_decimal_division_by_zero_is_null() -> None:
+    s = pl.Series([D("1.00"), D("2.00")])
+    out = s / pl.Series([D("0.00"), D("4.00")])
+    assert out.to_list() == [None, D("0.50")]
+
+
+def test_decimal_overflow_raises() -> None:
+    s = pl.Series([D(10**20)])
+    with pytest.raises(pl.ComputeError, match="overflow"):
+        s * s
+
+
+def test_decimal_comparison() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([D("1.5"), D("2.0"), D("3.0")], dtype=pl.Decimal(4, 1)),
+            "b": pl.Series([D("1.50"), D("2.50"), D("2.99")], dtype=pl.Decimal(5, 2)),
+        }
+    )
+    assert df.select(
+        eq=pl.col("a") == pl.col("b"),
+        lt=pl.col("a") < pl.col("b"),
+        gt=pl.col("a") > pl.col("b"),
+    ).to_dict(as_series=False) == {
+        "eq": [True, False, False],
+        "lt": [False, True, False],
+        "gt": [False, False, True],
+    }
+
+
+def test_decimal_group_by_key() -> None:
+    df = pl.DataFrame(
+        {
+            "k": [D("1.10"), D("2.20"), D("1.10"), None],
+            "v": [1, 2, 3, 4],
+        }
+    )
+    out = df.group_by("k", maintain_order=True).agg(pl.sum("v"))
+    assert out.schema == {"k": pl.Decimal(None, 2), "v": pl.Int64}
+    assert out.to_dict(as_series=False) == {
+        "k": [D("1.10"), D("2.20"), None],
+        "v": [4, 2, 4],
+    }
+    assert df["k"].n_unique() == 3
+    assert df["k"].sort().to_list() == [None, D("1.10"), D("1.10"), D("2.20")]
+
+
+def test_decimal_mean_median() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2],
+            "a": [D("0.1"), D("10.1"), D("100.01"), D("9000.12")],
+        }
+    )
+    assert df.group_by("g", maintain_order=True).agg(
+        mean=pl.mean("a"),
+        median=pl.median("a"),
+    ).to_dict(as_series=False) == {
+        "g": [1, 2],
+        "mean": [5.1, 4550.065],
+        "median": [5.1, 4550.065],
+    }
+    assert df["a"].mean() == pytest.approx(2277.5825)
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+@pytest.mark.parametrize("how", ["inner", "left", "outer", "semi", "anti"])
+def test_decimal_join(how: str, streaming: bool) -> None:
+    left = pl.DataFrame(
+        {
+            "k": pl.Series([D("1.0"), D("2.5"), D("3.0")], dtype=pl.Decimal(10, 1)),
+            "a": [1, 2, 3],
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "k": pl.Series([D("2.50"), D("3.00"), D("4.00")], dtype=pl.Decimal(12, 2)),
+            "b": ["x", "y", "z"],
+        }
+    )
+    out = (
+        left.lazy()
+        .join(right.lazy(), on="k", how=how)  # type: ignore[arg-type]
+        .collect(streaming=streaming)
+        .sort("k", nulls_last=True)
+    )
+    expected = {
+        "inner": [D("2.5"), D("3.0")],
+        "left": [D("1.0"), D("2.5"), D("3.0")],
+        "outer": [D("1.0"), D("2.5"), D("3.0"), None],
+        "semi": [D("2.5"), D("3.0")],
+        "anti": [D("1.0")],
+    }[how]
+    assert out["k"].to_list() == expected
+
+
+@pytest.mark.write_disk()
+def test_decimal_parquet_ipc_round_trip(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([D("1.23"), None, D("-45.60")], dtype=pl.Decimal(10, 2)),
+            "b": pl.Series([D("0.000001"), D("123456.7"), None], dtype=pl.Decimal(20, 6)),
+        }
+    )
+
+    path = tmp_path / "decimal.parquet"
+    df.write_parquet(path)
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    path = tmp_path / "decimal.arrow"
+    df.write_ipc(path)
+    assert_frame_equal(pl.read_ipc(path), df)
+
+
 def test_decimal_in_filter() -> None:
     df = pl.DataFrame(
         {
@@ -9,7 +9,7 @@ import pytest
 
 import polars as pl
 from polars import StringCache
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_enum_creation() -> None:
@@ -361,3 +361,107 @@ def test_enum_categories_series_zero_copy() -> None:
 
     assert categories._get_buffer_info() == dtype.categories._get_buffer_info()
     assert categories._get_buffer_info() == result_dtype.categories._get_buffer_info()  # type: ignore[attr-defined]
+
+
+def test_casting_to_an_enum_from_utf_non_strict() -> None:
+    dtype = pl.Enum(["a", "b"])
+    s = pl.Series("s", [None, "a", "x", "b"])
+
+    out = s.cast(dtype, strict=False)
+    expected = pl.Series("s", [None, "a", None, "b"], dtype=dtype)
+    assert_series_equal(out, expected)
+
+    with pytest.raises(pl.ComputeError, match="value 'x' is not present in Enum"):
+        s.cast(dtype)
+
+
+def test_casting_to_an_enum_from_categorical_non_strict() -> None:
+    dtype = pl.Enum(["a", "b"])
+    s = pl.Series("s", ["a", "c", None], dtype=pl.Categorical)
+    out = s.cast(dtype, strict=False)
+    assert_series_equal(out, pl.Series("s", ["a", None, None], dtype=dtype))
+
+
+def test_enum_ordering() -> None:
+    physical = pl.Enum(["low", "medium", "high"])
+    lexical = pl.Enum(["low", "medium", "high"], ordering="lexical")
+    assert physical.ordering == "physical"
+    assert physical != lexical
+    assert repr(lexical) == (
+        "Enum(categories=['low', 'medium', 'high'], ordering='lexical')"
+    )
+
+    values = ["high", "low", None, "medium"]
+    s = pl.Series(values, dtype=physical)
+    assert s.dtype == physical
+    assert s.sort().to_list() == [None, "low", "medium", "high"]
+    assert (s < "medium").to_list() == [False, True, None, False]
+    assert (s < pl.Series(["low"] * 4, dtype=physical)).to_list() == [
+        False,
+        False,
+        None,
+        False,
+    ]
+
+    s = pl.Series(values, dtype=lexical)
+    assert s.dtype == lexical
+    assert s.sort().to_list() == [None, "high", "low", "medium"]
+    assert (s < "medium").to_list() == [True, True, None, False]
+    assert (s < pl.Series(["low"] * 4, dtype=lexical)).to_list() == [
+        True,
+        False,
+        None,
+        False,
+    ]
+
+
+def test_enum_group_by() -> None:
+    dtype = pl.Enum(["a", "b", "c"])
+    df = pl.DataFrame(
+        {
+            "k": pl.Series(["b", "a", "b", None, "c"], dtype=dtype),
+            "v": [1, 2, 3, 4, 5],
+        }
+    )
+    out = df.group_by("k", maintain_order=True).agg(pl.sum("v"))
+    expected = pl.DataFrame(
+        {"k": pl.Series(["b", "a", None, "c"], dtype=dtype), "v": [4, 2, 4, 5]}
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_enum_join(streaming: bool) -> None:
+    dtype = pl.Enum(["a", "b", "c"])
+    left = pl.DataFrame({"k": pl.Series(["a", "b", "c"], dtype=dtype), "x": [1, 2, 3]})
+    right = pl.DataFrame({"k": pl.Series(["c", "a"], dtype=dtype), "y": [10, 20]})
+
+    out = (
+        left.lazy()
+        .join(right.lazy(), on="k", how="left")
+        .collect(streaming=streaming)
+        .sort("x")
+    )
+    assert out.to_dict(as_series=False) == {
+        "k": ["a", "b", "c"],
+        "x": [1, 2, 3],
+        "y": [20, None, 10],
+    }
+
+    # keys of another type are cast to the enum, unknown values don't match
+    for other in (pl.String, pl.Categorical):
+        right_other = pl.DataFrame(
+            {"k": pl.Series(["c", "a", "z"], dtype=other), "y": [10, 20, 30]}
+        )
+        out = (
+            left.lazy()
+            .join(right_other.lazy(), on="k", how="inner")
+            .collect(streaming=streaming)
+            .sort("x")
+        )
+        assert out.schema["k"] == dtype
+        assert out.to_dict(as_series=False) == {
+            "k": ["a", "c"],
+            "x": [1, 3],
+            "y": [20, 10],
+        }
@@ -761,6 +761,42 @@ def test_upsample_time_zones(
     assert_frame_equal(result, expected)
 
 
+def test_upsample_lazy() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 2, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+            ],
+            "groups": ["A", "B", "A", "B"],
+            "values": [0, 1, 2, 3],
+        }
+    )
+    result = df.lazy().upsample("time", every="1mo", by="groups").collect()
+    expected = (
+        df.upsample(time_column="time", every="1mo", by="groups", maintain_order=True)
+        .with_columns(pl.col("groups").forward_fill())
+        .sort("groups", "time")
+    )
+    assert_frame_equal(result, expected)
+
+    result = (
+        df.lazy()
+        .upsample("time", every="1mo", by=["groups"], fill_strategy="forward")
+        .collect()
+    )
+    assert result["values"].to_list() == [0, 0, 0, 2, 1, 1, 3]
+
+    result = (
+        df.lazy()
+        .upsample("time", every="1mo", fill_strategy="backward", limit=1)
+        .collect()
+    )
+    assert result["values"].to_list() == [0, 1, 1, 2, 3]
+
+
 def test_microseconds_accuracy() -> None:
     timestamps = [
         datetime(2600, 1, 1, 0, 0, 0, 123456),
@@ -1700,6 +1736,29 @@ def test_replace_time_zone_ambiguous_raises() -> None:
         ts.dt.replace_time_zone("Europe/Brussels")
 
 
+def test_replace_time_zone_ambiguous_null() -> None:
+    ts = pl.Series(
+        ["2018-10-28 01:30:00", "2018-10-28 02:30:00", None, "2018-10-28 03:30:00"]
+    ).str.strptime(pl.Datetime)
+    result = ts.dt.replace_time_zone("Europe/Brussels", ambiguous="null")
+    tz = ZoneInfo("Europe/Brussels")
+    assert result.to_list() == [
+        datetime(2018, 10, 28, 1, 30, tzinfo=tz),
+        None,
+        None,
+        datetime(2018, 10, 28, 3, 30, tzinfo=tz),
+    ]
+
+    # per-row ambiguous values
+    df = pl.DataFrame({"ts": ts[:2], "ambiguous": ["raise", "null"]})
+    out = df.select(
+        pl.col("ts").dt.replace_time_zone(
+            "Europe/Brussels", ambiguous=pl.col("ambiguous")
+        )
+    )
+    assert out["ts"].to_list() == [datetime(2018, 10, 28, 1, 30, tzinfo=tz), None]
+
+
 @pytest.mark.parametrize(
     ("from_tz", "expected_sortedness", "ambiguous"),
     [
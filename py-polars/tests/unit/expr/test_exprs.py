@@ -412,6 +412,50 @@ def test_search_sorted() -> None:
     assert a.search_sorted(b, side="right").to_list() == [0, 2, 2, 4, 4]
 
 
+def test_search_sorted_descending() -> None:
+    a = pl.Series([4, 4, 1, 1])
+    b = pl.Series([5, 4, 2, 1, 0])
+    left = a.search_sorted(b, side="left", descending=True)
+    assert left.to_list() == [0, 0, 2, 2, 4]
+    right = a.search_sorted(b, side="right", descending=True)
+    assert right.to_list() == [0, 2, 2, 4, 4]
+
+    a = pl.Series(["d", "c", "a"])
+    assert a.search_sorted("b", descending=True) == 2
+
+
+def test_search_sorted_temporal() -> None:
+    a = pl.Series([date(2020, 1, 1), date(2021, 1, 1), date(2022, 1, 1)])
+    assert a.search_sorted(date(2021, 6, 1)) == 2
+    assert a.search_sorted(date(2021, 1, 1), side="right") == 2
+
+    a = pl.Series([timedelta(hours=1), timedelta(hours=3)])
+    assert a.search_sorted(timedelta(hours=2)) == 1
+
+
+def test_index_of() -> None:
+    s = pl.Series("a", [3, None, 1, 3])
+    assert s.index_of(3) == 0
+    assert s.index_of(1) == 2
+    assert s.index_of(None) == 1
+    assert s.index_of(7) is None
+
+    s = pl.Series(["x", "y", None])
+    assert s.index_of("y") == 1
+    assert s.index_of("z") is None
+
+    s = pl.Series([date(2020, 1, 1), date(2021, 1, 1)])
+    assert s.index_of(date(2021, 1, 1)) == 1
+
+    s = pl.Series([5, 4, 4, 2]).set_sorted(descending=True)
+    assert s.index_of(4) == 1
+    assert s.index_of(3) is None
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "v": [7, 8, 8, 9]})
+    out = df.group_by("g", maintain_order=True).agg(pl.col("v").index_of(8))
+    assert out.to_dict(as_series=False) == {"g": [1, 2], "v": [1, 0]}
+
+
 def test_abs_expr() -> None:
     df = pl.DataFrame({"x": [-1, 0, 1]})
     out = df.select(abs(pl.col("x")))
@@ -465,6 +509,74 @@ def test_ewm_with_multiple_chunks() -> None:
     assert ewm_std.null_count().sum_horizontal()[0] == 4
 
 
+@pytest.mark.parametrize("adjust", [True, False])
+@pytest.mark.parametrize("ignore_nulls", [True, False])
+def test_ewm_by_equally_spaced(adjust: bool, ignore_nulls: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(date(2020, 1, 1), date(2020, 1, 6), eager=True),
+            "a": [1.0, 5.0, None, 7.0, 1.0, 2.0],
+        }
+    )
+    params = {"adjust": adjust, "ignore_nulls": ignore_nulls}
+    result = df.select(
+        pl.col("a").ewm_mean(half_life="1d", by="date", **params).alias("mean"),
+        pl.col("a")
+        .ewm_std(half_life=timedelta(days=1), by="date", **params)
+        .alias("std"),
+        pl.col("a").ewm_var(half_life="24h", by="date", **params).alias("var"),
+    )
+    expected = df.select(
+        pl.col("a").ewm_mean(half_life=1, **params).alias("mean"),
+        pl.col("a").ewm_std(half_life=1, **params).alias("std"),
+        pl.col("a").ewm_var(half_life=1, **params).alias("var"),
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_ewm_mean_by_irregular() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [
+                datetime(2020, 1, 1),
+                datetime(2020, 1, 2),
+                datetime(2020, 1, 5),
+                datetime(2020, 1, 1),
+                datetime(2020, 1, 3),
+            ],
+            "group": ["a", "a", "a", "b", "b"],
+            "value": [1, 2, 3, 1, 3],
+        }
+    )
+    result = df.select(
+        pl.col("value").ewm_mean(half_life="1d", by="date").over("group")
+    )
+    expected = pl.Series("value", [1.0, 5 / 3, 3.3125 / 1.1875, 1.0, 2.6])
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.group_by("group", maintain_order=True).agg(
+        pl.col("value").ewm_mean(half_life="1d", by="date", adjust=False)
+    )
+    expected_frame = pl.DataFrame(
+        {"group": ["a", "b"], "value": [[1.0, 1.5, 2.8125], [1.0, 2.5]]}
+    )
+    assert_frame_equal(result, expected_frame)
+
+
+def test_ewm_by_invalid() -> None:
+    df = pl.DataFrame({"date": [date(2020, 1, 2), date(2020, 1, 1)], "a": [1, 2]})
+    with pytest.raises(pl.InvalidOperationError, match="sorted"):
+        df.select(pl.col("a").ewm_mean(half_life="1d", by="date"))
+    with pytest.raises(pl.InvalidOperationError, match="date/datetime"):
+        df.select(pl.col("a").ewm_mean(half_life="1d", by="a"))
+    with pytest.raises(pl.InvalidOperationError, match="half_life"):
+        df.select(pl.col("a").ewm_mean(half_life="1mo", by="date"))
+    with pytest.raises(ValueError, match="only `half_life`"):
+        pl.col("a").ewm_mean(com=1, by="date")
+    with pytest.raises(TypeError, match="duration `half_life`"):
+        pl.col("a").ewm_mean(half_life="1d")
+
+
 def test_lit_dtypes() -> None:
     def lit_series(value: Any, dtype: pl.PolarsDataType | None) -> pl.Series:
         return pl.select(pl.lit(value, dtype=dtype)).to_series()
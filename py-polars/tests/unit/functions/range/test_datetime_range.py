@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import date, datetime, timedelta
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -104,6 +104,21 @@ def test_datetime_range_invalid_time_unit() -> None:
         )
 
 
+@pytest.mark.parametrize("fn", [pl.datetime_range, pl.datetime_ranges])
+def test_datetime_range_non_temporal_input(fn: Any) -> None:
+    with pytest.raises(
+        pl.InvalidOperationError, match="expected a temporal datatype, got i32"
+    ):
+        pl.select(fn(pl.lit(1), pl.lit(2), "1d"))
+
+
+def test_datetime_ranges_nanosecond_interval_from_dates() -> None:
+    lf = pl.LazyFrame({"start": [date(2022, 1, 1)], "end": [date(2022, 1, 1)]})
+    q = lf.select(pl.datetime_ranges("start", "end", "1ns"))
+    assert q.schema == {"start": pl.List(pl.Datetime("ns"))}
+    assert q.collect().schema == q.schema
+
+
 def test_datetime_range_lazy_time_zones_warning() -> None:
     start = datetime(2020, 1, 1, tzinfo=ZoneInfo("Asia/Kathmandu"))
     stop = datetime(2020, 1, 2, tzinfo=ZoneInfo("Asia/Kathmandu"))
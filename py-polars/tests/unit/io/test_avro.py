@@ -89,3 +89,27 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+def test_write_logical_and_nested_types() -> None:
+    df = pl.DataFrame(
+        {
+            "u8": pl.Series([1, 2], dtype=pl.UInt8),
+            "dt": pl.Series([1_500, 2_000_000], dtype=pl.Datetime("ns")),
+            "cat": pl.Series(["a", "b"], dtype=pl.Categorical),
+            "list": [[1, 2], [3]],
+            "struct": [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}],
+        }
+    )
+    df = pl.concat([df, df], rechunk=False)
+
+    f = io.BytesIO()
+    df.write_avro(f)
+    f.seek(0)
+
+    expected = df.with_columns(
+        pl.col("u8").cast(pl.Int32),
+        pl.col("dt").dt.cast_time_unit("us"),
+        pl.col("cat").cast(pl.String),
+    )
+    assert_frame_equal(pl.read_avro(f), expected)
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import bz2
 import gzip
 import io
 import sys
@@ -508,13 +509,20 @@ def test_compressed_csv(io_files_path: Path) -> None:
 
     # zstd compressed file
     csv_file = io_files_path / "zstd_compressed.csv.zst"
-    with pytest.raises(
-        ComputeError,
-        match="cannot scan compressed csv; use `read_csv` for compressed data",
-    ):
-        pl.scan_csv(csv_file)
     out = pl.read_csv(str(csv_file), truncate_ragged_lines=True)
     assert_frame_equal(out, expected)
+    out = pl.scan_csv(csv_file, truncate_ragged_lines=True).collect()
+    assert_frame_equal(out, expected)
+    for low_memory in [False, True]:
+        out = pl.scan_csv(
+            csv_file, truncate_ragged_lines=True, low_memory=low_memory
+        ).collect(streaming=True)
+        assert_frame_equal(out, expected)
+
+    # bzip2 compression
+    csv_bytes = bz2.compress(csv.encode())
+    out = pl.read_csv(csv_bytes)
+    assert_frame_equal(out, expected)
 
     # no compression
     f2 = io.BytesIO(b"a,b\n1,2\n")
@@ -1744,3 +1752,103 @@ def test_invalid_csv_raise() -> None:
     "SK0127960V000","SK BT 0018977","
     """.strip()
         )
+
+
+def test_csv_null_values_list_per_column() -> None:
+    csv = textwrap.dedent(
+        """\
+        a,b
+        NA,1
+        -,x
+        3,-
+        """
+    )
+    f = io.StringIO(csv)
+    df = pl.read_csv(f, null_values={"a": ["NA", "-"], "b": "x"})
+    expected = pl.DataFrame({"a": [None, None, 3], "b": ["1", None, "-"]})
+    assert_frame_equal(df, expected)
+
+
+def test_csv_decimal_comma(tmp_path: Path) -> None:
+    csv = textwrap.dedent(
+        """\
+        a;b;c
+        1,5;x;2
+        -0,25;y;3
+        ;z;4
+        """
+    )
+    expected = pl.DataFrame(
+        {"a": [1.5, -0.25, None], "b": ["x", "y", "z"], "c": [2, 3, 4]}
+    )
+    df = pl.read_csv(io.StringIO(csv), separator=";", decimal_comma=True)
+    assert_frame_equal(df, expected)
+
+    df = pl.read_csv(
+        io.StringIO(csv),
+        separator=";",
+        decimal_comma=True,
+        dtypes={"c": pl.Float32},
+    )
+    assert df["c"].to_list() == [2.0, 3.0, 4.0]
+
+    # Without the option, the column can't be parsed as floats.
+    df = pl.read_csv(io.StringIO(csv), separator=";")
+    assert df["a"].dtype == pl.String
+
+    tmp_path.mkdir(exist_ok=True)
+    file_path = tmp_path / "decimal_comma.csv"
+    file_path.write_text(csv)
+    result = pl.scan_csv(file_path, separator=";", decimal_comma=True).collect()
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.read_csv(io.StringIO(csv), decimal_comma=True)
+
+
+@pytest.mark.write_disk()
+def test_csv_dtypes_regex(tmp_path: Path) -> None:
+    csv = textwrap.dedent(
+        """\
+        id,sensor_1,sensor_2,sensor_3
+        1,10,20,30
+        2,11,21,31
+        """
+    )
+    dtypes = {"^sensor_.*$": pl.Float32, "sensor_3": pl.Int16}
+    expected_schema = {
+        "id": pl.Int64,
+        "sensor_1": pl.Float32,
+        "sensor_2": pl.Float32,
+        "sensor_3": pl.Int16,
+    }
+    df = pl.read_csv(io.StringIO(csv), dtypes=dtypes)
+    assert df.schema == expected_schema
+    assert df["sensor_3"].to_list() == [30, 31]
+
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "sensors.csv"
+    path.write_text(csv)
+    assert pl.scan_csv(path, dtypes=dtypes).collect().schema == expected_schema
+
+    with pytest.raises(pl.ComputeError, match="invalid regex"):
+        pl.read_csv(io.StringIO(csv), dtypes={"^sensor_[$": pl.Float32})
+
+
+@pytest.mark.write_disk()
+def test_scan_csv_quoted_multiline_fields(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "multiline.csv"
+    rows = [f'{i},"{i},a,b\n{i},c,d\n{i},""e"",f",{i}\n' for i in range(2000)]
+    path.write_text("id,text,value\n" + "".join(rows))
+
+    expected = pl.DataFrame(
+        {
+            "id": range(2000),
+            "text": [f'{i},a,b\n{i},c,d\n{i},"e",f' for i in range(2000)],
+            "value": range(2000),
+        }
+    )
+    for streaming in (False, True):
+        result = pl.scan_csv(path).collect(streaming=streaming)
+        assert_frame_equal(result, expected, check_dtype=False)
@@ -401,6 +401,47 @@ def test_read_database_parameterised(tmp_path: Path) -> None:
             )
 
 
+def test_scan_database(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    create_temp_sqlite_db(test_db := str(tmp_path / "test.db"))
+
+    conn = sqlite3.connect(test_db)
+    queries: list[str] = []
+    conn.set_trace_callback(queries.append)
+
+    # sqlite3 returns rows without dtypes, so the schema of an empty result is unknown
+    lf = pl.scan_database(
+        "test_data",
+        conn,
+        schema_overrides={
+            "id": pl.Int64,
+            "name": pl.String,
+            "value": pl.Float64,
+            "date": pl.String,
+        },
+    )
+    assert lf.columns == ["id", "name", "value", "date"]
+
+    # the length filter can't be translated and is applied after the query
+    df = (
+        lf.filter((pl.col("value") > 0) & (pl.col("name").str.len_chars() > 0))
+        .select("name")
+        .collect()
+    )
+    assert_frame_equal(df, pl.DataFrame({"name": ["misc"]}))
+    query = queries[-1]
+    assert query.startswith("SELECT ")
+    assert 'WHERE ("value" > 0' in query
+    assert '"date"' not in query
+
+    df = lf.filter(pl.col("name").is_in(["other"]) | pl.col("value").is_null()).collect()
+    assert df["id"].to_list() == [2]
+    assert """WHERE (("name" IN ('other')) OR ("value" IS NULL))""" in queries[-1]
+
+    assert lf.head(1).collect().height == 1
+    assert queries[-1].endswith("LIMIT 1")
+
+
 @pytest.mark.parametrize(
     ("driver", "batch_size", "iter_batches", "expected_call"),
     [
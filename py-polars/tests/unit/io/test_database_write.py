@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import sqlite3
 import sys
 from typing import TYPE_CHECKING
 
@@ -182,3 +183,42 @@ class TestWriteDatabase:
                 if_table_exists="do_something",  # type: ignore[arg-type]
                 engine=engine,
             )
+
+
+def test_write_database_dbapi_connection() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "name": ["misc", "other", None, "it's", "x"],
+            "value": [1000.0, -9999.0, 0.5, None, 2.25],
+        }
+    )
+    conn = sqlite3.connect(":memory:")
+
+    assert df.write_database("test_data", conn, batch_size=2) == 5
+    column_types = conn.execute("PRAGMA table_info(test_data)").fetchall()
+    assert [(col[1], col[2]) for col in column_types] == [
+        ("id", "BIGINT"),
+        ("name", "TEXT"),
+        ("value", "DOUBLE PRECISION"),
+    ]
+    assert_frame_equal(pl.read_database("SELECT * FROM test_data", conn), df)
+
+    with pytest.raises(sqlite3.OperationalError, match="already exists"):
+        df.write_database("test_data", conn)
+
+    assert df[:2].write_database("test_data", conn, if_table_exists="append") == 2
+    assert_frame_equal(
+        pl.read_database("SELECT * FROM test_data", conn), pl.concat([df, df[:2]])
+    )
+
+    assert df[3:].write_database("test_data", conn, if_table_exists="replace") == 2
+    assert_frame_equal(pl.read_database("SELECT * FROM test_data", conn), df[3:])
+
+    # the connection is still usable
+    assert conn.execute("SELECT COUNT(*) FROM test_data").fetchone() == (2,)
+
+    with pytest.raises(TypeError, match="cannot write columns of dtype"):
+        pl.DataFrame({"x": [[1, 2]]}).write_database("test_list", conn)
+    with pytest.raises(ValueError, match="`batch_size` must be positive"):
+        df.write_database("test_batch", conn, batch_size=0)
@@ -164,3 +164,36 @@ def test_hive_partitioned_err(io_files_path: Path, tmp_path: Path) -> None:
 
     with pytest.raises(pl.ComputeError, match="invalid hive partitions"):
         pl.scan_parquet(root / "**/*.parquet", hive_partitioning=True)
+
+
+@pytest.mark.write_disk()
+def test_hive_partitioned_ipc(tmp_path: Path, monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    df = pl.DataFrame({"a": [1, 1, 2, 2, 3, 3], "b": ["x", "y", "x", "y", "x", "y"]})
+    for (a,), part in df.partition_by("a", as_dict=True).items():
+        # two files per partition directory
+        directory = tmp_path / f"a={a}"
+        directory.mkdir()
+        part.head(1).drop("a").write_ipc(directory / "0.ipc")
+        part.tail(1).drop("a").write_ipc(directory / "1.ipc")
+
+    q = pl.scan_ipc(tmp_path / "**/*.ipc", hive_partitioning=True)
+    assert q.columns == ["b", "a"]
+    assert_frame_equal(q.collect().sort("a", "b"), df.select("b", "a"))
+
+    capfd.readouterr()
+    result = q.filter(pl.col("a") >= 2).collect()
+    assert "hive partitioning: skipped 2 files" in capfd.readouterr().err
+    assert_frame_equal(
+        result.sort("a", "b"), df.filter(pl.col("a") >= 2).select("b", "a")
+    )
+
+    # all partitions are pruned
+    assert q.filter(pl.col("a") > 3).collect().shape == (0, 2)
+    # only hive partition columns are projected
+    assert_frame_equal(q.select("a").collect().sort("a"), df.select("a"))
+    # the row index continues over the files
+    assert q.with_row_index().collect()["index"].to_list() == list(range(6))
+
+    q = pl.scan_ipc(tmp_path / "**/*.ipc", hive_partitioning=False)
+    assert q.columns == ["b"]
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import gzip
 import io
 from typing import TYPE_CHECKING, Any
 
@@ -208,3 +209,15 @@ def test_from_float16() -> None:
     pandas_df.to_feather(f)
     f.seek(0)
     assert pl.read_ipc(f, use_pyarrow=False).dtypes == [pl.Float32]
+
+
+@pytest.mark.write_disk()
+def test_read_ipc_gzip_compressed_file(df: pl.DataFrame, tmp_path: Path) -> None:
+    buf = io.BytesIO()
+    df.write_ipc(buf)
+    file_path = tmp_path / "small.ipc.gz"
+    file_path.write_bytes(gzip.compress(buf.getvalue()))
+
+    assert_frame_equal(pl.read_ipc(file_path), df, categorical_as_str=True)
+    out = pl.scan_ipc(file_path).select("bools").collect()
+    assert_frame_equal(out, df.select("bools"))
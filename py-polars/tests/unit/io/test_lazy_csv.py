@@ -285,3 +285,16 @@ def test_scan_empty_csv_with_row_index(tmp_path: Path) -> None:
 
     read = pl.scan_csv(file_path).with_row_index("idx")
     assert read.collect().schema == OrderedDict([("idx", pl.UInt32), ("a", pl.String)])
+
+
+@pytest.mark.write_disk()
+def test_scan_csv_include_file_paths(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    pl.DataFrame({"a": [1, 2]}).write_csv(tmp_path / "0.csv")
+    pl.DataFrame({"a": [3]}).write_csv(tmp_path / "1.csv")
+
+    lf = pl.scan_csv(tmp_path / "*.csv", include_file_paths="path")
+    out = lf.filter(pl.col("path").str.ends_with("1.csv")).collect()
+    assert out.columns == ["a", "path"]
+    assert out["a"].to_list() == [3]
+    assert out["path"].to_list() == [str(tmp_path / "1.csv")]
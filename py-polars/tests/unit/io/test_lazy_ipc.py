@@ -85,3 +85,40 @@ def test_ipc_list_arg(io_files_path: Path) -> None:
     assert df.shape == (54, 4)
     assert df.row(-1) == ("seafood", 194, 12.0, 1)
     assert df.row(0) == ("vegetables", 45, 0.5, 2)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("memory_map", [True, False])
+def test_scan_ipc_struct_field_projection(tmp_path: Path, memory_map: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3],
+            "s": [
+                {"x": 1, "y": "a", "z": True},
+                {"x": 2, "y": "b", "z": None},
+                None,
+            ],
+        }
+    )
+    file_path = tmp_path / "structs.ipc"
+    df.write_ipc(file_path)
+
+    lf = pl.scan_ipc(file_path, memory_map=memory_map)
+    out = lf.select(pl.col("s").struct.field("x"), pl.col("s").struct.field("z"))
+    assert out.collect().to_dict(as_series=False) == {
+        "x": [1, 2, None],
+        "z": [True, None, None],
+    }
+
+    # filters on a field are evaluated on the pruned struct
+    out = lf.filter(pl.col("s").struct.field("x") > 1).select(
+        pl.col("a"), pl.col("s").struct.field("y")
+    )
+    assert out.collect().to_dict(as_series=False) == {"a": [2], "y": ["b"]}
+
+    # the whole struct is read if it is used as a column
+    out = lf.select(pl.col("s").struct.field("x"), pl.col("s"))
+    assert out.collect().to_dict(as_series=False) == {
+        "x": [1, 2, None],
+        "s": df["s"].to_list(),
+    }
@@ -1,8 +1,11 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+import bz2
+import gzip
+from typing import TYPE_CHECKING, Callable
 
 import pytest
+import zstandard
 
 import polars as pl
 from polars.testing import assert_frame_equal
@@ -156,3 +159,47 @@ def test_sink_ndjson_should_write_same_data(
     df = pl.read_ndjson(target_path)
     # Assert
     assert_frame_equal(df, expected)
+
+
+@pytest.mark.write_disk()
+def test_scan_ndjson_schema_evolution(tmp_path: Path) -> None:
+    file_path = tmp_path / "evolving.ndjson"
+    file_path.write_text(
+        '{"a": 1, "b": "x"}\n'
+        '{"a": 2, "b": "y", "extra": {"nested": [1, 2]}}\n'
+        '{"b": "z"}\n'
+        '{"a": "not an int", "b": "w", "c": 1.5}\n'
+    )
+
+    lf = pl.scan_ndjson(
+        file_path,
+        infer_schema_length=2,
+        schema_overrides={"a": pl.Int64, "c": pl.Float64},
+        ignore_errors=True,
+    )
+    assert lf.columns == ["a", "b", "extra", "c"]
+
+    out = lf.select("c", "a").collect()
+    expected = pl.DataFrame(
+        {"c": [None, None, None, 1.5], "a": [1, 2, None, None]},
+        schema={"c": pl.Float64, "a": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+    out = lf.filter(pl.col("a") > 1).select("b").collect()
+    assert out.to_dict(as_series=False) == {"b": ["y"]}
+
+
+@pytest.mark.parametrize("compress", [gzip.compress, bz2.compress, zstandard.compress])
+@pytest.mark.write_disk()
+def test_scan_ndjson_compressed(
+    compress: Callable[[bytes], bytes], tmp_path: Path
+) -> None:
+    file_path = tmp_path / "compressed.ndjson"
+    file_path.write_bytes(compress(b'{"a": 1, "b": "x"}\n{"a": 2, "b": "y"}\n'))
+
+    expected = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    assert_frame_equal(pl.read_ndjson(file_path), expected)
+    assert_frame_equal(pl.scan_ndjson(file_path).collect(), expected)
+    out = pl.scan_ndjson(file_path).filter(pl.col("a") > 1).select("b").collect()
+    assert out.to_dict(as_series=False) == {"b": ["y"]}
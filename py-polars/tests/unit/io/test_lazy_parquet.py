@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from collections import OrderedDict
+from datetime import date, datetime
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
@@ -241,6 +242,47 @@ def test_parquet_eq_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) ->
         )
 
 
+@pytest.mark.write_disk()
+def test_parquet_bloom_filter(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # the values of the row groups interleave, so min/max statistics can't skip any
+    df = pl.concat(
+        [
+            pl.DataFrame({"id": [4 * i + rg for i in range(25)]}).with_columns(
+                key=pl.format("key_{}", "id")
+            )
+            for rg in range(4)
+        ],
+        rechunk=False,
+    )
+    assert df.n_chunks("all") == [4, 4]
+
+    file_path = tmp_path / "bloom.parquet"
+    df.write_parquet(file_path, statistics=True, bloom_filter_columns=["id", "key"])
+
+    for pred in [
+        pl.col("id") == 5,
+        pl.col("key") == "key_5",
+        pl.col("key") == "key_1000",
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+        captured = capfd.readouterr().err
+        assert (
+            "parquet file can be skipped, the statistics were sufficient"
+            " to apply the predicate." in captured
+        )
+
+    with pytest.raises(pl.InvalidOperationError):
+        df.with_columns(flag=pl.lit(True)).write_parquet(
+            file_path, bloom_filter_columns=["flag"]
+        )
+
+
 @pytest.mark.write_disk()
 def test_parquet_is_in_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -313,6 +355,51 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     )
 
 
+@pytest.mark.write_disk()
+def test_parquet_statistics_non_numeric(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    df = pl.DataFrame({"idx": pl.arange(0, 100, eager=True)}).with_columns(
+        part=pl.col("idx") // 25,
+        date=pl.date(2024, 1, 1) + pl.duration(days=pl.col("idx")),
+        datetime=pl.datetime(2024, 1, 1) + pl.duration(hours=pl.col("idx")),
+        string=pl.col("idx").cast(pl.String).str.zfill(3),
+        flag=pl.col("idx") >= 50,
+        nullable=pl.when(pl.col("idx") >= 50).then(pl.col("idx")),
+    )
+    df = pl.concat(df.partition_by("part", as_dict=False), rechunk=False)
+
+    file_path = tmp_path / "stats.parquet"
+    df.write_parquet(file_path, statistics=True, use_pyarrow=False)
+
+    for pred in [
+        pl.col("date") > date(2024, 3, 1),
+        pl.col("datetime") <= datetime(2024, 1, 1, 12),
+        pl.col("string") < "020",
+        pl.col("string") == "099",
+        pl.col("flag") == False,  # noqa: E712
+        ~pl.col("flag"),
+        pl.col("nullable").is_null(),
+        pl.col("nullable").is_not_null(),
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+        captured = capfd.readouterr().err
+        assert (
+            "parquet file can be skipped, the statistics were sufficient"
+            " to apply the predicate." in captured
+        )
+
+    # a boolean column as predicate
+    result = pl.scan_parquet(file_path).filter(pl.col("flag")).collect()
+    assert_frame_equal(result, df.filter(pl.col("flag")))
+
+
 @pytest.mark.write_disk()
 def test_streaming_categorical(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -447,3 +534,36 @@ def test_nested_slice_12480(tmp_path: Path) -> None:
     df.write_parquet(path, use_pyarrow=True, pyarrow_options={"data_page_size": 1})
 
     assert pl.scan_parquet(path).slice(0, 1).collect().height == 1
+
+
+@pytest.mark.write_disk()
+def test_parquet_schema_drift(tmp_path: Path) -> None:
+    f1 = tmp_path / "a.parquet"
+    f2 = tmp_path / "b.parquet"
+    pl.DataFrame(
+        {"a": [1, 2], "b": ["x", "y"]}, schema={"a": pl.Int32, "b": pl.String}
+    ).write_parquet(f1)
+    pl.DataFrame({"a": [3, 4]}, schema={"a": pl.Int64}).write_parquet(f2)
+
+    with pytest.raises(pl.SchemaError):
+        pl.scan_parquet([f1, f2])
+
+    lf = pl.scan_parquet([f1, f2], missing_columns="insert_null")
+    expected = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["x", "y", None, None]})
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.filter(pl.col("a") > 1).select("b").collect(),
+        pl.DataFrame({"b": ["y", None, None]}),
+    )
+
+
+@pytest.mark.write_disk()
+def test_scan_parquet_include_file_paths(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    pl.DataFrame({"a": [1, 2]}).write_parquet(tmp_path / "0.parquet")
+    pl.DataFrame({"a": [3]}).write_parquet(tmp_path / "1.parquet")
+
+    lf = pl.scan_parquet(tmp_path / "*.parquet", include_file_paths="path")
+    assert lf.columns == ["a", "path"]
+    out = lf.filter(pl.col("path").str.ends_with("0.parquet")).select("path")
+    assert out.collect()["path"].to_list() == [str(tmp_path / "0.parquet")] * 2
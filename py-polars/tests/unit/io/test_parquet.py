@@ -70,6 +70,108 @@ def test_write_parquet_using_pyarrow_write_to_dataset_with_partitioning(
     assert_frame_equal(df, read_df)
 
 
+@pytest.mark.write_disk()
+def test_write_parquet_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4, 5],
+            "part": ["x/y", "x/y", "x/y", "z", None],
+        }
+    )
+    path = tmp_path / "dataset"
+    df.write_parquet_partitioned(
+        path,
+        "part",
+        max_rows_per_file=2,
+        filename_template="part-{i}-{uuid}.{ext}",
+    )
+
+    files = sorted(p.relative_to(path).as_posix() for p in path.rglob("*.parquet"))
+    assert [f.split("/")[0] for f in files] == [
+        "part=__HIVE_DEFAULT_PARTITION__",
+        "part=x%2Fy",
+        "part=x%2Fy",
+        "part=z",
+    ]
+    # all files of a write share the uuid
+    assert len({f.split("/")[1][len("part-0000-") :] for f in files}) == 1
+
+    read_df = pl.read_parquet(path / "part=x%2Fy/*.parquet", hive_partitioning=True)
+    assert_frame_equal(read_df.sort("a"), df.head(3), check_column_order=False)
+
+    # pyarrow reads the layout as a hive partitioned dataset
+    tbl = ds.dataset(path, format="parquet", partitioning="hive").to_table()
+    assert_frame_equal(
+        pl.from_arrow(tbl).sort("a"),  # type: ignore[union-attr]
+        df,
+        check_column_order=False,
+        check_dtype=False,
+    )
+
+    with pytest.raises(pl.InvalidOperationError, match="must contain"):
+        df.write_parquet_partitioned(path, "part", filename_template="{uuid}.parquet")
+
+
+def test_write_parquet_field_ids() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2],
+            "s": [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}],
+            "l": [[1, 2], [3]],
+        }
+    )
+    field_ids = {"a": 1, "s": 2, "s.x": 3, "s.y": 4, "l": 5, "l.item": 6}
+    f = io.BytesIO()
+    df.write_parquet(f, field_ids=field_ids)
+
+    f.seek(0)
+    assert pl.read_parquet_field_ids(f) == field_ids
+    f.seek(0)
+    read_df = pl.read_parquet(f)
+    assert_frame_equal(read_df, df)
+
+    # the field ids are preserved when the file is rewritten
+    f.seek(0)
+    out = io.BytesIO()
+    read_df.write_parquet(out, field_ids=pl.read_parquet_field_ids(f))
+    out.seek(0)
+    assert pl.read_parquet_field_ids(out) == field_ids
+
+    # pyarrow reads the field ids from the parquet schema
+    f.seek(0)
+    schema = pq.read_schema(f)
+    assert schema.field("a").metadata[b"PARQUET:field_id"] == b"1"
+    assert schema.field("s").type.field("y").metadata[b"PARQUET:field_id"] == b"4"
+
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.write_parquet(io.BytesIO(), field_ids={"s.z": 7})
+    with pytest.raises(ValueError, match="field_ids"):
+        df.write_parquet(io.BytesIO(), field_ids={"a": 1}, use_pyarrow=True)
+
+
+def test_read_parquet_field_ids_written_by_pyarrow() -> None:
+    schema = pa.schema(
+        [
+            pa.field("a", pa.int64(), metadata={"PARQUET:field_id": "10"}),
+            pa.field(
+                "l",
+                pa.list_(
+                    pa.field(
+                        "element", pa.string(), metadata={"PARQUET:field_id": "12"}
+                    )
+                ),
+                metadata={"PARQUET:field_id": "11"},
+            ),
+        ]
+    )
+    tbl = pa.table({"a": [1], "l": [["x"]]}, schema=schema)
+    f = io.BytesIO()
+    pq.write_table(tbl, f)
+
+    f.seek(0)
+    assert pl.read_parquet_field_ids(f) == {"a": 10, "l": 11, "l.element": 12}
+
+
 @pytest.fixture()
 def small_parquet_path(io_files_path: Path) -> Path:
     return io_files_path / "small.parquet"
@@ -679,3 +781,49 @@ def test_read_parquet_binary_bytes() -> None:
 
     out = pl.read_parquet(bytes)
     assert_frame_equal(out, df)
+
+
+def test_parquet_column_options_and_row_group_size_bytes() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 1, 0, 99), "1m", eager=True
+            ),
+            "x": pl.Series(range(100), dtype=pl.Float64),
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        statistics=True,
+        # 25 rows of two 8 byte columns
+        row_group_size_bytes=400,
+        column_options={
+            "ts": {
+                "encoding": "delta_binary_packed",
+                "compression": "snappy",
+                "statistics": False,
+            }
+        },
+    )
+    f.seek(0)
+    metadata = pq.ParquetFile(f).metadata
+    assert metadata.num_row_groups == 4
+    for i in range(metadata.num_row_groups):
+        ts, x = metadata.row_group(i).column(0), metadata.row_group(i).column(1)
+        assert "DELTA_BINARY_PACKED" in ts.encodings
+        assert ts.compression == "SNAPPY"
+        assert not ts.is_stats_set
+        assert x.compression == "ZSTD"
+        assert x.is_stats_set
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    with pytest.raises(pl.InvalidOperationError, match="encoding"):
+        df.write_parquet(
+            io.BytesIO(), column_options={"x": {"encoding": "delta_binary_packed"}}
+        )
+    with pytest.raises(ValueError, match="column options"):
+        df.write_parquet(io.BytesIO(), column_options={"x": {"level": 1}})
+    with pytest.raises(ValueError, match="not supported when `use_pyarrow=True`"):
+        df.write_parquet(io.BytesIO(), use_pyarrow=True, row_group_size_bytes=400)
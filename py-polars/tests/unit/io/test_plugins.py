@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+from typing import Any
+
+import polars as pl
+from polars.io.plugins import register_io_source
+from polars.testing import assert_frame_equal
+
+
+def test_io_plugin_predicate_pushdown() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["w", "x", "y", "z"]})
+    calls: list[Any] = []
+
+    def source(
+        with_columns: list[str] | None, predicate: pl.Expr | None, n_rows: int | None
+    ) -> pl.DataFrame:
+        calls.append((with_columns, predicate))
+        out = df
+        if with_columns is not None:
+            out = out.select(with_columns)
+        # only prune partially, polars applies the predicate again
+        return out.head(3) if predicate is not None else out
+
+    lf = register_io_source(source, schema=df.schema)
+    out = lf.filter(pl.col("a") > 1).select("b").collect()
+
+    assert_frame_equal(out, pl.DataFrame({"b": ["x", "y"]}))
+    with_columns, predicate = calls[0]
+    assert set(with_columns) == {"a", "b"}
+    assert isinstance(predicate, pl.Expr)
+    assert_frame_equal(df.filter(predicate), df.filter(pl.col("a") > 1))
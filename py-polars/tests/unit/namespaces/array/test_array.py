@@ -269,3 +269,18 @@ def test_array_contains_literal(
     out = df.select(contains=pl.col("array").arr.contains(data)).to_series()
     expected_series = pl.Series("contains", expected)
     assert_series_equal(out, expected_series)
+
+
+def test_arr_mean() -> None:
+    s = pl.Series("a", [[1, 2], [4, 3], None], dtype=pl.Array(pl.Int64, 2))
+    assert s.arr.mean().to_list() == [1.5, 3.5, None]
+
+    s = pl.Series("a", [[1.0, None], [4.0, 3.0]], dtype=pl.Array(pl.Float32, 2))
+    out = s.arr.mean()
+    assert out.dtype == pl.Float32
+    assert out.to_list() == [1.0, 3.5]
+
+    df = pl.DataFrame({"a": pl.Series([[1, 2], [4, 4]], dtype=pl.Array(pl.Int8, 2))})
+    out = df.lazy().select(pl.col("a").arr.mean())
+    assert out.schema == {"a": pl.Float64}
+    assert out.collect().to_series().to_list() == [1.5, 4.0]
@@ -584,6 +584,17 @@ def test_json_decode_series() -> None:
     assert_series_equal(s.str.json_decode(dtype), expected)
 
 
+def test_json_decode_keeps_name() -> None:
+    s = pl.Series("json", ['{"a": [1, 2], "b": {"c": "x"}}', None])
+    dtype = pl.Struct(
+        [pl.Field("a", pl.List(pl.Int8)), pl.Field("b", pl.Struct({"c": pl.String}))]
+    )
+    out = s.str.json_decode(dtype)
+    assert out.name == "json"
+    assert out.dtype == dtype
+    assert out.to_list() == [{"a": [1, 2], "b": {"c": "x"}}, None]
+
+
 def test_json_decode_lazy_expr() -> None:
     dtype = pl.Struct([pl.Field("a", pl.Int64), pl.Field("b", pl.Boolean)])
     ldf = (
@@ -1032,6 +1043,11 @@ def test_extract_groups() -> None:
         as_series=False
     ) == {"iso_code": [{"iso_code": None}, {"iso_code": None}]}
 
+    # the schema matches the output, also without capture groups
+    for pat in ("", pattern, r"(\w+) (?<year>\d+)"):
+        q = df.lazy().select(pl.col("iso_code").str.extract_groups(pat))
+        assert q.schema == q.collect().schema
+
     assert df.select(
         pl.col("iso_code").str.extract_groups(r"\A(ISO\S*).*?(\d+)")
     ).to_dict(as_series=False) == {
@@ -1357,3 +1373,35 @@ def test_replace_lit_n_char_13385(
     res = s.str.replace("a", "b", literal=True)
     expected_s = pl.Series(expected_dat, dtype=pl.String)
     assert_series_equal(res, expected_s)
+
+
+def test_string_distances() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "über", "", None, "MARTHA"],
+            "b": ["sitting", "lawn", "uber", "abc", "x", "MARHTA"],
+        }
+    )
+    out = df.select(
+        lev=pl.col("a").str.levenshtein(pl.col("b")),
+        ham=pl.col("a").str.hamming(pl.col("b")),
+        jw=pl.col("a").str.jaro_winkler(pl.col("b")),
+        lev_lit=pl.col("a").str.levenshtein("flaw"),
+    )
+    assert out.schema == {
+        "lev": pl.UInt32,
+        "ham": pl.UInt32,
+        "jw": pl.Float64,
+        "lev_lit": pl.UInt32,
+    }
+    assert out["lev"].to_list() == [3, 2, 1, 3, None, 2]
+    assert out["ham"].to_list() == [None, None, 1, None, None, 2]
+    assert out["lev_lit"].to_list() == [6, 0, 4, 4, None, 6]
+    jw = out["jw"].to_list()
+    assert jw[4] is None
+    assert jw[:4] + jw[5:] == pytest.approx(
+        [0.746032, 0.833333, 0.833333, 0.0, 0.961111], abs=1e-6
+    )
+
+    with pytest.raises(pl.ShapeError):
+        pl.Series(["a", "b"]).str.levenshtein(pl.Series(["a", "b", "c"]))
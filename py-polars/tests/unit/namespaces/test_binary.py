@@ -1,8 +1,11 @@
+import gzip
+import uuid
+
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
-from polars.type_aliases import TransferEncoding
+from polars.testing import assert_frame_equal, assert_series_equal
+from polars.type_aliases import BinaryCompression, TransferEncoding
 
 
 def test_binary_conversions() -> None:
@@ -150,3 +153,73 @@ def test_compare_decode_between_lazy_and_eager_6814(encoding: TransferEncoding)
     dtype = result_eager["x"].dtype
     result_lazy = df.lazy().select(expr).select(pl.col(dtype)).collect()
     assert_frame_equal(result_eager, result_lazy)
+
+
+@pytest.mark.parametrize("compression", ["gzip", "zstd"])
+def test_compress_roundtrip(compression: BinaryCompression) -> None:
+    s = pl.Series("x", [b"polars" * 100, None, b""])
+    compressed = s.bin.compress(compression)
+    assert len(compressed[0]) < len(s[0])
+    assert_series_equal(compressed.bin.decompress(compression), s)
+    assert_series_equal(s.bin.compress(compression, level=1).bin.decompress(compression), s)
+
+
+def test_decompress_gzip_from_python() -> None:
+    s = pl.Series("x", [gzip.compress(b"foo"), gzip.compress(b"bar")])
+    assert s.bin.decompress("gzip").to_list() == [b"foo", b"bar"]
+
+
+def test_decompress_invalid() -> None:
+    s = pl.Series("x", [b"not compressed", gzip.compress(b"foo")])
+    with pytest.raises(pl.ComputeError, match="strict=false"):
+        s.bin.decompress("gzip")
+    assert s.bin.decompress("gzip", strict=False).to_list() == [None, b"foo"]
+
+    with pytest.raises(ValueError, match="`compression` must be one of"):
+        s.bin.compress("lz4")  # type: ignore[arg-type]
+    with pytest.raises(pl.ComputeError, match="compression level"):
+        s.bin.compress("gzip", level=10)
+
+
+def test_uuid_roundtrip() -> None:
+    u = uuid.uuid4()
+    s = pl.Series("x", [str(u), u.hex.upper(), f"{{{u}}}", "not a uuid", None])
+    assert s.str.is_uuid().to_list() == [True, True, True, False, None]
+
+    with pytest.raises(pl.ComputeError, match="invalid UUID"):
+        s.str.to_uuid()
+    parsed = s.str.to_uuid(strict=False)
+    assert parsed.to_list() == [u.bytes, u.bytes, u.bytes, None, None]
+    assert parsed.bin.format_uuid().to_list() == [str(u)] * 3 + [None, None]
+
+    with pytest.raises(pl.ComputeError, match="16 bytes"):
+        pl.Series([b"abc"]).bin.format_uuid()
+
+
+def test_uuid4() -> None:
+    df = pl.DataFrame({"a": range(100)})
+    out = df.select(pl.col("a").uuid4(seed=0))
+    assert out.schema == {"a": pl.String}
+    assert out["a"].n_unique() == 100
+    assert all(uuid.UUID(v).version == 4 for v in out["a"])
+    assert_frame_equal(out, df.select(pl.col("a").uuid4(seed=0)))
+
+
+def test_binary_size() -> None:
+    s = pl.Series("data", [b"\x00\x01\x02", b"", None, b"x" * 2048])
+    assert_series_equal(
+        s.bin.size(), pl.Series("data", [3, 0, None, 2048], dtype=pl.UInt32)
+    )
+    assert_series_equal(
+        s.bin.size("kb"), pl.Series("data", [3 / 1024, 0.0, None, 2.0])
+    )
+
+    df = s.to_frame()
+    out = df.select(pl.col("data").bin.size())
+    assert out.schema == {"data": pl.UInt32}
+    assert df.lazy().select(pl.col("data").bin.size("mb")).schema == {
+        "data": pl.Float64
+    }
+
+    with pytest.raises(ValueError, match="`unit` must be one of"):
+        s.bin.size("bits")  # type: ignore[arg-type]
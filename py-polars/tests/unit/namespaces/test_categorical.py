@@ -142,3 +142,16 @@ def test_cat_uses_lexical_ordering() -> None:
 
     s = s.cast(pl.Categorical("physical"))
     assert s.cat.uses_lexical_ordering() is False
+
+
+def test_cat_to_local_identity_mapping() -> None:
+    # The first column under a fresh cache has global ids equal to its local ids.
+    with pl.StringCache():
+        s = pl.Series(["b", "a", None, "b"], dtype=pl.Categorical("lexical"))
+
+    out = s.cat.to_local()
+    assert out.cat.is_local()
+    assert out.cat.uses_lexical_ordering()
+    assert out.to_physical().to_list() == s.to_physical().to_list()
+    assert out.to_list() == ["b", "a", None, "b"]
+    assert out.sort().to_list() == [None, "a", "b", "b"]
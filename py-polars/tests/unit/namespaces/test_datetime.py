@@ -20,7 +20,7 @@ elif _ZONEINFO_AVAILABLE:
     from backports.zoneinfo._zoneinfo import ZoneInfo
 
 if TYPE_CHECKING:
-    from polars.type_aliases import TimeUnit
+    from polars.type_aliases import DurationFormat, TimeUnit
 
 
 @pytest.fixture()
@@ -43,6 +43,41 @@ def test_dt_to_string(series_of_int_dates: pl.Series) -> None:
     assert_series_equal(series_of_int_dates.dt.strftime("%F"), expected_str_dates)
 
 
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_dt_to_string_duration(time_unit: TimeUnit) -> None:
+    s = pl.Series(
+        "d",
+        [
+            timedelta(days=1, hours=2, minutes=3, seconds=4, milliseconds=5),
+            timedelta(seconds=-90),
+            timedelta(0),
+            None,
+        ],
+        dtype=pl.Duration(time_unit),
+    )
+    assert s.dt.to_string("iso").to_list() == [
+        "P1DT2H3M4.005S",
+        "-PT1M30S",
+        "PT0S",
+        None,
+    ]
+    assert s.dt.to_string("polars").to_list() == [
+        "1d 2h 3m 4s 5ms",
+        "-1m -30s",
+        f"0{time_unit.replace('us', 'µs')}",
+        None,
+    ]
+
+    # both formats parse back into the same values
+    formats: list[DurationFormat] = ["iso", "polars"]
+    for fmt in formats:
+        out = s.dt.to_string(fmt).str.to_duration(fmt, time_unit=time_unit)
+        assert_series_equal(out, s)
+
+    with pytest.raises(InvalidOperationError, match="invalid format"):
+        s.dt.to_string("%H:%M")
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
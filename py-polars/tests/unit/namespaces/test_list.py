@@ -426,6 +426,26 @@ def test_list_gather() -> None:
     ]
 
 
+def test_list_gather_nulls_and_logical() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [["x", "y", "z"], None, ["u", "v"], ["w"]],
+            "idx": [[2, None, 0], [0], None, [-1, 0]],
+        },
+        schema={"a": pl.List(pl.Categorical), "idx": pl.List(pl.Int32)},
+    )
+    out = df.select(pl.col("a").list.gather(pl.col("idx")))
+    assert out.schema == {"a": pl.List(pl.Categorical)}
+    assert out.to_series().to_list() == [["z", None, "x"], None, None, ["w", "w"]]
+
+    # a single index list is applied to every row
+    s = pl.Series("a", [[1, 2, 3], [4, 5]])
+    assert s.list.gather(pl.Series([[1, 0]])).to_list() == [[2, 1], [5, 4]]
+
+    with pytest.raises(pl.ShapeError):
+        s.list.gather(pl.Series([[0], [0], [0]]))
+
+
 def test_list_eval_all_null() -> None:
     df = pl.DataFrame({"foo": [1, 2, 3], "bar": [None, None, None]}).with_columns(
         pl.col("bar").cast(pl.List(pl.String))
@@ -436,6 +456,21 @@ def test_list_eval_all_null() -> None:
     ) == {"bar": [None, None, None]}
 
 
+@pytest.mark.parametrize("parallel", [True, False])
+def test_list_eval_with_nulls(parallel: bool) -> None:
+    df = pl.DataFrame({"a": [[3, 1, 2], None, [], [5, None, 4], None]})
+    out = df.select(
+        rank=pl.col("a").list.eval(pl.element().rank(), parallel=parallel),
+        first=pl.col("a").list.eval(pl.element().first(), parallel=parallel),
+        cum=pl.col("a").list.eval(pl.element().cum_sum(), parallel=parallel),
+    )
+    assert out.to_dict(as_series=False) == {
+        "rank": [[3.0, 1.0, 2.0], None, [], [2.0, None, 1.0], None],
+        "first": [[3], None, [None], [5], None],
+        "cum": [[3, 4, 6], None, [], [5, None, 9], None],
+    }
+
+
 def test_list_function_group_awareness() -> None:
     df = pl.DataFrame(
         {
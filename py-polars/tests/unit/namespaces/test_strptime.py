@@ -686,3 +686,41 @@ def test_to_datetime_out_of_range_13401(time_unit: TimeUnit) -> None:
         s.str.to_datetime("%Y-%B-%d %H:%M:%S", strict=False, time_unit=time_unit).item()
         is None
     )
+
+
+def test_to_duration() -> None:
+    s = pl.Series("d", ["1d 2h 3m", "-1m -30s", "2w", "1ms 5µs", None])
+    expected = pl.Series(
+        "d",
+        [
+            timedelta(days=1, hours=2, minutes=3),
+            timedelta(seconds=-90),
+            timedelta(weeks=2),
+            timedelta(microseconds=1005),
+            None,
+        ],
+        dtype=pl.Duration("us"),
+    )
+    assert_series_equal(s.str.to_duration(), expected)
+
+    iso = pl.Series("d", ["P1DT2H3M", "-PT1M30S", "P2W", "PT0.001005S", None])
+    assert_series_equal(iso.str.to_duration("iso"), expected)
+
+    lf = pl.LazyFrame({"d": ["PT1.5S"]})
+    q = lf.select(pl.col("d").str.to_duration("iso", time_unit="ms"))
+    assert q.schema == {"d": pl.Duration("ms")}
+    assert q.collect().item() == timedelta(seconds=1.5)
+
+
+def test_to_duration_invalid() -> None:
+    s = pl.Series(["1d", "1mo", "P1Y", "9999999999999999d"])
+    with pytest.raises(ComputeError, match="conversion .* failed"):
+        s.str.to_duration()
+    assert s.str.to_duration(strict=False).to_list() == [
+        timedelta(days=1),
+        None,
+        None,
+        None,
+    ]
+    with pytest.raises(pl.InvalidOperationError, match="invalid format"):
+        s.str.to_duration("%H")  # type: ignore[arg-type]
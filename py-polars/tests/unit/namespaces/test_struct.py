@@ -42,3 +42,47 @@ def test_struct_json_encode() -> None:
         "a": [{"a": [1, 2], "b": [45]}, {"a": [9, 1, 3], "b": None}],
         "encoded": ['{"a":[1,2],"b":[45]}', '{"a":[9,1,3],"b":null}'],
     }
+
+
+def test_struct_with_fields() -> None:
+    df = pl.DataFrame(
+        {
+            "coords": [{"x": 1, "y": 4}, {"x": 4, "y": 9}, {"x": 9, "y": 16}],
+            "multiply": [10, 2, 3],
+        }
+    )
+    x = pl.col("coords").struct.field("x")
+    y = pl.col("coords").struct.field("y")
+
+    q = df.lazy().select(
+        pl.col("coords").struct.with_fields(
+            (x * pl.col("multiply")).alias("x"),
+            (x + y).alias("sum"),
+            name=pl.lit("p"),
+        )
+    )
+    expected_dtype = pl.Struct(
+        {"x": pl.Int64, "y": pl.Int64, "sum": pl.Int64, "name": pl.String}
+    )
+    assert q.schema == {"coords": expected_dtype}
+
+    out = q.collect()
+    assert out.schema == {"coords": expected_dtype}
+    assert out.to_dict(as_series=False) == {
+        "coords": [
+            {"x": 10, "y": 4, "sum": 5, "name": "p"},
+            {"x": 8, "y": 9, "sum": 13, "name": "p"},
+            {"x": 27, "y": 16, "sum": 25, "name": "p"},
+        ]
+    }
+
+
+def test_struct_with_fields_rename_and_json() -> None:
+    df = pl.DataFrame({"s": [{"a": 1}, {"a": 2}], "b": ["x", "y"]})
+    out = df.select(
+        pl.col("s")
+        .struct.with_fields(pl.col("b"))
+        .struct.rename_fields(["A", "B"])
+        .struct.json_encode()
+    )
+    assert out.to_series().to_list() == ['{"A":1,"B":"x"}', '{"A":2,"B":"y"}']
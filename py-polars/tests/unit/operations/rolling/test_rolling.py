@@ -896,3 +896,80 @@ def test_rolling_min_periods(
         )
     )["value"]
     assert_series_equal(result, pl.Series("value", expected, pl.Int64))
+
+
+def test_rolling_by_matches_rolling_with_by_column(example_df: pl.DataFrame) -> None:
+    for agg in ("min", "max", "mean", "sum", "median", "std", "var"):
+        result = example_df.select(
+            getattr(pl.col("values"), f"rolling_{agg}_by")("dt", window_size="2d")
+        )
+        expected = example_df.select(
+            getattr(pl.col("values"), f"rolling_{agg}")(window_size="2d", by="dt")
+        )
+        assert_frame_equal(result, expected)
+
+    result = example_df.select(
+        pl.col("values").rolling_quantile_by(
+            "dt", timedelta(days=2), quantile=0.5, interpolation="linear"
+        )
+    )
+    expected = example_df.select(
+        pl.col("values").rolling_quantile(0.5, "linear", window_size="2d", by="dt")
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_rolling_by_expression_input() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": ["2020-01-01", "2020-01-02", "2020-01-04"],
+            "val": [1, 2, 3],
+        }
+    )
+    result = df.select(
+        pl.col("val").rolling_sum_by(
+            pl.col("dt").str.to_date(), "2d", closed="both", warn_if_unsorted=False
+        )
+    )
+    assert_series_equal(result.to_series(), pl.Series("val", [1, 3, 5]))
+
+
+def test_rolling_by_over_and_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2001, 1, d) for d in (1, 2, 1, 2, 3)],
+            "group": ["A", "A", "B", "B", "B"],
+            "val": [1, 2, 3, 4, 5],
+        }
+    )
+    result = df.select(
+        pl.col("val").rolling_sum_by("date", "2d", warn_if_unsorted=False).over("group")
+    )
+    assert_series_equal(result.to_series(), pl.Series("val", [1, 3, 3, 7, 9]))
+
+    result = (
+        df.group_by("group", maintain_order=True)
+        .agg(pl.col("val").rolling_max_by("date", "1d", warn_if_unsorted=False))
+        .sort("group")
+    )
+    expected = pl.DataFrame({"group": ["A", "B"], "val": [[1, 2], [3, 4, 5]]})
+    assert_frame_equal(result, expected)
+
+    # groups of a single row should still give a list per group
+    result = (
+        df.head(2)
+        .group_by("date", maintain_order=True)
+        .agg(
+            pl.col("val").rolling_mean_by("date", "1d", warn_if_unsorted=False)
+        )
+    )
+    expected = pl.DataFrame(
+        {"date": [date(2001, 1, 1), date(2001, 1, 2)], "val": [[1.0], [2.0]]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_rolling_by_invalid_input() -> None:
+    df = pl.DataFrame({"dt": [1, 2, 3], "val": [1, 2, 3]})
+    with pytest.raises(pl.InvalidOperationError, match=r"`rolling_mean` operation not supported for dtype `i64`"):
+        df.select(pl.col("val").rolling_mean_by("dt", "2d"))
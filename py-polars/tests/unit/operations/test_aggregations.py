@@ -398,3 +398,108 @@ def test_agg_filter_over_empty_df_13610() -> None:
     out = df.group_by("a").agg(pl.col("b").filter(pl.col("b").shift()))
     expected = pl.DataFrame(schema={"a": pl.Int64, "b": pl.List(pl.Boolean)})
     assert_frame_equal(out, expected)
+
+
+def test_weighted_aggregations() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "x": [1.0, 2.0, None, 4.0, 3.0, 5.0],
+            "w": [1, 2, 5, 1, None, 0],
+        }
+    )
+    result = df.select(
+        mean=pl.col("x").wmean("w"),
+        var=pl.col("x").wvar("w"),
+        var0=pl.col("x").wvar("w", ddof=0),
+        median=pl.col("x").wquantile("w", 0.5),
+        q0=pl.col("x").wquantile("w", 0.0),
+        q1=pl.col("x").wquantile("w", 1.0),
+    )
+    # equivalent to the unweighted statistics of [1, 2, 2, 4]
+    expected = pl.DataFrame(
+        {
+            "mean": [2.25],
+            "var": [4.75 / 3],
+            "var0": [4.75 / 4],
+            "median": [2.0],
+            "q0": [1.0],
+            "q1": [4.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # the only non-null value of group "b" has a zero weight
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(
+            pl.col("x").wmean("w").alias("mean"),
+            pl.col("x").wvar("w").alias("var"),
+            pl.col("x").wquantile("w", 0.5).alias("median"),
+        )
+        .with_columns(pl.col("var").round(6))
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "mean": [2.25, None],
+            "var": [1.583333, None],
+            "median": [2.0, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_weighted_aggregations_float32() -> None:
+    df = pl.DataFrame({"x": [1.0, 3.0]}, schema={"x": pl.Float32})
+    result = df.select(pl.col("x").wmean(pl.lit(pl.Series([1, 3]))))
+    assert result.schema["x"] == pl.Float32
+    assert result.item() == 2.5
+
+
+def test_weighted_aggregations_invalid_weights() -> None:
+    df = pl.DataFrame({"x": [1.0, 2.0], "w": [1.0, -1.0]})
+    with pytest.raises(pl.ComputeError, match="weights must be non-negative"):
+        df.select(pl.col("x").wmean("w"))
+    with pytest.raises(pl.ShapeError):
+        df.select(pl.col("x").wmean(pl.lit(pl.Series([1, 2, 3]))))
+
+
+def test_approx_quantile() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2, 3],
+            "x": [4.0, None, 1.0, float("nan"), 2.0, 3.0, None],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").approx_quantile(0.0).alias("min"),
+        pl.col("x").approx_quantile(0.5).alias("median"),
+        pl.col("x").approx_quantile(1.0).alias("max"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "min": [1.0, 2.0, None],
+            "median": [2.5, 2.5, None],
+            "max": [4.0, 3.0, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("x", range(100_000)).shuffle(seed=0)
+    for q in [0.01, 0.5, 0.99]:
+        estimate = s.to_frame().select(pl.col("x").approx_quantile(q)).item()
+        assert estimate == pytest.approx(q * 99_999, abs=1_000)
+
+
+def test_approx_quantile_dtypes() -> None:
+    df = pl.DataFrame({"x": [1.0, 3.0]}, schema={"x": pl.Float32})
+    result = df.select(pl.col("x").approx_quantile(0.5))
+    assert result.schema["x"] == pl.Float32
+    assert result.item() == 2.0
+
+    with pytest.raises(pl.ComputeError, match="quantile should be between"):
+        df.select(pl.col("x").approx_quantile(1.5))
+    with pytest.raises(pl.InvalidOperationError):
+        pl.DataFrame({"x": ["a"]}).select(pl.col("x").approx_quantile(0.5))
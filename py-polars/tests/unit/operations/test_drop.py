@@ -93,6 +93,23 @@ def test_drop_nulls_lazy() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_drop_nulls_subset_expressions() -> None:
+    lf = pl.LazyFrame({"a": [1, None, 3, 4], "b": [1, 2, None, 0]})
+
+    result = lf.drop_nulls(pl.col("a") + pl.col("b")).collect()
+    expected = pl.DataFrame({"a": [1, 4], "b": [1, 0]})
+    assert_frame_equal(result, expected)
+
+    # rows are dropped where the expression evaluates to null, not where the input is
+    result = lf.drop_nulls(pl.when(pl.col("b") != 0).then(pl.col("b"))).collect()
+    expected = pl.DataFrame({"a": [1, None], "b": [1, 2]})
+    assert_frame_equal(result, expected)
+
+    result = lf.collect().drop_nulls(["a", pl.col("b").cum_sum()])
+    expected = pl.DataFrame({"a": [1, 4], "b": [1, 0]})
+    assert_frame_equal(result, expected)
+
+
 def test_drop_columns() -> None:
     out = pl.LazyFrame({"a": [1], "b": [2], "c": [3]}).drop(["a", "b"])
     assert out.columns == ["c"]
@@ -288,6 +288,43 @@ def test_explode_invalid_element_count() -> None:
         df.explode(["col1", "col2"])
 
 
+def test_explode_mismatch() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "a": [[1, 2], [3], []],
+            "b": [["x", "y"], ["z", "w"], ["v"]],
+        }
+    )
+    out = df.explode("a", "b", mismatch="null_pad")
+    expected = pl.DataFrame(
+        {
+            "id": [1, 1, 2, 2, 3],
+            "a": [1, 2, 3, None, None],
+            "b": ["x", "y", "z", "w", "v"],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.lazy().explode("a", "b", mismatch="cartesian").collect()
+    expected = pl.DataFrame(
+        {
+            "id": [1, 1, 2, 2, 3],
+            "a": [1, 2, 3, 3, None],
+            "b": ["x", "y", "z", "w", "v"],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.slice(1, 1).explode("a", "b", mismatch="cartesian")
+    assert out.rows() == [(2, 3, "z"), (2, 3, "w")]
+
+    with pytest.raises(pl.ShapeError):
+        df.explode("a", "b")
+    with pytest.raises(ValueError, match="`mismatch` must be one of"):
+        df.explode("a", "b", mismatch="zip")  # type: ignore[arg-type]
+
+
 def test_logical_explode() -> None:
     out = (
         pl.DataFrame(
@@ -1,3 +1,5 @@
+import pytest
+
 import polars as pl
 
 
@@ -32,3 +34,35 @@ def test_gather_agg_schema() -> None:
         .schema["value"]
         == pl.Int64
     )
+
+
+def test_gather_every_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2, 2, 2], "a": [1, 2, 3, 4, 5, 6, 7]})
+    result = df.group_by("g", maintain_order=True).agg(
+        every_2=pl.col("a").gather_every(2),
+        every_2_offset_1=pl.col("a").gather_every(2, offset=1),
+    )
+    assert result.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "every_2": [[1, 3], [4, 6]],
+        "every_2_offset_1": [[2], [5, 7]],
+    }
+
+
+def test_gather_every_invalid_n() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(pl.InvalidOperationError, match="n should be positive"):
+        df.select(pl.col("a").gather_every(0))
+
+
+def test_arg_where_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "a": [5, 1, 7, 2, 9]})
+    result = df.group_by("g", maintain_order=True).agg(
+        idx=pl.arg_where(pl.col("a") > 4),
+        values=pl.col("a").gather(pl.arg_where(pl.col("a") > 4)),
+    )
+    assert result.to_dict(as_series=False) == {
+        "g": [1, 2],
+        "idx": [[0, 2], [1]],
+        "values": [[5, 7], [9]],
+    }
@@ -0,0 +1,33 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+def test_haversine_distance() -> None:
+    df = pl.DataFrame({"lat": [48.8566, 52.3676, None], "lon": [2.3522, 4.9041, 0.0]})
+    out = df.select(pl.haversine_distance(52.3676, 4.9041, "lat", "lon").round(1))
+    assert out.to_series().to_list() == [429.9, 0.0, None]
+
+
+def test_point_in_polygon() -> None:
+    df = pl.DataFrame({"x": [0.25, 1.5, 0.9, None], "y": [0.25, 0.5, 0.2, 0.5]})
+    points = pl.struct("x", "y")
+    out = df.select(
+        polygon=points.point_in_polygon([(0, 0), (1, 0), (0, 1)]),
+        bbox=points.within_bbox(0, 0, 1, 1),
+    )
+    assert_series_equal(
+        out["polygon"], pl.Series("polygon", [True, False, False, None])
+    )
+    assert_series_equal(out["bbox"], pl.Series("bbox", [True, False, True, None]))
+
+
+def test_point_in_polygon_invalid() -> None:
+    df = pl.DataFrame({"x": [0.25], "y": [0.25]})
+    with pytest.raises(pl.ComputeError, match="at least 3 vertices"):
+        df.select(pl.struct("x", "y").point_in_polygon([(0, 0), (1, 0)]))
+    with pytest.raises(pl.InvalidOperationError, match="points must be structs"):
+        df.select(pl.col("x").within_bbox(0, 0, 1, 1))
@@ -695,6 +695,47 @@ def test_group_by_empty_groups(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("aggregation", "expected_values", "expected_dtype"),
+    [
+        ("sum", [1, 5, 0], pl.Int64),
+        ("mean", [1.0, 5.0, None], pl.Float64),
+        ("count", [1, 1, 0], pl.UInt32),
+        ("len", [2, 1, 0], pl.UInt32),
+    ],
+)
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.Int64, pl.Float32])
+def test_group_by_masked_aggregation(
+    aggregation: str,
+    expected_values: list[object],
+    expected_dtype: pl.DataType,
+    dtype: pl.DataType,
+) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": pl.Series([1, None, 3, 4, 5, 6], dtype=dtype),
+            "b": [True, True, None, False, True, False],
+        }
+    )
+
+    def agg(predicate: pl.Expr) -> pl.DataFrame:
+        expr = getattr(pl.col("a").filter(predicate), aggregation)()
+        return df.group_by("g", maintain_order=True).agg(expr)
+
+    # the predicate is element-wise, so the aggregation is fused with the filter
+    result = agg(pl.col("b"))
+    # `shift(0)` is a no-op that isn't element-wise, so this filters every group
+    assert_frame_equal(result, agg(pl.col("b").shift(0)))
+
+    if aggregation in ("sum", "mean") and dtype == pl.Float32:
+        expected_dtype = pl.Float32
+    expected = pl.DataFrame({"g": [1, 2, 3], "a": expected_values}).with_columns(
+        pl.col("a").cast(expected_dtype)
+    )
+    assert_frame_equal(result, expected)
+
+
 # https://github.com/pola-rs/polars/issues/8663
 def test_perfect_hash_table_null_values() -> None:
     # fmt: off
@@ -933,3 +974,54 @@ def test_group_by_all_12869() -> None:
     df = pl.DataFrame({"a": [1]})
     result = next(iter(df.group_by(pl.all())))[1]
     assert_frame_equal(df, result)
+
+
+def test_group_by_having() -> None:
+    lf = pl.LazyFrame({"a": ["x", "y", "x", "z", "x", "y"], "b": [1, 2, 3, 4, 5, 6]})
+    q = (
+        lf.group_by("a", maintain_order=True)
+        .having(pl.len() > 1, pl.col("b").min() < 3)
+        .agg(pl.col("b").sum(), pl.col("b").max().alias("max"))
+    )
+    assert "FILTER" not in q.explain()
+    assert q.collect().to_dict(as_series=False) == {
+        "a": ["x", "y"],
+        "b": [9, 8],
+        "max": [5, 6],
+    }
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+
+def test_group_by_mode_skew_kurtosis_entropy() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "b", "a", "c", "b"],
+            "x": [1.0, 2.0, 2.0, None, 2.0, 5.0, 9.0, 3.0, 5.0],
+        }
+    )
+    aggs = [
+        pl.col("x").mode().alias("mode"),
+        pl.col("x").skew().alias("skew"),
+        pl.col("x").skew(bias=False).alias("skew_unbiased"),
+        pl.col("x").kurtosis().alias("kurtosis"),
+        pl.col("x").kurtosis(fisher=False, bias=False).alias("kurtosis_pearson"),
+        pl.col("x").entropy().alias("entropy"),
+        pl.col("x").entropy(base=2, normalize=False).alias("entropy_base2"),
+    ]
+    result = df.group_by("g", maintain_order=True).agg(aggs)
+
+    expected = pl.concat(
+        [
+            df.filter(pl.col("g") == g).select(
+                pl.lit(g).alias("g"), *aggs[1:], aggs[0].implode()
+            )
+            for g in ["a", "b", "c"]
+        ]
+    ).select(result.columns)
+    assert_frame_equal(result, expected)
+
+    # Multiple modes are ordered by their first occurrence.
+    df = pl.DataFrame({"g": [1, 1, 1, 1, 2], "x": ["q", "p", "p", "q", "r"]})
+    assert df.group_by("g", maintain_order=True).agg(
+        pl.col("x").mode()
+    ).to_dict(as_series=False) == {"g": [1, 2], "x": [["q", "p"], ["r"]]}
@@ -972,3 +972,26 @@ def test_group_by_dynamic_agg_bad_input_types(input: Any) -> None:
         df.group_by_dynamic(
             index_column="index_column", every="2i", closed="right"
         ).agg(input)
+
+
+def test_group_by_dynamic_mode_skew_kurtosis_entropy() -> None:
+    df = pl.DataFrame({"i": list(range(8)), "x": [1, 3, 3, 2, 2, None, 4, 4]})
+    df = df.set_sorted("i")
+    aggs = [
+        pl.col("x").mode().alias("mode"),
+        pl.col("x").skew().alias("skew"),
+        pl.col("x").kurtosis().alias("kurtosis"),
+        pl.col("x").entropy().alias("entropy"),
+    ]
+    # The windows overlap, as the period is larger than `every`.
+    result = df.group_by_dynamic("i", every="2i", period="4i").agg(aggs)
+
+    expected = pl.concat(
+        [
+            df.filter(pl.col("i").is_between(start, start + 4, closed="left"))
+            .select(pl.lit(start, dtype=pl.Int64).alias("i"), *aggs)
+            .with_columns(pl.col("mode").implode())
+            for start in range(0, 8, 2)
+        ]
+    )
+    assert_frame_equal(result, expected)
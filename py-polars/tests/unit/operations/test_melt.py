@@ -1,3 +1,5 @@
+import pytest
+
 import polars as pl
 import polars.selectors as cs
 from polars.testing import assert_frame_equal
@@ -69,3 +71,39 @@ def test_melt_projection_pd_7747() -> None:
         }
     )
     assert_frame_equal(result, expected)
+
+
+def test_melt_regex_and_selectors() -> None:
+    df = pl.DataFrame(
+        {
+            "id": ["x", "y"],
+            "val_a": [1, 2],
+            "val_b": [3, 4],
+            "other": [5.0, 6.0],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "id": ["x", "y", "x", "y"],
+            "variable": ["val_a", "val_a", "val_b", "val_b"],
+            "value": [1, 2, 3, 4],
+        }
+    )
+    assert_frame_equal(df.melt("id", "^val_.*$"), expected)
+    assert_frame_equal(df.lazy().melt("^id$", ["^val_.*$"]).collect(), expected)
+    assert_frame_equal(df.melt(cs.string(), cs.integer()), expected)
+
+    out = (
+        df.lazy()
+        .melt(cs.string(), "^val_.*$", streamable=True)
+        .collect(streaming=True)
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_melt_missing_column() -> None:
+    df = pl.DataFrame({"a": [1], "b": [2]})
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.lazy().melt("a", "c").collect()
+    with pytest.raises(pl.ColumnNotFoundError):
+        df.melt("c")
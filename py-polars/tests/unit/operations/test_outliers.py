@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture()
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "g": ["a"] * 5 + ["b"] * 5,
+            "v": [1, 2, 3, 4, 100, 10, 20, 30, 40, 50],
+        }
+    )
+
+
+def test_winsorize_over(df: pl.DataFrame) -> None:
+    result = df.select(pl.col("v").winsorize(0.0, 0.75).over("g"))
+    expected = pl.DataFrame({"v": [1, 2, 3, 4, 4, 10, 20, 30, 40, 40]})
+    assert_frame_equal(result, expected)
+
+
+def test_winsorize_invalid_quantiles() -> None:
+    with pytest.raises(pl.ComputeError):
+        pl.select(pl.lit(1).winsorize(0.9, 0.1))
+
+
+def test_is_outlier_group_by(df: pl.DataFrame) -> None:
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(
+            pl.col("v").is_outlier_iqr().alias("iqr"),
+            pl.col("v").is_outlier_zscore(1.5).alias("zscore"),
+        )
+        .explode("iqr", "zscore")
+    )
+    flags = [False, False, False, False, True] + [False] * 5
+    expected = pl.DataFrame(
+        {"g": ["a"] * 5 + ["b"] * 5, "iqr": flags, "zscore": flags}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_is_outlier_nulls() -> None:
+    s = pl.Series("a", [1.0, None, 2.0, 3.0, 1000.0])
+    result = s.to_frame().select(pl.col("a").is_outlier_iqr()).to_series()
+    assert result.to_list() == [False, None, False, False, True]
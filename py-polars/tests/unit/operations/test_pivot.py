@@ -354,3 +354,77 @@ def test_pivot_struct() -> None:
             {"num1": 4, "num2": 4},
         ],
     }
+
+
+@pytest.mark.parametrize(
+    "agg_fn", ["first", "last", "len", "min", "max", "sum", "mean", "median"]
+)
+def test_lazy_pivot_matches_eager(agg_fn: PivotAgg) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, 2, 2, 3, 1],
+            "b": ["a", "a", "b", "b", "b", "c"],
+            "c": [2, 4, None, 8, 10, 1],
+            "d": [1.5, 2.5, 3.5, 4.5, 5.5, 6.5],
+        }
+    )
+    for values in ["c", ["c", "d"]]:
+        expected = df.pivot(
+            values=values, index="b", columns="a", aggregate_function=agg_fn
+        )
+        result = df.lazy().pivot(
+            values=values,
+            index="b",
+            columns="a",
+            column_values=[1, 2, 3],
+            aggregate_function=agg_fn,
+        )
+        assert_frame_equal(result.collect(), expected, check_dtype=False)
+
+
+def test_lazy_pivot_expression_and_struct_output() -> None:
+    lf = pl.LazyFrame(
+        {
+            "k": ["x", "x", "y", "y", "x"],
+            "on": ["p", "q", "p", "p", "p"],
+            "u": [1, 2, 3, 4, 5],
+            "v": [10, 20, 30, 40, 50],
+        }
+    )
+    result = lf.pivot(
+        values="u",
+        index="k",
+        columns="on",
+        column_values=pl.Series(["q", "p", "r"]),
+        aggregate_function=pl.element().max() - pl.element().min(),
+        sort_columns=True,
+    )
+    assert result.collect().to_dict(as_series=False) == {
+        "k": ["x", "y"],
+        "p": [4, 1],
+        "q": [0, None],
+        "r": [None, None],
+    }
+
+    result = lf.pivot(
+        values=["u", "v"],
+        index="k",
+        columns="on",
+        column_values=["p", "q"],
+        aggregate_function="sum",
+        struct_output=True,
+    )
+    assert result.collect().to_dict(as_series=False) == {
+        "k": ["x", "y"],
+        "p": [{"u": 6, "v": 60}, {"u": 7, "v": 70}],
+        "q": [{"u": 2, "v": 20}, {"u": None, "v": None}],
+    }
+
+    with pytest.raises(ValueError, match="aggregate_function"):
+        lf.pivot(
+            values="u",
+            index="k",
+            columns="on",
+            column_values=["p"],
+            aggregate_function="foo",  # type: ignore[arg-type]
+        )
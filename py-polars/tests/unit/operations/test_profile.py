@@ -1,5 +1,14 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
 import polars as pl
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 def test_profile_columns() -> None:
     ldf = pl.LazyFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
@@ -30,3 +39,26 @@ def test_profile_with_cse() -> None:
         .then(None)
         .otherwise(pl.when(y == 0).then(None).otherwise(x + y))
     ).profile(comm_subexpr_elim=True)[1].shape == (2, 3)
+
+
+@pytest.mark.write_disk()
+def test_profile_scan_statistics(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 5, 3, 7]}).write_parquet(path, row_group_size=2)
+
+    df, timings, scan_stats = (
+        pl.scan_parquet(path).filter(pl.col("a") > 5).profile(scan_statistics=True)
+    )
+    assert df.to_series().to_list() == [7]
+    assert scan_stats.columns == [
+        "node",
+        "files_listed",
+        "files_pruned",
+        "row_groups_read",
+        "row_groups_pruned_by_statistics",
+        "row_groups_pruned_by_bloom_filter",
+        "bytes_downloaded",
+        "decode_time",
+    ]
+    assert scan_stats.drop("node", "decode_time").row(0) == (1, 0, 1, 1, 0, 0)
+    assert scan_stats["node"][0] in timings["node"].to_list()
@@ -130,3 +130,23 @@ def test_qcut_deprecated_label_name() -> None:
         s.qcut([0.1], category_label="x")
     with pytest.deprecated_call():
         s.qcut([0.1], break_point_label="x")
+
+
+@pytest.mark.parametrize("values", [[], [None, None]])
+def test_qcut_without_values(values: list[float | None]) -> None:
+    s = pl.Series("a", values, dtype=pl.Float64)
+
+    result = s.qcut([0.5], labels=["low", "high"])
+    expected = pl.Series("a", values, dtype=pl.Categorical)
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+    result = s.qcut([0.5], include_breaks=True)
+    result_df = result.struct.unnest()
+    assert result_df.schema == {"brk": pl.Float64, "a_bin": pl.Categorical}
+    assert result_df.null_count().row(0) == (len(values), len(values))
+
+
+def test_qcut_invalid_quantiles() -> None:
+    s = pl.Series([1.0, 2.0, 3.0])
+    with pytest.raises(pl.ComputeError, match="quantiles must be between 0 and 1"):
+        s.qcut([0.5, 1.5])
@@ -0,0 +1,41 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_rle() -> None:
+    s = pl.Series("s", [1, 1, 2, None, None, 3, 1, 1])
+    result = s.rle().struct.unnest()
+    expected = pl.DataFrame(
+        {
+            "lengths": pl.Series([2, 1, 2, 1, 2], dtype=pl.UInt32),
+            "values": [1, 2, None, 3, 1],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_rle_schema() -> None:
+    lf = pl.LazyFrame({"a": [1, 1, 2]}).select(pl.col("a").rle())
+    result = lf.collect()
+    assert lf.schema == result.schema
+    assert result.schema["a"] == pl.Struct({"lengths": pl.UInt32, "values": pl.Int64})
+
+
+def test_rle_empty() -> None:
+    s = pl.Series("s", [], dtype=pl.String)
+    result = s.rle()
+    assert result.len() == 0
+    assert result.dtype == pl.Struct({"lengths": pl.UInt32, "values": pl.String})
+
+
+def test_rle_id() -> None:
+    s = pl.Series("s", [1, 1, 2, None, None, 3, 1, 1])
+    expected = pl.Series("s", [0, 0, 1, 2, 2, 3, 4, 4], dtype=pl.UInt32)
+    assert_series_equal(s.rle_id(), expected)
+
+
+def test_rle_id_empty() -> None:
+    s = pl.Series("s", [], dtype=pl.Int64)
+    assert_series_equal(s.rle_id(), pl.Series("s", [], dtype=pl.UInt32))
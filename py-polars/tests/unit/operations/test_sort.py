@@ -484,6 +484,35 @@ def test_merge_sorted_one_empty() -> None:
     assert_frame_equal(out, df1)
 
 
+@pytest.mark.parametrize("streaming", [False, True])
+def test_concat_sorted(streaming: bool) -> None:
+    frames = [
+        pl.DataFrame({"day": [1, 4, 7], "value": [1, 4, 7]}),
+        pl.DataFrame({"day": [2, 5, 8], "value": [2, 5, 8]}),
+        pl.DataFrame({"day": [3, 6, 9], "value": [3, 6, 9]}),
+        pl.DataFrame({"day": [0, 10], "value": [0, 10]}),
+        pl.DataFrame({"day": [5], "value": [5]}),
+    ]
+    expected = pl.concat(frames).sort("day", maintain_order=True)
+
+    out = pl.concat_sorted(
+        [df.lazy().filter(pl.col("value") >= 0) for df in frames], by="day"
+    ).collect(streaming=streaming)
+    assert_frame_equal(out.select("day"), expected.select("day"))
+    assert out["day"].flags["SORTED_ASC"]
+
+    out = pl.concat_sorted(frames, by="day")
+    assert_frame_equal(out.select("day"), expected.select("day"))
+    assert out["day"].flags["SORTED_ASC"]
+
+    out = pl.concat_sorted(frames[:1], by="day")
+    assert_frame_equal(out, frames[0])
+    assert out["day"].flags["SORTED_ASC"]
+
+    with pytest.raises(ValueError, match="empty"):
+        pl.concat_sorted([], by="day")
+
+
 def test_sort_args() -> None:
     df = pl.DataFrame(
         {
@@ -608,6 +637,46 @@ def test_top_k_descending() -> None:
         df.top_k(1, by=["a", "b"], descending=[True])
 
 
+def test_top_k_by() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4, 5, 6],
+            "b": [12, 11, 10, 9, 8, 7],
+            "c": ["Apple", "Orange", "Apple", None, "Banana", "Banana"],
+        }
+    )
+
+    result = df.select(
+        pl.col("a").top_k_by("b", 2).alias("top"),
+        pl.col("a").bottom_k_by("b", 2).alias("bottom"),
+    )
+    assert result.to_dict(as_series=False) == {"top": [1, 2], "bottom": [6, 5]}
+
+    # nulls in `by` are selected last
+    result = df.select(pl.col("a").top_k_by("c", 6))
+    assert result.to_series().to_list() == [2, 5, 6, 1, 3, 4]
+    result = df.select(pl.col("a").bottom_k_by("c", 6))
+    assert result.to_series().to_list() == [1, 3, 5, 6, 2, 4]
+
+    result = df.select(
+        pl.col("a").top_k_by(["c", "a"], 3, descending=[True, False]),
+        pl.col("b").bottom_k_by(["c", "b"], 3, descending=[False, True]),
+    )
+    assert result.to_dict(as_series=False) == {"a": [3, 1, 6], "b": [12, 10, 8]}
+
+    result = df.group_by("c", maintain_order=True).agg(pl.col("a").top_k_by("b", 1))
+    assert result.to_dict(as_series=False) == {
+        "c": ["Apple", "Orange", None, "Banana"],
+        "a": [[1], [2], [4], [5]],
+    }
+
+    with pytest.raises(
+        ValueError,
+        match=r"the length of `descending` \(1\) does not match the length of `by` \(2\)",
+    ):
+        df.select(pl.col("a").top_k_by(["b", "c"], descending=[True]))
+
+
 def test_sort_by_descending() -> None:
     df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
     result = df.select(pl.col("a").sort_by(["a", "b"], descending=True))
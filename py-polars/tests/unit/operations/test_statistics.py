@@ -64,6 +64,26 @@ def test_hist_rand(n: int) -> None:
         assert ((a <= upper) & (a > lower)).sum() == count[i]
 
 
+def test_hist_multiple_chunks() -> None:
+    a = pl.Series("a", [1, 3, 8, 8])
+    a.append(pl.Series("a", [2, 1, 3]))
+    assert a.n_chunks() == 2
+
+    assert_frame_equal(a.hist(bin_count=4), a.rechunk().hist(bin_count=4))
+    assert_frame_equal(a.hist(bins=[0, 2]), a.rechunk().hist(bins=[0, 2]))
+    assert_frame_equal(a.hist(), a.rechunk().hist())
+
+
+def test_hist_nan_and_empty() -> None:
+    a = pl.Series("a", [1.0, float("nan"), 3.0, None])
+    result = a.hist(bins=[2], include_category=False, include_breakpoint=False)
+    assert result.to_series().to_list() == [1, 1]
+
+    empty = pl.Series("a", [], dtype=pl.Float64)
+    result = empty.hist(bin_count=2)
+    assert result["count"].to_list() == [0, 0, 0]
+
+
 def test_median_quantile_duration() -> None:
     df = pl.DataFrame({"A": [timedelta(days=0), timedelta(days=1)]})
 
@@ -193,3 +193,29 @@ def test_err_transpose_object() -> None:
 
     with pytest.raises(pl.InvalidOperationError):
         pl.DataFrame([CustomObject()]).transpose()
+
+
+def test_transpose_integer_column_names() -> None:
+    df = pl.DataFrame({"id": [1, 2], "a": [1.5, 2.5], "b": [3.5, None]})
+    result = df.transpose(include_header=True, column_names="id")
+    expected = pl.DataFrame(
+        {"column": ["a", "b"], "1": [1.5, 3.5], "2": [2.5, None]},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="null values"):
+        pl.DataFrame({"id": [1, None], "a": [1, 2]}).transpose(column_names="id")
+
+
+def test_transpose_wide() -> None:
+    df = pl.DataFrame(
+        {"a": list(range(100)), "b": [str(i) for i in range(100)]},
+    )
+    result = df.transpose()
+    assert result.shape == (2, 100)
+    assert result.row(0) == tuple(str(i) for i in range(100))
+    assert result.row(1) == tuple(str(i) for i in range(100))
+    assert_frame_equal(
+        result.transpose(column_names=["a", "b"]),
+        df.select(pl.all().cast(pl.String)),
+    )
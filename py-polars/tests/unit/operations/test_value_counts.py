@@ -3,6 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
+import polars.selectors as cs
 from polars.testing import assert_frame_equal
 
 
@@ -66,3 +67,41 @@ def test_value_counts_duplicate_name() -> None:
         "literal": [1],
         "a": [3],
     }
+
+
+def test_df_value_counts() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 1, 1, 2, None],
+            "b": ["x", "y", "x", "z", "y", None],
+            "c": [0.1, 0.2, 0.3, 0.4, 0.5, 0.6],
+        }
+    )
+    result = df.value_counts(["a", "b"])
+    expected = pl.DataFrame(
+        {"a": [1, 2, 1, None], "b": ["x", "y", "z", None], "count": [2, 2, 1, 1]},
+        schema_overrides={"count": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.value_counts(cs.string(), sort=True, normalize=True)
+    expected = pl.DataFrame(
+        {"b": ["x", "y", "z", None], "proportion": [2 / 6, 2 / 6, 1 / 6, 1 / 6]}
+    )
+    assert_frame_equal(result, expected)
+
+    assert df.value_counts(sort=True).height == 6
+    assert_frame_equal(
+        df.value_counts("a", sort=True),
+        pl.DataFrame(
+            {"a": [1, 2, None], "count": [3, 2, 1]},
+            schema_overrides={"count": pl.UInt32},
+        ),
+    )
+
+
+def test_df_value_counts_duplicate_name() -> None:
+    df = pl.DataFrame({"count": [1, 2]})
+    with pytest.raises(pl.DuplicateError):
+        df.value_counts()
+    assert df.value_counts(normalize=True).columns == ["count", "proportion"]
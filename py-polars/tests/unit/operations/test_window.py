@@ -442,3 +442,125 @@ def test_window_13173() -> None:
         "val": ["2", "3"],
         "min_val_per_color": ["2", "3"],
     }
+
+
+def test_shift_and_diff_over_groups() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "c"],
+            "x": [1, 10, 3, None, 6, 100],
+        }
+    )
+    out = df.select(
+        pl.col("x").shift(1).over("g").alias("shift"),
+        pl.col("x").shift(-1, fill_value=0).over("g").alias("shift_fill"),
+        pl.col("x").diff().over("g").alias("diff"),
+        pl.col("x").diff(-1).over("g").alias("diff_neg"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "shift": [None, None, 1, 10, 3, None],
+        "shift_fill": [3, None, 6, 0, 0, 0],
+        "diff": [None, None, 2, None, 3, None],
+        "diff_neg": [-2, None, -3, None, None, None],
+    }
+
+
+
+def test_ranking_functions_over_groups() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "a", "b", "b", "b"],
+            "x": [4, 1, 4, 2, 5, 7, 7, None],
+        }
+    )
+    out = df.select(
+        pl.col("x").dense_rank().over("g").alias("dense_rank"),
+        pl.col("x").percent_rank().over("g").alias("percent_rank"),
+        pl.col("x").cume_dist().over("g").alias("cume_dist"),
+        pl.col("x").ntile(2).over("g").alias("ntile"),
+    )
+    assert out.schema == {
+        "dense_rank": pl.UInt32,
+        "percent_rank": pl.Float64,
+        "cume_dist": pl.Float64,
+        "ntile": pl.UInt32,
+    }
+    assert out.to_dict(as_series=False) == {
+        "dense_rank": [3, 1, 3, 2, 4, 1, 1, None],
+        "percent_rank": [0.5, 0.0, 0.5, 0.25, 1.0, 0.0, 0.0, None],
+        "cume_dist": [0.8, 0.2, 0.8, 0.4, 1.0, 1.0, 1.0, None],
+        "ntile": [1, 1, 2, 1, 2, 1, 2, None],
+    }
+
+
+def test_ranking_functions_edge_cases() -> None:
+    s = pl.Series("x", [1, 2, 3])
+    out = s.to_frame().select(
+        pl.col("x").ntile(5).alias("ntile"),
+        pl.col("x").percent_rank(descending=True).alias("percent_rank"),
+        pl.col("x").cume_dist(descending=True).alias("cume_dist"),
+        pl.col("x").head(1).percent_rank().alias("single"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "ntile": [1, 2, 3],
+        "percent_rank": [1.0, 0.5, 0.0],
+        "cume_dist": [1.0, 2 / 3, 1 / 3],
+        "single": [0.0, 0.0, 0.0],
+    }
+
+    with pytest.raises(ValueError, match="must be positive"):
+        pl.col("x").ntile(0)
+
+
+def test_over_order_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "a"],
+            "t": [3, 1, 1, 2, 2, 4],
+            "x": [30, 10, 10, 20, 20, 40],
+        }
+    )
+    out = df.select(
+        pl.col("x").cum_sum().over("g", order_by="t").alias("cum_sum"),
+        pl.col("x").first().over("g", order_by="t").alias("first"),
+        pl.col("x").shift(1).over("g", order_by="t").alias("shift"),
+        pl.col("x")
+        .cum_sum()
+        .over("g", order_by="t", descending=True)
+        .alias("cum_sum_desc"),
+        pl.col("x").cum_sum().over("g", order_by=["g", "t"]).alias("cum_sum_multi"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "cum_sum": [60, 10, 10, 30, 30, 100],
+        "first": [10, 10, 10, 10, 10, 10],
+        "shift": [20, None, None, 10, 10, 30],
+        "cum_sum_desc": [70, 30, 100, 20, 90, 40],
+        "cum_sum_multi": [60, 10, 10, 30, 30, 100],
+    }
+
+
+def test_over_frame() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "a"],
+            "t": [3, 1, 1, 2, 2, 4],
+            "x": [30, 10, 10, 20, 20, 40],
+        }
+    )
+    out = df.select(
+        pl.col("x").sum().over("g", order_by="t", frame=(-1, 0)).alias("moving"),
+        pl.col("x").max().over("g", order_by="t", frame=(1, 1)).alias("next"),
+        pl.col("x").sum().over("g", order_by="t", frame=(None, 0)).alias("running"),
+        pl.col("x").len().over("g", frame=(None, None)).alias("len"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "moving": [50, 10, 10, 30, 30, 70],
+        "next": [40, 20, 20, None, 30, None],
+        "running": [60, 10, 10, 30, 30, 100],
+        "len": [4, 2, 4, 2, 4, 4],
+    }
+
+    with pytest.raises(ValueError, match="group_to_rows"):
+        pl.col("x").sum().over("g", mapping_strategy="join", frame=(-1, 0))
+    with pytest.raises(pl.InvalidOperationError, match="requires an aggregation"):
+        df.select(pl.col("x").cum_sum().over("g", frame=(-1, 0)))
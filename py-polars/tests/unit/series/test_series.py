@@ -2357,6 +2357,30 @@ def test_cumulative_eval() -> None:
     assert_series_equal(s.cumulative_eval(expr3), expected3)
 
 
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.element().sum(),
+        pl.element().mean(),
+        pl.element().min(),
+        pl.element().max(),
+        pl.element().rank("min").last(),
+        pl.element().rank("max", descending=True).last(),
+        pl.element().rank("average").last(),
+    ],
+)
+@pytest.mark.parametrize("min_periods", [0, 1, 3])
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.Int64, pl.Float64])
+def test_cumulative_eval_incremental(
+    expr: pl.Expr, min_periods: int, dtype: pl.PolarsDataType
+) -> None:
+    s = pl.Series("values", [None, 3, None, 1, 3, 2, 5], dtype=dtype)
+    result = s.cumulative_eval(expr, min_periods=min_periods)
+    # wrapping the expression prevents the incremental implementation from being used
+    expected = s.cumulative_eval(expr.alias("slow"), min_periods=min_periods)
+    assert_series_equal(result, expected)
+
+
 def test_reverse() -> None:
     s = pl.Series("values", [1, 2, 3, 4, 5])
     assert s.reverse().to_list() == [5, 4, 3, 2, 1]
@@ -395,3 +395,19 @@ def test_streaming_with_hconcat(tmp_path: Path) -> None:
     )
 
     assert_frame_equal(result, expected)
+
+
+def test_streaming_with_row_index(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    lf = (
+        pl.LazyFrame({"a": range(10_000)})
+        .filter(pl.col("a") % 3 == 0)
+        .with_row_index(offset=5)
+        .filter(pl.col("index") > 100)
+    )
+    result = lf.collect(streaming=True)
+    assert_frame_equal(result, lf.collect(streaming=False))
+    assert result["index"].to_list() == list(range(101, 3339))
+
+    (_, err) = capfd.readouterr()
+    assert "row_index" in err
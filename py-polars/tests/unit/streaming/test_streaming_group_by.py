@@ -422,3 +422,26 @@ def test_streaming_group_by_literal(literal: Any) -> None:
         "a_count": [20],
         "a_sum": [190],
     }
+
+
+def test_streaming_group_by_approx_aggregations() -> None:
+    n = 30_000
+    df = pl.DataFrame({"g": [i % 3 for i in range(n)], "x": range(n)})
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("x").approx_n_unique().alias("n_unique"),
+            pl.col("x").approx_quantile(0.5).alias("median"),
+        )
+        .sort("g")
+    )
+    assert "--- STREAMING" in q.explain(streaming=True)
+
+    for result in [q.collect(streaming=True), q.collect()]:
+        assert result["g"].to_list() == [0, 1, 2]
+        assert result["n_unique"].dtype == pl.UInt32
+        for n_unique in result["n_unique"]:
+            assert n_unique == pytest.approx(n // 3, rel=0.05)
+        for g, median in zip(result["g"], result["median"]):
+            assert median == pytest.approx(n / 2 + g, rel=0.01)
@@ -3,6 +3,7 @@ from __future__ import annotations
 import unittest
 from typing import TYPE_CHECKING
 
+import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
@@ -198,3 +199,52 @@ def test_streaming_cross_join_schema(tmp_path: Path) -> None:
     a.join(b, how="cross").sink_parquet(file_path)
     read = pl.read_parquet(file_path, parallel="none")
     assert read.to_dict(as_series=False) == {"a": [1, 2], "b": ["b", "b"]}
+
+
+@pytest.mark.write_disk()
+def test_sink_parquet_row_group_size_bytes_and_column_options(tmp_path: Path) -> None:
+    file_path = tmp_path / "sink.parquet"
+    df = pl.DataFrame({"a": range(1000), "b": [str(i) for i in range(1000)]})
+
+    df.lazy().sink_parquet(
+        file_path,
+        row_group_size_bytes=1000,
+        column_options={"b": {"encoding": "delta_length_byte_array"}},
+    )
+
+    assert_frame_equal(pl.read_parquet(file_path), df)
+    metadata = pq.ParquetFile(file_path).metadata
+    assert metadata.num_row_groups > 1
+    assert "DELTA_LENGTH_BYTE_ARRAY" in metadata.row_group(0).column(1).encodings
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("file_format", ["parquet", "ipc", "csv"])
+def test_sink_partitioned(tmp_path: Path, file_format: str) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame(
+        {
+            "a": ["x", "y", "x", "z", "y"],
+            "b": [1, 1, 2, 2, 1],
+            "c": [1.0, 2.0, 3.0, 4.0, 5.0],
+        }
+    )
+    df.lazy().sink_partitioned(
+        tmp_path,
+        "a",
+        "b",
+        file_format=file_format,  # type: ignore[arg-type]
+    )
+
+    readers = {"parquet": pl.read_parquet, "ipc": pl.read_ipc, "csv": pl.read_csv}
+    read = readers[file_format]
+    for (a, b), expected in df.partition_by("a", "b", as_dict=True).items():
+        result = read(tmp_path / f"a={a}" / f"b={b}" / f"data-0000.{file_format}")
+        assert_frame_equal(result, expected)
+
+
+@pytest.mark.write_disk()
+def test_sink_partitioned_missing_column(tmp_path: Path) -> None:
+    lf = pl.LazyFrame({"a": [1, 2]})
+    with pytest.raises(pl.ColumnNotFoundError):
+        lf.sink_partitioned(tmp_path, "b")
@@ -773,6 +773,35 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_result_cache_dir(tmp_path: Path) -> None:
+    cache_dir = tmp_path / "results"
+    path = tmp_path / "data.csv"
+    pl.DataFrame({"a": [1, 2, 3]}).write_csv(path)
+    q = pl.scan_csv(path).select(pl.col("a").sum())
+
+    with pl.Config() as cfg:
+        cfg.set_result_cache_dir(cache_dir)
+        assert os.environ.get("POLARS_RESULT_CACHE_DIR") == str(cache_dir)
+
+        assert q.collect().item() == 6
+        assert len(list(cache_dir.iterdir())) == 1
+        # the second run reads the cached result
+        assert q.collect().item() == 6
+        assert len(list(cache_dir.iterdir())) == 1
+
+        # a change of the file invalidates the result
+        pl.DataFrame({"a": [1, 2, 3, 4]}).write_csv(path)
+        assert q.collect().item() == 10
+        assert len(list(cache_dir.iterdir())) == 2
+
+        # queries on in-memory data are not cached
+        assert pl.LazyFrame({"a": [1, 2]}).sum().collect().item() == 3
+        assert len(list(cache_dir.iterdir())) == 2
+
+        cfg.set_result_cache_dir(None)
+        assert "POLARS_RESULT_CACHE_DIR" not in os.environ
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
@@ -839,6 +868,7 @@ def test_set_fmt_str_lengths_invalid_length() -> None:
             True,
             "1",
         ),
+        ("POLARS_RESULT_CACHE_DIR", "set_result_cache_dir", "/tmp/res", "/tmp/res"),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
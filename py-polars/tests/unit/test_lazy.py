@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import json
 from datetime import date, datetime
 from functools import reduce
 from inspect import signature
@@ -339,6 +340,24 @@ def test_describe_plan() -> None:
     assert isinstance(pl.LazyFrame({"a": [1]}).explain(optimized=False), str)
 
 
+def test_describe_annotated_plan() -> None:
+    left = pl.LazyFrame({"key": [3, 1, 2, 5, 1], "l": ["a", "b", "c", "d", "e"]})
+    right = pl.LazyFrame({"key": [1, 5, 4, 3], "r": [10, 20, 30, 40]})
+    q = left.join(right, on="key")
+
+    plan = q.explain(annotate=True)
+    assert plan.startswith("INNER JOIN")
+    assert "strategy: hash, build: right" in plan
+
+    plan = json.loads(q.explain(annotate=True, format="json"))
+    assert plan["node"] == "join"
+    assert plan["join_strategy"] == {"algorithm": "hash", "build_side": "right"}
+    assert [i["estimated_rows"] for i in plan["inputs"]] == [5, 4]
+
+    with pytest.raises(ValueError, match="`format` must be one of"):
+        q.explain(annotate=True, format="xml")  # type: ignore[arg-type]
+
+
 def test_inspect(capsys: CaptureFixture[str]) -> None:
     ldf = pl.LazyFrame({"a": [1]})
     ldf.inspect().collect()